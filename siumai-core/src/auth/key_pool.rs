@@ -0,0 +1,521 @@
+//! API key pools with rotation and per-key health.
+//!
+//! Provider configs take a single API key. An [`ApiKeyPool`] spreads traffic across several
+//! keys of one provider. It is registered as an [`HttpInterceptor`] bound to that provider
+//! ([`ApiKeyPool::for_provider`]):
+//!
+//! - before each attempt, executors ask it for a key ([`HttpInterceptor::api_key_for`]) and put
+//!   the key into `ProviderContext::api_key`, so the provider's `build_headers` emits its usual
+//!   auth header with it;
+//! - after the attempt, it records the outcome against the key that was used.
+//!
+//! Keys are quarantined when a request fails with 401 (disabled until reinstated) or
+//! 429/quota errors (cooled down for a while). Because selection happens per attempt, retries
+//! rotate to a healthy key. Per-key counters are available via [`ApiKeyPool::usage`].
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::error::LlmError;
+use crate::execution::http::interceptor::{HttpInterceptor, HttpRequestContext};
+
+/// How many in-flight request ids are remembered to attribute responses to keys.
+const MAX_TRACKED_REQUESTS: usize = 1024;
+
+/// Key selection strategy for [`ApiKeyPool`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyRotationStrategy {
+    /// Cycle through healthy keys in order.
+    #[default]
+    RoundRobin,
+    /// Prefer the healthy key that was rate limited longest ago (never-limited keys first).
+    LeastRecentlyLimited,
+}
+
+/// Per-key usage and health snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeyUsage {
+    /// Display label (never the raw key).
+    pub label: String,
+    /// Attempts sent with this key.
+    pub requests: u64,
+    /// Attempts that received a successful response (not observed on custom transports).
+    pub successes: u64,
+    /// Rate-limit (429) failures.
+    pub rate_limited: u64,
+    /// Quota-exhaustion failures.
+    pub quota_exceeded: u64,
+    /// Authentication (401/403) failures.
+    pub auth_failures: u64,
+    /// Other request failures.
+    pub other_failures: u64,
+    /// Whether the key is disabled until [`ApiKeyPool::reinstate`] is called.
+    pub disabled: bool,
+    /// Remaining cooldown after a rate-limit or quota failure.
+    pub cooldown_remaining: Option<Duration>,
+}
+
+#[derive(Debug)]
+struct PooledKey {
+    label: String,
+    key: String,
+    requests: u64,
+    successes: u64,
+    rate_limited: u64,
+    quota_exceeded: u64,
+    auth_failures: u64,
+    other_failures: u64,
+    disabled: bool,
+    cooldown_until: Option<Instant>,
+    last_limited_at: Option<Instant>,
+}
+
+impl PooledKey {
+    fn new(label: String, key: String) -> Self {
+        Self {
+            label,
+            key,
+            requests: 0,
+            successes: 0,
+            rate_limited: 0,
+            quota_exceeded: 0,
+            auth_failures: 0,
+            other_failures: 0,
+            disabled: false,
+            cooldown_until: None,
+            last_limited_at: None,
+        }
+    }
+
+    fn is_available(&self, now: Instant) -> bool {
+        !self.disabled && self.cooldown_until.is_none_or(|until| until <= now)
+    }
+}
+
+#[derive(Debug, Default)]
+struct PoolState {
+    keys: Vec<PooledKey>,
+    cursor: usize,
+    in_flight: VecDeque<(String, usize)>,
+}
+
+impl PoolState {
+    fn select(&mut self, strategy: KeyRotationStrategy, now: Instant) -> Option<usize> {
+        let len = self.keys.len();
+        let idx = match strategy {
+            KeyRotationStrategy::RoundRobin => (0..len)
+                .map(|offset| (self.cursor + offset) % len)
+                .find(|&i| self.keys[i].is_available(now)),
+            KeyRotationStrategy::LeastRecentlyLimited => (0..len)
+                .filter(|&i| self.keys[i].is_available(now))
+                .min_by_key(|&i| (self.keys[i].last_limited_at, self.keys[i].requests)),
+        }?;
+        self.cursor = (idx + 1) % len;
+        Some(idx)
+    }
+
+    fn track(&mut self, request_id: &str, idx: usize) {
+        self.in_flight.retain(|(id, _)| id != request_id);
+        if self.in_flight.len() >= MAX_TRACKED_REQUESTS {
+            self.in_flight.pop_front();
+        }
+        self.in_flight.push_back((request_id.to_string(), idx));
+    }
+
+    fn take(&mut self, request_id: &str) -> Option<usize> {
+        let pos = self.in_flight.iter().position(|(id, _)| id == request_id)?;
+        self.in_flight.remove(pos).map(|(_, idx)| idx)
+    }
+}
+
+/// A pool of API keys for one provider, usable as an [`HttpInterceptor`].
+///
+/// Clones share the same state.
+#[derive(Debug, Clone)]
+pub struct ApiKeyPool {
+    state: Arc<Mutex<PoolState>>,
+    provider_id: Option<String>,
+    strategy: KeyRotationStrategy,
+    rate_limit_cooldown: Duration,
+    quota_cooldown: Duration,
+}
+
+impl ApiKeyPool {
+    /// Create a pool from raw keys. Labels are derived from the last characters of each key.
+    pub fn new<I, S>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        keys.into_iter().fold(Self::empty(), |pool, key| {
+            let key = key.into();
+            let label = default_label(&key);
+            pool.with_labeled_key(label, key)
+        })
+    }
+
+    fn empty() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(PoolState::default())),
+            provider_id: None,
+            strategy: KeyRotationStrategy::default(),
+            rate_limit_cooldown: Duration::from_secs(60),
+            quota_cooldown: Duration::from_secs(60 * 60),
+        }
+    }
+
+    /// Add a key with an explicit label used in usage reports.
+    pub fn with_labeled_key(self, label: impl Into<String>, key: impl Into<String>) -> Self {
+        let key = key.into();
+        if let Ok(mut state) = self.state.lock()
+            && !key.is_empty()
+            && !state.keys.iter().any(|k| k.key == key)
+        {
+            state.keys.push(PooledKey::new(label.into(), key));
+        }
+        self
+    }
+
+    /// Only supply keys to, and record outcomes for, requests of `provider_id`.
+    ///
+    /// Registry ids with a variant suffix also match their family's requests: a pool bound to
+    /// `"acme-chat"` serves requests reported as `"acme"`. An unbound pool serves every
+    /// request it sees, which only suits interceptor lists owned by a single provider client.
+    pub fn for_provider(mut self, provider_id: impl Into<String>) -> Self {
+        self.provider_id = Some(provider_id.into());
+        self
+    }
+
+    /// The provider this pool is bound to, if any.
+    pub fn provider_id(&self) -> Option<&str> {
+        self.provider_id.as_deref()
+    }
+
+    pub fn with_strategy(mut self, strategy: KeyRotationStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// How long a key is skipped after a rate-limit (429) failure (default: 60s).
+    pub fn with_rate_limit_cooldown(mut self, cooldown: Duration) -> Self {
+        self.rate_limit_cooldown = cooldown;
+        self
+    }
+
+    /// How long a key is skipped after a quota-exhaustion failure (default: 1h).
+    pub fn with_quota_cooldown(mut self, cooldown: Duration) -> Self {
+        self.quota_cooldown = cooldown;
+        self
+    }
+
+    /// The key providers should be configured with.
+    ///
+    /// Executors replace it per attempt; it only lets provider builders that require a key
+    /// succeed.
+    pub fn primary_key(&self) -> Option<String> {
+        let state = self.state.lock().ok()?;
+        state.keys.first().map(|k| k.key.clone())
+    }
+
+    /// Number of keys in the pool.
+    pub fn len(&self) -> usize {
+        self.state.lock().map(|s| s.keys.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Per-key usage and health, in insertion order.
+    pub fn usage(&self) -> Vec<ApiKeyUsage> {
+        let Ok(state) = self.state.lock() else {
+            return Vec::new();
+        };
+        let now = Instant::now();
+        state
+            .keys
+            .iter()
+            .map(|k| ApiKeyUsage {
+                label: k.label.clone(),
+                requests: k.requests,
+                successes: k.successes,
+                rate_limited: k.rate_limited,
+                quota_exceeded: k.quota_exceeded,
+                auth_failures: k.auth_failures,
+                other_failures: k.other_failures,
+                disabled: k.disabled,
+                cooldown_remaining: k
+                    .cooldown_until
+                    .and_then(|until| until.checked_duration_since(now))
+                    .filter(|d| !d.is_zero()),
+            })
+            .collect()
+    }
+
+    /// Re-enable a key (by label) that was disabled or cooled down. Returns `false` if unknown.
+    pub fn reinstate(&self, label: &str) -> bool {
+        let Ok(mut state) = self.state.lock() else {
+            return false;
+        };
+        let Some(key) = state.keys.iter_mut().find(|k| k.label == label) else {
+            return false;
+        };
+        key.disabled = false;
+        key.cooldown_until = None;
+        true
+    }
+
+    fn serves(&self, ctx: &HttpRequestContext) -> bool {
+        let Some(bound) = self.provider_id.as_deref() else {
+            return true;
+        };
+        bound == ctx.provider_id
+            || bound
+                .strip_prefix(ctx.provider_id.as_str())
+                .is_some_and(|variant| variant.starts_with('-'))
+    }
+
+    /// Select the key for the next attempt and account for it.
+    fn checkout(&self, request_id: &str) -> Result<Option<String>, LlmError> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| LlmError::InternalError("API key pool lock poisoned".to_string()))?;
+        if state.keys.is_empty() {
+            return Ok(None);
+        }
+
+        let Some(idx) = state.select(self.strategy, Instant::now()) else {
+            return Err(if state.keys.iter().all(|k| k.disabled) {
+                LlmError::AuthenticationError(
+                    "All API keys in the pool failed authentication".to_string(),
+                )
+            } else {
+                LlmError::rate_limit_error(
+                    "All API keys in the pool are cooling down after rate limits".to_string(),
+                )
+            });
+        };
+
+        state.keys[idx].requests += 1;
+        state.track(request_id, idx);
+        Ok(Some(state.keys[idx].key.clone()))
+    }
+
+    fn record_failure(&self, ctx: &HttpRequestContext, error: &LlmError) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let Some(idx) = state.take(&ctx.request_id) else {
+            return;
+        };
+        let now = Instant::now();
+        let key = &mut state.keys[idx];
+
+        match classify(error) {
+            Failure::Auth => {
+                key.auth_failures += 1;
+                key.disabled = true;
+            }
+            Failure::RateLimit => {
+                key.rate_limited += 1;
+                key.last_limited_at = Some(now);
+                key.cooldown_until = Some(now + self.rate_limit_cooldown);
+            }
+            Failure::Quota => {
+                key.quota_exceeded += 1;
+                key.last_limited_at = Some(now);
+                key.cooldown_until = Some(now + self.quota_cooldown);
+            }
+            Failure::Other => key.other_failures += 1,
+        }
+
+        tracing::debug!(
+            target: "siumai::auth",
+            request_id = %ctx.request_id,
+            provider = %ctx.provider_id,
+            key = %key.label,
+            disabled = key.disabled,
+            err = %error,
+            "API key request failed"
+        );
+    }
+}
+
+impl HttpInterceptor for ApiKeyPool {
+    fn api_key_for(&self, ctx: &HttpRequestContext) -> Result<Option<String>, LlmError> {
+        if !self.serves(ctx) {
+            return Ok(None);
+        }
+        self.checkout(&ctx.request_id)
+    }
+
+    fn on_response(
+        &self,
+        ctx: &HttpRequestContext,
+        _response: &reqwest::Response,
+    ) -> Result<(), LlmError> {
+        if self.serves(ctx)
+            && let Ok(mut state) = self.state.lock()
+            && let Some(idx) = state.take(&ctx.request_id)
+        {
+            state.keys[idx].successes += 1;
+        }
+        Ok(())
+    }
+
+    fn on_error(&self, ctx: &HttpRequestContext, error: &LlmError) {
+        if self.serves(ctx) {
+            self.record_failure(ctx, error);
+        }
+    }
+
+    fn on_retry(&self, ctx: &HttpRequestContext, error: &LlmError, _attempt: usize) {
+        // Executors retry a 401 once with rebuilt headers; quarantine the key first so the retry
+        // rotates. Other failures were already recorded by `on_error`.
+        if self.serves(ctx) && matches!(classify(error), Failure::Auth) {
+            self.record_failure(ctx, error);
+        }
+    }
+}
+
+enum Failure {
+    Auth,
+    RateLimit,
+    Quota,
+    Other,
+}
+
+fn classify(error: &LlmError) -> Failure {
+    use crate::error::LlmErrorExt;
+
+    match error {
+        LlmError::QuotaExceededError { .. } => Failure::Quota,
+        // Synthetic error passed to `on_retry` by the executors' single 401 retry.
+        LlmError::HttpError(msg) if msg.starts_with("401") => Failure::Auth,
+        e if e.is_auth_error() => Failure::Auth,
+        e if e.is_rate_limit_error() => Failure::RateLimit,
+        _ => Failure::Other,
+    }
+}
+
+fn default_label(key: &str) -> String {
+    let tail: String = key
+        .chars()
+        .rev()
+        .take(4)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    format!("...{tail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx_for(provider_id: &str, request_id: &str) -> HttpRequestContext {
+        HttpRequestContext {
+            request_id: request_id.to_string(),
+            provider_id: provider_id.to_string(),
+            url: "https://example.com/v1/chat".to_string(),
+            stream: false,
+        }
+    }
+
+    fn ctx(request_id: &str) -> HttpRequestContext {
+        ctx_for("test", request_id)
+    }
+
+    fn key_for(pool: &ApiKeyPool, request_id: &str) -> Result<String, LlmError> {
+        Ok(pool
+            .api_key_for(&ctx(request_id))?
+            .expect("pool supplies a key"))
+    }
+
+    #[test]
+    fn round_robin_rotates_keys() {
+        let pool = ApiKeyPool::new(["key-aaaa", "key-bbbb", "key-cccc"]);
+        assert_eq!(pool.primary_key().as_deref(), Some("key-aaaa"));
+
+        let used: Vec<_> = (0..4)
+            .map(|i| key_for(&pool, &format!("r{i}")).unwrap())
+            .collect();
+        assert_eq!(used, vec!["key-aaaa", "key-bbbb", "key-cccc", "key-aaaa"]);
+        assert_eq!(pool.usage()[0].requests, 2);
+        assert_eq!(pool.usage()[0].label, "...aaaa");
+    }
+
+    #[test]
+    fn quarantines_keys_after_auth_and_rate_limit_failures() {
+        let pool = ApiKeyPool::new(["key-aaaa", "key-bbbb", "key-cccc"]);
+
+        assert_eq!(key_for(&pool, "r1").unwrap(), "key-aaaa");
+        pool.on_retry(
+            &ctx("r1"),
+            &LlmError::HttpError("401 Unauthorized".into()),
+            1,
+        );
+        assert_eq!(key_for(&pool, "r1").unwrap(), "key-bbbb");
+        pool.on_error(&ctx("r1"), &LlmError::rate_limit_error("slow down"));
+        assert_eq!(key_for(&pool, "r1").unwrap(), "key-cccc");
+
+        let usage = pool.usage();
+        assert!(usage[0].disabled);
+        assert_eq!(usage[0].auth_failures, 1);
+        assert_eq!(usage[1].rate_limited, 1);
+        assert!(usage[1].cooldown_remaining.is_some());
+
+        // Only key-cccc is healthy.
+        assert_eq!(key_for(&pool, "r2").unwrap(), "key-cccc");
+        pool.on_error(&ctx("r2"), &LlmError::quota_exceeded_error("empty"));
+        assert!(matches!(
+            key_for(&pool, "r3"),
+            Err(LlmError::RateLimitError { .. })
+        ));
+
+        assert!(pool.reinstate("...aaaa"));
+        assert_eq!(key_for(&pool, "r4").unwrap(), "key-aaaa");
+    }
+
+    #[test]
+    fn least_recently_limited_prefers_never_limited_keys() {
+        let pool = ApiKeyPool::new(["key-aaaa", "key-bbbb"])
+            .with_strategy(KeyRotationStrategy::LeastRecentlyLimited)
+            .with_rate_limit_cooldown(Duration::ZERO);
+
+        assert_eq!(key_for(&pool, "r1").unwrap(), "key-aaaa");
+        pool.on_error(&ctx("r1"), &LlmError::rate_limit_error("slow down"));
+        // key-aaaa is available again (zero cooldown) but was limited recently.
+        assert_eq!(key_for(&pool, "r2").unwrap(), "key-bbbb");
+        assert_eq!(key_for(&pool, "r3").unwrap(), "key-bbbb");
+    }
+
+    #[test]
+    fn bound_pool_ignores_other_providers() {
+        let pool = ApiKeyPool::new(["key-aaaa", "key-bbbb"]).for_provider("acme-chat");
+
+        assert_eq!(pool.api_key_for(&ctx_for("other", "r1")).unwrap(), None);
+        pool.on_error(
+            &ctx_for("other", "r1"),
+            &LlmError::AuthenticationError("bad key".into()),
+        );
+        assert_eq!(pool.usage()[0].requests, 0);
+        assert!(!pool.usage()[0].disabled);
+
+        // The variant id also covers its family's requests, but not look-alike ids.
+        assert_eq!(
+            pool.api_key_for(&ctx_for("acme", "r2")).unwrap().as_deref(),
+            Some("key-aaaa")
+        );
+        assert_eq!(
+            pool.api_key_for(&ctx_for("acme-chat", "r3"))
+                .unwrap()
+                .as_deref(),
+            Some("key-bbbb")
+        );
+        assert_eq!(pool.api_key_for(&ctx_for("acm", "r4")).unwrap(), None);
+    }
+}
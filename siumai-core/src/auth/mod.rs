@@ -4,6 +4,10 @@
 use crate::error::LlmError;
 use async_trait::async_trait;
 
+pub mod key_pool;
+
+pub use key_pool::{ApiKeyPool, ApiKeyUsage, KeyRotationStrategy};
+
 /// An async Bearer token provider.
 ///
 /// Notes:
@@ -137,6 +137,14 @@ pub trait LlmClient: Send + Sync {
         None
     }
 
+    /// Get as voice management capability if supported
+    ///
+    /// Returns None by default. Providers that can list or manage TTS voices
+    /// should override this method to return Some(self).
+    fn as_voice_management_capability(&self) -> Option<&dyn VoiceManagementCapability> {
+        None
+    }
+
     /// Get as moderation capability if supported
     ///
     /// Returns None by default. Providers that support moderation
@@ -419,6 +427,10 @@ impl LlmClient for ClientWrapper {
         self.client().as_skills_capability()
     }
 
+    fn as_voice_management_capability(&self) -> Option<&dyn VoiceManagementCapability> {
+        self.client().as_voice_management_capability()
+    }
+
     fn as_moderation_capability(&self) -> Option<&dyn ModerationCapability> {
         self.client().as_moderation_capability()
     }
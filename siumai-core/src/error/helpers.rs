@@ -103,7 +103,9 @@ pub fn summarize_error(
     let raw = RawInfo {
         message: Some(message.clone()),
         body: extract_details(err),
-        headers: None,
+        headers: err
+            .http_details()
+            .and_then(|details| serde_json::to_value(&details.headers).ok()),
     };
     let diagnosis = Diagnosis {
         note: diagnosis_note(err),
@@ -137,10 +139,12 @@ pub fn map_error_kind(err: &LlmError) -> ErrorKind {
                 ErrorKind::Client
             }
         }
-        ErrorCategory::Server => ErrorKind::Server,
+        ErrorCategory::Server | ErrorCategory::Overloaded => ErrorKind::Server,
         ErrorCategory::Network => ErrorKind::Network,
         ErrorCategory::Parsing => ErrorKind::Parsing,
-        ErrorCategory::Validation => ErrorKind::Validation,
+        ErrorCategory::Validation | ErrorCategory::ContextLength | ErrorCategory::ContentFilter => {
+            ErrorKind::Validation
+        }
         ErrorCategory::Unsupported => ErrorKind::Unsupported,
         _ => ErrorKind::Unknown,
     }
@@ -151,8 +155,11 @@ fn extract_raw_message(err: &LlmError) -> String {
     match err {
         LlmError::ApiError { message, .. } => message.clone(),
         LlmError::AuthenticationError(msg)
-        | LlmError::RateLimitError(msg)
-        | LlmError::QuotaExceededError(msg)
+        | LlmError::RateLimitError { message: msg, .. }
+        | LlmError::QuotaExceededError { message: msg, .. }
+        | LlmError::ContextLengthExceeded { message: msg, .. }
+        | LlmError::ContentFiltered { message: msg, .. }
+        | LlmError::Overloaded { message: msg, .. }
         | LlmError::TimeoutError(msg)
         | LlmError::ConnectionError(msg)
         | LlmError::ParseError(msg)
@@ -184,6 +191,15 @@ pub fn suggest_fixes(err: &LlmError, provider_id: Option<&str>) -> Vec<String> {
         ErrorCategory::Server => {
             tips.push("Retry with backoff; check provider status page".to_string());
         }
+        ErrorCategory::ContextLength => {
+            tips.push("Trim conversation history or switch to a larger-context model".to_string());
+        }
+        ErrorCategory::ContentFilter => {
+            tips.push("Rephrase the request; the provider's content filter blocked it".to_string());
+        }
+        ErrorCategory::Overloaded => {
+            tips.push("Retry with backoff or fall back to another model".to_string());
+        }
         ErrorCategory::Parsing => {
             tips.push("Verify response format or disable strict parsing".to_string());
         }
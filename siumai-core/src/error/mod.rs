@@ -4,6 +4,8 @@
 
 pub mod helpers;
 pub mod policy;
+pub mod taxonomy;
 pub use helpers::*;
 pub use policy::*;
 pub use siumai_spec::error::*;
+pub use taxonomy::*;
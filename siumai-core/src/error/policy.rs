@@ -15,6 +15,12 @@ pub enum ErrorCategory {
     Authentication,
     /// Rate limiting and quota errors.
     RateLimit,
+    /// The prompt does not fit the model's context window.
+    ContextLength,
+    /// The provider's safety or content filters blocked the request or response.
+    ContentFilter,
+    /// The provider is temporarily over capacity.
+    Overloaded,
     /// Client-side errors (4xx HTTP status codes).
     Client,
     /// Server-side errors (5xx HTTP status codes).
@@ -89,7 +95,10 @@ impl LlmErrorExt for LlmError {
                     .any(|keyword| lower.contains(keyword))
             }
             Self::ApiError { code, .. } => matches!(*code, 408 | 429 | 500..=599),
-            Self::RateLimitError(_) | Self::TimeoutError(_) | Self::ConnectionError(_) => true,
+            Self::RateLimitError { .. }
+            | Self::Overloaded { .. }
+            | Self::TimeoutError(_)
+            | Self::ConnectionError(_) => true,
             Self::ContextualError {
                 source_error: Some(source),
                 ..
@@ -108,7 +117,7 @@ impl LlmErrorExt for LlmError {
 
     fn is_rate_limit_error(&self) -> bool {
         match self {
-            Self::RateLimitError(_) => true,
+            Self::RateLimitError { .. } => true,
             Self::ApiError { code, .. } => *code == 429,
             _ => false,
         }
@@ -117,7 +126,7 @@ impl LlmErrorExt for LlmError {
     fn status_code(&self) -> Option<u16> {
         match self {
             Self::ApiError { code, .. } => Some(*code),
-            _ => None,
+            _ => self.http_details().and_then(|details| details.status),
         }
     }
 
@@ -127,7 +136,12 @@ impl LlmErrorExt for LlmError {
                 ErrorCategory::Network
             }
             Self::AuthenticationError(_) | Self::MissingApiKey(_) => ErrorCategory::Authentication,
-            Self::RateLimitError(_) | Self::QuotaExceededError(_) => ErrorCategory::RateLimit,
+            Self::RateLimitError { .. } | Self::QuotaExceededError { .. } => {
+                ErrorCategory::RateLimit
+            }
+            Self::ContextLengthExceeded { .. } => ErrorCategory::ContextLength,
+            Self::ContentFiltered { .. } => ErrorCategory::ContentFilter,
+            Self::Overloaded { .. } => ErrorCategory::Overloaded,
             Self::ApiError { code, .. } => match *code {
                 429 => ErrorCategory::RateLimit,
                 400..=499 => ErrorCategory::Client,
@@ -137,9 +151,10 @@ impl LlmErrorExt for LlmError {
             Self::JsonError(_) | Self::ParseError(_) | Self::NoObjectGenerated { .. } => {
                 ErrorCategory::Parsing
             }
-            Self::InvalidInput(_) | Self::InvalidParameter(_) | Self::ToolValidationError(_) => {
-                ErrorCategory::Validation
-            }
+            Self::InvalidInput(_)
+            | Self::InvalidParameter(_)
+            | Self::ToolValidationError(_)
+            | Self::GuardrailViolation { .. } => ErrorCategory::Validation,
             Self::ConfigurationError(_) => ErrorCategory::Configuration,
             Self::ModelNotSupported(_)
             | Self::UnsupportedOperation(_)
@@ -160,12 +175,21 @@ impl LlmErrorExt for LlmError {
             Self::AuthenticationError(_) | Self::MissingApiKey(_) => {
                 "Authentication failed. Please check your API key.".to_string()
             }
-            Self::RateLimitError(_) => {
+            Self::RateLimitError { .. } => {
                 "Rate limit exceeded. Please wait before making more requests.".to_string()
             }
-            Self::QuotaExceededError(_) => {
+            Self::QuotaExceededError { .. } => {
                 "API quota exceeded. Please check your usage limits.".to_string()
             }
+            Self::ContextLengthExceeded { .. } => {
+                "The request is too long for the model's context window.".to_string()
+            }
+            Self::ContentFiltered { .. } => {
+                "The provider's content filter blocked this request.".to_string()
+            }
+            Self::Overloaded { .. } => {
+                "The provider is overloaded. Please try again shortly.".to_string()
+            }
             Self::ModelNotSupported(model) => {
                 format!("The model '{model}' is not supported by this provider.")
             }
@@ -193,6 +217,12 @@ impl LlmErrorExt for LlmError {
                 "The provider completed the structured-output request but returned no valid object."
                     .to_string()
             }
+            Self::GuardrailViolation { stage, .. } if stage == "input" => {
+                "The request was blocked by a content policy.".to_string()
+            }
+            Self::GuardrailViolation { .. } => {
+                "The response was blocked by a content policy.".to_string()
+            }
             _ => self.to_string(),
         }
     }
@@ -208,7 +238,7 @@ impl LlmErrorExt for LlmError {
                     "Verify you're using the correct API endpoint".to_string(),
                 ]
             }
-            Self::RateLimitError(_) => {
+            Self::RateLimitError { .. } => {
                 vec![
                     "Implement exponential backoff with jitter".to_string(),
                     "Reduce request frequency".to_string(),
@@ -216,7 +246,7 @@ impl LlmErrorExt for LlmError {
                     "Use request batching if supported".to_string(),
                 ]
             }
-            Self::QuotaExceededError(_) => {
+            Self::QuotaExceededError { .. } => {
                 vec![
                     "Check your usage dashboard for current consumption".to_string(),
                     "Upgrade your API plan for higher quotas".to_string(),
@@ -224,6 +254,25 @@ impl LlmErrorExt for LlmError {
                     "Optimize your requests to use fewer tokens".to_string(),
                 ]
             }
+            Self::ContextLengthExceeded { .. } => {
+                vec![
+                    "Trim or summarize earlier conversation turns".to_string(),
+                    "Reduce attached documents, tool results or max output tokens".to_string(),
+                    "Switch to a model with a larger context window".to_string(),
+                ]
+            }
+            Self::ContentFiltered { .. } => {
+                vec![
+                    "Rephrase the prompt to avoid content the provider's policy blocks".to_string(),
+                    "Inspect the provider code on the error for the filter that fired".to_string(),
+                ]
+            }
+            Self::Overloaded { .. } => {
+                vec![
+                    "Retry the request with exponential backoff".to_string(),
+                    "Fall back to another model or provider while capacity recovers".to_string(),
+                ]
+            }
             Self::ConnectionError(_) | Self::TimeoutError(_) => {
                 vec![
                     "Check your internet connection stability".to_string(),
@@ -310,6 +359,12 @@ impl LlmErrorExt for LlmError {
                         .to_string(),
                 ]
             }
+            Self::GuardrailViolation { .. } => {
+                vec![
+                    "Inspect the policy name and flagged categories on the error".to_string(),
+                    "Rephrase the request or relax the guardrail policy if the block is a false positive".to_string(),
+                ]
+            }
             _ => vec![
                 "Check the error details and documentation".to_string(),
                 "Contact support if the issue persists".to_string(),
@@ -319,7 +374,16 @@ impl LlmErrorExt for LlmError {
 
     fn recommended_retry_delay(&self) -> Option<u64> {
         match self {
-            Self::RateLimitError(_) => Some(60),
+            Self::RateLimitError { .. } => Some(
+                self.retry_after()
+                    .map(|delay| delay.as_secs_f64().ceil() as u64)
+                    .unwrap_or(60),
+            ),
+            Self::Overloaded { .. } => Some(
+                self.retry_after()
+                    .map(|delay| delay.as_secs_f64().ceil() as u64)
+                    .unwrap_or(5),
+            ),
             Self::ApiError { code: 429, .. } => Some(30),
             Self::ApiError {
                 code: 500..=599, ..
@@ -332,7 +396,8 @@ impl LlmErrorExt for LlmError {
 
     fn max_retry_attempts(&self) -> u32 {
         match self {
-            Self::RateLimitError(_) => 3,
+            Self::RateLimitError { .. } => 3,
+            Self::Overloaded { .. } => 5,
             Self::ApiError { code: 429, .. } => 3,
             Self::ApiError {
                 code: 500..=599, ..
@@ -442,4 +507,21 @@ mod tests {
                 .any(|tip| tip.contains("empty text"))
         );
     }
+
+    #[test]
+    fn guardrail_violation_is_a_non_retryable_validation_error() {
+        let error = LlmError::GuardrailViolation {
+            stage: "input".to_string(),
+            policy: "moderation".to_string(),
+            message: "flagged".to_string(),
+            categories: vec!["violence".to_string()],
+        };
+
+        assert_eq!(error.category(), ErrorCategory::Validation);
+        assert!(!error.is_retryable());
+        assert_eq!(
+            error.user_message(),
+            "The request was blocked by a content policy."
+        );
+    }
 }
@@ -0,0 +1,149 @@
+//! Cross-provider failure taxonomy.
+//!
+//! Providers report "prompt too long", "blocked by a content filter" and "over capacity" with
+//! different status codes, error codes and wording. Provider error mappers call
+//! [`normalize_provider_failure`] with what they extracted from the error body so those failures
+//! surface as `LlmError::ContextLengthExceeded`, `LlmError::ContentFiltered` and
+//! `LlmError::Overloaded` instead of opaque `ApiError`/`InvalidInput` strings. Mappers handle
+//! provider-specific codes themselves and rely on this module for the shared vocabulary.
+
+use crate::error::LlmError;
+
+/// Error codes/types that mean the prompt does not fit the context window.
+const CONTEXT_LENGTH_CODES: &[&str] = &[
+    "context_length_exceeded",
+    "context_window_exceeded",
+    "model_context_window_exceeded",
+];
+
+/// Message fragments that mean the prompt does not fit the context window.
+const CONTEXT_LENGTH_PHRASES: &[&str] = &[
+    "maximum context length",
+    "context length exceeded",
+    "context window",
+    "prompt is too long",
+    "input is too long",
+    "too many input tokens",
+    "too many tokens",
+    "exceeds the maximum number of tokens",
+    "exceeds the context",
+];
+
+/// Error codes/types that mean a safety or content filter blocked the request.
+const CONTENT_FILTER_CODES: &[&str] = &[
+    "content_filter",
+    "content_policy_violation",
+    "responsibleaipolicyviolation",
+    "content_filtered",
+];
+
+/// Message fragments that mean a safety or content filter blocked the request.
+const CONTENT_FILTER_PHRASES: &[&str] = &[
+    "content management policy",
+    "content filter",
+    "content policy",
+    "safety system",
+];
+
+/// Error codes/types that mean the provider is over capacity.
+const OVERLOADED_CODES: &[&str] = &[
+    "overloaded_error",
+    "overloaded",
+    "server_overloaded",
+    "engine_overloaded",
+];
+
+/// Message fragments that mean the provider is over capacity.
+const OVERLOADED_PHRASES: &[&str] = &["overloaded", "over capacity", "at capacity", "server busy"];
+
+/// Map a provider failure onto a normalized error kind.
+///
+/// `codes` are the structured error code/type fields the mapper extracted (missing ones may be
+/// passed as `None`); `message` is the provider's error message or raw body. Structured codes
+/// win over message heuristics, and message heuristics only apply to the status ranges where the
+/// failure kind is plausible (4xx for context length and content filters, 5xx for overload), so
+/// a rate-limit message mentioning tokens is never mistaken for a context-length failure.
+///
+/// Returns `None` when the failure does not belong to one of the normalized kinds; callers then
+/// keep their usual status-based mapping.
+pub fn normalize_provider_failure(
+    status: u16,
+    codes: &[Option<&str>],
+    message: &str,
+) -> Option<LlmError> {
+    let codes: Vec<String> = codes
+        .iter()
+        .flatten()
+        .map(|code| code.trim().to_ascii_lowercase())
+        .filter(|code| !code.is_empty())
+        .collect();
+    let has_code = |known: &[&str]| codes.iter().any(|code| known.contains(&code.as_str()));
+    let lower = message.to_lowercase();
+    let mentions = |phrases: &[&str]| phrases.iter().any(|phrase| lower.contains(phrase));
+    let client_error = (400..=499).contains(&status) && status != 429;
+
+    if has_code(CONTEXT_LENGTH_CODES) || (client_error && mentions(CONTEXT_LENGTH_PHRASES)) {
+        return Some(LlmError::context_length_exceeded(message));
+    }
+    if has_code(CONTENT_FILTER_CODES) || (client_error && mentions(CONTENT_FILTER_PHRASES)) {
+        return Some(LlmError::content_filtered(message));
+    }
+    if status == 529
+        || has_code(OVERLOADED_CODES)
+        || ((500..=599).contains(&status) && mentions(OVERLOADED_PHRASES))
+    {
+        return Some(LlmError::overloaded(message));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn structured_codes_win_regardless_of_status() {
+        let err = normalize_provider_failure(400, &[Some("context_length_exceeded")], "bad")
+            .expect("normalized");
+        assert!(matches!(err, LlmError::ContextLengthExceeded { .. }));
+
+        let err = normalize_provider_failure(400, &[None, Some("Content_Filter")], "filtered")
+            .expect("normalized");
+        assert!(matches!(err, LlmError::ContentFiltered { .. }));
+
+        let err = normalize_provider_failure(200, &[Some("overloaded_error")], "Overloaded")
+            .expect("normalized");
+        assert!(matches!(err, LlmError::Overloaded { .. }));
+    }
+
+    #[test]
+    fn message_heuristics_respect_status_ranges() {
+        let err = normalize_provider_failure(
+            400,
+            &[],
+            "The input token count (1200000) exceeds the maximum number of tokens allowed (1048576).",
+        )
+        .expect("normalized");
+        assert!(matches!(err, LlmError::ContextLengthExceeded { .. }));
+
+        // Token-per-minute rate limits mention tokens but are not context-length failures.
+        assert!(
+            normalize_provider_failure(429, &[], "Too many tokens per minute, slow down").is_none()
+        );
+
+        let err = normalize_provider_failure(503, &[], "The server is currently overloaded")
+            .expect("normalized");
+        assert!(matches!(err, LlmError::Overloaded { .. }));
+        assert!(normalize_provider_failure(400, &[], "model overloaded with options").is_none());
+
+        let err = normalize_provider_failure(529, &[], "").expect("normalized");
+        assert!(matches!(err, LlmError::Overloaded { .. }));
+    }
+
+    #[test]
+    fn unrelated_failures_are_left_to_the_caller() {
+        assert!(normalize_provider_failure(400, &[Some("invalid_request_error")], "bad").is_none());
+        assert!(normalize_provider_failure(503, &[], "service unavailable").is_none());
+        assert!(normalize_provider_failure(403, &[], "quota exceeded").is_none());
+    }
+}
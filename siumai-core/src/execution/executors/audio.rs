@@ -176,6 +176,7 @@ impl AudioExecutor for HttpAudioExecutor {
 
         let base_url = self.provider_spec.audio_base_url(&self.provider_context);
         let url = crate::utils::url::join_url(&base_url, self.transformer.tts_endpoint());
+        let retry_url = url.clone();
 
         let provider_id = self.provider_id.clone();
         let http_client = self.http_client.clone();
@@ -291,7 +292,14 @@ impl AudioExecutor for HttpAudioExecutor {
         };
 
         if let Some(opts) = retry_wrapper_opts {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &retry_url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
@@ -307,6 +315,7 @@ impl AudioExecutor for HttpAudioExecutor {
 
         let base_url = self.provider_spec.audio_base_url(&self.provider_context);
         let url = crate::utils::url::join_url(&base_url, self.transformer.stt_endpoint());
+        let retry_url = url.clone();
 
         let provider_id = self.provider_id.clone();
         let http_client = self.http_client.clone();
@@ -401,7 +410,14 @@ impl AudioExecutor for HttpAudioExecutor {
         };
 
         if let Some(opts) = retry_wrapper_opts {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &retry_url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
@@ -118,7 +118,6 @@ pub(super) async fn create_sse_stream_with_middlewares(
     provider_context: crate::core::ProviderContext,
     url: String,
     http: reqwest::Client,
-    headers_base: reqwest::header::HeaderMap,
     transformed: serde_json::Value,
     sse_tx: Arc<dyn crate::execution::transformers::stream::StreamChunkTransformer>,
     transport: Option<Arc<dyn HttpTransport>>,
@@ -135,15 +134,22 @@ pub(super) async fn create_sse_stream_with_middlewares(
         req: req_in.clone(),
         convert: converter,
     };
-    let request_id = crate::execution::http::interceptor::generate_request_id();
+    let ctx = crate::execution::http::interceptor::HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: provider_id.clone(),
+        url: url.clone(),
+        stream: true,
+    };
+    let headers_base = crate::execution::executors::common::build_request_headers(
+        provider_spec.as_ref(),
+        &provider_context,
+        &interceptors,
+        &ctx,
+    )?;
+    let request_id = ctx.request_id.clone();
     let intercepting = InterceptingConverter {
         interceptors: interceptors.clone(),
-        ctx: crate::execution::http::interceptor::HttpRequestContext {
-            request_id: request_id.clone(),
-            provider_id: provider_id.clone(),
-            url: url.clone(),
-            stream: true,
-        },
+        ctx,
         convert: mw_wrapped,
     };
 
@@ -174,7 +180,6 @@ pub(super) async fn create_json_stream_with_middlewares(
     provider_context: crate::core::ProviderContext,
     url: String,
     http: reqwest::Client,
-    headers_base: reqwest::header::HeaderMap,
     transformed: serde_json::Value,
     json_conv: Arc<dyn crate::streaming::JsonEventConverter>,
     transport: Option<Arc<dyn HttpTransport>>,
@@ -191,12 +196,25 @@ pub(super) async fn create_json_stream_with_middlewares(
     };
     let per_request_http_config =
         build_effective_chat_request_http_config(&provider_spec, &provider_context, true, &req_in);
+    let ctx = crate::execution::http::interceptor::HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: provider_id.clone(),
+        url: url.clone(),
+        stream: true,
+    };
+    let headers_base = crate::execution::executors::common::build_request_headers(
+        provider_spec.as_ref(),
+        &provider_context,
+        &interceptors,
+        &ctx,
+    )?;
     let stream =
         crate::execution::executors::stream_json::execute_json_stream_request_with_headers(
             &http,
             &provider_id,
             Some(provider_spec.as_ref()),
             &url,
+            ctx.request_id,
             headers_base,
             transformed,
             &interceptors,
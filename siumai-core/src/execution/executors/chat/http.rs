@@ -156,6 +156,9 @@ impl ChatExecutor for HttpChatExecutor {
             Box::pin({
                 async move {
                     let retry_wrapper_opts = retry_options.clone();
+                    let retry_url = url.clone();
+                    let retry_provider_id = provider_id.clone();
+                    let retry_interceptors = interceptors.clone();
                     let run_once = move || {
                         let req_in = req_in.clone();
                         let url = url.clone();
@@ -224,7 +227,14 @@ impl ChatExecutor for HttpChatExecutor {
                     };
 
                     if let Some(opts) = retry_wrapper_opts {
-                        crate::retry_api::retry_with(run_once, opts).await
+                        crate::execution::executors::common::retry_with_interceptors(
+                            run_once,
+                            opts,
+                            &retry_provider_id,
+                            &retry_url,
+                            &retry_interceptors,
+                        )
+                        .await
                     } else {
                         run_once().await
                     }
@@ -333,7 +343,6 @@ impl ChatExecutor for HttpChatExecutor {
         let url = self
             .provider_spec
             .try_chat_url(true, &req, &self.provider_context)?;
-        let headers_base = self.provider_spec.build_headers(&self.provider_context)?;
         let disable_compression = self.policy.stream_disable_compression;
         let middlewares = self.middlewares.clone();
         let provider_spec = self.provider_spec.clone();
@@ -351,7 +360,6 @@ impl ChatExecutor for HttpChatExecutor {
             let transport = transport.clone();
             let before_send = before_send.clone();
             let url = url.clone();
-            let headers_base = headers_base.clone();
             let middlewares = middlewares.clone();
             let provider_spec = provider_spec.clone();
             let provider_context = provider_context.clone();
@@ -376,7 +384,6 @@ impl ChatExecutor for HttpChatExecutor {
                         provider_context.clone(),
                         url.clone(),
                         http.clone(),
-                        headers_base.clone(),
                         transformed.clone(),
                         stream_tx.clone(),
                         transport.clone(),
@@ -394,7 +401,6 @@ impl ChatExecutor for HttpChatExecutor {
                         provider_context.clone(),
                         url.clone(),
                         http.clone(),
-                        headers_base.clone(),
                         transformed.clone(),
                         jsonc.clone(),
                         transport.clone(),
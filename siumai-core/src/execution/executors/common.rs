@@ -22,7 +22,7 @@
 
 use crate::core::{ProviderContext, ProviderSpec};
 use crate::error::LlmError;
-use crate::execution::http::interceptor::HttpInterceptor;
+use crate::execution::http::interceptor::{HttpInterceptor, HttpRequestContext};
 use crate::execution::http::transport::HttpTransport;
 use crate::retry_api::RetryOptions;
 use reqwest::header::HeaderMap;
@@ -56,6 +56,39 @@ pub struct HttpExecutionConfig {
     pub retry_options: Option<RetryOptions>,
 }
 
+impl HttpExecutionConfig {
+    /// Build the provider headers for one attempt of the request described by `ctx`.
+    pub fn build_headers_for(&self, ctx: &HttpRequestContext) -> Result<HeaderMap, LlmError> {
+        build_request_headers(
+            self.provider_spec.as_ref(),
+            &self.provider_context,
+            &self.interceptors,
+            ctx,
+        )
+    }
+}
+
+/// Build the provider headers for one attempt of a request.
+///
+/// An interceptor that supplies an API key for `ctx` (see [`HttpInterceptor::api_key_for`])
+/// replaces `provider_context.api_key` before `build_headers` runs, so the provider emits its
+/// usual auth header with that key.
+pub fn build_request_headers(
+    provider_spec: &dyn ProviderSpec,
+    provider_context: &ProviderContext,
+    interceptors: &[Arc<dyn HttpInterceptor>],
+    ctx: &HttpRequestContext,
+) -> Result<HeaderMap, LlmError> {
+    for interceptor in interceptors {
+        if let Some(api_key) = interceptor.api_key_for(ctx)? {
+            let mut provider_context = provider_context.clone();
+            provider_context.api_key = Some(api_key);
+            return provider_spec.build_headers(&provider_context);
+        }
+    }
+    provider_spec.build_headers(provider_context)
+}
+
 /// Result of HTTP request execution
 #[derive(Debug)]
 pub struct HttpExecutionResult {
@@ -240,6 +273,36 @@ where
 
 // unit tests migrated to integration tests in tests/http_common_retry_401.rs
 
+/// Run `operation` under `options`, notifying `HttpInterceptor::on_retry` before each retry.
+///
+/// The error passed to `on_retry` is the classified failure, so interceptors can read
+/// `LlmError::http_details` (status, request id, retry-after) for rate limit and quota errors.
+pub async fn retry_with_interceptors<F, Fut, T>(
+    operation: F,
+    options: RetryOptions,
+    provider_id: &str,
+    url: &str,
+    interceptors: &[Arc<dyn HttpInterceptor>],
+) -> Result<T, LlmError>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: std::future::Future<Output = Result<T, LlmError>> + Send,
+    T: Send,
+{
+    let ctx = crate::execution::http::interceptor::HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: provider_id.to_string(),
+        url: url.to_string(),
+        stream: false,
+    };
+    crate::retry_api::retry_with_notify(operation, options, |error, attempt, _delay| {
+        for interceptor in interceptors {
+            interceptor.on_retry(&ctx, error, attempt);
+        }
+    })
+    .await
+}
+
 /// Execute an HTTP request (JSON or Multipart) with unified retry, interceptors, and error handling
 pub async fn execute_request(
     config: &HttpExecutionConfig,
@@ -496,3 +559,25 @@ pub async fn execute_get_binary(
     )
     .await
 }
+
+/// Send a raw-body request and return the successful response unread.
+///
+/// See [`crate::execution::executors::http_request::execute_raw_request`].
+pub async fn execute_raw_request(
+    config: &HttpExecutionConfig,
+    method: reqwest::Method,
+    url: &str,
+    extra_headers: HeaderMap,
+    body: Option<bytes::Bytes>,
+    per_request_http_config: Option<&crate::types::HttpConfig>,
+) -> Result<reqwest::Response, LlmError> {
+    crate::execution::executors::http_request::execute_raw_request(
+        config,
+        method,
+        url,
+        extra_headers,
+        body,
+        per_request_http_config,
+    )
+    .await
+}
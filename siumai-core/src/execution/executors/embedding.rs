@@ -34,8 +34,13 @@ impl EmbeddingExecutor for HttpEmbeddingExecutor {
             ));
         }
         let retry_options = self.policy.retry_options.clone();
-        let run_once = move || {
+        // Resolve the URL once; retries reuse it and report it to `on_retry`.
+        let url = self
+            .provider_spec
+            .try_embedding_url(&req, &self.provider_context)?;
+        let run_once = || {
             let req = req.clone();
+            let url = url.clone();
             async move {
                 // 1. Transform request to JSON
                 let mut body = self.request_transformer.transform_embedding(&req)?;
@@ -51,12 +56,7 @@ impl EmbeddingExecutor for HttpEmbeddingExecutor {
                     body = cb(&body)?;
                 }
 
-                // 3. Get URL from provider spec
-                let url = self
-                    .provider_spec
-                    .try_embedding_url(&req, &self.provider_context)?;
-
-                // 4. Build execution config for common HTTP layer
+                // 3. Build execution config for common HTTP layer
                 let config = crate::execution::executors::common::HttpExecutionConfig {
                     provider_id: self.provider_id.clone(),
                     http_client: self.http_client.clone(),
@@ -67,7 +67,7 @@ impl EmbeddingExecutor for HttpEmbeddingExecutor {
                     retry_options: self.policy.retry_options.clone(),
                 };
 
-                // 5. Execute request using common HTTP layer
+                // 4. Execute request using common HTTP layer
                 let result = crate::execution::executors::common::execute_json_request(
                     &config,
                     &url,
@@ -77,12 +77,12 @@ impl EmbeddingExecutor for HttpEmbeddingExecutor {
                 )
                 .await?;
 
-                // 6. Transform response
+                // 5. Transform response
                 let mut out = self
                     .response_transformer
                     .transform_embedding_response(&result.json)?;
 
-                // 7. Attach HTTP response envelope (Vercel-style `response.headers` parity).
+                // 6. Attach HTTP response envelope (Vercel-style `response.headers` parity).
                 out.response = Some(crate::types::HttpResponseInfo {
                     timestamp: chrono::Utc::now(),
                     model_id: req.model.clone().or_else(|| Some(out.model.clone())),
@@ -95,7 +95,14 @@ impl EmbeddingExecutor for HttpEmbeddingExecutor {
         };
 
         if let Some(opts) = retry_options {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
@@ -386,6 +393,77 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn rate_limit_retries_report_http_details_to_interceptors() {
+        struct CaptureRetry {
+            seen: Arc<Mutex<Vec<(usize, LlmError)>>>,
+        }
+        impl HttpInterceptor for CaptureRetry {
+            fn on_retry(&self, _ctx: &HttpRequestContext, error: &LlmError, attempt: usize) {
+                self.seen.lock().unwrap().push((attempt, error.clone()));
+            }
+        }
+
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("POST", "/embeddings")
+            .with_status(429)
+            .with_header("retry-after-ms", "5")
+            .with_header("x-request-id", "req_1")
+            .with_body(r#"{"error":{"message":"slow down","code":"rate_limit_exceeded"}}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let retry = crate::retry_api::RetryOptions {
+            policy: Some(
+                crate::retry::RetryPolicy::new()
+                    .with_max_attempts(2)
+                    .with_jitter(false),
+            ),
+            ..crate::retry_api::RetryOptions::policy_default()
+        };
+        let exec = HttpEmbeddingExecutor {
+            provider_id: "test".into(),
+            http_client: reqwest::Client::new(),
+            request_transformer: Arc::new(EchoReq),
+            response_transformer: Arc::new(NoopResp),
+            provider_spec: Arc::new(TestSpec),
+            provider_context: crate::core::ProviderContext::new(
+                "test",
+                server.url(),
+                None,
+                Default::default(),
+            ),
+            policy: crate::execution::ExecutionPolicy::new()
+                .with_interceptors(vec![Arc::new(CaptureRetry { seen: seen.clone() })])
+                .with_retry_options(Some(retry)),
+        };
+
+        let err = exec
+            .execute(crate::types::EmbeddingRequest::new(vec!["hi".into()]).with_model("m"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::RateLimitError { .. }));
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen.len(), 1);
+        let (attempt, error) = &seen[0];
+        assert_eq!(*attempt, 1);
+        let details = error.http_details().expect("details");
+        assert_eq!(details.status, Some(429));
+        assert_eq!(details.request_id.as_deref(), Some("req_1"));
+        assert_eq!(
+            details.provider_code.as_deref(),
+            Some("rate_limit_exceeded")
+        );
+        assert_eq!(
+            details.retry_after,
+            Some(std::time::Duration::from_millis(5))
+        );
+    }
+
     #[tokio::test]
     async fn spec_before_send_runs_before_policy_hook() {
         // Spec that sets mark:"spec" via embedding_before_send
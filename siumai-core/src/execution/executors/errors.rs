@@ -10,7 +10,8 @@ use reqwest::header::HeaderMap;
 use std::sync::Arc;
 
 /// Classify an HTTP failure using the provider hook if present, otherwise fall back
-/// to the generic `retry_api::classify_http_error`.
+/// to the generic `retry_api::classify_http_error`. Rate limit and quota errors get
+/// structured `HttpErrorDetails` either way.
 pub fn classify_http_error(
     provider_id: &str,
     provider_spec: Option<&dyn ProviderSpec>,
@@ -22,7 +23,9 @@ pub fn classify_http_error(
     if let Some(spec) = provider_spec
         && let Some(e) = spec.classify_http_error(status, body_text, headers)
     {
-        return e;
+        return e.with_http_details(crate::retry_api::http_error_details(
+            status, body_text, headers,
+        ));
     }
     crate::retry_api::classify_http_error(provider_id, status, body_text, headers, fallback_message)
 }
@@ -162,7 +165,7 @@ mod tests {
         match err {
             LlmError::ApiError { .. }
             | LlmError::InvalidInput(_)
-            | LlmError::RateLimitError(_)
+            | LlmError::RateLimitError { .. }
             | LlmError::AuthenticationError(_)
             | LlmError::ProviderError { .. }
             | LlmError::HttpError(_) => {}
@@ -3,7 +3,8 @@
 use crate::error::LlmError;
 use crate::execution::transformers::files::{FilesHttpBody, FilesTransformer};
 use crate::types::{
-    FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
+    FileContentStream, FileDeleteResponse, FileListQuery, FileListResponse, FileObject,
+    FileUploadRequest,
 };
 use std::sync::Arc;
 
@@ -14,6 +15,14 @@ pub trait FilesExecutor: Send + Sync {
     async fn retrieve(&self, file_id: String) -> Result<FileObject, LlmError>;
     async fn delete(&self, file_id: String) -> Result<FileDeleteResponse, LlmError>;
     async fn get_content(&self, file_id: String) -> Result<Vec<u8>, LlmError>;
+
+    /// Download file content as a byte stream (defaults to a single buffered chunk).
+    async fn get_content_stream(&self, file_id: String) -> Result<FileContentStream, LlmError> {
+        let bytes = self.get_content(file_id).await?;
+        Ok(Box::pin(futures::stream::once(async move {
+            Ok(bytes::Bytes::from(bytes))
+        })))
+    }
 }
 
 /// Generic HTTP-based Files executor
@@ -126,6 +135,7 @@ impl FilesExecutor for HttpFilesExecutor {
         let base_url = self.provider_spec.files_base_url(&self.provider_context);
         let endpoint = self.transformer.upload_endpoint(&req);
         let url = crate::utils::url::join_url(&base_url, &endpoint);
+        let retry_url = url.clone();
 
         let provider_id = self.provider_id.clone();
         let http_client = self.http_client.clone();
@@ -209,7 +219,14 @@ impl FilesExecutor for HttpFilesExecutor {
         };
 
         if let Some(opts) = retry_wrapper_opts {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &retry_url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
@@ -226,6 +243,7 @@ impl FilesExecutor for HttpFilesExecutor {
         let endpoint = self.transformer.list_endpoint(&query);
         let base_url = self.provider_spec.files_base_url(&self.provider_context);
         let url = crate::utils::url::join_url(&base_url, &endpoint);
+        let retry_url = url.clone();
 
         let provider_id = self.provider_id.clone();
         let http_client = self.http_client.clone();
@@ -276,7 +294,14 @@ impl FilesExecutor for HttpFilesExecutor {
         };
 
         if let Some(opts) = retry_wrapper_opts {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &retry_url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
@@ -293,6 +318,7 @@ impl FilesExecutor for HttpFilesExecutor {
         let endpoint = self.transformer.retrieve_endpoint(&file_id);
         let base_url = self.provider_spec.files_base_url(&self.provider_context);
         let url = crate::utils::url::join_url(&base_url, &endpoint);
+        let retry_url = url.clone();
 
         let provider_id = self.provider_id.clone();
         let http_client = self.http_client.clone();
@@ -336,7 +362,14 @@ impl FilesExecutor for HttpFilesExecutor {
         };
 
         if let Some(opts) = retry_wrapper_opts {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &retry_url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
@@ -354,6 +387,7 @@ impl FilesExecutor for HttpFilesExecutor {
         let endpoint = self.transformer.delete_endpoint(&file_id);
         let base_url = self.provider_spec.files_base_url(&self.provider_context);
         let url = crate::utils::url::join_url(&base_url, &endpoint);
+        let retry_url = url.clone();
 
         let provider_id = self.provider_id.clone();
         let http_client = self.http_client.clone();
@@ -396,7 +430,14 @@ impl FilesExecutor for HttpFilesExecutor {
         };
 
         if let Some(opts) = retry_wrapper_opts {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &retry_url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
@@ -419,6 +460,8 @@ impl FilesExecutor for HttpFilesExecutor {
         let retry_options_for_http = self.policy.retry_options.clone();
         let transport = self.policy.transport.clone();
 
+        // The content URL may be resolved per attempt; report the files base URL on retry.
+        let retry_url = self.provider_spec.files_base_url(&self.provider_context);
         let file_id_for_attempts = file_id;
 
         let run_once = move || {
@@ -477,11 +520,85 @@ impl FilesExecutor for HttpFilesExecutor {
         };
 
         if let Some(opts) = retry_wrapper_opts {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &retry_url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
     }
+
+    async fn get_content_stream(&self, file_id: String) -> Result<FileContentStream, LlmError> {
+        use futures::TryStreamExt;
+
+        // Custom transports only expose buffered responses.
+        if self.policy.transport.is_some() {
+            let bytes = self.get_content(file_id).await?;
+            return Ok(Box::pin(futures::stream::once(async move {
+                Ok(bytes::Bytes::from(bytes))
+            })));
+        }
+        let caps = self.provider_spec.capabilities();
+        if !caps.supports("file_management") {
+            return Err(LlmError::UnsupportedOperation(
+                "File content download is not supported by this provider".to_string(),
+            ));
+        }
+
+        let config = self.execution_config();
+        let base_url = self.provider_spec.files_base_url(&self.provider_context);
+        let url = if let Some(ep) = self.transformer.content_endpoint(&file_id) {
+            crate::utils::url::join_url(&base_url, &ep)
+        } else {
+            let endpoint = self.transformer.retrieve_endpoint(&file_id);
+            let retrieve_url = crate::utils::url::join_url(&base_url, &endpoint);
+            let result = crate::execution::executors::http_request::execute_get_request(
+                &config,
+                &retrieve_url,
+                None,
+            )
+            .await?;
+            let file = self.transformer.transform_file_object(&result.json)?;
+            self.transformer
+                .content_url_from_file_object(&file)
+                .ok_or_else(|| {
+                    LlmError::UnsupportedOperation("File download URI not available".to_string())
+                })?
+        };
+
+        let resp = crate::execution::executors::http_request::execute_raw_request(
+            &config,
+            reqwest::Method::GET,
+            &url,
+            reqwest::header::HeaderMap::new(),
+            None,
+            None,
+        )
+        .await?;
+        Ok(Box::pin(resp.bytes_stream().map_err(|e| {
+            LlmError::HttpError(format!("Failed to read file content stream: {e}"))
+        })))
+    }
+}
+
+impl HttpFilesExecutor {
+    /// Execution config for helpers outside the trait methods (e.g. chunked upload protocols).
+    pub fn execution_config(&self) -> crate::execution::executors::common::HttpExecutionConfig {
+        crate::execution::executors::common::HttpExecutionConfig {
+            provider_id: self.provider_id.clone(),
+            http_client: self.http_client.clone(),
+            transport: self.policy.transport.clone(),
+            provider_spec: self.provider_spec.clone(),
+            provider_context: self.provider_context.clone(),
+            interceptors: self.policy.interceptors.clone(),
+            retry_options: self.policy.retry_options.clone(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -19,10 +19,15 @@ pub async fn execute_bytes_request(
     body: HttpBody,
     per_request_http_config: Option<&crate::types::HttpConfig>,
 ) -> Result<crate::execution::executors::common::HttpBytesResult, LlmError> {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream: false,
+    };
+
     // 1. Build base headers
-    let base_headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let base_headers = config.build_headers_for(&ctx)?;
 
     // 2. Merge per-request headers
     let effective_headers = if let Some(req_http) = per_request_http_config {
@@ -61,12 +66,6 @@ pub async fn execute_bytes_request(
     };
 
     // 4. Interceptors
-    let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
-        provider_id: config.provider_id.clone(),
-        url: url.to_string(),
-        stream: false,
-    };
     rb = apply_before_send_interceptors(
         &config.interceptors,
         &ctx,
@@ -96,9 +95,7 @@ pub async fn execute_bytes_request(
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
 
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -183,9 +180,7 @@ pub async fn execute_bytes_request(
             for interceptor in &config.interceptors {
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -262,10 +257,15 @@ pub async fn execute_multipart_bytes_request<F>(
 where
     F: Fn() -> Result<reqwest::multipart::Form, LlmError>,
 {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream: false,
+    };
+
     // 1. Build base headers from provider spec
-    let base_headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let base_headers = config.build_headers_for(&ctx)?;
 
     // 2. Merge per-request headers if provided
     let mut effective_headers = if let Some(req_http) = per_request_http_config {
@@ -278,12 +278,6 @@ where
     // Multipart must own its boundary-based Content-Type; strip JSON Content-Type if present.
     effective_headers.remove(reqwest::header::CONTENT_TYPE);
 
-    let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
-        provider_id: config.provider_id.clone(),
-        url: url.to_string(),
-        stream: false,
-    };
     let empty_json = serde_json::json!({});
     let per_request_timeout = per_request_http_config.and_then(|hc| hc.timeout);
 
@@ -332,9 +326,7 @@ where
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
 
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let mut retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -469,9 +461,7 @@ where
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
             // Rebuild headers and form
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -29,10 +29,15 @@ pub async fn execute_request(
     per_request_http_config: Option<&crate::types::HttpConfig>,
     stream: bool,
 ) -> Result<HttpExecutionResult, LlmError> {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream,
+    };
+
     // 1. Build base headers from provider spec
-    let base_headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let base_headers = config.build_headers_for(&ctx)?;
 
     // 2. Merge per-request headers if provided
     let effective_headers = if let Some(req_http) = per_request_http_config {
@@ -69,12 +74,6 @@ pub async fn execute_request(
     };
 
     // Apply interceptors
-    let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
-        provider_id: config.provider_id.clone(),
-        url: url.to_string(),
-        stream,
-    };
 
     // Apply before-send interceptors
     rb = apply_before_send_interceptors(
@@ -106,9 +105,7 @@ pub async fn execute_request(
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
 
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -205,9 +202,7 @@ pub async fn execute_request(
             }
 
             // Rebuild headers and retry once (unified helper)
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -20,10 +20,15 @@ pub async fn execute_multipart_request<F>(
 where
     F: Fn() -> Result<reqwest::multipart::Form, LlmError>,
 {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream: false,
+    };
+
     // 1. Build base headers from provider spec
-    let base_headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let base_headers = config.build_headers_for(&ctx)?;
 
     // 2. Merge per-request headers if provided
     let mut effective_headers = if let Some(req_http) = per_request_http_config {
@@ -36,12 +41,6 @@ where
     // Multipart must own its boundary-based Content-Type; strip JSON Content-Type if present.
     effective_headers.remove(reqwest::header::CONTENT_TYPE);
 
-    let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
-        provider_id: config.provider_id.clone(),
-        url: url.to_string(),
-        stream: false,
-    };
     let empty_json = serde_json::json!({});
     let per_request_timeout = per_request_http_config.and_then(|hc| hc.timeout);
 
@@ -90,9 +89,7 @@ where
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
 
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let mut retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -215,9 +212,7 @@ where
             for interceptor in &config.interceptors {
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
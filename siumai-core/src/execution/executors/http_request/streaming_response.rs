@@ -54,9 +54,7 @@ pub async fn execute_json_request_streaming_response_with_ctx(
     ctx: HttpRequestContext,
 ) -> Result<reqwest::Response, LlmError> {
     // 1. Build base headers from provider spec
-    let base_headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let base_headers = config.build_headers_for(&ctx)?;
 
     // 2. Merge per-request headers if provided
     let effective_headers = if let Some(req_http) = per_request_http_config {
@@ -107,9 +105,7 @@ pub async fn execute_json_request_streaming_response_with_ctx(
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
 
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -191,9 +187,7 @@ pub async fn execute_json_request_streaming_response_with_ctx(
             for interceptor in &config.interceptors {
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -259,9 +253,7 @@ where
     F: Fn() -> Result<reqwest::multipart::Form, LlmError>,
 {
     // 1. Build base headers from provider spec
-    let base_headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let base_headers = config.build_headers_for(&ctx)?;
 
     // 2. Merge per-request headers if provided
     let mut effective_headers = if let Some(req_http) = per_request_http_config {
@@ -322,9 +314,7 @@ where
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
 
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let mut retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -438,9 +428,7 @@ where
             for interceptor in &config.interceptors {
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let mut retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
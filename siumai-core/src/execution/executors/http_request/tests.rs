@@ -317,6 +317,96 @@ async fn json_with_headers_retries_401_then_200() {
     assert_eq!(res.json["ok"], true);
 }
 
+/// Emits the context API key the way real provider specs do.
+struct BearerKeySpec;
+
+impl crate::core::ProviderSpec for BearerKeySpec {
+    fn id(&self) -> &'static str {
+        "test"
+    }
+    fn capabilities(&self) -> crate::traits::ProviderCapabilities {
+        crate::traits::ProviderCapabilities::new()
+    }
+    fn build_headers(
+        &self,
+        ctx: &crate::core::ProviderContext,
+    ) -> Result<reqwest::header::HeaderMap, LlmError> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(key) = ctx.api_key.as_deref() {
+            headers.insert(
+                reqwest::header::AUTHORIZATION,
+                format!("Bearer {key}").parse().unwrap(),
+            );
+        }
+        Ok(headers)
+    }
+    fn try_chat_url(
+        &self,
+        _stream: bool,
+        _req: &crate::types::ChatRequest,
+        _ctx: &crate::core::ProviderContext,
+    ) -> Result<String, LlmError> {
+        unreachable!()
+    }
+    fn choose_chat_transformers(
+        &self,
+        _req: &crate::types::ChatRequest,
+        _ctx: &crate::core::ProviderContext,
+    ) -> crate::core::ChatTransformers {
+        unreachable!()
+    }
+}
+
+#[tokio::test]
+async fn json_with_headers_takes_pooled_keys_through_build_headers() {
+    let mut server = mockito::Server::new_async().await;
+    let _revoked = server
+        .mock("POST", "/pool")
+        .match_header("authorization", "Bearer key-a")
+        .with_status(401)
+        .with_body("unauthorized")
+        .expect(1)
+        .create_async()
+        .await;
+    let _healthy = server
+        .mock("POST", "/pool")
+        .match_header("authorization", "Bearer key-b")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("{\"ok\":true}")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let pool = crate::auth::ApiKeyPool::new(["key-a", "key-b"]).for_provider("test");
+    let other_pool = crate::auth::ApiKeyPool::new(["other-key"]).for_provider("other");
+    let mut config = test_config(
+        &reqwest::Client::new(),
+        reqwest::header::HeaderMap::new(),
+        vec![Arc::new(other_pool.clone()), Arc::new(pool.clone())],
+        Some(crate::retry_api::RetryOptions::default()),
+    );
+    config.provider_spec = Arc::new(BearerKeySpec);
+    config.provider_context.api_key = pool.primary_key();
+
+    let url = format!("{}/pool", server.url());
+    let res = execute_json_request(
+        &config,
+        &url,
+        HttpBody::Json(serde_json::json!({"q": "x"})),
+        None,
+        false,
+    )
+    .await
+    .expect("should succeed with the second key");
+
+    assert_eq!(res.status, 200);
+    let usage = pool.usage();
+    assert!(usage[0].disabled);
+    assert_eq!(usage[1].requests, 1);
+    assert_eq!(other_pool.usage()[0].requests, 0);
+}
+
 #[tokio::test]
 async fn json_with_headers_classifies_429_rate_limit() {
     let mut server = mockito::Server::new_async().await;
@@ -340,7 +430,7 @@ async fn json_with_headers_classifies_429_rate_limit() {
 
     let res = execute_json_request(&config, &url, HttpBody::Json(body), None, false).await;
     match res {
-        Err(crate::error::LlmError::RateLimitError(_)) => {}
+        Err(crate::error::LlmError::RateLimitError { .. }) => {}
         other => panic!("expected RateLimitError, got: {:?}", other),
     }
 }
@@ -16,10 +16,15 @@ pub async fn execute_get_request(
     url: &str,
     per_request_http_config: Option<&crate::types::HttpConfig>,
 ) -> Result<HttpExecutionResult, LlmError> {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream: false,
+    };
+
     // 1. Build headers via ProviderSpec
-    let headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let headers = config.build_headers_for(&ctx)?;
 
     // 2. Merge per-request headers
     let effective_headers = if let Some(req_http) = per_request_http_config {
@@ -50,12 +55,6 @@ pub async fn execute_get_request(
     };
 
     // 4. Apply interceptors
-    let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
-        provider_id: config.provider_id.clone(),
-        url: url.to_string(),
-        stream: false,
-    };
     let empty_json = serde_json::json!({});
     rb = apply_before_send_interceptors(
         &config.interceptors,
@@ -85,9 +84,7 @@ pub async fn execute_get_request(
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
 
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -178,9 +175,7 @@ pub async fn execute_get_request(
             for interceptor in &config.interceptors {
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -254,10 +249,15 @@ pub async fn execute_delete_request(
     url: &str,
     per_request_http_config: Option<&crate::types::HttpConfig>,
 ) -> Result<HttpExecutionResult, LlmError> {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream: false,
+    };
+
     // 1. Build base headers
-    let headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let headers = config.build_headers_for(&ctx)?;
     let effective_headers = if let Some(req_http) = per_request_http_config {
         config
             .provider_spec
@@ -283,12 +283,6 @@ pub async fn execute_delete_request(
         }
         b
     };
-    let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
-        provider_id: config.provider_id.clone(),
-        url: url.to_string(),
-        stream: false,
-    };
     let empty_json = serde_json::json!({});
     rb = apply_before_send_interceptors(
         &config.interceptors,
@@ -312,9 +306,7 @@ pub async fn execute_delete_request(
             for interceptor in &config.interceptors {
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -392,10 +384,15 @@ pub async fn execute_delete_json_request(
     body: serde_json::Value,
     per_request_http_config: Option<&crate::types::HttpConfig>,
 ) -> Result<HttpExecutionResult, LlmError> {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream: false,
+    };
+
     // 1. Build base headers
-    let base_headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let base_headers = config.build_headers_for(&ctx)?;
 
     // 2. Merge per-request headers
     let effective_headers = if let Some(req_http) = per_request_http_config {
@@ -424,12 +421,6 @@ pub async fn execute_delete_json_request(
     }
 
     // 4. Interceptors
-    let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
-        provider_id: config.provider_id.clone(),
-        url: url.to_string(),
-        stream: false,
-    };
     rb = apply_before_send_interceptors(&config.interceptors, &ctx, rb, &body, &effective_headers)?;
 
     // 5. Send
@@ -450,9 +441,7 @@ pub async fn execute_delete_json_request(
             for interceptor in &config.interceptors {
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -538,10 +527,15 @@ pub async fn execute_patch_json_request(
     body: serde_json::Value,
     per_request_http_config: Option<&crate::types::HttpConfig>,
 ) -> Result<HttpExecutionResult, LlmError> {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream: false,
+    };
+
     // 1. Build base headers
-    let base_headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let base_headers = config.build_headers_for(&ctx)?;
 
     // 2. Merge per-request headers
     let effective_headers = if let Some(req_http) = per_request_http_config {
@@ -570,12 +564,6 @@ pub async fn execute_patch_json_request(
     }
 
     // 4. Interceptors
-    let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
-        provider_id: config.provider_id.clone(),
-        url: url.to_string(),
-        stream: false,
-    };
     rb = apply_before_send_interceptors(&config.interceptors, &ctx, rb, &body, &effective_headers)?;
 
     // 5. Send
@@ -596,9 +584,7 @@ pub async fn execute_patch_json_request(
             for interceptor in &config.interceptors {
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -680,10 +666,15 @@ pub async fn execute_get_binary(
     url: &str,
     per_request_http_config: Option<&crate::types::HttpConfig>,
 ) -> Result<HttpBinaryResult, LlmError> {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream: false,
+    };
+
     // 1. Build base headers
-    let headers = config
-        .provider_spec
-        .build_headers(&config.provider_context)?;
+    let headers = config.build_headers_for(&ctx)?;
     let effective_headers = if let Some(req_http) = per_request_http_config {
         config
             .provider_spec
@@ -710,12 +701,6 @@ pub async fn execute_get_binary(
         b
     };
     // 3. Interceptors
-    let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
-        provider_id: config.provider_id.clone(),
-        url: url.to_string(),
-        stream: false,
-    };
     let empty_json = serde_json::json!({});
     rb = apply_before_send_interceptors(
         &config.interceptors,
@@ -745,9 +730,7 @@ pub async fn execute_get_binary(
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
 
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -829,9 +812,7 @@ pub async fn execute_get_binary(
             for interceptor in &config.interceptors {
                 interceptor.on_retry(&ctx, &LlmError::HttpError("401 Unauthorized".into()), 1);
             }
-            let retry_headers = config
-                .provider_spec
-                .build_headers(&config.provider_context)?;
+            let retry_headers = config.build_headers_for(&ctx)?;
             let retry_effective_headers = if let Some(req_http) = per_request_http_config {
                 config
                     .provider_spec
@@ -885,3 +866,90 @@ pub async fn execute_get_binary(
         headers: response_headers,
     })
 }
+
+/// Send a request with a raw body and return the successful response unread.
+///
+/// Used by chunked upload protocols and streaming downloads, which need the response headers or
+/// the body stream. ProviderSpec headers, per-request headers and `extra_headers` are merged (in
+/// that order) and interceptors are notified. Custom transports are not used on this path, and
+/// there is no automatic 401 retry because raw bodies are consumed by the first attempt.
+pub async fn execute_raw_request(
+    config: &HttpExecutionConfig,
+    method: reqwest::Method,
+    url: &str,
+    extra_headers: HeaderMap,
+    body: Option<bytes::Bytes>,
+    per_request_http_config: Option<&crate::types::HttpConfig>,
+) -> Result<reqwest::Response, LlmError> {
+    let ctx = HttpRequestContext {
+        request_id: crate::execution::http::interceptor::generate_request_id(),
+        provider_id: config.provider_id.clone(),
+        url: url.to_string(),
+        stream: false,
+    };
+
+    let headers = config.build_headers_for(&ctx)?;
+    let mut effective_headers = if let Some(req_http) = per_request_http_config {
+        config
+            .provider_spec
+            .merge_request_headers(headers, &req_http.headers)
+    } else {
+        headers
+    };
+    for (name, value) in &extra_headers {
+        effective_headers.insert(name.clone(), value.clone());
+    }
+    if body.is_some() {
+        effective_headers.remove(reqwest::header::CONTENT_TYPE);
+        if let Some(content_type) = extra_headers.get(reqwest::header::CONTENT_TYPE) {
+            effective_headers.insert(reqwest::header::CONTENT_TYPE, content_type.clone());
+        }
+    }
+
+    let mut rb = config
+        .http_client
+        .request(method, url)
+        .headers(effective_headers.clone());
+    if let Some(req_http) = per_request_http_config
+        && let Some(timeout) = req_http.timeout
+    {
+        rb = rb.timeout(timeout);
+    }
+    if let Some(body) = body {
+        rb = rb.body(body);
+    }
+
+    let empty_json = serde_json::json!({});
+    rb = apply_before_send_interceptors(
+        &config.interceptors,
+        &ctx,
+        rb,
+        &empty_json,
+        &effective_headers,
+    )?;
+
+    let resp = match rb.send().await {
+        Ok(resp) => resp,
+        Err(e) => {
+            let error = LlmError::HttpError(e.to_string());
+            for interceptor in &config.interceptors {
+                interceptor.on_error(&ctx, &error);
+            }
+            return Err(error);
+        }
+    };
+    if !resp.status().is_success() {
+        return Err(exec_errors::classify_error_with_text(
+            &config.provider_id,
+            Some(config.provider_spec.as_ref()),
+            resp,
+            &ctx,
+            &config.interceptors,
+        )
+        .await);
+    }
+    for interceptor in &config.interceptors {
+        interceptor.on_response(&ctx, &resp)?;
+    }
+    Ok(resp)
+}
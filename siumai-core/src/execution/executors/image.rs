@@ -70,6 +70,7 @@ async fn materialize_url_backed_image_input(
                         code: status.as_u16(),
                         message: format!("Failed to download {label} from {url}"),
                         details: Some(serde_json::json!({ "url": url, "body": body })),
+                        http_details: None,
                     });
                 }
 
@@ -294,8 +295,12 @@ impl ImageExecutor for HttpImageExecutor {
             ));
         }
         let retry_options = self.policy.retry_options.clone();
-        let run_once = move || {
+        let url = self
+            .provider_spec
+            .try_image_url(&req, &self.provider_context)?;
+        let run_once = || {
             let req = req.clone();
+            let url = url.clone();
             async move {
                 let warnings = self
                     .provider_spec
@@ -308,12 +313,7 @@ impl ImageExecutor for HttpImageExecutor {
                     body = cb(&body)?;
                 }
 
-                // 3. Get URL from provider spec
-                let url = self
-                    .provider_spec
-                    .try_image_url(&req, &self.provider_context)?;
-
-                // 4. Build execution config for common HTTP layer
+                // 3. Build execution config for common HTTP layer
                 let config = crate::execution::executors::common::HttpExecutionConfig {
                     provider_id: self.provider_id.clone(),
                     http_client: self.http_client.clone(),
@@ -324,7 +324,7 @@ impl ImageExecutor for HttpImageExecutor {
                     retry_options: self.policy.retry_options.clone(),
                 };
 
-                // 5. Execute request using common HTTP layer
+                // 4. Execute request using common HTTP layer
                 let result = crate::execution::executors::common::execute_json_request(
                     &config,
                     &url,
@@ -334,7 +334,7 @@ impl ImageExecutor for HttpImageExecutor {
                 )
                 .await?;
 
-                // 6. Transform response
+                // 5. Transform response
                 let mut out = self
                     .response_transformer
                     .transform_image_response(&result.json)?;
@@ -352,7 +352,14 @@ impl ImageExecutor for HttpImageExecutor {
         };
 
         if let Some(opts) = retry_options {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
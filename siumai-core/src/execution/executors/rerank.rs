@@ -149,8 +149,12 @@ impl RerankExecutor for HttpRerankExecutor {
         }
 
         let retry_options = self.policy.retry_options.clone();
-        let run_once = move || {
+        let url = self
+            .provider_spec
+            .try_rerank_url(&req, &self.provider_context)?;
+        let run_once = || {
             let req = req.clone();
+            let url = url.clone();
             async move {
                 // 1. Transform request
                 let mut body = self.request_transformer.transform(&req)?;
@@ -166,12 +170,7 @@ impl RerankExecutor for HttpRerankExecutor {
                     body = cb(&body)?;
                 }
 
-                // 3. Resolve URL via ProviderSpec
-                let url = self
-                    .provider_spec
-                    .try_rerank_url(&req, &self.provider_context)?;
-
-                // 4. Execute request via the common HTTP layer
+                // 3. Execute request via the common HTTP layer
                 let config = crate::execution::executors::common::HttpExecutionConfig {
                     provider_id: self.provider_id.clone(),
                     http_client: self.http_client.clone(),
@@ -190,7 +189,7 @@ impl RerankExecutor for HttpRerankExecutor {
                 )
                 .await?;
 
-                // 5. Transform response and attach AI SDK-style response envelope.
+                // 4. Transform response and attach AI SDK-style response envelope.
                 let mut out = self.response_transformer.transform(result.json.clone())?;
                 out.response = Some(crate::types::HttpResponseInfo {
                     timestamp: chrono::Utc::now(),
@@ -203,7 +202,14 @@ impl RerankExecutor for HttpRerankExecutor {
         };
 
         if let Some(opts) = retry_options {
-            crate::retry_api::retry_with(run_once, opts).await
+            crate::execution::executors::common::retry_with_interceptors(
+                run_once,
+                opts,
+                &self.provider_id,
+                &url,
+                &self.policy.interceptors,
+            )
+            .await
         } else {
             run_once().await
         }
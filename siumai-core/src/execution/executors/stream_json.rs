@@ -20,6 +20,7 @@ pub async fn execute_json_stream_request_with_headers<C>(
     provider_id: &str,
     provider_spec: Option<&dyn crate::core::ProviderSpec>,
     url: &str,
+    request_id: String,
     headers_base: HeaderMap,
     body: serde_json::Value,
     interceptors: &[Arc<dyn HttpInterceptor>],
@@ -44,7 +45,7 @@ where
     };
 
     let ctx = HttpRequestContext {
-        request_id: crate::execution::http::interceptor::generate_request_id(),
+        request_id,
         provider_id: provider_id.to_string(),
         url: url.to_string(),
         stream: true,
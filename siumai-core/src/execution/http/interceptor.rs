@@ -29,6 +29,16 @@ pub fn generate_request_id() -> String {
 
 /// HTTP interceptor trait
 pub trait HttpInterceptor: Send + Sync {
+    /// Supplies the API key for this attempt, if the interceptor manages keys for
+    /// `ctx.provider_id` (e.g. an `ApiKeyPool`).
+    ///
+    /// Executors put the key into `ProviderContext::api_key` before calling
+    /// `ProviderSpec::build_headers`, so it is sent in the provider's own auth header. The first
+    /// interceptor returning `Some` wins; returning an error fails the attempt.
+    fn api_key_for(&self, _ctx: &HttpRequestContext) -> Result<Option<String>, LlmError> {
+        Ok(None)
+    }
+
     /// Called before sending a request. Interceptors may add headers or modify
     /// attributes on the request builder. Return the (possibly modified)
     /// builder or an error to short-circuit the request.
@@ -58,6 +68,8 @@ pub trait HttpInterceptor: Send + Sync {
     ///
     /// This is called when a request is about to be retried after an error.
     /// Useful for logging, metrics, or implementing custom retry logic.
+    /// Rate limit and quota errors expose the HTTP status, provider request id and
+    /// server retry-after hint via [`LlmError::http_details`].
     ///
     /// # Arguments
     /// * `ctx` - Request context
@@ -362,6 +374,7 @@ impl HttpInterceptor for HttpTracingInterceptor {
             stream = %ctx.stream,
             err = %error,
             attempt = %attempt,
+            retry_after_ms = error.retry_after().map(|d| d.as_millis() as u64),
             "retrying request"
         );
     }
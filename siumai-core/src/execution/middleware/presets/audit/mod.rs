@@ -0,0 +1,533 @@
+//! Audit log middleware.
+//!
+//! [`HttpInterceptor`](crate::execution::http::interceptor::HttpInterceptor) sees provider wire
+//! payloads, and `LoggingInterceptor` deliberately records none. Compliance transcripts need
+//! the unified view instead: this middleware captures every call's [`ChatRequest`], the final
+//! [`ChatResponse`] (streams are accumulated with [`StreamProcessor`]), usage, timing,
+//! provider/model and request id as an [`AuditRecord`], and hands it to an [`AuditSink`].
+//!
+//! Records pass through [`AuditRedaction`] before they reach the sink: credential-bearing
+//! headers are always scrubbed, and configured field paths are replaced. Sampling keeps a
+//! fraction of successful calls while (by default) still recording every failure.
+//!
+//! Records are handed to a background writer task, so a slow sink never delays the response or
+//! the stream; [`AuditMiddleware::flush`] waits until queued records are written. Sink failures
+//! are logged and counted but never fail the call. Streams dropped before they finish are
+//! recorded as [`AuditOutcome::Cancelled`] with the partial response.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::error::LlmError;
+use crate::execution::middleware::LanguageModelMiddleware;
+use crate::execution::middleware::language_model::{GenerateAsyncFn, StreamAsyncFn};
+use crate::streaming::{ChatStream, StreamProcessor};
+use crate::types::{ChatRequest, ChatResponse, ChatStreamEvent, FinishReason, Usage};
+
+mod redact;
+mod sink;
+
+pub use redact::AuditRedaction;
+pub use sink::{AuditSink, ChannelAuditSink, JsonlFileAuditSink};
+
+/// Response headers checked, in order, for a provider request id.
+const REQUEST_ID_HEADERS: &[&str] = &["x-request-id", "request-id", "x-amzn-requestid"];
+
+/// How an audited call ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    /// The call produced a response.
+    Success,
+    /// The call (or its stream) failed.
+    Error,
+    /// The stream was dropped before it finished.
+    Cancelled,
+}
+
+/// Transcript of one language model call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditRecord {
+    /// Unique record id.
+    pub id: String,
+    /// When the call started.
+    pub started_at: DateTime<Utc>,
+    /// Provider id, when known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+    /// Requested model id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// Provider request id, from response headers or stream metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    /// Whether the call was streamed.
+    pub stream: bool,
+    /// How the call ended.
+    pub outcome: AuditOutcome,
+    /// Wall-clock duration of the call (until the stream finished, for streams).
+    pub duration_ms: u64,
+    /// Time until the first stream event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_to_first_event_ms: Option<u64>,
+    /// Token usage.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<Usage>,
+    /// Finish reason.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finish_reason: Option<FinishReason>,
+    /// Serialized unified request.
+    pub request: Value,
+    /// Serialized final response (partial for cancelled streams).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response: Option<Value>,
+    /// Error message, for failed calls.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Telemetry user id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+    /// Telemetry session id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub session_id: Option<String>,
+    /// Telemetry metadata (e.g. prompt template id and version).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// Audit behavior.
+#[derive(Debug, Clone)]
+pub struct AuditConfig {
+    /// Fraction of successful calls recorded, in `0.0..=1.0`.
+    pub sample_rate: f64,
+    /// Record failed calls even when they were sampled out.
+    pub always_record_errors: bool,
+    /// Provider id to record; otherwise taken from stream metadata or the response's provider
+    /// metadata.
+    pub provider: Option<String>,
+    /// Redaction applied before records reach the sink.
+    pub redaction: AuditRedaction,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            sample_rate: 1.0,
+            always_record_errors: true,
+            provider: None,
+            redaction: AuditRedaction::default(),
+        }
+    }
+}
+
+impl AuditConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_sample_rate(mut self, rate: f64) -> Self {
+        self.sample_rate = rate.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_always_record_errors(mut self, enabled: bool) -> Self {
+        self.always_record_errors = enabled;
+        self
+    }
+
+    pub fn with_provider(mut self, provider: impl Into<String>) -> Self {
+        self.provider = Some(provider.into());
+        self
+    }
+
+    pub fn with_redaction(mut self, redaction: AuditRedaction) -> Self {
+        self.redaction = redaction;
+        self
+    }
+
+    fn sample(&self) -> bool {
+        self.sample_rate >= 1.0 || rand::random_range(0.0..1.0) < self.sample_rate
+    }
+}
+
+/// Counters observed by [`AuditMiddleware`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditStats {
+    /// Records handed to the sink successfully.
+    pub recorded: u64,
+    /// Calls not recorded because of sampling.
+    pub sampled_out: u64,
+    /// Records the sink failed to write.
+    pub sink_errors: u64,
+}
+
+struct AuditShared {
+    sink: Arc<dyn AuditSink>,
+    config: AuditConfig,
+    stats: Arc<Mutex<AuditStats>>,
+    /// Queue of the background writer, started on first use inside a Tokio runtime.
+    writer: OnceLock<mpsc::UnboundedSender<WriterMessage>>,
+}
+
+enum WriterMessage {
+    Record(Box<AuditRecord>),
+    Flush(oneshot::Sender<()>),
+}
+
+fn bump_stats(stats: &Mutex<AuditStats>, f: impl FnOnce(&mut AuditStats)) {
+    if let Ok(mut stats) = stats.lock() {
+        f(&mut stats);
+    }
+}
+
+/// Write queued records in order. Ends once the middleware (and its queue sender) is dropped.
+async fn run_writer(
+    mut queue: mpsc::UnboundedReceiver<WriterMessage>,
+    sink: Arc<dyn AuditSink>,
+    redaction: AuditRedaction,
+    stats: Arc<Mutex<AuditStats>>,
+) {
+    while let Some(message) = queue.recv().await {
+        match message {
+            WriterMessage::Record(mut record) => {
+                redaction.apply(&mut record);
+                match sink.write(&record).await {
+                    Ok(()) => bump_stats(&stats, |s| s.recorded += 1),
+                    Err(e) => {
+                        tracing::warn!(error = %e, record_id = %record.id, "audit sink write failed");
+                        bump_stats(&stats, |s| s.sink_errors += 1);
+                    }
+                }
+            }
+            WriterMessage::Flush(done) => {
+                let _ = done.send(());
+            }
+        }
+    }
+}
+
+impl AuditShared {
+    fn bump(&self, f: impl FnOnce(&mut AuditStats)) {
+        bump_stats(&self.stats, f);
+    }
+
+    fn writer(&self) -> Option<&mpsc::UnboundedSender<WriterMessage>> {
+        if let Some(writer) = self.writer.get() {
+            return Some(writer);
+        }
+        let handle = tokio::runtime::Handle::try_current().ok()?;
+        Some(self.writer.get_or_init(|| {
+            let (tx, rx) = mpsc::unbounded_channel();
+            handle.spawn(run_writer(
+                rx,
+                Arc::clone(&self.sink),
+                self.config.redaction.clone(),
+                Arc::clone(&self.stats),
+            ));
+            tx
+        }))
+    }
+
+    /// Queue `record` for the background writer without waiting for the sink.
+    fn write(&self, record: AuditRecord) {
+        let queued = self
+            .writer()
+            .is_some_and(|writer| writer.send(WriterMessage::Record(Box::new(record))).is_ok());
+        if !queued {
+            tracing::warn!("audit record dropped: no Tokio runtime for the audit writer");
+            self.bump(|s| s.sink_errors += 1);
+        }
+    }
+
+    async fn flush(&self) {
+        let Some(writer) = self.writer.get() else {
+            return;
+        };
+        let (done, wait) = oneshot::channel();
+        if writer.send(WriterMessage::Flush(done)).is_ok() {
+            let _ = wait.await;
+        }
+    }
+}
+
+/// A record under construction.
+struct PendingRecord {
+    record: AuditRecord,
+    started: Instant,
+    sampled: bool,
+}
+
+impl PendingRecord {
+    fn start(shared: &AuditShared, req: &ChatRequest, stream: bool, sampled: bool) -> Self {
+        let telemetry = req.telemetry.as_ref();
+        Self {
+            record: AuditRecord {
+                id: uuid::Uuid::new_v4().to_string(),
+                started_at: Utc::now(),
+                provider: shared.config.provider.clone(),
+                model: Some(req.common_params.model.clone()).filter(|m| !m.is_empty()),
+                request_id: None,
+                stream,
+                outcome: AuditOutcome::Success,
+                duration_ms: 0,
+                time_to_first_event_ms: None,
+                usage: None,
+                finish_reason: None,
+                request: serde_json::to_value(req).unwrap_or(Value::Null),
+                response: None,
+                error: None,
+                user_id: telemetry.and_then(|t| t.user_id.clone()),
+                session_id: telemetry.and_then(|t| t.session_id.clone()),
+                metadata: telemetry
+                    .map(|t| t.metadata.clone().into_iter().collect())
+                    .unwrap_or_default(),
+            },
+            started: Instant::now(),
+            sampled,
+        }
+    }
+
+    /// Whether a call ending with `outcome` should be written.
+    fn keep(&self, shared: &AuditShared, outcome: AuditOutcome) -> bool {
+        self.sampled || (outcome == AuditOutcome::Error && shared.config.always_record_errors)
+    }
+
+    fn finish(
+        mut self,
+        outcome: AuditOutcome,
+        response: Option<&ChatResponse>,
+        error: Option<&LlmError>,
+    ) -> AuditRecord {
+        let record = &mut self.record;
+        record.outcome = outcome;
+        record.duration_ms = self.started.elapsed().as_millis() as u64;
+        record.error = error.map(ToString::to_string);
+        if let Some(response) = response {
+            record.usage = response.usage.clone();
+            record.finish_reason = response.finish_reason.clone();
+            if let Some(info) = &response.response {
+                record.request_id = record.request_id.take().or_else(|| {
+                    REQUEST_ID_HEADERS
+                        .iter()
+                        .find_map(|name| info.headers.get(*name).cloned())
+                });
+            }
+            if record.provider.is_none() {
+                record.provider = response.provider_metadata.as_ref().and_then(|metadata| {
+                    let mut providers = metadata.keys().filter(|k| k.as_str() != "siumai");
+                    match (providers.next(), providers.next()) {
+                        (Some(provider), None) => Some(provider.clone()),
+                        _ => None,
+                    }
+                });
+            }
+            record.response = serde_json::to_value(response).ok();
+        }
+        self.record
+    }
+}
+
+/// State of an audited stream; records a cancelled call if dropped unfinished.
+struct StreamAudit {
+    shared: Arc<AuditShared>,
+    pending: Option<PendingRecord>,
+    processor: StreamProcessor,
+}
+
+impl StreamAudit {
+    fn observe(&mut self, event: &ChatStreamEvent) {
+        let Some(pending) = self.pending.as_mut() else {
+            return;
+        };
+        if pending.record.time_to_first_event_ms.is_none() {
+            pending.record.time_to_first_event_ms =
+                Some(pending.started.elapsed().as_millis() as u64);
+        }
+        if let ChatStreamEvent::StreamStart { metadata } = event {
+            pending.record.request_id = metadata.request_id.clone();
+            if pending.record.provider.is_none() && !metadata.provider.is_empty() {
+                pending.record.provider = Some(metadata.provider.clone());
+            }
+        }
+        self.processor.process_event(event.clone());
+    }
+
+    fn take(
+        &mut self,
+        outcome: AuditOutcome,
+        error: Option<&LlmError>,
+    ) -> Option<(Arc<AuditShared>, AuditRecord)> {
+        let pending = self.pending.take()?;
+        if !pending.keep(&self.shared, outcome) {
+            self.shared.bump(|s| s.sampled_out += 1);
+            return None;
+        }
+        let response = self.processor.build_final_response();
+        let record = pending.finish(outcome, Some(&response), error);
+        Some((Arc::clone(&self.shared), record))
+    }
+
+    fn finish(&mut self, outcome: AuditOutcome, error: Option<&LlmError>) {
+        if let Some((shared, record)) = self.take(outcome, error) {
+            shared.write(record);
+        }
+    }
+}
+
+impl Drop for StreamAudit {
+    fn drop(&mut self) {
+        self.finish(AuditOutcome::Cancelled, None);
+    }
+}
+
+/// Middleware recording redacted transcripts of every call to an [`AuditSink`].
+#[derive(Clone)]
+pub struct AuditMiddleware {
+    shared: Arc<AuditShared>,
+}
+
+impl std::fmt::Debug for AuditMiddleware {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditMiddleware")
+            .field("config", &self.shared.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AuditMiddleware {
+    /// Record every call to `sink` with default redaction.
+    pub fn new(sink: Arc<dyn AuditSink>) -> Self {
+        Self::with_config(sink, AuditConfig::default())
+    }
+
+    pub fn with_config(sink: Arc<dyn AuditSink>, config: AuditConfig) -> Self {
+        Self {
+            shared: Arc::new(AuditShared {
+                sink,
+                config,
+                stats: Arc::new(Mutex::new(AuditStats::default())),
+                writer: OnceLock::new(),
+            }),
+        }
+    }
+
+    /// Active configuration.
+    pub fn config(&self) -> &AuditConfig {
+        &self.shared.config
+    }
+
+    /// Wait until every record queued so far has been handed to the sink.
+    pub async fn flush(&self) {
+        self.shared.flush().await;
+    }
+
+    /// Snapshot of the counters observed so far (shared across clones).
+    pub fn stats(&self) -> AuditStats {
+        self.shared
+            .stats
+            .lock()
+            .map(|s| s.clone())
+            .unwrap_or_default()
+    }
+
+    /// Start auditing a call, or `None` when it can never be recorded.
+    fn begin(shared: &AuditShared, req: &ChatRequest, stream: bool) -> Option<PendingRecord> {
+        let sampled = shared.config.sample();
+        if !sampled && !shared.config.always_record_errors {
+            shared.bump(|s| s.sampled_out += 1);
+            return None;
+        }
+        Some(PendingRecord::start(shared, req, stream, sampled))
+    }
+}
+
+impl LanguageModelMiddleware for AuditMiddleware {
+    fn wrap_generate_async(&self, next: Arc<GenerateAsyncFn>) -> Arc<GenerateAsyncFn> {
+        let shared = Arc::clone(&self.shared);
+        Arc::new(move |req: ChatRequest| {
+            let next = Arc::clone(&next);
+            let shared = Arc::clone(&shared);
+            Box::pin(async move {
+                let Some(pending) = AuditMiddleware::begin(&shared, &req, false) else {
+                    return next(req).await;
+                };
+                let result = next(req).await;
+                let outcome = match &result {
+                    Ok(_) => AuditOutcome::Success,
+                    Err(_) => AuditOutcome::Error,
+                };
+                if pending.keep(&shared, outcome) {
+                    let record =
+                        pending.finish(outcome, result.as_ref().ok(), result.as_ref().err());
+                    shared.write(record);
+                } else {
+                    shared.bump(|s| s.sampled_out += 1);
+                }
+                result
+            })
+        })
+    }
+
+    fn wrap_stream_async(&self, next: Arc<StreamAsyncFn>) -> Arc<StreamAsyncFn> {
+        let shared = Arc::clone(&self.shared);
+        Arc::new(move |req: ChatRequest| {
+            let next = Arc::clone(&next);
+            let shared = Arc::clone(&shared);
+            Box::pin(async move {
+                let Some(pending) = AuditMiddleware::begin(&shared, &req, true) else {
+                    return next(req).await;
+                };
+                let mut inner = match next(req).await {
+                    Ok(inner) => inner,
+                    Err(e) => {
+                        if pending.keep(&shared, AuditOutcome::Error) {
+                            shared.write(pending.finish(AuditOutcome::Error, None, Some(&e)));
+                        } else {
+                            shared.bump(|s| s.sampled_out += 1);
+                        }
+                        return Err(e);
+                    }
+                };
+
+                let mut audit = StreamAudit {
+                    shared,
+                    pending: Some(pending),
+                    processor: StreamProcessor::new(),
+                };
+                let stream: ChatStream = Box::pin(async_stream::stream! {
+                    while let Some(item) = inner.next().await {
+                        match &item {
+                            Ok(event) => {
+                                audit.observe(event);
+                                match event {
+                                    ChatStreamEvent::StreamEnd { .. } => {
+                                        audit.finish(AuditOutcome::Success, None);
+                                    }
+                                    ChatStreamEvent::Error { error } => {
+                                        let error = LlmError::StreamError(error.clone());
+                                        audit.finish(AuditOutcome::Error, Some(&error));
+                                    }
+                                    _ => {}
+                                }
+                            }
+                            Err(e) => audit.finish(AuditOutcome::Error, Some(e)),
+                        }
+                        yield item;
+                    }
+                    audit.finish(AuditOutcome::Success, None);
+                });
+                Ok(stream)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,166 @@
+//! Field redaction and header scrubbing for audit records.
+
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use super::AuditRecord;
+
+/// Headers scrubbed by default (compared case-insensitively).
+const DEFAULT_SECRET_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "x-api-key",
+    "api-key",
+    "x-goog-api-key",
+    "cookie",
+    "set-cookie",
+];
+
+/// Header name fragments that mark a header as a credential.
+const SECRET_HEADER_FRAGMENTS: &[&str] = &["api-key", "apikey", "token", "secret", "signature"];
+
+/// Redaction applied to [`AuditRecord`]s before they reach a sink.
+///
+/// Headers in the request's `http_config.headers` and the response's `response.headers` are
+/// scrubbed when their name is a known credential header or contains `api-key`, `token`,
+/// `secret` or `signature`.
+///
+/// Field paths are dotted and rooted at `request`, `response`, `error` or `metadata`; `*`
+/// matches every key of an object or element of an array. For example
+/// `request.messages.*.content` hides every prompt, and `response.content` hides the answer.
+#[derive(Debug, Clone)]
+pub struct AuditRedaction {
+    headers: BTreeSet<String>,
+    fields: Vec<Vec<String>>,
+    replacement: String,
+}
+
+impl Default for AuditRedaction {
+    fn default() -> Self {
+        Self {
+            headers: DEFAULT_SECRET_HEADERS
+                .iter()
+                .map(|h| h.to_string())
+                .collect(),
+            fields: Vec::new(),
+            replacement: "[REDACTED]".to_string(),
+        }
+    }
+}
+
+impl AuditRedaction {
+    /// Default header scrubbing, no field redaction.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also scrub header `name`.
+    pub fn with_header(mut self, name: impl Into<String>) -> Self {
+        self.headers.insert(name.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Redact the field at dotted `path`.
+    pub fn with_field(mut self, path: &str) -> Self {
+        self.fields
+            .push(path.split('.').map(str::to_string).collect());
+        self
+    }
+
+    /// Text that replaces redacted values (default `[REDACTED]`).
+    pub fn with_replacement(mut self, replacement: impl Into<String>) -> Self {
+        self.replacement = replacement.into();
+        self
+    }
+
+    /// Whether header `name` is scrubbed.
+    pub fn is_secret_header(&self, name: &str) -> bool {
+        let name = name.to_ascii_lowercase();
+        self.headers.contains(&name)
+            || SECRET_HEADER_FRAGMENTS
+                .iter()
+                .any(|fragment| name.contains(fragment))
+    }
+
+    pub(super) fn apply(&self, record: &mut AuditRecord) {
+        self.scrub_headers(&mut record.request, &["http_config", "headers"]);
+        if let Some(response) = record.response.as_mut() {
+            self.scrub_headers(response, &["response", "headers"]);
+        }
+
+        for path in &self.fields {
+            let Some((root, rest)) = path.split_first() else {
+                continue;
+            };
+            match root.as_str() {
+                "request" => self.redact(&mut record.request, rest),
+                "response" => {
+                    if let Some(response) = record.response.as_mut() {
+                        self.redact(response, rest);
+                    }
+                }
+                "error" => {
+                    if let Some(error) = record.error.as_mut() {
+                        *error = self.replacement.clone();
+                    }
+                }
+                "metadata" => {
+                    for (key, value) in record.metadata.iter_mut() {
+                        if rest.is_empty() || rest[0] == "*" || rest[0] == *key {
+                            *value = self.replacement.clone();
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn scrub_headers(&self, value: &mut Value, path: &[&str]) {
+        let mut current = value;
+        for key in path {
+            match current.get_mut(*key) {
+                Some(next) => current = next,
+                None => return,
+            }
+        }
+        if let Value::Object(headers) = current {
+            for (name, value) in headers.iter_mut() {
+                if self.is_secret_header(name) {
+                    *value = Value::String(self.replacement.clone());
+                }
+            }
+        }
+    }
+
+    fn redact(&self, value: &mut Value, path: &[String]) {
+        let Some((key, rest)) = path.split_first() else {
+            *value = Value::String(self.replacement.clone());
+            return;
+        };
+        match value {
+            Value::Object(map) if key == "*" => {
+                for child in map.values_mut() {
+                    self.redact(child, rest);
+                }
+            }
+            Value::Array(items) if key == "*" => {
+                for child in items {
+                    self.redact(child, rest);
+                }
+            }
+            Value::Object(map) => {
+                if let Some(child) = map.get_mut(key) {
+                    self.redact(child, rest);
+                }
+            }
+            Value::Array(items) => {
+                if let Some(child) = key.parse::<usize>().ok().and_then(|i| items.get_mut(i)) {
+                    self.redact(child, rest);
+                }
+            }
+            _ => {}
+        }
+    }
+}
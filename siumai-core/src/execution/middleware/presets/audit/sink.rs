@@ -0,0 +1,183 @@
+//! Audit record sinks.
+
+use std::path::{Path, PathBuf};
+
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{Mutex, mpsc};
+
+use super::AuditRecord;
+use crate::error::LlmError;
+
+/// Destination for [`AuditRecord`]s.
+#[async_trait::async_trait]
+pub trait AuditSink: Send + Sync {
+    /// Persist one record.
+    async fn write(&self, record: &AuditRecord) -> Result<(), LlmError>;
+}
+
+/// Sends records to a bounded channel (e.g. for a background shipper).
+#[derive(Debug, Clone)]
+pub struct ChannelAuditSink {
+    sender: mpsc::Sender<AuditRecord>,
+}
+
+impl ChannelAuditSink {
+    /// A sink and the receiver its records arrive on. Writes wait while the channel is full.
+    pub fn channel(capacity: usize) -> (Self, mpsc::Receiver<AuditRecord>) {
+        let (sender, receiver) = mpsc::channel(capacity.max(1));
+        (Self { sender }, receiver)
+    }
+
+    /// Wrap an existing sender.
+    pub fn new(sender: mpsc::Sender<AuditRecord>) -> Self {
+        Self { sender }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditSink for ChannelAuditSink {
+    async fn write(&self, record: &AuditRecord) -> Result<(), LlmError> {
+        self.sender
+            .send(record.clone())
+            .await
+            .map_err(|_| LlmError::InternalError("audit channel closed".to_string()))
+    }
+}
+
+struct OpenFile {
+    file: tokio::fs::File,
+    size: u64,
+}
+
+/// Appends records as JSON lines to `<dir>/<prefix>.jsonl`, rotating by size.
+///
+/// When the active file would exceed `max_bytes`, it is renamed to `<prefix>.1.jsonl` (older
+/// rotations shift to `.2`, `.3`, ...) and files beyond `max_files` rotations are deleted.
+pub struct JsonlFileAuditSink {
+    dir: PathBuf,
+    prefix: String,
+    max_bytes: u64,
+    max_files: usize,
+    file: Mutex<Option<OpenFile>>,
+}
+
+impl std::fmt::Debug for JsonlFileAuditSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonlFileAuditSink")
+            .field("dir", &self.dir)
+            .field("prefix", &self.prefix)
+            .field("max_bytes", &self.max_bytes)
+            .field("max_files", &self.max_files)
+            .finish_non_exhaustive()
+    }
+}
+
+impl JsonlFileAuditSink {
+    /// Write to `<dir>/<prefix>.jsonl`, rotating at 64 MiB and keeping 10 rotations.
+    pub fn new(dir: impl Into<PathBuf>, prefix: impl Into<String>) -> Self {
+        Self {
+            dir: dir.into(),
+            prefix: prefix.into(),
+            max_bytes: 64 * 1024 * 1024,
+            max_files: 10,
+            file: Mutex::new(None),
+        }
+    }
+
+    /// Rotate once the active file would exceed `max_bytes`.
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes.max(1);
+        self
+    }
+
+    /// Number of rotated files kept (0 discards the active file on rotation).
+    pub fn with_max_files(mut self, max_files: usize) -> Self {
+        self.max_files = max_files;
+        self
+    }
+
+    /// Path of the active file.
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(format!("{}.jsonl", self.prefix))
+    }
+
+    fn rotated_path(&self, n: usize) -> PathBuf {
+        self.dir.join(format!("{}.{n}.jsonl", self.prefix))
+    }
+
+    async fn open(&self) -> Result<OpenFile, LlmError> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| io_error(&self.dir, e))?;
+        let path = self.path();
+        let file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .map_err(|e| io_error(&path, e))?;
+        let size = file.metadata().await.map_err(|e| io_error(&path, e))?.len();
+        Ok(OpenFile { file, size })
+    }
+
+    async fn rotate(&self) -> Result<(), LlmError> {
+        if self.max_files == 0 {
+            return remove_if_exists(&self.path()).await;
+        }
+        remove_if_exists(&self.rotated_path(self.max_files)).await?;
+        for n in (1..self.max_files).rev() {
+            rename_if_exists(&self.rotated_path(n), &self.rotated_path(n + 1)).await?;
+        }
+        rename_if_exists(&self.path(), &self.rotated_path(1)).await
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditSink for JsonlFileAuditSink {
+    async fn write(&self, record: &AuditRecord) -> Result<(), LlmError> {
+        let mut line = serde_json::to_vec(record)
+            .map_err(|e| LlmError::JsonError(format!("audit record: {e}")))?;
+        line.push(b'\n');
+
+        let mut guard = self.file.lock().await;
+        if guard.is_none() {
+            *guard = Some(self.open().await?);
+        }
+        let needs_rotation = guard
+            .as_ref()
+            .is_some_and(|f| f.size > 0 && f.size + line.len() as u64 > self.max_bytes);
+        if needs_rotation {
+            *guard = None;
+            self.rotate().await?;
+            *guard = Some(self.open().await?);
+        }
+
+        let open = guard.as_mut().expect("audit file opened above");
+        let path = self.path();
+        open.file
+            .write_all(&line)
+            .await
+            .map_err(|e| io_error(&path, e))?;
+        open.file.flush().await.map_err(|e| io_error(&path, e))?;
+        open.size += line.len() as u64;
+        Ok(())
+    }
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> LlmError {
+    LlmError::IoError(format!("audit log {}: {e}", path.display()))
+}
+
+async fn remove_if_exists(path: &Path) -> Result<(), LlmError> {
+    match tokio::fs::remove_file(path).await {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_error(path, e)),
+        _ => Ok(()),
+    }
+}
+
+async fn rename_if_exists(from: &Path, to: &Path) -> Result<(), LlmError> {
+    match tokio::fs::rename(from, to).await {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_error(from, e)),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,308 @@
+use super::*;
+use crate::observability::telemetry::TelemetryConfig;
+use crate::types::{ChatMessage, HttpConfig, HttpResponseInfo, MessageContent, ResponseMetadata};
+use std::collections::HashMap;
+use std::time::Duration;
+
+fn usage(prompt: u32, completion: u32) -> Usage {
+    Usage::builder()
+        .prompt_tokens(prompt)
+        .completion_tokens(completion)
+        .total_tokens(prompt + completion)
+        .build()
+}
+
+fn request() -> ChatRequest {
+    let mut req = ChatRequest::new(vec![
+        ChatMessage::system("You are a support bot.").build(),
+        ChatMessage::user("My card number is 4111").build(),
+    ]);
+    req.common_params.model = "model-a".to_string();
+    let mut http = HttpConfig::empty();
+    http.headers
+        .insert("Authorization".to_string(), "Bearer sk-live".to_string());
+    http.headers
+        .insert("X-Session-Token".to_string(), "tok".to_string());
+    http.headers
+        .insert("X-Trace".to_string(), "trace-1".to_string());
+    req.http_config = Some(http);
+    let mut telemetry = TelemetryConfig {
+        user_id: Some("user-7".to_string()),
+        ..Default::default()
+    };
+    telemetry.metadata.insert(
+        "prompt.template_id".to_string(),
+        "support.reply".to_string(),
+    );
+    req.telemetry = Some(telemetry);
+    req
+}
+
+fn response() -> ChatResponse {
+    let mut resp = ChatResponse::new(MessageContent::Text("Thanks!".to_string()));
+    resp.finish_reason = Some(FinishReason::Stop);
+    resp.usage = Some(usage(12, 3));
+    resp.response = Some(HttpResponseInfo {
+        timestamp: Utc::now(),
+        model_id: Some("model-a".to_string()),
+        headers: HashMap::from([
+            ("x-request-id".to_string(), "req_123".to_string()),
+            ("set-cookie".to_string(), "session=abc".to_string()),
+        ]),
+        body: None,
+    });
+    resp.provider_metadata =
+        Some(serde_json::from_value(serde_json::json!({"acme": {"cached": false}})).unwrap());
+    resp
+}
+
+fn generate_backend(fail: bool) -> Arc<GenerateAsyncFn> {
+    Arc::new(move |_req: ChatRequest| {
+        Box::pin(async move {
+            if fail {
+                Err(LlmError::rate_limit_error("slow down"))
+            } else {
+                Ok(response())
+            }
+        })
+    })
+}
+
+fn stream_backend() -> Arc<StreamAsyncFn> {
+    Arc::new(|_req: ChatRequest| {
+        Box::pin(async move {
+            let events = vec![
+                ChatStreamEvent::StreamStart {
+                    metadata: ResponseMetadata {
+                        id: Some("resp_1".to_string()),
+                        model: Some("model-a".to_string()),
+                        created: None,
+                        provider: "acme".to_string(),
+                        request_id: Some("req_stream".to_string()),
+                        headers: None,
+                        body: None,
+                    },
+                },
+                ChatStreamEvent::text_delta_part("0", "Hel"),
+                ChatStreamEvent::text_delta_part("0", "lo"),
+                ChatStreamEvent::finish_part(usage(5, 2), FinishReason::Stop),
+            ];
+            let stream: ChatStream = Box::pin(futures::stream::iter(events.into_iter().map(Ok)));
+            Ok(stream)
+        })
+    })
+}
+
+#[tokio::test]
+async fn generate_records_redacted_transcript() {
+    let (sink, mut records) = ChannelAuditSink::channel(8);
+    let audit = AuditMiddleware::with_config(
+        Arc::new(sink),
+        AuditConfig::new().with_redaction(
+            AuditRedaction::new()
+                .with_header("x-trace")
+                .with_field("request.messages.*.content"),
+        ),
+    );
+
+    let generate = audit.wrap_generate_async(generate_backend(false));
+    let resp = generate(request()).await.unwrap();
+    assert_eq!(resp.content.all_text(), "Thanks!");
+
+    let record = records.recv().await.unwrap();
+    assert_eq!(record.outcome, AuditOutcome::Success);
+    assert!(!record.stream);
+    assert_eq!(record.provider.as_deref(), Some("acme"));
+    assert_eq!(record.model.as_deref(), Some("model-a"));
+    assert_eq!(record.request_id.as_deref(), Some("req_123"));
+    assert_eq!(record.usage.as_ref().unwrap().total_tokens(), Some(15));
+    assert_eq!(record.user_id.as_deref(), Some("user-7"));
+    assert_eq!(record.metadata["prompt.template_id"], "support.reply");
+
+    let headers = &record.request["http_config"]["headers"];
+    assert_eq!(headers["Authorization"], "[REDACTED]");
+    assert_eq!(headers["X-Session-Token"], "[REDACTED]");
+    assert_eq!(headers["X-Trace"], "[REDACTED]");
+    let transcript = record.request.to_string();
+    assert!(!transcript.contains("4111") && !transcript.contains("sk-live"));
+
+    let response = record.response.as_ref().unwrap();
+    assert_eq!(response["response"]["headers"]["set-cookie"], "[REDACTED]");
+    assert_eq!(response["response"]["headers"]["x-request-id"], "req_123");
+    assert_eq!(response["content"]["Text"], "Thanks!");
+    audit.flush().await;
+    assert_eq!(audit.stats().recorded, 1);
+}
+
+#[tokio::test]
+async fn streams_are_accumulated_and_cancellations_recorded() {
+    let (sink, mut records) = ChannelAuditSink::channel(8);
+    let audit = AuditMiddleware::new(Arc::new(sink));
+    let stream_fn = audit.wrap_stream_async(stream_backend());
+
+    let mut stream = stream_fn(request()).await.unwrap();
+    while stream.next().await.is_some() {}
+    let record = records.recv().await.unwrap();
+    assert_eq!(record.outcome, AuditOutcome::Success);
+    assert!(record.stream);
+    assert_eq!(record.provider.as_deref(), Some("acme"));
+    assert_eq!(record.request_id.as_deref(), Some("req_stream"));
+    assert!(record.time_to_first_event_ms.is_some());
+    assert_eq!(record.usage.as_ref().unwrap().total_tokens(), Some(7));
+    assert_eq!(
+        record.response.as_ref().unwrap()["content"]["Text"],
+        "Hello"
+    );
+
+    // Dropping a stream midway still produces a record with the partial response.
+    let mut stream = stream_fn(request()).await.unwrap();
+    stream.next().await;
+    stream.next().await;
+    drop(stream);
+    let record = tokio::time::timeout(Duration::from_secs(1), records.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(record.outcome, AuditOutcome::Cancelled);
+    assert_eq!(record.response.as_ref().unwrap()["content"]["Text"], "Hel");
+}
+
+#[tokio::test]
+async fn stream_error_events_are_recorded_as_errors() {
+    let (sink, mut records) = ChannelAuditSink::channel(8);
+    let audit = AuditMiddleware::new(Arc::new(sink));
+    let stream_fn = audit.wrap_stream_async(Arc::new(|_req: ChatRequest| {
+        Box::pin(async move {
+            let events = vec![
+                ChatStreamEvent::text_delta_part("0", "Hel"),
+                ChatStreamEvent::Error {
+                    error: "upstream overloaded".to_string(),
+                },
+            ];
+            let stream: ChatStream = Box::pin(futures::stream::iter(events.into_iter().map(Ok)));
+            Ok(stream)
+        })
+    }));
+
+    let events: Vec<_> = stream_fn(request()).await.unwrap().collect().await;
+    assert_eq!(events.len(), 2);
+    let record = records.recv().await.unwrap();
+    assert_eq!(record.outcome, AuditOutcome::Error);
+    assert!(
+        record
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("upstream overloaded")
+    );
+    audit.flush().await;
+    assert!(records.try_recv().is_err());
+}
+
+/// Sink whose writes wait for a permit.
+struct StalledSink(tokio::sync::Semaphore);
+
+#[async_trait::async_trait]
+impl AuditSink for StalledSink {
+    async fn write(&self, _record: &AuditRecord) -> Result<(), LlmError> {
+        self.0.acquire().await.unwrap().forget();
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn slow_sink_does_not_delay_the_call() {
+    let sink = Arc::new(StalledSink(tokio::sync::Semaphore::new(0)));
+    let audit = AuditMiddleware::new(sink.clone());
+
+    let generate = audit.wrap_generate_async(generate_backend(false));
+    tokio::time::timeout(Duration::from_secs(1), generate(request()))
+        .await
+        .expect("generate must not wait for the sink")
+        .unwrap();
+
+    let stream_fn = audit.wrap_stream_async(stream_backend());
+    let events = tokio::time::timeout(Duration::from_secs(1), async {
+        stream_fn(request())
+            .await
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await
+    })
+    .await
+    .expect("stream end must not wait for the sink");
+    assert_eq!(events.len(), 4);
+
+    sink.0.add_permits(2);
+    audit.flush().await;
+    assert_eq!(audit.stats().recorded, 2);
+}
+
+#[tokio::test]
+async fn sampling_keeps_errors_by_default() {
+    let (sink, mut records) = ChannelAuditSink::channel(8);
+    let audit =
+        AuditMiddleware::with_config(Arc::new(sink), AuditConfig::new().with_sample_rate(0.0));
+
+    audit.wrap_generate_async(generate_backend(false))(request())
+        .await
+        .unwrap();
+    let err = audit.wrap_generate_async(generate_backend(true))(request())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, LlmError::RateLimitError { .. }));
+
+    let record = records.recv().await.unwrap();
+    assert_eq!(record.outcome, AuditOutcome::Error);
+    assert!(record.error.as_deref().unwrap().contains("slow down"));
+    audit.flush().await;
+    assert!(records.try_recv().is_err());
+    assert_eq!(
+        audit.stats(),
+        AuditStats {
+            recorded: 1,
+            sampled_out: 1,
+            sink_errors: 0
+        }
+    );
+
+    let (sink, mut records) = ChannelAuditSink::channel(8);
+    let audit = AuditMiddleware::with_config(
+        Arc::new(sink),
+        AuditConfig::new()
+            .with_sample_rate(0.0)
+            .with_always_record_errors(false),
+    );
+    let _ = audit.wrap_generate_async(generate_backend(true))(request()).await;
+    audit.flush().await;
+    assert!(records.try_recv().is_err());
+    assert_eq!(audit.stats().sampled_out, 1);
+}
+
+#[tokio::test]
+async fn jsonl_sink_rotates_by_size() {
+    let dir = tempfile::tempdir().unwrap();
+    let sink = JsonlFileAuditSink::new(dir.path(), "audit")
+        .with_max_bytes(1)
+        .with_max_files(2);
+    let audit = AuditMiddleware::new(Arc::new(sink));
+    let generate = audit.wrap_generate_async(generate_backend(false));
+    for _ in 0..4 {
+        generate(request()).await.unwrap();
+    }
+    audit.flush().await;
+
+    let mut files: Vec<String> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    files.sort();
+    assert_eq!(files, ["audit.1.jsonl", "audit.2.jsonl", "audit.jsonl"]);
+
+    let active = std::fs::read_to_string(dir.path().join("audit.jsonl")).unwrap();
+    let lines: Vec<&str> = active.lines().collect();
+    assert_eq!(lines.len(), 1);
+    let record: AuditRecord = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(record.request_id.as_deref(), Some("req_123"));
+    assert!(!active.contains("sk-live"));
+}
@@ -0,0 +1,525 @@
+//! Guardrails: PII redaction, moderation gates and output filters.
+//!
+//! [`GuardrailsMiddleware`] runs up to three stages around each call:
+//!
+//! 1. **PII redaction**: user and assistant text is scrubbed with a [`PiiRedactor`]; values are
+//!    replaced with placeholders (`[EMAIL_1]`) that are restored in the model output.
+//! 2. **Moderation gate**: the (redacted) user text is checked with any
+//!    [`ModerationCapability`] before the provider is called.
+//! 3. **Output policies**: each [`OutputPolicy`] can allow, flag, rewrite or block the response.
+//!    Streaming text is filtered on a sliding window so partial matches are never emitted.
+//!
+//! Blocked requests and responses fail with [`LlmError::GuardrailViolation`]; flagged and
+//! rewritten output is reported as [`Warning`]s on the final [`ChatResponse`]. Policies run
+//! before placeholders are restored, so they never see the original PII.
+
+use std::collections::{BTreeSet, HashSet};
+use std::sync::Arc;
+
+use futures::StreamExt;
+
+use crate::error::LlmError;
+use crate::execution::middleware::LanguageModelMiddleware;
+use crate::execution::middleware::language_model::{GenerateAsyncFn, StreamAsyncFn};
+use crate::streaming::ChatStream;
+use crate::traits::ModerationCapability;
+use crate::types::{
+    ChatRequest, ChatResponse, ChatStreamEvent, ChatStreamPart, ContentPart, MessageContent,
+    MessageRole, ModerationRequest, ToolResultContentPart, ToolResultOutput, Warning,
+};
+
+mod pii;
+mod policy;
+
+pub use pii::{PiiKind, PiiRedactor, PiiVault};
+pub use policy::{OutputPolicy, PolicyAction, PolicyVerdict, RegexPolicy};
+
+/// Pre-call moderation check backed by a [`ModerationCapability`].
+#[derive(Clone)]
+pub struct ModerationGate {
+    moderator: Arc<dyn ModerationCapability>,
+    model: Option<String>,
+    categories: Option<HashSet<String>>,
+    score_threshold: Option<f32>,
+    fail_open: bool,
+}
+
+impl std::fmt::Debug for ModerationGate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ModerationGate")
+            .field("model", &self.model)
+            .field("categories", &self.categories)
+            .field("score_threshold", &self.score_threshold)
+            .field("fail_open", &self.fail_open)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ModerationGate {
+    /// Block any request the moderator flags.
+    pub fn new(moderator: Arc<dyn ModerationCapability>) -> Self {
+        Self {
+            moderator,
+            model: None,
+            categories: None,
+            score_threshold: None,
+            fail_open: false,
+        }
+    }
+
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Only block when one of these categories fires.
+    pub fn with_categories<I, S>(mut self, categories: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.categories = Some(categories.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Also block when a category score reaches `threshold`, even if the provider did not flag it.
+    pub fn with_score_threshold(mut self, threshold: f32) -> Self {
+        self.score_threshold = Some(threshold);
+        self
+    }
+
+    /// Let requests through when the moderation call itself fails (default: fail closed).
+    pub fn with_fail_open(mut self, fail_open: bool) -> Self {
+        self.fail_open = fail_open;
+        self
+    }
+
+    fn watches(&self, category: &str) -> bool {
+        self.categories
+            .as_ref()
+            .is_none_or(|categories| categories.contains(category))
+    }
+
+    async fn check(&self, input: String) -> Result<(), LlmError> {
+        let request = ModerationRequest {
+            input,
+            inputs: None,
+            model: self.model.clone(),
+        };
+        let response = match self.moderator.moderate(request).await {
+            Ok(response) => response,
+            Err(e) if self.fail_open => {
+                tracing::warn!(error = %e, "guardrail moderation failed; allowing request");
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut hits = BTreeSet::new();
+        let mut flagged = false;
+        for result in &response.results {
+            hits.extend(
+                result
+                    .categories
+                    .iter()
+                    .filter(|(category, on)| **on && self.watches(category))
+                    .map(|(category, _)| category.clone()),
+            );
+            if let Some(threshold) = self.score_threshold {
+                hits.extend(
+                    result
+                        .category_scores
+                        .iter()
+                        .filter(|(category, score)| **score >= threshold && self.watches(category))
+                        .map(|(category, _)| category.clone()),
+                );
+            }
+            flagged |= result.flagged && self.categories.is_none();
+        }
+
+        if hits.is_empty() && !flagged {
+            return Ok(());
+        }
+        let categories: Vec<String> = hits.into_iter().collect();
+        Err(LlmError::GuardrailViolation {
+            stage: "input".to_string(),
+            policy: "moderation".to_string(),
+            message: if categories.is_empty() {
+                "request flagged by moderation".to_string()
+            } else {
+                format!("request flagged by moderation: {}", categories.join(", "))
+            },
+            categories,
+        })
+    }
+}
+
+/// Middleware applying PII redaction, a moderation gate and output policies.
+#[derive(Clone, Default)]
+pub struct GuardrailsMiddleware {
+    pii: Option<Arc<PiiRedactor>>,
+    moderation: Option<Arc<ModerationGate>>,
+    policies: Vec<Arc<dyn OutputPolicy>>,
+}
+
+impl std::fmt::Debug for GuardrailsMiddleware {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GuardrailsMiddleware")
+            .field("pii", &self.pii)
+            .field("moderation", &self.moderation)
+            .field(
+                "policies",
+                &self.policies.iter().map(|p| p.name()).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl GuardrailsMiddleware {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Redact user, assistant and tool content (including tool-call arguments and tool results)
+    /// before it leaves the process.
+    pub fn with_pii_redaction(mut self, redactor: PiiRedactor) -> Self {
+        self.pii = Some(Arc::new(redactor));
+        self
+    }
+
+    /// Gate requests through a moderation model.
+    pub fn with_moderation(mut self, gate: ModerationGate) -> Self {
+        self.moderation = Some(Arc::new(gate));
+        self
+    }
+
+    /// Add an output policy; policies run in registration order.
+    pub fn with_output_policy<P: OutputPolicy + 'static>(mut self, policy: P) -> Self {
+        self.policies.push(Arc::new(policy));
+        self
+    }
+
+    /// Redact the request and run the moderation gate.
+    async fn guard_input(&self, mut req: ChatRequest) -> Result<(ChatRequest, PiiVault), LlmError> {
+        let mut vault = PiiVault::new();
+        if let Some(redactor) = &self.pii {
+            for message in req.messages.iter_mut().filter(|m| {
+                matches!(
+                    m.role,
+                    MessageRole::User | MessageRole::Assistant | MessageRole::Tool
+                )
+            }) {
+                map_text(&mut message.content, |text| {
+                    redactor.redact(text, &mut vault)
+                });
+                redact_tool_parts(&mut message.content, redactor, &mut vault);
+            }
+        }
+
+        if let Some(gate) = &self.moderation {
+            let input = req
+                .messages
+                .iter()
+                .filter(|m| m.role == MessageRole::User)
+                .map(|m| m.content.all_text())
+                .filter(|text| !text.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            if !input.is_empty() {
+                gate.check(input).await?;
+            }
+        }
+
+        Ok((req, vault))
+    }
+
+    fn output_guard(&self, vault: PiiVault) -> OutputGuard {
+        OutputGuard {
+            lookahead: self
+                .policies
+                .iter()
+                .map(|p| p.lookahead())
+                .max()
+                .unwrap_or(0),
+            policies: self.policies.clone(),
+            vault,
+            pending: String::new(),
+            pending_id: String::new(),
+            warnings: Vec::new(),
+            reported: HashSet::new(),
+        }
+    }
+}
+
+impl LanguageModelMiddleware for GuardrailsMiddleware {
+    fn wrap_generate_async(&self, next: Arc<GenerateAsyncFn>) -> Arc<GenerateAsyncFn> {
+        let this = self.clone();
+        Arc::new(move |req: ChatRequest| {
+            let next = Arc::clone(&next);
+            let this = this.clone();
+            Box::pin(async move {
+                let (req, vault) = this.guard_input(req).await?;
+                let resp = next(req).await?;
+                this.output_guard(vault).finish_response(resp)
+            })
+        })
+    }
+
+    fn wrap_stream_async(&self, next: Arc<StreamAsyncFn>) -> Arc<StreamAsyncFn> {
+        let this = self.clone();
+        Arc::new(move |req: ChatRequest| {
+            let next = Arc::clone(&next);
+            let this = this.clone();
+            Box::pin(async move {
+                let (req, vault) = this.guard_input(req).await?;
+                let mut guard = this.output_guard(vault);
+                let mut inner = next(req).await?;
+                let stream: ChatStream = Box::pin(async_stream::stream! {
+                    while let Some(item) = inner.next().await {
+                        let event = match item {
+                            Ok(event) => event,
+                            Err(e) => {
+                                yield Err(e);
+                                continue;
+                            }
+                        };
+                        match guard.on_event(event) {
+                            Ok(events) => {
+                                for event in events {
+                                    yield Ok(event);
+                                }
+                            }
+                            Err(e) => {
+                                yield Err(e);
+                                return;
+                            }
+                        }
+                    }
+                    match guard.flush() {
+                        Ok(events) => {
+                            for event in events {
+                                yield Ok(event);
+                            }
+                        }
+                        Err(e) => yield Err(e),
+                    }
+                });
+                Ok(stream)
+            })
+        })
+    }
+}
+
+/// Per-call output state: policy evaluation, placeholder restoration and streaming hold-back.
+struct OutputGuard {
+    policies: Vec<Arc<dyn OutputPolicy>>,
+    vault: PiiVault,
+    lookahead: usize,
+    pending: String,
+    pending_id: String,
+    warnings: Vec<Warning>,
+    reported: HashSet<String>,
+}
+
+impl OutputGuard {
+    fn report(&mut self, policy: &str, reason: &str) {
+        if self.reported.insert(policy.to_string()) {
+            self.warnings
+                .push(Warning::other(format!("guardrail '{policy}': {reason}")));
+        }
+    }
+
+    /// Run every policy over `text`, returning the (possibly rewritten) text.
+    fn check(&mut self, text: String) -> Result<String, LlmError> {
+        let mut text = text;
+        for policy in self.policies.clone() {
+            match policy.evaluate(&text) {
+                PolicyVerdict::Allow => {}
+                PolicyVerdict::Flag { reason } => self.report(policy.name(), &reason),
+                PolicyVerdict::Rewrite {
+                    text: rewritten,
+                    reason,
+                } => {
+                    self.report(policy.name(), &reason);
+                    text = rewritten;
+                }
+                PolicyVerdict::Block { reason } => {
+                    return Err(LlmError::GuardrailViolation {
+                        stage: "output".to_string(),
+                        policy: policy.name().to_string(),
+                        message: reason,
+                        categories: Vec::new(),
+                    });
+                }
+            }
+        }
+        Ok(text)
+    }
+
+    /// Byte offset up to which buffered text can be emitted without splitting a potential match
+    /// or placeholder.
+    fn safe_split(&self) -> usize {
+        let chars = self.pending.chars().count();
+        if chars <= self.lookahead {
+            return 0;
+        }
+        let mut split = self
+            .pending
+            .char_indices()
+            .nth(chars - self.lookahead)
+            .map_or(self.pending.len(), |(i, _)| i);
+        if !self.vault.is_empty()
+            && let Some(open) = self.pending[..split].rfind('[')
+            && !self.pending[open..split].contains(']')
+        {
+            split = open;
+        }
+        split
+    }
+
+    fn emit(&mut self, upto: usize) -> Option<ChatStreamEvent> {
+        if upto == 0 {
+            return None;
+        }
+        let chunk: String = self.pending.drain(..upto).collect();
+        Some(ChatStreamEvent::text_delta_part(
+            self.pending_id.clone(),
+            self.vault.restore(&chunk),
+        ))
+    }
+
+    fn flush(&mut self) -> Result<Vec<ChatStreamEvent>, LlmError> {
+        if self.pending.is_empty() {
+            return Ok(Vec::new());
+        }
+        let pending = std::mem::take(&mut self.pending);
+        self.pending = self.check(pending)?;
+        Ok(self.emit(self.pending.len()).into_iter().collect())
+    }
+
+    fn on_event(&mut self, event: ChatStreamEvent) -> Result<Vec<ChatStreamEvent>, LlmError> {
+        let delta = match event.part_ref() {
+            Some(ChatStreamPart::TextDelta { id, delta, .. }) => Some((id.clone(), delta.clone())),
+            _ => None,
+        };
+
+        let Some((id, delta)) = delta else {
+            let mut out = self.flush()?;
+            match event {
+                ChatStreamEvent::StreamEnd { response } => {
+                    out.push(ChatStreamEvent::StreamEnd {
+                        response: self.finish_response(response)?,
+                    });
+                }
+                other => out.push(other),
+            }
+            return Ok(out);
+        };
+
+        let mut out = Vec::new();
+        if self.pending_id != id {
+            out = self.flush()?;
+            self.pending_id = id;
+        }
+        let mut pending = std::mem::take(&mut self.pending);
+        pending.push_str(&delta);
+        self.pending = self.check(pending)?;
+        let split = self.safe_split();
+        out.extend(self.emit(split));
+        Ok(out)
+    }
+
+    /// Apply policies and restore placeholders on a complete response.
+    fn finish_response(&mut self, mut resp: ChatResponse) -> Result<ChatResponse, LlmError> {
+        let mut failure = None;
+        map_text(&mut resp.content, |text| {
+            match self.check(text.to_string()) {
+                Ok(checked) => self.vault.restore(&checked),
+                Err(e) => {
+                    failure.get_or_insert(e);
+                    String::new()
+                }
+            }
+        });
+        if let Some(e) = failure {
+            return Err(e);
+        }
+
+        if let MessageContent::MultiModal(parts) = &mut resp.content {
+            for part in parts {
+                if let ContentPart::ToolCall { arguments, .. } = part {
+                    self.vault.restore_json(arguments);
+                }
+            }
+        }
+
+        if !self.warnings.is_empty() {
+            resp.warnings
+                .get_or_insert_with(Vec::new)
+                .append(&mut self.warnings);
+        }
+        Ok(resp)
+    }
+}
+
+/// Redact tool-call arguments and tool results, which `map_text` does not visit.
+///
+/// Placeholders restored into response tool calls come back on the next turn, so they must be
+/// redacted again with the same vault.
+fn redact_tool_parts(content: &mut MessageContent, redactor: &PiiRedactor, vault: &mut PiiVault) {
+    let MessageContent::MultiModal(parts) = content else {
+        return;
+    };
+    for part in parts {
+        match part {
+            ContentPart::ToolCall { arguments, .. } => redactor.redact_json(arguments, vault),
+            ContentPart::ToolResult { output, input, .. } => {
+                if let Some(input) = input {
+                    redactor.redact_json(input, vault);
+                }
+                match output {
+                    ToolResultOutput::Text { value, .. }
+                    | ToolResultOutput::ErrorText { value, .. } => {
+                        *value = redactor.redact(value, vault);
+                    }
+                    ToolResultOutput::Json { value, .. }
+                    | ToolResultOutput::ErrorJson { value, .. } => {
+                        redactor.redact_json(value, vault);
+                    }
+                    ToolResultOutput::Content { value, .. } => {
+                        for item in value {
+                            if let ToolResultContentPart::Text { text, .. } = item {
+                                *text = redactor.redact(text, vault);
+                            }
+                        }
+                    }
+                    ToolResultOutput::ExecutionDenied { reason, .. } => {
+                        if let Some(reason) = reason {
+                            *reason = redactor.redact(reason, vault);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Rewrite every text segment of a message in place.
+fn map_text(content: &mut MessageContent, mut f: impl FnMut(&str) -> String) {
+    match content {
+        MessageContent::Text(text) => *text = f(text),
+        MessageContent::MultiModal(parts) => {
+            for part in parts {
+                if let ContentPart::Text { text, .. } = part {
+                    *text = f(text);
+                }
+            }
+        }
+        #[cfg(feature = "structured-messages")]
+        MessageContent::Json(_) => {}
+    }
+}
+
+#[cfg(test)]
+mod tests;
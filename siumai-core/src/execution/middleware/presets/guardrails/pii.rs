@@ -0,0 +1,311 @@
+//! Reversible PII redaction.
+//!
+//! Detected values are replaced with numbered placeholders such as `[EMAIL_1]`. The mapping is
+//! kept in a per-request [`PiiVault`] so placeholders echoed by the model (in text or tool-call
+//! arguments) can be restored before the response reaches the caller.
+
+use std::collections::HashMap;
+
+use regex::Regex;
+
+use crate::error::LlmError;
+
+/// Category of a detected PII value; determines the placeholder label.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PiiKind {
+    Email,
+    Phone,
+    /// Payment card numbers (Luhn-validated).
+    CardNumber,
+    /// User-defined pattern; the name becomes the placeholder label.
+    Custom(String),
+}
+
+impl PiiKind {
+    fn label(&self) -> String {
+        match self {
+            Self::Email => "EMAIL".to_string(),
+            Self::Phone => "PHONE".to_string(),
+            Self::CardNumber => "CARD".to_string(),
+            Self::Custom(name) => name
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() {
+                        c.to_ascii_uppercase()
+                    } else {
+                        '_'
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PiiPattern {
+    kind: PiiKind,
+    regex: Regex,
+}
+
+/// Detects PII with regular expressions and swaps it for vault placeholders.
+#[derive(Debug, Clone)]
+pub struct PiiRedactor {
+    patterns: Vec<PiiPattern>,
+}
+
+impl Default for PiiRedactor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PiiRedactor {
+    /// Redactor with the built-in card number, email and phone detectors.
+    pub fn new() -> Self {
+        Self::empty()
+            .with_kind(PiiKind::CardNumber)
+            .with_kind(PiiKind::Email)
+            .with_kind(PiiKind::Phone)
+    }
+
+    /// Redactor without any detector; add them with [`with_kind`](Self::with_kind) or
+    /// [`with_custom`](Self::with_custom).
+    pub fn empty() -> Self {
+        Self {
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Enable a built-in detector. Custom kinds need [`with_custom`](Self::with_custom).
+    pub fn with_kind(mut self, kind: PiiKind) -> Self {
+        let pattern = match kind {
+            PiiKind::Email => r"(?i)\b[a-z0-9._%+-]+@[a-z0-9.-]+\.[a-z]{2,}\b",
+            PiiKind::Phone => {
+                r"(?:\+\d{1,3}[\s.-]?)?(?:\(\d{1,4}\)[\s.-]?)?\d{2,4}[\s.-]\d{3,4}(?:[\s.-]\d{2,4})?\b"
+            }
+            PiiKind::CardNumber => r"\b(?:\d[ -]?){12,18}\d\b",
+            PiiKind::Custom(_) => return self,
+        };
+        self.patterns.retain(|p| p.kind != kind);
+        self.patterns.push(PiiPattern {
+            kind,
+            regex: Regex::new(pattern).expect("built-in PII pattern is valid"),
+        });
+        self
+    }
+
+    /// Add a custom detector; matches are replaced with `[<NAME>_<n>]`.
+    pub fn with_custom(mut self, name: impl Into<String>, pattern: &str) -> Result<Self, LlmError> {
+        let regex = Regex::new(pattern).map_err(|e| {
+            LlmError::ConfigurationError(format!("invalid PII pattern '{pattern}': {e}"))
+        })?;
+        self.patterns.push(PiiPattern {
+            kind: PiiKind::Custom(name.into()),
+            regex,
+        });
+        Ok(self)
+    }
+
+    /// Replace every detected value in `text`, recording the mapping in `vault`.
+    pub fn redact(&self, text: &str, vault: &mut PiiVault) -> String {
+        let mut out = text.to_string();
+        for pattern in &self.patterns {
+            if !pattern.regex.is_match(&out) {
+                continue;
+            }
+            out = pattern
+                .regex
+                .replace_all(&out, |caps: &regex::Captures<'_>| {
+                    let value = &caps[0];
+                    if is_valid(&pattern.kind, value) {
+                        vault.placeholder_for(&pattern.kind, value)
+                    } else {
+                        value.to_string()
+                    }
+                })
+                .into_owned();
+        }
+        out
+    }
+
+    /// Redact every string inside a JSON value (tool arguments and results).
+    pub fn redact_json(&self, value: &mut serde_json::Value, vault: &mut PiiVault) {
+        match value {
+            serde_json::Value::String(s) => *s = self.redact(s, vault),
+            serde_json::Value::Array(items) => {
+                items.iter_mut().for_each(|v| self.redact_json(v, vault))
+            }
+            serde_json::Value::Object(map) => {
+                map.values_mut().for_each(|v| self.redact_json(v, vault))
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_valid(kind: &PiiKind, value: &str) -> bool {
+    let digits: Vec<u32> = value.chars().filter_map(|c| c.to_digit(10)).collect();
+    match kind {
+        PiiKind::Phone => (7..=15).contains(&digits.len()),
+        PiiKind::CardNumber => (13..=19).contains(&digits.len()) && luhn(&digits),
+        _ => true,
+    }
+}
+
+fn luhn(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                d
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Per-request mapping between placeholders and the original values.
+#[derive(Debug, Clone, Default)]
+pub struct PiiVault {
+    originals: HashMap<String, String>,
+    placeholders: HashMap<String, String>,
+    counters: HashMap<String, usize>,
+}
+
+impl PiiVault {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Placeholder for `value`; the same value always maps to the same placeholder.
+    pub fn placeholder_for(&mut self, kind: &PiiKind, value: &str) -> String {
+        if let Some(existing) = self.placeholders.get(value) {
+            return existing.clone();
+        }
+        let label = kind.label();
+        let counter = self.counters.entry(label.clone()).or_default();
+        *counter += 1;
+        let placeholder = format!("[{label}_{counter}]");
+        self.placeholders
+            .insert(value.to_string(), placeholder.clone());
+        self.originals
+            .insert(placeholder.clone(), value.to_string());
+        placeholder
+    }
+
+    /// Original value behind a placeholder.
+    pub fn original(&self, placeholder: &str) -> Option<&str> {
+        self.originals.get(placeholder).map(String::as_str)
+    }
+
+    /// Number of redacted values.
+    pub fn len(&self) -> usize {
+        self.originals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.originals.is_empty()
+    }
+
+    /// Replace known placeholders in `text` with their original values.
+    pub fn restore(&self, text: &str) -> String {
+        if self.is_empty() || !text.contains('[') {
+            return text.to_string();
+        }
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find('[') {
+            out.push_str(&rest[..start]);
+            let tail = &rest[start..];
+            match tail
+                .find(']')
+                .and_then(|end| self.original(&tail[..=end]).map(|v| (end, v)))
+            {
+                Some((end, original)) => {
+                    out.push_str(original);
+                    rest = &tail[end + 1..];
+                }
+                None => {
+                    out.push('[');
+                    rest = &tail[1..];
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+
+    /// Restore placeholders inside every string of a JSON value (e.g. tool-call arguments).
+    pub fn restore_json(&self, value: &mut serde_json::Value) {
+        if self.is_empty() {
+            return;
+        }
+        match value {
+            serde_json::Value::String(s) => *s = self.restore(s),
+            serde_json::Value::Array(items) => items.iter_mut().for_each(|v| self.restore_json(v)),
+            serde_json::Value::Object(map) => map.values_mut().for_each(|v| self.restore_json(v)),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redacts_and_restores_builtin_kinds() {
+        let redactor = PiiRedactor::new();
+        let mut vault = PiiVault::new();
+        let text = "Mail ada@example.com or call +1 415-555-0100; card 4111 1111 1111 1111. \
+                    Again: ada@example.com";
+
+        let redacted = redactor.redact(text, &mut vault);
+        assert_eq!(
+            redacted,
+            "Mail [EMAIL_1] or call [PHONE_1]; card [CARD_1]. Again: [EMAIL_1]"
+        );
+        assert_eq!(vault.len(), 3);
+        assert_eq!(vault.restore(&redacted), text);
+    }
+
+    #[test]
+    fn skips_numbers_failing_validation() {
+        let redactor = PiiRedactor::new();
+        let mut vault = PiiVault::new();
+        // Card-shaped but fails the Luhn check.
+        let text = "order 1234567890123456";
+        assert_eq!(redactor.redact(text, &mut vault), text);
+        assert!(vault.is_empty());
+    }
+
+    #[test]
+    fn custom_patterns_use_their_name_as_label() {
+        let redactor = PiiRedactor::empty()
+            .with_custom("employee id", r"\bEMP-\d{5}\b")
+            .unwrap();
+        let mut vault = PiiVault::new();
+        assert_eq!(
+            redactor.redact("ticket for EMP-00042", &mut vault),
+            "ticket for [EMPLOYEE_ID_1]"
+        );
+        assert!(PiiRedactor::empty().with_custom("bad", "(").is_err());
+    }
+
+    #[test]
+    fn restores_placeholders_in_json_and_keeps_unknown_brackets() {
+        let mut vault = PiiVault::new();
+        let placeholder = vault.placeholder_for(&PiiKind::Email, "ada@example.com");
+        let mut args = serde_json::json!({ "to": [placeholder], "note": "[draft]" });
+        vault.restore_json(&mut args);
+        assert_eq!(
+            args,
+            serde_json::json!({ "to": ["ada@example.com"], "note": "[draft]" })
+        );
+    }
+}
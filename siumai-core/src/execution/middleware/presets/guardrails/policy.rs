@@ -0,0 +1,181 @@
+//! Output policies applied to model text.
+
+use regex::Regex;
+
+use crate::error::LlmError;
+
+/// Outcome of evaluating an [`OutputPolicy`] against a piece of text.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PolicyVerdict {
+    /// Nothing to report.
+    Allow,
+    /// Keep the text but record a warning.
+    Flag { reason: String },
+    /// Replace the text and record a warning.
+    Rewrite { text: String, reason: String },
+    /// Reject the output with [`LlmError::GuardrailViolation`].
+    Block { reason: String },
+}
+
+/// A check run on model output.
+///
+/// Streaming output is evaluated on a sliding buffer: the last
+/// [`lookahead`](OutputPolicy::lookahead) characters are held back until more text arrives, so a
+/// match split across deltas is never emitted half-way.
+pub trait OutputPolicy: Send + Sync {
+    /// Policy name reported in warnings and errors.
+    fn name(&self) -> &str;
+
+    /// Evaluate `text`.
+    fn evaluate(&self, text: &str) -> PolicyVerdict;
+
+    /// Longest span (in characters) a single match can cover.
+    fn lookahead(&self) -> usize {
+        64
+    }
+}
+
+/// What a [`RegexPolicy`] does on a match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyAction {
+    Block,
+    /// Replace every match; `$n` capture references are expanded.
+    Rewrite(String),
+    Flag,
+}
+
+/// Regex-based output policy.
+#[derive(Debug, Clone)]
+pub struct RegexPolicy {
+    name: String,
+    regex: Regex,
+    action: PolicyAction,
+    lookahead: usize,
+}
+
+impl RegexPolicy {
+    /// Policy blocking any output that matches `pattern`.
+    pub fn new(name: impl Into<String>, pattern: &str) -> Result<Self, LlmError> {
+        let regex = Regex::new(pattern).map_err(|e| {
+            LlmError::ConfigurationError(format!("invalid guardrail pattern '{pattern}': {e}"))
+        })?;
+        Ok(Self {
+            name: name.into(),
+            regex,
+            action: PolicyAction::Block,
+            lookahead: 64,
+        })
+    }
+
+    /// Policy matching any of `terms` as whole words, case-insensitively.
+    ///
+    /// Fails when `terms` is empty or contains an empty term, which would match everything.
+    pub fn terms(name: impl Into<String>, terms: &[&str]) -> Result<Self, LlmError> {
+        let name = name.into();
+        if terms.is_empty() || terms.iter().any(|t| t.trim().is_empty()) {
+            return Err(LlmError::ConfigurationError(format!(
+                "guardrail term policy '{name}' needs at least one non-empty term"
+            )));
+        }
+        let alternation = terms
+            .iter()
+            .map(|t| regex::escape(t))
+            .collect::<Vec<_>>()
+            .join("|");
+        let mut policy = Self::new(name, &format!(r"(?i)\b(?:{alternation})\b"))?;
+        policy.lookahead = terms.iter().map(|t| t.chars().count()).max().unwrap_or(0) + 1;
+        Ok(policy)
+    }
+
+    pub fn block(mut self) -> Self {
+        self.action = PolicyAction::Block;
+        self
+    }
+
+    pub fn rewrite(mut self, replacement: impl Into<String>) -> Self {
+        self.action = PolicyAction::Rewrite(replacement.into());
+        self
+    }
+
+    pub fn flag(mut self) -> Self {
+        self.action = PolicyAction::Flag;
+        self
+    }
+
+    /// Override the streaming hold-back window (see [`OutputPolicy::lookahead`]).
+    pub fn with_lookahead(mut self, chars: usize) -> Self {
+        self.lookahead = chars;
+        self
+    }
+}
+
+impl OutputPolicy for RegexPolicy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn evaluate(&self, text: &str) -> PolicyVerdict {
+        if !self.regex.is_match(text) {
+            return PolicyVerdict::Allow;
+        }
+        let reason = format!("output matched policy '{}'", self.name);
+        match &self.action {
+            PolicyAction::Block => PolicyVerdict::Block { reason },
+            PolicyAction::Flag => PolicyVerdict::Flag { reason },
+            PolicyAction::Rewrite(replacement) => PolicyVerdict::Rewrite {
+                text: self
+                    .regex
+                    .replace_all(text, replacement.as_str())
+                    .into_owned(),
+                reason,
+            },
+        }
+    }
+
+    fn lookahead(&self) -> usize {
+        self.lookahead
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regex_policy_actions() {
+        let text = "the launch code is 0000";
+        let block = RegexPolicy::new("codes", r"code is \d+").unwrap();
+        assert!(matches!(block.evaluate(text), PolicyVerdict::Block { .. }));
+        assert_eq!(block.evaluate("hello"), PolicyVerdict::Allow);
+
+        let rewrite = block.clone().rewrite("code is [withheld]");
+        let PolicyVerdict::Rewrite { text, .. } = rewrite.evaluate(text) else {
+            panic!("expected rewrite");
+        };
+        assert_eq!(text, "the launch code is [withheld]");
+
+        assert!(matches!(
+            block.flag().evaluate("code is 1"),
+            PolicyVerdict::Flag { .. }
+        ));
+    }
+
+    #[test]
+    fn term_policy_matches_whole_words_case_insensitively() {
+        let policy = RegexPolicy::terms("competitors", &["Acme", "Globex"]).unwrap();
+        assert!(matches!(
+            policy.evaluate("try ACME instead"),
+            PolicyVerdict::Block { .. }
+        ));
+        assert_eq!(policy.evaluate("acmes are fine"), PolicyVerdict::Allow);
+        assert_eq!(policy.lookahead(), 7);
+    }
+
+    #[test]
+    fn term_policy_rejects_empty_terms() {
+        for terms in [&[][..], &["secret", ""][..], &["  "][..]] {
+            let err = RegexPolicy::terms("words", terms).unwrap_err();
+            assert!(matches!(err, LlmError::ConfigurationError(_)), "{err:?}");
+        }
+    }
+}
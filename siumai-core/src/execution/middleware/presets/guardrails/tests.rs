@@ -0,0 +1,297 @@
+use super::*;
+use crate::types::{ChatMessage, FinishReason, ModerationResponse, ModerationResult};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+fn request(text: &str) -> ChatRequest {
+    let mut req = ChatRequest::new(vec![
+        ChatMessage::system("Contact: support@example.com").build(),
+        ChatMessage::user(text).build(),
+    ]);
+    req.common_params.model = "model-a".to_string();
+    req
+}
+
+/// Echoes the last user message and records what the provider saw.
+fn echo_backend(seen: Arc<Mutex<Vec<ChatRequest>>>) -> Arc<GenerateAsyncFn> {
+    Arc::new(move |req: ChatRequest| {
+        let seen = Arc::clone(&seen);
+        Box::pin(async move {
+            let text = req.messages.last().unwrap().content.all_text();
+            seen.lock().unwrap().push(req);
+            let mut resp = ChatResponse::new(MessageContent::Text(format!("You said: {text}")));
+            resp.finish_reason = Some(FinishReason::Stop);
+            Ok(resp)
+        })
+    })
+}
+
+fn stream_backend(deltas: &'static [&'static str]) -> Arc<StreamAsyncFn> {
+    Arc::new(move |_req: ChatRequest| {
+        Box::pin(async move {
+            let text: String = deltas.concat();
+            let mut events: Vec<Result<ChatStreamEvent, LlmError>> = deltas
+                .iter()
+                .map(|d| Ok(ChatStreamEvent::text_delta_part("0", *d)))
+                .collect();
+            let mut resp = ChatResponse::new(MessageContent::Text(text));
+            resp.finish_reason = Some(FinishReason::Stop);
+            events.push(Ok(ChatStreamEvent::StreamEnd { response: resp }));
+            Ok(Box::pin(futures::stream::iter(events)) as ChatStream)
+        })
+    })
+}
+
+struct KeywordModerator;
+
+#[async_trait]
+impl ModerationCapability for KeywordModerator {
+    async fn moderate(&self, request: ModerationRequest) -> Result<ModerationResponse, LlmError> {
+        let violent = request.input.contains("attack");
+        Ok(ModerationResponse {
+            results: vec![ModerationResult {
+                flagged: violent,
+                categories: HashMap::from([
+                    ("violence".to_string(), violent),
+                    ("hate".to_string(), false),
+                ]),
+                category_scores: HashMap::from([
+                    ("violence".to_string(), if violent { 0.9 } else { 0.01 }),
+                    ("hate".to_string(), 0.4),
+                ]),
+            }],
+            model: "moderation-test".to_string(),
+        })
+    }
+}
+
+#[tokio::test]
+async fn redacts_input_and_restores_output() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let mw = GuardrailsMiddleware::new().with_pii_redaction(PiiRedactor::new());
+    let generate = mw.wrap_generate_async(echo_backend(Arc::clone(&seen)));
+
+    let resp = generate(request("Email me at ada@example.com"))
+        .await
+        .unwrap();
+
+    let sent = seen.lock().unwrap()[0].clone();
+    assert_eq!(sent.messages[1].content.all_text(), "Email me at [EMAIL_1]");
+    // System prompts are left alone.
+    assert_eq!(
+        sent.messages[0].content.all_text(),
+        "Contact: support@example.com"
+    );
+    assert_eq!(
+        resp.content.all_text(),
+        "You said: Email me at ada@example.com"
+    );
+}
+
+#[tokio::test]
+async fn tool_turns_never_send_raw_pii() {
+    let seen = Arc::new(Mutex::new(Vec::<ChatRequest>::new()));
+    let mw = GuardrailsMiddleware::new().with_pii_redaction(PiiRedactor::new());
+    let backend_seen = Arc::clone(&seen);
+    let backend: Arc<GenerateAsyncFn> = Arc::new(move |req: ChatRequest| {
+        let seen = Arc::clone(&backend_seen);
+        Box::pin(async move {
+            let turn = {
+                let mut seen = seen.lock().unwrap();
+                seen.push(req.clone());
+                seen.len()
+            };
+            let mut resp = if turn == 1 {
+                // The model echoes the placeholder into a tool call.
+                ChatResponse::new(MessageContent::MultiModal(vec![ContentPart::tool_call(
+                    "call_1",
+                    "lookup_customer",
+                    serde_json::json!({ "email": "[EMAIL_1]" }),
+                    None,
+                )]))
+            } else {
+                ChatResponse::new(MessageContent::Text("Done.".to_string()))
+            };
+            resp.finish_reason = Some(FinishReason::Stop);
+            Ok(resp)
+        })
+    });
+    let generate = mw.wrap_generate_async(backend);
+
+    let mut req = request("Find the account for ada@example.com");
+    let first = generate(req.clone()).await.unwrap();
+    // The caller's tool receives the real address...
+    let ContentPart::ToolCall { arguments, .. } = first.tool_calls()[0].clone() else {
+        panic!("expected a tool call");
+    };
+    assert_eq!(arguments["email"], "ada@example.com");
+
+    // ...and the next turn replays it, plus a tool result carrying more PII.
+    req.messages.push(
+        ChatMessage::assistant_with_content(vec![ContentPart::tool_call(
+            "call_1",
+            "lookup_customer",
+            arguments,
+            None,
+        )])
+        .build(),
+    );
+    req.messages.push(
+        ChatMessage::tool_result_json(
+            "call_1",
+            "lookup_customer",
+            serde_json::json!({ "email": "ada@example.com", "phone": "+1 415-555-0100" }),
+        )
+        .build(),
+    );
+    req.messages
+        .push(ChatMessage::tool_result_text("call_2", "notes", "Backup: bob@example.com").build());
+    generate(req).await.unwrap();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 2);
+    let second = serde_json::to_string(&seen[1].messages[1..]).unwrap();
+    for raw in ["ada@example.com", "415-555-0100", "bob@example.com"] {
+        assert!(!second.contains(raw), "{raw} reached the model: {second}");
+    }
+    assert!(second.contains("[EMAIL_1]"));
+}
+
+#[tokio::test]
+async fn moderation_gate_blocks_flagged_requests() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let mw = GuardrailsMiddleware::new()
+        .with_moderation(ModerationGate::new(Arc::new(KeywordModerator)));
+    let generate = mw.wrap_generate_async(echo_backend(Arc::clone(&seen)));
+
+    let err = generate(request("plan an attack")).await.unwrap_err();
+    let LlmError::GuardrailViolation {
+        stage,
+        policy,
+        categories,
+        ..
+    } = err
+    else {
+        panic!("expected guardrail violation, got {err:?}");
+    };
+    assert_eq!((stage.as_str(), policy.as_str()), ("input", "moderation"));
+    assert_eq!(categories, vec!["violence".to_string()]);
+    assert!(seen.lock().unwrap().is_empty());
+
+    assert!(generate(request("plan a picnic")).await.is_ok());
+
+    // Score thresholds can be stricter than the provider's own flags.
+    let strict = GuardrailsMiddleware::new().with_moderation(
+        ModerationGate::new(Arc::new(KeywordModerator))
+            .with_categories(["hate"])
+            .with_score_threshold(0.3),
+    );
+    let generate = strict.wrap_generate_async(echo_backend(seen));
+    assert!(matches!(
+        generate(request("plan a picnic")).await,
+        Err(LlmError::GuardrailViolation { .. })
+    ));
+}
+
+#[tokio::test]
+async fn output_policies_block_rewrite_and_flag() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+
+    let blocking = GuardrailsMiddleware::new()
+        .with_output_policy(RegexPolicy::terms("competitors", &["Globex"]).unwrap());
+    let generate = blocking.wrap_generate_async(echo_backend(Arc::clone(&seen)));
+    assert!(matches!(
+        generate(request("try Globex")).await,
+        Err(LlmError::GuardrailViolation { ref stage, .. }) if stage == "output"
+    ));
+
+    let rewriting = GuardrailsMiddleware::new()
+        .with_output_policy(
+            RegexPolicy::terms("competitors", &["Globex"])
+                .unwrap()
+                .rewrite("[redacted]"),
+        )
+        .with_output_policy(RegexPolicy::new("mentions", "said").unwrap().flag());
+    let generate = rewriting.wrap_generate_async(echo_backend(seen));
+    let resp = generate(request("try Globex")).await.unwrap();
+    assert_eq!(resp.content.all_text(), "You said: try [redacted]");
+    let warnings = resp.warnings.unwrap();
+    assert_eq!(warnings.len(), 2);
+    assert!(matches!(&warnings[0], Warning::Other { message } if message.contains("competitors")));
+}
+
+#[tokio::test]
+async fn streaming_rewrites_matches_split_across_deltas() {
+    let mw = GuardrailsMiddleware::new().with_output_policy(
+        RegexPolicy::new("codes", r"code \d{4}")
+            .unwrap()
+            .rewrite("code ****")
+            .with_lookahead(9),
+    );
+    let stream = mw.wrap_stream_async(stream_backend(&[
+        "Your access co",
+        "de 12",
+        "34 is ready. Enjoy the rest of your day.",
+    ]));
+
+    let events: Vec<ChatStreamEvent> = stream(request("code?"))
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    let streamed: String = events.iter().filter_map(|e| e.text_delta()).collect();
+    assert_eq!(
+        streamed,
+        "Your access code **** is ready. Enjoy the rest of your day."
+    );
+    for event in &events {
+        assert!(!event.text_delta().unwrap_or_default().contains("12"));
+    }
+    let Some(ChatStreamEvent::StreamEnd { response }) = events.last() else {
+        panic!("expected StreamEnd");
+    };
+    assert_eq!(response.content.all_text(), streamed);
+    assert_eq!(response.warnings.as_ref().map(Vec::len), Some(1));
+}
+
+#[tokio::test]
+async fn streaming_block_ends_with_error_and_restores_placeholders() {
+    let blocking = GuardrailsMiddleware::new()
+        .with_output_policy(RegexPolicy::terms("secrets", &["password"]).unwrap());
+    let stream = blocking.wrap_stream_async(stream_backend(&["Sure, the pass", "word is hunter2"]));
+    let items: Vec<Result<ChatStreamEvent, LlmError>> =
+        stream(request("hi")).await.unwrap().collect().await;
+    assert!(matches!(
+        items.last(),
+        Some(Err(LlmError::GuardrailViolation { .. }))
+    ));
+    let leaked: String = items
+        .iter()
+        .filter_map(|i| i.as_ref().ok().and_then(|e| e.text_delta()))
+        .collect();
+    assert!(!leaked.contains("pass"));
+
+    let redacting = GuardrailsMiddleware::new().with_pii_redaction(PiiRedactor::new());
+    let stream = redacting.wrap_stream_async(Arc::new(|req: ChatRequest| {
+        Box::pin(async move {
+            // The provider only ever sees the placeholder and echoes it back in pieces.
+            assert!(req.messages[1].content.all_text().contains("[EMAIL_1]"));
+            let events = vec![
+                Ok(ChatStreamEvent::text_delta_part("0", "Sending to [EMA")),
+                Ok(ChatStreamEvent::text_delta_part("0", "IL_1] now")),
+            ];
+            Ok(Box::pin(futures::stream::iter(events)) as ChatStream)
+        })
+    }));
+    let text: String = stream(request("mail ada@example.com"))
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .filter_map(|e| async move { e.text_delta().map(str::to_string) })
+        .collect()
+        .await;
+    assert_eq!(text, "Sending to ada@example.com now");
+}
@@ -3,8 +3,16 @@
 //! This module provides commonly used middleware implementations that are
 //! ready to use out of the box.
 
+pub mod audit;
 pub mod extract_reasoning;
+pub mod guardrails;
+pub mod prompt_cache;
+pub mod semantic_cache;
 pub mod system_message_mode_warning;
 
+pub use audit::*;
 pub use extract_reasoning::*;
+pub use guardrails::*;
+pub use prompt_cache::*;
+pub use semantic_cache::*;
 pub use system_message_mode_warning::*;
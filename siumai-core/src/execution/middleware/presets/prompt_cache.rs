@@ -0,0 +1,573 @@
+//! Automatic prompt-cache breakpoint placement.
+//!
+//! Providers with explicit prompt caching cache the prompt prefix up to each marked block
+//! (for example `cache_control` or `cachePoint` markers). Marking breakpoints by hand on every
+//! request is tedious, so this middleware places them automatically on:
+//!
+//! 1. the tool definitions,
+//! 2. the leading system prompt, and
+//! 3. the longest stable conversation prefix (the final message, plus the previous user turn
+//!    so the prefix written by the last request is read back).
+//!
+//! A breakpoint is only placed when the cached prefix is large enough for the provider to
+//! cache it. Cache read/write tokens reported in `Usage` are accumulated into
+//! [`PromptCacheStats`] so hit rates can be measured.
+//!
+//! The middleware is provider-agnostic: providers contribute a [`PromptCacheMarker`] that
+//! knows their provider options namespace, marker shape and limits.
+
+use std::sync::{Arc, Mutex};
+
+use crate::error::LlmError;
+use crate::execution::middleware::LanguageModelMiddleware;
+use crate::types::{ChatMessage, ChatRequest, ChatResponse, MessageRole, Tool, Usage};
+
+/// Prompt-cache lifetime requested for placed breakpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptCacheTtl {
+    /// Short-lived cache (typically 5 minutes).
+    FiveMinutes,
+    /// Extended cache (typically 1 hour).
+    OneHour,
+}
+
+impl PromptCacheTtl {
+    /// Wire representation used by providers that accept duration strings.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::FiveMinutes => "5m",
+            Self::OneHour => "1h",
+        }
+    }
+}
+
+/// Request element that can carry a prompt-cache breakpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptCacheTarget {
+    /// Function tool definitions.
+    Tools,
+    /// Leading system messages.
+    System,
+    /// Conversation messages.
+    Message,
+}
+
+/// Breakpoint chosen by [`PromptCacheStrategy::plan`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptCacheBreakpoint {
+    /// Breakpoint on `ChatRequest.tools[index]`.
+    Tool(usize),
+    /// Breakpoint on `ChatRequest.messages[index]`.
+    Message(usize),
+}
+
+/// Provider-specific prompt-cache marker.
+///
+/// Implementations describe how a breakpoint is expressed in request-level
+/// `provider_options` (message options and function tool options).
+pub trait PromptCacheMarker: Send + Sync {
+    /// Provider options namespace receiving the marker.
+    fn namespace(&self) -> &str;
+
+    /// Provider option entries that mark one breakpoint.
+    ///
+    /// The entries are merged into the namespace object of the marked message or tool.
+    fn marker(&self, ttl: Option<PromptCacheTtl>) -> serde_json::Map<String, serde_json::Value>;
+
+    /// Whether `options` (the namespace object of a message or tool) already carries a marker.
+    fn has_marker(&self, options: &serde_json::Map<String, serde_json::Value>) -> bool {
+        self.marker(None).keys().any(|k| options.contains_key(k))
+    }
+
+    /// Whether the provider accepts breakpoints on `target`.
+    fn supports(&self, _target: PromptCacheTarget) -> bool {
+        true
+    }
+
+    /// Maximum number of breakpoints per request.
+    fn max_breakpoints(&self) -> usize {
+        4
+    }
+
+    /// Minimum cacheable prefix size (in estimated tokens) for `model`.
+    fn min_cacheable_tokens(&self, _model: &str) -> u32 {
+        1024
+    }
+}
+
+/// Placement strategy for automatic prompt-cache breakpoints.
+#[derive(Debug, Clone)]
+pub struct PromptCacheStrategy {
+    /// Place a breakpoint after the tool definitions.
+    pub cache_tools: bool,
+    /// Place a breakpoint after the leading system prompt.
+    pub cache_system: bool,
+    /// Place breakpoints on the stable conversation prefix.
+    pub cache_conversation: bool,
+    /// Optional cache TTL attached to placed breakpoints.
+    pub ttl: Option<PromptCacheTtl>,
+    /// Override for the provider's per-model minimum cacheable prefix size.
+    pub min_cacheable_tokens: Option<u32>,
+    /// Override for the provider's breakpoint limit.
+    pub max_breakpoints: Option<usize>,
+}
+
+impl Default for PromptCacheStrategy {
+    fn default() -> Self {
+        Self {
+            cache_tools: true,
+            cache_system: true,
+            cache_conversation: true,
+            ttl: None,
+            min_cacheable_tokens: None,
+            max_breakpoints: None,
+        }
+    }
+}
+
+impl PromptCacheStrategy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tools(mut self, enabled: bool) -> Self {
+        self.cache_tools = enabled;
+        self
+    }
+
+    pub fn with_system(mut self, enabled: bool) -> Self {
+        self.cache_system = enabled;
+        self
+    }
+
+    pub fn with_conversation(mut self, enabled: bool) -> Self {
+        self.cache_conversation = enabled;
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: PromptCacheTtl) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    pub fn with_min_cacheable_tokens(mut self, tokens: u32) -> Self {
+        self.min_cacheable_tokens = Some(tokens);
+        self
+    }
+
+    pub fn with_max_breakpoints(mut self, max: usize) -> Self {
+        self.max_breakpoints = Some(max);
+        self
+    }
+
+    /// Choose breakpoints for `req`.
+    ///
+    /// Breakpoints already present in the request count against the limit and are never
+    /// duplicated. The result is ordered by priority: tools, system, conversation.
+    pub fn plan(
+        &self,
+        req: &ChatRequest,
+        marker: &dyn PromptCacheMarker,
+    ) -> Vec<PromptCacheBreakpoint> {
+        let namespace = marker.namespace();
+        let tools = req.tools.as_deref().unwrap_or(&[]);
+
+        let tool_marked = |tool: &Tool| match tool {
+            Tool::Function { function } => function
+                .provider_options_map
+                .get_object(namespace)
+                .is_some_and(|o| marker.has_marker(o)),
+            Tool::ProviderDefined(_) => false,
+        };
+        let message_marked = |message: &ChatMessage| {
+            message
+                .provider_options
+                .get_object(namespace)
+                .is_some_and(|o| marker.has_marker(o))
+        };
+
+        let existing = tools.iter().filter(|t| tool_marked(t)).count()
+            + req.messages.iter().filter(|m| message_marked(m)).count();
+        let limit = self
+            .max_breakpoints
+            .unwrap_or_else(|| marker.max_breakpoints());
+        let mut budget = limit.saturating_sub(existing);
+        let threshold = u64::from(
+            self.min_cacheable_tokens
+                .unwrap_or_else(|| marker.min_cacheable_tokens(&req.common_params.model)),
+        );
+
+        let mut out = Vec::new();
+        let mut push = |bp: PromptCacheBreakpoint, prefix_tokens: u64, marked: bool| {
+            if budget > 0 && !marked && prefix_tokens >= threshold && !out.contains(&bp) {
+                out.push(bp);
+                budget -= 1;
+            }
+        };
+
+        // 1) Tool definitions (the tools block precedes the system prompt in the cached prefix).
+        let tools_tokens: u64 = tools.iter().map(estimate_json_tokens).sum();
+        if self.cache_tools
+            && marker.supports(PromptCacheTarget::Tools)
+            && let Some(idx) = tools
+                .iter()
+                .rposition(|t| matches!(t, Tool::Function { .. }))
+        {
+            push(
+                PromptCacheBreakpoint::Tool(idx),
+                tools_tokens,
+                tools.iter().any(tool_marked),
+            );
+        }
+
+        // Cumulative prefix size at each message.
+        let mut prefix = Vec::with_capacity(req.messages.len());
+        let mut running = tools_tokens;
+        for message in &req.messages {
+            running += estimate_json_tokens(&message.content);
+            prefix.push(running);
+        }
+
+        // 2) Leading system prompt.
+        let leading_system = req
+            .messages
+            .iter()
+            .take_while(|m| matches!(m.role, MessageRole::System | MessageRole::Developer))
+            .count();
+        if self.cache_system && marker.supports(PromptCacheTarget::System) && leading_system > 0 {
+            let idx = leading_system - 1;
+            push(
+                PromptCacheBreakpoint::Message(idx),
+                prefix[idx],
+                req.messages[..leading_system].iter().any(message_marked),
+            );
+        }
+
+        // 3) Stable conversation prefix: the final message, then the previous user turn.
+        if self.cache_conversation
+            && marker.supports(PromptCacheTarget::Message)
+            && req.messages.len() > leading_system
+        {
+            let last = req.messages.len() - 1;
+            push(
+                PromptCacheBreakpoint::Message(last),
+                prefix[last],
+                message_marked(&req.messages[last]),
+            );
+
+            if let Some(prev_user) = req.messages[leading_system..last]
+                .iter()
+                .rposition(|m| matches!(m.role, MessageRole::User))
+                .map(|i| i + leading_system)
+            {
+                push(
+                    PromptCacheBreakpoint::Message(prev_user),
+                    prefix[prev_user],
+                    message_marked(&req.messages[prev_user]),
+                );
+            }
+        }
+
+        out
+    }
+
+    /// Place the planned breakpoints on `req`.
+    pub fn apply(&self, mut req: ChatRequest, marker: &dyn PromptCacheMarker) -> ChatRequest {
+        let plan = self.plan(&req, marker);
+        if plan.is_empty() {
+            return req;
+        }
+
+        let entries = marker.marker(self.ttl);
+        for bp in plan {
+            match bp {
+                PromptCacheBreakpoint::Tool(idx) => {
+                    if let Some(Tool::Function { function }) =
+                        req.tools.as_mut().and_then(|tools| tools.get_mut(idx))
+                    {
+                        merge_marker(
+                            &mut function.provider_options_map,
+                            marker.namespace(),
+                            &entries,
+                        );
+                    }
+                }
+                PromptCacheBreakpoint::Message(idx) => {
+                    if let Some(message) = req.messages.get_mut(idx) {
+                        merge_marker(&mut message.provider_options, marker.namespace(), &entries);
+                    }
+                }
+            }
+        }
+
+        req
+    }
+}
+
+fn merge_marker(
+    options: &mut crate::types::ProviderOptionsMap,
+    namespace: &str,
+    entries: &serde_json::Map<String, serde_json::Value>,
+) {
+    let mut overrides = crate::types::ProviderOptionsMap::new();
+    overrides.insert(namespace, serde_json::Value::Object(entries.clone()));
+    options.merge_overrides(overrides);
+}
+
+/// Rough token estimate (about four characters per token) of a JSON-serializable value.
+fn estimate_json_tokens<T: serde::Serialize>(value: &T) -> u64 {
+    let chars = serde_json::to_string(value).map(|s| s.len()).unwrap_or(0) as u64;
+    chars.div_ceil(4)
+}
+
+/// Aggregated prompt-cache usage observed by [`PromptCacheMiddleware`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PromptCacheStats {
+    /// Responses with usage information.
+    pub requests: u64,
+    /// Responses that read at least one token from cache.
+    pub cache_hits: u64,
+    /// Total input tokens (including cache reads and writes).
+    pub input_tokens: u64,
+    /// Input tokens read from cache.
+    pub cache_read_tokens: u64,
+    /// Input tokens written to cache.
+    pub cache_write_tokens: u64,
+}
+
+impl PromptCacheStats {
+    /// Share of input tokens served from cache (`0.0` when no usage was recorded).
+    pub fn hit_rate(&self) -> f64 {
+        if self.input_tokens == 0 {
+            return 0.0;
+        }
+        self.cache_read_tokens as f64 / self.input_tokens as f64
+    }
+
+    /// Record one response's usage.
+    pub fn record(&mut self, usage: &Usage) {
+        let input = usage.normalized_input_tokens();
+        let read = u64::from(input.cache_read.unwrap_or(0));
+        let write = u64::from(input.cache_write.unwrap_or(0));
+        let total = input
+            .total
+            .map(u64::from)
+            .unwrap_or_else(|| u64::from(input.no_cache.unwrap_or(0)) + read + write);
+
+        self.requests += 1;
+        if read > 0 {
+            self.cache_hits += 1;
+        }
+        self.input_tokens += total;
+        self.cache_read_tokens += read;
+        self.cache_write_tokens += write;
+    }
+}
+
+/// Middleware placing prompt-cache breakpoints automatically.
+#[derive(Clone)]
+pub struct PromptCacheMiddleware {
+    marker: Arc<dyn PromptCacheMarker>,
+    strategy: PromptCacheStrategy,
+    stats: Arc<Mutex<PromptCacheStats>>,
+}
+
+impl std::fmt::Debug for PromptCacheMiddleware {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PromptCacheMiddleware")
+            .field("namespace", &self.marker.namespace())
+            .field("strategy", &self.strategy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PromptCacheMiddleware {
+    pub fn new(marker: Arc<dyn PromptCacheMarker>) -> Self {
+        Self {
+            marker,
+            strategy: PromptCacheStrategy::default(),
+            stats: Arc::new(Mutex::new(PromptCacheStats::default())),
+        }
+    }
+
+    pub fn with_strategy(mut self, strategy: PromptCacheStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Snapshot of the cache usage observed so far (shared across clones).
+    pub fn stats(&self) -> PromptCacheStats {
+        self.stats.lock().map(|s| s.clone()).unwrap_or_default()
+    }
+
+    fn record_usage(&self, resp: &ChatResponse) {
+        if let Some(usage) = resp.usage.as_ref()
+            && let Ok(mut stats) = self.stats.lock()
+        {
+            stats.record(usage);
+        }
+    }
+}
+
+impl LanguageModelMiddleware for PromptCacheMiddleware {
+    fn transform_params(&self, req: ChatRequest) -> ChatRequest {
+        self.strategy.apply(req, self.marker.as_ref())
+    }
+
+    fn post_generate(
+        &self,
+        _req: &ChatRequest,
+        resp: ChatResponse,
+    ) -> Result<ChatResponse, LlmError> {
+        self.record_usage(&resp);
+        Ok(resp)
+    }
+
+    fn on_stream_end(&self, _req: &ChatRequest, response: &ChatResponse) -> Result<(), LlmError> {
+        self.record_usage(response);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ChatMessage, MessageContent, UsageInputTokens};
+
+    struct TestMarker;
+
+    impl PromptCacheMarker for TestMarker {
+        fn namespace(&self) -> &str {
+            "provider-a"
+        }
+
+        fn marker(
+            &self,
+            ttl: Option<PromptCacheTtl>,
+        ) -> serde_json::Map<String, serde_json::Value> {
+            let mut cache = serde_json::json!({ "type": "ephemeral" });
+            if let Some(ttl) = ttl {
+                cache["ttl"] = serde_json::json!(ttl.as_str());
+            }
+            serde_json::Map::from_iter([("cacheControl".to_string(), cache)])
+        }
+
+        fn min_cacheable_tokens(&self, _model: &str) -> u32 {
+            10
+        }
+    }
+
+    fn long_text(words: usize) -> String {
+        "lorem ipsum ".repeat(words)
+    }
+
+    fn tool(name: &str) -> Tool {
+        Tool::function(name, long_text(10), serde_json::json!({ "type": "object" }))
+    }
+
+    fn cache_control(message: &ChatMessage) -> Option<&serde_json::Value> {
+        message
+            .provider_options
+            .get_object("provider-a")
+            .and_then(|o| o.get("cacheControl"))
+    }
+
+    #[test]
+    fn plan_marks_tools_system_and_conversation_prefix() {
+        let req = ChatRequest::new(vec![
+            ChatMessage::system(long_text(20)).build(),
+            ChatMessage::user(long_text(5)).build(),
+            ChatMessage::assistant("ok").build(),
+            ChatMessage::user("next").build(),
+        ])
+        .with_tools(vec![tool("a"), tool("b")]);
+
+        let plan = PromptCacheStrategy::new().plan(&req, &TestMarker);
+        assert_eq!(
+            plan,
+            vec![
+                PromptCacheBreakpoint::Tool(1),
+                PromptCacheBreakpoint::Message(0),
+                PromptCacheBreakpoint::Message(3),
+                PromptCacheBreakpoint::Message(1),
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_skips_prefixes_below_threshold_and_respects_existing_markers() {
+        let mut marked = ChatMessage::user(long_text(20)).build();
+        marked.provider_options.insert(
+            "provider-a",
+            serde_json::json!({ "cacheControl": { "type": "ephemeral" } }),
+        );
+        let req = ChatRequest::new(vec![
+            ChatMessage::system("short").build(),
+            marked,
+            ChatMessage::user("next").build(),
+        ]);
+
+        let plan = PromptCacheStrategy::new()
+            .with_max_breakpoints(2)
+            .plan(&req, &TestMarker);
+        // The short system prompt is below threshold; one existing marker leaves one slot.
+        assert_eq!(plan, vec![PromptCacheBreakpoint::Message(2)]);
+    }
+
+    #[test]
+    fn apply_merges_marker_with_ttl_into_provider_options() {
+        let req = ChatRequest::new(vec![
+            ChatMessage::system(long_text(20)).build(),
+            ChatMessage::user("hi").build(),
+        ]);
+        let req = PromptCacheStrategy::new()
+            .with_conversation(false)
+            .with_ttl(PromptCacheTtl::OneHour)
+            .apply(req, &TestMarker);
+
+        assert_eq!(
+            cache_control(&req.messages[0]),
+            Some(&serde_json::json!({ "type": "ephemeral", "ttl": "1h" }))
+        );
+        assert_eq!(cache_control(&req.messages[1]), None);
+    }
+
+    #[test]
+    fn middleware_accumulates_cache_usage() {
+        let mw = PromptCacheMiddleware::new(Arc::new(TestMarker));
+        let req = ChatRequest::new(vec![ChatMessage::user("hi").build()]);
+
+        let mut resp = ChatResponse::new(MessageContent::Text("ok".to_string()));
+        resp.usage = Some(
+            Usage::builder()
+                .with_input_tokens(UsageInputTokens {
+                    total: Some(100),
+                    no_cache: Some(20),
+                    cache_read: Some(80),
+                    cache_write: None,
+                })
+                .build(),
+        );
+        mw.post_generate(&req, resp).expect("post-generate");
+
+        let mut resp = ChatResponse::new(MessageContent::Text("ok".to_string()));
+        resp.usage = Some(
+            Usage::builder()
+                .with_input_tokens(UsageInputTokens {
+                    total: Some(100),
+                    no_cache: Some(10),
+                    cache_read: None,
+                    cache_write: Some(90),
+                })
+                .build(),
+        );
+        mw.on_stream_end(&req, &resp).expect("stream end");
+
+        let stats = mw.stats();
+        assert_eq!(stats.requests, 2);
+        assert_eq!(stats.cache_hits, 1);
+        assert_eq!(stats.cache_read_tokens, 80);
+        assert_eq!(stats.cache_write_tokens, 90);
+        assert!((stats.hit_rate() - 0.4).abs() < f64::EPSILON);
+    }
+}
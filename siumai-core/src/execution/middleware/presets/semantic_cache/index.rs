@@ -0,0 +1,659 @@
+//! Vector indices for the semantic cache.
+//!
+//! Two in-memory implementations are provided:
+//! - [`InMemoryVectorIndex`]: exact brute-force cosine search, fine up to a few thousand entries
+//!   per namespace.
+//! - [`HnswVectorIndex`]: approximate search over a Hierarchical Navigable Small World graph for
+//!   larger caches.
+//!
+//! Both normalize vectors on insert so similarity is a plain dot product. External stores can
+//! implement [`SemanticVectorIndex`] directly.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use async_trait::async_trait;
+
+use crate::error::LlmError;
+use crate::types::ChatResponse;
+
+/// A cached response together with the embedding of the prompt that produced it.
+#[derive(Debug, Clone)]
+pub struct SemanticCacheEntry {
+    /// Unique entry id.
+    pub id: String,
+    /// Cache partition (namespace, model and prompt fingerprint).
+    pub namespace: String,
+    /// Prompt embedding.
+    pub vector: Vec<f32>,
+    /// Embedded prompt text (kept for debugging and external stores).
+    pub prompt: String,
+    /// Cached response.
+    pub response: ChatResponse,
+    /// Insertion time, used for TTL checks.
+    pub created_at: SystemTime,
+}
+
+/// A search result with its cosine similarity to the query.
+#[derive(Debug, Clone)]
+pub struct SemanticCacheMatch {
+    pub entry: SemanticCacheEntry,
+    pub score: f32,
+}
+
+/// Pluggable vector store used by [`SemanticCacheMiddleware`](super::SemanticCacheMiddleware).
+#[async_trait]
+pub trait SemanticVectorIndex: Send + Sync {
+    /// Return up to `limit` entries of `namespace`, most similar first.
+    async fn search(
+        &self,
+        namespace: &str,
+        vector: &[f32],
+        limit: usize,
+    ) -> Result<Vec<SemanticCacheMatch>, LlmError>;
+
+    /// Insert an entry.
+    async fn insert(&self, entry: SemanticCacheEntry) -> Result<(), LlmError>;
+
+    /// Remove an entry (e.g. after it expired).
+    async fn remove(&self, namespace: &str, id: &str) -> Result<(), LlmError>;
+
+    /// Drop all entries of a namespace.
+    async fn clear(&self, namespace: &str) -> Result<(), LlmError>;
+}
+
+fn normalize(vector: &[f32]) -> Vec<f32> {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return vector.to_vec();
+    }
+    vector.iter().map(|x| x / norm).collect()
+}
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Exact brute-force index.
+#[derive(Debug, Default)]
+pub struct InMemoryVectorIndex {
+    namespaces: Mutex<HashMap<String, Vec<SemanticCacheEntry>>>,
+    max_entries: Option<usize>,
+}
+
+impl InMemoryVectorIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cap entries per namespace; the oldest entry is evicted first.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries.max(1));
+        self
+    }
+
+    /// Number of entries in a namespace.
+    pub fn len(&self, namespace: &str) -> usize {
+        self.namespaces
+            .lock()
+            .map(|ns| ns.get(namespace).map_or(0, Vec::len))
+            .unwrap_or(0)
+    }
+
+    /// Whether a namespace has no entries.
+    pub fn is_empty(&self, namespace: &str) -> bool {
+        self.len(namespace) == 0
+    }
+}
+
+#[async_trait]
+impl SemanticVectorIndex for InMemoryVectorIndex {
+    async fn search(
+        &self,
+        namespace: &str,
+        vector: &[f32],
+        limit: usize,
+    ) -> Result<Vec<SemanticCacheMatch>, LlmError> {
+        let query = normalize(vector);
+        let namespaces = self
+            .namespaces
+            .lock()
+            .map_err(|_| LlmError::InternalError("semantic cache index poisoned".to_string()))?;
+        let Some(entries) = namespaces.get(namespace) else {
+            return Ok(Vec::new());
+        };
+        let mut matches: Vec<SemanticCacheMatch> = entries
+            .iter()
+            .map(|entry| SemanticCacheMatch {
+                score: dot(&query, &entry.vector),
+                entry: entry.clone(),
+            })
+            .collect();
+        matches.sort_by(|a, b| b.score.total_cmp(&a.score));
+        matches.truncate(limit);
+        Ok(matches)
+    }
+
+    async fn insert(&self, mut entry: SemanticCacheEntry) -> Result<(), LlmError> {
+        entry.vector = normalize(&entry.vector);
+        let mut namespaces = self
+            .namespaces
+            .lock()
+            .map_err(|_| LlmError::InternalError("semantic cache index poisoned".to_string()))?;
+        let entries = namespaces.entry(entry.namespace.clone()).or_default();
+        entries.retain(|e| e.id != entry.id);
+        entries.push(entry);
+        if let Some(max) = self.max_entries
+            && entries.len() > max
+        {
+            let excess = entries.len() - max;
+            entries.drain(..excess);
+        }
+        Ok(())
+    }
+
+    async fn remove(&self, namespace: &str, id: &str) -> Result<(), LlmError> {
+        if let Ok(mut namespaces) = self.namespaces.lock()
+            && let Some(entries) = namespaces.get_mut(namespace)
+        {
+            entries.retain(|e| e.id != id);
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, namespace: &str) -> Result<(), LlmError> {
+        if let Ok(mut namespaces) = self.namespaces.lock() {
+            namespaces.remove(namespace);
+        }
+        Ok(())
+    }
+}
+
+/// HNSW tuning parameters.
+#[derive(Debug, Clone, Copy)]
+pub struct HnswParams {
+    /// Maximum neighbors per node on upper layers (layer 0 keeps `2 * m`).
+    pub m: usize,
+    /// Candidate list size while inserting.
+    pub ef_construction: usize,
+    /// Candidate list size while searching.
+    pub ef_search: usize,
+}
+
+impl Default for HnswParams {
+    fn default() -> Self {
+        Self {
+            m: 16,
+            ef_construction: 100,
+            ef_search: 64,
+        }
+    }
+}
+
+/// Approximate nearest-neighbor index based on HNSW.
+///
+/// Removed entries are tombstoned and the graph is rebuilt once tombstones outnumber live
+/// entries.
+#[derive(Debug, Default)]
+pub struct HnswVectorIndex {
+    params: HnswParams,
+    graphs: Mutex<HashMap<String, HnswGraph>>,
+    max_entries: Option<usize>,
+}
+
+impl HnswVectorIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_params(mut self, params: HnswParams) -> Self {
+        self.params = HnswParams {
+            m: params.m.max(2),
+            ef_construction: params.ef_construction.max(1),
+            ef_search: params.ef_search.max(1),
+        };
+        self
+    }
+
+    /// Cap live entries per namespace; the oldest entry is evicted first.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries.max(1));
+        self
+    }
+
+    /// Number of live entries in a namespace.
+    pub fn len(&self, namespace: &str) -> usize {
+        self.graphs
+            .lock()
+            .map(|g| g.get(namespace).map_or(0, HnswGraph::live))
+            .unwrap_or(0)
+    }
+
+    /// Whether a namespace has no live entries.
+    pub fn is_empty(&self, namespace: &str) -> bool {
+        self.len(namespace) == 0
+    }
+}
+
+#[async_trait]
+impl SemanticVectorIndex for HnswVectorIndex {
+    async fn search(
+        &self,
+        namespace: &str,
+        vector: &[f32],
+        limit: usize,
+    ) -> Result<Vec<SemanticCacheMatch>, LlmError> {
+        let query = normalize(vector);
+        let graphs = self
+            .graphs
+            .lock()
+            .map_err(|_| LlmError::InternalError("semantic cache index poisoned".to_string()))?;
+        let Some(graph) = graphs.get(namespace) else {
+            return Ok(Vec::new());
+        };
+        Ok(graph
+            .search(&query, limit, self.params.ef_search.max(limit))
+            .into_iter()
+            .map(|(idx, score)| SemanticCacheMatch {
+                entry: graph.nodes[idx].entry.clone(),
+                score,
+            })
+            .collect())
+    }
+
+    async fn insert(&self, mut entry: SemanticCacheEntry) -> Result<(), LlmError> {
+        entry.vector = normalize(&entry.vector);
+        let mut graphs = self
+            .graphs
+            .lock()
+            .map_err(|_| LlmError::InternalError("semantic cache index poisoned".to_string()))?;
+        let graph = graphs
+            .entry(entry.namespace.clone())
+            .or_insert_with(|| HnswGraph::new(self.params));
+        graph.remove(&entry.id);
+        graph.insert(entry);
+        if let Some(max) = self.max_entries {
+            while graph.live() > max {
+                graph.remove_oldest();
+            }
+        }
+        graph.maybe_rebuild();
+        Ok(())
+    }
+
+    async fn remove(&self, namespace: &str, id: &str) -> Result<(), LlmError> {
+        if let Ok(mut graphs) = self.graphs.lock()
+            && let Some(graph) = graphs.get_mut(namespace)
+        {
+            graph.remove(id);
+            graph.maybe_rebuild();
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, namespace: &str) -> Result<(), LlmError> {
+        if let Ok(mut graphs) = self.graphs.lock() {
+            graphs.remove(namespace);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Scored {
+    score: f32,
+    idx: usize,
+}
+
+impl Eq for Scored {}
+
+impl Ord for Scored {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.score
+            .total_cmp(&other.score)
+            .then_with(|| self.idx.cmp(&other.idx))
+    }
+}
+
+impl PartialOrd for Scored {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(Debug)]
+struct HnswNode {
+    entry: SemanticCacheEntry,
+    neighbors: Vec<Vec<usize>>,
+    deleted: bool,
+}
+
+#[derive(Debug)]
+struct HnswGraph {
+    params: HnswParams,
+    nodes: Vec<HnswNode>,
+    ids: HashMap<String, usize>,
+    entry_point: Option<usize>,
+    rng: u64,
+}
+
+impl Default for HnswGraph {
+    fn default() -> Self {
+        Self::new(HnswParams::default())
+    }
+}
+
+impl HnswGraph {
+    fn new(params: HnswParams) -> Self {
+        Self {
+            params,
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            entry_point: None,
+            rng: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    fn live(&self) -> usize {
+        self.ids.len()
+    }
+
+    fn similarity(&self, query: &[f32], idx: usize) -> f32 {
+        dot(query, &self.nodes[idx].entry.vector)
+    }
+
+    fn random_level(&mut self) -> usize {
+        // xorshift64*: deterministic, dependency-free level sampling.
+        self.rng ^= self.rng >> 12;
+        self.rng ^= self.rng << 25;
+        self.rng ^= self.rng >> 27;
+        let bits = self.rng.wrapping_mul(0x2545_F491_4F6C_DD1D);
+        let uniform = ((bits >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+        let ml = 1.0 / (self.params.m as f64).ln();
+        ((-uniform.ln() * ml).floor() as usize).min(16)
+    }
+
+    fn top_level(&self) -> usize {
+        self.entry_point
+            .map_or(0, |ep| self.nodes[ep].neighbors.len() - 1)
+    }
+
+    fn max_neighbors(&self, level: usize) -> usize {
+        if level == 0 {
+            self.params.m * 2
+        } else {
+            self.params.m
+        }
+    }
+
+    fn greedy(&self, query: &[f32], mut current: usize, level: usize) -> usize {
+        let mut best = self.similarity(query, current);
+        loop {
+            let mut improved = false;
+            for &n in &self.nodes[current].neighbors[level] {
+                let s = self.similarity(query, n);
+                if s > best {
+                    best = s;
+                    current = n;
+                    improved = true;
+                }
+            }
+            if !improved {
+                return current;
+            }
+        }
+    }
+
+    /// Beam search on one layer; returns candidates sorted by similarity (best first).
+    fn search_layer(&self, query: &[f32], entry: usize, ef: usize, level: usize) -> Vec<Scored> {
+        let mut visited = HashSet::from([entry]);
+        let first = Scored {
+            score: self.similarity(query, entry),
+            idx: entry,
+        };
+        // Max-heap of candidates to expand, min-heap (via Reverse) of current results.
+        let mut candidates = BinaryHeap::from([first]);
+        let mut results = BinaryHeap::from([std::cmp::Reverse(first)]);
+
+        while let Some(candidate) = candidates.pop() {
+            let worst = results.peek().map_or(f32::MIN, |r| r.0.score);
+            if candidate.score < worst && results.len() >= ef {
+                break;
+            }
+            for &n in &self.nodes[candidate.idx].neighbors[level] {
+                if !visited.insert(n) {
+                    continue;
+                }
+                let scored = Scored {
+                    score: self.similarity(query, n),
+                    idx: n,
+                };
+                let worst = results.peek().map_or(f32::MIN, |r| r.0.score);
+                if results.len() < ef || scored.score > worst {
+                    candidates.push(scored);
+                    results.push(std::cmp::Reverse(scored));
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+
+        let mut out: Vec<Scored> = results.into_iter().map(|r| r.0).collect();
+        out.sort_by(|a, b| b.cmp(a));
+        out
+    }
+
+    fn insert(&mut self, entry: SemanticCacheEntry) {
+        let level = self.random_level();
+        let idx = self.nodes.len();
+        self.ids.insert(entry.id.clone(), idx);
+        self.nodes.push(HnswNode {
+            entry,
+            neighbors: vec![Vec::new(); level + 1],
+            deleted: false,
+        });
+
+        let Some(mut ep) = self.entry_point else {
+            self.entry_point = Some(idx);
+            return;
+        };
+
+        let query = self.nodes[idx].entry.vector.clone();
+        let top = self.top_level();
+        for l in (level + 1..=top).rev() {
+            ep = self.greedy(&query, ep, l);
+        }
+
+        for l in (0..=level.min(top)).rev() {
+            let candidates = self.search_layer(&query, ep, self.params.ef_construction, l);
+            let max = self.max_neighbors(l);
+            let selected: Vec<usize> = candidates.iter().take(max).map(|c| c.idx).collect();
+            for &n in &selected {
+                self.nodes[n].neighbors[l].push(idx);
+                if self.nodes[n].neighbors[l].len() > max {
+                    self.prune(n, l, max);
+                }
+            }
+            self.nodes[idx].neighbors[l] = selected;
+            ep = candidates.first().map_or(ep, |c| c.idx);
+        }
+
+        if level > top {
+            self.entry_point = Some(idx);
+        }
+    }
+
+    fn prune(&mut self, node: usize, level: usize, max: usize) {
+        let base = self.nodes[node].entry.vector.clone();
+        let mut scored: Vec<Scored> = self.nodes[node].neighbors[level]
+            .iter()
+            .map(|&n| Scored {
+                score: self.similarity(&base, n),
+                idx: n,
+            })
+            .collect();
+        scored.sort_by(|a, b| b.cmp(a));
+        scored.truncate(max);
+        self.nodes[node].neighbors[level] = scored.into_iter().map(|s| s.idx).collect();
+    }
+
+    fn search(&self, query: &[f32], limit: usize, ef: usize) -> Vec<(usize, f32)> {
+        let Some(mut ep) = self.entry_point else {
+            return Vec::new();
+        };
+        for l in (1..=self.top_level()).rev() {
+            ep = self.greedy(query, ep, l);
+        }
+        self.search_layer(query, ep, ef, 0)
+            .into_iter()
+            .filter(|c| !self.nodes[c.idx].deleted)
+            .take(limit)
+            .map(|c| (c.idx, c.score))
+            .collect()
+    }
+
+    fn remove(&mut self, id: &str) {
+        if let Some(idx) = self.ids.remove(id) {
+            self.nodes[idx].deleted = true;
+        }
+    }
+
+    fn remove_oldest(&mut self) {
+        let oldest = self
+            .nodes
+            .iter()
+            .filter(|n| !n.deleted)
+            .min_by_key(|n| n.entry.created_at)
+            .map(|n| n.entry.id.clone());
+        if let Some(id) = oldest {
+            self.remove(&id);
+        }
+    }
+
+    fn maybe_rebuild(&mut self) {
+        let tombstones = self.nodes.len() - self.live();
+        if tombstones == 0 || tombstones <= self.live() {
+            return;
+        }
+        let live: Vec<SemanticCacheEntry> = std::mem::take(&mut self.nodes)
+            .into_iter()
+            .filter(|n| !n.deleted)
+            .map(|n| n.entry)
+            .collect();
+        self.ids.clear();
+        self.entry_point = None;
+        for entry in live {
+            self.insert(entry);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, vector: Vec<f32>) -> SemanticCacheEntry {
+        SemanticCacheEntry {
+            id: id.to_string(),
+            namespace: "ns".to_string(),
+            vector,
+            prompt: id.to_string(),
+            response: ChatResponse::new(crate::types::MessageContent::Text(id.to_string())),
+            created_at: SystemTime::now(),
+        }
+    }
+
+    fn unit(angle: f32) -> Vec<f32> {
+        vec![angle.cos(), angle.sin()]
+    }
+
+    #[tokio::test]
+    async fn brute_force_returns_most_similar_first_and_evicts_oldest() {
+        let index = InMemoryVectorIndex::new().with_max_entries(2);
+        index.insert(entry("a", vec![1.0, 0.0])).await.unwrap();
+        index.insert(entry("b", vec![0.0, 2.0])).await.unwrap();
+        index.insert(entry("c", vec![1.0, 1.0])).await.unwrap();
+        assert_eq!(index.len("ns"), 2);
+
+        let hits = index.search("ns", &[0.0, 1.0], 5).await.unwrap();
+        let ids: Vec<&str> = hits.iter().map(|m| m.entry.id.as_str()).collect();
+        assert_eq!(ids, vec!["b", "c"]);
+        assert!((hits[0].score - 1.0).abs() < 1e-6);
+        assert!(
+            index
+                .search("other", &[0.0, 1.0], 5)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    /// Deterministic pseudo-random vectors (LCG), so the test needs no extra dependencies.
+    fn random_vectors(count: usize, dim: usize, seed: u64) -> Vec<Vec<f32>> {
+        let mut state = seed;
+        (0..count)
+            .map(|_| {
+                (0..dim)
+                    .map(|_| {
+                        state = state
+                            .wrapping_mul(6_364_136_223_846_793_005)
+                            .wrapping_add(1_442_695_040_888_963_407);
+                        ((state >> 33) as f32 / (1u64 << 31) as f32) - 0.5
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn hnsw_matches_brute_force_nearest_neighbor() {
+        let hnsw = HnswVectorIndex::new().with_params(HnswParams {
+            m: 8,
+            ef_construction: 64,
+            ef_search: 32,
+        });
+        let exact = InMemoryVectorIndex::new();
+        for (i, vector) in random_vectors(500, 8, 7).into_iter().enumerate() {
+            let e = entry(&format!("e{i}"), vector);
+            hnsw.insert(e.clone()).await.unwrap();
+            exact.insert(e).await.unwrap();
+        }
+
+        let queries = random_vectors(20, 8, 42);
+        let mut agree = 0;
+        for q in &queries {
+            let approx = hnsw.search("ns", q, 1).await.unwrap();
+            let truth = exact.search("ns", q, 1).await.unwrap();
+            if approx[0].entry.id == truth[0].entry.id {
+                agree += 1;
+            }
+        }
+        assert!(agree >= 19, "recall@1 too low: {agree}/20");
+    }
+
+    #[tokio::test]
+    async fn hnsw_removal_hides_entries_and_rebuilds() {
+        let index = HnswVectorIndex::new();
+        for i in 0..10 {
+            index
+                .insert(entry(&format!("e{i}"), unit(i as f32 * 0.3)))
+                .await
+                .unwrap();
+        }
+        for i in 0..8 {
+            index.remove("ns", &format!("e{i}")).await.unwrap();
+        }
+        assert_eq!(index.len("ns"), 2);
+
+        let hits = index.search("ns", &unit(0.0), 10).await.unwrap();
+        let mut ids: Vec<&str> = hits.iter().map(|m| m.entry.id.as_str()).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["e8", "e9"]);
+    }
+}
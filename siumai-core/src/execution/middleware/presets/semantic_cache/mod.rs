@@ -0,0 +1,529 @@
+//! Semantic response cache.
+//!
+//! Exact-match caches miss paraphrased prompts ("how do I reset my password?" vs "password
+//! reset steps?"). This middleware embeds the last user turn with any [`EmbeddingModel`],
+//! looks up the nearest cached prompt in a [`SemanticVectorIndex`], and returns the cached
+//! [`ChatResponse`] when the cosine similarity reaches the configured threshold.
+//!
+//! Cache partitions are derived from the configured namespace, the model id and a stable
+//! fingerprint of the system prompt, response format, seed, output limits (`max_tokens`, stop
+//! sequences) and provider options, so the same question asked of a different model, persona or
+//! configuration never shares an entry.
+//!
+//! Requests are passed through untouched (a "bypass") when they carry tools or a tool choice,
+//! no explicit temperature at or below `max_temperature` (provider defaults are not
+//! deterministic), other sampling parameters, non-text user input, or (by default) more than
+//! one user turn. Embedding and index failures fail open: the request goes to the provider.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use futures::StreamExt;
+
+use crate::embedding::EmbeddingModel;
+use crate::error::LlmError;
+use crate::execution::middleware::LanguageModelMiddleware;
+use crate::execution::middleware::language_model::{GenerateAsyncFn, StreamAsyncFn};
+use crate::streaming::ChatStream;
+use crate::types::{
+    ChatRequest, ChatResponse, ChatStreamEvent, EmbeddingRequest, FinishReason, MessageContent,
+    MessageRole, ResponseMetadata,
+};
+use crate::utils::stable_hash;
+
+mod index;
+
+pub use index::{
+    HnswParams, HnswVectorIndex, InMemoryVectorIndex, SemanticCacheEntry, SemanticCacheMatch,
+    SemanticVectorIndex,
+};
+
+/// Semantic cache behavior.
+#[derive(Debug, Clone)]
+pub struct SemanticCacheConfig {
+    /// Minimum cosine similarity for a hit.
+    pub threshold: f32,
+    /// Entry lifetime; `None` keeps entries until evicted by the index.
+    pub ttl: Option<Duration>,
+    /// Namespace prefix isolating tenants or applications sharing one index.
+    pub namespace: String,
+    /// Partition entries by a fingerprint of the system prompt.
+    pub fingerprint_system_prompt: bool,
+    /// Allow caching requests with earlier user turns (only the last turn is embedded).
+    pub allow_multi_turn: bool,
+    /// Highest temperature still treated as deterministic. Requests must set a temperature
+    /// explicitly to be cached.
+    pub max_temperature: f64,
+    /// Candidates fetched from the index per lookup.
+    pub search_limit: usize,
+}
+
+impl Default for SemanticCacheConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 0.92,
+            ttl: None,
+            namespace: "default".to_string(),
+            fingerprint_system_prompt: true,
+            allow_multi_turn: false,
+            max_temperature: 0.0,
+            search_limit: 4,
+        }
+    }
+}
+
+impl SemanticCacheConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    pub fn with_system_prompt_fingerprint(mut self, enabled: bool) -> Self {
+        self.fingerprint_system_prompt = enabled;
+        self
+    }
+
+    pub fn with_multi_turn(mut self, enabled: bool) -> Self {
+        self.allow_multi_turn = enabled;
+        self
+    }
+
+    pub fn with_max_temperature(mut self, max_temperature: f64) -> Self {
+        self.max_temperature = max_temperature;
+        self
+    }
+
+    pub fn with_search_limit(mut self, limit: usize) -> Self {
+        self.search_limit = limit.max(1);
+        self
+    }
+}
+
+/// Why a request was not looked up in the cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SemanticCacheBypass {
+    /// The request carries tools or a tool choice.
+    Tools,
+    /// Sampling parameters make the response non-deterministic.
+    NonDeterministic,
+    /// The last message is not a user turn, or it has no text.
+    NoUserText,
+    /// The last user turn contains non-text parts.
+    NonTextInput,
+    /// Earlier user turns are present and `allow_multi_turn` is off.
+    MultiTurn,
+}
+
+/// Counters observed by [`SemanticCacheMiddleware`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SemanticCacheStats {
+    /// Requests served from cache.
+    pub hits: u64,
+    /// Cacheable requests sent to the provider.
+    pub misses: u64,
+    /// Requests that skipped the cache entirely.
+    pub bypassed: u64,
+    /// Responses written to the cache.
+    pub stored: u64,
+    /// Expired entries removed during lookups.
+    pub expired: u64,
+    /// Embedding or index failures (the request still went to the provider).
+    pub errors: u64,
+}
+
+impl SemanticCacheStats {
+    /// Share of cacheable requests served from cache.
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            return 0.0;
+        }
+        self.hits as f64 / total as f64
+    }
+}
+
+/// Cache key derived from a request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemanticCacheKey {
+    /// Index partition.
+    pub namespace: String,
+    /// Text that is embedded (the last user turn).
+    pub prompt: String,
+}
+
+impl SemanticCacheConfig {
+    /// Derive the cache key for `req`, or the reason it must bypass the cache.
+    pub fn key_for(&self, req: &ChatRequest) -> Result<SemanticCacheKey, SemanticCacheBypass> {
+        if req.tools.as_ref().is_some_and(|t| !t.is_empty()) || req.tool_choice.is_some() {
+            return Err(SemanticCacheBypass::Tools);
+        }
+
+        let params = &req.common_params;
+        let non_deterministic = params.temperature.is_none_or(|t| t > self.max_temperature)
+            || params.top_p.is_some_and(|p| p < 1.0)
+            || params.top_k.is_some();
+        if non_deterministic {
+            return Err(SemanticCacheBypass::NonDeterministic);
+        }
+
+        let Some(last) = req.messages.last() else {
+            return Err(SemanticCacheBypass::NoUserText);
+        };
+        if last.role != MessageRole::User {
+            return Err(SemanticCacheBypass::NoUserText);
+        }
+        if let MessageContent::MultiModal(parts) = &last.content
+            && parts.iter().any(|p| !p.is_text())
+        {
+            return Err(SemanticCacheBypass::NonTextInput);
+        }
+        let prompt = last.content.all_text().trim().to_string();
+        if prompt.is_empty() {
+            return Err(SemanticCacheBypass::NoUserText);
+        }
+
+        let conversation = req
+            .messages
+            .iter()
+            .filter(|m| !matches!(m.role, MessageRole::System | MessageRole::Developer))
+            .count();
+        if conversation > 1 && !self.allow_multi_turn {
+            return Err(SemanticCacheBypass::MultiTurn);
+        }
+
+        let mut fingerprint: Vec<String> = Vec::new();
+        if self.fingerprint_system_prompt {
+            fingerprint.extend(
+                req.messages
+                    .iter()
+                    .filter(|m| matches!(m.role, MessageRole::System | MessageRole::Developer))
+                    .map(|m| format!("system:{}", m.content.all_text())),
+            );
+        }
+        if let Some(format) = &req.response_format {
+            fingerprint.push(format!(
+                "format:{}",
+                serde_json::to_string(format).unwrap_or_default()
+            ));
+        }
+        if let Some(seed) = params.seed {
+            fingerprint.push(format!("seed:{seed}"));
+        }
+        if let Some(max_tokens) = params.max_tokens {
+            fingerprint.push(format!("max_tokens:{max_tokens}"));
+        }
+        if let Some(max_completion_tokens) = params.max_completion_tokens {
+            fingerprint.push(format!("max_completion_tokens:{max_completion_tokens}"));
+        }
+        if let Some(stop) = params.stop_sequences.as_ref().filter(|s| !s.is_empty()) {
+            fingerprint.push(format!(
+                "stop:{}",
+                serde_json::to_string(stop).unwrap_or_default()
+            ));
+        }
+        if !req.provider_options_map.is_empty() {
+            fingerprint.push(format!(
+                "providerOptions:{}",
+                serde_json::to_string(&req.provider_options_map).unwrap_or_default()
+            ));
+        }
+        let parts: Vec<&str> = fingerprint.iter().map(String::as_str).collect();
+
+        Ok(SemanticCacheKey {
+            namespace: format!(
+                "{}/{}/{:016x}",
+                self.namespace,
+                params.model,
+                stable_hash(&parts)
+            ),
+            prompt,
+        })
+    }
+}
+
+struct SemanticCacheShared {
+    embedding: Arc<dyn EmbeddingModel>,
+    index: Arc<dyn SemanticVectorIndex>,
+    config: SemanticCacheConfig,
+    stats: Mutex<SemanticCacheStats>,
+}
+
+impl SemanticCacheShared {
+    fn bump(&self, f: impl FnOnce(&mut SemanticCacheStats)) {
+        if let Ok(mut stats) = self.stats.lock() {
+            f(&mut stats);
+        }
+    }
+
+    async fn embed(&self, prompt: &str) -> Result<Vec<f32>, LlmError> {
+        let resp = self
+            .embedding
+            .embed(EmbeddingRequest::query(prompt.to_string()))
+            .await?;
+        resp.embeddings
+            .into_iter()
+            .next()
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| {
+                LlmError::ParseError("semantic cache embedding returned no vector".to_string())
+            })
+    }
+
+    fn is_expired(&self, entry: &SemanticCacheEntry, now: SystemTime) -> bool {
+        self.config.ttl.is_some_and(|ttl| {
+            now.duration_since(entry.created_at)
+                .is_ok_and(|age| age >= ttl)
+        })
+    }
+
+    /// Embed and look up `key`; returns the vector for a later store on a miss.
+    async fn lookup(&self, key: &SemanticCacheKey) -> Lookup {
+        let vector = match self.embed(&key.prompt).await {
+            Ok(v) => v,
+            Err(e) => {
+                tracing::warn!(error = %e, "semantic cache embedding failed; bypassing cache");
+                self.bump(|s| s.errors += 1);
+                return Lookup::Failed;
+            }
+        };
+
+        let matches = match self
+            .index
+            .search(&key.namespace, &vector, self.config.search_limit)
+            .await
+        {
+            Ok(m) => m,
+            Err(e) => {
+                tracing::warn!(error = %e, "semantic cache lookup failed; bypassing cache");
+                self.bump(|s| s.errors += 1);
+                return Lookup::Failed;
+            }
+        };
+
+        let now = SystemTime::now();
+        for m in matches {
+            if self.is_expired(&m.entry, now) {
+                let _ = self.index.remove(&key.namespace, &m.entry.id).await;
+                self.bump(|s| s.expired += 1);
+                continue;
+            }
+            if m.score >= self.config.threshold {
+                self.bump(|s| s.hits += 1);
+                return Lookup::Hit(Box::new(m.entry.response));
+            }
+            // Matches are sorted by similarity; nothing below can reach the threshold.
+            break;
+        }
+
+        self.bump(|s| s.misses += 1);
+        Lookup::Miss(vector)
+    }
+
+    async fn store(&self, key: SemanticCacheKey, vector: Vec<f32>, response: &ChatResponse) {
+        if !is_cacheable_response(response) {
+            return;
+        }
+        let entry = SemanticCacheEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            namespace: key.namespace,
+            vector,
+            prompt: key.prompt,
+            response: response.clone(),
+            created_at: SystemTime::now(),
+        };
+        match self.index.insert(entry).await {
+            Ok(()) => self.bump(|s| s.stored += 1),
+            Err(e) => {
+                tracing::warn!(error = %e, "semantic cache insert failed");
+                self.bump(|s| s.errors += 1);
+            }
+        }
+    }
+}
+
+enum Lookup {
+    Hit(Box<ChatResponse>),
+    Miss(Vec<f32>),
+    Failed,
+}
+
+/// Only complete, tool-free answers are worth replaying.
+fn is_cacheable_response(response: &ChatResponse) -> bool {
+    matches!(response.finish_reason, None | Some(FinishReason::Stop))
+        && !response.has_tool_calls()
+        && !response.content.all_text().trim().is_empty()
+}
+
+/// Replay a cached response as a stream.
+fn replay_stream(response: ChatResponse) -> ChatStream {
+    let mut events = vec![ChatStreamEvent::StreamStart {
+        metadata: ResponseMetadata {
+            id: response.id.clone(),
+            model: response.model.clone(),
+            created: None,
+            provider: String::new(),
+            request_id: None,
+            headers: None,
+            body: None,
+        },
+    }];
+    for reasoning in response.reasoning() {
+        events.push(ChatStreamEvent::reasoning_delta_part("0", reasoning));
+    }
+    events.push(ChatStreamEvent::text_delta_part(
+        "0",
+        response.content.all_text(),
+    ));
+    events.push(ChatStreamEvent::finish_part(
+        response.usage.clone().unwrap_or_default(),
+        response.finish_reason.clone().unwrap_or(FinishReason::Stop),
+    ));
+    events.push(ChatStreamEvent::StreamEnd { response });
+    Box::pin(futures::stream::iter(events.into_iter().map(Ok)))
+}
+
+/// Middleware serving semantically similar prompts from a vector-indexed response cache.
+#[derive(Clone)]
+pub struct SemanticCacheMiddleware {
+    shared: Arc<SemanticCacheShared>,
+}
+
+impl std::fmt::Debug for SemanticCacheMiddleware {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SemanticCacheMiddleware")
+            .field("config", &self.shared.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SemanticCacheMiddleware {
+    /// Create a cache backed by `index`, embedding prompts with `embedding`.
+    pub fn new(embedding: Arc<dyn EmbeddingModel>, index: Arc<dyn SemanticVectorIndex>) -> Self {
+        Self::with_config(embedding, index, SemanticCacheConfig::default())
+    }
+
+    pub fn with_config(
+        embedding: Arc<dyn EmbeddingModel>,
+        index: Arc<dyn SemanticVectorIndex>,
+        config: SemanticCacheConfig,
+    ) -> Self {
+        Self {
+            shared: Arc::new(SemanticCacheShared {
+                embedding,
+                index,
+                config,
+                stats: Mutex::new(SemanticCacheStats::default()),
+            }),
+        }
+    }
+
+    /// Active configuration.
+    pub fn config(&self) -> &SemanticCacheConfig {
+        &self.shared.config
+    }
+
+    /// Snapshot of the counters observed so far (shared across clones).
+    pub fn stats(&self) -> SemanticCacheStats {
+        self.shared
+            .stats
+            .lock()
+            .map(|s| s.clone())
+            .unwrap_or_default()
+    }
+
+    /// Drop the cache partition `req` maps to (namespace, model and prompt fingerprint).
+    pub async fn invalidate(&self, req: &ChatRequest) -> Result<(), LlmError> {
+        match self.shared.config.key_for(req) {
+            Ok(key) => self.shared.index.clear(&key.namespace).await,
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+impl LanguageModelMiddleware for SemanticCacheMiddleware {
+    fn wrap_generate_async(&self, next: Arc<GenerateAsyncFn>) -> Arc<GenerateAsyncFn> {
+        let shared = Arc::clone(&self.shared);
+        Arc::new(move |req: ChatRequest| {
+            let next = Arc::clone(&next);
+            let shared = Arc::clone(&shared);
+            Box::pin(async move {
+                let key = match shared.config.key_for(&req) {
+                    Ok(key) => key,
+                    Err(_) => {
+                        shared.bump(|s| s.bypassed += 1);
+                        return next(req).await;
+                    }
+                };
+
+                match shared.lookup(&key).await {
+                    Lookup::Hit(resp) => Ok(*resp),
+                    Lookup::Failed => next(req).await,
+                    Lookup::Miss(vector) => {
+                        let resp = next(req).await?;
+                        shared.store(key, vector, &resp).await;
+                        Ok(resp)
+                    }
+                }
+            })
+        })
+    }
+
+    fn wrap_stream_async(&self, next: Arc<StreamAsyncFn>) -> Arc<StreamAsyncFn> {
+        let shared = Arc::clone(&self.shared);
+        Arc::new(move |req: ChatRequest| {
+            let next = Arc::clone(&next);
+            let shared = Arc::clone(&shared);
+            Box::pin(async move {
+                let key = match shared.config.key_for(&req) {
+                    Ok(key) => key,
+                    Err(_) => {
+                        shared.bump(|s| s.bypassed += 1);
+                        return next(req).await;
+                    }
+                };
+
+                let vector = match shared.lookup(&key).await {
+                    Lookup::Hit(resp) => return Ok(replay_stream(*resp)),
+                    Lookup::Failed => return next(req).await,
+                    Lookup::Miss(vector) => vector,
+                };
+
+                let mut inner = next(req).await?;
+                let stream: ChatStream = Box::pin(async_stream::stream! {
+                    let mut pending = Some((key, vector));
+                    while let Some(item) = inner.next().await {
+                        match &item {
+                            // A stream that reported an error is not a trustworthy answer.
+                            Ok(ChatStreamEvent::Error { .. }) | Err(_) => pending = None,
+                            Ok(ChatStreamEvent::StreamEnd { response }) => {
+                                if let Some((key, vector)) = pending.take() {
+                                    shared.store(key, vector, response).await;
+                                }
+                            }
+                            _ => {}
+                        }
+                        yield item;
+                    }
+                });
+                Ok(stream)
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,308 @@
+use super::*;
+use crate::traits::EmbeddingCapability;
+use crate::types::{ChatMessage, EmbeddingResponse, Tool};
+use async_trait::async_trait;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Bag-of-topics embedding: prompts mentioning the same topic words land on the same axis.
+struct TopicEmbedding;
+
+impl crate::traits::ModelMetadata for TopicEmbedding {
+    fn provider_id(&self) -> &str {
+        "fake"
+    }
+
+    fn model_id(&self) -> &str {
+        "topic-embedding"
+    }
+}
+
+#[async_trait]
+impl EmbeddingCapability for TopicEmbedding {
+    async fn embed(&self, input: Vec<String>) -> Result<EmbeddingResponse, LlmError> {
+        let topics = ["password", "refund", "shipping"];
+        Ok(EmbeddingResponse::new(
+            input
+                .iter()
+                .map(|text| {
+                    let text = text.to_lowercase();
+                    let mut v: Vec<f32> = topics
+                        .iter()
+                        .map(|t| if text.contains(t) { 1.0 } else { 0.0 })
+                        .collect();
+                    // Small length-dependent component so paraphrases are close, not identical.
+                    v.push(text.len() as f32 / 1000.0);
+                    v
+                })
+                .collect(),
+            "topic-embedding".to_string(),
+        ))
+    }
+
+    fn embedding_dimension(&self) -> usize {
+        4
+    }
+}
+
+fn request(text: &str) -> ChatRequest {
+    let mut req = ChatRequest::new(vec![
+        ChatMessage::system("You are a support bot.").build(),
+        ChatMessage::user(text).build(),
+    ]);
+    req.common_params.model = "model-a".to_string();
+    req.common_params.temperature = Some(0.0);
+    req
+}
+
+fn counting_backend(calls: Arc<AtomicUsize>) -> Arc<GenerateAsyncFn> {
+    Arc::new(move |req: ChatRequest| {
+        let calls = Arc::clone(&calls);
+        Box::pin(async move {
+            let n = calls.fetch_add(1, Ordering::SeqCst);
+            let mut resp = ChatResponse::new(MessageContent::Text(format!(
+                "answer {n} to {}",
+                req.messages.last().unwrap().content.all_text()
+            )));
+            resp.finish_reason = Some(FinishReason::Stop);
+            Ok(resp)
+        })
+    })
+}
+
+fn middleware(config: SemanticCacheConfig) -> SemanticCacheMiddleware {
+    SemanticCacheMiddleware::with_config(
+        Arc::new(TopicEmbedding),
+        Arc::new(InMemoryVectorIndex::new()),
+        config,
+    )
+}
+
+#[tokio::test]
+async fn paraphrased_prompt_hits_cache() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mw = middleware(SemanticCacheConfig::default());
+    let generate = mw.wrap_generate_async(counting_backend(Arc::clone(&calls)));
+
+    let first = generate(request("How do I reset my password?"))
+        .await
+        .unwrap();
+    let second = generate(request("password reset steps please"))
+        .await
+        .unwrap();
+    let other = generate(request("Where is my refund?")).await.unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(first.content.all_text(), second.content.all_text());
+    assert_ne!(first.content.all_text(), other.content.all_text());
+
+    let stats = mw.stats();
+    assert_eq!((stats.hits, stats.misses, stats.stored), (1, 2, 2));
+}
+
+#[tokio::test]
+async fn partitions_by_model_and_system_prompt() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mw = middleware(SemanticCacheConfig::default());
+    let generate = mw.wrap_generate_async(counting_backend(Arc::clone(&calls)));
+
+    generate(request("reset password")).await.unwrap();
+
+    let mut other_model = request("reset password");
+    other_model.common_params.model = "model-b".to_string();
+    generate(other_model).await.unwrap();
+
+    let mut other_persona = request("reset password");
+    other_persona.messages[0] = ChatMessage::system("You are a pirate.").build();
+    generate(other_persona).await.unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+    assert_eq!(mw.stats().hits, 0);
+}
+
+#[tokio::test]
+async fn expired_entries_are_not_served() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mw = middleware(SemanticCacheConfig::default().with_ttl(Duration::from_millis(20)));
+    let generate = mw.wrap_generate_async(counting_backend(Arc::clone(&calls)));
+
+    generate(request("reset password")).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    generate(request("reset password")).await.unwrap();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(mw.stats().expired, 1);
+}
+
+#[test]
+fn bypasses_tools_sampling_and_multi_turn() {
+    let config = SemanticCacheConfig::default();
+
+    let mut with_tools = request("reset password");
+    with_tools.tools = Some(vec![Tool::function(
+        "lookup",
+        "Look up an account",
+        serde_json::json!({ "type": "object" }),
+    )]);
+    assert_eq!(config.key_for(&with_tools), Err(SemanticCacheBypass::Tools));
+
+    let mut hot = request("reset password");
+    hot.common_params.temperature = Some(0.7);
+    assert_eq!(
+        config.key_for(&hot),
+        Err(SemanticCacheBypass::NonDeterministic)
+    );
+
+    let mut unset = request("reset password");
+    unset.common_params.temperature = None;
+    assert_eq!(
+        config.key_for(&unset),
+        Err(SemanticCacheBypass::NonDeterministic)
+    );
+
+    let mut multi = request("reset password");
+    multi.messages.insert(1, ChatMessage::user("hello").build());
+    multi
+        .messages
+        .insert(2, ChatMessage::assistant("hi").build());
+    assert_eq!(config.key_for(&multi), Err(SemanticCacheBypass::MultiTurn));
+    assert!(config.clone().with_multi_turn(true).key_for(&multi).is_ok());
+
+    let cold = request("reset password");
+    assert_eq!(config.key_for(&cold).unwrap().prompt, "reset password");
+}
+
+#[test]
+fn key_namespace_is_stable_and_partitioned_by_seed() {
+    let config = SemanticCacheConfig::default();
+
+    let base = config.key_for(&request("reset password")).unwrap();
+    assert_eq!(
+        base.namespace,
+        format!(
+            "default/model-a/{:016x}",
+            stable_hash(&["system:You are a support bot."])
+        )
+    );
+
+    let mut seeded = request("reset password");
+    seeded.common_params.seed = Some(7);
+    let seeded_key = config.key_for(&seeded).unwrap();
+    assert_ne!(seeded_key.namespace, base.namespace);
+
+    seeded.common_params.seed = Some(8);
+    assert_ne!(
+        config.key_for(&seeded).unwrap().namespace,
+        seeded_key.namespace
+    );
+}
+
+#[test]
+fn key_namespace_is_partitioned_by_output_limits_and_provider_options() {
+    let config = SemanticCacheConfig::default();
+    let base = config
+        .key_for(&request("reset password"))
+        .unwrap()
+        .namespace;
+
+    let mut limited = request("reset password");
+    limited.common_params.max_tokens = Some(16);
+    let limited_ns = config.key_for(&limited).unwrap().namespace;
+    assert_ne!(limited_ns, base);
+
+    let mut stopped = request("reset password");
+    stopped.common_params.stop_sequences = Some(vec!["\n".to_string()]);
+    let stopped_ns = config.key_for(&stopped).unwrap().namespace;
+    assert_ne!(stopped_ns, base);
+    assert_ne!(stopped_ns, limited_ns);
+
+    let mut with_options = request("reset password");
+    with_options.provider_options_map.insert(
+        "provider-a",
+        serde_json::json!({ "reasoningEffort": "high" }),
+    );
+    let options_ns = config.key_for(&with_options).unwrap().namespace;
+    assert_ne!(options_ns, base);
+    assert_eq!(config.key_for(&with_options).unwrap().namespace, options_ns);
+}
+
+#[tokio::test]
+async fn stream_with_error_event_is_not_cached() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mw = middleware(SemanticCacheConfig::default());
+
+    let stream_calls = Arc::clone(&calls);
+    let backend: Arc<StreamAsyncFn> = Arc::new(move |_req: ChatRequest| {
+        let calls = Arc::clone(&stream_calls);
+        Box::pin(async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            let mut resp = ChatResponse::new(MessageContent::Text("partial".to_string()));
+            resp.finish_reason = Some(FinishReason::Stop);
+            let events = vec![
+                Ok(ChatStreamEvent::text_delta_part("0", "partial")),
+                Ok(ChatStreamEvent::Error {
+                    error: "upstream reset".to_string(),
+                }),
+                Ok(ChatStreamEvent::StreamEnd { response: resp }),
+            ];
+            Ok(Box::pin(futures::stream::iter(events)) as ChatStream)
+        })
+    });
+    let stream = mw.wrap_stream_async(backend);
+
+    for _ in 0..2 {
+        let events: Vec<_> = stream(request("reset password"))
+            .await
+            .unwrap()
+            .collect()
+            .await;
+        assert_eq!(events.len(), 3);
+    }
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(mw.stats().stored, 0);
+}
+
+#[tokio::test]
+async fn stream_hit_replays_cached_response() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let mw = middleware(SemanticCacheConfig::default());
+
+    let stream_calls = Arc::clone(&calls);
+    let backend: Arc<StreamAsyncFn> = Arc::new(move |_req: ChatRequest| {
+        let calls = Arc::clone(&stream_calls);
+        Box::pin(async move {
+            calls.fetch_add(1, Ordering::SeqCst);
+            let mut resp = ChatResponse::new(MessageContent::Text("streamed answer".to_string()));
+            resp.finish_reason = Some(FinishReason::Stop);
+            let events = vec![
+                Ok(ChatStreamEvent::text_delta_part("0", "streamed answer")),
+                Ok(ChatStreamEvent::StreamEnd { response: resp }),
+            ];
+            Ok(Box::pin(futures::stream::iter(events)) as ChatStream)
+        })
+    });
+    let stream = mw.wrap_stream_async(backend);
+
+    let first: Vec<_> = stream(request("reset password"))
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert_eq!(first.len(), 2);
+
+    let replayed: Vec<ChatStreamEvent> = stream(request("password reset"))
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    let Some(ChatStreamEvent::StreamEnd { response }) = replayed.last() else {
+        panic!("expected StreamEnd");
+    };
+    assert_eq!(response.content.all_text(), "streamed answer");
+    assert!(
+        replayed
+            .iter()
+            .any(|e| matches!(e, ChatStreamEvent::Part { .. }))
+    );
+}
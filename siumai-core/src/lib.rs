@@ -17,6 +17,7 @@ pub mod execution;
 pub mod image;
 pub mod observability;
 pub mod params;
+pub mod prompt_template;
 pub mod rerank;
 pub mod retry;
 pub mod retry_api;
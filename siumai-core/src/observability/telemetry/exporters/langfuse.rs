@@ -135,6 +135,7 @@ impl LangfuseExporter {
                 code: status.as_u16(),
                 message: format!("Langfuse API error: {}", body),
                 details: None,
+                http_details: None,
             });
         }
 
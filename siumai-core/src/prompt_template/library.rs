@@ -0,0 +1,207 @@
+//! Named, versioned template collections.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use super::{PromptTemplate, PromptVariables, RenderedPrompt, syntax};
+use crate::error::LlmError;
+
+/// Templates by id and version, plus shared partials.
+///
+/// Templates are looked up by `id` (latest version) or `id@version`.
+///
+/// # Directory layout
+///
+/// [`PromptLibrary::from_dir`] walks a directory recursively:
+///
+/// - files under `partials/` are partials, named by their path below `partials/` without the
+///   extension (`partials/legal/footer.md` is `{{> legal/footer}}`)
+/// - every other `*.json` file is a [`PromptTemplate`]; a missing `id` is derived from the path
+///   (`support/reply.json` is `support.reply`) and a missing `version` from an `@version`
+///   suffix (`reply@2.json`)
+/// - other files are ignored
+#[derive(Debug, Clone, Default)]
+pub struct PromptLibrary {
+    templates: BTreeMap<String, Vec<PromptTemplate>>,
+    partials: BTreeMap<String, String>,
+}
+
+impl PromptLibrary {
+    /// An empty library.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load every template and partial under `dir`.
+    pub fn from_dir(dir: impl AsRef<Path>) -> Result<Self, LlmError> {
+        let mut library = Self::new();
+        library.load_dir(dir)?;
+        Ok(library)
+    }
+
+    /// Load every template and partial under `dir` into this library.
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Result<(), LlmError> {
+        let root = dir.as_ref();
+        let mut files = Vec::new();
+        collect_files(root, &mut files)?;
+        files.sort();
+
+        let mut templates = Vec::new();
+        for file in files {
+            let relative = file
+                .strip_prefix(root)
+                .unwrap_or(&file)
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            if let Some(partial) = relative.strip_prefix("partials/") {
+                let name = partial
+                    .rsplit_once('.')
+                    .map_or(partial, |(stem, _)| stem)
+                    .to_string();
+                self.add_partial(name, read(&file)?)?;
+            } else if let Some(stem) = relative.strip_suffix(".json") {
+                templates.push(parse_template_file(stem, &read(&file)?)?);
+            }
+        }
+        // Partials first, so templates can be validated against them.
+        for template in templates {
+            self.add(template)?;
+        }
+        Ok(())
+    }
+
+    /// Add a validated template. Fails if `id@version` is already present.
+    pub fn add(&mut self, template: PromptTemplate) -> Result<(), LlmError> {
+        template.validate_with(Some(&self.partials))?;
+        let versions = self.templates.entry(template.id.clone()).or_default();
+        if versions.iter().any(|t| t.version == template.version) {
+            return Err(LlmError::ConfigurationError(format!(
+                "duplicate prompt template '{}'",
+                template.reference()
+            )));
+        }
+        versions.push(template);
+        versions.sort_by(|a, b| compare_versions(&a.version, &b.version));
+        Ok(())
+    }
+
+    /// Add a shared partial.
+    pub fn add_partial(
+        &mut self,
+        name: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Result<(), LlmError> {
+        let (name, text) = (name.into(), text.into());
+        syntax::parse(&text)
+            .map_err(|e| LlmError::ConfigurationError(format!("prompt partial '{name}': {e}")))?;
+        self.partials.insert(name, text);
+        Ok(())
+    }
+
+    /// Template for `id` (latest version) or `id@version`.
+    pub fn get(&self, reference: &str) -> Option<&PromptTemplate> {
+        if let Some(versions) = self.templates.get(reference) {
+            return versions.last();
+        }
+        let (id, version) = reference.rsplit_once('@')?;
+        self.templates
+            .get(id)?
+            .iter()
+            .find(|t| t.version == version)
+    }
+
+    /// Template ids, sorted.
+    pub fn ids(&self) -> impl Iterator<Item = &str> {
+        self.templates.keys().map(String::as_str)
+    }
+
+    /// Versions of `id`, oldest first.
+    pub fn versions(&self, id: &str) -> Vec<&str> {
+        self.templates
+            .get(id)
+            .map(|versions| versions.iter().map(|t| t.version.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Render `id` (latest version) or `id@version` with the library's partials.
+    pub fn render(
+        &self,
+        reference: &str,
+        variables: &PromptVariables,
+    ) -> Result<RenderedPrompt, LlmError> {
+        self.get(reference)
+            .ok_or_else(|| LlmError::NotFound(format!("prompt template '{reference}'")))?
+            .render_with(variables, Some(&self.partials))
+    }
+}
+
+/// Dotted numeric components compare numerically; anything else compares as text.
+fn compare_versions(a: &str, b: &str) -> Ordering {
+    let mut left = a.split('.');
+    let mut right = b.split('.');
+    loop {
+        let ordering = match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) => match (l.parse::<u64>(), r.parse::<u64>()) {
+                (Ok(l), Ok(r)) => l.cmp(&r),
+                _ => l.cmp(r),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn parse_template_file(stem: &str, text: &str) -> Result<PromptTemplate, LlmError> {
+    let mut value: Value = serde_json::from_str(text)
+        .map_err(|e| LlmError::ParseError(format!("prompt template {stem}.json: {e}")))?;
+    let (id, version) = match stem.rsplit_once('@') {
+        Some((id, version)) => (id, Some(version)),
+        None => (stem, None),
+    };
+    if let Some(object) = value.as_object_mut() {
+        object
+            .entry("id")
+            .or_insert_with(|| Value::String(id.replace('/', ".")));
+        if let Some(version) = version {
+            object
+                .entry("version")
+                .or_insert_with(|| Value::String(version.to_string()));
+        }
+    }
+    serde_json::from_value(value)
+        .map_err(|e| LlmError::ParseError(format!("prompt template {stem}.json: {e}")))
+}
+
+fn read(path: &Path) -> Result<String, LlmError> {
+    std::fs::read_to_string(path)
+        .map_err(|e| LlmError::IoError(format!("failed to read {}: {e}", path.display())))
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), LlmError> {
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        LlmError::IoError(format!(
+            "failed to read prompt directory {}: {e}",
+            dir.display()
+        ))
+    })?;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| LlmError::IoError(format!("{}: {e}", dir.display())))?
+            .path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,840 @@
+//! Prompt templates
+//!
+//! Reusable prompts as message-level templates with typed variables, partials and versions.
+//! Templates are plain serde data, so they can be kept in JSON files and loaded with
+//! [`PromptLibrary::from_dir`].
+//!
+//! Text uses a small mustache-like syntax: `{{name}}` and `{{order.id}}` insert variables,
+//! `{{> signature}}` includes a partial, `{{! note }}` is a comment and `\{{` is a literal `{{`.
+//! Image and file parts are bound from variables of type [`VariableType::Image`] /
+//! [`VariableType::File`].
+//!
+//! ```rust
+//! use siumai_core::prompt_template::{
+//!     MessageTemplate, PromptTemplate, PromptVariables, VariableSpec, VariableType,
+//!     template_reference,
+//! };
+//!
+//! let template = PromptTemplate::new("support.reply")
+//!     .with_version("2")
+//!     .with_variable("customer", VariableSpec::new(VariableType::String))
+//!     .with_variable("screenshot", VariableSpec::new(VariableType::Image).optional())
+//!     .with_partial("tone", "Be concise and friendly.")
+//!     .with_message(MessageTemplate::system("You are a support agent. {{> tone}}"))
+//!     .with_message(MessageTemplate::user("Hi, I am {{customer}}.").with_image("screenshot"));
+//! template.validate().unwrap();
+//!
+//! let rendered = template
+//!     .render(&PromptVariables::new().with("customer", "Ada"))
+//!     .unwrap();
+//! assert_eq!(rendered.messages[1].content.all_text(), "Hi, I am Ada.");
+//!
+//! // The request carries `prompt.template_id` / `prompt.template_version` telemetry metadata.
+//! let request = rendered.into_request();
+//! assert_eq!(template_reference(&request), Some(("support.reply", "2")));
+//! ```
+
+mod library;
+mod syntax;
+#[cfg(test)]
+mod tests;
+
+pub use library::PromptLibrary;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::error::LlmError;
+use crate::observability::telemetry::TelemetryConfig;
+use crate::types::{ChatMessage, ChatRequest, ContentPart, ImageDetail, MessageRole};
+use syntax::Segment;
+
+/// Telemetry metadata key holding the id of the template a request was rendered from.
+pub const TEMPLATE_ID_METADATA_KEY: &str = "prompt.template_id";
+/// Telemetry metadata key holding the version of the template a request was rendered from.
+pub const TEMPLATE_VERSION_METADATA_KEY: &str = "prompt.template_version";
+
+/// Partials may include partials, up to this depth (which also catches cycles).
+const MAX_PARTIAL_DEPTH: usize = 16;
+
+/// Type of a template variable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariableType {
+    /// A JSON string.
+    #[default]
+    String,
+    /// Any JSON number.
+    Number,
+    /// An integral JSON number.
+    Integer,
+    /// `true` or `false`.
+    Boolean,
+    /// A JSON array (rendered as JSON in text).
+    Array,
+    /// A JSON object (rendered as JSON in text; fields are reachable with dotted paths).
+    Object,
+    /// An image: a URL string, `{"url": ...}` or `{"data": "<base64>", "media_type": ...}`.
+    Image,
+    /// A file: a URL string, `{"url": ..., "media_type": ...}` or
+    /// `{"data": "<base64>", "media_type": ..., "filename": ...}`.
+    File,
+    /// Any value.
+    Any,
+}
+
+impl VariableType {
+    fn accepts(self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Number => value.is_number(),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Boolean => value.is_boolean(),
+            Self::Array => value.is_array(),
+            Self::Object => value.is_object(),
+            Self::Image | Self::File => {
+                value.is_string()
+                    || ["url", "data"]
+                        .iter()
+                        .any(|key| value.get(key).is_some_and(Value::is_string))
+            }
+            Self::Any => true,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Integer => "integer",
+            Self::Boolean => "boolean",
+            Self::Array => "array",
+            Self::Object => "object",
+            Self::Image => "image",
+            Self::File => "file",
+            Self::Any => "any",
+        }
+    }
+}
+
+fn json_type(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Declaration of a template variable.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VariableSpec {
+    /// Expected type.
+    #[serde(rename = "type", default)]
+    pub ty: VariableType,
+    /// Whether rendering fails when the variable is unbound and has no default.
+    #[serde(default = "default_true")]
+    pub required: bool,
+    /// Value used when the variable is unbound.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    /// Human-readable description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl VariableSpec {
+    /// A required variable of type `ty`.
+    pub fn new(ty: VariableType) -> Self {
+        Self {
+            ty,
+            required: true,
+            default: None,
+            description: None,
+        }
+    }
+
+    /// Allow the variable to be unbound; it then renders as empty text and its media parts are
+    /// omitted.
+    pub fn optional(mut self) -> Self {
+        self.required = false;
+        self
+    }
+
+    /// Value used when the variable is unbound.
+    pub fn with_default(mut self, value: impl Into<Value>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Human-readable description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// One part of a multi-part message template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PartTemplate {
+    /// Templated text.
+    Text {
+        /// Template text.
+        text: String,
+    },
+    /// An image bound from a variable.
+    Image {
+        /// Variable holding the image.
+        variable: String,
+        /// Image detail hint.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        detail: Option<ImageDetail>,
+    },
+    /// A file bound from a variable.
+    File {
+        /// Variable holding the file.
+        variable: String,
+        /// Media type, unless the bound value carries one.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        media_type: Option<String>,
+    },
+}
+
+/// Content of a message template: templated text or a list of parts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContentTemplate {
+    /// Templated text.
+    Text(String),
+    /// Text, image and file parts.
+    Parts(Vec<PartTemplate>),
+}
+
+/// Template for one message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageTemplate {
+    /// Message role (`tool` is not supported).
+    pub role: MessageRole,
+    /// Message content.
+    pub content: ContentTemplate,
+}
+
+impl MessageTemplate {
+    /// A text message template.
+    pub fn new(role: MessageRole, text: impl Into<String>) -> Self {
+        Self {
+            role,
+            content: ContentTemplate::Text(text.into()),
+        }
+    }
+
+    /// A system message template.
+    pub fn system(text: impl Into<String>) -> Self {
+        Self::new(MessageRole::System, text)
+    }
+
+    /// A user message template.
+    pub fn user(text: impl Into<String>) -> Self {
+        Self::new(MessageRole::User, text)
+    }
+
+    /// An assistant message template.
+    pub fn assistant(text: impl Into<String>) -> Self {
+        Self::new(MessageRole::Assistant, text)
+    }
+
+    /// Append a templated text part.
+    pub fn with_text(self, text: impl Into<String>) -> Self {
+        self.with_part(PartTemplate::Text { text: text.into() })
+    }
+
+    /// Append an image part bound from `variable`.
+    pub fn with_image(self, variable: impl Into<String>) -> Self {
+        self.with_part(PartTemplate::Image {
+            variable: variable.into(),
+            detail: None,
+        })
+    }
+
+    /// Append a file part bound from `variable`.
+    pub fn with_file(self, variable: impl Into<String>, media_type: impl Into<String>) -> Self {
+        self.with_part(PartTemplate::File {
+            variable: variable.into(),
+            media_type: Some(media_type.into()),
+        })
+    }
+
+    /// Append a part.
+    pub fn with_part(mut self, part: PartTemplate) -> Self {
+        let parts = match self.content {
+            ContentTemplate::Text(text) if text.is_empty() => vec![part],
+            ContentTemplate::Text(text) => vec![PartTemplate::Text { text }, part],
+            ContentTemplate::Parts(mut parts) => {
+                parts.push(part);
+                parts
+            }
+        };
+        self.content = ContentTemplate::Parts(parts);
+        self
+    }
+}
+
+fn default_version() -> String {
+    "1".to_string()
+}
+
+/// A named, versioned prompt template.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptTemplate {
+    /// Template id, e.g. `support.reply`.
+    pub id: String,
+    /// Template version (defaults to `1`); dotted numeric versions compare numerically.
+    #[serde(default = "default_version")]
+    pub version: String,
+    /// Human-readable description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Declared variables. When empty, every referenced variable is required and untyped.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, VariableSpec>,
+    /// Partials local to this template; they shadow library partials of the same name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub partials: BTreeMap<String, String>,
+    /// Message templates, in order.
+    pub messages: Vec<MessageTemplate>,
+}
+
+impl PromptTemplate {
+    /// An empty template at version `1`.
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            version: default_version(),
+            description: None,
+            variables: BTreeMap::new(),
+            partials: BTreeMap::new(),
+            messages: Vec::new(),
+        }
+    }
+
+    /// Parse a template from JSON and validate it.
+    pub fn from_json(json: &str) -> Result<Self, LlmError> {
+        let template: Self = serde_json::from_str(json)
+            .map_err(|e| LlmError::ParseError(format!("prompt template: {e}")))?;
+        template.validate()?;
+        Ok(template)
+    }
+
+    /// Set the version.
+    pub fn with_version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// Set the description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Declare a variable.
+    pub fn with_variable(mut self, name: impl Into<String>, spec: VariableSpec) -> Self {
+        self.variables.insert(name.into(), spec);
+        self
+    }
+
+    /// Add a local partial.
+    pub fn with_partial(mut self, name: impl Into<String>, text: impl Into<String>) -> Self {
+        self.partials.insert(name.into(), text.into());
+        self
+    }
+
+    /// Append a message template.
+    pub fn with_message(mut self, message: MessageTemplate) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    /// `id@version`.
+    pub fn reference(&self) -> String {
+        format!("{}@{}", self.id, self.version)
+    }
+
+    /// Check syntax, partial references, roles and variable declarations.
+    pub fn validate(&self) -> Result<(), LlmError> {
+        self.validate_with(None)
+    }
+
+    /// Render with the template's own partials.
+    pub fn render(&self, variables: &PromptVariables) -> Result<RenderedPrompt, LlmError> {
+        self.render_with(variables, None)
+    }
+
+    fn error(&self, message: impl std::fmt::Display) -> String {
+        format!("prompt template '{}': {message}", self.reference())
+    }
+
+    fn partial<'a>(
+        &'a self,
+        name: &str,
+        shared: Option<&'a BTreeMap<String, String>>,
+    ) -> Result<&'a str, LlmError> {
+        self.partials
+            .get(name)
+            .or_else(|| shared.and_then(|partials| partials.get(name)))
+            .map(String::as_str)
+            .ok_or_else(|| LlmError::NotFound(self.error(format!("unknown partial '{name}'"))))
+    }
+
+    pub(crate) fn validate_with(
+        &self,
+        shared: Option<&BTreeMap<String, String>>,
+    ) -> Result<(), LlmError> {
+        let invalid = |message: String| LlmError::ConfigurationError(self.error(message));
+        if self.id.trim().is_empty() {
+            return Err(LlmError::ConfigurationError(
+                "prompt template id must not be empty".to_string(),
+            ));
+        }
+        if self.messages.is_empty() {
+            return Err(invalid("has no messages".to_string()));
+        }
+        for (name, spec) in &self.variables {
+            if let Some(default) = &spec.default
+                && !spec.ty.accepts(default)
+            {
+                return Err(invalid(format!(
+                    "default of '{name}' must be {}, got {}",
+                    spec.ty.as_str(),
+                    json_type(default)
+                )));
+            }
+        }
+        for text in self.partials.values() {
+            syntax::parse(text).map_err(|e| invalid(e.to_string()))?;
+        }
+
+        let mut referenced = BTreeSet::new();
+        for message in &self.messages {
+            if message.role == MessageRole::Tool {
+                return Err(invalid("tool messages cannot be templated".to_string()));
+            }
+            match &message.content {
+                ContentTemplate::Text(text) => {
+                    self.collect_variables(text, shared, 0, &mut referenced)?
+                }
+                ContentTemplate::Parts(parts) => {
+                    for part in parts {
+                        let (variable, ty) = match part {
+                            PartTemplate::Text { text } => {
+                                self.collect_variables(text, shared, 0, &mut referenced)?;
+                                continue;
+                            }
+                            PartTemplate::Image { variable, .. } => (variable, VariableType::Image),
+                            PartTemplate::File { variable, .. } => (variable, VariableType::File),
+                        };
+                        if let Some(spec) = self.variables.get(variable)
+                            && spec.ty != VariableType::Any
+                            && spec.ty != ty
+                        {
+                            return Err(invalid(format!(
+                                "'{variable}' is used as {} but declared as {}",
+                                ty.as_str(),
+                                spec.ty.as_str()
+                            )));
+                        }
+                        referenced.insert(variable.clone());
+                    }
+                }
+            }
+        }
+
+        if !self.variables.is_empty() {
+            let undeclared: Vec<&str> = referenced
+                .iter()
+                .filter(|name| !self.variables.contains_key(*name))
+                .map(String::as_str)
+                .collect();
+            if !undeclared.is_empty() {
+                return Err(invalid(format!(
+                    "undeclared variables: {}",
+                    undeclared.join(", ")
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn collect_variables(
+        &self,
+        source: &str,
+        shared: Option<&BTreeMap<String, String>>,
+        depth: usize,
+        referenced: &mut BTreeSet<String>,
+    ) -> Result<(), LlmError> {
+        for segment in
+            syntax::parse(source).map_err(|e| LlmError::ConfigurationError(self.error(e)))?
+        {
+            match segment {
+                Segment::Text(_) => {}
+                Segment::Variable(path) => {
+                    referenced.insert(path[0].clone());
+                }
+                Segment::Partial(name) => {
+                    if depth >= MAX_PARTIAL_DEPTH {
+                        return Err(LlmError::ConfigurationError(
+                            self.error(format!("partial '{name}' nests too deeply (cycle?)")),
+                        ));
+                    }
+                    let text = self.partial(&name, shared)?;
+                    self.collect_variables(text, shared, depth + 1, referenced)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn render_with(
+        &self,
+        variables: &PromptVariables,
+        shared: Option<&BTreeMap<String, String>>,
+    ) -> Result<RenderedPrompt, LlmError> {
+        let mut values: BTreeMap<&str, &Value> = variables
+            .0
+            .iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(name, value)| (name.as_str(), value))
+            .collect();
+        let mut problems = Vec::new();
+        for (name, spec) in &self.variables {
+            if !values.contains_key(name.as_str())
+                && let Some(default) = &spec.default
+            {
+                values.insert(name, default);
+            }
+            match values.get(name.as_str()) {
+                Some(value) if !spec.ty.accepts(value) => problems.push(format!(
+                    "'{name}' must be {}, got {}",
+                    spec.ty.as_str(),
+                    json_type(value)
+                )),
+                None if spec.required => problems.push(format!("missing variable '{name}'")),
+                _ => {}
+            }
+        }
+        if !problems.is_empty() {
+            return Err(LlmError::InvalidInput(self.error(problems.join("; "))));
+        }
+
+        let mut renderer = Renderer {
+            template: self,
+            shared,
+            values,
+            missing: BTreeSet::new(),
+        };
+        let mut messages = Vec::with_capacity(self.messages.len());
+        for message in &self.messages {
+            messages.push(renderer.message(message)?);
+        }
+        if !renderer.missing.is_empty() {
+            let missing: Vec<String> = renderer.missing.into_iter().collect();
+            return Err(LlmError::InvalidInput(
+                self.error(format!("missing variables: {}", missing.join(", "))),
+            ));
+        }
+        Ok(RenderedPrompt {
+            template_id: self.id.clone(),
+            template_version: self.version.clone(),
+            messages,
+        })
+    }
+}
+
+struct Renderer<'a> {
+    template: &'a PromptTemplate,
+    shared: Option<&'a BTreeMap<String, String>>,
+    values: BTreeMap<&'a str, &'a Value>,
+    missing: BTreeSet<String>,
+}
+
+impl<'a> Renderer<'a> {
+    fn message(&mut self, message: &MessageTemplate) -> Result<ChatMessage, LlmError> {
+        let builder = match message.role {
+            MessageRole::System => ChatMessage::system(""),
+            MessageRole::User => ChatMessage::user(""),
+            MessageRole::Assistant => ChatMessage::assistant(""),
+            MessageRole::Developer => ChatMessage::developer(""),
+            MessageRole::Tool => {
+                return Err(LlmError::ConfigurationError(
+                    self.template.error("tool messages cannot be templated"),
+                ));
+            }
+        };
+        let parts = match &message.content {
+            ContentTemplate::Text(text) => {
+                let mut out = String::new();
+                self.text(text, &mut out, 0)?;
+                let mut message = builder.build();
+                message.content = crate::types::MessageContent::Text(out);
+                return Ok(message);
+            }
+            ContentTemplate::Parts(parts) => parts,
+        };
+
+        let mut content = Vec::with_capacity(parts.len());
+        for part in parts {
+            match part {
+                PartTemplate::Text { text } => {
+                    let mut out = String::new();
+                    self.text(text, &mut out, 0)?;
+                    if !out.is_empty() {
+                        content.push(ContentPart::text(out));
+                    }
+                }
+                PartTemplate::Image { variable, detail } => {
+                    if let Some(value) = self.media(variable) {
+                        content.push(self.image(variable, value, detail.clone())?);
+                    }
+                }
+                PartTemplate::File {
+                    variable,
+                    media_type,
+                } => {
+                    if let Some(value) = self.media(variable) {
+                        content.push(self.file(variable, value, media_type.as_deref())?);
+                    }
+                }
+            }
+        }
+        Ok(builder.with_content_parts(content).build())
+    }
+
+    fn text(&mut self, source: &str, out: &mut String, depth: usize) -> Result<(), LlmError> {
+        for segment in syntax::parse(source)
+            .map_err(|e| LlmError::ConfigurationError(self.template.error(e)))?
+        {
+            match segment {
+                Segment::Text(text) => out.push_str(&text),
+                Segment::Variable(path) => match self.lookup(&path) {
+                    Some(Some(Value::String(text))) => out.push_str(text),
+                    Some(Some(Value::Null)) | Some(None) => {}
+                    Some(Some(value)) => out.push_str(&value.to_string()),
+                    None => {
+                        self.missing.insert(path.join("."));
+                    }
+                },
+                Segment::Partial(name) => {
+                    if depth >= MAX_PARTIAL_DEPTH {
+                        return Err(LlmError::ConfigurationError(
+                            self.template
+                                .error(format!("partial '{name}' nests too deeply (cycle?)")),
+                        ));
+                    }
+                    let text = self.template.partial(&name, self.shared)?;
+                    self.text(text, out, depth + 1)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// `None` when the path is missing, `Some(None)` for an unbound optional variable.
+    fn lookup(&self, path: &[String]) -> Option<Option<&'a Value>> {
+        let Some(mut value) = self.values.get(path[0].as_str()).copied() else {
+            return self
+                .template
+                .variables
+                .contains_key(&path[0])
+                .then_some(None);
+        };
+        for key in &path[1..] {
+            value = match value {
+                Value::Object(map) => map.get(key)?,
+                Value::Array(items) => items.get(key.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        Some(Some(value))
+    }
+
+    fn media(&mut self, variable: &str) -> Option<&'a Value> {
+        let value = self.values.get(variable).copied();
+        if value.is_none() && !self.template.variables.contains_key(variable) {
+            self.missing.insert(variable.to_string());
+        }
+        value
+    }
+
+    fn source(&self, variable: &str, value: &'a Value) -> Result<MediaSource<'a>, LlmError> {
+        if let Some(url) = value.as_str() {
+            return Ok(MediaSource::Url(url));
+        }
+        if let Some(url) = value.get("url").and_then(Value::as_str) {
+            return Ok(MediaSource::Url(url));
+        }
+        if let Some(data) = value.get("data").and_then(Value::as_str) {
+            return Ok(MediaSource::Data(data));
+        }
+        Err(LlmError::InvalidInput(self.template.error(format!(
+            "'{variable}' needs a URL string or an object with `url` or `data`"
+        ))))
+    }
+
+    fn image(
+        &self,
+        variable: &str,
+        value: &'a Value,
+        detail: Option<ImageDetail>,
+    ) -> Result<ContentPart, LlmError> {
+        let mut part = match (self.source(variable, value)?, detail) {
+            (MediaSource::Url(url), Some(detail)) => {
+                ContentPart::image_url_with_detail(url, detail)
+            }
+            (MediaSource::Url(url), None) => ContentPart::image_url(url),
+            (MediaSource::Data(data), detail) => {
+                let mut part = ContentPart::image_base64(data);
+                if let ContentPart::Image { detail: slot, .. } = &mut part {
+                    *slot = detail;
+                }
+                part
+            }
+        };
+        if let Some(media_type) = value.get("media_type").and_then(Value::as_str) {
+            part = part.with_image_media_type(media_type);
+        }
+        Ok(part)
+    }
+
+    fn file(
+        &self,
+        variable: &str,
+        value: &'a Value,
+        media_type: Option<&str>,
+    ) -> Result<ContentPart, LlmError> {
+        let media_type = value
+            .get("media_type")
+            .and_then(Value::as_str)
+            .or(media_type)
+            .ok_or_else(|| {
+                LlmError::InvalidInput(
+                    self.template
+                        .error(format!("file '{variable}' needs a media_type")),
+                )
+            })?;
+        let filename = value
+            .get("filename")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        Ok(match self.source(variable, value)? {
+            MediaSource::Url(url) => ContentPart::file_url(url, media_type),
+            MediaSource::Data(data) => ContentPart::file_base64(data, media_type, filename),
+        })
+    }
+}
+
+enum MediaSource<'a> {
+    Url(&'a str),
+    Data(&'a str),
+}
+
+/// Variable bindings for rendering.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PromptVariables(Map<String, Value>);
+
+impl PromptVariables {
+    /// No bindings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Bind every field of a serializable struct (or map).
+    pub fn from_serialize<T: Serialize>(value: &T) -> Result<Self, LlmError> {
+        match serde_json::to_value(value)
+            .map_err(|e| LlmError::InvalidInput(format!("prompt variables: {e}")))?
+        {
+            Value::Object(map) => Ok(Self(map)),
+            other => Err(LlmError::InvalidInput(format!(
+                "prompt variables must serialize to an object, got {}",
+                json_type(&other)
+            ))),
+        }
+    }
+
+    /// Bind `name`.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Bind `name` in place.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<Value>) {
+        self.0.insert(name.into(), value.into());
+    }
+
+    /// Bound value of `name`.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.get(name)
+    }
+}
+
+impl From<Map<String, Value>> for PromptVariables {
+    fn from(map: Map<String, Value>) -> Self {
+        Self(map)
+    }
+}
+
+/// Messages rendered from a template.
+#[derive(Debug, Clone)]
+pub struct RenderedPrompt {
+    /// Id of the source template.
+    pub template_id: String,
+    /// Version of the source template.
+    pub template_version: String,
+    /// Rendered messages.
+    pub messages: Vec<ChatMessage>,
+}
+
+impl RenderedPrompt {
+    /// A request with the rendered messages, tagged with the template id and version.
+    pub fn into_request(self) -> ChatRequest {
+        let mut request = ChatRequest::new(Vec::new());
+        self.record(&mut request);
+        request.messages = self.messages;
+        request
+    }
+
+    /// Tag `request` with the template id and version in its telemetry metadata (creating a
+    /// disabled telemetry config if the request has none).
+    pub fn record(&self, request: &mut ChatRequest) {
+        let telemetry = request
+            .telemetry
+            .get_or_insert_with(TelemetryConfig::default);
+        telemetry.metadata.insert(
+            TEMPLATE_ID_METADATA_KEY.to_string(),
+            self.template_id.clone(),
+        );
+        telemetry.metadata.insert(
+            TEMPLATE_VERSION_METADATA_KEY.to_string(),
+            self.template_version.clone(),
+        );
+    }
+}
+
+/// Template id and version recorded on `request` by [`RenderedPrompt::record`].
+pub fn template_reference(request: &ChatRequest) -> Option<(&str, &str)> {
+    let metadata = &request.telemetry.as_ref()?.metadata;
+    Some((
+        metadata.get(TEMPLATE_ID_METADATA_KEY)?,
+        metadata.get(TEMPLATE_VERSION_METADATA_KEY)?,
+    ))
+}
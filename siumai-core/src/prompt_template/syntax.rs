@@ -0,0 +1,78 @@
+//! Template text syntax.
+//!
+//! - `{{name}}` / `{{order.id}}` insert a variable (dotted paths index into objects and arrays)
+//! - `{{> signature}}` includes a partial
+//! - `{{! note }}` is a comment
+//! - `\{{` emits a literal `{{`
+
+use crate::error::LlmError;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Segment {
+    Text(String),
+    Variable(Vec<String>),
+    Partial(String),
+}
+
+pub(crate) fn parse(source: &str) -> Result<Vec<Segment>, LlmError> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut rest = source;
+
+    while let Some(start) = rest.find("{{") {
+        if rest[..start].ends_with('\\') {
+            text.push_str(&rest[..start - 1]);
+            text.push_str("{{");
+            rest = &rest[start + 2..];
+            continue;
+        }
+        text.push_str(&rest[..start]);
+        let offset = source.len() - rest.len() + start;
+        let after = &rest[start + 2..];
+        let end = after.find("}}").ok_or_else(|| {
+            LlmError::ParseError(format!(
+                "unclosed '{{{{' at byte {offset} in prompt template"
+            ))
+        })?;
+        let tag = after[..end].trim();
+        rest = &after[end + 2..];
+
+        if tag.starts_with('!') {
+            continue;
+        }
+        if !text.is_empty() {
+            segments.push(Segment::Text(std::mem::take(&mut text)));
+        }
+        if let Some(name) = tag.strip_prefix('>') {
+            let name = name.trim();
+            if !is_name(name, true) {
+                return Err(LlmError::ParseError(format!(
+                    "invalid partial name '{name}' at byte {offset} in prompt template"
+                )));
+            }
+            segments.push(Segment::Partial(name.to_string()));
+        } else {
+            let path: Vec<String> = tag.split('.').map(str::to_string).collect();
+            if !path.iter().all(|p| is_name(p, false)) {
+                return Err(LlmError::ParseError(format!(
+                    "invalid variable '{tag}' at byte {offset} in prompt template"
+                )));
+            }
+            segments.push(Segment::Variable(path));
+        }
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+    Ok(segments)
+}
+
+fn is_name(name: &str, partial: bool) -> bool {
+    !name.is_empty()
+        && name.chars().all(|c| {
+            c.is_ascii_alphanumeric()
+                || matches!(c, '_' | '-')
+                || (partial && matches!(c, '.' | '/'))
+        })
+}
@@ -0,0 +1,250 @@
+use serde_json::json;
+
+use super::*;
+use crate::types::MessageContent;
+
+fn support_template() -> PromptTemplate {
+    PromptTemplate::new("support.reply")
+        .with_version("2")
+        .with_variable("customer", VariableSpec::new(VariableType::String))
+        .with_variable("order", VariableSpec::new(VariableType::Object))
+        .with_variable(
+            "tone",
+            VariableSpec::new(VariableType::String).with_default("friendly"),
+        )
+        .with_variable(
+            "screenshot",
+            VariableSpec::new(VariableType::Image).optional(),
+        )
+        .with_partial("style", "Be {{tone}}. {{> signature}}")
+        .with_partial("signature", "Sign as Acme.")
+        .with_message(MessageTemplate::system(
+            "You help {{customer}}. {{> style}}{{! internal note }}",
+        ))
+        .with_message(
+            MessageTemplate::user("Order {{order.id}} ({{order.items.0}}) \\{{raw}}")
+                .with_image("screenshot"),
+        )
+}
+
+#[test]
+fn renders_variables_partials_defaults_and_optional_media() {
+    let template = support_template();
+    template.validate().unwrap();
+    let variables = PromptVariables::new()
+        .with("customer", "Ada")
+        .with("order", json!({"id": 42, "items": ["lamp"]}));
+
+    let rendered = template.render(&variables).unwrap();
+    assert_eq!(
+        rendered.messages[0].content.all_text(),
+        "You help Ada. Be friendly. Sign as Acme."
+    );
+    // The unbound optional screenshot drops its part, leaving a single text part.
+    match &rendered.messages[1].content {
+        MessageContent::MultiModal(parts) => {
+            assert_eq!(parts.len(), 1);
+            assert_eq!(parts[0].as_text(), Some("Order 42 (lamp) {{raw}}"));
+        }
+        other => panic!("unexpected content: {other:?}"),
+    }
+
+    let with_image = template
+        .render(&variables.with(
+            "screenshot",
+            json!({"data": "aGk=", "media_type": "image/png"}),
+        ))
+        .unwrap();
+    match &with_image.messages[1].content {
+        MessageContent::MultiModal(parts) => assert!(parts[1].is_image()),
+        other => panic!("unexpected content: {other:?}"),
+    }
+}
+
+#[test]
+fn reports_missing_and_mistyped_variables() {
+    let template = support_template();
+    let err = template
+        .render(&PromptVariables::new().with("order", "not an object"))
+        .unwrap_err();
+    let message = err.to_string();
+    assert!(matches!(err, LlmError::InvalidInput(_)));
+    assert!(message.contains("support.reply@2"));
+    assert!(message.contains("missing variable 'customer'"));
+    assert!(message.contains("'order' must be object, got string"));
+
+    let err = template
+        .render(
+            &PromptVariables::new()
+                .with("customer", "Ada")
+                .with("order", json!({"items": []})),
+        )
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("missing variables: order.id, order.items.0")
+    );
+
+    // Undeclared templates require every referenced variable.
+    let loose = PromptTemplate::new("loose").with_message(MessageTemplate::user("{{a}} {{b}}"));
+    let err = loose
+        .render(&PromptVariables::new().with("a", 1))
+        .unwrap_err();
+    assert!(err.to_string().contains("missing variables: b"));
+}
+
+#[test]
+fn validation_catches_template_mistakes() {
+    let undeclared = support_template().with_message(MessageTemplate::user("{{extra}}"));
+    assert!(
+        undeclared
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("undeclared variables: extra")
+    );
+
+    let cycle = PromptTemplate::new("cycle")
+        .with_partial("a", "{{> b}}")
+        .with_partial("b", "{{> a}}")
+        .with_message(MessageTemplate::user("{{> a}}"));
+    assert!(cycle.validate().unwrap_err().to_string().contains("cycle"));
+
+    let unclosed = PromptTemplate::new("bad").with_message(MessageTemplate::user("Hi {{name"));
+    assert!(
+        unclosed
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("unclosed")
+    );
+
+    let wrong_media = PromptTemplate::new("media")
+        .with_variable("doc", VariableSpec::new(VariableType::String))
+        .with_message(MessageTemplate::user("See").with_file("doc", "application/pdf"));
+    assert!(
+        wrong_media
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("'doc' is used as file but declared as string")
+    );
+}
+
+#[test]
+fn templates_round_trip_through_json_and_bind_structs() {
+    #[derive(serde::Serialize)]
+    struct Invoice {
+        customer: &'static str,
+        invoice: &'static str,
+    }
+
+    let template = PromptTemplate::from_json(
+        r#"{
+            "id": "billing.summarize",
+            "variables": {
+                "customer": {"type": "string"},
+                "invoice": {"type": "file"}
+            },
+            "messages": [
+                {"role": "system", "content": "Summarize invoices."},
+                {"role": "user", "content": [
+                    {"type": "text", "text": "Invoice for {{customer}}:"},
+                    {"type": "file", "variable": "invoice", "media_type": "application/pdf"}
+                ]}
+            ]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(template.version, "1");
+    let json = serde_json::to_string(&template).unwrap();
+    assert_eq!(PromptTemplate::from_json(&json).unwrap(), template);
+
+    let variables = PromptVariables::from_serialize(&Invoice {
+        customer: "Ada",
+        invoice: "https://example.com/inv.pdf",
+    })
+    .unwrap();
+    let rendered = template.render(&variables).unwrap();
+    match &rendered.messages[1].content {
+        MessageContent::MultiModal(parts) => {
+            assert!(matches!(
+                &parts[1],
+                ContentPart::File { media_type, .. } if media_type == "application/pdf"
+            ));
+        }
+        other => panic!("unexpected content: {other:?}"),
+    }
+
+    let mut request = ChatRequest::new(Vec::new());
+    rendered.record(&mut request);
+    assert_eq!(
+        template_reference(&request),
+        Some(("billing.summarize", "1"))
+    );
+    assert!(!request.telemetry.unwrap().enabled);
+}
+
+#[test]
+fn library_loads_versioned_templates_and_partials_from_a_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("support")).unwrap();
+    std::fs::create_dir_all(root.join("partials/legal")).unwrap();
+    std::fs::write(
+        root.join("partials/legal/footer.md"),
+        "Reply STOP to opt out.",
+    )
+    .unwrap();
+    std::fs::write(root.join("README.txt"), "ignored").unwrap();
+    for (version, greeting) in [("2", "Hello"), ("10", "Hi"), ("9", "Hey")] {
+        std::fs::write(
+            root.join(format!("support/reply@{version}.json")),
+            json!({
+                "messages": [
+                    {"role": "user", "content": format!("{greeting} {{{{name}}}}. {{{{> legal/footer}}}}")}
+                ]
+            })
+            .to_string(),
+        )
+        .unwrap();
+    }
+
+    let library = PromptLibrary::from_dir(root).unwrap();
+    assert_eq!(library.ids().collect::<Vec<_>>(), ["support.reply"]);
+    assert_eq!(library.versions("support.reply"), ["2", "9", "10"]);
+
+    let variables = PromptVariables::new().with("name", "Ada");
+    let latest = library.render("support.reply", &variables).unwrap();
+    assert_eq!(latest.template_version, "10");
+    assert_eq!(
+        latest.messages[0].content.all_text(),
+        "Hi Ada. Reply STOP to opt out."
+    );
+    let request = library
+        .render("support.reply@9", &variables)
+        .unwrap()
+        .into_request();
+    assert_eq!(template_reference(&request), Some(("support.reply", "9")));
+    assert_eq!(
+        request.messages[0].content.all_text(),
+        "Hey Ada. Reply STOP to opt out."
+    );
+
+    assert!(matches!(
+        library.render("support.reply@3", &variables),
+        Err(LlmError::NotFound(_))
+    ));
+
+    let mut library = library;
+    let duplicate = PromptTemplate::new("support.reply")
+        .with_version("9")
+        .with_message(MessageTemplate::user("again"));
+    assert!(library.add(duplicate).is_err());
+    let missing_partial =
+        PromptTemplate::new("other").with_message(MessageTemplate::user("{{> nope}}"));
+    assert!(matches!(
+        library.add(missing_partial),
+        Err(LlmError::NotFound(_))
+    ));
+}
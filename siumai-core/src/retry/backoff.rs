@@ -3,7 +3,9 @@
 //! This module provides a professional retry implementation using the `backoff` crate,
 //! which is more robust and feature-complete than our custom implementation.
 
+use backoff::backoff::Backoff;
 use backoff::{ExponentialBackoff, ExponentialBackoffBuilder};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use crate::error::{LlmError, LlmErrorExt};
@@ -12,6 +14,7 @@ use crate::error::{LlmError, LlmErrorExt};
 #[derive(Debug, Clone)]
 pub struct BackoffRetryExecutor {
     backoff: ExponentialBackoff,
+    respect_retry_after: bool,
 }
 
 impl BackoffRetryExecutor {
@@ -19,12 +22,25 @@ impl BackoffRetryExecutor {
     pub fn new() -> Self {
         Self {
             backoff: Self::recommended_default_backoff(),
+            respect_retry_after: true,
         }
     }
 
     /// Create a retry executor with custom backoff configuration
     pub fn with_backoff(backoff: ExponentialBackoff) -> Self {
-        Self { backoff }
+        Self {
+            backoff,
+            respect_retry_after: true,
+        }
+    }
+
+    /// Enable or disable honoring server-provided retry-after delays.
+    ///
+    /// When enabled (the default), a retryable error carrying a retry-after hint waits that
+    /// long, capped by the backoff's `max_interval`, instead of the next backoff interval.
+    pub fn with_respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
     }
 
     /// Execute an operation with retry logic
@@ -34,21 +50,52 @@ impl BackoffRetryExecutor {
         Fut: std::future::Future<Output = Result<T, LlmError>> + Send,
         T: Send,
     {
-        backoff::future::retry(self.backoff.clone(), || async {
-            match operation().await {
-                Ok(result) => Ok(result),
-                Err(error) => {
-                    if error.is_retryable() {
-                        Err(backoff::Error::Transient {
-                            err: error,
-                            retry_after: None,
-                        })
-                    } else {
-                        Err(backoff::Error::Permanent(error))
+        self.execute_with_notify(operation, |_, _| {}).await
+    }
+
+    /// Execute with retry logic, calling `notify` with the error and delay before each retry
+    pub async fn execute_with_notify<F, Fut, T, N>(
+        &self,
+        operation: F,
+        mut notify: N,
+    ) -> Result<T, LlmError>
+    where
+        F: Fn() -> Fut + Send + Sync,
+        Fut: std::future::Future<Output = Result<T, LlmError>> + Send,
+        T: Send,
+        N: FnMut(&LlmError, Duration) + Send,
+    {
+        // The server delay replaces the next backoff interval but never extends the
+        // overall `max_elapsed_time` budget.
+        let hint = Arc::new(Mutex::new(None));
+        let backoff = RetryAfterBackoff {
+            inner: self.backoff.clone(),
+            hint: hint.clone(),
+        };
+        backoff::future::retry_notify(
+            backoff,
+            || async {
+                match operation().await {
+                    Ok(result) => Ok(result),
+                    Err(error) => {
+                        if error.is_retryable() {
+                            if self.respect_retry_after
+                                && let Ok(mut slot) = hint.lock()
+                            {
+                                *slot = error.retry_after();
+                            }
+                            Err(backoff::Error::Transient {
+                                err: error,
+                                retry_after: None,
+                            })
+                        } else {
+                            Err(backoff::Error::Permanent(error))
+                        }
                     }
                 }
-            }
-        })
+            },
+            |error: LlmError, delay| notify(&error, delay),
+        )
         .await
     }
 
@@ -63,6 +110,24 @@ impl BackoffRetryExecutor {
     }
 }
 
+/// Exponential backoff that swaps in the retry-after delay recorded for the last error.
+struct RetryAfterBackoff {
+    inner: ExponentialBackoff,
+    hint: Arc<Mutex<Option<Duration>>>,
+}
+
+impl Backoff for RetryAfterBackoff {
+    fn next_backoff(&mut self) -> Option<Duration> {
+        let next = self.inner.next_backoff()?;
+        let server_delay = self.hint.lock().ok().and_then(|mut slot| slot.take());
+        Some(server_delay.map_or(next, |delay| delay.min(self.inner.max_interval)))
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
 impl Default for BackoffRetryExecutor {
     fn default() -> Self {
         Self::new()
@@ -97,7 +162,7 @@ mod tests {
             .execute(|| async {
                 let count = counter_clone.fetch_add(1, Ordering::SeqCst);
                 if count == 0 {
-                    Err(LlmError::RateLimitError("Rate limited".to_string()))
+                    Err(LlmError::rate_limit_error("Rate limited"))
                 } else {
                     Ok("Success".to_string())
                 }
@@ -25,6 +25,9 @@ pub struct RetryPolicy {
     pub jitter_factor: f64,
     /// Custom retry condition function
     pub retry_condition: Option<fn(&LlmError) -> bool>,
+    /// Wait for the server-provided retry-after delay (capped by `max_delay`) when the error
+    /// carries one
+    pub respect_retry_after: bool,
 }
 
 impl Default for RetryPolicy {
@@ -37,6 +40,7 @@ impl Default for RetryPolicy {
             use_jitter: true,
             jitter_factor: 0.1,
             retry_condition: None,
+            respect_retry_after: true,
         }
     }
 }
@@ -89,6 +93,12 @@ impl RetryPolicy {
         self
     }
 
+    /// Enable or disable honoring server-provided retry-after delays
+    pub const fn with_respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
     /// Check if an error should be retried
     pub fn should_retry(&self, error: &LlmError) -> bool {
         if let Some(condition) = self.retry_condition {
@@ -112,6 +122,17 @@ impl RetryPolicy {
         }
     }
 
+    /// Calculate the delay before retrying after `error`.
+    ///
+    /// Uses the server-provided retry-after delay when present (capped by `max_delay`),
+    /// otherwise falls back to [`RetryPolicy::calculate_delay`].
+    pub fn delay_for_error(&self, attempt: u32, error: &LlmError) -> Duration {
+        match error.retry_after() {
+            Some(server_delay) if self.respect_retry_after => server_delay.min(self.max_delay),
+            _ => self.calculate_delay(attempt),
+        }
+    }
+
     /// Add jitter to a delay
     fn add_jitter(&self, delay: Duration) -> Duration {
         let jitter_range = delay.as_millis() as f64 * self.jitter_factor;
@@ -134,10 +155,24 @@ impl RetryExecutor {
     }
 
     /// Execute a function with retry logic
-    pub async fn execute<F, Fut, T>(&self, mut operation: F) -> Result<T, LlmError>
+    pub async fn execute<F, Fut, T>(&self, operation: F) -> Result<T, LlmError>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, LlmError>>,
+    {
+        self.execute_with_notify(operation, |_, _| {}).await
+    }
+
+    /// Execute with retry logic, calling `notify` with the error and delay before each retry
+    pub async fn execute_with_notify<F, Fut, T, N>(
+        &self,
+        mut operation: F,
+        mut notify: N,
+    ) -> Result<T, LlmError>
     where
         F: FnMut() -> Fut,
         Fut: std::future::Future<Output = Result<T, LlmError>>,
+        N: FnMut(&LlmError, Duration),
     {
         let mut last_error = None;
 
@@ -158,7 +193,8 @@ impl RetryExecutor {
                     }
 
                     // Calculate and apply delay
-                    let delay = self.policy.calculate_delay(attempt);
+                    let delay = self.policy.delay_for_error(attempt, &error);
+                    notify(&error, delay);
                     sleep(delay).await;
                 }
             }
@@ -205,7 +241,7 @@ impl RetryExecutor {
                     }
 
                     // Calculate and apply delay
-                    let delay = self.policy.calculate_delay(attempt);
+                    let delay = self.policy.delay_for_error(attempt, &error);
                     sleep(delay).await;
                 }
             }
@@ -252,6 +288,7 @@ mod tests {
                             code: 500,
                             message: "Server error".to_string(),
                             details: None,
+                            http_details: None,
                         })
                     } else {
                         Ok("success")
@@ -282,6 +319,7 @@ mod tests {
                         code: 500,
                         message: "Server error".to_string(),
                         details: None,
+                        http_details: None,
                     })
                 }
             })
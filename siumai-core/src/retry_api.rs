@@ -23,8 +23,9 @@
 //! # }
 //! ```
 
-use crate::error::LlmError;
+use crate::error::{HttpErrorDetails, LlmError};
 use reqwest::header::HeaderMap;
+use std::time::Duration;
 
 // Re-export core types for convenience
 pub use crate::retry::BackoffRetryExecutor;
@@ -106,6 +107,24 @@ impl RetryOptions {
         self.idempotent = idempotent;
         self
     }
+
+    /// Set whether server-provided retry-after delays are honored (default: true).
+    ///
+    /// Server delays are capped by the policy `max_delay` (Policy backend) or the backoff
+    /// `max_interval` (Backoff backend).
+    pub fn with_respect_retry_after(mut self, respect: bool) -> Self {
+        match self.backend {
+            RetryBackend::Backoff => {
+                let executor = self.backoff_executor.take().unwrap_or_default();
+                self.backoff_executor = Some(executor.with_respect_retry_after(respect));
+            }
+            RetryBackend::Policy => {
+                let policy = self.policy.take().unwrap_or_default();
+                self.policy = Some(policy.with_respect_retry_after(respect));
+            }
+        }
+        self
+    }
 }
 
 /// Recommended default retry (backoff-based)
@@ -125,18 +144,38 @@ where
     Fut: std::future::Future<Output = Result<T, LlmError>> + Send,
     T: Send,
 {
+    retry_with_notify(operation, options, |_, _, _| {}).await
+}
+
+/// Retry with explicit options, calling `notify(error, attempt, delay)` before each retry.
+///
+/// `attempt` is 1-based (1 means the first retry) and `delay` is the wait before it, which
+/// already accounts for any server-provided retry-after hint.
+pub async fn retry_with_notify<F, Fut, T, N>(
+    operation: F,
+    options: RetryOptions,
+    mut notify: N,
+) -> Result<T, LlmError>
+where
+    F: Fn() -> Fut + Send + Sync,
+    Fut: std::future::Future<Output = Result<T, LlmError>> + Send,
+    T: Send,
+    N: FnMut(&LlmError, usize, Duration) + Send,
+{
+    let mut attempt = 0usize;
+    let notify = move |error: &LlmError, delay: Duration| {
+        attempt += 1;
+        notify(error, attempt, delay);
+    };
     match options.backend {
         RetryBackend::Backoff => {
-            if let Some(executor) = options.backoff_executor.as_ref() {
-                executor.execute(operation).await
-            } else {
-                crate::retry::retry_with_backoff(operation).await
-            }
+            let executor = options.backoff_executor.unwrap_or_default();
+            executor.execute_with_notify(operation, notify).await
         }
         RetryBackend::Policy => {
             let policy = options.policy.unwrap_or_default();
             let executor = crate::retry::RetryExecutor::new(policy);
-            executor.execute(operation).await
+            executor.execute_with_notify(operation, notify).await
         }
     }
 }
@@ -161,18 +200,171 @@ where
     }
 }
 
+/// Response headers that carry a provider request id, in lookup order.
+const REQUEST_ID_HEADERS: [&str; 4] = [
+    "x-request-id",
+    "request-id",
+    "x-amzn-requestid",
+    "apim-request-id",
+];
+
+/// Server-provided retry delay from the response headers.
+///
+/// Looks at `retry-after-ms` (milliseconds), then `retry-after` (seconds or an HTTP date),
+/// then the `x-ratelimit-reset-*` family (durations like `1m30s`/`250ms`, seconds, Unix
+/// timestamps or RFC 3339 dates; the longest reset wins).
+pub fn retry_after_from_headers(headers: &HeaderMap) -> Option<Duration> {
+    let header_str = |name: &str| headers.get(name).and_then(|v| v.to_str().ok());
+
+    if let Some(ms) = header_str("retry-after-ms").and_then(|v| v.trim().parse::<f64>().ok()) {
+        return Duration::try_from_secs_f64(ms / 1000.0).ok();
+    }
+    if let Some(delay) = header_str("retry-after").and_then(parse_retry_after_value) {
+        return Some(delay);
+    }
+    headers
+        .iter()
+        .filter(|(name, _)| name.as_str().starts_with("x-ratelimit-reset"))
+        .filter_map(|(_, value)| value.to_str().ok().and_then(parse_rate_limit_reset))
+        .max()
+}
+
+/// Build structured details (status, provider code/type, request id, retry-after, headers)
+/// for a failed HTTP response.
+pub fn http_error_details(status: u16, body_text: &str, headers: &HeaderMap) -> HttpErrorDetails {
+    let mut details = HttpErrorDetails::new().with_status(status).with_headers(
+        crate::execution::http::headers::headermap_to_hashmap(headers),
+    );
+
+    if let Some(request_id) = REQUEST_ID_HEADERS
+        .iter()
+        .find_map(|name| headers.get(*name).and_then(|v| v.to_str().ok()))
+    {
+        details = details.with_request_id(request_id);
+    }
+    if let Some(delay) = retry_after_from_headers(headers) {
+        details = details.with_retry_after(delay);
+    }
+
+    // Common envelopes: `{"error": {"code", "type" | "status"}}`, possibly wrapped in an array.
+    if let Ok(json) = serde_json::from_str::<serde_json::Value>(body_text) {
+        let root = json
+            .as_array()
+            .and_then(|items| items.first())
+            .unwrap_or(&json);
+        let error = root.get("error").filter(|e| e.is_object()).unwrap_or(root);
+        match error.get("code") {
+            Some(serde_json::Value::String(code)) => details = details.with_provider_code(code),
+            Some(serde_json::Value::Number(code)) => {
+                details = details.with_provider_code(code.to_string())
+            }
+            _ => {}
+        }
+        if let Some(error_type) = error
+            .get("type")
+            .or_else(|| error.get("status"))
+            .and_then(|v| v.as_str())
+        {
+            details = details.with_error_type(error_type);
+        }
+    }
+    details
+}
+
+fn parse_retry_after_value(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<f64>() {
+        return Duration::try_from_secs_f64(seconds).ok();
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    Some(duration_until(date.with_timezone(&chrono::Utc)))
+}
+
+fn parse_rate_limit_reset(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(number) = value.parse::<f64>() {
+        // Large values are Unix timestamps; small ones are seconds from now.
+        if number > 1_000_000_000.0 {
+            return chrono::DateTime::from_timestamp(number as i64, 0).map(duration_until);
+        }
+        return Duration::try_from_secs_f64(number).ok();
+    }
+    if let Ok(date) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(duration_until(date.with_timezone(&chrono::Utc)));
+    }
+    parse_duration_literal(value)
+}
+
+/// Parse Go-style duration literals such as `6m0s`, `1.5s` or `250ms`.
+fn parse_duration_literal(value: &str) -> Option<Duration> {
+    let is_number = |c: char| c.is_ascii_digit() || c == '.';
+    let mut rest = value;
+    let mut seconds = 0.0;
+    while !rest.is_empty() {
+        let number_end = rest.find(|c: char| !is_number(c))?;
+        if number_end == 0 {
+            return None;
+        }
+        let number: f64 = rest[..number_end].parse().ok()?;
+        rest = &rest[number_end..];
+        let unit_end = rest.find(is_number).unwrap_or(rest.len());
+        let scale = match &rest[..unit_end] {
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 1e-3,
+            "us" | "µs" => 1e-6,
+            "ns" => 1e-9,
+            _ => return None,
+        };
+        seconds += number * scale;
+        rest = &rest[unit_end..];
+    }
+    Duration::try_from_secs_f64(seconds).ok()
+}
+
+fn duration_until(at: chrono::DateTime<chrono::Utc>) -> Duration {
+    (at - chrono::Utc::now()).to_std().unwrap_or(Duration::ZERO)
+}
+
 /// Classify an HTTP failure into a more specific error type with retry hints.
 ///
 /// This helper inspects the HTTP status code, response body and headers to
 /// derive a better-typed LlmError (e.g., RateLimitError / QuotaExceededError)
 /// rather than a generic ApiError. It is provider-agnostic with light-weight
-/// heuristics.
+/// heuristics. Context-length, content-filter and overload failures are normalized through
+/// [`crate::error::normalize_provider_failure`]; those and rate limit/quota errors carry
+/// [`HttpErrorDetails`].
 pub fn classify_http_error(
     provider_id: &str,
     status: u16,
     body_text: &str,
     headers: &HeaderMap,
     fallback_message: Option<&str>,
+) -> LlmError {
+    let details = http_error_details(status, body_text, headers);
+    let codes = [
+        details.provider_code.as_deref(),
+        details.error_type.as_deref(),
+    ];
+    classify_http_status(
+        provider_id,
+        status,
+        &codes,
+        body_text,
+        headers,
+        fallback_message,
+    )
+    .with_http_details(details)
+}
+
+fn classify_http_status(
+    provider_id: &str,
+    status: u16,
+    codes: &[Option<&str>],
+    body_text: &str,
+    headers: &HeaderMap,
+    fallback_message: Option<&str>,
 ) -> LlmError {
     let lower = body_text.to_lowercase();
 
@@ -202,13 +394,26 @@ pub fn classify_http_error(
     // Limit body sample size to avoid noisy logs
     let body_sample = body_text.chars().take(200).collect::<String>();
 
+    // Context-length, content-filter and overload failures. Checked before the quota heuristics
+    // below because context-length messages usually say "exceeds".
+    if let Some(error) = crate::error::normalize_provider_failure(
+        status,
+        codes,
+        &format!(
+            "provider={} http={}{} body_sample={}",
+            provider_id, status, ids_suffix, body_sample
+        ),
+    ) {
+        return error;
+    }
+
     // 429 Too Many Requests → RateLimit with optional Retry-After hint
     if status == 429 {
         let retry_after = headers
             .get("retry-after")
             .and_then(|v| v.to_str().ok())
             .unwrap_or("");
-        return LlmError::RateLimitError(format!(
+        return LlmError::rate_limit_error(format!(
             "provider={} http=429 retry_after={}{} body_sample={}",
             provider_id, retry_after, ids_suffix, body_sample
         ));
@@ -254,13 +459,13 @@ pub fn classify_http_error(
             || lower.contains("ratelimitexceeded")
             || lower.contains("ratelimit exceeded");
         if quota_like {
-            return LlmError::QuotaExceededError(format!(
+            return LlmError::quota_exceeded_error(format!(
                 "provider={} quota exceeded",
                 provider_id
             ));
         }
         if rate_like {
-            return LlmError::RateLimitError(format!("provider={} rate limited", provider_id));
+            return LlmError::rate_limit_error(format!("provider={} rate limited", provider_id));
         }
     }
 
@@ -335,6 +540,7 @@ mod tests {
                             code: 500,
                             message: "server".into(),
                             details: None,
+                            http_details: None,
                         })
                     } else {
                         Ok(())
@@ -359,11 +565,7 @@ mod tests {
             Some("Bad Gateway"),
         );
         match err {
-            LlmError::ApiError {
-                code,
-                message,
-                details: _,
-            } => {
+            LlmError::ApiError { code, message, .. } => {
                 assert_eq!(code, 502);
                 assert_eq!(message, "Bad Gateway");
             }
@@ -371,6 +573,257 @@ mod tests {
         }
     }
 
+    fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut map = HeaderMap::new();
+        for (name, value) in pairs {
+            map.insert(*name, value.parse().unwrap());
+        }
+        map
+    }
+
+    #[test]
+    fn retry_after_headers_are_parsed_in_priority_order() {
+        assert_eq!(
+            retry_after_from_headers(&headers(&[
+                ("retry-after-ms", "1500"),
+                ("retry-after", "9")
+            ])),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            retry_after_from_headers(&headers(&[("retry-after", "2")])),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            retry_after_from_headers(&headers(&[
+                ("x-ratelimit-reset-requests", "1m30s"),
+                ("x-ratelimit-reset-tokens", "250ms"),
+            ])),
+            Some(Duration::from_secs(90))
+        );
+        assert_eq!(
+            retry_after_from_headers(&headers(&[("retry-after", "soon")])),
+            None
+        );
+        assert_eq!(retry_after_from_headers(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn retry_after_http_date_is_relative_to_now() {
+        let at = chrono::Utc::now() + chrono::Duration::seconds(30);
+        let delay = retry_after_from_headers(&headers(&[("retry-after", &at.to_rfc2822())]))
+            .expect("http date");
+        assert!(delay <= Duration::from_secs(30) && delay >= Duration::from_secs(28));
+
+        let past = chrono::Utc::now() - chrono::Duration::seconds(30);
+        assert_eq!(
+            retry_after_from_headers(&headers(&[("retry-after", &past.to_rfc2822())])),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn classify_http_error_attaches_structured_details_to_rate_limits() {
+        let body =
+            r#"{"error":{"message":"slow down","type":"requests","code":"rate_limit_exceeded"}}"#;
+        let err = classify_http_error(
+            "provider-a",
+            429,
+            body,
+            &headers(&[("retry-after", "3"), ("x-request-id", "req_123")]),
+            None,
+        );
+        assert!(matches!(err, LlmError::RateLimitError { .. }));
+        let details = err.http_details().expect("details");
+        assert_eq!(details.status, Some(429));
+        assert_eq!(
+            details.provider_code.as_deref(),
+            Some("rate_limit_exceeded")
+        );
+        assert_eq!(details.error_type.as_deref(), Some("requests"));
+        assert_eq!(details.request_id.as_deref(), Some("req_123"));
+        assert_eq!(
+            details.headers.get("retry-after").map(String::as_str),
+            Some("3")
+        );
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn classify_http_error_normalizes_context_length_before_quota_heuristics() {
+        let body = r#"{"error":{"code":400,"message":"The input token count (1200000) exceeds the maximum number of tokens allowed (1048576).","status":"INVALID_ARGUMENT"}}"#;
+        let err = classify_http_error("provider-a", 400, body, &HeaderMap::new(), None);
+        assert!(
+            matches!(err, LlmError::ContextLengthExceeded { .. }),
+            "{err:?}"
+        );
+        let details = err.http_details().expect("details");
+        assert_eq!(details.status, Some(400));
+        assert_eq!(details.error_type.as_deref(), Some("INVALID_ARGUMENT"));
+    }
+
+    #[test]
+    fn classify_http_error_normalizes_overload() {
+        use crate::error::LlmErrorExt;
+
+        let body = r#"{"error":{"message":"The model is overloaded. Please try again later.","type":"server_error"}}"#;
+        let err = classify_http_error(
+            "provider-a",
+            503,
+            body,
+            &headers(&[("retry-after", "2")]),
+            None,
+        );
+        assert!(matches!(err, LlmError::Overloaded { .. }), "{err:?}");
+        assert!(err.is_retryable());
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn classify_http_error_keeps_retry_after_on_plain_server_errors() {
+        let err = classify_http_error(
+            "provider-a",
+            503,
+            "service unavailable",
+            &headers(&[("retry-after", "4"), ("x-request-id", "req-503")]),
+            None,
+        );
+        assert!(
+            matches!(
+                err,
+                LlmError::ApiError {
+                    code: 503,
+                    details: None,
+                    ..
+                }
+            ),
+            "{err:?}"
+        );
+        assert_eq!(err.retry_after(), Some(Duration::from_secs(4)));
+        let details = err.http_details().expect("http details");
+        assert_eq!(details.status, Some(503));
+        assert_eq!(details.request_id.as_deref(), Some("req-503"));
+        assert_eq!(
+            details.headers.get("retry-after").map(String::as_str),
+            Some("4")
+        );
+
+        let policy = RetryPolicy::new()
+            .with_max_delay(Duration::from_secs(10))
+            .with_jitter(false);
+        assert_eq!(policy.delay_for_error(1, &err), Duration::from_secs(4));
+    }
+
+    #[tokio::test]
+    async fn policy_backend_waits_for_server_delay_capped_by_max_delay() {
+        use std::sync::{Arc, Mutex};
+
+        let policy = RetryPolicy::new()
+            .with_max_attempts(3)
+            .with_initial_delay(Duration::from_millis(1))
+            .with_max_delay(Duration::from_millis(20))
+            .with_jitter(false);
+        let options = RetryOptions {
+            policy: Some(policy),
+            ..RetryOptions::policy_default()
+        };
+        let hints = [Duration::from_millis(5), Duration::from_secs(60)];
+        let calls = Arc::new(Mutex::new(0usize));
+        let delays = Arc::new(Mutex::new(Vec::new()));
+        let delays_for_notify = delays.clone();
+
+        let result = retry_with_notify(
+            || {
+                let calls = calls.clone();
+                async move {
+                    let mut calls = calls.lock().unwrap();
+                    *calls += 1;
+                    match hints.get(*calls - 1) {
+                        Some(hint) => Err(LlmError::rate_limit_error("slow down")
+                            .with_http_details(HttpErrorDetails::new().with_retry_after(*hint))),
+                        None => Ok("done"),
+                    }
+                }
+            },
+            options,
+            move |error, attempt, delay| {
+                assert!(matches!(error, LlmError::RateLimitError { .. }));
+                delays_for_notify.lock().unwrap().push((attempt, delay));
+            },
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), "done");
+        assert_eq!(
+            *delays.lock().unwrap(),
+            vec![
+                (1, Duration::from_millis(5)),
+                (2, Duration::from_millis(20)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn respect_retry_after_can_be_disabled() {
+        let policy = RetryPolicy::new()
+            .with_max_attempts(2)
+            .with_initial_delay(Duration::from_millis(1))
+            .with_jitter(false);
+        let options = RetryOptions {
+            policy: Some(policy),
+            ..RetryOptions::policy_default()
+        }
+        .with_respect_retry_after(false);
+        let mut delays = Vec::new();
+
+        let result: Result<(), LlmError> = retry_with_notify(
+            || async {
+                Err(LlmError::rate_limit_error("slow down").with_http_details(
+                    HttpErrorDetails::new().with_retry_after(Duration::from_secs(60)),
+                ))
+            },
+            options,
+            |_, _, delay| delays.push(delay),
+        )
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(delays, vec![Duration::from_millis(1)]);
+    }
+
+    #[tokio::test]
+    async fn backoff_backend_uses_server_delay() {
+        use backoff::ExponentialBackoffBuilder;
+
+        let backoff = ExponentialBackoffBuilder::new()
+            .with_initial_interval(Duration::from_secs(30))
+            .with_max_interval(Duration::from_secs(30))
+            .with_max_elapsed_time(Some(Duration::from_secs(120)))
+            .build();
+        let options = RetryOptions::backoff()
+            .with_backoff_executor(BackoffRetryExecutor::with_backoff(backoff));
+        let calls = std::sync::atomic::AtomicU32::new(0);
+        let mut delays = Vec::new();
+
+        let result = retry_with_notify(
+            || async {
+                if calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst) == 0 {
+                    Err(LlmError::rate_limit_error("slow down").with_http_details(
+                        HttpErrorDetails::new().with_retry_after(Duration::from_millis(10)),
+                    ))
+                } else {
+                    Ok(())
+                }
+            },
+            options,
+            |_, attempt, delay| delays.push((attempt, delay)),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(delays, vec![(1, Duration::from_millis(10))]);
+    }
+
     #[test]
     fn generic_http_error_classifier_source_stays_provider_agnostic() {
         let source = include_str!("retry_api.rs");
@@ -5,6 +5,8 @@
 //! `SpeechCapability` while provider construction continues moving toward
 //! model-family traits.
 
+pub mod pipeline;
+
 use async_trait::async_trait;
 
 use crate::error::LlmError;
@@ -0,0 +1,775 @@
+//! Chat-to-speech streaming pipeline.
+//!
+//! Voice assistants want to start speaking before the language model has finished. The pipeline
+//! consumes text deltas from a [`ChatStream`], cuts them into sentence or clause segments, and
+//! synthesizes the segments with bounded concurrency while emitting their audio as one ordered
+//! [`AudioStream`]. Reasoning, tool-call and other non-text parts are ignored.
+//!
+//! Cancelling the returned handle (for example when the user barges in) stops reading the chat
+//! stream, aborts in-flight synthesis and ends the audio stream without a `Done` event.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use tokio::sync::{Semaphore, mpsc};
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+
+use crate::error::LlmError;
+use crate::speech::SpeechModel;
+use crate::streaming::ChatStream;
+use crate::traits::SpeechExtras;
+use crate::types::{AudioStream, AudioStreamEvent, CancelHandle, ChatStreamEvent, TtsRequest};
+
+/// Sentence terminators that only end a sentence when followed by whitespace.
+const SPACED_TERMINATORS: &[char] = &['.', '!', '?', '…', '؟', '।', '॥'];
+/// Sentence terminators used by scripts written without spaces (CJK full-width punctuation).
+const UNSPACED_TERMINATORS: &[char] = &['。', '！', '？', '．', '\n'];
+/// Clause separators that only split when followed by whitespace.
+const SPACED_CLAUSE_SEPARATORS: &[char] = &[',', ';', ':', '،', '؛'];
+/// Clause separators used by scripts written without spaces.
+const UNSPACED_CLAUSE_SEPARATORS: &[char] = &['，', '、', '；', '：'];
+/// Closing punctuation that stays attached to the sentence it ends.
+const CLOSERS: &[char] = &['"', '\'', '”', '’', ')', ']', '」', '』', '）', '》'];
+/// Lowercased abbreviations whose trailing period does not end a sentence.
+const ABBREVIATIONS: &[&str] = &[
+    "mr", "mrs", "ms", "dr", "prof", "sr", "jr", "st", "vs", "etc", "e.g", "i.e", "no", "fig",
+];
+
+/// Incremental sentence/clause segmenter for streamed text.
+///
+/// Sentences end at terminal punctuation (Latin, Devanagari, Arabic and full-width CJK forms);
+/// clauses end at commas, semicolons and colons once a segment holds at least
+/// `min_clause_chars` characters; segments longer than `max_segment_chars` are cut at the last
+/// whitespace. Periods after common abbreviations, initials and inside numbers do not split.
+#[derive(Debug, Clone)]
+pub struct SentenceSegmenter {
+    buffer: String,
+    min_clause_chars: usize,
+    max_segment_chars: usize,
+}
+
+impl Default for SentenceSegmenter {
+    fn default() -> Self {
+        Self::new(40, 300)
+    }
+}
+
+impl SentenceSegmenter {
+    /// Create a segmenter with explicit clause and segment size limits (in characters).
+    pub fn new(min_clause_chars: usize, max_segment_chars: usize) -> Self {
+        Self {
+            buffer: String::new(),
+            min_clause_chars,
+            max_segment_chars: max_segment_chars.max(1),
+        }
+    }
+
+    /// Whether text is buffered but not yet emitted.
+    pub fn has_pending(&self) -> bool {
+        !self.buffer.trim().is_empty()
+    }
+
+    /// Append a text delta and return the segments it completed.
+    pub fn push(&mut self, delta: &str) -> Vec<String> {
+        self.buffer.push_str(delta);
+
+        let mut segments = Vec::new();
+        while let Some(end) = self.find_boundary() {
+            self.take_segment(end, &mut segments);
+        }
+        while self.buffer.chars().count() > self.max_segment_chars {
+            let end = self.overflow_cut();
+            self.take_segment(end, &mut segments);
+        }
+        segments
+    }
+
+    /// Emit buffered text early, cutting at the last whitespace when there is one.
+    ///
+    /// Used for max-latency flushes; text after the last whitespace stays buffered so words are
+    /// not split.
+    pub fn flush_partial(&mut self) -> Option<String> {
+        let trimmed_len = self.buffer.trim_end().len();
+        let end = self.buffer[..trimmed_len]
+            .rfind(char::is_whitespace)
+            .filter(|idx| !self.buffer[..*idx].trim().is_empty())
+            .unwrap_or(self.buffer.len());
+        let mut segments = Vec::new();
+        self.take_segment(end, &mut segments);
+        segments.pop()
+    }
+
+    /// Emit whatever text is left.
+    pub fn finish(&mut self) -> Option<String> {
+        let rest = std::mem::take(&mut self.buffer);
+        let rest = rest.trim();
+        (!rest.is_empty()).then(|| rest.to_string())
+    }
+
+    fn take_segment(&mut self, end: usize, segments: &mut Vec<String>) {
+        let rest = self.buffer.split_off(end);
+        let segment = std::mem::replace(&mut self.buffer, rest.trim_start().to_string());
+        let segment = segment.trim();
+        if !segment.is_empty() {
+            segments.push(segment.to_string());
+        }
+    }
+
+    /// Byte offset just past the first sentence or clause boundary, if one is complete.
+    fn find_boundary(&self) -> Option<usize> {
+        let chars: Vec<(usize, char)> = self.buffer.char_indices().collect();
+        for (pos, &(idx, c)) in chars.iter().enumerate() {
+            let chars_so_far = pos + 1;
+            let end = idx + c.len_utf8();
+
+            if UNSPACED_TERMINATORS.contains(&c) {
+                return Some(Self::skip_closers(&chars, pos + 1).map_or(end, |(i, _)| i));
+            }
+            if UNSPACED_CLAUSE_SEPARATORS.contains(&c) && chars_so_far >= self.min_clause_chars {
+                return Some(end);
+            }
+
+            let spaced_terminator = SPACED_TERMINATORS.contains(&c);
+            let spaced_clause = SPACED_CLAUSE_SEPARATORS.contains(&c);
+            if !spaced_terminator && !spaced_clause {
+                continue;
+            }
+
+            // Wait for the next character: the boundary is only known once whitespace follows.
+            let (next_idx, next) = Self::skip_closers(&chars, pos + 1)?;
+            if !next.is_whitespace() {
+                continue;
+            }
+            if spaced_terminator && !(c == '.' && self.is_abbreviation(idx)) {
+                return Some(next_idx);
+            }
+            if spaced_clause && chars_so_far >= self.min_clause_chars {
+                return Some(next_idx);
+            }
+        }
+        None
+    }
+
+    /// First character at or after `pos` that is not closing punctuation.
+    fn skip_closers(chars: &[(usize, char)], pos: usize) -> Option<(usize, char)> {
+        chars[pos.min(chars.len())..]
+            .iter()
+            .copied()
+            .find(|(_, c)| !CLOSERS.contains(c))
+    }
+
+    fn is_abbreviation(&self, dot_idx: usize) -> bool {
+        let word = self.buffer[..dot_idx]
+            .rsplit(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or("");
+        let word = word.trim_start_matches(|c: char| !c.is_alphanumeric());
+        let mut letters = word.chars();
+        // Initials such as "J. R. R. Tolkien".
+        if letters.next().is_some_and(char::is_alphabetic) && letters.next().is_none() {
+            return true;
+        }
+        ABBREVIATIONS.contains(&word.to_lowercase().as_str())
+    }
+
+    fn overflow_cut(&self) -> usize {
+        let limit = self
+            .buffer
+            .char_indices()
+            .nth(self.max_segment_chars)
+            .map_or(self.buffer.len(), |(idx, _)| idx);
+        self.buffer[..limit]
+            .rfind(char::is_whitespace)
+            .filter(|idx| *idx > 0)
+            .unwrap_or(limit)
+    }
+}
+
+/// Options for [`speak_chat_stream`] and [`speak_chat_stream_with_extras`].
+#[derive(Debug, Clone)]
+pub struct SpeechPipelineOptions {
+    /// Request template for every segment; its `text` is replaced by the segment text.
+    pub request: TtsRequest,
+    /// Maximum number of segments synthesized concurrently (at least 1).
+    pub max_concurrency: usize,
+    /// Flush buffered text after this long without a sentence boundary.
+    pub max_latency: Option<Duration>,
+    /// Minimum segment length (characters) before clause separators split.
+    pub min_clause_chars: usize,
+    /// Maximum segment length (characters) before a forced split.
+    pub max_segment_chars: usize,
+    /// Optional caller-owned cancellation handle (e.g. shared with the chat request).
+    pub cancel: Option<CancelHandle>,
+}
+
+impl Default for SpeechPipelineOptions {
+    fn default() -> Self {
+        Self {
+            request: TtsRequest::new(String::new()),
+            max_concurrency: 2,
+            max_latency: Some(Duration::from_millis(1500)),
+            min_clause_chars: 40,
+            max_segment_chars: 300,
+            cancel: None,
+        }
+    }
+}
+
+impl SpeechPipelineOptions {
+    /// Create options with a per-segment request template (voice, format, model...).
+    pub fn new(request: TtsRequest) -> Self {
+        Self {
+            request,
+            ..Default::default()
+        }
+    }
+
+    /// Set the maximum number of concurrently synthesized segments.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Set the max-latency flush interval (`None` disables latency flushes).
+    pub fn with_max_latency(mut self, max_latency: Option<Duration>) -> Self {
+        self.max_latency = max_latency;
+        self
+    }
+
+    /// Set the segment size limits in characters.
+    pub fn with_segment_limits(
+        mut self,
+        min_clause_chars: usize,
+        max_segment_chars: usize,
+    ) -> Self {
+        self.min_clause_chars = min_clause_chars;
+        self.max_segment_chars = max_segment_chars;
+        self
+    }
+
+    /// Use a caller-owned cancellation handle.
+    pub fn with_cancel(mut self, cancel: CancelHandle) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+}
+
+/// Ordered audio stream produced by the pipeline plus its cancellation handle.
+pub struct SpeechStreamHandle {
+    /// Audio events of all segments, in segment order, followed by one `Done`.
+    pub stream: AudioStream,
+    /// Cancels the pipeline (barge-in). Dropping `stream` cancels it as well.
+    pub cancel: CancelHandle,
+}
+
+/// Speak a chat stream through a [`SpeechModel`] (one request per segment).
+pub fn speak_chat_stream(
+    chat: ChatStream,
+    model: Arc<dyn SpeechModel>,
+    options: SpeechPipelineOptions,
+) -> SpeechStreamHandle {
+    run_pipeline(chat, Synthesizer::Model(model), options)
+}
+
+/// Speak a chat stream through [`SpeechExtras::tts_stream`] (audio streams per segment).
+pub fn speak_chat_stream_with_extras(
+    chat: ChatStream,
+    extras: Arc<dyn SpeechExtras>,
+    options: SpeechPipelineOptions,
+) -> SpeechStreamHandle {
+    run_pipeline(chat, Synthesizer::Extras(extras), options)
+}
+
+#[derive(Clone)]
+enum Synthesizer {
+    Model(Arc<dyn SpeechModel>),
+    Extras(Arc<dyn SpeechExtras>),
+}
+
+type SegmentSender = mpsc::Sender<Result<AudioStreamEvent, LlmError>>;
+type SegmentReceiver = mpsc::Receiver<Result<AudioStreamEvent, LlmError>>;
+
+impl Synthesizer {
+    async fn run(&self, request: TtsRequest, tx: &SegmentSender) {
+        match self {
+            Self::Model(model) => match model.synthesize(request).await {
+                Ok(response) => {
+                    if response.sample_rate.is_some() || response.duration.is_some() {
+                        let metadata = AudioStreamEvent::Metadata {
+                            sample_rate: response.sample_rate,
+                            duration: response.duration,
+                            metadata: HashMap::new(),
+                        };
+                        if tx.send(Ok(metadata)).await.is_err() {
+                            return;
+                        }
+                    }
+                    let _ = tx
+                        .send(Ok(AudioStreamEvent::AudioDelta {
+                            data: response.audio_data,
+                            format: response.format,
+                        }))
+                        .await;
+                }
+                Err(error) => {
+                    let _ = tx.send(Err(error)).await;
+                }
+            },
+            Self::Extras(extras) => match extras.tts_stream(request).await {
+                Ok(mut stream) => {
+                    while let Some(item) = stream.next().await {
+                        if tx.send(item).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(error) => {
+                    let _ = tx.send(Err(error)).await;
+                }
+            },
+        }
+    }
+}
+
+fn run_pipeline(
+    chat: ChatStream,
+    synthesizer: Synthesizer,
+    options: SpeechPipelineOptions,
+) -> SpeechStreamHandle {
+    let cancel = options.cancel.clone().unwrap_or_default();
+    // The pipeline runs on a child token so dropping the audio stream stops the pipeline
+    // without cancelling a caller-owned handle shared with other work.
+    let token = cancel.token().child_token();
+    let (order_tx, order_rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(drive_segments(
+        chat,
+        synthesizer,
+        options,
+        order_tx,
+        token.clone(),
+    ));
+
+    SpeechStreamHandle {
+        stream: ordered_audio(order_rx, token),
+        cancel,
+    }
+}
+
+/// Read the chat stream, segment its text and start one synthesis task per segment.
+async fn drive_segments(
+    mut chat: ChatStream,
+    synthesizer: Synthesizer,
+    options: SpeechPipelineOptions,
+    order_tx: mpsc::UnboundedSender<SegmentReceiver>,
+    token: CancellationToken,
+) {
+    let dispatcher = SegmentDispatcher {
+        synthesizer,
+        template: options.request,
+        permits: Arc::new(Semaphore::new(options.max_concurrency.max(1))),
+        order_tx,
+        token: token.clone(),
+    };
+    let mut segmenter = SentenceSegmenter::new(options.min_clause_chars, options.max_segment_chars);
+    let mut pending_since: Option<Instant> = None;
+
+    loop {
+        let deadline = pending_since
+            .zip(options.max_latency)
+            .map(|(since, latency)| since + latency);
+
+        // `biased`: once cancelled, never pick up another ready delta (barge-in).
+        let item = tokio::select! {
+            biased;
+            _ = token.cancelled() => return,
+            _ = sleep_until_deadline(deadline), if deadline.is_some() => {
+                if let Some(segment) = segmenter.flush_partial()
+                    && !dispatcher.dispatch(Ok(segment)).await
+                {
+                    return;
+                }
+                pending_since = segmenter.has_pending().then(Instant::now);
+                continue;
+            }
+            item = chat.next() => item,
+        };
+
+        let delta = match item {
+            None => break,
+            Some(Ok(ChatStreamEvent::Error { error })) => {
+                dispatcher.dispatch(Err(LlmError::StreamError(error))).await;
+                return;
+            }
+            Some(Ok(event)) => match event.text_delta() {
+                Some(delta) => delta.to_string(),
+                None => continue,
+            },
+            Some(Err(error)) => {
+                dispatcher.dispatch(Err(error)).await;
+                return;
+            }
+        };
+
+        let segments = segmenter.push(&delta);
+        let emitted = !segments.is_empty();
+        for segment in segments {
+            if !dispatcher.dispatch(Ok(segment)).await {
+                return;
+            }
+        }
+        pending_since = match (segmenter.has_pending(), emitted) {
+            (false, _) => None,
+            (true, true) => Some(Instant::now()),
+            (true, false) => pending_since.or_else(|| Some(Instant::now())),
+        };
+    }
+
+    if let Some(segment) = segmenter.finish() {
+        dispatcher.dispatch(Ok(segment)).await;
+    }
+}
+
+async fn sleep_until_deadline(deadline: Option<Instant>) {
+    if let Some(deadline) = deadline {
+        tokio::time::sleep_until(deadline).await;
+    }
+}
+
+struct SegmentDispatcher {
+    synthesizer: Synthesizer,
+    template: TtsRequest,
+    permits: Arc<Semaphore>,
+    order_tx: mpsc::UnboundedSender<SegmentReceiver>,
+    token: CancellationToken,
+}
+
+impl SegmentDispatcher {
+    /// Queue a segment (or a terminal error) in output order. Returns `false` once the
+    /// pipeline is cancelled or the audio stream was dropped.
+    async fn dispatch(&self, segment: Result<String, LlmError>) -> bool {
+        let (tx, rx) = mpsc::channel(16);
+
+        let text = match segment {
+            Ok(text) => text,
+            Err(error) => {
+                let _ = tx.try_send(Err(error));
+                return self.order_tx.send(rx).is_ok();
+            }
+        };
+
+        let permit = tokio::select! {
+            biased;
+            _ = self.token.cancelled() => return false,
+            permit = Arc::clone(&self.permits).acquire_owned() => match permit {
+                Ok(permit) => permit,
+                Err(_) => return false,
+            },
+        };
+        if self.order_tx.send(rx).is_err() {
+            return false;
+        }
+
+        let mut request = self.template.clone();
+        request.text = text;
+        let synthesizer = self.synthesizer.clone();
+        let token = self.token.clone();
+        tokio::spawn(async move {
+            let _permit = permit;
+            tokio::select! {
+                biased;
+                _ = token.cancelled() => {}
+                _ = synthesizer.run(request, &tx) => {}
+            }
+        });
+        true
+    }
+}
+
+/// Forward segment audio in order, collapsing per-segment `Done` events into one final `Done`.
+fn ordered_audio(
+    mut order_rx: mpsc::UnboundedReceiver<SegmentReceiver>,
+    token: CancellationToken,
+) -> AudioStream {
+    let stream = async_stream::stream! {
+        let _guard = token.clone().drop_guard();
+        let mut total_duration: Option<f32> = None;
+        let mut segments = 0u64;
+
+        loop {
+            let next = tokio::select! {
+                biased;
+                _ = token.cancelled() => return,
+                next = order_rx.recv() => next,
+            };
+            let Some(mut segment_rx) = next else { break };
+            segments += 1;
+            let mut segment_duration = None;
+
+            loop {
+                let item = tokio::select! {
+                    biased;
+                    _ = token.cancelled() => return,
+                    item = segment_rx.recv() => item,
+                };
+                match item {
+                    None => break,
+                    Some(Ok(AudioStreamEvent::Done { duration, .. })) => {
+                        segment_duration = duration.or(segment_duration);
+                    }
+                    Some(Ok(event)) => {
+                        if let AudioStreamEvent::Metadata { duration: Some(duration), .. } = &event {
+                            segment_duration = Some(*duration);
+                        }
+                        yield Ok(event);
+                    }
+                    Some(Err(error)) => {
+                        token.cancel();
+                        yield Err(error);
+                        return;
+                    }
+                }
+            }
+
+            if let Some(duration) = segment_duration {
+                total_duration = Some(total_duration.unwrap_or(0.0) + duration);
+            }
+        }
+
+        let mut metadata = HashMap::new();
+        metadata.insert("segments".to_string(), serde_json::json!(segments));
+        yield Ok(AudioStreamEvent::Done {
+            duration: total_duration,
+            metadata,
+        });
+    };
+    Box::pin(stream)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{ModelMetadata, SpeechCapability};
+    use crate::types::{ChatStreamPart, TtsResponse};
+    use std::sync::Mutex;
+
+    fn segment_all(segmenter: &mut SentenceSegmenter, deltas: &[&str]) -> Vec<String> {
+        let mut out = Vec::new();
+        for delta in deltas {
+            out.extend(segmenter.push(delta));
+        }
+        out.extend(segmenter.finish());
+        out
+    }
+
+    #[test]
+    fn segmenter_splits_sentences_across_deltas() {
+        let mut segmenter = SentenceSegmenter::default();
+        let out = segment_all(
+            &mut segmenter,
+            &[
+                "Hello there",
+                ". Dr. Smith paid $3.50 today! Is J. R. ",
+                "ok?",
+                " Bye",
+            ],
+        );
+        assert_eq!(
+            out,
+            vec![
+                "Hello there.",
+                "Dr. Smith paid $3.50 today!",
+                "Is J. R. ok?",
+                "Bye"
+            ]
+        );
+    }
+
+    #[test]
+    fn segmenter_handles_unspaced_scripts_and_closers() {
+        let mut segmenter = SentenceSegmenter::default();
+        let out = segment_all(
+            &mut segmenter,
+            &["你好。今天天气", "很好！", "He said \"stop.\" Then left"],
+        );
+        assert_eq!(
+            out,
+            vec!["你好。", "今天天气很好！", "He said \"stop.\"", "Then left"]
+        );
+    }
+
+    #[test]
+    fn segmenter_uses_clauses_and_limits_for_long_text() {
+        let mut segmenter = SentenceSegmenter::new(10, 20);
+        let out = segment_all(
+            &mut segmenter,
+            &[
+                "Short, then a much longer clause, ",
+                "and an unterminated tail that keeps going",
+            ],
+        );
+        assert_eq!(
+            out,
+            vec![
+                "Short, then a much longer clause,",
+                "and an unterminated",
+                "tail that keeps",
+                "going"
+            ]
+        );
+
+        let mut segmenter = SentenceSegmenter::default();
+        segmenter.push("partial words here");
+        assert_eq!(segmenter.flush_partial().as_deref(), Some("partial words"));
+        assert_eq!(segmenter.finish().as_deref(), Some("here"));
+    }
+
+    struct RecordingSpeech {
+        seen: Mutex<Vec<String>>,
+    }
+
+    impl ModelMetadata for RecordingSpeech {
+        fn provider_id(&self) -> &str {
+            "fake"
+        }
+
+        fn model_id(&self) -> &str {
+            "fake-speech"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl SpeechCapability for RecordingSpeech {
+        async fn tts(&self, request: TtsRequest) -> Result<TtsResponse, LlmError> {
+            self.seen.lock().unwrap().push(request.text.clone());
+            // Later segments finish first to prove output order follows segment order.
+            let delay = 30u64.saturating_sub(request.text.len() as u64);
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+            Ok(TtsResponse {
+                audio_data: request.text.into_bytes(),
+                format: request.format.unwrap_or_default(),
+                duration: Some(1.0),
+                sample_rate: None,
+                metadata: HashMap::new(),
+                request: None,
+                warnings: None,
+                provider_metadata: None,
+                response: None,
+            })
+        }
+    }
+
+    fn chat_stream(events: Vec<ChatStreamEvent>) -> ChatStream {
+        Box::pin(futures::stream::iter(events.into_iter().map(Ok)))
+    }
+
+    #[tokio::test]
+    async fn pipeline_speaks_text_parts_in_order() {
+        let model = Arc::new(RecordingSpeech {
+            seen: Mutex::new(Vec::new()),
+        });
+        let chat = chat_stream(vec![
+            ChatStreamEvent::Part {
+                part: ChatStreamPart::ReasoningDelta {
+                    id: "r".to_string(),
+                    delta: "Thinking. Hard.".to_string(),
+                    provider_metadata: None,
+                },
+            },
+            ChatStreamEvent::text_delta_part("t", "A much longer first sentence. "),
+            ChatStreamEvent::text_delta_part("t", "Short. Tail"),
+        ]);
+
+        let handle = speak_chat_stream(
+            chat,
+            model.clone(),
+            SpeechPipelineOptions::new(TtsRequest::new(String::new()).with_format("pcm".into()))
+                .with_max_concurrency(3),
+        );
+        let events: Vec<_> = handle.stream.collect().await;
+
+        let mut audio = Vec::new();
+        let mut done = None;
+        for event in events {
+            match event.expect("audio event") {
+                AudioStreamEvent::AudioDelta { data, format } => {
+                    assert_eq!(format, "pcm");
+                    audio.push(String::from_utf8(data).unwrap());
+                }
+                AudioStreamEvent::Done { duration, metadata } => done = Some((duration, metadata)),
+                _ => {}
+            }
+        }
+        assert_eq!(
+            audio,
+            vec!["A much longer first sentence.", "Short.", "Tail"]
+        );
+        let (duration, metadata) = done.expect("final done");
+        assert_eq!(duration, Some(3.0));
+        assert_eq!(metadata["segments"], serde_json::json!(3));
+        assert!(
+            !model
+                .seen
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|t| t.contains("Thinking"))
+        );
+    }
+
+    #[tokio::test]
+    async fn pipeline_flushes_after_max_latency_and_stops_on_cancel() {
+        let model = Arc::new(RecordingSpeech {
+            seen: Mutex::new(Vec::new()),
+        });
+        let (tx, rx) = mpsc::unbounded_channel::<Result<ChatStreamEvent, LlmError>>();
+        let chat: ChatStream = Box::pin(tokio_stream_from(rx));
+
+        let mut handle = speak_chat_stream(
+            chat,
+            model.clone(),
+            SpeechPipelineOptions::default().with_max_latency(Some(Duration::from_millis(20))),
+        );
+        tx.send(Ok(ChatStreamEvent::text_delta_part("t", "no boundary yet")))
+            .unwrap();
+
+        match handle.stream.next().await {
+            Some(Ok(AudioStreamEvent::Metadata { .. })) => {}
+            other => panic!("expected metadata, got {other:?}"),
+        }
+        match handle.stream.next().await {
+            Some(Ok(AudioStreamEvent::AudioDelta { data, .. })) => {
+                assert_eq!(data, b"no boundary");
+            }
+            other => panic!("expected flushed audio, got {other:?}"),
+        }
+
+        handle.cancel.cancel();
+        tx.send(Ok(ChatStreamEvent::text_delta_part("t", " more. Text.")))
+            .ok();
+        assert!(handle.stream.next().await.is_none());
+        // The dispatcher drops the chat stream when it exits; wait for that, then give any
+        // synthesis task it started a chance to run before checking what was spoken.
+        tokio::time::timeout(Duration::from_secs(1), tx.closed())
+            .await
+            .expect("pipeline stops reading the chat stream after cancel");
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        // "yet" may hit its own deadline before the cancel; nothing sent after it may be spoken.
+        let seen = model.seen.lock().unwrap();
+        assert_eq!(seen[0], "no boundary");
+        assert!(!seen.iter().any(|text| text.contains("more")));
+    }
+
+    fn tokio_stream_from<T: Send + 'static>(
+        mut rx: mpsc::UnboundedReceiver<T>,
+    ) -> impl futures::Stream<Item = T> + Send {
+        async_stream::stream! {
+            while let Some(item) = rx.recv().await {
+                yield item;
+            }
+        }
+    }
+}
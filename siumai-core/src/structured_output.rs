@@ -74,6 +74,66 @@ pub enum PartialJsonValueStreamEvent {
 pub type PartialJsonValueStream =
     Pin<Box<dyn Stream<Item = Result<PartialJsonValueStreamEvent, LlmError>> + Send>>;
 
+/// A completed top-level element found by [`JsonArrayElementScanner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScannedJsonArrayElement {
+    /// Zero-based position of the element in the array.
+    pub index: usize,
+    /// Raw JSON text of the element, trimmed of surrounding whitespace.
+    pub json: String,
+}
+
+/// Incremental scanner that extracts completed elements of a streamed JSON array.
+///
+/// Text is fed in arbitrary chunks; each top-level element is returned exactly once, as soon
+/// as the `,` or `]` that terminates it arrives. Leading prose and markdown fences are skipped.
+/// The array is either the root value (`[...]`) or the first array-valued property of a root
+/// object (`{"elements": [...]}`), which covers tool-call arguments.
+#[derive(Debug, Clone, Default)]
+pub struct JsonArrayElementScanner {
+    buffer: String,
+    cursor: usize,
+    stack: Vec<u8>,
+    in_string: bool,
+    escaped: bool,
+    array_depth: Option<usize>,
+    element_start: Option<usize>,
+    next_index: usize,
+    finished: bool,
+}
+
+/// Streaming event emitted by `json_array_element_stream`.
+#[derive(Debug, Clone)]
+pub enum JsonArrayElementStreamEvent {
+    /// A completed array element.
+    Element {
+        /// Zero-based position of the element in the array.
+        index: usize,
+        /// Parsed element value.
+        value: serde_json::Value,
+    },
+    /// A completed array element that is not valid JSON; the stream continues.
+    ElementError {
+        /// Zero-based position of the element in the array.
+        index: usize,
+        /// Parse error for this element.
+        error: LlmError,
+    },
+    /// End of the source stream.
+    Finish {
+        /// Number of elements seen, including invalid ones.
+        elements: usize,
+        /// Whether the closing `]` of the array was seen.
+        complete: bool,
+        /// Final response assembled from the source stream.
+        response: Box<ChatResponse>,
+    },
+}
+
+/// Stream of completed array elements from a chat stream.
+pub type JsonArrayElementStream =
+    Pin<Box<dyn Stream<Item = Result<JsonArrayElementStreamEvent, LlmError>> + Send>>;
+
 fn process_partial_value_start(
     stack: &mut Vec<JsonRepairState>,
     ch: char,
@@ -484,6 +544,170 @@ pub fn partial_json_value_stream(mut stream: ChatStream) -> PartialJsonValueStre
     })
 }
 
+impl JsonArrayElementScanner {
+    /// Create an empty scanner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a chunk of text and return the elements it completed, in order.
+    pub fn push_str(&mut self, delta: &str) -> Vec<ScannedJsonArrayElement> {
+        self.buffer.push_str(delta);
+        let mut completed = Vec::new();
+
+        // Structural characters are ASCII, so byte-wise scanning is UTF-8 safe.
+        while self.cursor < self.buffer.len() && !self.finished {
+            let pos = self.cursor;
+            let byte = self.buffer.as_bytes()[pos];
+            self.cursor += 1;
+
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                }
+                continue;
+            }
+
+            // Until the root value starts, skip prose and fences without tracking strings.
+            if self.stack.is_empty() && !matches!(byte, b'[' | b'{') {
+                continue;
+            }
+
+            let at_element_level = self.array_depth == Some(self.stack.len());
+            if at_element_level
+                && self.element_start.is_none()
+                && !byte.is_ascii_whitespace()
+                && !matches!(byte, b',' | b']')
+            {
+                self.element_start = Some(pos);
+            }
+
+            match byte {
+                b'"' => self.in_string = true,
+                b'[' | b'{' => {
+                    self.stack.push(byte);
+                    if self.array_depth.is_none()
+                        && byte == b'['
+                        && matches!(self.stack.as_slice(), [b'['] | [b'{', b'['])
+                    {
+                        self.array_depth = Some(self.stack.len());
+                    }
+                }
+                b',' if at_element_level => completed.extend(self.take_element(pos)),
+                b']' if at_element_level => {
+                    completed.extend(self.take_element(pos));
+                    self.finished = true;
+                }
+                b']' | b'}' => {
+                    self.stack.pop();
+                }
+                _ => {}
+            }
+        }
+
+        completed
+    }
+
+    fn take_element(&mut self, end: usize) -> Option<ScannedJsonArrayElement> {
+        let start = self.element_start.take()?;
+        let json = self.buffer.get(start..end)?.trim().to_string();
+        let index = self.next_index;
+        self.next_index += 1;
+        Some(ScannedJsonArrayElement { index, json })
+    }
+
+    /// Whether any text has been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// Whether the closing `]` of the array has been seen; later input is ignored.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Number of elements returned so far.
+    pub fn elements_seen(&self) -> usize {
+        self.next_index
+    }
+}
+
+fn parse_array_element(element: ScannedJsonArrayElement) -> JsonArrayElementStreamEvent {
+    match serde_json::from_str(&element.json) {
+        Ok(value) => JsonArrayElementStreamEvent::Element {
+            index: element.index,
+            value,
+        },
+        Err(e) => JsonArrayElementStreamEvent::ElementError {
+            index: element.index,
+            error: LlmError::ParseError(format!(
+                "array element {} is not valid JSON: {e}",
+                element.index
+            )),
+        },
+    }
+}
+
+/// Emit each completed element of a streamed JSON array as soon as it is closed.
+///
+/// Unlike `partial_json_value_stream`, which re-parses the whole accumulated buffer on every
+/// delta, this scans incrementally and yields every element exactly once. An element that is
+/// not valid JSON is reported as `ElementError` and the stream continues with the next one.
+pub fn json_array_element_stream(mut stream: ChatStream) -> JsonArrayElementStream {
+    Box::pin(async_stream::try_stream! {
+        let mut processor = StreamProcessor::new();
+        let mut scanner = JsonArrayElementScanner::new();
+
+        while let Some(item) = stream.next().await {
+            let event = item?;
+
+            if let Some(delta) = text_delta_from_stream_event(&event) {
+                for element in scanner.push_str(delta) {
+                    yield parse_array_element(element);
+                }
+            }
+
+            match &event {
+                ChatStreamEvent::StreamEnd { response } => {
+                    let _ = processor.process_event(event.clone());
+                    let final_response =
+                        merge_stream_end_response_with_accumulated(&processor, response.clone());
+                    // Providers that only deliver the final response never sent deltas.
+                    if scanner.is_empty()
+                        && let Some(text) = final_response.text()
+                    {
+                        for element in scanner.push_str(&text) {
+                            yield parse_array_element(element);
+                        }
+                    }
+                    yield JsonArrayElementStreamEvent::Finish {
+                        elements: scanner.elements_seen(),
+                        complete: scanner.is_finished(),
+                        response: Box::new(final_response),
+                    };
+                    return;
+                }
+                ChatStreamEvent::Error { error } => {
+                    Err(LlmError::StreamError(error.clone()))?;
+                }
+                _ => {
+                    let _ = processor.process_event(event);
+                }
+            }
+        }
+
+        yield JsonArrayElementStreamEvent::Finish {
+            elements: scanner.elements_seen(),
+            complete: scanner.is_finished(),
+            response: Box::new(processor.build_final_response()),
+        };
+    })
+}
+
 fn extract_first_markdown_fenced_block(text: &str) -> Option<&str> {
     let start = text.find("```")?;
     let after_start = start + 3;
@@ -1252,4 +1476,120 @@ mod tests {
             other => panic!("expected ParseError, got {other:?}"),
         }
     }
+
+    #[test]
+    fn json_array_element_scanner_emits_each_element_once_across_chunks() {
+        let mut scanner = JsonArrayElementScanner::new();
+        let chunks = [
+            "Here you go:\n```json\n[",
+            r#"{"name": "a, [b]", "tags": ["x"#,
+            r#"", "y"]}, {"name": "q\"}"}"#,
+            ", 3",
+            ", [1, 2]]\n```",
+        ];
+        let elements: Vec<_> = chunks
+            .iter()
+            .flat_map(|chunk| scanner.push_str(chunk))
+            .collect();
+
+        assert_eq!(
+            elements
+                .iter()
+                .map(|e| (e.index, e.json.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, r#"{"name": "a, [b]", "tags": ["x", "y"]}"#),
+                (1, r#"{"name": "q\"}"}"#),
+                (2, "3"),
+                (3, "[1, 2]"),
+            ]
+        );
+        assert!(scanner.is_finished());
+        assert!(scanner.push_str(", 4]").is_empty());
+    }
+
+    #[test]
+    fn json_array_element_scanner_reads_array_property_of_root_object() {
+        let mut scanner = JsonArrayElementScanner::new();
+        let elements = scanner.push_str(r#"{"elements": [{"a": 1}, {"a": 2}]}"#);
+        assert_eq!(elements.len(), 2);
+        assert_eq!(elements[1].json, r#"{"a": 2}"#);
+        assert!(scanner.is_finished());
+
+        let mut empty = JsonArrayElementScanner::new();
+        assert!(empty.push_str("[ ]").is_empty());
+        assert!(empty.is_finished());
+    }
+
+    #[tokio::test]
+    async fn json_array_element_stream_reports_invalid_elements_and_continues() {
+        let events = vec![
+            text_delta_event(r#"[{"id": 1}, {"id": "#),
+            text_delta_event(r#"}, {"id": 3}"#),
+            text_delta_event("]"),
+            Ok(ChatStreamEvent::StreamEnd {
+                response: ChatResponse::new(MessageContent::Text(
+                    r#"[{"id": 1}, {"id": }, {"id": 3}]"#.to_string(),
+                )),
+            }),
+        ];
+        let mut stream = json_array_element_stream(Box::pin(futures::stream::iter(events)));
+        let mut out = Vec::new();
+        while let Some(event) = stream.next().await {
+            out.push(event.expect("stream event"));
+        }
+
+        assert_eq!(out.len(), 4);
+        assert!(matches!(
+            &out[0],
+            JsonArrayElementStreamEvent::Element { index: 0, value }
+                if value == &serde_json::json!({ "id": 1 })
+        ));
+        assert!(matches!(
+            &out[1],
+            JsonArrayElementStreamEvent::ElementError {
+                index: 1,
+                error: LlmError::ParseError(_)
+            }
+        ));
+        assert!(matches!(
+            &out[2],
+            JsonArrayElementStreamEvent::Element { index: 2, value }
+                if value == &serde_json::json!({ "id": 3 })
+        ));
+        assert!(matches!(
+            &out[3],
+            JsonArrayElementStreamEvent::Finish {
+                elements: 3,
+                complete: true,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn json_array_element_stream_falls_back_to_final_response_text() {
+        let events = vec![Ok(ChatStreamEvent::StreamEnd {
+            response: ChatResponse::new(MessageContent::Text("[1, 2".to_string())),
+        })];
+        let mut stream = json_array_element_stream(Box::pin(futures::stream::iter(events)));
+        let mut out = Vec::new();
+        while let Some(event) = stream.next().await {
+            out.push(event.expect("stream event"));
+        }
+
+        assert_eq!(out.len(), 2);
+        assert!(matches!(
+            &out[0],
+            JsonArrayElementStreamEvent::Element { index: 0, value } if value == &serde_json::json!(1)
+        ));
+        assert!(matches!(
+            &out[1],
+            JsonArrayElementStreamEvent::Finish {
+                elements: 1,
+                complete: false,
+                ..
+            }
+        ));
+    }
 }
@@ -14,6 +14,7 @@
 //!   plus compatibility-only `AudioCapability`
 //! - **`files`** - File management capabilities (`FileManagementCapability`)
 //! - **`skills`** - Skill upload capabilities (`SkillsCapability`)
+//! - **`voice`** - Voice listing/cloning/design capabilities (`VoiceManagementCapability`)
 //! - **`moderation`** - Content moderation capabilities (`ModerationCapability`)
 //! - **`rerank`** - Document reranking capabilities (`RerankCapability`)
 //!
@@ -122,6 +123,9 @@ pub use audio::AudioCapability;
 mod speech;
 pub use speech::{SpeechCapability, SpeechExtras};
 
+mod voice;
+pub use voice::VoiceManagementCapability;
+
 mod transcription;
 pub use transcription::{TranscriptionCapability, TranscriptionExtras};
 
@@ -169,6 +173,7 @@ mod tests {
             let _: Option<Arc<dyn ImageGenerationCapability>> = None;
             let _: Option<Arc<dyn FileManagementCapability>> = None;
             let _: Option<Arc<dyn SkillsCapability>> = None;
+            let _: Option<Arc<dyn VoiceManagementCapability>> = None;
             let _: Option<Arc<dyn ModerationCapability>> = None;
             let _: Option<Arc<dyn ModelListingCapability>> = None;
         }
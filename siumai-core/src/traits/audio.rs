@@ -129,6 +129,7 @@ mod tests {
                 language: None,
                 confidence: None,
                 words: None,
+                segments: None,
                 duration: None,
                 metadata: Default::default(),
                 request: None,
@@ -148,6 +149,7 @@ mod tests {
                 language: None,
                 confidence: None,
                 words: None,
+                segments: None,
                 duration: None,
                 metadata: Default::default(),
                 request: None,
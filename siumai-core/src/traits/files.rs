@@ -2,7 +2,8 @@
 
 use crate::error::LlmError;
 use crate::types::{
-    FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
+    FileContentStream, FileDeleteResponse, FileListQuery, FileListResponse, FileObject,
+    FileStreamUploadRequest, FileUploadRequest,
 };
 use async_trait::async_trait;
 
@@ -13,4 +14,29 @@ pub trait FileManagementCapability: Send + Sync {
     async fn retrieve_file(&self, file_id: String) -> Result<FileObject, LlmError>;
     async fn delete_file(&self, file_id: String) -> Result<FileDeleteResponse, LlmError>;
     async fn get_file_content(&self, file_id: String) -> Result<Vec<u8>, LlmError>;
+
+    /// Upload a file read from a path or `AsyncRead` in chunks.
+    ///
+    /// Providers with a resumable or multipart upload protocol override this. The default
+    /// buffers the source in memory and delegates to [`Self::upload_file`].
+    async fn upload_file_stream(
+        &self,
+        request: FileStreamUploadRequest,
+    ) -> Result<FileObject, LlmError> {
+        let request = request.into_buffered().await?;
+        self.upload_file(request).await
+    }
+
+    /// Download file content as a byte stream.
+    ///
+    /// The default yields the result of [`Self::get_file_content`] as a single chunk.
+    async fn get_file_content_stream(
+        &self,
+        file_id: String,
+    ) -> Result<FileContentStream, LlmError> {
+        let bytes = self.get_file_content(file_id).await?;
+        Ok(Box::pin(futures::stream::once(async move {
+            Ok(bytes::Bytes::from(bytes))
+        })))
+    }
 }
@@ -91,6 +91,7 @@ mod tests {
                 language: None,
                 confidence: None,
                 words: None,
+                segments: None,
                 duration: None,
                 metadata: HashMap::new(),
                 request: None,
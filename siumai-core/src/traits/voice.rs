@@ -0,0 +1,44 @@
+//! Voice management capability trait
+//!
+//! Lists the voices a provider offers and manages custom voices (cloned from
+//! reference audio or designed from a text description). Voice ids returned here
+//! are passed unchanged as `TtsRequest::voice`.
+
+use crate::error::LlmError;
+use crate::types::{
+    VoiceCloneRequest, VoiceCreationResponse, VoiceDeleteResponse, VoiceDesignRequest, VoiceInfo,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait VoiceManagementCapability: Send + Sync {
+    /// List built-in and custom voices available to the caller.
+    async fn list_voices(&self) -> Result<Vec<VoiceInfo>, LlmError>;
+
+    /// Clone a voice from reference audio.
+    async fn clone_voice(
+        &self,
+        _request: VoiceCloneRequest,
+    ) -> Result<VoiceCreationResponse, LlmError> {
+        Err(LlmError::UnsupportedOperation(
+            "Voice cloning not supported by this provider".to_string(),
+        ))
+    }
+
+    /// Design a voice from a text description.
+    async fn design_voice(
+        &self,
+        _request: VoiceDesignRequest,
+    ) -> Result<VoiceCreationResponse, LlmError> {
+        Err(LlmError::UnsupportedOperation(
+            "Voice design not supported by this provider".to_string(),
+        ))
+    }
+
+    /// Delete a custom voice.
+    async fn delete_voice(&self, _voice_id: String) -> Result<VoiceDeleteResponse, LlmError> {
+        Err(LlmError::UnsupportedOperation(
+            "Voice deletion not supported by this provider".to_string(),
+        ))
+    }
+}
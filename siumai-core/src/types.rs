@@ -6,6 +6,9 @@ use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
 
 pub use siumai_spec::types::*;
 
+mod file_stream;
+pub use file_stream::*;
+
 /// A cloneable runtime cancellation handle for request-scoped abort semantics.
 #[derive(Clone, Debug, Default)]
 pub struct CancelHandle {
@@ -0,0 +1,434 @@
+//! Streaming file upload and download types.
+//!
+//! [`FileUploadRequest`](crate::types::FileUploadRequest) carries the whole payload in memory.
+//! The types here describe uploads read from a path or an `AsyncRead` in fixed-size chunks, the
+//! session state needed to resume an interrupted upload, and the byte stream returned by
+//! streaming downloads.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeekExt};
+
+use crate::error::LlmError;
+use crate::types::{FileUploadRequest, HttpConfig, ProviderOptionsMap};
+
+/// Default chunk size for streaming uploads (8 MiB, a multiple of common 256 KiB granularities).
+pub const DEFAULT_UPLOAD_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Default number of retries for a single failed chunk.
+pub const DEFAULT_UPLOAD_CHUNK_RETRIES: u32 = 3;
+
+/// Streamed file content returned by `get_file_content_stream`.
+pub type FileContentStream = Pin<Box<dyn futures::Stream<Item = Result<Bytes, LlmError>> + Send>>;
+
+/// Callback invoked after every acknowledged chunk with the current session state.
+///
+/// Persist the session to resume the upload after a crash or restart.
+pub type UploadProgressCallback = Arc<dyn Fn(&UploadSession) + Send + Sync>;
+
+/// Where the bytes of a streaming upload come from.
+pub enum FileUploadSource {
+    /// A local file; its length is read from the filesystem metadata.
+    Path(PathBuf),
+    /// Any async reader with a known total length.
+    Reader {
+        reader: Box<dyn AsyncRead + Send + Unpin>,
+        length: u64,
+    },
+}
+
+impl FileUploadSource {
+    /// Upload from a local file.
+    pub fn path(path: impl Into<PathBuf>) -> Self {
+        Self::Path(path.into())
+    }
+
+    /// Upload from an async reader producing exactly `length` bytes.
+    pub fn reader(reader: impl AsyncRead + Send + Unpin + 'static, length: u64) -> Self {
+        Self::Reader {
+            reader: Box::new(reader),
+            length,
+        }
+    }
+
+    /// Best-effort filename derived from the source (the file name of a path).
+    pub fn file_name(&self) -> Option<String> {
+        match self {
+            Self::Path(path) => path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned()),
+            Self::Reader { .. } => None,
+        }
+    }
+
+    /// Open the source positioned at `offset`.
+    ///
+    /// Files are seeked; readers discard the first `offset` bytes.
+    pub async fn open(self, offset: u64) -> Result<FileChunkReader, LlmError> {
+        let (mut reader, length): (Box<dyn AsyncRead + Send + Unpin>, u64) = match self {
+            Self::Path(path) => {
+                let mut file = tokio::fs::File::open(&path).await.map_err(|e| {
+                    LlmError::InvalidInput(format!("Cannot open {}: {e}", path.display()))
+                })?;
+                let length = file
+                    .metadata()
+                    .await
+                    .map_err(|e| LlmError::InvalidInput(format!("Cannot stat file: {e}")))?
+                    .len();
+                if offset > 0 {
+                    file.seek(std::io::SeekFrom::Start(offset))
+                        .await
+                        .map_err(|e| LlmError::InvalidInput(format!("Cannot seek file: {e}")))?;
+                }
+                (Box::new(file), length)
+            }
+            Self::Reader { mut reader, length } => {
+                if offset > 0 {
+                    let skipped =
+                        tokio::io::copy(&mut (&mut reader).take(offset), &mut tokio::io::sink())
+                            .await
+                            .map_err(|e| {
+                                LlmError::InvalidInput(format!("Cannot skip input: {e}"))
+                            })?;
+                    if skipped != offset {
+                        return Err(LlmError::InvalidInput(format!(
+                            "Upload source ended after {skipped} bytes, before resume offset {offset}"
+                        )));
+                    }
+                }
+                (reader, length)
+            }
+        };
+        if offset > length {
+            return Err(LlmError::InvalidInput(format!(
+                "Resume offset {offset} is past the end of the {length}-byte upload source"
+            )));
+        }
+        // Keep the reader type uniform regardless of the source.
+        reader = Box::new(reader.take(length - offset));
+        Ok(FileChunkReader {
+            reader,
+            length,
+            offset,
+        })
+    }
+}
+
+impl std::fmt::Debug for FileUploadSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            Self::Reader { length, .. } => f
+                .debug_struct("Reader")
+                .field("length", length)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Sequential chunk reader over an opened [`FileUploadSource`].
+pub struct FileChunkReader {
+    reader: Box<dyn AsyncRead + Send + Unpin>,
+    length: u64,
+    offset: u64,
+}
+
+impl FileChunkReader {
+    /// Total length of the source in bytes.
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+
+    /// Offset of the next chunk.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Read the next chunk of up to `max_len` bytes, or `None` once the source is exhausted.
+    ///
+    /// Fails when the source ends before its declared length.
+    pub async fn next_chunk(&mut self, max_len: usize) -> Result<Option<Bytes>, LlmError> {
+        let remaining = self.length - self.offset;
+        if remaining == 0 {
+            return Ok(None);
+        }
+        let want = remaining.min(max_len.max(1) as u64) as usize;
+        let mut buf = vec![0u8; want];
+        let mut filled = 0;
+        while filled < want {
+            let read = self.reader.read(&mut buf[filled..]).await.map_err(|e| {
+                LlmError::InvalidInput(format!("Failed to read upload source: {e}"))
+            })?;
+            if read == 0 {
+                return Err(LlmError::InvalidInput(format!(
+                    "Upload source ended at byte {} but declared {} bytes",
+                    self.offset + filled as u64,
+                    self.length
+                )));
+            }
+            filled += read;
+        }
+        self.offset += want as u64;
+        Ok(Some(Bytes::from(buf)))
+    }
+}
+
+/// Provider-side state of a resumable upload.
+///
+/// Serializable so callers can persist it (for example from an [`UploadProgressCallback`]) and
+/// pass it back through [`FileStreamUploadRequest::with_resume`] after an interruption.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadSession {
+    /// Provider that owns the session.
+    pub provider_id: String,
+    /// Provider handle for the session (a resumable upload URL or an upload id).
+    pub upload_id: String,
+    /// Total size of the upload in bytes.
+    pub total_bytes: u64,
+    /// Number of bytes the provider has acknowledged.
+    pub offset: u64,
+    /// Part ids acknowledged so far (multipart upload protocols).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub part_ids: Vec<String>,
+}
+
+/// Chunked file upload request read from a path or `AsyncRead`.
+pub struct FileStreamUploadRequest {
+    /// Source of the file bytes
+    pub source: FileUploadSource,
+    /// Filename; defaults to the path's file name
+    pub filename: Option<String>,
+    /// MIME type
+    pub mime_type: Option<String>,
+    /// Purpose of the file (e.g., "assistants", "fine-tune")
+    pub purpose: String,
+    /// Additional metadata
+    pub metadata: HashMap<String, String>,
+    /// Optional provider-specific options (`providerOptions`)
+    pub provider_options: ProviderOptionsMap,
+    /// Per-request HTTP configuration (headers, timeout, etc.)
+    pub http_config: Option<HttpConfig>,
+    /// Chunk size in bytes (providers may round it to their required granularity)
+    pub chunk_size: usize,
+    /// Retries per failed chunk before the upload is aborted
+    pub max_chunk_retries: u32,
+    /// Session to resume instead of starting a new upload
+    pub resume: Option<UploadSession>,
+    /// Progress callback invoked after every acknowledged chunk
+    pub on_progress: Option<UploadProgressCallback>,
+}
+
+impl FileStreamUploadRequest {
+    /// Create a request with default chunking for the given source and purpose.
+    pub fn new(source: FileUploadSource, purpose: impl Into<String>) -> Self {
+        Self {
+            filename: source.file_name(),
+            source,
+            mime_type: None,
+            purpose: purpose.into(),
+            metadata: HashMap::new(),
+            provider_options: ProviderOptionsMap::default(),
+            http_config: None,
+            chunk_size: DEFAULT_UPLOAD_CHUNK_SIZE,
+            max_chunk_retries: DEFAULT_UPLOAD_CHUNK_RETRIES,
+            resume: None,
+            on_progress: None,
+        }
+    }
+
+    /// Upload a local file.
+    pub fn from_path(path: impl Into<PathBuf>, purpose: impl Into<String>) -> Self {
+        Self::new(FileUploadSource::path(path), purpose)
+    }
+
+    /// Upload from an async reader producing exactly `length` bytes.
+    pub fn from_reader(
+        reader: impl AsyncRead + Send + Unpin + 'static,
+        length: u64,
+        purpose: impl Into<String>,
+    ) -> Self {
+        Self::new(FileUploadSource::reader(reader, length), purpose)
+    }
+
+    pub fn with_filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    pub fn with_mime_type(mut self, mime_type: impl Into<String>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn with_provider_options(mut self, provider_options: ProviderOptionsMap) -> Self {
+        self.provider_options = provider_options;
+        self
+    }
+
+    pub fn with_http_config(mut self, http_config: HttpConfig) -> Self {
+        self.http_config = Some(http_config);
+        self
+    }
+
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    pub fn with_max_chunk_retries(mut self, retries: u32) -> Self {
+        self.max_chunk_retries = retries;
+        self
+    }
+
+    /// Resume a previously started upload instead of creating a new session.
+    pub fn with_resume(mut self, session: UploadSession) -> Self {
+        self.resume = Some(session);
+        self
+    }
+
+    pub fn with_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&UploadSession) + Send + Sync + 'static,
+    {
+        self.on_progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Notify the progress callback, if any.
+    pub fn report_progress(&self, session: &UploadSession) {
+        if let Some(callback) = &self.on_progress {
+            callback(session);
+        }
+    }
+
+    /// Read the whole source into memory as a regular [`FileUploadRequest`].
+    ///
+    /// Used by providers without a chunked upload protocol.
+    pub async fn into_buffered(self) -> Result<FileUploadRequest, LlmError> {
+        let mut reader = self.source.open(0).await?;
+        let mut content = Vec::with_capacity(reader.length().min(64 * 1024 * 1024) as usize);
+        while let Some(chunk) = reader.next_chunk(self.chunk_size).await? {
+            content.extend_from_slice(&chunk);
+        }
+        Ok(FileUploadRequest {
+            content,
+            filename: self.filename,
+            mime_type: self.mime_type,
+            purpose: self.purpose,
+            metadata: self.metadata,
+            provider_options: self.provider_options,
+            http_config: self.http_config,
+        })
+    }
+}
+
+impl std::fmt::Debug for FileStreamUploadRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileStreamUploadRequest")
+            .field("source", &self.source)
+            .field("filename", &self.filename)
+            .field("mime_type", &self.mime_type)
+            .field("purpose", &self.purpose)
+            .field("chunk_size", &self.chunk_size)
+            .field("max_chunk_retries", &self.max_chunk_retries)
+            .field("resume", &self.resume)
+            .field("has_progress", &self.on_progress.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Run one chunk operation, retrying retryable failures with exponential backoff.
+///
+/// `operation` receives the zero-based attempt number.
+pub async fn retry_upload_chunk<F, Fut, T>(
+    max_retries: u32,
+    mut operation: F,
+) -> Result<T, LlmError>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: std::future::Future<Output = Result<T, LlmError>>,
+{
+    use crate::error::LlmErrorExt;
+
+    let mut attempt = 0;
+    loop {
+        match operation(attempt).await {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt < max_retries && error.is_retryable() => {
+                let backoff = 200u64.saturating_mul(1 << attempt.min(6));
+                tokio::time::sleep(std::time::Duration::from_millis(backoff)).await;
+                attempt += 1;
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reader_source_skips_to_resume_offset_and_chunks() {
+        let data: Vec<u8> = (0..10u8).collect();
+        let mut reader = FileUploadSource::reader(std::io::Cursor::new(data), 10)
+            .open(3)
+            .await
+            .unwrap();
+        assert_eq!(reader.offset(), 3);
+        assert_eq!(
+            &reader.next_chunk(4).await.unwrap().unwrap()[..],
+            &[3, 4, 5, 6]
+        );
+        assert_eq!(
+            &reader.next_chunk(4).await.unwrap().unwrap()[..],
+            &[7, 8, 9]
+        );
+        assert!(reader.next_chunk(4).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn short_reader_is_an_error() {
+        let mut reader = FileUploadSource::reader(std::io::Cursor::new(vec![1u8, 2]), 5)
+            .open(0)
+            .await
+            .unwrap();
+        assert!(reader.next_chunk(8).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn retry_upload_chunk_retries_only_retryable_errors() {
+        let mut calls = 0;
+        let value = retry_upload_chunk(2, |_| {
+            calls += 1;
+            let attempt = calls;
+            async move {
+                if attempt < 2 {
+                    Err(LlmError::TimeoutError("slow".into()))
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(value, 2);
+
+        let err = retry_upload_chunk(2, |_| async {
+            Err::<(), _>(LlmError::InvalidInput("bad".into()))
+        })
+        .await
+        .unwrap_err();
+        assert!(matches!(err, LlmError::InvalidInput(_)));
+    }
+}
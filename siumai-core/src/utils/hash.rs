@@ -0,0 +1,17 @@
+//! Hashing helpers whose output is stable across processes and Rust releases.
+
+/// FNV-1a over `parts`, each terminated by a `0xff` byte so `["ab", "c"]` and `["a", "bc"]`
+/// differ.
+///
+/// Use this instead of `DefaultHasher` for values that are persisted or shared between
+/// processes (cache keys, traffic assignments): `DefaultHasher` may change between releases.
+pub fn stable_hash(parts: &[&str]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.bytes().chain([0xff]) {
+            hash ^= u64::from(byte);
+            hash = hash.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    hash
+}
@@ -16,6 +16,7 @@ pub mod chat_request;
 pub mod data;
 pub mod download;
 pub mod error_message;
+pub mod hash;
 pub mod headers;
 pub mod id;
 pub mod json_instruction;
@@ -38,6 +39,7 @@ pub use cancel::{delay, is_abort_error};
 pub use data::*;
 pub use download::*;
 pub use error_message::*;
+pub use hash::stable_hash;
 pub use headers::*;
 pub use id::*;
 pub use json_instruction::*;
@@ -674,6 +674,7 @@ fn core_family_contract_and_tooling_sources_do_not_handle_provider_maps() {
         "src/text.rs",
         "src/completion.rs",
         "src/speech.rs",
+        "src/speech/pipeline.rs",
         "src/transcription.rs",
         "src/tooling.rs",
         "src/traits.rs",
@@ -0,0 +1,425 @@
+//! Evaluation harness for prompts, models and agents.
+//!
+//! An [`EvalRunner`] runs every [`EvalCase`] of an [`EvalDataset`] against one or more
+//! [`EvalTarget`]s with bounded concurrency, scores each output with pluggable [`Scorer`]s, and
+//! aggregates latency, token usage and cost into an [`EvalReport`]. Reports serialize to JSON
+//! and Markdown in a stable order so two runs can be diffed (or compared with
+//! [`EvalReport::compare`]).
+//!
+//! [`EvalRunner`]: crate::eval::EvalRunner
+//! [`EvalCase`]: crate::eval::EvalCase
+//! [`EvalDataset`]: crate::eval::EvalDataset
+//! [`EvalTarget`]: crate::eval::EvalTarget
+//! [`Scorer`]: crate::eval::Scorer
+//! [`EvalReport`]: crate::eval::EvalReport
+//! [`EvalReport::compare`]: crate::eval::EvalReport::compare
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use siumai_extras::eval::*;
+//!
+//! let dataset = EvalDataset::from_jsonl("capitals", include_str!("capitals.jsonl"))?;
+//! let reg = siumai::registry::global();
+//!
+//! let report = EvalRunner::new(dataset)
+//!     .with_target(ModelTarget::new("gpt-4o-mini", Arc::new(reg.language_model("openai:gpt-4o-mini")?)))
+//!     .with_target(ModelTarget::new("claude-haiku", Arc::new(reg.language_model("anthropic:claude-3-5-haiku-latest")?)))
+//!     .with_scorer(ExactMatch::new().ignore_case())
+//!     .with_scorer(LlmJudge::new(Arc::new(reg.language_model("openai:gpt-4o")?), "Is the answer correct and concise?"))
+//!     .with_concurrency(8)
+//!     .run()
+//!     .await;
+//!
+//! std::fs::write("report.md", report.to_markdown())?;
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use siumai::prelude::unified::{
+    ChatCapability, ChatMessage, ChatRequest, LanguageModel, LlmError, Usage,
+};
+
+use crate::orchestrator::{ToolLoopAgent, ToolResolver};
+
+mod report;
+mod scorers;
+
+pub use report::{CaseResult, EvalReport, MetricDelta, TargetSummary, TokenUsage};
+#[cfg(feature = "schema")]
+pub use scorers::JsonSchemaMatch;
+pub use scorers::{EmbeddingSimilarity, ExactMatch, LlmJudge, RegexMatch, Score, Scorer};
+
+/// One input with its (optional) expected output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalCase {
+    /// Stable identifier used to line up results across runs.
+    pub id: String,
+    /// User prompt.
+    pub input: String,
+    /// Optional system prompt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system: Option<String>,
+    /// Reference answer, used by scorers that compare against one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// Free-form metadata (tags, difficulty, source, ...).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, Value>,
+}
+
+impl EvalCase {
+    /// Create a case from an id and a user prompt.
+    pub fn new(id: impl Into<String>, input: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            input: input.into(),
+            system: None,
+            expected: None,
+            metadata: BTreeMap::new(),
+        }
+    }
+
+    /// Set the system prompt.
+    pub fn with_system(mut self, system: impl Into<String>) -> Self {
+        self.system = Some(system.into());
+        self
+    }
+
+    /// Set the reference answer.
+    pub fn with_expected(mut self, expected: impl Into<String>) -> Self {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    /// Attach a metadata entry.
+    pub fn with_metadata(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.metadata.insert(key.into(), value.into());
+        self
+    }
+
+    /// Chat messages for this case (system prompt first when present).
+    pub fn messages(&self) -> Vec<ChatMessage> {
+        let mut messages = Vec::with_capacity(2);
+        if let Some(system) = &self.system {
+            messages.push(ChatMessage::system(system.clone()).build());
+        }
+        messages.push(ChatMessage::user(self.input.clone()).build());
+        messages
+    }
+}
+
+/// A named collection of cases.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalDataset {
+    /// Dataset name, carried into reports.
+    pub name: String,
+    /// Cases in evaluation order.
+    pub cases: Vec<EvalCase>,
+}
+
+impl EvalDataset {
+    /// Create a dataset from cases.
+    pub fn new(name: impl Into<String>, cases: Vec<EvalCase>) -> Self {
+        Self {
+            name: name.into(),
+            cases,
+        }
+    }
+
+    /// Parse one [`EvalCase`] per non-empty line.
+    pub fn from_jsonl(name: impl Into<String>, jsonl: &str) -> Result<Self, LlmError> {
+        let cases = jsonl
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .map_err(|e| LlmError::ParseError(format!("eval dataset line {}: {e}", i + 1)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(name, cases))
+    }
+}
+
+/// What a target produced for one case.
+#[derive(Debug, Clone, Default)]
+pub struct EvalOutput {
+    /// Final text output, which is what scorers see.
+    pub text: String,
+    /// Token usage across the whole run (all agent steps).
+    pub usage: Option<Usage>,
+    /// Number of model calls made (1 for plain models).
+    pub steps: usize,
+}
+
+/// Per-million-token prices used to estimate cost.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPricing {
+    /// Price per million input tokens.
+    pub input_per_million: f64,
+    /// Price per million output tokens.
+    pub output_per_million: f64,
+}
+
+impl ModelPricing {
+    /// Create a pricing entry.
+    pub const fn new(input_per_million: f64, output_per_million: f64) -> Self {
+        Self {
+            input_per_million,
+            output_per_million,
+        }
+    }
+
+    /// Estimated cost of `usage`.
+    pub fn cost(&self, usage: &Usage) -> f64 {
+        let input = f64::from(usage.prompt_tokens().unwrap_or(0));
+        let output = f64::from(usage.completion_tokens().unwrap_or(0));
+        (input * self.input_per_million + output * self.output_per_million) / 1_000_000.0
+    }
+}
+
+/// Something that can answer an [`EvalCase`]: a model, an agent, or a whole pipeline.
+#[async_trait::async_trait]
+pub trait EvalTarget: Send + Sync {
+    /// Name shown in reports; must be unique within a run.
+    fn name(&self) -> &str;
+
+    /// Prices used for cost aggregation.
+    fn pricing(&self) -> Option<ModelPricing> {
+        None
+    }
+
+    /// Produce an output for `case`.
+    async fn run(&self, case: &EvalCase) -> Result<EvalOutput, LlmError>;
+}
+
+/// Evaluates a single chat model, e.g. a registry language model handle.
+#[derive(Clone)]
+pub struct ModelTarget {
+    name: String,
+    model: Arc<dyn ChatCapability>,
+    pricing: Option<ModelPricing>,
+    template: ChatRequest,
+}
+
+impl ModelTarget {
+    /// Wrap `model` under a report name.
+    pub fn new(name: impl Into<String>, model: Arc<dyn ChatCapability>) -> Self {
+        Self {
+            name: name.into(),
+            model,
+            pricing: None,
+            template: ChatRequest::new(Vec::new()),
+        }
+    }
+
+    /// Set prices for cost aggregation.
+    pub fn with_pricing(mut self, pricing: ModelPricing) -> Self {
+        self.pricing = Some(pricing);
+        self
+    }
+
+    /// Use `template` for every call (parameters, tools, provider options); its messages are
+    /// replaced by the case messages.
+    pub fn with_request_template(mut self, template: ChatRequest) -> Self {
+        self.template = template;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl EvalTarget for ModelTarget {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn pricing(&self) -> Option<ModelPricing> {
+        self.pricing
+    }
+
+    async fn run(&self, case: &EvalCase) -> Result<EvalOutput, LlmError> {
+        let mut request = self.template.clone();
+        request.messages = case.messages();
+        let response = self.model.chat_request(request).await?;
+        Ok(EvalOutput {
+            text: response.content.all_text(),
+            usage: response.usage,
+            steps: 1,
+        })
+    }
+}
+
+/// Evaluates a [`ToolLoopAgent`] end to end, tools included.
+pub struct AgentTarget<M>
+where
+    M: LanguageModel + Send + Sync + 'static,
+{
+    name: String,
+    agent: ToolLoopAgent<M>,
+    resolver: Arc<dyn ToolResolver>,
+    pricing: Option<ModelPricing>,
+}
+
+impl<M> AgentTarget<M>
+where
+    M: LanguageModel + Send + Sync + 'static,
+{
+    /// Wrap `agent`; tool calls are executed with `resolver`.
+    pub fn new(
+        name: impl Into<String>,
+        agent: ToolLoopAgent<M>,
+        resolver: Arc<dyn ToolResolver>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            agent,
+            resolver,
+            pricing: None,
+        }
+    }
+
+    /// Set prices for cost aggregation.
+    pub fn with_pricing(mut self, pricing: ModelPricing) -> Self {
+        self.pricing = Some(pricing);
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl<M> EvalTarget for AgentTarget<M>
+where
+    M: LanguageModel + Send + Sync + 'static,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn pricing(&self) -> Option<ModelPricing> {
+        self.pricing
+    }
+
+    async fn run(&self, case: &EvalCase) -> Result<EvalOutput, LlmError> {
+        let result = self
+            .agent
+            .generate(case.messages(), self.resolver.as_ref())
+            .await?;
+        Ok(EvalOutput {
+            text: result.response.content.all_text(),
+            usage: result.total_usage(),
+            steps: result.steps.len(),
+        })
+    }
+}
+
+/// Runs a dataset against targets and scores the results.
+pub struct EvalRunner {
+    dataset: EvalDataset,
+    targets: Vec<Arc<dyn EvalTarget>>,
+    scorers: Vec<Arc<dyn Scorer>>,
+    concurrency: usize,
+}
+
+impl EvalRunner {
+    /// Create a runner for `dataset` with a concurrency of 4.
+    pub fn new(dataset: EvalDataset) -> Self {
+        Self {
+            dataset,
+            targets: Vec::new(),
+            scorers: Vec::new(),
+            concurrency: 4,
+        }
+    }
+
+    /// Add a target.
+    pub fn with_target(mut self, target: impl EvalTarget + 'static) -> Self {
+        self.targets.push(Arc::new(target));
+        self
+    }
+
+    /// Add a shared target.
+    pub fn with_target_arc(mut self, target: Arc<dyn EvalTarget>) -> Self {
+        self.targets.push(target);
+        self
+    }
+
+    /// Add a scorer; every output is scored by every scorer.
+    pub fn with_scorer(mut self, scorer: impl Scorer + 'static) -> Self {
+        self.scorers.push(Arc::new(scorer));
+        self
+    }
+
+    /// Maximum number of cases in flight across all targets (at least 1).
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Run every case against every target.
+    ///
+    /// Target and scorer failures are recorded in the report rather than aborting the run.
+    pub async fn run(&self) -> EvalReport {
+        let jobs = self.targets.iter().flat_map(|target| {
+            self.dataset
+                .cases
+                .iter()
+                .map(move |case| (target.clone(), case))
+        });
+        let results: Vec<CaseResult> = futures::stream::iter(jobs)
+            .map(|(target, case)| self.run_case(target, case))
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+        EvalReport::new(self.dataset.name.clone(), results)
+    }
+
+    async fn run_case(&self, target: Arc<dyn EvalTarget>, case: &EvalCase) -> CaseResult {
+        let started = Instant::now();
+        let outcome = target.run(case).await;
+        let latency_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        let mut result = CaseResult {
+            case_id: case.id.clone(),
+            target: target.name().to_string(),
+            output: None,
+            error: None,
+            scores: BTreeMap::new(),
+            latency_ms,
+            steps: 0,
+            usage: None,
+            cost: None,
+        };
+        let output = match outcome {
+            Ok(output) => output,
+            Err(e) => {
+                result.error = Some(e.to_string());
+                return result;
+            }
+        };
+
+        for scorer in &self.scorers {
+            let score = scorer
+                .score(case, &output.text)
+                .await
+                .unwrap_or_else(|e| Score::error(e.to_string()));
+            result.scores.insert(scorer.name().to_string(), score);
+        }
+        result.cost = target
+            .pricing()
+            .zip(output.usage.as_ref())
+            .map(|(pricing, usage)| pricing.cost(usage));
+        result.usage = output.usage.as_ref().map(TokenUsage::from);
+        result.steps = output.steps;
+        result.output = Some(output.text);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,308 @@
+//! Evaluation reports.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+use siumai::prelude::unified::{LlmError, Usage};
+
+use super::Score;
+
+/// Token counts in a serialization-stable shape.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Input (prompt) tokens.
+    pub input: u64,
+    /// Output (completion) tokens.
+    pub output: u64,
+    /// Total tokens.
+    pub total: u64,
+}
+
+impl From<&Usage> for TokenUsage {
+    fn from(usage: &Usage) -> Self {
+        let input = u64::from(usage.prompt_tokens().unwrap_or(0));
+        let output = u64::from(usage.completion_tokens().unwrap_or(0));
+        Self {
+            input,
+            output,
+            total: usage
+                .total_tokens()
+                .map(u64::from)
+                .unwrap_or(input + output),
+        }
+    }
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.input += other.input;
+        self.output += other.output;
+        self.total += other.total;
+    }
+}
+
+/// Outcome of one case on one target.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseResult {
+    /// Case id.
+    pub case_id: String,
+    /// Target name.
+    pub target: String,
+    /// Output text, when the target succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    /// Target error, when it failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Scores keyed by scorer name.
+    pub scores: BTreeMap<String, Score>,
+    /// Wall-clock latency of the target call.
+    pub latency_ms: f64,
+    /// Model calls made by the target.
+    pub steps: usize,
+    /// Token usage, when reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<TokenUsage>,
+    /// Estimated cost, when the target has pricing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
+}
+
+/// Aggregates for one target.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TargetSummary {
+    /// Target name.
+    pub target: String,
+    /// Cases run.
+    pub cases: usize,
+    /// Cases where the target failed.
+    pub errors: usize,
+    /// Mean score per scorer, over cases that produced output.
+    pub mean_scores: BTreeMap<String, f64>,
+    /// Fraction of cases passing per scorer (failed cases count as not passing).
+    pub pass_rates: BTreeMap<String, f64>,
+    /// Median latency.
+    pub latency_p50_ms: f64,
+    /// 95th percentile latency.
+    pub latency_p95_ms: f64,
+    /// Summed token usage.
+    pub usage: TokenUsage,
+    /// Summed cost, when any case had one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<f64>,
+}
+
+impl TargetSummary {
+    fn from_results(target: &str, results: &[&CaseResult]) -> Self {
+        let scorers: BTreeSet<&String> = results.iter().flat_map(|r| r.scores.keys()).collect();
+        let mut mean_scores = BTreeMap::new();
+        let mut pass_rates = BTreeMap::new();
+        for scorer in scorers {
+            let scores: Vec<&Score> = results
+                .iter()
+                .filter_map(|r| r.scores.get(scorer))
+                .collect();
+            let mean = scores.iter().map(|s| s.value).sum::<f64>() / scores.len() as f64;
+            let passed = scores.iter().filter(|s| s.passed).count();
+            mean_scores.insert(scorer.clone(), mean);
+            pass_rates.insert(scorer.clone(), passed as f64 / results.len() as f64);
+        }
+
+        let mut latencies: Vec<f64> = results.iter().map(|r| r.latency_ms).collect();
+        latencies.sort_by(f64::total_cmp);
+        let mut usage = TokenUsage::default();
+        for u in results.iter().filter_map(|r| r.usage) {
+            usage += u;
+        }
+        let costs: Vec<f64> = results.iter().filter_map(|r| r.cost).collect();
+
+        Self {
+            target: target.to_string(),
+            cases: results.len(),
+            errors: results.iter().filter(|r| r.error.is_some()).count(),
+            mean_scores,
+            pass_rates,
+            latency_p50_ms: percentile(&latencies, 0.5),
+            latency_p95_ms: percentile(&latencies, 0.95),
+            usage,
+            cost: (!costs.is_empty()).then(|| costs.iter().sum()),
+        }
+    }
+}
+
+fn percentile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = ((q * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len());
+    sorted[rank - 1]
+}
+
+/// Change of one metric between a baseline report and the current one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricDelta {
+    /// Target name.
+    pub target: String,
+    /// `score:<scorer>`, `pass_rate:<scorer>`, `latency_p95_ms` or `cost`.
+    pub metric: String,
+    /// Baseline value.
+    pub baseline: f64,
+    /// Current value.
+    pub current: f64,
+}
+
+impl MetricDelta {
+    /// `current - baseline`.
+    pub fn delta(&self) -> f64 {
+        self.current - self.baseline
+    }
+}
+
+/// Results of an evaluation run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalReport {
+    /// Dataset name.
+    pub dataset: String,
+    /// One summary per target, in target order.
+    pub summaries: Vec<TargetSummary>,
+    /// Per-case results, grouped by target in dataset order.
+    pub results: Vec<CaseResult>,
+}
+
+impl EvalReport {
+    /// Build a report (and its summaries) from case results.
+    pub fn new(dataset: impl Into<String>, results: Vec<CaseResult>) -> Self {
+        let mut targets: Vec<&str> = Vec::new();
+        for result in &results {
+            if !targets.contains(&result.target.as_str()) {
+                targets.push(&result.target);
+            }
+        }
+        let summaries = targets
+            .iter()
+            .map(|target| {
+                let rows: Vec<&CaseResult> =
+                    results.iter().filter(|r| r.target == *target).collect();
+                TargetSummary::from_results(target, &rows)
+            })
+            .collect();
+        Self {
+            dataset: dataset.into(),
+            summaries,
+            results,
+        }
+    }
+
+    /// Summary for `target`.
+    pub fn summary(&self, target: &str) -> Option<&TargetSummary> {
+        self.summaries.iter().find(|s| s.target == target)
+    }
+
+    /// Pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("eval reports always serialize")
+    }
+
+    /// Parse a report written by [`EvalReport::to_json`].
+    pub fn from_json(json: &str) -> Result<Self, LlmError> {
+        serde_json::from_str(json).map_err(|e| LlmError::ParseError(format!("eval report: {e}")))
+    }
+
+    /// Summary and per-case tables in Markdown.
+    pub fn to_markdown(&self) -> String {
+        let scorers: BTreeSet<&String> = self
+            .summaries
+            .iter()
+            .flat_map(|s| s.mean_scores.keys())
+            .collect();
+        let mut md = format!("# Eval report: {}\n\n## Summary\n\n", self.dataset);
+
+        md.push_str("| Target | Cases | Errors |");
+        for scorer in &scorers {
+            let _ = write!(md, " {scorer} |");
+        }
+        md.push_str(" p50 ms | p95 ms | Tokens | Cost |\n|---|---:|---:|");
+        md.push_str(&"---:|".repeat(scorers.len() + 4));
+        md.push('\n');
+        for s in &self.summaries {
+            let _ = write!(md, "| {} | {} | {} |", s.target, s.cases, s.errors);
+            for scorer in &scorers {
+                match (s.mean_scores.get(*scorer), s.pass_rates.get(*scorer)) {
+                    (Some(mean), Some(rate)) => {
+                        let _ = write!(md, " {mean:.3} ({:.0}% pass) |", rate * 100.0);
+                    }
+                    _ => md.push_str(" - |"),
+                }
+            }
+            let _ = writeln!(
+                md,
+                " {:.0} | {:.0} | {} | {} |",
+                s.latency_p50_ms,
+                s.latency_p95_ms,
+                s.usage.total,
+                s.cost.map_or("-".to_string(), |c| format!("{c:.4}"))
+            );
+        }
+
+        md.push_str("\n## Results\n\n| Case | Target |");
+        for scorer in &scorers {
+            let _ = write!(md, " {scorer} |");
+        }
+        md.push_str(" Latency ms | Error |\n|---|---|");
+        md.push_str(&"---:|".repeat(scorers.len() + 1));
+        md.push_str("---|\n");
+        for r in &self.results {
+            let _ = write!(md, "| {} | {} |", r.case_id, r.target);
+            for scorer in &scorers {
+                match r.scores.get(*scorer) {
+                    Some(score) => {
+                        let mark = if score.passed { "pass" } else { "fail" };
+                        let _ = write!(md, " {:.2} {mark} |", score.value);
+                    }
+                    None => md.push_str(" - |"),
+                }
+            }
+            let error = r.error.as_deref().unwrap_or("").replace('|', "\\|");
+            let _ = writeln!(md, " {:.0} | {error} |", r.latency_ms);
+        }
+        md
+    }
+
+    /// Metric changes relative to `baseline`, for targets present in both reports.
+    pub fn compare(&self, baseline: &EvalReport) -> Vec<MetricDelta> {
+        let mut deltas = Vec::new();
+        for current in &self.summaries {
+            let Some(base) = baseline.summary(&current.target) else {
+                continue;
+            };
+            let mut push = |metric: String, baseline: f64, current_value: f64| {
+                deltas.push(MetricDelta {
+                    target: current.target.clone(),
+                    metric,
+                    baseline,
+                    current: current_value,
+                });
+            };
+            for (scorer, mean) in &current.mean_scores {
+                if let Some(base_mean) = base.mean_scores.get(scorer) {
+                    push(format!("score:{scorer}"), *base_mean, *mean);
+                }
+            }
+            for (scorer, rate) in &current.pass_rates {
+                if let Some(base_rate) = base.pass_rates.get(scorer) {
+                    push(format!("pass_rate:{scorer}"), *base_rate, *rate);
+                }
+            }
+            push(
+                "latency_p95_ms".to_string(),
+                base.latency_p95_ms,
+                current.latency_p95_ms,
+            );
+            if let (Some(base_cost), Some(cost)) = (base.cost, current.cost) {
+                push("cost".to_string(), base_cost, cost);
+            }
+        }
+        deltas
+    }
+}
@@ -0,0 +1,332 @@
+//! Built-in scorers.
+
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use siumai::prelude::unified::{
+    ChatCapability, ChatMessage, ChatRequest, EmbeddingModel, EmbeddingRequest, LlmError,
+    cosine_similarity,
+};
+
+use super::EvalCase;
+use crate::structured_output::{OutputDecodeConfig, decode_json_value};
+
+/// Result of scoring one output.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Score {
+    /// Normalized score in `0.0..=1.0`.
+    pub value: f64,
+    /// Whether the output meets the scorer's bar.
+    pub passed: bool,
+    /// Explanation, when the scorer has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl Score {
+    /// A binary score.
+    pub fn pass_fail(passed: bool) -> Self {
+        Self {
+            value: if passed { 1.0 } else { 0.0 },
+            passed,
+            reason: None,
+        }
+    }
+
+    /// A graded score that passes at or above `threshold`.
+    pub fn graded(value: f64, threshold: f64) -> Self {
+        let value = value.clamp(0.0, 1.0);
+        Self {
+            value,
+            passed: value >= threshold,
+            reason: None,
+        }
+    }
+
+    /// A failing score recording why the scorer could not run.
+    pub fn error(reason: impl Into<String>) -> Self {
+        Self {
+            value: 0.0,
+            passed: false,
+            reason: Some(reason.into()),
+        }
+    }
+
+    /// Attach an explanation.
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+}
+
+/// Scores a target's output for a case.
+#[async_trait::async_trait]
+pub trait Scorer: Send + Sync {
+    /// Name used as the metric key in reports; must be unique within a run.
+    fn name(&self) -> &str;
+
+    /// Score `output` for `case`. Errors are recorded as failing scores.
+    async fn score(&self, case: &EvalCase, output: &str) -> Result<Score, LlmError>;
+}
+
+fn expected(case: &EvalCase) -> Result<&str, LlmError> {
+    case.expected.as_deref().ok_or_else(|| {
+        LlmError::InvalidInput(format!("eval case '{}' has no expected output", case.id))
+    })
+}
+
+/// Output equals the expected answer after trimming.
+#[derive(Debug, Clone, Default)]
+pub struct ExactMatch {
+    ignore_case: bool,
+}
+
+impl ExactMatch {
+    /// Case-sensitive exact match.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compare case-insensitively.
+    pub fn ignore_case(mut self) -> Self {
+        self.ignore_case = true;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Scorer for ExactMatch {
+    fn name(&self) -> &str {
+        "exact_match"
+    }
+
+    async fn score(&self, case: &EvalCase, output: &str) -> Result<Score, LlmError> {
+        let (expected, output) = (expected(case)?.trim(), output.trim());
+        Ok(Score::pass_fail(if self.ignore_case {
+            expected.to_lowercase() == output.to_lowercase()
+        } else {
+            expected == output
+        }))
+    }
+}
+
+/// Output matches a regular expression.
+///
+/// Without an explicit pattern, the case's expected output is used as the pattern.
+#[derive(Debug, Clone)]
+pub struct RegexMatch {
+    name: String,
+    pattern: Option<regex::Regex>,
+}
+
+impl RegexMatch {
+    /// Match every output against `pattern`.
+    pub fn new(pattern: &str) -> Result<Self, LlmError> {
+        let pattern = regex::Regex::new(pattern)
+            .map_err(|e| LlmError::ConfigurationError(format!("invalid regex scorer: {e}")))?;
+        Ok(Self {
+            name: "regex_match".to_string(),
+            pattern: Some(pattern),
+        })
+    }
+
+    /// Treat each case's expected output as the pattern.
+    pub fn from_expected() -> Self {
+        Self {
+            name: "regex_match".to_string(),
+            pattern: None,
+        }
+    }
+
+    /// Override the metric name (useful with several regex scorers).
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Scorer for RegexMatch {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn score(&self, case: &EvalCase, output: &str) -> Result<Score, LlmError> {
+        let matched = match &self.pattern {
+            Some(pattern) => pattern.is_match(output),
+            None => regex::Regex::new(expected(case)?)
+                .map_err(|e| LlmError::InvalidInput(format!("case '{}': {e}", case.id)))?
+                .is_match(output),
+        };
+        Ok(Score::pass_fail(matched))
+    }
+}
+
+/// Output is JSON (fenced or embedded JSON is extracted) that validates against a schema.
+#[cfg(feature = "schema")]
+#[derive(Debug, Clone)]
+pub struct JsonSchemaMatch {
+    schema: serde_json::Value,
+}
+
+#[cfg(feature = "schema")]
+impl JsonSchemaMatch {
+    /// Validate outputs against `schema`.
+    pub fn new(schema: serde_json::Value) -> Self {
+        Self { schema }
+    }
+}
+
+#[cfg(feature = "schema")]
+#[async_trait::async_trait]
+impl Scorer for JsonSchemaMatch {
+    fn name(&self) -> &str {
+        "json_schema"
+    }
+
+    async fn score(&self, _case: &EvalCase, output: &str) -> Result<Score, LlmError> {
+        let value = match decode_json_value(output, &OutputDecodeConfig::default()) {
+            Ok(value) => value,
+            Err(e) => return Ok(Score::pass_fail(false).with_reason(e.to_string())),
+        };
+        Ok(match crate::schema::validate_json(&self.schema, &value) {
+            Ok(()) => Score::pass_fail(true),
+            Err(e) => Score::pass_fail(false).with_reason(e.to_string()),
+        })
+    }
+}
+
+/// Cosine similarity between the embeddings of the output and the expected answer.
+#[derive(Clone)]
+pub struct EmbeddingSimilarity {
+    model: Arc<dyn EmbeddingModel>,
+    threshold: f64,
+}
+
+impl EmbeddingSimilarity {
+    /// Score with `model`; passes at a similarity of 0.8 or more.
+    pub fn new(model: Arc<dyn EmbeddingModel>) -> Self {
+        Self {
+            model,
+            threshold: 0.8,
+        }
+    }
+
+    /// Minimum similarity to pass.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+#[async_trait::async_trait]
+impl Scorer for EmbeddingSimilarity {
+    fn name(&self) -> &str {
+        "embedding_similarity"
+    }
+
+    async fn score(&self, case: &EvalCase, output: &str) -> Result<Score, LlmError> {
+        let request = EmbeddingRequest::new(vec![output.to_string(), expected(case)?.to_string()]);
+        let response = self.model.embed(request).await?;
+        let [actual, reference] = response.embeddings.as_slice() else {
+            return Err(LlmError::ParseError(format!(
+                "expected 2 embeddings, got {}",
+                response.embeddings.len()
+            )));
+        };
+        let similarity = cosine_similarity(actual, reference)?;
+        Ok(Score::graded(similarity, self.threshold))
+    }
+}
+
+#[derive(Deserialize)]
+struct Verdict {
+    score: f64,
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+/// Asks a model to grade the output against a rubric.
+///
+/// The judge sees the rubric, the input, the reference answer (if any) and the output, and must
+/// reply with `{"score": <0..=scale>, "reason": "..."}`.
+#[derive(Clone)]
+pub struct LlmJudge {
+    name: String,
+    judge: Arc<dyn ChatCapability>,
+    rubric: String,
+    scale: u32,
+    threshold: f64,
+}
+
+impl LlmJudge {
+    /// Grade on a 0-10 scale; a normalized score of 0.7 or more passes.
+    pub fn new(judge: Arc<dyn ChatCapability>, rubric: impl Into<String>) -> Self {
+        Self {
+            name: "llm_judge".to_string(),
+            judge,
+            rubric: rubric.into(),
+            scale: 10,
+            threshold: 0.7,
+        }
+    }
+
+    /// Override the metric name (useful with several rubrics).
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Maximum score the judge may give (at least 1).
+    pub fn with_scale(mut self, scale: u32) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    /// Minimum normalized score to pass.
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    fn prompt(&self, case: &EvalCase, output: &str) -> String {
+        let mut prompt = format!(
+            "Grade the response below against the rubric.\n\nRubric:\n{}\n\nInput:\n{}\n",
+            self.rubric, case.input
+        );
+        if let Some(expected) = &case.expected {
+            prompt.push_str(&format!("\nReference answer:\n{expected}\n"));
+        }
+        prompt.push_str(&format!(
+            "\nResponse:\n{output}\n\nReply with only a JSON object: \
+             {{\"score\": <integer from 0 to {}>, \"reason\": \"<one sentence>\"}}",
+            self.scale
+        ));
+        prompt
+    }
+}
+
+#[async_trait::async_trait]
+impl Scorer for LlmJudge {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn score(&self, case: &EvalCase, output: &str) -> Result<Score, LlmError> {
+        let mut request = ChatRequest::new(vec![
+            ChatMessage::system("You are a strict, impartial evaluator.").build(),
+            ChatMessage::user(self.prompt(case, output)).build(),
+        ]);
+        request.common_params.temperature = Some(0.0);
+        let response = self.judge.chat_request(request).await?;
+        let value =
+            decode_json_value(&response.content.all_text(), &OutputDecodeConfig::default())?;
+        let verdict: Verdict = serde_json::from_value(value)
+            .map_err(|e| LlmError::ParseError(format!("invalid judge verdict: {e}")))?;
+        let score = Score::graded(verdict.score / f64::from(self.scale), self.threshold);
+        Ok(match verdict.reason {
+            Some(reason) => score.with_reason(reason),
+            None => score,
+        })
+    }
+}
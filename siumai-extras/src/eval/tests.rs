@@ -0,0 +1,339 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde_json::json;
+use siumai::embedding::{BatchEmbeddingRequest, BatchEmbeddingResponse};
+use siumai::prelude::unified::*;
+
+use super::*;
+use crate::orchestrator::step_count_is;
+
+/// Answers from a lookup table keyed by the last user message.
+#[derive(Clone)]
+struct TableModel {
+    answers: Arc<Vec<(&'static str, &'static str)>>,
+    delay: Duration,
+}
+
+impl TableModel {
+    fn new(answers: &[(&'static str, &'static str)]) -> Self {
+        Self {
+            answers: Arc::new(answers.to_vec()),
+            delay: Duration::ZERO,
+        }
+    }
+}
+
+impl ModelMetadata for TableModel {
+    fn provider_id(&self) -> &str {
+        "table"
+    }
+
+    fn model_id(&self) -> &str {
+        "table-model"
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatCapability for TableModel {
+    async fn chat_with_tools(
+        &self,
+        messages: Vec<ChatMessage>,
+        _tools: Option<Vec<Tool>>,
+    ) -> Result<ChatResponse, LlmError> {
+        tokio::time::sleep(self.delay).await;
+        let prompt = messages.last().unwrap().content.all_text();
+        let answer = self
+            .answers
+            .iter()
+            .find(|(q, _)| prompt.contains(q))
+            .map(|(_, a)| *a)
+            .ok_or_else(|| LlmError::ApiError {
+                code: 500,
+                message: format!("no answer for {prompt}"),
+                details: None,
+                http_details: None,
+            })?;
+        let mut response = ChatResponse::new(MessageContent::Text(answer.to_string()));
+        response.usage = Some(
+            Usage::builder()
+                .prompt_tokens(100)
+                .completion_tokens(20)
+                .build(),
+        );
+        Ok(response)
+    }
+
+    async fn chat_stream(
+        &self,
+        _messages: Vec<ChatMessage>,
+        _tools: Option<Vec<Tool>>,
+    ) -> Result<ChatStream, LlmError> {
+        Err(LlmError::UnsupportedOperation("table model".into()))
+    }
+}
+
+struct LengthEmbedder;
+
+impl ModelMetadata for LengthEmbedder {
+    fn provider_id(&self) -> &str {
+        "test"
+    }
+
+    fn model_id(&self) -> &str {
+        "length"
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddingModel for LengthEmbedder {
+    async fn embed(&self, request: EmbeddingRequest) -> Result<EmbeddingResponse, LlmError> {
+        // Embeds text as (length, vowel count): similar strings get similar vectors.
+        let embeddings = request
+            .input
+            .iter()
+            .map(|t| {
+                let vowels = t.chars().filter(|c| "aeiou".contains(*c)).count();
+                vec![t.len() as f32, vowels as f32 * 4.0]
+            })
+            .collect();
+        Ok(EmbeddingResponse::new(embeddings, "length".to_string()))
+    }
+
+    async fn embed_many(
+        &self,
+        _requests: BatchEmbeddingRequest,
+    ) -> Result<BatchEmbeddingResponse, LlmError> {
+        Err(LlmError::UnsupportedOperation("batch".into()))
+    }
+}
+
+fn dataset() -> EvalDataset {
+    EvalDataset::from_jsonl(
+        "capitals",
+        r#"
+{"id": "fr", "input": "Capital of France?", "expected": "Paris"}
+{"id": "jp", "input": "Capital of Japan?", "expected": "Tokyo"}
+
+{"id": "xx", "input": "Capital of Atlantis?", "expected": "None", "metadata": {"trick": true}}
+"#,
+    )
+    .unwrap()
+}
+
+#[test]
+fn dataset_parses_jsonl_and_reports_bad_lines() {
+    let dataset = dataset();
+    assert_eq!(dataset.cases.len(), 3);
+    assert_eq!(dataset.cases[2].metadata["trick"], json!(true));
+
+    let err =
+        EvalDataset::from_jsonl("bad", "{\"id\": \"a\", \"input\": \"x\"}\nnot json").unwrap_err();
+    assert!(err.to_string().contains("line 2"));
+}
+
+#[tokio::test]
+async fn runner_scores_targets_and_aggregates_usage() {
+    let good = TableModel::new(&[("France", "Paris"), ("Japan", " tokyo ")]);
+    let mut slow = TableModel::new(&[("France", "Lyon"), ("Japan", "Tokyo"), ("Atlantis", "None")]);
+    slow.delay = Duration::from_millis(5);
+
+    let report = EvalRunner::new(dataset())
+        .with_target(
+            ModelTarget::new("good", Arc::new(good)).with_pricing(ModelPricing::new(1.0, 2.0)),
+        )
+        .with_target(ModelTarget::new("slow", Arc::new(slow)))
+        .with_scorer(ExactMatch::new().ignore_case())
+        .with_scorer(RegexMatch::new(r"^\s*[A-Z]").unwrap().named("capitalized"))
+        .with_concurrency(3)
+        .run()
+        .await;
+
+    // Results keep target order and dataset order regardless of completion order.
+    let order: Vec<(&str, &str)> = report
+        .results
+        .iter()
+        .map(|r| (r.target.as_str(), r.case_id.as_str()))
+        .collect();
+    assert_eq!(
+        order,
+        [
+            ("good", "fr"),
+            ("good", "jp"),
+            ("good", "xx"),
+            ("slow", "fr"),
+            ("slow", "jp"),
+            ("slow", "xx")
+        ]
+    );
+
+    let good = report.summary("good").unwrap();
+    assert_eq!((good.cases, good.errors), (3, 1));
+    assert_eq!(good.mean_scores["exact_match"], 1.0);
+    assert!((good.pass_rates["exact_match"] - 2.0 / 3.0).abs() < 1e-9);
+    assert_eq!(good.pass_rates["capitalized"], 1.0 / 3.0);
+    assert_eq!(good.usage.total, 240);
+    // 200 input tokens at $1/M plus 40 output tokens at $2/M.
+    assert!((good.cost.unwrap() - 0.00028).abs() < 1e-12);
+
+    let slow = report.summary("slow").unwrap();
+    assert!((slow.pass_rates["exact_match"] - 2.0 / 3.0).abs() < 1e-9);
+    assert!(slow.latency_p95_ms >= 5.0);
+    assert_eq!(slow.cost, None);
+
+    let failed = &report.results[2];
+    assert!(failed.error.as_deref().unwrap().contains("Atlantis"));
+    assert!(failed.scores.is_empty());
+}
+
+#[tokio::test]
+async fn reports_round_trip_and_compare_against_a_baseline() {
+    async fn run(answers: &'static [(&'static str, &'static str)]) -> EvalReport {
+        EvalRunner::new(dataset())
+            .with_target(ModelTarget::new(
+                "model",
+                Arc::new(TableModel::new(answers)),
+            ))
+            .with_scorer(ExactMatch::new())
+            .run()
+            .await
+    }
+    let baseline = run(&[
+        ("France", "Paris"),
+        ("Japan", "Kyoto"),
+        ("Atlantis", "None"),
+    ])
+    .await;
+    let current = run(&[
+        ("France", "Paris"),
+        ("Japan", "Tokyo"),
+        ("Atlantis", "None"),
+    ])
+    .await;
+
+    // serde_json does not round-trip every f64 exactly; pin measured latencies first.
+    let mut results = baseline.results;
+    for result in &mut results {
+        result.latency_ms = result.latency_ms.round();
+    }
+    let baseline = EvalReport::new(baseline.dataset, results);
+    let parsed = EvalReport::from_json(&baseline.to_json()).unwrap();
+    assert_eq!(parsed, baseline);
+
+    let deltas = current.compare(&parsed);
+    let pass_rate = deltas
+        .iter()
+        .find(|d| d.metric == "pass_rate:exact_match")
+        .unwrap();
+    assert!((pass_rate.delta() - 1.0 / 3.0).abs() < 1e-9);
+
+    let md = current.to_markdown();
+    assert!(md.starts_with("# Eval report: capitals"));
+    assert!(md.contains("| model | 3 | 0 | 1.000 (100% pass) |"));
+    assert!(md.contains("| jp | model | 1.00 pass |"));
+}
+
+#[tokio::test]
+async fn embedding_and_judge_scorers() {
+    let case = EvalCase::new("c", "Name a fruit").with_expected("banana");
+
+    let similarity = EmbeddingSimilarity::new(Arc::new(LengthEmbedder)).with_threshold(0.99);
+    assert!(similarity.score(&case, "banana").await.unwrap().passed);
+    let far = similarity.score(&case, "plum").await.unwrap();
+    assert!(!far.passed && far.value < 0.99);
+
+    let seen = Arc::new(Mutex::new(String::new()));
+    struct Judge(Arc<Mutex<String>>);
+    #[async_trait::async_trait]
+    impl ChatCapability for Judge {
+        async fn chat_with_tools(
+            &self,
+            messages: Vec<ChatMessage>,
+            _tools: Option<Vec<Tool>>,
+        ) -> Result<ChatResponse, LlmError> {
+            *self.0.lock().unwrap() = messages.last().unwrap().content.all_text();
+            Ok(ChatResponse::new(MessageContent::Text(
+                "```json\n{\"score\": 6, \"reason\": \"Correct but terse.\"}\n```".to_string(),
+            )))
+        }
+
+        async fn chat_stream(
+            &self,
+            _messages: Vec<ChatMessage>,
+            _tools: Option<Vec<Tool>>,
+        ) -> Result<ChatStream, LlmError> {
+            Err(LlmError::UnsupportedOperation("judge".into()))
+        }
+    }
+
+    let judge = LlmJudge::new(Arc::new(Judge(seen.clone())), "Is it a yellow fruit?");
+    let score = judge.score(&case, "banana").await.unwrap();
+    assert_eq!(score.value, 0.6);
+    assert!(!score.passed);
+    assert_eq!(score.reason.as_deref(), Some("Correct but terse."));
+    let prompt = seen.lock().unwrap().clone();
+    assert!(
+        prompt.contains("Is it a yellow fruit?") && prompt.contains("Reference answer:\nbanana")
+    );
+
+    assert!(
+        judge
+            .with_threshold(0.5)
+            .score(&case, "banana")
+            .await
+            .unwrap()
+            .passed
+    );
+}
+
+#[cfg(feature = "schema")]
+#[tokio::test]
+async fn json_schema_scorer_extracts_and_validates_json() {
+    let scorer = JsonSchemaMatch::new(json!({
+        "type": "object",
+        "properties": { "city": { "type": "string" } },
+        "required": ["city"]
+    }));
+    let case = EvalCase::new("c", "Where?");
+    assert!(
+        scorer
+            .score(&case, "Here you go: {\"city\": \"Paris\"}")
+            .await
+            .unwrap()
+            .passed
+    );
+    let missing = scorer.score(&case, "{\"town\": \"Paris\"}").await.unwrap();
+    assert!(!missing.passed && missing.reason.is_some());
+}
+
+struct NoTools;
+
+#[async_trait::async_trait]
+impl ToolResolver for NoTools {
+    async fn call_tool(&self, name: &str, _arguments: Value) -> Result<Value, LlmError> {
+        Err(LlmError::ToolCallError(format!("unknown tool {name}")))
+    }
+}
+
+#[tokio::test]
+async fn agent_targets_report_steps() {
+    let agent = ToolLoopAgent::new(
+        TableModel::new(&[("France", "Paris")]),
+        Vec::new(),
+        vec![step_count_is(3)],
+    );
+    let report = EvalRunner::new(EvalDataset::new(
+        "agent",
+        vec![EvalCase::new("fr", "Capital of France?").with_expected("Paris")],
+    ))
+    .with_target(AgentTarget::new("agent", agent, Arc::new(NoTools)))
+    .with_scorer(ExactMatch::new())
+    .run()
+    .await;
+
+    let result = &report.results[0];
+    assert_eq!(result.output.as_deref(), Some("Paris"));
+    assert_eq!(result.steps, 1);
+    assert_eq!(result.usage.unwrap().input, 100);
+    assert!(result.scores["exact_match"].passed);
+}
@@ -7,6 +7,10 @@
 //! to generate typed JSON objects using any TextModel model. The function
 //! performs optional JSON Schema validation and optional text repair before
 //! deserializing into `T`.
+//!
+//! With `self_correction` set, invalid output is not only repaired locally: the
+//! parse/schema/deserialization errors are sent back to the model as a follow-up
+//! turn, up to a configurable number of rounds.
 
 use futures::Stream;
 use serde::de::DeserializeOwned;
@@ -17,12 +21,17 @@ use siumai::provider_ext::anthropic::AnthropicChatRequestExt;
 use siumai::provider_ext::gemini::GeminiChatRequestExt;
 #[cfg(feature = "openai")]
 use siumai::provider_ext::openai::OpenAiChatRequestExt;
+use siumai::structured_output::{JsonArrayElementScanner, ScannedJsonArrayElement};
 use siumai::text::TextModel;
+use std::future::Future;
 use std::pin::Pin;
+use std::sync::Arc;
 
 use crate::structured_output::{
-    GenerateMode, OutputDecodeConfig, OutputKind, RepairFn, decode_typed,
+    GenerateMode, OutputDecodeConfig, OutputKind, RepairFn, accumulate_usage, correction_message,
+    correction_prompt, decode_typed, diagnose_typed,
 };
+pub use crate::structured_output::{OutputIssue, SelfCorrection};
 
 /// Options for object generation.
 pub struct GenerateObjectOptions {
@@ -42,6 +51,8 @@ pub struct GenerateObjectOptions {
     pub repair_text: Option<RepairFn>,
     /// Number of repair attempts to try when parsing/validation fails.
     pub max_repair_rounds: usize,
+    /// Send validation errors back to the model for another attempt (opt-in).
+    pub self_correction: Option<SelfCorrection>,
 }
 
 impl Default for GenerateObjectOptions {
@@ -54,6 +65,7 @@ impl Default for GenerateObjectOptions {
             mode: GenerateMode::default(),
             repair_text: None,
             max_repair_rounds: 1,
+            self_correction: None,
         }
     }
 }
@@ -65,39 +77,16 @@ impl Default for GenerateObjectOptions {
 /// - Extracts text content and attempts to parse as JSON.
 /// - Optionally validates against a JSON Schema.
 /// - Deserializes JSON into `T` and returns along with the raw ChatResponse.
+///
+/// With `opts.self_correction`, a failed attempt is answered with a follow-up turn listing
+/// each problem by JSON path (a tool error when the object came as a tool call); the returned
+/// response is the last attempt, with usage summed over all attempts.
 pub async fn generate_object<T: DeserializeOwned>(
     model: &impl TextModel,
-    messages: Vec<ChatMessage>,
+    mut messages: Vec<ChatMessage>,
     tools: Option<Vec<Tool>>,
     opts: GenerateObjectOptions,
 ) -> Result<(T, ChatResponse), LlmError> {
-    // Build ChatRequest to allow passing provider hints for structured outputs
-    let request = build_chat_request_with_hints(messages, tools.clone(), &opts, false);
-    let resp = model.generate(request).await?;
-
-    // Prefer tool call arguments if present (Tool mode or provider chose tools)
-    let text = {
-        let tool_calls = resp.tool_calls();
-        if let Some(first) = tool_calls.first() {
-            if let ContentPart::ToolCall { arguments, .. } = first {
-                serde_json::to_string(arguments).unwrap_or_default()
-            } else {
-                resp.content_text()
-                    .map(|s| s.to_string())
-                    .unwrap_or_default()
-            }
-        } else {
-            resp.content_text()
-                .map(|s| s.to_string())
-                .unwrap_or_default()
-        }
-    };
-    if text.is_empty() {
-        return Err(LlmError::ParseError(
-            "No content for object generation".into(),
-        ));
-    }
-
     let cfg = OutputDecodeConfig {
         schema: opts.schema.clone().map(|schema| OutputSchema {
             schema,
@@ -110,9 +99,113 @@ pub async fn generate_object<T: DeserializeOwned>(
         repair_text: opts.repair_text.clone(),
         max_repair_rounds: opts.max_repair_rounds,
     };
+    let mut total_usage = None;
+    let mut round = 0;
 
-    let obj = decode_typed::<T>(&text, &cfg)?;
-    Ok((obj, resp))
+    loop {
+        // Build ChatRequest to allow passing provider hints for structured outputs
+        let request = build_chat_request_with_hints(messages.clone(), tools.clone(), &opts, false);
+        let mut resp = model.generate(request).await?;
+        accumulate_usage(&mut total_usage, resp.usage.as_ref());
+
+        let text = response_object_text(&resp);
+        let result = if text.is_empty() {
+            Err(crate::structured_output::DecodeFailure {
+                error: LlmError::ParseError("No content for object generation".into()),
+                issues: vec![OutputIssue {
+                    path: String::new(),
+                    message: "the response contained no JSON output".into(),
+                }],
+            })
+        } else {
+            diagnose_typed::<T>(&text, &cfg)
+        };
+
+        match result {
+            Ok(obj) => {
+                resp.usage = total_usage;
+                return Ok((obj, resp));
+            }
+            Err(failure) => match &opts.self_correction {
+                Some(correction) if round < correction.max_rounds => {
+                    round += 1;
+                    let tool_call = response_tool_call(&resp);
+                    push_correction_turn(
+                        &mut messages,
+                        text,
+                        tool_call,
+                        &failure.issues,
+                        &cfg,
+                        correction,
+                    );
+                }
+                _ => return Err(failure.error),
+            },
+        }
+    }
+}
+
+/// Id and name of the tool call carrying the object, if any.
+fn response_tool_call(resp: &ChatResponse) -> Option<(String, String)> {
+    match resp.tool_calls().first() {
+        Some(ContentPart::ToolCall {
+            tool_call_id,
+            tool_name,
+            ..
+        }) => Some((tool_call_id.clone(), tool_name.clone())),
+        _ => None,
+    }
+}
+
+/// Replay a rejected attempt and ask the model to fix it.
+///
+/// Output delivered through a tool call (`GenerateMode::Tool`) is replayed as that call and
+/// answered with a tool error carrying the problems, so every tool call in the conversation has
+/// a result; text output is replayed as an assistant turn followed by a user correction.
+fn push_correction_turn(
+    messages: &mut Vec<ChatMessage>,
+    text: String,
+    tool_call: Option<(String, String)>,
+    issues: &[OutputIssue],
+    cfg: &OutputDecodeConfig,
+    correction: &SelfCorrection,
+) {
+    match tool_call {
+        Some((tool_call_id, tool_name)) => {
+            let arguments = serde_json::from_str(&text).unwrap_or(serde_json::Value::String(text));
+            messages.push(
+                ChatMessage::assistant_with_content(vec![ContentPart::tool_call(
+                    tool_call_id.clone(),
+                    tool_name.clone(),
+                    arguments,
+                    None,
+                )])
+                .build(),
+            );
+            messages.push(
+                ChatMessage::tool_error(
+                    tool_call_id,
+                    tool_name,
+                    correction_prompt(issues, cfg.schema.as_ref(), correction),
+                )
+                .build(),
+            );
+        }
+        None => {
+            messages.push(ChatMessage::assistant(text).build());
+            messages.push(correction_message(issues, cfg.schema.as_ref(), correction));
+        }
+    }
+}
+
+/// Text carrying the object: tool call arguments when present, otherwise the content text.
+fn response_object_text(resp: &ChatResponse) -> String {
+    if let Some(ContentPart::ToolCall { arguments, .. }) = resp.tool_calls().first() {
+        return serde_json::to_string(arguments).unwrap_or_default();
+    }
+    resp.content_text()
+        .map(|s| s.to_string())
+        .unwrap_or_default()
 }
 
 /// Stream options for `stream_object`.
@@ -133,6 +226,9 @@ pub struct StreamObjectOptions {
     pub max_repair_rounds: usize,
     /// Whether to attempt partial JSON parsing on each delta and emit partial updates.
     pub emit_partial_object: bool,
+    /// Optional model-driven correction turns. Only [`stream_object_owned`] can re-stream;
+    /// the other streaming helpers reject it.
+    pub self_correction: Option<SelfCorrection>,
 }
 
 impl Default for StreamObjectOptions {
@@ -146,6 +242,7 @@ impl Default for StreamObjectOptions {
             repair_text: None,
             max_repair_rounds: 1,
             emit_partial_object: true,
+            self_correction: None,
         }
     }
 }
@@ -162,6 +259,14 @@ pub enum StreamObjectEvent<T> {
         /// Latest parsed JSON value built from the accumulated stream buffer.
         partial: serde_json::Value,
     },
+    /// The output was rejected and a correction turn was started; later deltas and
+    /// partial objects belong to the new attempt.
+    Correction {
+        /// 1-based correction round.
+        round: usize,
+        /// Problems reported to the model.
+        issues: Vec<OutputIssue>,
+    },
     /// Usage update passthrough.
     UsageUpdate {
         /// Updated token usage statistics.
@@ -176,6 +281,41 @@ pub enum StreamObjectEvent<T> {
     },
 }
 
+/// Streaming events for [`stream_object_elements`].
+pub enum StreamElementEvent<E> {
+    /// A completed array element that passed validation, emitted once as soon as it closes.
+    Element {
+        /// Zero-based position of the element in the array.
+        index: usize,
+        /// The element decoded into `E`.
+        element: E,
+    },
+    /// A completed array element that failed parsing, schema validation or deserialization;
+    /// the stream continues with the next element.
+    ElementError {
+        /// Zero-based position of the element in the array.
+        index: usize,
+        /// The decoding error.
+        error: LlmError,
+        /// Located problems, with paths relative to the element.
+        issues: Vec<OutputIssue>,
+    },
+    /// Usage update passthrough.
+    UsageUpdate {
+        /// Updated token usage statistics.
+        usage: Usage,
+    },
+    /// End of generation.
+    Finish {
+        /// Number of elements seen, including rejected ones.
+        elements: usize,
+        /// Whether the closing `]` of the array was seen.
+        complete: bool,
+        /// The underlying raw chat response.
+        response: ChatResponse,
+    },
+}
+
 fn maybe_extract_partial_object(
     acc: &str,
     emit_partial: bool,
@@ -229,17 +369,24 @@ fn maybe_accumulate_text_from_loose_part(
     }
 }
 
+/// Accumulate tool call arguments and remember the id and name of the call carrying them.
 fn maybe_accumulate_tool_arguments_from_runtime_part(
     part: &ChatStreamPart,
     tool_args_acc: &mut String,
+    tool_call: &mut Option<(String, String)>,
 ) -> bool {
     match part {
+        ChatStreamPart::ToolInputStart { id, tool_name, .. } => {
+            *tool_call = Some((id.clone(), tool_name.clone()));
+            true
+        }
         ChatStreamPart::ToolInputDelta { delta, .. } => {
             tool_args_acc.push_str(delta);
             true
         }
         ChatStreamPart::ToolCall(call) => {
             *tool_args_acc = call.input.clone();
+            *tool_call = Some((call.tool_call_id.clone(), call.tool_name.clone()));
             true
         }
         _ => false,
@@ -249,6 +396,7 @@ fn maybe_accumulate_tool_arguments_from_runtime_part(
 fn maybe_accumulate_tool_arguments_from_loose_part(
     data: &serde_json::Value,
     tool_args_acc: &mut String,
+    tool_call: &mut Option<(String, String)>,
 ) -> bool {
     let Some(part) = siumai::experimental::streaming::TypedStreamPart::parse_loose_json(data)
     else {
@@ -256,12 +404,19 @@ fn maybe_accumulate_tool_arguments_from_loose_part(
     };
 
     match part {
+        siumai::experimental::streaming::TypedStreamPart::ToolInputStart {
+            id, tool_name, ..
+        } => {
+            *tool_call = Some((id, tool_name));
+            true
+        }
         siumai::experimental::streaming::TypedStreamPart::ToolInputDelta { delta, .. } => {
             tool_args_acc.push_str(&delta);
             true
         }
         siumai::experimental::streaming::TypedStreamPart::ToolCall(call) => {
             *tool_args_acc = call.input;
+            *tool_call = Some((call.tool_call_id, call.tool_name));
             true
         }
         _ => false,
@@ -272,103 +427,319 @@ fn maybe_accumulate_tool_arguments_from_loose_part(
 ///
 /// Minimal strategy: accumulate text deltas; on stream end attempt parse + optional
 /// schema validation + optional repair rounds; yield Final event when successful.
+///
+/// Self-correction needs to re-stream after the first attempt, so `opts.self_correction` is
+/// rejected here; use [`stream_object_owned`] for it.
 pub async fn stream_object<T: DeserializeOwned + Send + 'static>(
     model: &impl TextModel,
     messages: Vec<ChatMessage>,
     tools: Option<Vec<Tool>>,
     opts: StreamObjectOptions,
 ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamObjectEvent<T>, LlmError>> + Send>>, LlmError> {
-    let req = build_chat_request_with_hints(
-        messages,
-        tools.clone(),
-        &GenerateObjectOptions {
-            schema: opts.schema.clone(),
-            schema_name: opts.schema_name.clone(),
-            schema_description: opts.schema_description.clone(),
-            output: opts.output.clone(),
-            mode: opts.mode,
-            repair_text: opts.repair_text.clone(),
-            max_repair_rounds: opts.max_repair_rounds,
-        },
-        true,
-    );
-    let mut stream = model.stream(req).await?;
-    let output_kind = opts.output.clone();
-    let mode = opts.mode;
-    let repair = opts.repair_text.clone();
-    let max_rounds = opts.max_repair_rounds;
+    reject_self_correction(&opts, "stream_object")?;
+    let req = build_chat_request_with_hints(messages, tools, &hint_options(&opts), true);
+    let stream = model.stream(req).await?;
+    Ok(object_event_stream(stream, Vec::new(), None, opts))
+}
+
+/// Stream a typed object `T`, taking ownership of the model.
+///
+/// Behaves like [`stream_object`] and additionally honours `opts.self_correction`: a rejected
+/// attempt yields [`StreamObjectEvent::Correction`] and the model is streamed again with the
+/// problems as a follow-up turn. The final response carries usage summed over all attempts.
+pub async fn stream_object_owned<T, M>(
+    model: M,
+    messages: Vec<ChatMessage>,
+    tools: Option<Vec<Tool>>,
+    opts: StreamObjectOptions,
+) -> Result<Pin<Box<dyn Stream<Item = Result<StreamObjectEvent<T>, LlmError>> + Send>>, LlmError>
+where
+    T: DeserializeOwned + Send + 'static,
+    M: TextModel + 'static,
+{
+    let model = Arc::new(model);
+    let hints = hint_options(&opts);
+    let reopen: ReopenStream = Arc::new(move |messages| {
+        let model = model.clone();
+        let req = build_chat_request_with_hints(messages, tools.clone(), &hints, true);
+        Box::pin(async move { model.stream(req).await })
+    });
+    let stream = reopen(messages.clone()).await?;
+    let correction = opts
+        .self_correction
+        .clone()
+        .map(|correction| (correction, reopen));
+    Ok(object_event_stream(stream, messages, correction, opts))
+}
+
+/// Streaming helpers that cannot re-stream refuse `self_correction` instead of ignoring it.
+fn reject_self_correction(opts: &StreamObjectOptions, api: &str) -> Result<(), LlmError> {
+    if opts.self_correction.is_some() {
+        return Err(LlmError::InvalidParameter(format!(
+            "{api} cannot re-stream for self_correction; use stream_object_owned"
+        )));
+    }
+    Ok(())
+}
+
+/// Starts another streamed attempt from the full conversation.
+type ReopenStream = Arc<
+    dyn Fn(Vec<ChatMessage>) -> Pin<Box<dyn Future<Output = Result<ChatStream, LlmError>> + Send>>
+        + Send
+        + Sync,
+>;
+
+fn hint_options(opts: &StreamObjectOptions) -> GenerateObjectOptions {
+    GenerateObjectOptions {
+        schema: opts.schema.clone(),
+        schema_name: opts.schema_name.clone(),
+        schema_description: opts.schema_description.clone(),
+        output: opts.output.clone(),
+        mode: opts.mode,
+        repair_text: opts.repair_text.clone(),
+        max_repair_rounds: opts.max_repair_rounds,
+        self_correction: None,
+    }
+}
+
+fn object_event_stream<T: DeserializeOwned + Send + 'static>(
+    mut stream: ChatStream,
+    mut messages: Vec<ChatMessage>,
+    correction: Option<(SelfCorrection, ReopenStream)>,
+    opts: StreamObjectOptions,
+) -> Pin<Box<dyn Stream<Item = Result<StreamObjectEvent<T>, LlmError>> + Send>> {
     let emit_partial = opts.emit_partial_object;
+    let cfg = OutputDecodeConfig {
+        schema: opts.schema.clone().map(|schema| OutputSchema {
+            schema,
+            name: opts.schema_name.clone(),
+            description: opts.schema_description.clone(),
+        }),
+        kind: opts.output.clone(),
+        mode: opts.mode,
+        emit_partial,
+        repair_text: opts.repair_text.clone(),
+        max_repair_rounds: opts.max_repair_rounds,
+    };
 
     let s = async_stream::try_stream! {
         use futures::StreamExt;
-        let mut acc = String::new();
+        let mut total_usage = None;
+        let mut round = 0;
+        loop {
+            let mut acc = String::new();
+            let mut final_resp: Option<ChatResponse> = None;
+            let mut tool_args_acc = String::new();
+            let mut tool_call: Option<(String, String)> = None;
+            let mut last_partial: Option<serde_json::Value> = None;
+            while let Some(item) = stream.next().await {
+                match item? {
+                    ChatStreamEvent::Part { part } | ChatStreamEvent::PartWithReplay { part, .. } => {
+                        if let Some(delta) = maybe_accumulate_text_from_runtime_part(&part, &mut acc) {
+                            yield StreamObjectEvent::TextDelta { delta };
+                            if let Some(partial) =
+                                maybe_extract_partial_object(&acc, emit_partial, &mut last_partial)
+                            {
+                                yield StreamObjectEvent::PartialObject { partial };
+                            }
+                        }
+                        let _ = maybe_accumulate_tool_arguments_from_runtime_part(
+                            &part,
+                            &mut tool_args_acc,
+                            &mut tool_call,
+                        );
+                        if let ChatStreamPart::Finish { usage, .. } = &part {
+                            yield StreamObjectEvent::UsageUpdate {
+                                usage: usage.clone(),
+                            };
+                        }
+                    }
+                    ChatStreamEvent::Custom { data, .. } => {
+                        if let Some(delta) = maybe_accumulate_text_from_loose_part(&data, &mut acc) {
+                            yield StreamObjectEvent::TextDelta { delta };
+                            if let Some(partial) =
+                                maybe_extract_partial_object(&acc, emit_partial, &mut last_partial)
+                            {
+                                yield StreamObjectEvent::PartialObject { partial };
+                            }
+                        }
+                        let _ = maybe_accumulate_tool_arguments_from_loose_part(
+                            &data,
+                            &mut tool_args_acc,
+                            &mut tool_call,
+                        );
+                    }
+                    ChatStreamEvent::StreamEnd { response } => {
+                        final_resp = Some(response);
+                        break;
+                    }
+                    ChatStreamEvent::Error { error } => {
+                        Err::<(), _>(LlmError::StreamError(error))?;
+                    }
+                    _ => {}
+                }
+            }
+            let mut resp = final_resp
+                .unwrap_or_else(|| ChatResponse::new(MessageContent::Text(acc.clone())));
+            accumulate_usage(&mut total_usage, resp.usage.as_ref());
+            // Try parse/validate/deserialize with optional repair
+            // Prefer tool arguments if present
+            let (text, tool_call) = if !tool_args_acc.is_empty() {
+                (tool_args_acc, tool_call.or_else(|| response_tool_call(&resp)))
+            } else {
+                (acc, None)
+            };
+
+            let failure = match diagnose_typed::<T>(&text, &cfg) {
+                Ok(object) => {
+                    resp.usage = total_usage.take();
+                    yield StreamObjectEvent::Final { object, response: resp };
+                    break;
+                }
+                Err(failure) => failure,
+            };
+            let retry = match &correction {
+                Some((correction, reopen)) if round < correction.max_rounds => {
+                    Some((correction, reopen))
+                }
+                _ => None,
+            };
+            let Some((correction, reopen)) = retry else {
+                Err::<(), _>(failure.error)?;
+                break;
+            };
+
+            round += 1;
+            yield StreamObjectEvent::Correction {
+                round,
+                issues: failure.issues.clone(),
+            };
+            push_correction_turn(&mut messages, text, tool_call, &failure.issues, &cfg, correction);
+            stream = reopen(messages.clone()).await?;
+        }
+    };
+    Box::pin(s)
+}
+
+/// Stream the elements of an array output one by one, each typed as `E`.
+///
+/// The output is requested as a JSON array (`opts.output` is forced to `OutputKind::Array`,
+/// and `opts.schema` describes the whole array). Every top-level element is emitted exactly once,
+/// as soon as its closing delimiter arrives, after validation against the schema's `items` and
+/// deserialization into `E`. A rejected element yields [`StreamElementEvent::ElementError`]
+/// without ending the stream.
+pub async fn stream_object_elements<E: DeserializeOwned + Send + 'static>(
+    model: &impl TextModel,
+    messages: Vec<ChatMessage>,
+    tools: Option<Vec<Tool>>,
+    mut opts: StreamObjectOptions,
+) -> Result<Pin<Box<dyn Stream<Item = Result<StreamElementEvent<E>, LlmError>> + Send>>, LlmError> {
+    reject_self_correction(&opts, "stream_object_elements")?;
+    opts.output = OutputKind::Array;
+    let hints = hint_options(&opts);
+    let req = build_chat_request_with_hints(messages, tools, &hints, true);
+    let stream = model.stream(req).await?;
+    let cfg = OutputDecodeConfig {
+        schema: hints.schema.map(|schema| OutputSchema {
+            schema,
+            name: hints.schema_name,
+            description: hints.schema_description,
+        }),
+        kind: hints.output,
+        mode: hints.mode,
+        emit_partial: false,
+        repair_text: hints.repair_text,
+        max_repair_rounds: hints.max_repair_rounds,
+    };
+    Ok(element_event_stream(stream, cfg.array_element()))
+}
+
+fn decode_element<E: DeserializeOwned>(
+    scanned: ScannedJsonArrayElement,
+    cfg: &OutputDecodeConfig,
+) -> StreamElementEvent<E> {
+    match diagnose_typed::<E>(&scanned.json, cfg) {
+        Ok(element) => StreamElementEvent::Element {
+            index: scanned.index,
+            element,
+        },
+        Err(failure) => StreamElementEvent::ElementError {
+            index: scanned.index,
+            error: failure.error,
+            issues: failure.issues,
+        },
+    }
+}
+
+fn element_event_stream<E: DeserializeOwned + Send + 'static>(
+    mut stream: ChatStream,
+    element_cfg: OutputDecodeConfig,
+) -> Pin<Box<dyn Stream<Item = Result<StreamElementEvent<E>, LlmError>> + Send>> {
+    use siumai::experimental::streaming::TypedStreamPart;
+
+    let s = async_stream::try_stream! {
+        use futures::StreamExt;
+        // Elements may arrive as text or as tool-call arguments; scan both independently.
+        let mut text = JsonArrayElementScanner::new();
+        let mut tool_args = JsonArrayElementScanner::new();
         let mut final_resp: Option<ChatResponse> = None;
-        let mut tool_args_acc = String::new();
-        let mut last_partial: Option<serde_json::Value> = None;
         while let Some(item) = stream.next().await {
-            match item? {
+            let scanned = match item? {
                 ChatStreamEvent::Part { part } | ChatStreamEvent::PartWithReplay { part, .. } => {
-                    if let Some(delta) = maybe_accumulate_text_from_runtime_part(&part, &mut acc) {
-                        yield StreamObjectEvent::TextDelta { delta };
-                        if let Some(partial) =
-                            maybe_extract_partial_object(&acc, emit_partial, &mut last_partial)
-                        {
-                            yield StreamObjectEvent::PartialObject { partial };
-                        }
-                    }
-                    let _ = maybe_accumulate_tool_arguments_from_runtime_part(
-                        &part,
-                        &mut tool_args_acc,
-                    );
                     if let ChatStreamPart::Finish { usage, .. } = &part {
-                        yield StreamObjectEvent::UsageUpdate {
+                        yield StreamElementEvent::UsageUpdate {
                             usage: usage.clone(),
                         };
                     }
-                }
-                ChatStreamEvent::Custom { data, .. } => {
-                    if let Some(delta) = maybe_accumulate_text_from_loose_part(&data, &mut acc) {
-                        yield StreamObjectEvent::TextDelta { delta };
-                        if let Some(partial) =
-                            maybe_extract_partial_object(&acc, emit_partial, &mut last_partial)
-                        {
-                            yield StreamObjectEvent::PartialObject { partial };
+                    match part {
+                        ChatStreamPart::TextDelta { delta, .. } => text.push_str(&delta),
+                        ChatStreamPart::ToolInputDelta { delta, .. } => tool_args.push_str(&delta),
+                        ChatStreamPart::ToolCall(call) if tool_args.is_empty() => {
+                            tool_args.push_str(&call.input)
                         }
+                        _ => Vec::new(),
                     }
-                    let _ = maybe_accumulate_tool_arguments_from_loose_part(
-                        &data,
-                        &mut tool_args_acc,
-                    );
                 }
+                ChatStreamEvent::Custom { data, .. } => match TypedStreamPart::parse_loose_json(&data) {
+                    Some(TypedStreamPart::TextDelta { delta, .. }) => text.push_str(&delta),
+                    Some(TypedStreamPart::ToolInputDelta { delta, .. }) => tool_args.push_str(&delta),
+                    Some(TypedStreamPart::ToolCall(call)) if tool_args.is_empty() => {
+                        tool_args.push_str(&call.input)
+                    }
+                    _ => Vec::new(),
+                },
                 ChatStreamEvent::StreamEnd { response } => {
                     final_resp = Some(response);
                     break;
                 }
-                _ => {}
+                ChatStreamEvent::Error { error } => {
+                    Err::<(), _>(LlmError::StreamError(error))?;
+                    break;
+                }
+                _ => Vec::new(),
+            };
+            for element in scanned {
+                yield decode_element(element, &element_cfg);
             }
         }
-        let resp = final_resp
-            .unwrap_or_else(|| ChatResponse::new(MessageContent::Text(acc.clone())));
-        // Try parse/validate/deserialize with optional repair
-        // Prefer tool arguments if present
-        let text = if !tool_args_acc.is_empty() { tool_args_acc } else { acc };
-        let cfg = OutputDecodeConfig {
-            schema: opts.schema.clone().map(|schema| OutputSchema {
-                schema,
-                name: opts.schema_name.clone(),
-                description: opts.schema_description.clone(),
-            }),
-            kind: output_kind,
-            mode,
-            emit_partial,
-            repair_text: repair,
-            max_repair_rounds: max_rounds,
-        };
 
-        let obj = decode_typed::<T>(&text, &cfg)?;
-        yield StreamObjectEvent::Final { object: obj, response: resp };
+        let response =
+            final_resp.unwrap_or_else(|| ChatResponse::new(MessageContent::Text(String::new())));
+        // Providers that only deliver the final response never sent deltas.
+        if text.is_empty() && tool_args.is_empty() {
+            let body = response_object_text(&response);
+            for element in text.push_str(&body) {
+                yield decode_element(element, &element_cfg);
+            }
+        }
+        let scanner = if tool_args.is_empty() { &text } else { &tool_args };
+        yield StreamElementEvent::Finish {
+            elements: scanner.elements_seen(),
+            complete: scanner.is_finished(),
+            response,
+        };
     };
-    Ok(Box::pin(s))
+    Box::pin(s)
 }
 
 // Extracting balanced JSON slices is handled by `crate::structured_output`.
@@ -632,6 +1003,7 @@ pub async fn stream_object_openai<T: DeserializeOwned + Send + 'static>(
     tools: Option<Vec<Tool>>,
     opts: StreamObjectOptions,
 ) -> Result<Pin<Box<dyn Stream<Item = Result<StreamObjectEvent<T>, LlmError>> + Send>>, LlmError> {
+    reject_self_correction(&opts, "stream_object_openai")?;
     // Build a ChatRequest with provider_options for structured output
     use siumai::provider_ext::openai::{OpenAiOptions, ResponsesApiConfig};
 
@@ -379,3 +379,505 @@ async fn stream_object_accepts_stable_text_parts() {
         })
     );
 }
+
+/// Returns queued responses in order and records every request's messages.
+#[derive(Clone, Default)]
+struct ScriptedModel {
+    responses: std::sync::Arc<std::sync::Mutex<Vec<&'static str>>>,
+    calls: std::sync::Arc<std::sync::Mutex<Vec<Vec<ChatMessage>>>>,
+    /// Deliver each response as the arguments of a `submit_object` tool call.
+    as_tool_call: bool,
+}
+
+impl ScriptedModel {
+    fn new(responses: Vec<&'static str>) -> Self {
+        Self {
+            responses: std::sync::Arc::new(std::sync::Mutex::new(responses)),
+            ..Default::default()
+        }
+    }
+
+    fn next(&self, messages: Vec<ChatMessage>) -> Result<&'static str, LlmError> {
+        self.calls.lock().unwrap().push(messages);
+        let mut responses = self.responses.lock().unwrap();
+        if responses.is_empty() {
+            return Err(LlmError::InternalError("no more scripted responses".into()));
+        }
+        Ok(responses.remove(0))
+    }
+
+    fn with_tool_calls(mut self) -> Self {
+        self.as_tool_call = true;
+        self
+    }
+
+    fn last_user_text(&self) -> String {
+        let calls = self.calls.lock().unwrap();
+        calls
+            .last()
+            .and_then(|messages| messages.last())
+            .and_then(|message| message.content_text())
+            .unwrap_or_default()
+            .to_string()
+    }
+}
+
+#[async_trait]
+impl ChatCapability for ScriptedModel {
+    async fn chat_with_tools(
+        &self,
+        messages: Vec<ChatMessage>,
+        _tools: Option<Vec<Tool>>,
+    ) -> Result<ChatResponse, LlmError> {
+        let text = self.next(messages)?;
+        let content = if self.as_tool_call {
+            MessageContent::MultiModal(vec![ContentPart::tool_call(
+                "call_1",
+                "submit_object",
+                serde_json::from_str(text).unwrap(),
+                None,
+            )])
+        } else {
+            MessageContent::Text(text.into())
+        };
+        let mut response = ChatResponse::new(content);
+        response.usage = Some(Usage::new(10, 5));
+        Ok(response)
+    }
+
+    async fn chat_stream(
+        &self,
+        messages: Vec<ChatMessage>,
+        _tools: Option<Vec<Tool>>,
+    ) -> Result<ChatStream, LlmError> {
+        let text = self.next(messages)?;
+        let as_tool_call = self.as_tool_call;
+        let s = async_stream::try_stream! {
+            if as_tool_call {
+                yield ChatStreamEvent::tool_call_part("call_1", "submit_object", text);
+            } else {
+                yield ChatStreamEvent::text_delta_part("0", text);
+            }
+            let mut response = ChatResponse::new(MessageContent::Text(String::new()));
+            response.usage = Some(Usage::new(10, 5));
+            yield ChatStreamEvent::StreamEnd { response };
+        };
+        Ok(Box::pin(s))
+    }
+}
+
+#[tokio::test]
+async fn generate_object_self_correction_reports_paths_and_sums_usage() {
+    let model = ScriptedModel::new(vec![
+        r#"{"name":"Ada","age":"thirty-six"}"#,
+        r#"{"name":"Ada","age":36}"#,
+    ]);
+    let (user, resp): (User, _) = generate_object(
+        &model,
+        vec![ChatMessage::user("give me user json").build()],
+        None,
+        GenerateObjectOptions {
+            self_correction: Some(SelfCorrection::new(2)),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("corrected object");
+
+    assert_eq!(user.age, 36);
+    assert_eq!(model.calls.lock().unwrap().len(), 2);
+    let prompt = model.last_user_text();
+    assert!(prompt.contains("/age: invalid type"), "{prompt}");
+    let usage = resp.usage.expect("usage");
+    assert_eq!(usage.prompt_tokens(), Some(20));
+    assert_eq!(usage.completion_tokens(), Some(10));
+}
+
+#[tokio::test]
+async fn generate_object_without_self_correction_fails_after_one_call() {
+    let model = ScriptedModel::new(vec![r#"{"name":"Ada"}"#, r#"{"name":"Ada","age":36}"#]);
+    let err = generate_object::<User>(&model, vec![], None, Default::default())
+        .await
+        .expect_err("missing field");
+
+    assert!(err.to_string().contains("missing field `age`"));
+    assert_eq!(model.calls.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn generate_object_self_correction_gives_up_after_max_rounds() {
+    let model = ScriptedModel::new(vec!["not json", "still not json", r#"{"a":1}"#]);
+    let err = generate_object::<serde_json::Value>(
+        &model,
+        vec![],
+        None,
+        GenerateObjectOptions {
+            self_correction: Some(SelfCorrection::new(1).with_instructions("Use double quotes.")),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect_err("rounds exhausted");
+
+    assert!(matches!(err, LlmError::ParseError(_)));
+    assert_eq!(model.calls.lock().unwrap().len(), 2);
+    let prompt = model.last_user_text();
+    assert!(prompt.contains("(root): invalid JSON"), "{prompt}");
+    assert!(prompt.ends_with("Use double quotes."));
+}
+
+#[cfg(feature = "schema")]
+#[tokio::test]
+async fn generate_object_self_correction_sends_schema_violations() {
+    let model = ScriptedModel::new(vec![
+        r#"{"name":"Ada","age":-1}"#,
+        r#"{"name":"Ada","age":36}"#,
+    ]);
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "name": {"type": "string"},
+            "age": {"type": "integer", "minimum": 0}
+        },
+        "required": ["name", "age"]
+    });
+    let (user, _): (User, _) = generate_object(
+        &model,
+        vec![],
+        None,
+        GenerateObjectOptions {
+            schema: Some(schema),
+            self_correction: Some(SelfCorrection::default()),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("corrected object");
+
+    assert_eq!(user.age, 36);
+    let prompt = model.last_user_text();
+    assert!(
+        prompt.contains("/age: -1 is less than the minimum of 0"),
+        "{prompt}"
+    );
+    assert!(prompt.contains("JSON Schema"));
+}
+
+#[tokio::test]
+async fn stream_object_owned_restreams_after_correction() {
+    use futures::StreamExt;
+
+    let model = ScriptedModel::new(vec![r#"{"name":"Ada"}"#, r#"{"name":"Ada","age":36}"#]);
+    let mut s = stream_object_owned::<User, _>(
+        model.clone(),
+        vec![ChatMessage::user("user").build()],
+        None,
+        StreamObjectOptions {
+            self_correction: Some(SelfCorrection::new(1)),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("stream");
+
+    let mut corrections = Vec::new();
+    let mut final_object = None;
+    while let Some(ev) = s.next().await {
+        match ev.expect("ok") {
+            StreamObjectEvent::Correction { round, issues } => corrections.push((round, issues)),
+            StreamObjectEvent::Final { object, response } => {
+                assert_eq!(response.usage.and_then(|u| u.total_tokens()), Some(30));
+                final_object = Some(object);
+            }
+            _ => {}
+        }
+    }
+
+    assert_eq!(corrections.len(), 1);
+    assert_eq!(corrections[0].0, 1);
+    assert!(corrections[0].1[0].message.contains("missing field `age`"));
+    assert_eq!(final_object.expect("final").age, 36);
+    let calls = model.calls.lock().unwrap();
+    assert_eq!(calls.len(), 2);
+    assert!(
+        calls[1]
+            .iter()
+            .any(|m| m.content_text() == Some(r#"{"name":"Ada"}"#))
+    );
+}
+
+/// The rejected tool call must be replayed and answered before the correction.
+fn assert_tool_call_replayed(messages: &[ChatMessage]) {
+    let replayed = messages
+        .iter()
+        .position(|m| {
+            m.role == MessageRole::Assistant
+                && matches!(
+                    m.tool_calls().first(),
+                    Some(ContentPart::ToolCall { tool_call_id, .. }) if tool_call_id == "call_1"
+                )
+        })
+        .expect("assistant tool call replayed");
+    let answer = &messages[replayed + 1];
+    assert_eq!(answer.role, MessageRole::Tool);
+    match answer.tool_results().first() {
+        Some(ContentPart::ToolResult {
+            tool_call_id,
+            output,
+            ..
+        }) => {
+            assert_eq!(tool_call_id, "call_1");
+            assert!(
+                format!("{output:?}").contains("missing field `age`"),
+                "{output:?}"
+            );
+        }
+        other => panic!("expected tool result, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn generate_object_tool_mode_answers_the_rejected_tool_call() {
+    let model = ScriptedModel::new(vec![r#"{"name":"Ada"}"#, r#"{"name":"Ada","age":36}"#])
+        .with_tool_calls();
+    let (user, _): (User, _) = generate_object(
+        &model,
+        vec![ChatMessage::user("user").build()],
+        None,
+        GenerateObjectOptions {
+            schema: Some(serde_json::json!({ "type": "object" })),
+            mode: GenerateMode::Tool,
+            self_correction: Some(SelfCorrection::new(1)),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("corrected object");
+
+    assert_eq!(user.age, 36);
+    assert_tool_call_replayed(&model.calls.lock().unwrap()[1]);
+}
+
+#[tokio::test]
+async fn stream_object_rejects_self_correction() {
+    let model = ScriptedModel::new(vec![r#"{"name":"Ada","age":36}"#]);
+    let err = stream_object::<User>(
+        &model,
+        vec![ChatMessage::user("user").build()],
+        None,
+        StreamObjectOptions {
+            self_correction: Some(SelfCorrection::new(1)),
+            ..Default::default()
+        },
+    )
+    .await
+    .err()
+    .expect("self_correction is rejected");
+
+    assert!(
+        matches!(&err, LlmError::InvalidParameter(msg) if msg.contains("stream_object_owned")),
+        "{err:?}"
+    );
+    assert!(model.calls.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn stream_object_owned_tool_mode_answers_the_rejected_tool_call() {
+    use futures::StreamExt;
+
+    let model = ScriptedModel::new(vec![r#"{"name":"Ada"}"#, r#"{"name":"Ada","age":36}"#])
+        .with_tool_calls();
+    let events: Vec<_> = stream_object_owned::<User, _>(
+        model.clone(),
+        vec![ChatMessage::user("user").build()],
+        None,
+        StreamObjectOptions {
+            schema: Some(serde_json::json!({ "type": "object" })),
+            mode: GenerateMode::Tool,
+            self_correction: Some(SelfCorrection::new(1)),
+            ..Default::default()
+        },
+    )
+    .await
+    .expect("stream")
+    .collect()
+    .await;
+
+    assert!(matches!(
+        events.last(),
+        Some(Ok(StreamObjectEvent::Final { object, .. })) if object.age == 36
+    ));
+    assert_tool_call_replayed(&model.calls.lock().unwrap()[1]);
+}
+
+async fn collect_elements(
+    model: &StreamOnlyModel,
+    opts: StreamObjectOptions,
+) -> Vec<StreamElementEvent<User>> {
+    use futures::StreamExt;
+    let stream = stream_object_elements::<User>(model, vec![], None, opts)
+        .await
+        .expect("stream");
+    stream.map(|ev| ev.expect("ok")).collect().await
+}
+
+#[tokio::test]
+async fn stream_object_elements_emits_each_element_once_and_skips_bad_ones() {
+    let model = StreamOnlyModel {
+        deltas: vec![
+            "```json\n[{\"name\":\"Ada\",\"age\":36}",
+            ", {\"name\":\"Bob\",\"age\":\"x\"}, {\"name\":",
+            "\"Cy\",\"age\":7}]\n```",
+        ],
+    };
+    let events = collect_elements(&model, Default::default()).await;
+
+    assert_eq!(events.len(), 4);
+    assert!(matches!(
+        &events[0],
+        StreamElementEvent::Element { index: 0, element } if element.name == "Ada"
+    ));
+    match &events[1] {
+        StreamElementEvent::ElementError { index, issues, .. } => {
+            assert_eq!(*index, 1);
+            assert_eq!(issues[0].path, "/age");
+        }
+        _ => panic!("expected element error"),
+    }
+    assert!(matches!(
+        &events[2],
+        StreamElementEvent::Element { index: 2, element } if element == &User { name: "Cy".into(), age: 7 }
+    ));
+    assert!(matches!(
+        &events[3],
+        StreamElementEvent::Finish {
+            elements: 3,
+            complete: true,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn stream_object_elements_reports_truncated_array() {
+    let model = StreamOnlyModel {
+        deltas: vec!["[{\"name\":\"Ada\",\"age\":36}, {\"name\":\"B"],
+    };
+    let events = collect_elements(&model, Default::default()).await;
+
+    assert_eq!(events.len(), 2);
+    assert!(matches!(
+        &events[1],
+        StreamElementEvent::Finish {
+            elements: 1,
+            complete: false,
+            ..
+        }
+    ));
+}
+
+/// Streams one complete element and then fails mid-stream.
+struct FailingStreamModel;
+
+#[async_trait]
+impl ChatCapability for FailingStreamModel {
+    async fn chat_with_tools(
+        &self,
+        _messages: Vec<ChatMessage>,
+        _tools: Option<Vec<Tool>>,
+    ) -> Result<ChatResponse, LlmError> {
+        Err(LlmError::UnsupportedOperation("non-stream".into()))
+    }
+
+    async fn chat_stream(
+        &self,
+        _messages: Vec<ChatMessage>,
+        _tools: Option<Vec<Tool>>,
+    ) -> Result<ChatStream, LlmError> {
+        let s = async_stream::try_stream! {
+            yield ChatStreamEvent::text_delta_part("0", "[{\"name\":\"Ada\",\"age\":36}, {");
+            yield ChatStreamEvent::Error {
+                error: "upstream reset".to_string(),
+            };
+            yield ChatStreamEvent::text_delta_part("0", "\"name\":\"Bob\",\"age\":1}]");
+        };
+        Ok(Box::pin(s))
+    }
+}
+
+#[tokio::test]
+async fn stream_object_elements_propagates_stream_errors() {
+    use futures::StreamExt;
+
+    let events: Vec<_> =
+        stream_object_elements::<User>(&FailingStreamModel, vec![], None, Default::default())
+            .await
+            .expect("stream")
+            .collect()
+            .await;
+
+    assert_eq!(events.len(), 2);
+    assert!(matches!(
+        &events[0],
+        Ok(StreamElementEvent::Element { index: 0, .. })
+    ));
+    assert!(
+        matches!(&events[1], Err(LlmError::StreamError(message)) if message == "upstream reset")
+    );
+}
+
+#[tokio::test]
+async fn stream_object_propagates_stream_errors() {
+    use futures::StreamExt;
+
+    let events: Vec<_> =
+        stream_object::<serde_json::Value>(&FailingStreamModel, vec![], None, Default::default())
+            .await
+            .expect("stream")
+            .collect()
+            .await;
+
+    assert!(matches!(
+        events.last(),
+        Some(Err(LlmError::StreamError(message))) if message == "upstream reset"
+    ));
+    assert!(
+        !events
+            .iter()
+            .any(|event| matches!(event, Ok(StreamObjectEvent::Final { .. })))
+    );
+}
+
+#[cfg(feature = "schema")]
+#[tokio::test]
+async fn stream_object_elements_validates_against_item_schema() {
+    let model = StreamOnlyModel {
+        deltas: vec![
+            "[{\"name\":\"Ada\",\"age\":36},",
+            "{\"name\":\"\",\"age\":1}]",
+        ],
+    };
+    let opts = StreamObjectOptions {
+        schema: Some(serde_json::json!({
+            "type": "array",
+            "items": {
+                "type": "object",
+                "properties": { "name": { "type": "string", "minLength": 1 } }
+            }
+        })),
+        ..Default::default()
+    };
+    let events = collect_elements(&model, opts).await;
+
+    assert!(matches!(
+        &events[0],
+        StreamElementEvent::Element { index: 0, .. }
+    ));
+    match &events[1] {
+        StreamElementEvent::ElementError { index, issues, .. } => {
+            assert_eq!(*index, 1);
+            assert_eq!(issues[0].path, "/name");
+        }
+        _ => panic!("expected element error"),
+    }
+}
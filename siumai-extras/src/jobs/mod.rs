@@ -0,0 +1,703 @@
+//! Durable tracking for long-running video generation tasks.
+//!
+//! `siumai::video::wait_for_task` polls in-process, so a restart loses every in-flight task.
+//! A [`JobTracker`] records each task (id, provider, model and the original request) in a
+//! pluggable [`JobStore`], polls it with per-provider [`BackoffPolicy`] delays, and once the
+//! provider reports success materializes the output (provider references through
+//! `materialize_video_reference`, URLs by download) into an [`OutputSink`].
+//!
+//! Stores: [`InMemoryJobStore`], [`FileJobStore`] (one JSON file per job) and, with the
+//! `jobs-sqlite` feature, `SqliteJobStore`. Providers that support completion callbacks
+//! (MiniMax `callback_url`, OpenAI video webhooks) can wake the tracker through
+//! [`JobTracker::handle_webhook`]; with the `server` feature,
+//! `server::axum::job_webhook_router` exposes it as an Axum route. Callbacks only trigger an
+//! immediate poll, so the stored state always comes from the provider's task query.
+//!
+//! The router does not verify OpenAI webhook signatures. A forged callback cannot change a
+//! job's state, but anyone who can reach the route can trigger polls, so expose it only behind
+//! your own authentication or network restrictions.
+//!
+//! Only video tasks are tracked. Music generation is out of scope: `MusicGenerationCapability`
+//! has no task API to submit and poll.
+//!
+//! [`JobTracker`]: crate::jobs::JobTracker
+//! [`JobStore`]: crate::jobs::JobStore
+//! [`BackoffPolicy`]: crate::jobs::BackoffPolicy
+//! [`OutputSink`]: crate::jobs::OutputSink
+//! [`InMemoryJobStore`]: crate::jobs::InMemoryJobStore
+//! [`FileJobStore`]: crate::jobs::FileJobStore
+//! [`JobTracker::handle_webhook`]: crate::jobs::JobTracker::handle_webhook
+//!
+//! ## Example
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use siumai_extras::jobs::*;
+//!
+//! let tracker = Arc::new(
+//!     JobTracker::new(Arc::new(FileJobStore::new("./jobs")))
+//!         .with_model(Arc::new(minimax_client))
+//!         .with_sink(Arc::new(DirectorySink::new("./videos")))
+//!         .with_provider_backoff("minimaxi", BackoffPolicy::new(Duration::from_secs(10), Duration::from_secs(60))),
+//! );
+//!
+//! // Jobs recorded before a restart become due again and are polled by `run`.
+//! tracker.resume().await?;
+//! tokio::spawn({ let tracker = tracker.clone(); async move { tracker.run().await } });
+//!
+//! let job = tracker.submit("minimaxi", VideoGenerationRequest::new("hailuo-2.3", "A sunset")).await?;
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use siumai::prelude::unified::LlmError;
+use siumai::retry_api::RetryOptions;
+use siumai::video::{
+    CreateTaskOptions, GeneratedVideo, GeneratedVideoData, MaterializeVideoOptions,
+    MaterializedVideoAsset, QueryTaskOptions, VideoGenerationRequest, VideoModel,
+    VideoTaskStatusResponse,
+};
+use tokio::sync::{Notify, OwnedMutexGuard};
+
+mod sink;
+#[cfg(feature = "jobs-sqlite")]
+mod sqlite;
+mod store;
+mod webhook;
+
+pub use sink::{DirectorySink, OutputSink};
+#[cfg(feature = "jobs-sqlite")]
+pub use sqlite::SqliteJobStore;
+pub use store::{FileJobStore, InMemoryJobStore, JobStore};
+pub use webhook::{WebhookOutcome, WebhookPayload, parse_webhook_payload};
+
+/// Idle wait between store scans when no job is scheduled.
+const IDLE_SCAN_INTERVAL: Duration = Duration::from_secs(30);
+/// Maximum number of jobs polled concurrently by one scan.
+const POLL_CONCURRENCY: usize = 8;
+/// Default number of failed materialization attempts before a job fails.
+const DEFAULT_MAX_MATERIALIZE_ATTEMPTS: u32 = 5;
+
+/// Exponential delay between polls of one job.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackoffPolicy {
+    /// Delay before the second poll.
+    pub initial: Duration,
+    /// Upper bound for the delay.
+    pub max: Duration,
+    /// Growth factor applied per poll.
+    pub multiplier: f64,
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_secs(2),
+            max: Duration::from_secs(60),
+            multiplier: 1.5,
+        }
+    }
+}
+
+impl BackoffPolicy {
+    /// Create a policy with the default 1.5x growth factor.
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self {
+            initial,
+            max,
+            multiplier: 1.5,
+        }
+    }
+
+    /// Set the growth factor.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Delay after `attempt` polls (the first delay is `initial`).
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(64) as i32;
+        let seconds = self.initial.as_secs_f64() * self.multiplier.max(1.0).powi(exponent);
+        Duration::from_secs_f64(seconds.min(self.max.as_secs_f64()))
+    }
+}
+
+/// Lifecycle of a tracked job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobState {
+    /// The provider is still working on the task.
+    Pending,
+    /// The provider finished; outputs are being written to the sink.
+    Materializing,
+    /// The task finished (and its outputs were written, when a sink is configured).
+    Succeeded,
+    /// The task failed or timed out.
+    Failed,
+}
+
+impl JobState {
+    /// Whether the tracker is done with the job.
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed)
+    }
+}
+
+/// One output written by an [`OutputSink`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobOutput {
+    /// Sink-specific location (file path, object key, ...).
+    pub location: String,
+    /// IANA media type of the output.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_type: Option<String>,
+    /// Output size in bytes.
+    pub size: u64,
+}
+
+/// Persisted state of one provider task.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRecord {
+    /// Tracker id (`provider:task_id`).
+    pub id: String,
+    /// Provider id used to pick the polling model.
+    pub provider: String,
+    /// Model id the task was submitted with.
+    pub model: String,
+    /// Provider task id.
+    pub task_id: String,
+    /// The original `VideoGenerationRequest`, serialized.
+    pub request: serde_json::Value,
+    /// Current lifecycle state.
+    pub state: JobState,
+    /// Latest task status returned by the provider.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_status: Option<VideoTaskStatusResponse>,
+    /// Number of polls since the job was recorded (or last resumed).
+    pub attempts: u32,
+    /// Number of failed attempts to write the outputs to the sink.
+    #[serde(default)]
+    pub materialize_failures: u32,
+    /// When the job should be polled next.
+    pub next_poll_at: DateTime<Utc>,
+    /// When the job was recorded.
+    pub created_at: DateTime<Utc>,
+    /// Last state change.
+    pub updated_at: DateTime<Utc>,
+    /// Last polling/materialization error, or the failure reason.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Outputs written by the sink.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outputs: Vec<JobOutput>,
+}
+
+impl JobRecord {
+    /// Create a pending job that is due immediately.
+    pub fn new(
+        provider: impl Into<String>,
+        model: impl Into<String>,
+        task_id: impl Into<String>,
+        request: serde_json::Value,
+    ) -> Self {
+        let provider = provider.into();
+        let task_id = task_id.into();
+        let now = Utc::now();
+        Self {
+            id: job_id(&provider, &task_id),
+            provider,
+            model: model.into(),
+            task_id,
+            request,
+            state: JobState::Pending,
+            last_status: None,
+            attempts: 0,
+            materialize_failures: 0,
+            next_poll_at: now,
+            created_at: now,
+            updated_at: now,
+            error: None,
+            outputs: Vec::new(),
+        }
+    }
+
+    /// Deserialize the original request.
+    pub fn video_request(&self) -> Result<VideoGenerationRequest, LlmError> {
+        serde_json::from_value(self.request.clone())
+            .map_err(|e| LlmError::JsonError(format!("invalid stored video request: {e}")))
+    }
+}
+
+/// Tracker id for a provider task.
+pub fn job_id(provider: &str, task_id: &str) -> String {
+    format!("{provider}:{task_id}")
+}
+
+/// Polls recorded video tasks to completion and materializes their outputs.
+pub struct JobTracker {
+    store: Arc<dyn JobStore>,
+    sink: Option<Arc<dyn OutputSink>>,
+    models: HashMap<String, Arc<dyn VideoModel>>,
+    default_backoff: BackoffPolicy,
+    provider_backoff: HashMap<String, BackoffPolicy>,
+    job_timeout: Option<Duration>,
+    max_materialize_attempts: u32,
+    query_retry: Option<RetryOptions>,
+    materialize: MaterializeVideoOptions,
+    wake: Notify,
+    job_locks: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    last_run_error: Mutex<Option<String>>,
+}
+
+impl JobTracker {
+    /// Create a tracker backed by `store`.
+    pub fn new(store: Arc<dyn JobStore>) -> Self {
+        Self {
+            store,
+            sink: None,
+            models: HashMap::new(),
+            default_backoff: BackoffPolicy::default(),
+            provider_backoff: HashMap::new(),
+            job_timeout: None,
+            max_materialize_attempts: DEFAULT_MAX_MATERIALIZE_ATTEMPTS,
+            query_retry: None,
+            materialize: MaterializeVideoOptions::default(),
+            wake: Notify::new(),
+            job_locks: Mutex::new(HashMap::new()),
+            last_run_error: Mutex::new(None),
+        }
+    }
+
+    /// Register the model used to create, poll and materialize tasks of its provider.
+    pub fn with_model(mut self, model: Arc<dyn VideoModel>) -> Self {
+        self.models.insert(model.provider_id().to_string(), model);
+        self
+    }
+
+    /// Register a model under an explicit provider id.
+    pub fn with_provider_model(
+        mut self,
+        provider: impl Into<String>,
+        model: Arc<dyn VideoModel>,
+    ) -> Self {
+        self.models.insert(provider.into(), model);
+        self
+    }
+
+    /// Write finished outputs to `sink`.
+    pub fn with_sink(mut self, sink: Arc<dyn OutputSink>) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    /// Set the backoff used for providers without a specific policy.
+    pub fn with_backoff(mut self, policy: BackoffPolicy) -> Self {
+        self.default_backoff = policy;
+        self
+    }
+
+    /// Set the backoff for one provider.
+    pub fn with_provider_backoff(
+        mut self,
+        provider: impl Into<String>,
+        policy: BackoffPolicy,
+    ) -> Self {
+        self.provider_backoff.insert(provider.into(), policy);
+        self
+    }
+
+    /// Fail jobs that are still unfinished (pending or materializing) this long after they were
+    /// recorded.
+    pub fn with_job_timeout(mut self, timeout: Duration) -> Self {
+        self.job_timeout = Some(timeout);
+        self
+    }
+
+    /// Fail jobs whose outputs could not be written after `attempts` tries (default 5).
+    pub fn with_max_materialize_attempts(mut self, attempts: u32) -> Self {
+        self.max_materialize_attempts = attempts.max(1);
+        self
+    }
+
+    /// Retry policy applied around each task query.
+    pub fn with_query_retry(mut self, retry: RetryOptions) -> Self {
+        self.query_retry = Some(retry);
+        self
+    }
+
+    /// HTTP options used when downloading URL-backed outputs.
+    pub fn with_materialize_options(mut self, options: MaterializeVideoOptions) -> Self {
+        self.materialize = options;
+        self
+    }
+
+    /// The underlying store.
+    pub fn store(&self) -> &Arc<dyn JobStore> {
+        &self.store
+    }
+
+    /// The last store or polling error seen by [`JobTracker::run`], if any.
+    pub fn last_run_error(&self) -> Option<String> {
+        self.last_run_error.lock().expect("run error lock").clone()
+    }
+
+    /// Serialize updates of one job within this tracker, so a poll, `resume` and `poll_soon`
+    /// never overwrite each other's changes.
+    async fn lock_job(&self, id: &str) -> OwnedMutexGuard<()> {
+        let lock = self
+            .job_locks
+            .lock()
+            .expect("job lock map")
+            .entry(id.to_string())
+            .or_default()
+            .clone();
+        lock.lock_owned().await
+    }
+
+    /// Drop the lock entry of a finished job; finished jobs are never updated again.
+    fn forget_job_lock(&self, id: &str) {
+        self.job_locks.lock().expect("job lock map").remove(id);
+    }
+
+    fn model(&self, provider: &str) -> Result<&Arc<dyn VideoModel>, LlmError> {
+        self.models.get(provider).ok_or_else(|| {
+            LlmError::ConfigurationError(format!(
+                "no video model registered with the job tracker for provider '{provider}'"
+            ))
+        })
+    }
+
+    fn backoff(&self, provider: &str) -> BackoffPolicy {
+        self.provider_backoff
+            .get(provider)
+            .copied()
+            .unwrap_or(self.default_backoff)
+    }
+
+    /// Submit a task through the provider's model and start tracking it.
+    pub async fn submit(
+        &self,
+        provider: &str,
+        request: VideoGenerationRequest,
+    ) -> Result<JobRecord, LlmError> {
+        let model = self.model(provider)?;
+        let stored = serde_json::to_value(&request)
+            .map_err(|e| LlmError::JsonError(format!("failed to serialize video request: {e}")))?;
+        let model_id = request.model.clone();
+        let response =
+            siumai::video::create_task(model.as_ref(), request, CreateTaskOptions::default())
+                .await?;
+        self.record(JobRecord::new(provider, model_id, response.task_id, stored))
+            .await
+    }
+
+    /// Track a task that was created elsewhere.
+    pub async fn track(
+        &self,
+        provider: &str,
+        task_id: &str,
+        request: &VideoGenerationRequest,
+    ) -> Result<JobRecord, LlmError> {
+        let stored = serde_json::to_value(request)
+            .map_err(|e| LlmError::JsonError(format!("failed to serialize video request: {e}")))?;
+        self.record(JobRecord::new(
+            provider,
+            request.model.clone(),
+            task_id,
+            stored,
+        ))
+        .await
+    }
+
+    async fn record(&self, job: JobRecord) -> Result<JobRecord, LlmError> {
+        self.store.save(&job).await?;
+        self.wake.notify_one();
+        Ok(job)
+    }
+
+    /// Look up a job by tracker id.
+    pub async fn get(&self, id: &str) -> Result<Option<JobRecord>, LlmError> {
+        self.store.load(id).await
+    }
+
+    /// Make every unfinished job due now; call once on startup.
+    ///
+    /// Backoff restarts from the provider's initial delay. Returns the number of resumed jobs.
+    pub async fn resume(&self) -> Result<usize, LlmError> {
+        let now = Utc::now();
+        let mut resumed = 0;
+        for listed in self.store.list_unfinished().await? {
+            let _guard = self.lock_job(&listed.id).await;
+            let Some(mut job) = self.store.load(&listed.id).await? else {
+                continue;
+            };
+            if job.state.is_finished() {
+                continue;
+            }
+            job.attempts = 0;
+            job.next_poll_at = now;
+            self.store.save(&job).await?;
+            resumed += 1;
+        }
+        self.wake.notify_one();
+        Ok(resumed)
+    }
+
+    /// Mark a job due now and wake the polling loop. Returns `false` for unknown jobs.
+    ///
+    /// Only `next_poll_at` changes; if the job is being polled, this waits for that poll to be
+    /// saved first.
+    pub async fn poll_soon(&self, id: &str) -> Result<bool, LlmError> {
+        let _guard = self.lock_job(id).await;
+        let Some(mut job) = self.store.load(id).await? else {
+            return Ok(false);
+        };
+        if !job.state.is_finished() {
+            job.next_poll_at = Utc::now();
+            self.store.save(&job).await?;
+            self.wake.notify_one();
+        }
+        Ok(true)
+    }
+
+    /// Handle a provider completion callback.
+    ///
+    /// Verification challenges are echoed back; task notifications make the job due now.
+    pub async fn handle_webhook(
+        &self,
+        provider: &str,
+        payload: &serde_json::Value,
+    ) -> Result<WebhookOutcome, LlmError> {
+        match parse_webhook_payload(provider, payload) {
+            WebhookPayload::Challenge(challenge) => Ok(WebhookOutcome::Challenge(challenge)),
+            WebhookPayload::Task(task_id) => {
+                let matched = self.poll_soon(&job_id(provider, &task_id)).await?;
+                Ok(WebhookOutcome::Accepted { task_id, matched })
+            }
+            WebhookPayload::Unrecognized => Ok(WebhookOutcome::Unrecognized),
+        }
+    }
+
+    /// Poll every due job once and return the updated records.
+    pub async fn poll_due(&self) -> Result<Vec<JobRecord>, LlmError> {
+        let now = Utc::now();
+        let due: Vec<JobRecord> = self
+            .store
+            .list_unfinished()
+            .await?
+            .into_iter()
+            .filter(|job| job.next_poll_at <= now)
+            .collect();
+
+        futures::stream::iter(due)
+            .map(|job| self.advance(job))
+            .buffer_unordered(POLL_CONCURRENCY)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Poll due jobs until the future is dropped; sleeps until the next job is due or a
+    /// submission/webhook wakes it.
+    ///
+    /// Store and polling errors do not stop the loop: they are kept in
+    /// [`JobTracker::last_run_error`] and the scan is retried after the default backoff's
+    /// initial delay.
+    pub async fn run(&self) {
+        loop {
+            let wait = match self.scan().await {
+                Ok(wait) => wait,
+                Err(error) => {
+                    #[cfg(feature = "telemetry")]
+                    tracing::warn!(error = %error, "job tracker scan failed");
+                    *self.last_run_error.lock().expect("run error lock") = Some(error.to_string());
+                    self.default_backoff.initial.min(IDLE_SCAN_INTERVAL)
+                }
+            };
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = self.wake.notified() => {}
+            }
+        }
+    }
+
+    /// Poll due jobs and return how long to wait before the next one is due.
+    async fn scan(&self) -> Result<Duration, LlmError> {
+        self.poll_due().await?;
+        let next_due = self
+            .store
+            .list_unfinished()
+            .await?
+            .into_iter()
+            .map(|job| job.next_poll_at)
+            .min();
+        Ok(next_due
+            .map(|at| (at - Utc::now()).to_std().unwrap_or(Duration::ZERO))
+            .unwrap_or(IDLE_SCAN_INTERVAL)
+            .min(IDLE_SCAN_INTERVAL))
+    }
+
+    /// Run one polling or materialization step for `job` and persist the result.
+    async fn advance(&self, listed: JobRecord) -> Result<JobRecord, LlmError> {
+        let _guard = self.lock_job(&listed.id).await;
+        let now = Utc::now();
+        // Re-read under the lock: another scan, `resume` or `poll_soon` may have saved it since
+        // it was listed.
+        let mut job = match self.store.load(&listed.id).await? {
+            Some(job) if !job.state.is_finished() && job.next_poll_at <= now => job,
+            Some(job) => return Ok(job),
+            None => return Ok(listed),
+        };
+        let backoff = self.backoff(&job.provider);
+        let model = match self.model(&job.provider) {
+            Ok(model) => model,
+            Err(error) => {
+                // The model may be registered by a later process; keep the job.
+                job.error = Some(error.to_string());
+                job.attempts += 1;
+                job.next_poll_at = now + backoff.delay(job.attempts);
+                self.store.save(&job).await?;
+                return Ok(job);
+            }
+        };
+
+        if job.state == JobState::Pending {
+            let query = siumai::video::query_task(
+                model.as_ref(),
+                &job.task_id,
+                QueryTaskOptions {
+                    retry: self.query_retry.clone(),
+                    request_options: None,
+                },
+            )
+            .await;
+            job.attempts += 1;
+            match query {
+                Ok(status) if status.is_success() => {
+                    job.error = None;
+                    job.state = if self.sink.is_some() {
+                        JobState::Materializing
+                    } else {
+                        JobState::Succeeded
+                    };
+                    job.last_status = Some(status);
+                    job.updated_at = now;
+                }
+                Ok(status) if status.is_failed() => {
+                    job.error = Some(failure_reason(&job.task_id, &status));
+                    job.state = JobState::Failed;
+                    job.last_status = Some(status);
+                    job.updated_at = now;
+                }
+                Ok(status) => {
+                    job.error = None;
+                    job.last_status = Some(status);
+                }
+                Err(error) => job.error = Some(error.to_string()),
+            }
+        }
+
+        if job.state == JobState::Materializing
+            && let Some(sink) = &self.sink
+        {
+            match self.materialize(model.as_ref(), sink.as_ref(), &job).await {
+                Ok(outputs) => {
+                    job.outputs = outputs;
+                    job.error = None;
+                    job.state = JobState::Succeeded;
+                    job.updated_at = now;
+                }
+                Err(error) => {
+                    job.attempts += 1;
+                    job.materialize_failures += 1;
+                    if job.materialize_failures >= self.max_materialize_attempts {
+                        job.state = JobState::Failed;
+                        job.error = Some(format!(
+                            "failed to write the outputs of video task '{}' after {} attempts: {error}",
+                            job.task_id, job.materialize_failures
+                        ));
+                        job.updated_at = now;
+                    } else {
+                        job.error = Some(error.to_string());
+                    }
+                }
+            }
+        }
+
+        if !job.state.is_finished()
+            && let Some(timeout) = self.job_timeout
+            && (now - job.created_at).to_std().unwrap_or_default() >= timeout
+        {
+            job.state = JobState::Failed;
+            job.error = Some(format!(
+                "video task '{}' did not finish within {} s",
+                job.task_id,
+                timeout.as_secs()
+            ));
+            job.updated_at = now;
+        }
+
+        job.next_poll_at = now + backoff.delay(job.attempts.max(1));
+        self.store.save(&job).await?;
+        if job.state.is_finished() {
+            self.forget_job_lock(&job.id);
+        }
+        Ok(job)
+    }
+
+    async fn materialize(
+        &self,
+        model: &dyn VideoModel,
+        sink: &dyn OutputSink,
+        job: &JobRecord,
+    ) -> Result<Vec<JobOutput>, LlmError> {
+        let status = job.last_status.as_ref().ok_or_else(|| {
+            LlmError::InternalError(format!("job '{}' has no final task status", job.id))
+        })?;
+
+        let asset = if let Some(url) = &status.video_url {
+            let video = GeneratedVideo {
+                task_id: job.task_id.clone(),
+                media_type: "video/mp4".to_string(),
+                data: GeneratedVideoData::Url { url: url.clone() },
+                metadata: HashMap::new(),
+            };
+            let materialized = video.materialize(self.materialize.clone()).await?;
+            MaterializedVideoAsset {
+                bytes: materialized.bytes()?,
+                media_type: Some(materialized.media_type),
+            }
+        } else if let Some(reference) = status.effective_provider_reference(&job.provider) {
+            model.materialize_video_reference(&reference).await?
+        } else {
+            return Err(LlmError::ProcessingError(format!(
+                "video task '{}' succeeded without a video URL or provider reference",
+                job.task_id
+            )));
+        };
+
+        Ok(vec![sink.write(job, 0, asset).await?])
+    }
+}
+
+fn failure_reason(task_id: &str, status: &VideoTaskStatusResponse) -> String {
+    match status
+        .base_resp
+        .as_ref()
+        .map(|base| base.status_msg.as_str())
+        .filter(|message| !message.is_empty())
+    {
+        Some(message) => format!("video task '{task_id}' failed: {message}"),
+        None => format!("video task '{task_id}' failed"),
+    }
+}
+
+#[cfg(test)]
+mod tests;
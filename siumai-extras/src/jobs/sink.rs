@@ -0,0 +1,76 @@
+//! Destinations for materialized job outputs.
+
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use siumai::prelude::unified::LlmError;
+use siumai::video::MaterializedVideoAsset;
+
+use super::store::file_stem;
+use super::{JobOutput, JobRecord};
+
+/// Destination for the outputs of finished jobs.
+#[async_trait]
+pub trait OutputSink: Send + Sync {
+    /// Persist output `index` of `job` and describe where it went.
+    async fn write(
+        &self,
+        job: &JobRecord,
+        index: usize,
+        asset: MaterializedVideoAsset,
+    ) -> Result<JobOutput, LlmError>;
+}
+
+/// Writes outputs as `<stem>-<index>.<ext>` files into a directory.
+///
+/// `<stem>` is the job id with bytes outside `[a-z0-9._-]` percent-encoded, the same mapping
+/// [`FileJobStore`](super::FileJobStore) uses; output 0 of job `minimaxi:task` is written to
+/// `minimaxi%3Atask-0.mp4`.
+#[derive(Debug, Clone)]
+pub struct DirectorySink {
+    dir: PathBuf,
+}
+
+impl DirectorySink {
+    /// Write outputs under `dir` (created on first write).
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+fn extension_for(media_type: Option<&str>) -> &'static str {
+    match media_type {
+        Some("video/webm") => "webm",
+        Some("video/quicktime") => "mov",
+        Some("audio/mpeg") => "mp3",
+        Some("audio/wav") | Some("audio/x-wav") => "wav",
+        _ => "mp4",
+    }
+}
+
+#[async_trait]
+impl OutputSink for DirectorySink {
+    async fn write(
+        &self,
+        job: &JobRecord,
+        index: usize,
+        asset: MaterializedVideoAsset,
+    ) -> Result<JobOutput, LlmError> {
+        tokio::fs::create_dir_all(&self.dir).await.map_err(|e| {
+            LlmError::IoError(format!("failed to create {}: {e}", self.dir.display()))
+        })?;
+        let path = self.dir.join(format!(
+            "{}-{index}.{}",
+            file_stem(&job.id),
+            extension_for(asset.media_type.as_deref())
+        ));
+        tokio::fs::write(&path, &asset.bytes)
+            .await
+            .map_err(|e| LlmError::IoError(format!("failed to write {}: {e}", path.display())))?;
+        Ok(JobOutput {
+            location: path.display().to_string(),
+            media_type: asset.media_type,
+            size: asset.bytes.len() as u64,
+        })
+    }
+}
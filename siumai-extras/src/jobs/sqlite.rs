@@ -0,0 +1,155 @@
+//! SQLite-backed job store (`jobs-sqlite` feature).
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use rusqlite::{Connection, OptionalExtension, params};
+use siumai::prelude::unified::LlmError;
+
+use super::{JobRecord, JobState, JobStore};
+
+const SCHEMA: &str = "CREATE TABLE IF NOT EXISTS jobs (
+    id TEXT PRIMARY KEY,
+    provider TEXT NOT NULL,
+    task_id TEXT NOT NULL,
+    state TEXT NOT NULL,
+    record TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+)";
+
+/// Job store keeping one row per job in a SQLite database.
+#[derive(Clone)]
+pub struct SqliteJobStore {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl std::fmt::Debug for SqliteJobStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqliteJobStore").finish_non_exhaustive()
+    }
+}
+
+fn sqlite_error(error: rusqlite::Error) -> LlmError {
+    LlmError::IoError(format!("job store sqlite error: {error}"))
+}
+
+fn state_name(state: JobState) -> &'static str {
+    match state {
+        JobState::Pending => "pending",
+        JobState::Materializing => "materializing",
+        JobState::Succeeded => "succeeded",
+        JobState::Failed => "failed",
+    }
+}
+
+fn decode(record: String) -> Result<JobRecord, LlmError> {
+    serde_json::from_str(&record)
+        .map_err(|e| LlmError::JsonError(format!("invalid stored job record: {e}")))
+}
+
+impl SqliteJobStore {
+    /// Open (or create) the database at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, LlmError> {
+        Self::from_connection(Connection::open(path).map_err(sqlite_error)?)
+    }
+
+    /// Open a private in-memory database.
+    pub fn in_memory() -> Result<Self, LlmError> {
+        Self::from_connection(Connection::open_in_memory().map_err(sqlite_error)?)
+    }
+
+    fn from_connection(conn: Connection) -> Result<Self, LlmError> {
+        conn.execute(SCHEMA, []).map_err(sqlite_error)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    async fn with_conn<T, F>(&self, f: F) -> Result<T, LlmError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Connection) -> Result<T, LlmError> + Send + 'static,
+    {
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || f(&conn.lock().expect("sqlite job store lock")))
+            .await
+            .map_err(|e| LlmError::InternalError(format!("sqlite job store task failed: {e}")))?
+    }
+
+    async fn query(&self, sql: &'static str) -> Result<Vec<JobRecord>, LlmError> {
+        self.with_conn(move |conn| {
+            let mut stmt = conn.prepare(sql).map_err(sqlite_error)?;
+            let rows = stmt
+                .query_map([], |row| row.get::<_, String>(0))
+                .map_err(sqlite_error)?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(sqlite_error)?;
+            rows.into_iter().map(decode).collect()
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl JobStore for SqliteJobStore {
+    async fn save(&self, job: &JobRecord) -> Result<(), LlmError> {
+        let record = serde_json::to_string(job)
+            .map_err(|e| LlmError::JsonError(format!("failed to serialize job: {e}")))?;
+        let job = job.clone();
+        self.with_conn(move |conn| {
+            conn.execute(
+                "INSERT INTO jobs (id, provider, task_id, state, record, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                 ON CONFLICT(id) DO UPDATE SET
+                    state = excluded.state,
+                    record = excluded.record,
+                    updated_at = excluded.updated_at",
+                params![
+                    job.id,
+                    job.provider,
+                    job.task_id,
+                    state_name(job.state),
+                    record,
+                    job.updated_at.to_rfc3339(),
+                ],
+            )
+            .map(|_| ())
+            .map_err(sqlite_error)
+        })
+        .await
+    }
+
+    async fn load(&self, id: &str) -> Result<Option<JobRecord>, LlmError> {
+        let id = id.to_string();
+        self.with_conn(move |conn| {
+            conn.query_row("SELECT record FROM jobs WHERE id = ?1", [id], |row| {
+                row.get::<_, String>(0)
+            })
+            .optional()
+            .map_err(sqlite_error)?
+            .map(decode)
+            .transpose()
+        })
+        .await
+    }
+
+    async fn list(&self) -> Result<Vec<JobRecord>, LlmError> {
+        self.query("SELECT record FROM jobs ORDER BY id").await
+    }
+
+    async fn remove(&self, id: &str) -> Result<(), LlmError> {
+        let id = id.to_string();
+        self.with_conn(move |conn| {
+            conn.execute("DELETE FROM jobs WHERE id = ?1", [id])
+                .map(|_| ())
+                .map_err(sqlite_error)
+        })
+        .await
+    }
+
+    async fn list_unfinished(&self) -> Result<Vec<JobRecord>, LlmError> {
+        self.query("SELECT record FROM jobs WHERE state NOT IN ('succeeded', 'failed') ORDER BY id")
+            .await
+    }
+}
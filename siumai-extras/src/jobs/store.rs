@@ -0,0 +1,192 @@
+//! Job persistence backends.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use siumai::prelude::unified::LlmError;
+
+use super::JobRecord;
+
+/// Persistence backend for [`JobRecord`]s.
+#[async_trait]
+pub trait JobStore: Send + Sync {
+    /// Insert or replace a job.
+    async fn save(&self, job: &JobRecord) -> Result<(), LlmError>;
+
+    /// Load a job by tracker id.
+    async fn load(&self, id: &str) -> Result<Option<JobRecord>, LlmError>;
+
+    /// List every stored job.
+    async fn list(&self) -> Result<Vec<JobRecord>, LlmError>;
+
+    /// Delete a job; deleting an unknown id is not an error.
+    async fn remove(&self, id: &str) -> Result<(), LlmError>;
+
+    /// List jobs the tracker still has to poll or materialize.
+    async fn list_unfinished(&self) -> Result<Vec<JobRecord>, LlmError> {
+        Ok(self
+            .list()
+            .await?
+            .into_iter()
+            .filter(|job| !job.state.is_finished())
+            .collect())
+    }
+}
+
+/// Process-local store; jobs do not survive a restart.
+#[derive(Debug, Default)]
+pub struct InMemoryJobStore {
+    jobs: Mutex<BTreeMap<String, JobRecord>>,
+}
+
+impl InMemoryJobStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl JobStore for InMemoryJobStore {
+    async fn save(&self, job: &JobRecord) -> Result<(), LlmError> {
+        self.jobs
+            .lock()
+            .expect("job store lock")
+            .insert(job.id.clone(), job.clone());
+        Ok(())
+    }
+
+    async fn load(&self, id: &str) -> Result<Option<JobRecord>, LlmError> {
+        Ok(self.jobs.lock().expect("job store lock").get(id).cloned())
+    }
+
+    async fn list(&self) -> Result<Vec<JobRecord>, LlmError> {
+        Ok(self
+            .jobs
+            .lock()
+            .expect("job store lock")
+            .values()
+            .cloned()
+            .collect())
+    }
+
+    async fn remove(&self, id: &str) -> Result<(), LlmError> {
+        self.jobs.lock().expect("job store lock").remove(id);
+        Ok(())
+    }
+}
+
+/// Directory-backed store writing one JSON file per job.
+///
+/// Files are written to a temporary name and renamed, so a crash never leaves a truncated record.
+#[derive(Debug, Clone)]
+pub struct FileJobStore {
+    dir: PathBuf,
+}
+
+impl FileJobStore {
+    /// Store jobs under `dir` (created on first write).
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The backing directory.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path_for(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", file_stem(id)))
+    }
+}
+
+/// Map a tracker id to a portable file stem.
+///
+/// Bytes outside `[a-z0-9._-]` are percent-encoded (`A` becomes `%41`), so the mapping is
+/// reversible and distinct ids never share a file, even on case-insensitive file systems.
+pub(super) fn file_stem(id: &str) -> String {
+    let mut stem = String::with_capacity(id.len());
+    for byte in id.bytes() {
+        if byte.is_ascii_lowercase() || byte.is_ascii_digit() || matches!(byte, b'-' | b'_' | b'.')
+        {
+            stem.push(char::from(byte));
+        } else {
+            stem.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    stem
+}
+
+fn io_error(action: &str, path: &Path, error: std::io::Error) -> LlmError {
+    LlmError::IoError(format!("failed to {action} {}: {error}", path.display()))
+}
+
+#[async_trait]
+impl JobStore for FileJobStore {
+    async fn save(&self, job: &JobRecord) -> Result<(), LlmError> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| io_error("create", &self.dir, e))?;
+        let path = self.path_for(&job.id);
+        let tmp = path.with_extension("json.tmp");
+        let body = serde_json::to_vec_pretty(job)
+            .map_err(|e| LlmError::JsonError(format!("failed to serialize job: {e}")))?;
+        tokio::fs::write(&tmp, body)
+            .await
+            .map_err(|e| io_error("write", &tmp, e))?;
+        tokio::fs::rename(&tmp, &path)
+            .await
+            .map_err(|e| io_error("rename", &tmp, e))
+    }
+
+    async fn load(&self, id: &str) -> Result<Option<JobRecord>, LlmError> {
+        let path = self.path_for(id);
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes).map(Some).map_err(|e| {
+                LlmError::JsonError(format!("invalid job file {}: {e}", path.display()))
+            }),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(error) => Err(io_error("read", &path, error)),
+        }
+    }
+
+    async fn list(&self) -> Result<Vec<JobRecord>, LlmError> {
+        let mut entries = match tokio::fs::read_dir(&self.dir).await {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(io_error("read", &self.dir, error)),
+        };
+
+        let mut jobs = Vec::new();
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| io_error("read", &self.dir, e))?
+        {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let bytes = tokio::fs::read(&path)
+                .await
+                .map_err(|e| io_error("read", &path, e))?;
+            let job: JobRecord = serde_json::from_slice(&bytes).map_err(|e| {
+                LlmError::JsonError(format!("invalid job file {}: {e}", path.display()))
+            })?;
+            jobs.push(job);
+        }
+        jobs.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(jobs)
+    }
+
+    async fn remove(&self, id: &str) -> Result<(), LlmError> {
+        let path = self.path_for(id);
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(error) => Err(io_error("remove", &path, error)),
+        }
+    }
+}
@@ -0,0 +1,537 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use async_trait::async_trait;
+
+use serde_json::json;
+use siumai::prelude::unified::{ModelMetadata, ProviderReference};
+use siumai::video::{VideoGenerationResponse, VideoTaskStatus};
+
+use super::*;
+
+/// Returns scripted task statuses in order; the last one repeats.
+struct ScriptedVideoModel {
+    statuses: Mutex<VecDeque<VideoTaskStatusResponse>>,
+    queries: Mutex<u32>,
+    /// When set, each query waits for a permit after being counted.
+    gate: Option<Arc<tokio::sync::Semaphore>>,
+}
+
+impl ScriptedVideoModel {
+    fn new(statuses: Vec<VideoTaskStatusResponse>) -> Self {
+        Self {
+            statuses: Mutex::new(statuses.into()),
+            queries: Mutex::new(0),
+            gate: None,
+        }
+    }
+
+    fn with_gate(mut self, gate: Arc<tokio::sync::Semaphore>) -> Self {
+        self.gate = Some(gate);
+        self
+    }
+
+    fn queries(&self) -> u32 {
+        *self.queries.lock().unwrap()
+    }
+}
+
+impl ModelMetadata for ScriptedVideoModel {
+    fn provider_id(&self) -> &str {
+        "fake-video"
+    }
+
+    fn model_id(&self) -> &str {
+        "fake-video-model"
+    }
+}
+
+#[async_trait::async_trait]
+impl VideoModel for ScriptedVideoModel {
+    async fn create_task(
+        &self,
+        request: VideoGenerationRequest,
+    ) -> Result<VideoGenerationResponse, LlmError> {
+        Ok(VideoGenerationResponse {
+            task_id: format!("task-for-{}", request.model),
+            base_resp: None,
+            metadata: HashMap::new(),
+            warnings: None,
+            response: None,
+        })
+    }
+
+    async fn query_task(&self, task_id: &str) -> Result<VideoTaskStatusResponse, LlmError> {
+        *self.queries.lock().unwrap() += 1;
+        if let Some(gate) = &self.gate {
+            gate.acquire().await.unwrap().forget();
+        }
+        let mut statuses = self.statuses.lock().unwrap();
+        let mut status = if statuses.len() > 1 {
+            statuses.pop_front().unwrap()
+        } else {
+            statuses.front().cloned().unwrap()
+        };
+        status.task_id = task_id.to_string();
+        Ok(status)
+    }
+
+    async fn materialize_video_reference(
+        &self,
+        provider_reference: &ProviderReference,
+    ) -> Result<MaterializedVideoAsset, LlmError> {
+        assert_eq!(provider_reference.get("fake-video"), Some("file-42"));
+        Ok(MaterializedVideoAsset::new(vec![1, 2, 3, 4]).with_media_type("video/webm"))
+    }
+}
+
+fn status(status: VideoTaskStatus) -> VideoTaskStatusResponse {
+    VideoTaskStatusResponse {
+        task_id: String::new(),
+        status,
+        file_id: None,
+        video_url: None,
+        provider_reference: None,
+        duration: None,
+        video_width: None,
+        video_height: None,
+        base_resp: None,
+        metadata: HashMap::new(),
+        response: None,
+    }
+}
+
+fn succeeded() -> VideoTaskStatusResponse {
+    VideoTaskStatusResponse {
+        file_id: Some("file-42".to_string()),
+        ..status(VideoTaskStatus::Success)
+    }
+}
+
+fn request() -> VideoGenerationRequest {
+    VideoGenerationRequest::new("fake-video-model", "A lighthouse at dusk")
+}
+
+/// In-memory store whose first `list_unfinished` call fails.
+#[derive(Default)]
+struct FlakyStore {
+    inner: InMemoryJobStore,
+    failed: AtomicBool,
+}
+
+#[async_trait]
+impl JobStore for FlakyStore {
+    async fn save(&self, job: &JobRecord) -> Result<(), LlmError> {
+        self.inner.save(job).await
+    }
+
+    async fn load(&self, id: &str) -> Result<Option<JobRecord>, LlmError> {
+        self.inner.load(id).await
+    }
+
+    async fn list(&self) -> Result<Vec<JobRecord>, LlmError> {
+        self.inner.list().await
+    }
+
+    async fn remove(&self, id: &str) -> Result<(), LlmError> {
+        self.inner.remove(id).await
+    }
+
+    async fn list_unfinished(&self) -> Result<Vec<JobRecord>, LlmError> {
+        if !self.failed.swap(true, Ordering::SeqCst) {
+            return Err(LlmError::IoError("store unavailable".to_string()));
+        }
+        self.inner.list_unfinished().await
+    }
+}
+
+/// Make every stored job due so tests do not wait for backoff delays.
+async fn make_due(tracker: &JobTracker) {
+    for mut job in tracker.store().list().await.unwrap() {
+        job.next_poll_at = Utc::now();
+        tracker.store().save(&job).await.unwrap();
+    }
+}
+
+#[test]
+fn backoff_grows_geometrically_and_caps() {
+    let policy =
+        BackoffPolicy::new(Duration::from_secs(2), Duration::from_secs(10)).with_multiplier(2.0);
+    assert_eq!(policy.delay(0), Duration::from_secs(2));
+    assert_eq!(policy.delay(1), Duration::from_secs(2));
+    assert_eq!(policy.delay(2), Duration::from_secs(4));
+    assert_eq!(policy.delay(3), Duration::from_secs(8));
+    assert_eq!(policy.delay(4), Duration::from_secs(10));
+    assert_eq!(policy.delay(500), Duration::from_secs(10));
+}
+
+#[tokio::test]
+async fn tracker_polls_until_success_and_writes_outputs_to_sink() {
+    let jobs_dir = tempfile::tempdir().unwrap();
+    let out_dir = tempfile::tempdir().unwrap();
+    let model = Arc::new(ScriptedVideoModel::new(vec![
+        status(VideoTaskStatus::Queueing),
+        status(VideoTaskStatus::Processing),
+        succeeded(),
+    ]));
+    let tracker = JobTracker::new(Arc::new(FileJobStore::new(jobs_dir.path())))
+        .with_model(model.clone())
+        .with_sink(Arc::new(DirectorySink::new(out_dir.path())))
+        .with_provider_backoff(
+            "fake-video",
+            BackoffPolicy::new(Duration::from_secs(30), Duration::from_secs(60)),
+        );
+
+    let job = tracker.submit("fake-video", request()).await.unwrap();
+    assert_eq!(job.id, "fake-video:task-for-fake-video-model");
+    assert_eq!(job.model, "fake-video-model");
+    assert_eq!(
+        job.video_request().unwrap().prompt.as_deref(),
+        Some("A lighthouse at dusk")
+    );
+
+    let polled = tracker.poll_due().await.unwrap();
+    assert_eq!(polled[0].state, JobState::Pending);
+    assert!(polled[0].next_poll_at >= Utc::now() + chrono::Duration::seconds(29));
+    // Not due yet: the provider backoff applies.
+    assert!(tracker.poll_due().await.unwrap().is_empty());
+
+    make_due(&tracker).await;
+    tracker.poll_due().await.unwrap();
+    make_due(&tracker).await;
+    let finished = tracker.poll_due().await.unwrap().remove(0);
+
+    assert_eq!(model.queries(), 3);
+    assert_eq!(finished.state, JobState::Succeeded);
+    assert_eq!(finished.outputs.len(), 1);
+    let output = &finished.outputs[0];
+    assert_eq!(output.media_type.as_deref(), Some("video/webm"));
+    assert_eq!(output.size, 4);
+    assert!(
+        output
+            .location
+            .ends_with("fake-video%3Atask-for-fake-video-model-0.webm")
+    );
+    assert_eq!(std::fs::read(&output.location).unwrap(), vec![1, 2, 3, 4]);
+
+    let stored = tracker.get(&finished.id).await.unwrap().unwrap();
+    assert_eq!(stored.state, JobState::Succeeded);
+    assert!(tracker.store().list_unfinished().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn failed_task_records_provider_reason() {
+    let mut failed = status(VideoTaskStatus::Fail);
+    failed.base_resp = serde_json::from_value(json!({
+        "status_code": 1026,
+        "status_msg": "content moderation"
+    }))
+    .unwrap();
+    let tracker = JobTracker::new(Arc::new(InMemoryJobStore::new()))
+        .with_model(Arc::new(ScriptedVideoModel::new(vec![failed])));
+
+    tracker
+        .track("fake-video", "task-1", &request())
+        .await
+        .unwrap();
+    let job = tracker.poll_due().await.unwrap().remove(0);
+
+    assert_eq!(job.state, JobState::Failed);
+    assert_eq!(
+        job.error.as_deref(),
+        Some("video task 'task-1' failed: content moderation")
+    );
+}
+
+#[tokio::test]
+async fn pending_job_fails_after_timeout() {
+    let tracker = JobTracker::new(Arc::new(InMemoryJobStore::new()))
+        .with_model(Arc::new(ScriptedVideoModel::new(vec![status(
+            VideoTaskStatus::Processing,
+        )])))
+        .with_job_timeout(Duration::from_secs(60));
+
+    let mut job = JobRecord::new("fake-video", "fake-video-model", "slow", json!({}));
+    job.created_at = Utc::now() - chrono::Duration::minutes(5);
+    tracker.store().save(&job).await.unwrap();
+
+    let job = tracker.poll_due().await.unwrap().remove(0);
+    assert_eq!(job.state, JobState::Failed);
+    assert!(job.error.unwrap().contains("did not finish within 60 s"));
+}
+
+#[tokio::test]
+async fn materialization_failures_are_capped() {
+    let out_dir = tempfile::tempdir().unwrap();
+    // Success without a URL or provider reference can never be materialized.
+    let tracker = JobTracker::new(Arc::new(InMemoryJobStore::new()))
+        .with_model(Arc::new(ScriptedVideoModel::new(vec![status(
+            VideoTaskStatus::Success,
+        )])))
+        .with_sink(Arc::new(DirectorySink::new(out_dir.path())))
+        .with_max_materialize_attempts(2);
+    tracker
+        .track("fake-video", "task-1", &request())
+        .await
+        .unwrap();
+
+    let job = tracker.poll_due().await.unwrap().remove(0);
+    assert_eq!(job.state, JobState::Materializing);
+    assert_eq!(job.materialize_failures, 1);
+
+    make_due(&tracker).await;
+    let job = tracker.poll_due().await.unwrap().remove(0);
+    assert_eq!(job.state, JobState::Failed);
+    assert!(job.error.unwrap().contains("after 2 attempts"));
+}
+
+#[tokio::test]
+async fn job_timeout_covers_materialization() {
+    let out_dir = tempfile::tempdir().unwrap();
+    let tracker = JobTracker::new(Arc::new(InMemoryJobStore::new()))
+        .with_model(Arc::new(ScriptedVideoModel::new(vec![status(
+            VideoTaskStatus::Success,
+        )])))
+        .with_sink(Arc::new(DirectorySink::new(out_dir.path())))
+        .with_job_timeout(Duration::from_secs(60));
+
+    let mut job = JobRecord::new("fake-video", "fake-video-model", "task-1", json!({}));
+    job.state = JobState::Materializing;
+    job.last_status = Some(status(VideoTaskStatus::Success));
+    job.created_at = Utc::now() - chrono::Duration::minutes(5);
+    tracker.store().save(&job).await.unwrap();
+
+    let job = tracker.poll_due().await.unwrap().remove(0);
+    assert_eq!(job.state, JobState::Failed);
+    assert!(job.error.unwrap().contains("did not finish within 60 s"));
+}
+
+#[tokio::test]
+async fn poll_soon_during_a_poll_is_not_overwritten() {
+    let gate = Arc::new(tokio::sync::Semaphore::new(0));
+    let model = Arc::new(
+        ScriptedVideoModel::new(vec![status(VideoTaskStatus::Processing)]).with_gate(gate.clone()),
+    );
+    let tracker = Arc::new(
+        JobTracker::new(Arc::new(InMemoryJobStore::new()))
+            .with_model(model.clone())
+            .with_backoff(BackoffPolicy::new(
+                Duration::from_secs(600),
+                Duration::from_secs(600),
+            )),
+    );
+    let job = tracker
+        .track("fake-video", "task-1", &request())
+        .await
+        .unwrap();
+
+    let polling = tokio::spawn({
+        let tracker = tracker.clone();
+        async move { tracker.poll_due().await }
+    });
+    while model.queries() == 0 {
+        tokio::task::yield_now().await;
+    }
+    // The webhook arrives while the query is in flight.
+    let webhook = tokio::spawn({
+        let tracker = tracker.clone();
+        let id = job.id.clone();
+        async move { tracker.poll_soon(&id).await }
+    });
+    tokio::task::yield_now().await;
+    gate.add_permits(1);
+
+    let polled = polling.await.unwrap().unwrap().remove(0);
+    assert_eq!(polled.attempts, 1);
+    assert!(webhook.await.unwrap().unwrap());
+    let stored = tracker.get(&job.id).await.unwrap().unwrap();
+    assert_eq!(stored.attempts, 1);
+    assert!(stored.next_poll_at <= Utc::now());
+}
+
+#[tokio::test]
+async fn run_keeps_polling_after_store_errors() {
+    let tracker = Arc::new(
+        JobTracker::new(Arc::new(FlakyStore::default()))
+            .with_model(Arc::new(ScriptedVideoModel::new(vec![succeeded()])))
+            .with_backoff(BackoffPolicy::new(
+                Duration::from_millis(10),
+                Duration::from_millis(10),
+            )),
+    );
+    let job = tracker
+        .track("fake-video", "task-1", &request())
+        .await
+        .unwrap();
+
+    let running = tokio::spawn({
+        let tracker = tracker.clone();
+        async move { tracker.run().await }
+    });
+    let finished = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let stored = tracker.get(&job.id).await.unwrap().unwrap();
+            if stored.state.is_finished() {
+                break stored;
+            }
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+    })
+    .await
+    .expect("run recovers from the store error");
+    running.abort();
+
+    assert_eq!(finished.state, JobState::Succeeded);
+    assert!(
+        tracker
+            .last_run_error()
+            .unwrap()
+            .contains("store unavailable")
+    );
+}
+
+#[test]
+fn file_stems_are_reversible_and_distinct() {
+    use super::store::file_stem;
+
+    assert_eq!(file_stem("openai:video_1-a.b"), "openai%3Avideo_1-a.b");
+    assert_ne!(file_stem("a:b"), file_stem("a_b"));
+    assert_ne!(file_stem("Task"), file_stem("task"));
+    assert_ne!(file_stem("a%3Ab"), file_stem("a:b"));
+    assert_eq!(file_stem("a/b"), "a%2Fb");
+}
+
+#[tokio::test]
+async fn missing_model_keeps_job_pending() {
+    let tracker = JobTracker::new(Arc::new(InMemoryJobStore::new()));
+    tracker
+        .track("unknown", "task-1", &request())
+        .await
+        .unwrap();
+
+    let job = tracker.poll_due().await.unwrap().remove(0);
+    assert_eq!(job.state, JobState::Pending);
+    assert_eq!(job.attempts, 1);
+    assert!(job.error.unwrap().contains("'unknown'"));
+}
+
+#[tokio::test]
+async fn resume_makes_persisted_jobs_due_in_a_new_tracker() {
+    let jobs_dir = tempfile::tempdir().unwrap();
+    {
+        let tracker = JobTracker::new(Arc::new(FileJobStore::new(jobs_dir.path())));
+        let mut job = JobRecord::new("fake-video", "fake-video-model", "task-1", json!({}));
+        job.attempts = 7;
+        job.next_poll_at = Utc::now() + chrono::Duration::hours(1);
+        tracker.store().save(&job).await.unwrap();
+
+        let mut done = JobRecord::new("fake-video", "fake-video-model", "task-2", json!({}));
+        done.state = JobState::Succeeded;
+        tracker.store().save(&done).await.unwrap();
+    }
+
+    let model = Arc::new(ScriptedVideoModel::new(vec![succeeded()]));
+    let tracker =
+        JobTracker::new(Arc::new(FileJobStore::new(jobs_dir.path()))).with_model(model.clone());
+    assert_eq!(tracker.resume().await.unwrap(), 1);
+
+    let polled = tracker.poll_due().await.unwrap();
+    assert_eq!(polled.len(), 1);
+    assert_eq!(polled[0].task_id, "task-1");
+    assert_eq!(polled[0].attempts, 1);
+    // No sink configured: success finishes the job without materializing.
+    assert_eq!(polled[0].state, JobState::Succeeded);
+    assert_eq!(model.queries(), 1);
+}
+
+#[test]
+fn parse_webhook_payload_handles_provider_shapes() {
+    assert_eq!(
+        parse_webhook_payload("minimaxi", &json!({ "challenge": "abc" })),
+        WebhookPayload::Challenge("abc".to_string())
+    );
+    assert_eq!(
+        parse_webhook_payload(
+            "minimaxi",
+            &json!({ "task_id": "106916112212032", "status": "success" })
+        ),
+        WebhookPayload::Task("106916112212032".to_string())
+    );
+    assert_eq!(
+        parse_webhook_payload(
+            "openai",
+            &json!({ "id": "evt_1", "type": "video.completed", "data": { "id": "video_1" } })
+        ),
+        WebhookPayload::Task("video_1".to_string())
+    );
+    assert_eq!(
+        parse_webhook_payload("custom", &json!({ "data": { "taskId": 42 } })),
+        WebhookPayload::Task("42".to_string())
+    );
+    assert_eq!(
+        parse_webhook_payload("custom", &json!({ "status": "done" })),
+        WebhookPayload::Unrecognized
+    );
+}
+
+#[tokio::test]
+async fn webhook_makes_tracked_job_due() {
+    let tracker = JobTracker::new(Arc::new(InMemoryJobStore::new()));
+    let mut job = JobRecord::new("minimaxi", "hailuo", "task-9", json!({}));
+    job.next_poll_at = Utc::now() + chrono::Duration::hours(1);
+    tracker.store().save(&job).await.unwrap();
+
+    let outcome = tracker
+        .handle_webhook(
+            "minimaxi",
+            &json!({ "task_id": "task-9", "status": "success" }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        outcome,
+        WebhookOutcome::Accepted {
+            task_id: "task-9".to_string(),
+            matched: true
+        }
+    );
+    assert!(tracker.get(&job.id).await.unwrap().unwrap().next_poll_at <= Utc::now());
+
+    let outcome = tracker
+        .handle_webhook("minimaxi", &json!({ "task_id": "other" }))
+        .await
+        .unwrap();
+    assert_eq!(
+        outcome,
+        WebhookOutcome::Accepted {
+            task_id: "other".to_string(),
+            matched: false
+        }
+    );
+}
+
+#[cfg(feature = "jobs-sqlite")]
+#[tokio::test]
+async fn sqlite_store_round_trips_and_filters_unfinished() {
+    let store = SqliteJobStore::in_memory().unwrap();
+    let mut pending = JobRecord::new("fake-video", "m", "a", json!({ "model": "m" }));
+    store.save(&pending).await.unwrap();
+    let mut done = JobRecord::new("fake-video", "m", "b", json!({}));
+    done.state = JobState::Failed;
+    store.save(&done).await.unwrap();
+
+    pending.attempts = 3;
+    store.save(&pending).await.unwrap();
+
+    let loaded = store.load(&pending.id).await.unwrap().unwrap();
+    assert_eq!(loaded.attempts, 3);
+    assert_eq!(loaded.request, json!({ "model": "m" }));
+    assert_eq!(store.list().await.unwrap().len(), 2);
+    let unfinished = store.list_unfinished().await.unwrap();
+    assert_eq!(unfinished.len(), 1);
+    assert_eq!(unfinished[0].id, pending.id);
+
+    store.remove(&pending.id).await.unwrap();
+    assert!(store.load(&pending.id).await.unwrap().is_none());
+}
@@ -0,0 +1,61 @@
+//! Provider completion callbacks.
+//!
+//! Callback bodies are treated as hints: they identify the task to poll, never its final state.
+
+use serde_json::Value;
+
+/// What a callback body asks the tracker to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookPayload {
+    /// URL verification request whose challenge must be echoed back (MiniMax).
+    Challenge(String),
+    /// Status notification for a provider task.
+    Task(String),
+    /// Body without a recognizable task id.
+    Unrecognized,
+}
+
+/// Result of [`JobTracker::handle_webhook`](super::JobTracker::handle_webhook).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookOutcome {
+    /// Echo this challenge back to the provider.
+    Challenge(String),
+    /// The notification was accepted; `matched` is false for tasks the tracker does not know.
+    Accepted {
+        /// Provider task id from the callback.
+        task_id: String,
+        /// Whether a tracked job was found.
+        matched: bool,
+    },
+    /// The body was not understood.
+    Unrecognized,
+}
+
+/// Extract the task id (or verification challenge) from a provider callback body.
+///
+/// - MiniMax: `{"challenge": ...}` on registration, then `{"task_id": ..., "status": ...}`.
+/// - OpenAI: `{"type": "video.completed", "data": {"id": ...}}` events.
+/// - Others: `task_id`, `taskId`, `id`, or the same keys under `data`.
+pub fn parse_webhook_payload(provider: &str, payload: &Value) -> WebhookPayload {
+    if let Some(challenge) = payload.get("challenge").and_then(Value::as_str) {
+        return WebhookPayload::Challenge(challenge.to_string());
+    }
+
+    let task_id = |value: &Value| {
+        ["task_id", "taskId", "id"]
+            .iter()
+            .find_map(|key| match value.get(key)? {
+                Value::String(id) if !id.is_empty() => Some(id.clone()),
+                Value::Number(id) => Some(id.to_string()),
+                _ => None,
+            })
+    };
+
+    let found = match provider {
+        // OpenAI event envelopes carry their own event id at the top level.
+        "openai" => payload.get("data").and_then(task_id),
+        _ => task_id(payload).or_else(|| payload.get("data").and_then(task_id)),
+    };
+
+    found.map_or(WebhookPayload::Unrecognized, WebhookPayload::Task)
+}
@@ -7,6 +7,8 @@
 //! - **OpenTelemetry** (`opentelemetry` feature): Full observability with distributed tracing and metrics
 //! - **Server Adapters** (`server` feature): Axum integration for streaming responses
 //! - **MCP Integration** (`mcp` feature): Model Context Protocol integration for dynamic tool discovery
+//! - **Evaluation** (`eval` feature): Dataset runs against models and agents with scorers and reports
+//! - **Job Tracking** (`jobs` feature): Durable polling and output materialization for video tasks
 //!
 //! ## Features
 //!
@@ -15,6 +17,8 @@
 //! - `opentelemetry` - Enable OpenTelemetry distributed tracing and metrics
 //! - `server` - Enable server adapter utilities (Axum)
 //! - `mcp` - Enable MCP (Model Context Protocol) integration
+//! - `eval` - Enable the evaluation harness
+//! - `jobs` - Enable the video job tracker (`jobs-sqlite` adds the SQLite store)
 //! - `all` - Enable all features
 //!
 //! ## Example
@@ -86,6 +90,14 @@ pub mod server;
 #[cfg(feature = "mcp")]
 pub mod mcp;
 
+/// Evaluation harness for prompts, models and agents
+#[cfg(feature = "eval")]
+pub mod eval;
+
+/// Durable job tracking for video generation tasks
+#[cfg(feature = "jobs")]
+pub mod jobs;
+
 /// Error types for siumai-extras
 pub mod error;
 
@@ -179,6 +179,90 @@ impl Default for LlmMetrics {
     }
 }
 
+/// Bucket boundaries (seconds) recommended for `gen_ai.client.operation.duration`.
+const OPERATION_DURATION_BUCKETS: &[f64] = &[
+    0.01, 0.02, 0.04, 0.08, 0.16, 0.32, 0.64, 1.28, 2.56, 5.12, 10.24, 20.48, 40.96, 81.92,
+];
+
+/// Bucket boundaries recommended for `gen_ai.client.token.usage`.
+const TOKEN_USAGE_BUCKETS: &[f64] = &[
+    1.0, 4.0, 16.0, 64.0, 256.0, 1024.0, 4096.0, 16384.0, 65536.0, 262144.0, 1048576.0, 4194304.0,
+    16777216.0, 67108864.0,
+];
+
+/// Client metrics defined by the OpenTelemetry GenAI semantic conventions
+///
+/// Records `gen_ai.client.operation.duration` (seconds) and `gen_ai.client.token.usage`
+/// (tokens, split by `gen_ai.token.type`). Callers supply the operation attributes
+/// (`gen_ai.operation.name`, `gen_ai.system`, `gen_ai.request.model`, ...).
+#[derive(Clone)]
+pub struct GenAiMetrics {
+    operation_duration: Histogram<f64>,
+    token_usage: Histogram<u64>,
+}
+
+impl GenAiMetrics {
+    /// Create the instruments on the global `siumai` meter
+    pub fn new() -> Self {
+        Self::with_meter(&global::meter("siumai"))
+    }
+
+    /// Create the instruments on a specific meter
+    pub fn with_meter(meter: &Meter) -> Self {
+        use opentelemetry_semantic_conventions::metric;
+
+        let operation_duration = meter
+            .f64_histogram(metric::GEN_AI_CLIENT_OPERATION_DURATION)
+            .with_description("GenAI operation duration")
+            .with_unit("s")
+            .with_boundaries(OPERATION_DURATION_BUCKETS.to_vec())
+            .build();
+
+        let token_usage = meter
+            .u64_histogram(metric::GEN_AI_CLIENT_TOKEN_USAGE)
+            .with_description("Measures number of input and output tokens used")
+            .with_unit("{token}")
+            .with_boundaries(TOKEN_USAGE_BUCKETS.to_vec())
+            .build();
+
+        Self {
+            operation_duration,
+            token_usage,
+        }
+    }
+
+    /// Record the duration of one operation
+    pub fn record_operation(&self, duration: Duration, attributes: &[KeyValue]) {
+        self.operation_duration
+            .record(duration.as_secs_f64(), attributes);
+    }
+
+    /// Record input and output token counts of one operation
+    pub fn record_token_usage(
+        &self,
+        input_tokens: Option<u64>,
+        output_tokens: Option<u64>,
+        attributes: &[KeyValue],
+    ) {
+        use opentelemetry_semantic_conventions::attribute::GEN_AI_TOKEN_TYPE;
+
+        for (token_type, tokens) in [("input", input_tokens), ("output", output_tokens)] {
+            let Some(tokens) = tokens else {
+                continue;
+            };
+            let mut attributes = attributes.to_vec();
+            attributes.push(KeyValue::new(GEN_AI_TOKEN_TYPE, token_type));
+            self.token_usage.record(tokens, &attributes);
+        }
+    }
+}
+
+impl Default for GenAiMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Global metrics instance
 static GLOBAL_METRICS: once_cell::sync::Lazy<LlmMetrics> =
     once_cell::sync::Lazy::new(LlmMetrics::new);
@@ -10,7 +10,9 @@ use super::types::{
     AgentResult, OrchestratorContext, OrchestratorFinishEvent, OrchestratorOptions,
     OrchestratorStreamOptions, StepResult, ToolResolver,
 };
-use crate::structured_output::{OutputDecodeConfig, decode_json_value};
+use crate::structured_output::{
+    DecodeFailure, OutputDecodeConfig, SelfCorrection, correction_message, diagnose_json_value,
+};
 use siumai::prelude::unified::*;
 
 /// A reusable agent that can generate text, stream responses, and use tools across multiple steps.
@@ -75,6 +77,10 @@ where
     /// (`OutputDecodeConfig`). This mirrors Vercel AI SDK's
     /// `experimental_output` parameter.
     output_config: Option<OutputDecodeConfig>,
+    /// Model-driven correction of rejected structured output.
+    ///
+    /// Kept apart from `output_config` so it applies whichever output builder runs last.
+    output_self_correction: Option<SelfCorrection>,
     /// Agent-level tool choice setting.
     ///
     /// Controls how the model should use tools. Can be overridden per-step
@@ -149,6 +155,7 @@ where
             options: OrchestratorOptions::default(),
             common_params: None,
             output_config: None,
+            output_self_correction: None,
             tool_choice: None,
             active_tools: None,
         }
@@ -366,6 +373,18 @@ where
         self
     }
 
+    /// Send structured output errors back to the model for another attempt.
+    ///
+    /// When the final response fails parsing or schema validation, the agent appends a
+    /// follow-up turn listing each problem by JSON path and runs the loop again (tools stay
+    /// available), up to `correction.max_rounds` times. The extra steps are included in
+    /// `AgentResult::steps`, so `total_usage()` covers every attempt. Can be called before or
+    /// after setting the output schema or config; has no effect without either.
+    pub fn with_output_self_correction(mut self, correction: SelfCorrection) -> Self {
+        self.output_self_correction = Some(correction);
+        self
+    }
+
     /// Set the agent-level tool choice.
     ///
     /// Controls how the model should use tools. Can be overridden per-step
@@ -506,21 +525,56 @@ where
             self.active_tools.clone(),
         );
 
-        let (response, steps) = generate(
+        let mut history = messages.clone();
+        let (mut response, mut steps) = generate(
             &self.model,
             messages,
             Some(self.tools.clone()),
             Some(resolver),
             &stop_refs,
-            opts,
+            opts.clone(),
         )
         .await?;
 
         // Extract structured output if configuration is set
-        let output = if let Some(ref cfg) = self.output_config {
-            Self::extract_output(&response, cfg)?
-        } else {
-            None
+        let Some(ref cfg) = self.output_config else {
+            return Ok(AgentResult::with_output(response, steps, None));
+        };
+
+        let mut round = 0;
+        let mut consumed_steps = 0;
+        let output = loop {
+            let failure = match Self::extract_output(&response, cfg) {
+                Ok(output) => break output,
+                Err(failure) => failure,
+            };
+            let correction = match self.output_self_correction.as_ref() {
+                Some(correction) if round < correction.max_rounds => correction,
+                _ => return Err(failure.error),
+            };
+            round += 1;
+
+            for step in &steps[consumed_steps..] {
+                history.extend(step.messages.iter().cloned());
+            }
+            consumed_steps = steps.len();
+            history.push(correction_message(
+                &failure.issues,
+                cfg.schema.as_ref(),
+                correction,
+            ));
+
+            let (next_response, next_steps) = generate(
+                &self.model,
+                history.clone(),
+                Some(self.tools.clone()),
+                Some(resolver),
+                &stop_refs,
+                opts.clone(),
+            )
+            .await?;
+            response = next_response;
+            steps.extend(next_steps);
         };
 
         Ok(AgentResult::with_output(response, steps, output))
@@ -530,10 +584,11 @@ where
     ///
     /// This method attempts to parse JSON from the response text using the
     /// unified structured output configuration.
+    #[allow(clippy::result_large_err)]
     fn extract_output(
         response: &ChatResponse,
         cfg: &OutputDecodeConfig,
-    ) -> Result<Option<serde_json::Value>, LlmError> {
+    ) -> Result<Option<serde_json::Value>, DecodeFailure> {
         // Try to get text content
         let text = match response.content_text() {
             Some(t) => t,
@@ -542,16 +597,19 @@ where
 
         // First try direct decode; if that fails, fall back to extracting JSON
         // from markdown fences and decoding again.
-        match decode_json_value(text, cfg) {
+        match diagnose_json_value(text, cfg) {
             Ok(value) => Ok(Some(value)),
-            Err(_) => {
+            Err(failure) => {
                 if let Some(json_str) = Self::extract_json_from_markdown(text) {
-                    let value = decode_json_value(json_str, cfg)?;
+                    let value = diagnose_json_value(json_str, cfg)?;
                     Ok(Some(value))
                 } else {
-                    Err(LlmError::ParseError(
-                        "Response does not contain valid JSON".to_string(),
-                    ))
+                    Err(DecodeFailure {
+                        error: LlmError::ParseError(
+                            "Response does not contain valid JSON".to_string(),
+                        ),
+                        issues: failure.issues,
+                    })
                 }
             }
         }
@@ -672,6 +730,7 @@ where
             },
             common_params: self.common_params.clone(),
             output_config: self.output_config.clone(),
+            output_self_correction: self.output_self_correction.clone(),
             tool_choice: self.tool_choice.clone(),
             active_tools: self.active_tools.clone(),
         }
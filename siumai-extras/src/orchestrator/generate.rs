@@ -13,6 +13,7 @@ use crate::tool_runtime::{
 };
 
 use super::prepare_step::{PrepareStepContext, filter_active_tools};
+use super::spans::SpanScope;
 use super::stop_condition::StopCondition;
 use super::types::{
     OrchestratorFinishEvent, OrchestratorOptions, StepLanguageModel, StepModelInfo, StepResult,
@@ -156,14 +157,20 @@ pub async fn generate(
         opts.max_steps
     };
 
-    let preprocessed_approvals = preprocess_tool_approval_responses(
-        &history,
-        resolver,
-        &current_context,
-        None,
-        opts.on_preliminary_tool_result.as_deref(),
-    )
-    .await?;
+    let run_span = SpanScope::run(
+        opts.telemetry
+            .as_ref()
+            .and_then(|telemetry| telemetry.function_id.as_deref()),
+    );
+    let preprocessed_approvals = run_span
+        .instrument(preprocess_tool_approval_responses(
+            &history,
+            resolver,
+            &current_context,
+            None,
+            opts.on_preliminary_tool_result.as_deref(),
+        ))
+        .await?;
     if let Some(message) = preprocessed_approvals.local_tool_message {
         history.push(message);
     }
@@ -245,6 +252,7 @@ pub async fn generate(
             .map(|model| model as &dyn LanguageModel)
             .unwrap_or(model);
         let step_input_messages = current_messages.clone();
+        let step_span = run_span.step(step_idx, &StepModelInfo::from_language_model(step_model));
 
         // Apply system message override if provided
         if let Some(system) = current_system {
@@ -275,12 +283,18 @@ pub async fn generate(
         request.telemetry = opts.telemetry.clone();
         let step_request = request.clone();
 
-        let mut resp = siumai::text::generate(
-            step_model,
-            request,
-            siumai::text::GenerateOptions::default(),
-        )
-        .await?;
+        let result = step_span
+            .instrument(siumai::text::generate(
+                step_model,
+                request,
+                siumai::text::GenerateOptions::default(),
+            ))
+            .await;
+        if let Err(error) = &result {
+            step_span.record_llm_error(error);
+            run_span.record_llm_error(error);
+        }
+        let mut resp = result?;
         annotate_response_tool_calls(&mut resp, resolver);
 
         let mut step_msgs: Vec<ChatMessage> = Vec::new();
@@ -382,20 +396,21 @@ pub async fn generate(
                             .unwrap_or(false);
                         let out_part = match decision {
                             ToolApproval::Approve(args) | ToolApproval::Modify(args) => {
-                                execute_local_tool_call(LocalToolCallExecution {
-                                    resolver,
-                                    tool_name,
-                                    tool_call_id,
-                                    execution_args: args,
-                                    tool_dynamic,
-                                    step_input_messages: Some(&step_input_messages),
-                                    context: &current_context,
-                                    abort_signal: None,
-                                    on_preliminary_tool_result: opts
-                                        .on_preliminary_tool_result
-                                        .as_deref(),
-                                })
-                                .await
+                                step_span
+                                    .instrument(execute_local_tool_call(LocalToolCallExecution {
+                                        resolver,
+                                        tool_name,
+                                        tool_call_id,
+                                        execution_args: args,
+                                        tool_dynamic,
+                                        step_input_messages: Some(&step_input_messages),
+                                        context: &current_context,
+                                        abort_signal: None,
+                                        on_preliminary_tool_result: opts
+                                            .on_preliminary_tool_result
+                                            .as_deref(),
+                                    }))
+                                    .await
                             }
                             ToolApproval::Deny { reason } => execution_denied_tool_result(
                                 tool_call_id,
@@ -465,6 +480,8 @@ pub async fn generate(
             warnings: resp.warnings.clone(),
             provider_metadata: resp.provider_metadata.clone(),
         };
+        step_span.record_response(&resp);
+        step_span.end();
         if let Some(cb) = &opts.on_step_finish {
             cb(&step);
         }
@@ -489,6 +506,8 @@ pub async fn generate(
                 }
             }
 
+            run_span.record_usage(StepResult::merge_usage(&steps).as_ref());
+            run_span.end();
             emit_telemetry_success(
                 &opts.telemetry,
                 &span_id,
@@ -521,10 +540,13 @@ pub async fn generate(
             }
         }
 
+        run_span.record_usage(StepResult::merge_usage(&steps).as_ref());
+        run_span.end();
         emit_telemetry_max_steps(&opts.telemetry, &span_id, &trace_id, &steps, start_time).await;
 
         Ok((resp, steps))
     } else {
+        run_span.record_error("no_steps", "orchestrator: no steps produced");
         emit_telemetry_error(&opts.telemetry, &span_id, &trace_id).await;
 
         Err(LlmError::InternalError(
@@ -33,6 +33,7 @@ pub mod workflow;
 
 // Private modules
 mod generate;
+pub(crate) mod spans;
 mod stream;
 mod validation;
 
@@ -62,6 +63,8 @@ pub use builder::OrchestratorBuilder;
 pub use generate::generate;
 pub use stream::{StreamOrchestration, generate_stream, generate_stream_owned};
 
+pub use crate::structured_output::{OutputIssue, SelfCorrection};
+
 use crate::structured_output::{OutputDecodeConfig, decode_typed};
 use serde::de::DeserializeOwned;
 use siumai::prelude::unified::*;
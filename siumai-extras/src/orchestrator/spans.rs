@@ -0,0 +1,216 @@
+//! OpenTelemetry GenAI spans for orchestrator runs, steps and tool calls.
+//!
+//! With the `opentelemetry` feature a run opens an `invoke_agent` span, each step a child
+//! `orchestrator.step` span and each local tool call an `execute_tool {name}` span. Model
+//! calls made inside a step (and traced by `OpenTelemetryMiddleware`) nest under the step.
+//! Without the feature every function here is a no-op.
+
+use std::future::Future;
+
+use siumai::prelude::unified::{ChatResponse, LlmError};
+
+use super::types::StepModelInfo;
+
+/// A span scope; futures run through [`SpanScope::instrument`] see it as their parent.
+#[derive(Clone, Default)]
+pub(crate) struct SpanScope {
+    #[cfg(feature = "opentelemetry")]
+    cx: opentelemetry::Context,
+}
+
+#[cfg(feature = "opentelemetry")]
+mod otel {
+    use opentelemetry::trace::{SpanKind, Status, TraceContextExt, Tracer};
+    use opentelemetry::{Context, KeyValue, global};
+    use opentelemetry_semantic_conventions::attribute::{
+        ERROR_TYPE, GEN_AI_AGENT_NAME, GEN_AI_OPERATION_NAME, GEN_AI_REQUEST_MODEL, GEN_AI_SYSTEM,
+        GEN_AI_TOOL_CALL_ID, GEN_AI_TOOL_NAME, GEN_AI_TOOL_TYPE, GEN_AI_USAGE_INPUT_TOKENS,
+        GEN_AI_USAGE_OUTPUT_TOKENS,
+    };
+    use siumai::prelude::unified::{ChatResponse, LlmError, Usage};
+
+    use super::SpanScope;
+    use crate::orchestrator::types::StepModelInfo;
+    use crate::otel_middleware::{
+        OPERATION_EXECUTE_TOOL, OPERATION_INVOKE_AGENT, error_type, gen_ai_system,
+        response_attributes,
+    };
+
+    /// Step number attribute of `orchestrator.step` spans.
+    const STEP_NUMBER: &str = "siumai.orchestrator.step.number";
+
+    fn start(parent: &Context, name: String, attributes: Vec<KeyValue>) -> SpanScope {
+        let tracer = global::tracer("siumai");
+        let builder = tracer
+            .span_builder(name)
+            .with_kind(SpanKind::Internal)
+            .with_attributes(attributes);
+        let span = tracer.build_with_context(builder, parent);
+        SpanScope {
+            cx: parent.with_span(span),
+        }
+    }
+
+    pub(super) fn run(agent_name: Option<&str>) -> SpanScope {
+        let mut attributes = vec![KeyValue::new(GEN_AI_OPERATION_NAME, OPERATION_INVOKE_AGENT)];
+        let name = match agent_name {
+            Some(agent) => {
+                attributes.push(KeyValue::new(GEN_AI_AGENT_NAME, agent.to_string()));
+                format!("{OPERATION_INVOKE_AGENT} {agent}")
+            }
+            None => OPERATION_INVOKE_AGENT.to_string(),
+        };
+        start(&Context::current(), name, attributes)
+    }
+
+    pub(super) fn step(parent: &SpanScope, step_number: usize, model: &StepModelInfo) -> SpanScope {
+        let mut attributes = vec![KeyValue::new(STEP_NUMBER, step_number as i64)];
+        if !model.model_id.is_empty() {
+            attributes.push(KeyValue::new(GEN_AI_REQUEST_MODEL, model.model_id.clone()));
+        }
+        if !model.provider.is_empty() {
+            attributes.push(KeyValue::new(
+                GEN_AI_SYSTEM,
+                gen_ai_system(&model.provider).to_string(),
+            ));
+        }
+        start(&parent.cx, "orchestrator.step".to_string(), attributes)
+    }
+
+    pub(super) fn tool(tool_name: &str, tool_call_id: &str) -> SpanScope {
+        start(
+            &Context::current(),
+            format!("{OPERATION_EXECUTE_TOOL} {tool_name}"),
+            vec![
+                KeyValue::new(GEN_AI_OPERATION_NAME, OPERATION_EXECUTE_TOOL),
+                KeyValue::new(GEN_AI_TOOL_NAME, tool_name.to_string()),
+                KeyValue::new(GEN_AI_TOOL_CALL_ID, tool_call_id.to_string()),
+                KeyValue::new(GEN_AI_TOOL_TYPE, "function"),
+            ],
+        )
+    }
+
+    pub(super) fn record_response(scope: &SpanScope, response: &ChatResponse) {
+        let span = scope.cx.span();
+        for attribute in response_attributes(response) {
+            span.set_attribute(attribute);
+        }
+    }
+
+    pub(super) fn record_usage(scope: &SpanScope, usage: &Usage) {
+        let span = scope.cx.span();
+        if let Some(input) = usage.prompt_tokens() {
+            span.set_attribute(KeyValue::new(GEN_AI_USAGE_INPUT_TOKENS, input as i64));
+        }
+        if let Some(output) = usage.completion_tokens() {
+            span.set_attribute(KeyValue::new(GEN_AI_USAGE_OUTPUT_TOKENS, output as i64));
+        }
+    }
+
+    pub(super) fn record_error(scope: &SpanScope, error_kind: String, description: String) {
+        let span = scope.cx.span();
+        span.set_attribute(KeyValue::new(ERROR_TYPE, error_kind));
+        span.set_status(Status::error(description));
+    }
+
+    pub(super) fn record_llm_error(scope: &SpanScope, error: &LlmError) {
+        record_error(scope, error_type(error), error.to_string());
+    }
+
+    pub(super) fn end(scope: &SpanScope) {
+        scope.cx.span().end();
+    }
+}
+
+impl SpanScope {
+    /// Open the `invoke_agent` span of an orchestrator run under the current context.
+    pub(crate) fn run(agent_name: Option<&str>) -> Self {
+        #[cfg(feature = "opentelemetry")]
+        {
+            otel::run(agent_name)
+        }
+        #[cfg(not(feature = "opentelemetry"))]
+        {
+            let _ = agent_name;
+            Self::default()
+        }
+    }
+
+    /// Open a step span under this run.
+    pub(crate) fn step(&self, step_number: usize, model: &StepModelInfo) -> Self {
+        #[cfg(feature = "opentelemetry")]
+        {
+            otel::step(self, step_number, model)
+        }
+        #[cfg(not(feature = "opentelemetry"))]
+        {
+            let _ = (step_number, model);
+            Self::default()
+        }
+    }
+
+    /// Open an `execute_tool` span under the current context.
+    pub(crate) fn tool(tool_name: &str, tool_call_id: &str) -> Self {
+        #[cfg(feature = "opentelemetry")]
+        {
+            otel::tool(tool_name, tool_call_id)
+        }
+        #[cfg(not(feature = "opentelemetry"))]
+        {
+            let _ = (tool_name, tool_call_id);
+            Self::default()
+        }
+    }
+
+    /// Run `fut` with this span as the current context.
+    pub(crate) fn instrument<F: Future>(&self, fut: F) -> impl Future<Output = F::Output> {
+        #[cfg(feature = "opentelemetry")]
+        {
+            opentelemetry::context::FutureExt::with_context(fut, self.cx.clone())
+        }
+        #[cfg(not(feature = "opentelemetry"))]
+        {
+            fut
+        }
+    }
+
+    /// Record response id, model, finish reason and usage.
+    pub(crate) fn record_response(&self, response: &ChatResponse) {
+        #[cfg(feature = "opentelemetry")]
+        otel::record_response(self, response);
+        #[cfg(not(feature = "opentelemetry"))]
+        let _ = response;
+    }
+
+    /// Record aggregated usage (used by runs).
+    pub(crate) fn record_usage(&self, usage: Option<&siumai::prelude::unified::Usage>) {
+        #[cfg(feature = "opentelemetry")]
+        if let Some(usage) = usage {
+            otel::record_usage(self, usage);
+        }
+        #[cfg(not(feature = "opentelemetry"))]
+        let _ = usage;
+    }
+
+    /// Mark the span as failed with `error.type = error_kind`.
+    pub(crate) fn record_error(&self, error_kind: &str, description: impl Into<String>) {
+        #[cfg(feature = "opentelemetry")]
+        otel::record_error(self, error_kind.to_string(), description.into());
+        #[cfg(not(feature = "opentelemetry"))]
+        let _ = (error_kind, description);
+    }
+
+    /// Mark the span as failed with `error`.
+    pub(crate) fn record_llm_error(&self, error: &LlmError) {
+        #[cfg(feature = "opentelemetry")]
+        otel::record_llm_error(self, error);
+        #[cfg(not(feature = "opentelemetry"))]
+        let _ = error;
+    }
+
+    /// End the span. Spans also end when the last scope clone is dropped.
+    pub(crate) fn end(&self) {
+        #[cfg(feature = "opentelemetry")]
+        otel::end(self);
+    }
+}
@@ -14,6 +14,7 @@ use crate::tool_runtime::{
 };
 
 use super::prepare_step::{PrepareStepContext, filter_active_tools};
+use super::spans::SpanScope;
 use super::types::{
     OrchestratorFinishEvent, OrchestratorStreamOptions, StepLanguageModel, StepModelInfo,
     StepResult, ToolApproval, ToolResolver,
@@ -316,6 +317,11 @@ where
     let call_id = uuid::Uuid::new_v4().to_string();
     let orchestrator_cancel = siumai::experimental::utils::cancel::new_cancel_handle();
     let orchestrator_cancel_clone = orchestrator_cancel.clone();
+    let run_span = SpanScope::run(
+        opts.telemetry
+            .as_ref()
+            .and_then(|telemetry| telemetry.function_id.as_deref()),
+    );
 
     tokio::spawn(async move {
         let mut step_results: Vec<StepResult> = Vec::new();
@@ -328,16 +334,17 @@ where
         // Track processed tool_call IDs to avoid duplicate executions across steps.
         let mut processed_call_ids: HashSet<String> = HashSet::new();
         let mut pending_deferred_tool_calls: HashSet<String> = HashSet::new();
-        let preprocessed_approvals = match preprocess_tool_approval_responses(
-            &history,
-            resolver
-                .as_deref()
-                .map(|resolver| resolver as &dyn ToolResolver),
-            &current_context,
-            Some(orchestrator_cancel_clone.clone()),
-            on_preliminary_tool_result.as_deref(),
-        )
-        .await
+        let preprocessed_approvals = match run_span
+            .instrument(preprocess_tool_approval_responses(
+                &history,
+                resolver
+                    .as_deref()
+                    .map(|resolver| resolver as &dyn ToolResolver),
+                &current_context,
+                Some(orchestrator_cancel_clone.clone()),
+                on_preliminary_tool_result.as_deref(),
+            ))
+            .await
         {
             Ok(result) => result,
             Err(err) => {
@@ -423,6 +430,8 @@ where
                 .map(|model| model as &dyn LanguageModel)
                 .unwrap_or(&model);
             let step_input_messages = current_messages.clone();
+            let step_span =
+                run_span.step(step_idx, &StepModelInfo::from_language_model(step_model));
 
             if let Some(system) = current_system {
                 current_messages.insert(0, ChatMessage::system(system).build());
@@ -458,15 +467,18 @@ where
                 request.telemetry = opts.telemetry.clone();
                 let step_request = request.clone();
 
-                let handle = match siumai::text::stream_with_cancel(
-                    step_model,
-                    request,
-                    siumai::text::StreamOptions::default(),
-                )
-                .await
+                let handle = match step_span
+                    .instrument(siumai::text::stream_with_cancel(
+                        step_model,
+                        request,
+                        siumai::text::StreamOptions::default(),
+                    ))
+                    .await
                 {
                     Ok(h) => h,
                     Err(e) => {
+                        step_span.record_llm_error(&e);
+                        run_span.record_llm_error(&e);
                         encountered_error = true;
                         let _ = sender.send(Err(e)).await;
                         break;
@@ -658,6 +670,8 @@ where
                             }
                         }
                         Err(e) => {
+                            step_span.record_llm_error(&e);
+                            run_span.record_llm_error(&e);
                             encountered_error = true;
                             let _ = sender.send(Err(e)).await;
                             break;
@@ -714,12 +728,13 @@ where
                 request.telemetry = opts.telemetry.clone();
                 let step_request = request.clone();
 
-                let result = siumai::text::generate(
-                    step_model,
-                    request,
-                    siumai::text::GenerateOptions::default(),
-                )
-                .await;
+                let result = step_span
+                    .instrument(siumai::text::generate(
+                        step_model,
+                        request,
+                        siumai::text::GenerateOptions::default(),
+                    ))
+                    .await;
 
                 match result {
                     Ok(r) => (
@@ -730,6 +745,8 @@ where
                         HashMap::new(),
                     ),
                     Err(e) => {
+                        step_span.record_llm_error(&e);
+                        run_span.record_llm_error(&e);
                         encountered_error = true;
                         let _ = sender.send(Err(e)).await;
                         break;
@@ -860,19 +877,20 @@ where
                             .unwrap_or(false);
                         let out_part = match decision {
                             ToolApproval::Approve(args) | ToolApproval::Modify(args) => {
-                                execute_local_tool_call(LocalToolCallExecution {
-                                    resolver: resolver.as_ref(),
-                                    tool_name,
-                                    tool_call_id,
-                                    execution_args: args,
-                                    tool_dynamic,
-                                    step_input_messages: Some(&step_input_messages),
-                                    context: &current_context,
-                                    abort_signal: Some(orchestrator_cancel_clone.clone()),
-                                    on_preliminary_tool_result: on_preliminary_tool_result
-                                        .as_deref(),
-                                })
-                                .await
+                                step_span
+                                    .instrument(execute_local_tool_call(LocalToolCallExecution {
+                                        resolver: resolver.as_ref(),
+                                        tool_name,
+                                        tool_call_id,
+                                        execution_args: args,
+                                        tool_dynamic,
+                                        step_input_messages: Some(&step_input_messages),
+                                        context: &current_context,
+                                        abort_signal: Some(orchestrator_cancel_clone.clone()),
+                                        on_preliminary_tool_result: on_preliminary_tool_result
+                                            .as_deref(),
+                                    }))
+                                    .await
                             }
                             ToolApproval::Deny { reason } => execution_denied_tool_result(
                                 tool_call_id,
@@ -942,6 +960,8 @@ where
                 warnings: resp.warnings.clone(),
                 provider_metadata: resp.provider_metadata.clone(),
             };
+            step_span.record_response(&resp);
+            step_span.end();
             if let Some(cb) = &on_step_finish {
                 cb(&step);
             }
@@ -963,7 +983,9 @@ where
                 break;
             }
         }
+        run_span.record_usage(StepResult::merge_usage(&step_results).as_ref());
         if orchestrator_cancel_clone.is_cancelled() {
+            run_span.record_error("cancelled", "orchestrator run aborted");
             if let Some(cb) = &on_abort {
                 cb(&step_results);
             }
@@ -974,6 +996,7 @@ where
         {
             cb(&event);
         }
+        run_span.end();
         let _ = total_usage_tx.send(StepResult::merge_usage(&step_results));
         let _ = steps_tx.send(step_results);
     });
@@ -1577,6 +1577,79 @@ async fn test_agent_with_tools() {
     assert_eq!(steps[0].tool_calls.len(), 1);
 }
 
+#[tokio::test]
+async fn test_agent_self_corrects_structured_output() {
+    let model = MockChatModel::new(vec![
+        create_text_response("The city is Paris."),
+        create_text_response(r#"{"city": "Paris"}"#),
+    ]);
+    let calls = model.calls.clone();
+    let resolver = MockToolResolver::new();
+    let schema = json!({
+        "type": "object",
+        "properties": { "city": { "type": "string" } },
+        "required": ["city"]
+    });
+
+    let agent = ToolLoopAgent::new(model, vec![], vec![step_count_is(5)])
+        .with_output_schema(OutputSchema::new(schema))
+        .with_output_self_correction(SelfCorrection::new(1));
+
+    let result = agent
+        .generate(vec![ChatMessage::user("Where?").build()], &resolver)
+        .await
+        .unwrap();
+
+    assert_eq!(result.output, Some(json!({ "city": "Paris" })));
+    assert_eq!(result.steps.len(), 2);
+    assert_eq!(result.total_usage().unwrap().total_tokens(), Some(300));
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 2);
+    let retry = &calls[1];
+    assert!(
+        retry
+            .iter()
+            .any(|m| m.content_text() == Some("The city is Paris."))
+    );
+    let prompt = retry.last().unwrap().content_text().unwrap();
+    assert!(prompt.contains("(root): invalid JSON"), "{prompt}");
+}
+
+#[tokio::test]
+async fn test_agent_self_correction_applies_regardless_of_builder_order() {
+    let model = MockChatModel::new(vec![
+        create_text_response("The city is Paris."),
+        create_text_response(r#"{"city": "Paris"}"#),
+    ]);
+    let resolver = MockToolResolver::new();
+
+    let agent = ToolLoopAgent::new(model, vec![], vec![step_count_is(5)])
+        .with_output_self_correction(SelfCorrection::new(1))
+        .with_output_schema(OutputSchema::new(json!({ "type": "object" })));
+
+    let result = agent
+        .generate(vec![ChatMessage::user("Where?").build()], &resolver)
+        .await
+        .unwrap();
+    assert_eq!(result.output, Some(json!({ "city": "Paris" })));
+}
+
+#[tokio::test]
+async fn test_agent_structured_output_error_without_self_correction() {
+    let model = MockChatModel::new(vec![create_text_response("The city is Paris.")]);
+    let resolver = MockToolResolver::new();
+
+    let agent = ToolLoopAgent::new(model, vec![], vec![step_count_is(5)])
+        .with_output_schema(OutputSchema::new(json!({ "type": "object" })));
+
+    let err = agent
+        .generate(vec![ChatMessage::user("Where?").build()], &resolver)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("does not contain valid JSON"));
+}
+
 #[tokio::test]
 async fn test_agent_with_callbacks() {
     let responses = vec![create_text_response("Done")];
@@ -3024,3 +3097,128 @@ fn test_step_result_merge_usage_partial() {
     assert_eq!(merged.total_tokens(), Some(150));
     assert_eq!(merged.raw_usage_value(), None);
 }
+
+#[tokio::test]
+async fn test_agent_with_public_mock_provider() {
+    use siumai::testing::MockProvider;
+
+    let mock = MockProvider::new();
+    mock.push_tool_call(
+        "call_1",
+        "calculator",
+        json!({"operation": "add", "a": 2, "b": 3}),
+    )
+    .push_text("The result is 5");
+
+    let resolver = MockToolResolver::new().with_result("calculator", json!({"result": 5}));
+    let agent = ToolLoopAgent::new(
+        mock.language_model("agent-model"),
+        vec![create_tool("calculator")],
+        vec![step_count_is(10)],
+    );
+
+    let messages = vec![ChatMessage::user("What is 2 + 3?").build()];
+    let AgentResult {
+        response, steps, ..
+    } = agent.generate(messages, &resolver).await.unwrap();
+
+    assert_eq!(response.content_text().unwrap(), "The result is 5");
+    assert_eq!(steps.len(), 2);
+
+    let requests = mock.chat_requests();
+    assert_eq!(requests.len(), 2);
+    assert!(
+        requests[0]
+            .tools
+            .as_ref()
+            .is_some_and(|tools| tools.len() == 1)
+    );
+    assert!(requests[1].messages.len() > requests[0].messages.len());
+}
+
+#[cfg(feature = "opentelemetry")]
+#[tokio::test]
+async fn test_generate_emits_gen_ai_run_step_and_tool_spans() {
+    use opentelemetry::Value as OtelValue;
+    use opentelemetry::trace::SpanId;
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+
+    let exporter = InMemorySpanExporter::default();
+    opentelemetry::global::set_tracer_provider(
+        SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build(),
+    );
+
+    let model = MockChatModel::new(vec![
+        create_response_with_tools(vec![create_tool_call(
+            "get_weather",
+            json!({"city": "Tokyo"}),
+        )]),
+        create_text_response("Sunny."),
+    ]);
+    let resolver = MockToolResolver::new().with_result("get_weather", json!({"temp": 25}));
+    let opts = OrchestratorOptions {
+        telemetry: Some(TelemetryConfig {
+            function_id: Some("weather-agent".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    generate(
+        &model,
+        vec![ChatMessage::user("Weather in Tokyo?").build()],
+        Some(vec![create_tool("get_weather")]),
+        Some(&resolver),
+        &[],
+        opts,
+    )
+    .await
+    .unwrap();
+
+    let spans = exporter.get_finished_spans().unwrap();
+    let run = spans
+        .iter()
+        .find(|s| s.name == "invoke_agent weather-agent")
+        .expect("run span");
+    let in_run: Vec<&SpanData> = spans
+        .iter()
+        .filter(|s| s.span_context.trace_id() == run.span_context.trace_id())
+        .collect();
+    let attribute = |span: &SpanData, key: &str| {
+        span.attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| kv.value.clone())
+    };
+
+    let steps: Vec<&&SpanData> = in_run
+        .iter()
+        .filter(|s| s.name == "orchestrator.step")
+        .collect();
+    assert_eq!(steps.len(), 2);
+    assert!(
+        steps
+            .iter()
+            .all(|s| s.parent_span_id == run.span_context.span_id())
+    );
+    assert_eq!(
+        attribute(steps[0], "gen_ai.request.model"),
+        Some(OtelValue::from("mock-model"))
+    );
+
+    let tool = in_run
+        .iter()
+        .find(|s| s.name == "execute_tool get_weather")
+        .expect("tool span");
+    assert_eq!(tool.parent_span_id, steps[0].span_context.span_id());
+    assert_eq!(
+        attribute(tool, "gen_ai.tool.call.id"),
+        Some(OtelValue::from("call_get_weather"))
+    );
+    assert_eq!(run.parent_span_id, SpanId::INVALID);
+    assert_eq!(
+        attribute(run, "gen_ai.usage.input_tokens"),
+        Some(OtelValue::I64(200))
+    );
+}
@@ -1,30 +1,43 @@
 //! OpenTelemetry middleware for automatic LLM request tracing
 //!
 //! This module provides middleware that automatically:
-//! - Creates spans for all LLM requests and responses
-//! - Records metrics (latency, token usage, error rates)
+//! - Creates spans for all LLM requests and responses (generate and stream)
+//! - Records metrics (operation duration, token usage)
 //! - Injects W3C traceparent headers for distributed tracing
 //!
+//! Spans, events and metrics follow the
+//! [OpenTelemetry GenAI semantic conventions](https://opentelemetry.io/docs/specs/semconv/gen-ai/),
+//! so backends that understand `gen_ai.*` attributes (Grafana, Datadog, Phoenix, ...) pick
+//! them up without custom mapping.
+//!
 //! ## Features
 //!
 //! ### Automatic Span Creation
-//! Creates OpenTelemetry spans for each LLM request with detailed attributes:
-//! - Model name, temperature, max_tokens
-//! - Token usage (prompt, completion, total)
-//! - Request/response timing
+//! Creates a `chat {model}` client span for each LLM request with:
+//! - `gen_ai.operation.name`, `gen_ai.system`, `gen_ai.request.model`
+//! - Request parameters (`gen_ai.request.max_tokens`, `gen_ai.request.temperature`, ...)
+//! - `gen_ai.response.id`, `gen_ai.response.model`, `gen_ai.response.finish_reasons`
+//! - `gen_ai.usage.input_tokens`, `gen_ai.usage.output_tokens`
+//! - `error.type` when the call fails
+//!
+//! ### Content Capture
+//! Prompts and completions are not recorded by default. Enable
+//! [`OpenTelemetryMiddleware::with_content_capture`] (or set
+//! `OTEL_INSTRUMENTATION_GENAI_CAPTURE_MESSAGE_CONTENT=true`) to attach them as
+//! `gen_ai.{system,user,assistant,tool}.message` and `gen_ai.choice` span events.
 //!
 //! ### W3C Trace Context Propagation
-//! Automatically injects `traceparent` headers into HTTP requests when an active
-//! OpenTelemetry span context exists. This enables end-to-end distributed tracing
-//! across your application and LLM providers.
+//! Injects a `traceparent` header pointing at the request span, so provider-side traces
+//! join the caller's trace.
 //!
 //! Format: `traceparent: 00-{trace_id}-{span_id}-{trace_flags}`
 //!
 //! ### Metrics Collection
-//! Records metrics for monitoring and alerting:
-//! - Request latency
-//! - Token usage
-//! - Error rates
+//! Records `gen_ai.client.operation.duration` and `gen_ai.client.token.usage`
+//! histograms through [`GenAiMetrics`].
+//!
+//! [`OpenTelemetryMiddleware::with_content_capture`]: crate::otel_middleware::OpenTelemetryMiddleware::with_content_capture
+//! [`GenAiMetrics`]: crate::metrics::GenAiMetrics
 //!
 //! ## Example
 //!
@@ -39,7 +52,11 @@
 //!
 //! // Create client with OpenTelemetry middleware
 //! let client = Client::builder()
-//!     .add_middleware(Arc::new(OpenTelemetryMiddleware::new()))
+//!     .add_middleware(Arc::new(
+//!         OpenTelemetryMiddleware::new()
+//!             .with_provider("openai")
+//!             .with_content_capture(true),
+//!     ))
 //!     .build()?;
 //!
 //! // All requests will now be traced and have traceparent headers injected
@@ -67,96 +84,196 @@
 //!
 //! // The LLM request will be traced as a child span with traceparent header
 //! ```
+//!
+//! Orchestrator runs (`siumai_extras::orchestrator`) add `invoke_agent`, per-step and
+//! `execute_tool` spans around these request spans when this feature is enabled.
 
-use crate::metrics::LlmMetrics;
+use crate::metrics::GenAiMetrics;
+use futures::StreamExt;
+use opentelemetry::context::FutureExt;
 use opentelemetry::{
-    KeyValue, global,
-    trace::{Span, Status, Tracer},
+    Array, Context, KeyValue, StringValue, Value, global,
+    global::BoxedTracer,
+    trace::{SpanKind, Status, TraceContextExt, Tracer},
 };
-use siumai::experimental::execution::middleware::language_model::LanguageModelMiddleware;
-use siumai::prelude::unified::{ChatRequest, ChatResponse, LlmError};
+use opentelemetry_semantic_conventions::attribute::{
+    ERROR_TYPE, GEN_AI_OPERATION_NAME, GEN_AI_REQUEST_FREQUENCY_PENALTY, GEN_AI_REQUEST_MAX_TOKENS,
+    GEN_AI_REQUEST_MODEL, GEN_AI_REQUEST_PRESENCE_PENALTY, GEN_AI_REQUEST_SEED,
+    GEN_AI_REQUEST_STOP_SEQUENCES, GEN_AI_REQUEST_TEMPERATURE, GEN_AI_REQUEST_TOP_K,
+    GEN_AI_REQUEST_TOP_P, GEN_AI_RESPONSE_FINISH_REASONS, GEN_AI_RESPONSE_ID,
+    GEN_AI_RESPONSE_MODEL, GEN_AI_SYSTEM, GEN_AI_USAGE_INPUT_TOKENS, GEN_AI_USAGE_OUTPUT_TOKENS,
+};
+use siumai::experimental::execution::middleware::{
+    GenerateAsyncFn, LanguageModelMiddleware, StreamAsyncFn,
+};
+use siumai::experimental::streaming::StreamProcessor;
+use siumai::prelude::unified::{
+    ChatMessage, ChatRequest, ChatResponse, ChatStream, ChatStreamEvent, ContentPart, FinishReason,
+    LlmError, MessageRole,
+};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// `gen_ai.operation.name` of chat completions.
+pub const OPERATION_CHAT: &str = "chat";
+/// `gen_ai.operation.name` of tool executions.
+pub const OPERATION_EXECUTE_TOOL: &str = "execute_tool";
+/// `gen_ai.operation.name` of agent (orchestrator) runs.
+pub const OPERATION_INVOKE_AGENT: &str = "invoke_agent";
+
+/// Span event carrying the completion when content capture is enabled.
+pub const CHOICE_EVENT: &str = "gen_ai.choice";
+
+/// Environment variable that enables content capture for [`OpenTelemetryMiddleware::new`].
+pub const CAPTURE_CONTENT_ENV: &str = "OTEL_INSTRUMENTATION_GENAI_CAPTURE_MESSAGE_CONTENT";
+
+/// Map a siumai provider id to its `gen_ai.system` value.
+///
+/// Providers without a well-known value keep their id.
+pub fn gen_ai_system(provider_id: &str) -> &str {
+    match provider_id {
+        "gemini" | "google" => "gcp.gemini",
+        "vertex" | "google-vertex" | "anthropic-vertex" => "gcp.vertex_ai",
+        "bedrock" | "amazon-bedrock" => "aws.bedrock",
+        "azure" | "azure-openai" => "az.ai.openai",
+        "mistral" => "mistral_ai",
+        other => other,
+    }
+}
 
 /// OpenTelemetry middleware for automatic tracing and metrics
 #[derive(Clone)]
 pub struct OpenTelemetryMiddleware {
-    #[allow(dead_code)]
-    metrics: LlmMetrics,
+    tracer: Arc<BoxedTracer>,
+    metrics: GenAiMetrics,
+    provider: Option<String>,
+    capture_content: bool,
 }
 
 impl OpenTelemetryMiddleware {
-    /// Create a new OpenTelemetry middleware
+    /// Create a new OpenTelemetry middleware using the global tracer and meter
+    ///
+    /// Content capture follows `OTEL_INSTRUMENTATION_GENAI_CAPTURE_MESSAGE_CONTENT`.
     pub fn new() -> Self {
+        let capture_content = std::env::var(CAPTURE_CONTENT_ENV)
+            .map(|v| v.eq_ignore_ascii_case("true") || v == "1")
+            .unwrap_or(false);
         Self {
-            metrics: LlmMetrics::new(),
+            tracer: Arc::new(global::tracer("siumai")),
+            metrics: GenAiMetrics::new(),
+            provider: None,
+            capture_content,
         }
     }
 
     /// Create a new OpenTelemetry middleware with custom metrics
-    pub fn with_metrics(metrics: LlmMetrics) -> Self {
-        Self { metrics }
+    pub fn with_metrics(metrics: GenAiMetrics) -> Self {
+        Self {
+            metrics,
+            ..Self::new()
+        }
+    }
+
+    /// Use a specific tracer instead of the global `siumai` tracer
+    pub fn with_tracer(mut self, tracer: BoxedTracer) -> Self {
+        self.tracer = Arc::new(tracer);
+        self
+    }
+
+    /// Set the provider id reported as `gen_ai.system`
+    ///
+    /// Without it the provider is taken from the response (provider metadata or the stream
+    /// start event) when available.
+    pub fn with_provider(mut self, provider_id: impl Into<String>) -> Self {
+        self.provider = Some(provider_id.into());
+        self
+    }
+
+    /// Record prompts and completions as span events
+    pub fn with_content_capture(mut self, enabled: bool) -> Self {
+        self.capture_content = enabled;
+        self
     }
 
     /// Create span attributes from request
     fn create_span_attributes(req: &ChatRequest) -> Vec<KeyValue> {
-        let mut attributes = vec![KeyValue::new("llm.model", req.common_params.model.clone())];
+        let params = &req.common_params;
+        let mut attributes = vec![
+            KeyValue::new(GEN_AI_OPERATION_NAME, OPERATION_CHAT),
+            KeyValue::new(GEN_AI_REQUEST_MODEL, params.model.clone()),
+        ];
 
-        if let Some(max_tokens) = req.common_params.max_tokens {
-            attributes.push(KeyValue::new("llm.max_tokens", max_tokens as i64));
+        if let Some(max_tokens) = params.max_tokens.or(params.max_completion_tokens) {
+            attributes.push(KeyValue::new(GEN_AI_REQUEST_MAX_TOKENS, max_tokens as i64));
         }
-
-        if let Some(temperature) = req.common_params.temperature {
-            attributes.push(KeyValue::new("llm.temperature", temperature));
+        if let Some(temperature) = params.temperature {
+            attributes.push(KeyValue::new(GEN_AI_REQUEST_TEMPERATURE, temperature));
         }
-
-        if let Some(top_p) = req.common_params.top_p {
-            attributes.push(KeyValue::new("llm.top_p", top_p));
+        if let Some(top_p) = params.top_p {
+            attributes.push(KeyValue::new(GEN_AI_REQUEST_TOP_P, top_p));
         }
-
-        // Add message count
-        attributes.push(KeyValue::new(
-            "llm.message_count",
-            req.messages.len() as i64,
-        ));
-
-        // Add tool count if present
-        if let Some(ref tools) = req.tools {
-            attributes.push(KeyValue::new("llm.tool_count", tools.len() as i64));
+        if let Some(top_k) = params.top_k {
+            attributes.push(KeyValue::new(GEN_AI_REQUEST_TOP_K, top_k));
+        }
+        if let Some(penalty) = params.frequency_penalty {
+            attributes.push(KeyValue::new(GEN_AI_REQUEST_FREQUENCY_PENALTY, penalty));
+        }
+        if let Some(penalty) = params.presence_penalty {
+            attributes.push(KeyValue::new(GEN_AI_REQUEST_PRESENCE_PENALTY, penalty));
+        }
+        if let Some(seed) = params.seed {
+            attributes.push(KeyValue::new(GEN_AI_REQUEST_SEED, seed as i64));
+        }
+        if let Some(stop) = params.stop_sequences.as_ref().filter(|s| !s.is_empty()) {
+            attributes.push(KeyValue::new(
+                GEN_AI_REQUEST_STOP_SEQUENCES,
+                string_array(stop.iter().cloned()),
+            ));
         }
 
         attributes
     }
 
-    /// Add response attributes to span
-    fn add_response_attributes<S: Span>(span: &mut S, response: &ChatResponse) {
-        if let Some(ref usage) = response.usage {
-            span.set_attribute(KeyValue::new(
-                "llm.usage.prompt_tokens",
-                usage.prompt_tokens().unwrap_or(0) as i64,
-            ));
-            span.set_attribute(KeyValue::new(
-                "llm.usage.completion_tokens",
-                usage.completion_tokens().unwrap_or(0) as i64,
+    /// Start the request span as a child of the current context
+    fn start(&self, req: &mut ChatRequest) -> CallSpan {
+        let parent = Context::current();
+        let model = req.common_params.model.clone();
+        let name = if model.is_empty() {
+            OPERATION_CHAT.to_string()
+        } else {
+            format!("{OPERATION_CHAT} {model}")
+        };
+        let mut attributes = Self::create_span_attributes(req);
+        if let Some(provider) = &self.provider {
+            attributes.push(KeyValue::new(
+                GEN_AI_SYSTEM,
+                gen_ai_system(provider).to_string(),
             ));
-            span.set_attribute(KeyValue::new(
-                "llm.usage.total_tokens",
-                usage.total_tokens().unwrap_or(0) as i64,
-            ));
-        }
-
-        if let Some(ref model) = response.model {
-            span.set_attribute(KeyValue::new("llm.response.model", model.clone()));
         }
+        let builder = self
+            .tracer
+            .span_builder(name)
+            .with_kind(SpanKind::Client)
+            .with_attributes(attributes);
+        let cx = parent.with_span(self.tracer.build_with_context(builder, &parent));
 
-        if let Some(ref id) = response.id {
-            span.set_attribute(KeyValue::new("llm.response.id", id.clone()));
+        if self.capture_content {
+            for message in &req.messages {
+                for (name, attributes) in message_events(message) {
+                    cx.span().add_event(name, attributes);
+                }
+            }
         }
+        inject_traceparent(req, &cx);
 
-        // Add finish reason if available
-        if let Some(ref finish_reason) = response.finish_reason {
-            span.set_attribute(KeyValue::new(
-                "llm.finish_reason",
-                format!("{:?}", finish_reason),
-            ));
+        CallSpan {
+            cx,
+            started: Instant::now(),
+            metrics: self.metrics.clone(),
+            request_model: model,
+            provider: self.provider.clone(),
+            capture_content: self.capture_content,
+            finished: false,
         }
     }
 }
@@ -168,53 +285,581 @@ impl Default for OpenTelemetryMiddleware {
 }
 
 impl LanguageModelMiddleware for OpenTelemetryMiddleware {
-    fn transform_params(&self, mut req: ChatRequest) -> ChatRequest {
-        // Get current OpenTelemetry span context
-        use opentelemetry::trace::TraceContextExt;
-        let current_cx = opentelemetry::Context::current();
-        let span = current_cx.span();
-        let span_cx = span.span_context();
-
-        // Only inject headers if we have a valid span context
-        if span_cx.is_valid() {
-            // Format W3C traceparent header
-            // Format: "00-{trace_id}-{span_id}-{trace_flags}"
-            let trace_id = span_cx.trace_id();
-            let span_id = span_cx.span_id();
-            let trace_flags = span_cx.trace_flags();
-
-            let traceparent = format!("00-{:032x}-{:016x}-{:02x}", trace_id, span_id, trace_flags);
-
-            // Inject into request headers
-            // Create http_config if it doesn't exist
-            let http_config = req.http_config.get_or_insert_with(Default::default);
-            http_config
-                .headers
-                .insert("traceparent".to_string(), traceparent);
-        }
+    fn wrap_generate_async(&self, next: Arc<GenerateAsyncFn>) -> Arc<GenerateAsyncFn> {
+        let this = self.clone();
+        Arc::new(move |mut req: ChatRequest| {
+            let next = Arc::clone(&next);
+            let mut span = this.start(&mut req);
+            Box::pin(async move {
+                let result = next(req).with_context(span.cx.clone()).await;
+                match &result {
+                    Ok(resp) => span.finish(Some(resp), None),
+                    Err(e) => span.finish(None, Some(e)),
+                }
+                result
+            })
+        })
+    }
 
-        req
+    fn wrap_stream_async(&self, next: Arc<StreamAsyncFn>) -> Arc<StreamAsyncFn> {
+        let this = self.clone();
+        Arc::new(move |mut req: ChatRequest| {
+            let next = Arc::clone(&next);
+            let mut span = this.start(&mut req);
+            Box::pin(async move {
+                let mut inner = match next(req).with_context(span.cx.clone()).await {
+                    Ok(inner) => inner,
+                    Err(e) => {
+                        span.finish(None, Some(&e));
+                        return Err(e);
+                    }
+                };
+
+                let mut processor = StreamProcessor::new();
+                let stream: ChatStream = Box::pin(async_stream::stream! {
+                    while let Some(item) = inner.next().await {
+                        match &item {
+                            Ok(event) => {
+                                if let ChatStreamEvent::StreamStart { metadata } = event
+                                    && span.provider.is_none()
+                                    && !metadata.provider.is_empty()
+                                {
+                                    span.provider = Some(metadata.provider.clone());
+                                }
+                                processor.process_event(event.clone());
+                                match event {
+                                    ChatStreamEvent::StreamEnd { response } => {
+                                        span.finish(Some(response), None);
+                                    }
+                                    ChatStreamEvent::Error { error } => span.finish(
+                                        None,
+                                        Some(&LlmError::StreamError(error.clone())),
+                                    ),
+                                    _ => {}
+                                }
+                            }
+                            Err(e) => span.finish(None, Some(e)),
+                        }
+                        yield item;
+                    }
+                    if !span.finished {
+                        span.finish(Some(&processor.build_final_response()), None);
+                    }
+                });
+                Ok(stream)
+            })
+        })
+    }
+}
+
+/// An in-flight request span. Ending it records the operation metrics.
+struct CallSpan {
+    cx: Context,
+    started: Instant,
+    metrics: GenAiMetrics,
+    request_model: String,
+    provider: Option<String>,
+    capture_content: bool,
+    finished: bool,
+}
+
+impl CallSpan {
+    fn finish(&mut self, response: Option<&ChatResponse>, error: Option<&LlmError>) {
+        self.end(response, error.map(|e| (error_type(e), e.to_string())));
     }
 
-    fn post_generate(
-        &self,
-        req: &ChatRequest,
-        resp: ChatResponse,
-    ) -> Result<ChatResponse, LlmError> {
-        // Create a span for this request
-        let tracer = global::tracer("siumai");
-        let mut span = tracer.start(format!("llm.chat {}", req.common_params.model));
+    /// End the span; `error` is the `error.type` value and status description.
+    fn end(&mut self, response: Option<&ChatResponse>, error: Option<(String, String)>) {
+        if std::mem::replace(&mut self.finished, true) {
+            return;
+        }
+        let span = self.cx.span();
+        let provider = self
+            .provider
+            .clone()
+            .or_else(|| response.and_then(response_provider));
 
-        // Add request attributes
-        for attr in Self::create_span_attributes(req) {
-            span.set_attribute(attr);
+        let mut metric_attributes = vec![
+            KeyValue::new(GEN_AI_OPERATION_NAME, OPERATION_CHAT),
+            KeyValue::new(GEN_AI_REQUEST_MODEL, self.request_model.clone()),
+        ];
+        if let Some(provider) = &provider {
+            let system = KeyValue::new(GEN_AI_SYSTEM, gen_ai_system(provider).to_string());
+            span.set_attribute(system.clone());
+            metric_attributes.push(system);
         }
 
-        // Add response attributes
-        Self::add_response_attributes(&mut span, &resp);
-        span.set_status(Status::Ok);
+        let mut tokens = (None, None);
+        if let Some(response) = response {
+            for attribute in response_attributes(response) {
+                span.set_attribute(attribute);
+            }
+            if let Some(model) = &response.model {
+                metric_attributes.push(KeyValue::new(GEN_AI_RESPONSE_MODEL, model.clone()));
+            }
+            if let Some(usage) = &response.usage {
+                tokens = (
+                    usage.prompt_tokens().map(u64::from),
+                    usage.completion_tokens().map(u64::from),
+                );
+            }
+            if self.capture_content {
+                span.add_event(CHOICE_EVENT, choice_event_attributes(response));
+            }
+        }
+
+        match error {
+            Some((error_type, description)) => {
+                let error_type = KeyValue::new(ERROR_TYPE, error_type);
+                span.set_attribute(error_type.clone());
+                span.set_status(Status::error(description));
+                metric_attributes.push(error_type);
+            }
+            None => span.set_status(Status::Ok),
+        }
         span.end();
 
-        Ok(resp)
+        self.metrics
+            .record_operation(self.started.elapsed(), &metric_attributes);
+        self.metrics
+            .record_token_usage(tokens.0, tokens.1, &metric_attributes);
+    }
+}
+
+impl Drop for CallSpan {
+    /// A stream dropped before it finished still ends its span.
+    fn drop(&mut self) {
+        self.end(
+            None,
+            Some((
+                "cancelled".to_string(),
+                "stream dropped before completion".to_string(),
+            )),
+        );
+    }
+}
+
+/// Response attributes shared by request spans and orchestrator step spans.
+pub(crate) fn response_attributes(response: &ChatResponse) -> Vec<KeyValue> {
+    let mut attributes = Vec::new();
+    if let Some(id) = &response.id {
+        attributes.push(KeyValue::new(GEN_AI_RESPONSE_ID, id.clone()));
+    }
+    if let Some(model) = &response.model {
+        attributes.push(KeyValue::new(GEN_AI_RESPONSE_MODEL, model.clone()));
+    }
+    if let Some(reason) = &response.finish_reason {
+        attributes.push(KeyValue::new(
+            GEN_AI_RESPONSE_FINISH_REASONS,
+            string_array([finish_reason_value(reason)]),
+        ));
+    }
+    if let Some(usage) = &response.usage {
+        if let Some(input) = usage.prompt_tokens() {
+            attributes.push(KeyValue::new(GEN_AI_USAGE_INPUT_TOKENS, input as i64));
+        }
+        if let Some(output) = usage.completion_tokens() {
+            attributes.push(KeyValue::new(GEN_AI_USAGE_OUTPUT_TOKENS, output as i64));
+        }
+    }
+    attributes
+}
+
+/// Low-cardinality `error.type` value for an error (its variant name).
+pub(crate) fn error_type(error: &LlmError) -> String {
+    let debug = format!("{error:?}");
+    debug
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or("_OTHER")
+        .to_string()
+}
+
+fn finish_reason_value(reason: &FinishReason) -> String {
+    match reason {
+        FinishReason::Stop | FinishReason::StopSequence => "stop".to_string(),
+        FinishReason::Length => "length".to_string(),
+        FinishReason::ToolCalls => "tool_calls".to_string(),
+        FinishReason::ContentFilter => "content_filter".to_string(),
+        FinishReason::Error => "error".to_string(),
+        FinishReason::Other(other) => other.clone(),
+        FinishReason::Unknown => "unknown".to_string(),
+    }
+}
+
+/// The single non-`siumai` provider metadata key, if any.
+fn response_provider(response: &ChatResponse) -> Option<String> {
+    let metadata = response.provider_metadata.as_ref()?;
+    let mut providers = metadata.keys().filter(|key| key.as_str() != "siumai");
+    let provider = providers.next()?;
+    providers.next().is_none().then(|| provider.clone())
+}
+
+fn string_array(values: impl IntoIterator<Item = String>) -> Value {
+    Value::Array(Array::String(
+        values.into_iter().map(StringValue::from).collect(),
+    ))
+}
+
+fn inject_traceparent(req: &mut ChatRequest, cx: &Context) {
+    let span = cx.span();
+    let span_cx = span.span_context();
+    if !span_cx.is_valid() {
+        return;
+    }
+    let traceparent = format!(
+        "00-{:032x}-{:016x}-{:02x}",
+        span_cx.trace_id(),
+        span_cx.span_id(),
+        span_cx.trace_flags()
+    );
+    req.http_config
+        .get_or_insert_with(Default::default)
+        .headers
+        .insert("traceparent".to_string(), traceparent);
+}
+
+/// Span events for one input message (`gen_ai.{role}.message`).
+fn message_events(message: &ChatMessage) -> Vec<(&'static str, Vec<KeyValue>)> {
+    let name = match message.role {
+        MessageRole::System | MessageRole::Developer => "gen_ai.system.message",
+        MessageRole::User => "gen_ai.user.message",
+        MessageRole::Assistant => "gen_ai.assistant.message",
+        MessageRole::Tool => "gen_ai.tool.message",
+    };
+
+    if matches!(message.role, MessageRole::Tool) {
+        return message
+            .tool_results()
+            .into_iter()
+            .filter_map(|part| match part {
+                ContentPart::ToolResult {
+                    tool_call_id,
+                    output,
+                    ..
+                } => Some((
+                    name,
+                    vec![
+                        KeyValue::new("id", tool_call_id.clone()),
+                        KeyValue::new("content", serde_json::to_string(output).unwrap_or_default()),
+                    ],
+                )),
+                _ => None,
+            })
+            .collect();
+    }
+
+    let mut attributes = Vec::new();
+    let text = message.content.all_text();
+    if !text.is_empty() {
+        attributes.push(KeyValue::new("content", text));
+    }
+    if let Some(tool_calls) = tool_calls_json(message.tool_calls()) {
+        attributes.push(KeyValue::new("tool_calls", tool_calls));
+    }
+    vec![(name, attributes)]
+}
+
+fn choice_event_attributes(response: &ChatResponse) -> Vec<KeyValue> {
+    let mut attributes = vec![KeyValue::new("index", 0_i64)];
+    if let Some(reason) = &response.finish_reason {
+        attributes.push(KeyValue::new("finish_reason", finish_reason_value(reason)));
+    }
+    let text = response.content.all_text();
+    if !text.is_empty() {
+        attributes.push(KeyValue::new("content", text));
+    }
+    if let Some(tool_calls) = tool_calls_json(response.tool_calls()) {
+        attributes.push(KeyValue::new("tool_calls", tool_calls));
+    }
+    attributes
+}
+
+fn tool_calls_json(parts: Vec<&ContentPart>) -> Option<String> {
+    let calls: Vec<serde_json::Value> = parts
+        .into_iter()
+        .filter_map(|part| match part {
+            ContentPart::ToolCall {
+                tool_call_id,
+                tool_name,
+                arguments,
+                ..
+            } => Some(serde_json::json!({
+                "id": tool_call_id,
+                "type": "function",
+                "function": {"name": tool_name, "arguments": arguments},
+            })),
+            _ => None,
+        })
+        .collect();
+    (!calls.is_empty()).then(|| serde_json::Value::Array(calls).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::{Status, TracerProvider};
+    use opentelemetry_sdk::metrics::{InMemoryMetricExporter, PeriodicReader, SdkMeterProvider};
+    use opentelemetry_sdk::trace::{InMemorySpanExporter, SdkTracerProvider, SpanData};
+    use siumai::prelude::unified::{MessageContent, ResponseMetadata, Usage};
+    use std::sync::Mutex;
+
+    fn tracer() -> (SdkTracerProvider, InMemorySpanExporter, BoxedTracer) {
+        let exporter = InMemorySpanExporter::default();
+        let provider = SdkTracerProvider::builder()
+            .with_simple_exporter(exporter.clone())
+            .build();
+        let tracer = BoxedTracer::new(Box::new(provider.tracer("test")));
+        (provider, exporter, tracer)
+    }
+
+    fn attribute<'a>(span: &'a SpanData, key: &str) -> Option<&'a Value> {
+        span.attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| &kv.value)
+    }
+
+    fn usage() -> Usage {
+        Usage::builder()
+            .prompt_tokens(12)
+            .completion_tokens(3)
+            .total_tokens(15)
+            .build()
+    }
+
+    fn request() -> ChatRequest {
+        let mut req = ChatRequest::new(vec![
+            ChatMessage::system("Be brief.").build(),
+            ChatMessage::user("Hi").build(),
+        ]);
+        req.common_params.model = "gpt-test".to_string();
+        req.common_params.temperature = Some(0.2);
+        req.common_params.max_tokens = Some(64);
+        req
+    }
+
+    fn response() -> ChatResponse {
+        let mut resp = ChatResponse::new(MessageContent::Text("Hello!".to_string()));
+        resp.id = Some("resp_1".to_string());
+        resp.model = Some("gpt-test-2025".to_string());
+        resp.finish_reason = Some(FinishReason::Stop);
+        resp.usage = Some(usage());
+        resp.provider_metadata =
+            Some(serde_json::from_value(serde_json::json!({"openai": {}})).unwrap());
+        resp
+    }
+
+    #[tokio::test]
+    async fn generate_spans_follow_gen_ai_conventions() {
+        let (_provider, exporter, tracer) = tracer();
+        let middleware = OpenTelemetryMiddleware::new()
+            .with_tracer(tracer)
+            .with_content_capture(true);
+
+        let seen_traceparent = Arc::new(Mutex::new(None));
+        let seen = Arc::clone(&seen_traceparent);
+        let generate = middleware.wrap_generate_async(Arc::new(move |req: ChatRequest| {
+            *seen.lock().unwrap() = req
+                .http_config
+                .as_ref()
+                .and_then(|http| http.headers.get("traceparent").cloned());
+            Box::pin(async { Ok(response()) })
+        }));
+        generate(request()).await.unwrap();
+
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(spans.len(), 1);
+        let span = &spans[0];
+        assert_eq!(span.name, "chat gpt-test");
+        assert_eq!(span.span_kind, SpanKind::Client);
+        assert_eq!(span.status, Status::Ok);
+        assert_eq!(
+            attribute(span, GEN_AI_OPERATION_NAME),
+            Some(&Value::from("chat"))
+        );
+        assert_eq!(attribute(span, GEN_AI_SYSTEM), Some(&Value::from("openai")));
+        assert_eq!(
+            attribute(span, GEN_AI_REQUEST_MODEL),
+            Some(&Value::from("gpt-test"))
+        );
+        assert_eq!(
+            attribute(span, GEN_AI_REQUEST_MAX_TOKENS),
+            Some(&Value::I64(64))
+        );
+        assert_eq!(
+            attribute(span, GEN_AI_RESPONSE_MODEL),
+            Some(&Value::from("gpt-test-2025"))
+        );
+        assert_eq!(
+            attribute(span, GEN_AI_RESPONSE_FINISH_REASONS),
+            Some(&string_array(["stop".to_string()]))
+        );
+        assert_eq!(
+            attribute(span, GEN_AI_USAGE_INPUT_TOKENS),
+            Some(&Value::I64(12))
+        );
+        assert_eq!(
+            attribute(span, GEN_AI_USAGE_OUTPUT_TOKENS),
+            Some(&Value::I64(3))
+        );
+
+        let events: Vec<&str> = span.events.iter().map(|e| e.name.as_ref()).collect();
+        assert_eq!(
+            events,
+            ["gen_ai.system.message", "gen_ai.user.message", CHOICE_EVENT]
+        );
+
+        let traceparent = seen_traceparent.lock().unwrap().clone().unwrap();
+        assert_eq!(
+            traceparent,
+            format!(
+                "00-{:032x}-{:016x}-01",
+                span.span_context.trace_id(),
+                span.span_context.span_id()
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_and_streamed_calls_are_recorded() {
+        let (_provider, exporter, tracer) = tracer();
+        let middleware = OpenTelemetryMiddleware::new().with_tracer(tracer);
+
+        let generate = middleware.wrap_generate_async(Arc::new(|_req: ChatRequest| {
+            Box::pin(async { Err(LlmError::rate_limit_error("slow down")) })
+        }));
+        assert!(generate(request()).await.is_err());
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(
+            attribute(&spans[0], ERROR_TYPE),
+            Some(&Value::from("RateLimitError"))
+        );
+        assert!(matches!(spans[0].status, Status::Error { .. }));
+        // Content is not captured unless enabled.
+        assert!(spans[0].events.is_empty());
+        exporter.reset();
+
+        let stream_fn = middleware.wrap_stream_async(Arc::new(|_req: ChatRequest| {
+            Box::pin(async {
+                let events = vec![
+                    ChatStreamEvent::StreamStart {
+                        metadata: ResponseMetadata {
+                            id: Some("resp_2".to_string()),
+                            model: Some("gpt-test".to_string()),
+                            created: None,
+                            provider: "anthropic".to_string(),
+                            request_id: None,
+                            headers: None,
+                            body: None,
+                        },
+                    },
+                    ChatStreamEvent::text_delta_part("0", "Hel"),
+                    ChatStreamEvent::text_delta_part("0", "lo"),
+                    ChatStreamEvent::finish_part(usage(), FinishReason::Length),
+                ];
+                let stream: ChatStream =
+                    Box::pin(futures::stream::iter(events.into_iter().map(Ok)));
+                Ok(stream)
+            })
+        }));
+        let mut stream = stream_fn(request()).await.unwrap();
+        while stream.next().await.is_some() {}
+        drop(stream);
+
+        let mut stream = stream_fn(request()).await.unwrap();
+        stream.next().await;
+        drop(stream);
+
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(
+            attribute(&spans[0], GEN_AI_SYSTEM),
+            Some(&Value::from("anthropic"))
+        );
+        assert_eq!(
+            attribute(&spans[0], GEN_AI_RESPONSE_FINISH_REASONS),
+            Some(&string_array(["length".to_string()]))
+        );
+        assert_eq!(
+            attribute(&spans[0], GEN_AI_USAGE_OUTPUT_TOKENS),
+            Some(&Value::I64(3))
+        );
+        assert_eq!(
+            attribute(&spans[1], ERROR_TYPE),
+            Some(&Value::from("cancelled"))
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_error_events_fail_the_span() {
+        let (_provider, exporter, tracer) = tracer();
+        let middleware = OpenTelemetryMiddleware::new().with_tracer(tracer);
+
+        let stream_fn = middleware.wrap_stream_async(Arc::new(|_req: ChatRequest| {
+            Box::pin(async {
+                let events = vec![
+                    ChatStreamEvent::text_delta_part("0", "Hel"),
+                    ChatStreamEvent::Error {
+                        error: "upstream reset".to_string(),
+                    },
+                    ChatStreamEvent::StreamEnd {
+                        response: response(),
+                    },
+                ];
+                let stream: ChatStream =
+                    Box::pin(futures::stream::iter(events.into_iter().map(Ok)));
+                Ok(stream)
+            })
+        }));
+        let mut stream = stream_fn(request()).await.unwrap();
+        while stream.next().await.is_some() {}
+
+        let spans = exporter.get_finished_spans().unwrap();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(
+            attribute(&spans[0], ERROR_TYPE),
+            Some(&Value::from("StreamError"))
+        );
+        assert!(
+            matches!(&spans[0].status, Status::Error { description } if description.contains("upstream reset"))
+        );
+    }
+
+    #[tokio::test]
+    async fn metrics_use_gen_ai_instrument_names() {
+        let exporter = InMemoryMetricExporter::default();
+        let provider = SdkMeterProvider::builder()
+            .with_reader(PeriodicReader::builder(exporter.clone()).build())
+            .build();
+        let metrics = GenAiMetrics::with_meter(&opentelemetry::metrics::MeterProvider::meter(
+            &provider, "test",
+        ));
+        let (_tracer_provider, _spans, tracer) = tracer();
+        let middleware = OpenTelemetryMiddleware::with_metrics(metrics).with_tracer(tracer);
+
+        let generate = middleware.wrap_generate_async(Arc::new(|_req: ChatRequest| {
+            Box::pin(async { Ok(response()) })
+        }));
+        generate(request()).await.unwrap();
+        provider.force_flush().unwrap();
+
+        let mut names: Vec<String> = exporter
+            .get_finished_metrics()
+            .unwrap()
+            .iter()
+            .flat_map(|rm| rm.scope_metrics())
+            .flat_map(|sm| sm.metrics())
+            .map(|m| m.name().to_string())
+            .collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(
+            names,
+            [
+                "gen_ai.client.operation.duration",
+                "gen_ai.client.token.usage"
+            ]
+        );
     }
 }
@@ -91,25 +91,55 @@ pub fn validate_json(schema: &Value, instance: &Value) -> Result<()> {
 /// - `Ok(())` if validation succeeds
 /// - `Err(ExtrasError::SchemaValidation)` with all error messages if validation fails
 pub fn validate_json_detailed(schema: &Value, instance: &Value) -> Result<()> {
-    if !schema.is_object() {
-        return Ok(());
+    let violations = validate_json_violations(schema, instance)?;
+    if !violations.is_empty() {
+        let msgs: Vec<String> = violations.iter().map(ToString::to_string).collect();
+        return Err(ExtrasError::SchemaValidation(msgs.join("; ")));
     }
 
-    let compiled = jsonschema::validator_for(schema)
-        .map_err(|e| ExtrasError::SchemaCompilation(format!("Invalid JSON Schema: {}", e)))?;
+    Ok(())
+}
 
-    if compiled.validate(instance).is_err() {
-        let msgs: Vec<String> = compiled
-            .iter_errors(instance)
-            .map(|err| format!("{} at {}", err, err.instance_path()))
-            .collect();
+/// A single JSON Schema violation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaViolation {
+    /// JSON Pointer to the offending value (empty for the root).
+    pub instance_path: String,
+    /// Human-readable description of the violation.
+    pub message: String,
+}
 
-        if !msgs.is_empty() {
-            return Err(ExtrasError::SchemaValidation(msgs.join("; ")));
-        }
+impl std::fmt::Display for SchemaViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at {}", self.message, self.instance_path)
     }
+}
 
-    Ok(())
+/// Collect every violation of a JSON Schema, keeping the instance path separate
+///
+/// This is the structured form of `validate_json_detailed`, useful when errors are
+/// reported back per path (for example in a correction prompt).
+///
+/// ## Returns
+///
+/// - `Ok(vec![])` if validation succeeds
+/// - `Ok(violations)` if validation fails
+/// - `Err(ExtrasError::SchemaCompilation)` if the schema itself is invalid
+pub fn validate_json_violations(schema: &Value, instance: &Value) -> Result<Vec<SchemaViolation>> {
+    if !schema.is_object() {
+        return Ok(Vec::new());
+    }
+
+    let compiled = jsonschema::validator_for(schema)
+        .map_err(|e| ExtrasError::SchemaCompilation(format!("Invalid JSON Schema: {}", e)))?;
+
+    Ok(compiled
+        .iter_errors(instance)
+        .map(|err| SchemaViolation {
+            instance_path: err.instance_path().to_string(),
+            message: err.to_string(),
+        })
+        .collect())
 }
 
 /// A reusable JSON Schema validator
@@ -231,6 +261,28 @@ mod tests {
         assert!(validate_json(&schema, &value).is_err());
     }
 
+    #[test]
+    fn test_validate_json_violations_reports_paths() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "items": { "type": "array", "items": { "type": "integer" } }
+            },
+            "required": ["items", "name"]
+        });
+
+        let violations =
+            validate_json_violations(&schema, &json!({ "items": [1, "two"] })).unwrap();
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().any(|v| v.instance_path == "/items/1"));
+        assert!(
+            violations
+                .iter()
+                .any(|v| v.instance_path.is_empty() && v.message.contains("name"))
+        );
+        assert!(validate_json_detailed(&schema, &json!({ "items": [], "name": 1 })).is_ok());
+    }
+
     #[test]
     fn test_schema_validator() {
         let schema = json!({ "type": "string" });
@@ -0,0 +1,57 @@
+//! Axum route for provider job callbacks.
+
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+};
+use serde_json::{Value, json};
+
+use crate::jobs::{JobTracker, WebhookOutcome};
+
+/// Build a router accepting provider completion callbacks at `POST /{provider}`.
+///
+/// Nest it under any prefix (e.g. `/webhooks/video`) and point the provider's callback URL
+/// (MiniMax `callback_url`, OpenAI webhook endpoint) at `<prefix>/<provider id>`.
+///
+/// Responses:
+/// - verification challenges are echoed as `{"challenge": ...}`
+/// - task notifications return `200 {"received": true, "matched": <tracked>}`
+/// - unrecognized bodies return `400`
+///
+/// OpenAI webhook signatures are not verified; callbacks only trigger a poll, so put the route
+/// behind your own authentication.
+pub fn job_webhook_router(tracker: Arc<JobTracker>) -> Router {
+    Router::new()
+        .route("/{provider}", post(handle_job_webhook))
+        .with_state(tracker)
+}
+
+async fn handle_job_webhook(
+    State(tracker): State<Arc<JobTracker>>,
+    Path(provider): Path<String>,
+    Json(payload): Json<Value>,
+) -> Response {
+    match tracker.handle_webhook(&provider, &payload).await {
+        Ok(WebhookOutcome::Challenge(challenge)) => {
+            Json(json!({ "challenge": challenge })).into_response()
+        }
+        Ok(WebhookOutcome::Accepted { matched, .. }) => {
+            Json(json!({ "received": true, "matched": matched })).into_response()
+        }
+        Ok(WebhookOutcome::Unrecognized) => (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": "unrecognized job callback payload" })),
+        )
+            .into_response(),
+        Err(error) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "error": error.to_string() })),
+        )
+            .into_response(),
+    }
+}
@@ -10,10 +10,14 @@
 //! - **Text Response**: `to_text_stream()` converts `ChatStream` to a plain text stream, and
 //!   `to_text_stream_response()` wraps that stream in an Axum `text/plain; charset=utf-8` response
 //! - **Gateway Helpers**: provider-native request normalization and SSE/JSON transcoding helpers
+//! - **Job Webhooks**: `job_webhook_router()` accepts provider completion callbacks for the
+//!   `jobs` tracker
 //! - **Runtime Helpers**: policy-aware request/upstream body reads
 //! - **Error Handling**: automatic error masking for production environments
 //! - **Type Safety**: strong typing with Axum SSE primitives
 
+#[cfg(feature = "jobs")]
+mod jobs_webhook;
 mod request_normalize;
 mod runtime;
 mod sse;
@@ -26,6 +30,8 @@ pub use crate::bridge::{
     stream_bridge_hook,
 };
 pub use crate::server::GatewayBridgePolicy;
+#[cfg(feature = "jobs")]
+pub use jobs_webhook::job_webhook_router;
 pub use request_normalize::{
     NormalizeRequestOptions, SourceRequestFormat, normalize_request_json,
     normalize_request_json_with_options,
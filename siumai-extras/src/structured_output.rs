@@ -10,12 +10,14 @@
 //! - Mode hints for providers (`GenerateMode`)
 //! - JSON repair and optional schema validation
 //! - Typed / untyped JSON decoding from raw model text
+//! - Model-driven self-correction (`SelfCorrection`): invalid output is described per path
+//!   (`OutputIssue`) and sent back to the model as a follow-up turn
 
 use std::sync::Arc;
 
 use serde::de::DeserializeOwned;
 use serde_json::Value;
-use siumai::prelude::unified::{LlmError, OutputSchema};
+use siumai::prelude::unified::{ChatMessage, LlmError, OutputSchema, Usage};
 
 /// Type alias for JSON repair function used in structured output APIs.
 pub type RepairFn = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
@@ -49,6 +51,72 @@ pub enum GenerateMode {
     Tool,
 }
 
+/// Opt-in self-correction: when the output fails parsing, schema validation or
+/// deserialization, the errors are sent back to the model as a follow-up turn.
+///
+/// Unlike `repair_text`, which patches the text locally, each round is a new model call;
+/// usage of every attempt is accumulated into the returned response.
+#[derive(Debug, Clone)]
+pub struct SelfCorrection {
+    /// Maximum number of follow-up turns after the first attempt.
+    pub max_rounds: usize,
+    /// Extra guidance appended to every correction prompt.
+    pub instructions: Option<String>,
+}
+
+impl Default for SelfCorrection {
+    fn default() -> Self {
+        Self {
+            max_rounds: 2,
+            instructions: None,
+        }
+    }
+}
+
+impl SelfCorrection {
+    /// Allow up to `max_rounds` correction turns.
+    pub fn new(max_rounds: usize) -> Self {
+        Self {
+            max_rounds,
+            instructions: None,
+        }
+    }
+
+    /// Append extra guidance to every correction prompt.
+    pub fn with_instructions(mut self, instructions: impl Into<String>) -> Self {
+        self.instructions = Some(instructions.into());
+        self
+    }
+}
+
+/// One problem found in structured model output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputIssue {
+    /// JSON Pointer to the offending value (empty for the whole output).
+    pub path: String,
+    /// What is wrong at `path`.
+    pub message: String,
+}
+
+impl OutputIssue {
+    fn new(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for OutputIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "(root): {}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
 /// Configuration for decoding structured output, inspired by Vercel AI SDK's
 /// `experimental_output` parameter.
 ///
@@ -100,6 +168,31 @@ impl OutputDecodeConfig {
     }
 }
 
+impl OutputDecodeConfig {
+    /// Config for decoding a single element of an array output.
+    ///
+    /// The element schema is the array schema's `items` (or `properties.elements.items` for
+    /// an `{"elements": [...]}` wrapper); the shape check is dropped.
+    pub(crate) fn array_element(&self) -> Self {
+        let schema = self.schema.as_ref().and_then(|out| {
+            out.schema
+                .get("items")
+                .or_else(|| out.schema.pointer("/properties/elements/items"))
+                .map(|items| OutputSchema {
+                    schema: items.clone(),
+                    name: None,
+                    description: None,
+                })
+        });
+        Self {
+            schema,
+            kind: OutputKind::NoSchema,
+            emit_partial: false,
+            ..self.clone()
+        }
+    }
+}
+
 /// Decode a raw JSON-like string into a `serde_json::Value` according to the
 /// structured output configuration.
 ///
@@ -151,28 +244,7 @@ fn parse_once(text: &str, cfg: &OutputDecodeConfig) -> Result<Value, LlmError> {
     let value: Value = serde_json::from_str(text)
         .map_err(|e| LlmError::ParseError(format!("Failed to parse JSON: {}", e)))?;
 
-    // Shape check from OutputKind.
-    match &cfg.kind {
-        OutputKind::Object => {
-            if !value.is_object() {
-                return Err(LlmError::InvalidParameter("Expected a JSON object".into()));
-            }
-        }
-        OutputKind::Array => {
-            if !value.is_array() {
-                return Err(LlmError::InvalidParameter("Expected a JSON array".into()));
-            }
-        }
-        OutputKind::Enum(allowed) => {
-            if !allowed.is_empty() && !allowed.contains(&value) {
-                return Err(LlmError::InvalidParameter(format!(
-                    "Value not in enum set: {}",
-                    value
-                )));
-            }
-        }
-        OutputKind::NoSchema => {}
-    }
+    check_shape(&value, &cfg.kind).map_err(LlmError::InvalidParameter)?;
 
     // Optional JSON Schema validation via `siumai-extras::schema` when enabled.
     if let Some(out_schema) = &cfg.schema {
@@ -194,6 +266,172 @@ fn parse_once(text: &str, cfg: &OutputDecodeConfig) -> Result<Value, LlmError> {
     Ok(value)
 }
 
+/// Shape check from `OutputKind`.
+fn check_shape(value: &Value, kind: &OutputKind) -> Result<(), String> {
+    match kind {
+        OutputKind::Object if !value.is_object() => Err("Expected a JSON object".into()),
+        OutputKind::Array if !value.is_array() => Err("Expected a JSON array".into()),
+        OutputKind::Enum(allowed) if !allowed.is_empty() && !allowed.contains(value) => {
+            Err(format!("Value not in enum set: {}", value))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Decoding failure together with the per-path issues to report to the model.
+pub(crate) struct DecodeFailure {
+    /// The error `decode_typed` / `decode_json_value` would have returned.
+    pub error: LlmError,
+    /// Located problems, suitable for a correction prompt.
+    pub issues: Vec<OutputIssue>,
+}
+
+/// Like [`decode_typed`], but explains failures as [`OutputIssue`]s.
+#[allow(clippy::result_large_err)]
+pub(crate) fn diagnose_typed<T: DeserializeOwned>(
+    text: &str,
+    cfg: &OutputDecodeConfig,
+) -> Result<T, DecodeFailure> {
+    decode_typed::<T>(text, cfg).map_err(|error| DecodeFailure {
+        issues: output_issues(text, cfg, |value| {
+            serde_path_to_error::deserialize::<_, T>(value)
+                .err()
+                .map(|e| OutputIssue::new(json_pointer(e.path()), e.inner().to_string()))
+        }),
+        error,
+    })
+}
+
+/// Like [`decode_json_value`], but explains failures as [`OutputIssue`]s.
+#[allow(clippy::result_large_err)]
+pub(crate) fn diagnose_json_value(
+    text: &str,
+    cfg: &OutputDecodeConfig,
+) -> Result<Value, DecodeFailure> {
+    decode_json_value(text, cfg).map_err(|error| DecodeFailure {
+        issues: output_issues(text, cfg, |_| None),
+        error,
+    })
+}
+
+/// Locate what is wrong with `text`: syntax, shape, schema, then (via `deserialize`)
+/// the target type.
+fn output_issues(
+    text: &str,
+    cfg: &OutputDecodeConfig,
+    deserialize: impl FnOnce(Value) -> Option<OutputIssue>,
+) -> Vec<OutputIssue> {
+    let value = match best_effort_value(text, cfg) {
+        Ok(value) => value,
+        Err(error) => return vec![OutputIssue::new("", format!("invalid JSON: {error}"))],
+    };
+
+    if let Err(message) = check_shape(&value, &cfg.kind) {
+        return vec![OutputIssue::new("", message)];
+    }
+
+    #[cfg(feature = "schema")]
+    if let Some(out_schema) = &cfg.schema {
+        match crate::schema::validate_json_violations(&out_schema.schema, &value) {
+            Ok(violations) if !violations.is_empty() => {
+                return violations
+                    .into_iter()
+                    .map(|v| OutputIssue::new(v.instance_path, v.message))
+                    .collect();
+            }
+            Ok(_) => {}
+            Err(error) => return vec![OutputIssue::new("", error.to_string())],
+        }
+    }
+
+    deserialize(value).into_iter().collect()
+}
+
+/// Parse `text` as JSON, applying the same repair chain as [`decode_json_value`] but
+/// without shape or schema checks.
+fn best_effort_value(text: &str, cfg: &OutputDecodeConfig) -> Result<Value, serde_json::Error> {
+    let first_error = match serde_json::from_str(text) {
+        Ok(value) => return Ok(value),
+        Err(error) => error,
+    };
+    let mut current = text.to_string();
+    for _ in 0..cfg.max_repair_rounds {
+        let next = match &cfg.repair_text {
+            Some(repair) => repair(&current),
+            None => default_repair_text(&current),
+        };
+        let Some(next) = next else { break };
+        if let Ok(value) = serde_json::from_str(&next) {
+            return Ok(value);
+        }
+        current = next;
+    }
+    Err(first_error)
+}
+
+fn json_pointer(path: &serde_path_to_error::Path) -> String {
+    use serde_path_to_error::Segment;
+
+    path.iter()
+        .filter_map(|segment| match segment {
+            Segment::Seq { index } => Some(index.to_string()),
+            Segment::Map { key } => Some(key.replace('~', "~0").replace('/', "~1")),
+            Segment::Enum { variant } => Some(variant.clone()),
+            Segment::Unknown => None,
+        })
+        .fold(String::new(), |mut pointer, token| {
+            pointer.push('/');
+            pointer.push_str(&token);
+            pointer
+        })
+}
+
+/// Follow-up user turn asking the model to fix its previous output.
+pub(crate) fn correction_message(
+    issues: &[OutputIssue],
+    schema: Option<&OutputSchema>,
+    correction: &SelfCorrection,
+) -> ChatMessage {
+    ChatMessage::user(correction_prompt(issues, schema, correction)).build()
+}
+
+/// Text asking the model to fix its previous output.
+pub(crate) fn correction_prompt(
+    issues: &[OutputIssue],
+    schema: Option<&OutputSchema>,
+    correction: &SelfCorrection,
+) -> String {
+    let mut prompt = String::from(
+        "Your previous output could not be accepted. Fix the following problems and reply with \
+         the corrected JSON only:\n",
+    );
+    for issue in issues {
+        prompt.push_str("- ");
+        prompt.push_str(&issue.to_string());
+        prompt.push('\n');
+    }
+    if let Some(schema) = schema {
+        prompt.push_str("\nThe output must match this JSON Schema:\n");
+        prompt.push_str(&schema.schema.to_string());
+        prompt.push('\n');
+    }
+    if let Some(instructions) = &correction.instructions {
+        prompt.push('\n');
+        prompt.push_str(instructions);
+    }
+    prompt.trim_end().to_string()
+}
+
+/// Add `usage` to a running total.
+pub(crate) fn accumulate_usage(total: &mut Option<Usage>, usage: Option<&Usage>) {
+    if let Some(usage) = usage {
+        match total {
+            Some(total) => total.merge(usage),
+            None => *total = Some(usage.clone()),
+        }
+    }
+}
+
 /// Default lightweight repair: strip markdown fences, trim to a balanced JSON
 /// slice, and remove trailing commas before `}`/`]`.
 pub(crate) fn default_repair_text(text: &str) -> Option<String> {
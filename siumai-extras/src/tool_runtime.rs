@@ -1,5 +1,6 @@
 use std::collections::{HashMap, HashSet};
 
+use crate::orchestrator::spans::SpanScope;
 use crate::orchestrator::{OrchestratorContext, ToolResolver};
 use futures::StreamExt;
 use serde_json::{Value, json};
@@ -262,36 +263,49 @@ pub(crate) async fn execute_local_tool_call(call: LocalToolCallExecution<'_>) ->
     let input = execution_args.clone();
     let execution_options =
         build_tool_execution_options(tool_call_id, step_input_messages, context, abort_signal);
-    let out_val = match resolver
-        .call_tool_stream_with_runtime_options(tool_name, execution_args, execution_options)
-        .await
-    {
-        Ok(mut stream) => {
-            let mut final_output = None;
-
-            while let Some(result) = stream.next().await {
-                match result {
-                    Ok(tool_result) => {
-                        if tool_result.is_preliminary() {
-                            if let Some(callback) = on_preliminary_tool_result {
-                                callback(tool_name, tool_call_id, tool_result.output());
+    let span = SpanScope::tool(tool_name, tool_call_id);
+    let out_val = span
+        .instrument(async {
+            match resolver
+                .call_tool_stream_with_runtime_options(tool_name, execution_args, execution_options)
+                .await
+            {
+                Ok(mut stream) => {
+                    let mut final_output = None;
+
+                    while let Some(result) = stream.next().await {
+                        match result {
+                            Ok(tool_result) => {
+                                if tool_result.is_preliminary() {
+                                    if let Some(callback) = on_preliminary_tool_result {
+                                        callback(tool_name, tool_call_id, tool_result.output());
+                                    }
+                                } else {
+                                    final_output = Some(tool_result.into_output());
+                                }
+                            }
+                            Err(error) => {
+                                span.record_llm_error(&error);
+                                final_output =
+                                    Some(Value::String(format!("<tool error: {}>", error)));
+                                break;
                             }
-                        } else {
-                            final_output = Some(tool_result.into_output());
                         }
                     }
-                    Err(error) => {
-                        final_output = Some(Value::String(format!("<tool error: {}>", error)));
-                        break;
-                    }
+
+                    final_output.unwrap_or_else(|| {
+                        span.record_error("no_final_result", "tool produced no final result");
+                        Value::String("<tool error: no final result>".to_string())
+                    })
+                }
+                Err(error) => {
+                    span.record_llm_error(&error);
+                    Value::String(format!("<tool error: {}>", error))
                 }
             }
-
-            final_output
-                .unwrap_or_else(|| Value::String("<tool error: no final result>".to_string()))
-        }
-        Err(error) => Value::String(format!("<tool error: {}>", error)),
-    };
+        })
+        .await;
+    span.end();
 
     let mut part =
         ContentPart::tool_result_json(tool_call_id.to_string(), tool_name.to_string(), out_val)
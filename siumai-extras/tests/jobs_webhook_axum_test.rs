@@ -0,0 +1,61 @@
+#![cfg(all(feature = "server", feature = "jobs"))]
+
+use std::sync::Arc;
+
+use axum::{
+    body::{Body, to_bytes},
+    http::{Request, StatusCode, header},
+};
+use serde_json::{Value, json};
+use siumai::video::VideoGenerationRequest;
+use siumai_extras::jobs::{InMemoryJobStore, JobTracker};
+use siumai_extras::server::axum::job_webhook_router;
+use tower::ServiceExt;
+
+async fn post(tracker: &Arc<JobTracker>, path: &str, body: Value) -> (StatusCode, Value) {
+    let response = job_webhook_router(tracker.clone())
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri(path)
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .expect("request"),
+        )
+        .await
+        .expect("response");
+    let status = response.status();
+    let bytes = to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body bytes");
+    (status, serde_json::from_slice(&bytes).expect("json body"))
+}
+
+#[tokio::test]
+async fn job_webhook_route_echoes_challenge_and_accepts_task_callbacks() {
+    let tracker = Arc::new(JobTracker::new(Arc::new(InMemoryJobStore::new())));
+    tracker
+        .track(
+            "minimaxi",
+            "task-1",
+            &VideoGenerationRequest::new("hailuo", "A sunset"),
+        )
+        .await
+        .expect("track");
+
+    let (status, body) = post(&tracker, "/minimaxi", json!({ "challenge": "xyz" })).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!({ "challenge": "xyz" }));
+
+    let (status, body) = post(
+        &tracker,
+        "/minimaxi",
+        json!({ "task_id": "task-1", "status": "success" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, json!({ "received": true, "matched": true }));
+
+    let (status, _) = post(&tracker, "/minimaxi", json!({ "status": "success" })).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use crate::error::LlmError;
+use crate::execution::middleware::presets::{PromptCacheMarker, PromptCacheTtl};
 use crate::types::{ChatMessage, ContentPart, FilePartSource, MediaSource, MessageContent};
 use base64::Engine;
 
@@ -81,6 +82,67 @@ pub enum CacheType {
     Ephemeral,
 }
 
+/// Prompt-cache marker for automatic breakpoint placement.
+///
+/// Used with `PromptCacheMiddleware`; breakpoints are written as
+/// `providerOptions.<namespace>.cacheControl` on messages and function tools.
+#[derive(Debug, Clone)]
+pub struct AnthropicPromptCacheMarker {
+    namespace: String,
+}
+
+impl Default for AnthropicPromptCacheMarker {
+    fn default() -> Self {
+        Self {
+            namespace: "anthropic".to_string(),
+        }
+    }
+}
+
+impl AnthropicPromptCacheMarker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the provider options namespace (for Anthropic-compatible providers).
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+}
+
+impl PromptCacheMarker for AnthropicPromptCacheMarker {
+    fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    fn marker(&self, ttl: Option<PromptCacheTtl>) -> serde_json::Map<String, serde_json::Value> {
+        let mut cache_control = serde_json::json!({ "type": "ephemeral" });
+        if let Some(ttl) = ttl {
+            cache_control["ttl"] = serde_json::json!(ttl.as_str());
+        }
+        serde_json::Map::from_iter([("cacheControl".to_string(), cache_control)])
+    }
+
+    fn has_marker(&self, options: &serde_json::Map<String, serde_json::Value>) -> bool {
+        options.contains_key("cacheControl") || options.contains_key("cache_control")
+    }
+
+    /// Minimum cacheable prompt length per model family.
+    ///
+    /// Reference: <https://docs.anthropic.com/en/docs/build-with-claude/prompt-caching#cache-limitations>
+    fn min_cacheable_tokens(&self, model: &str) -> u32 {
+        let model = model.to_ascii_lowercase();
+        if model.contains("haiku-4-5") || model.contains("opus-4-5") {
+            4096
+        } else if model.contains("haiku") {
+            2048
+        } else {
+            1024
+        }
+    }
+}
+
 /// Cache-aware message builder
 pub struct CacheAwareMessageBuilder {
     /// Base message
@@ -420,4 +482,48 @@ mod tests {
         assert!(content[0].get("cache_control").is_none());
         assert_eq!(content[1]["cache_control"]["type"], "ephemeral");
     }
+
+    #[test]
+    fn prompt_cache_middleware_places_cache_control_breakpoints() {
+        use crate::execution::middleware::LanguageModelMiddleware;
+        use crate::execution::middleware::presets::PromptCacheMiddleware;
+        use crate::types::{ChatRequest, Tool};
+
+        let long = "cached context ".repeat(400);
+        let req = ChatRequest::new(vec![
+            ChatMessage::system(long).build(),
+            ChatMessage::user("hello").build(),
+        ])
+        .with_tools(vec![Tool::function(
+            "lookup",
+            "Look something up",
+            serde_json::json!({ "type": "object" }),
+        )]);
+
+        let mw = PromptCacheMiddleware::new(std::sync::Arc::new(AnthropicPromptCacheMarker::new()))
+            .with_strategy(
+                crate::execution::middleware::presets::PromptCacheStrategy::new()
+                    .with_ttl(PromptCacheTtl::OneHour),
+            );
+        let req = mw.transform_params(req);
+
+        // The tool block alone is below the 1024-token minimum.
+        let Tool::Function { function } = &req.tools.as_ref().unwrap()[0] else {
+            panic!("expected function tool");
+        };
+        assert!(function.provider_options_map.get("anthropic").is_none());
+        assert_eq!(
+            req.messages[0].provider_options.get("anthropic"),
+            Some(&serde_json::json!({ "cacheControl": { "type": "ephemeral", "ttl": "1h" } }))
+        );
+        assert!(req.messages[1].provider_options.get("anthropic").is_some());
+    }
+
+    #[test]
+    fn prompt_cache_marker_uses_model_minimums() {
+        let marker = AnthropicPromptCacheMarker::new();
+        assert_eq!(marker.min_cacheable_tokens("claude-sonnet-4-5"), 1024);
+        assert_eq!(marker.min_cacheable_tokens("claude-3-5-haiku-latest"), 2048);
+        assert_eq!(marker.min_cacheable_tokens("claude-haiku-4-5"), 4096);
+    }
 }
@@ -1,4 +1,4 @@
-use crate::error::LlmError;
+use crate::error::{HttpErrorDetails, LlmError, normalize_provider_failure};
 use serde_json::Value;
 
 /// Classify Anthropic HTTP errors by parsing the structured error envelope.
@@ -6,6 +6,9 @@ use serde_json::Value;
 /// Anthropic typically returns:
 /// `{ "type": "error", "error": { "type": "...", "message": "..." } }`
 ///
+/// `overloaded_error` maps to `LlmError::Overloaded` and "prompt is too long" invalid requests
+/// map to `LlmError::ContextLengthExceeded`.
+///
 /// Returns `None` when the body doesn't match the Anthropic envelope so callers
 /// can fall back to the generic classifier.
 pub fn classify_anthropic_http_error(
@@ -29,24 +32,38 @@ pub fn classify_anthropic_http_error(
             LlmError::AuthenticationError(format!("Permission denied: {error_message}"))
         }
         "not_found_error" => LlmError::NotFound(error_message.to_string()),
-        "rate_limit_error" => LlmError::RateLimitError(error_message.to_string()),
-        "invalid_request_error" => LlmError::InvalidInput(error_message.to_string()),
-        "overloaded_error" => LlmError::ApiError {
-            // Vercel AI SDK parity: treat overloaded as a synthetic 529 so callers can
-            // distinguish it from generic 5xx errors while keeping retry semantics.
-            code: 529,
-            message: format!("{provider} service overloaded: {error_message}"),
-            details: Some(details),
-        },
+        "rate_limit_error" => LlmError::rate_limit_error(error_message.to_string()),
+        // `invalid_request_error` is always a 400, even when it arrives inside a stream.
+        "invalid_request_error" => normalize_provider_failure(400, &[], error_message)
+            .map(|error| {
+                error.with_http_details(
+                    HttpErrorDetails::new()
+                        .with_status(status)
+                        .with_error_type(error_type),
+                )
+            })
+            .unwrap_or_else(|| LlmError::InvalidInput(error_message.to_string())),
+        // Vercel AI SDK parity: report overloaded as a synthetic 529 (`status_code()`) so
+        // callers can distinguish it from generic 5xx errors while keeping retry semantics.
+        "overloaded_error" => {
+            LlmError::overloaded(format!("{provider} service overloaded: {error_message}"))
+                .with_http_details(
+                    HttpErrorDetails::new()
+                        .with_status(529)
+                        .with_error_type(error_type),
+                )
+        }
         "api_error" => LlmError::ApiError {
             code: status,
             message: format!("{provider} API error: {error_message}"),
             details: Some(details),
+            http_details: None,
         },
         other => LlmError::ApiError {
             code: status,
             message: format!("{provider} error ({other}): {error_message}"),
             details: Some(details),
+            http_details: None,
         },
     };
 
@@ -74,7 +91,7 @@ mod tests {
             r#"{"type":"error","error":{"type":"rate_limit_error","message":"Rate limited"}}"#;
         let err = classify_anthropic_http_error("anthropic", 429, body).expect("classified");
         match err {
-            LlmError::RateLimitError(msg) => assert_eq!(msg, "Rate limited"),
+            LlmError::RateLimitError { message: msg, .. } => assert_eq!(msg, "Rate limited"),
             other => panic!("unexpected error variant: {other:?}"),
         }
     }
@@ -84,13 +101,36 @@ mod tests {
         let body = r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#;
         let err = classify_anthropic_http_error("anthropic", 200, body).expect("classified");
         assert!(err.is_retryable());
+        assert_eq!(err.status_code(), Some(529));
         match &err {
-            LlmError::ApiError { code, message, .. } => {
-                assert_eq!(*code, 529);
-                assert!(message.contains("Overloaded"));
+            LlmError::Overloaded { message, .. } => assert!(message.contains("Overloaded")),
+            other => panic!("unexpected error variant: {other:?}"),
+        }
+        assert_eq!(
+            err.http_details().and_then(|d| d.error_type.as_deref()),
+            Some("overloaded_error")
+        );
+    }
+
+    #[test]
+    fn anthropic_error_mapping_prompt_too_long_is_context_length() {
+        let body = r#"{"type":"error","error":{"type":"invalid_request_error","message":"prompt is too long: 215337 tokens > 200000 maximum"},"request_id":"req_011CSHoEeqs5C35K2UUqR7Fy"}"#;
+        let err = classify_anthropic_http_error("anthropic", 400, body).expect("classified");
+        assert!(!err.is_retryable());
+        match &err {
+            LlmError::ContextLengthExceeded { message, .. } => {
+                assert!(message.contains("prompt is too long"))
             }
             other => panic!("unexpected error variant: {other:?}"),
         }
+        assert_eq!(err.status_code(), Some(400));
+    }
+
+    #[test]
+    fn anthropic_error_mapping_plain_invalid_request_stays_invalid_input() {
+        let body = r#"{"type":"error","error":{"type":"invalid_request_error","message":"max_tokens: Field required"}}"#;
+        let err = classify_anthropic_http_error("anthropic", 400, body).expect("classified");
+        assert!(matches!(err, LlmError::InvalidInput(_)), "{err:?}");
     }
 
     #[test]
@@ -1,6 +1,7 @@
 use super::*;
 #[cfg(test)]
 use crate::error::LlmErrorExt;
+use crate::error::{HttpErrorDetails, normalize_provider_failure};
 
 pub fn map_anthropic_error(
     status_code: u16,
@@ -13,27 +14,40 @@ pub fn map_anthropic_error(
         "permission_error" => {
             LlmError::AuthenticationError(format!("Permission denied: {error_message}"))
         }
-        "invalid_request_error" => LlmError::InvalidInput(error_message.to_string()),
+        "invalid_request_error" => normalize_provider_failure(400, &[], error_message)
+            .map(|error| {
+                error.with_http_details(
+                    HttpErrorDetails::new()
+                        .with_status(status_code)
+                        .with_error_type(error_type),
+                )
+            })
+            .unwrap_or_else(|| LlmError::InvalidInput(error_message.to_string())),
         "not_found_error" => LlmError::NotFound(error_message.to_string()),
         "request_too_large" => {
             LlmError::InvalidInput(format!("Request too large: {error_message}"))
         }
-        "rate_limit_error" => LlmError::RateLimitError(error_message.to_string()),
+        "rate_limit_error" => LlmError::rate_limit_error(error_message.to_string()),
         "api_error" => LlmError::ApiError {
             code: status_code,
             message: format!("Anthropic API error: {error_message}"),
             details: Some(error_details),
+            http_details: None,
         },
-        "overloaded_error" => LlmError::ApiError {
-            // Vercel AI SDK parity: represent overloaded as a synthetic 529.
-            code: 529,
-            message: format!("Anthropic service overloaded: {error_message}"),
-            details: Some(error_details),
-        },
+        // Vercel AI SDK parity: report overloaded as a synthetic 529 (`status_code()`).
+        "overloaded_error" => {
+            LlmError::overloaded(format!("Anthropic service overloaded: {error_message}"))
+                .with_http_details(
+                    HttpErrorDetails::new()
+                        .with_status(529)
+                        .with_error_type(error_type),
+                )
+        }
         _ => LlmError::ApiError {
             code: status_code,
             message: format!("Anthropic API error ({error_type}): {error_message}"),
             details: Some(error_details),
+            http_details: None,
         },
     }
 }
@@ -50,10 +64,22 @@ mod error_mapping_tests {
             "Overloaded",
             serde_json::json!({"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}),
         );
-        match err {
-            LlmError::ApiError { code, .. } => assert_eq!(code, 529),
-            other => panic!("unexpected error variant: {other:?}"),
-        }
+        assert!(matches!(err, LlmError::Overloaded { .. }), "{err:?}");
+        assert_eq!(err.status_code(), Some(529));
         assert!(err.is_retryable());
     }
+
+    #[test]
+    fn map_anthropic_error_prompt_too_long_is_context_length() {
+        let err = map_anthropic_error(
+            400,
+            "invalid_request_error",
+            "prompt is too long: 215337 tokens > 200000 maximum",
+            serde_json::json!({"type":"error","error":{"type":"invalid_request_error","message":"prompt is too long: 215337 tokens > 200000 maximum"}}),
+        );
+        assert!(
+            matches!(err, LlmError::ContextLengthExceeded { .. }),
+            "{err:?}"
+        );
+    }
 }
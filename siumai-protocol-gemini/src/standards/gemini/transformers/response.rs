@@ -4,6 +4,51 @@ use super::*;
 mod tests_gemini_metadata {
     use super::*;
 
+    #[test]
+    fn gemini_blocked_prompt_maps_to_content_filtered() {
+        let cfg = GeminiConfig::default()
+            .with_model("gemini-2.0-flash".into())
+            .with_base_url("https://example".into());
+        let tx = GeminiResponseTransformer { config: cfg };
+
+        let raw = serde_json::json!({
+            "promptFeedback": {
+                "blockReason": "SAFETY",
+                "safetyRatings": [
+                    { "category": "HARM_CATEGORY_DANGEROUS_CONTENT", "probability": "HIGH" }
+                ]
+            },
+            "usageMetadata": { "promptTokenCount": 12, "totalTokenCount": 12 },
+            "modelVersion": "gemini-2.0-flash"
+        });
+        let err = tx.transform_chat_response(&raw).unwrap_err();
+        assert!(matches!(err, LlmError::ContentFiltered { .. }), "{err:?}");
+        assert_eq!(
+            err.http_details().and_then(|d| d.provider_code.as_deref()),
+            Some("SAFETY")
+        );
+    }
+
+    #[test]
+    fn gemini_safety_stopped_candidate_maps_to_content_filtered() {
+        let cfg = GeminiConfig::default()
+            .with_model("gemini-2.0-flash".into())
+            .with_base_url("https://example".into());
+        let tx = GeminiResponseTransformer { config: cfg };
+
+        let raw = serde_json::json!({
+            "candidates": [{
+                "finishReason": "SAFETY",
+                "index": 0,
+                "safetyRatings": [
+                    { "category": "HARM_CATEGORY_HARASSMENT", "probability": "HIGH", "blocked": true }
+                ]
+            }]
+        });
+        let err = tx.transform_chat_response(&raw).unwrap_err();
+        assert!(matches!(err, LlmError::ContentFiltered { .. }), "{err:?}");
+    }
+
     fn chat_response_source() -> &'static str {
         let source = include_str!("response.rs");
         let (_, after_start) = source
@@ -667,6 +712,22 @@ impl ResponseTransformer for GeminiResponseTransformer {
             .map_err(|e| LlmError::ParseError(format!("Invalid Gemini response: {e}")))?;
 
         if response.candidates.is_empty() {
+            // A blocked prompt comes back as a 200 with `promptFeedback.blockReason` and no
+            // candidates.
+            if let Some(reason) = response
+                .prompt_feedback
+                .as_ref()
+                .and_then(|feedback| feedback.block_reason.as_ref())
+                .and_then(|reason| serde_json::to_value(reason).ok())
+                .and_then(|reason| reason.as_str().map(str::to_string))
+            {
+                return Err(LlmError::content_filtered(format!(
+                    "Prompt blocked by Gemini: {reason}"
+                ))
+                .with_http_details(
+                    crate::error::HttpErrorDetails::new().with_provider_code(reason),
+                ));
+            }
             return Err(LlmError::api_error(400, "No candidates in response"));
         }
 
@@ -674,10 +735,19 @@ impl ResponseTransformer for GeminiResponseTransformer {
 
         let candidate = &response.candidates[0];
         let raw_finish_reason = raw_candidate_finish_reason(raw);
-        let content = candidate
-            .content
-            .as_ref()
-            .ok_or_else(|| LlmError::api_error(400, "No content in candidate"))?;
+        let content = candidate.content.as_ref().ok_or_else(|| {
+            // Candidates stopped by safety filters carry a finish reason and no content.
+            match raw_finish_reason.as_deref() {
+                Some(
+                    reason @ ("SAFETY" | "BLOCKLIST" | "PROHIBITED_CONTENT" | "SPII"
+                    | "IMAGE_SAFETY"),
+                ) => LlmError::content_filtered(format!("Response blocked by Gemini: {reason}"))
+                    .with_http_details(
+                        crate::error::HttpErrorDetails::new().with_provider_code(reason),
+                    ),
+                _ => LlmError::api_error(400, "No content in candidate"),
+            }
+        })?;
 
         let mut text_content = String::new();
         let mut content_parts = Vec::new();
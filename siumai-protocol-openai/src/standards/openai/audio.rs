@@ -7,7 +7,7 @@
 
 use crate::error::LlmError;
 use crate::execution::transformers::audio::{AudioHttpBody, AudioTransformer};
-use crate::types::{ProviderOptionsMap, SttRequest, TtsRequest};
+use crate::types::{ProviderOptionsMap, SttRequest, SttSegment, TtsRequest};
 use std::borrow::Cow;
 
 #[derive(Debug, Clone)]
@@ -301,6 +301,37 @@ fn build_stt_body_impl(
     Ok(AudioHttpBody::Multipart(form))
 }
 
+/// Parse typed transcript segments from an OpenAI-style STT JSON payload.
+///
+/// Covers Whisper `verbose_json` segments (`avg_logprob`, `no_speech_prob`) and
+/// `diarized_json` segments (`speaker`). Returns `None` when the payload has no
+/// `segments` array.
+pub fn parse_stt_segments(json: &serde_json::Value) -> Option<Vec<SttSegment>> {
+    let segments = json.get("segments")?.as_array()?;
+    Some(
+        segments
+            .iter()
+            .filter_map(|item| {
+                let obj = item.as_object()?;
+                let float = |key: &str| obj.get(key).and_then(|v| v.as_f64()).map(|v| v as f32);
+                let speaker = match obj.get("speaker") {
+                    Some(serde_json::Value::String(s)) if !s.is_empty() => Some(s.clone()),
+                    Some(serde_json::Value::Number(n)) => Some(n.to_string()),
+                    _ => None,
+                };
+                Some(SttSegment {
+                    text: obj.get("text")?.as_str()?.trim().to_string(),
+                    start: float("start")?,
+                    end: float("end")?,
+                    avg_logprob: float("avg_logprob"),
+                    no_speech_prob: float("no_speech_prob"),
+                    speaker,
+                })
+            })
+            .collect(),
+    )
+}
+
 /// OpenAI Audio Transformer for TTS and STT.
 #[derive(Clone)]
 pub struct OpenAiAudioTransformer;
@@ -431,6 +462,38 @@ mod tests {
         assert_eq!(tx.parse_stt_response(&json).unwrap(), "hi");
     }
 
+    #[test]
+    fn parse_stt_segments_reads_verbose_and_diarized_shapes() {
+        let verbose = serde_json::json!({
+            "text": "hello world",
+            "segments": [{
+                "id": 0, "seek": 0, "start": 0.0, "end": 1.5, "text": " hello world",
+                "avg_logprob": -0.25, "no_speech_prob": 0.01
+            }]
+        });
+        let segments = parse_stt_segments(&verbose).expect("segments");
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].text, "hello world");
+        assert_eq!(segments[0].avg_logprob, Some(-0.25));
+        assert_eq!(segments[0].no_speech_prob, Some(0.01));
+        assert_eq!(segments[0].speaker, None);
+
+        let diarized = serde_json::json!({
+            "segments": [
+                { "type": "transcript.text.segment", "start": 0.0, "end": 1.0, "text": "Hi.", "speaker": "A" },
+                { "type": "transcript.text.segment", "start": 1.0, "end": 2.0, "text": "Hey.", "speaker": "B" }
+            ]
+        });
+        let speakers: Vec<_> = parse_stt_segments(&diarized)
+            .expect("segments")
+            .into_iter()
+            .map(|segment| segment.speaker)
+            .collect();
+        assert_eq!(speakers, vec![Some("A".to_string()), Some("B".to_string())]);
+
+        assert!(parse_stt_segments(&serde_json::json!({ "text": "hi" })).is_none());
+    }
+
     #[test]
     fn configurable_provider_id_reports_custom_id() {
         let tx = OpenAiAudioTransformerWithProviderId::new("openai-compatible");
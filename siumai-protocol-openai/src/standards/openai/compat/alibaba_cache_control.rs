@@ -3,6 +3,7 @@
 //! Alibaba's OpenAI-compatible chat surface accepts `cache_control` markers on text and image
 //! content parts. AI SDK exposes those markers through message/part `providerOptions.alibaba`.
 
+use crate::execution::middleware::presets::{PromptCacheMarker, PromptCacheTarget, PromptCacheTtl};
 use crate::types::{
     ChatMessage, ChatRequest, ContentPart, MessageContent, MessageRole, ProviderOptionsMap, Warning,
 };
@@ -101,6 +102,61 @@ pub fn supports_alibaba_cache_control(provider_id: &str) -> bool {
     )
 }
 
+/// Prompt-cache marker for automatic Alibaba/Qwen breakpoint placement.
+///
+/// Used with `PromptCacheMiddleware`; breakpoints are written as
+/// `providerOptions.<namespace>.cacheControl` on messages. Alibaba has no tool-level markers
+/// and no TTL selection, so tools are skipped and the TTL is ignored.
+#[derive(Debug, Clone)]
+pub struct AlibabaPromptCacheMarker {
+    namespace: String,
+}
+
+impl Default for AlibabaPromptCacheMarker {
+    fn default() -> Self {
+        Self {
+            namespace: "alibaba".to_string(),
+        }
+    }
+}
+
+impl AlibabaPromptCacheMarker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the provider options namespace (for example `qwen`).
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+}
+
+impl PromptCacheMarker for AlibabaPromptCacheMarker {
+    fn namespace(&self) -> &str {
+        &self.namespace
+    }
+
+    fn marker(&self, _ttl: Option<PromptCacheTtl>) -> serde_json::Map<String, serde_json::Value> {
+        serde_json::Map::from_iter([(
+            "cacheControl".to_string(),
+            serde_json::json!({ "type": "ephemeral" }),
+        )])
+    }
+
+    fn has_marker(&self, options: &serde_json::Map<String, serde_json::Value>) -> bool {
+        options.contains_key("cacheControl") || options.contains_key("cache_control")
+    }
+
+    fn supports(&self, target: PromptCacheTarget) -> bool {
+        !matches!(target, PromptCacheTarget::Tools)
+    }
+
+    fn max_breakpoints(&self) -> usize {
+        MAX_CACHE_BREAKPOINTS
+    }
+}
+
 /// Compute AI SDK-style prompt-cache warnings for Alibaba/Qwen chat requests.
 pub fn cache_control_warnings(provider_id: &str, req: &ChatRequest) -> Vec<Warning> {
     if !supports_alibaba_cache_control(provider_id) {
@@ -376,4 +432,27 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn prompt_cache_marker_marks_messages_without_ttl() {
+        use super::AlibabaPromptCacheMarker;
+        use crate::execution::middleware::presets::{PromptCacheStrategy, PromptCacheTtl};
+        use crate::types::{ChatMessage, ChatRequest};
+
+        let req = ChatRequest::new(vec![
+            ChatMessage::system("stable instructions ".repeat(400)).build(),
+            ChatMessage::user("hi").build(),
+        ]);
+        let req = PromptCacheStrategy::new()
+            .with_ttl(PromptCacheTtl::OneHour)
+            .apply(req, &AlibabaPromptCacheMarker::new());
+
+        for message in &req.messages {
+            assert_eq!(
+                message.provider_options.get("alibaba"),
+                Some(&serde_json::json!({ "cacheControl": { "type": "ephemeral" } }))
+            );
+        }
+        assert!(super::cache_control_warnings("alibaba", &req).is_empty());
+    }
 }
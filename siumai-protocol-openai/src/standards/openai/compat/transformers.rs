@@ -296,6 +296,7 @@ impl ResponseTransformer for CompatResponseTransformer {
                 code: 500,
                 message: "No choices in response".into(),
                 details: None,
+                http_details: None,
             })?;
 
         let content = if let Some(c) = choice.message.content {
@@ -1,4 +1,4 @@
-use crate::error::LlmError;
+use crate::error::{HttpErrorDetails, LlmError, normalize_provider_failure};
 use serde_json::Value;
 
 /// Classify OpenAI-compatible HTTP errors by parsing the standard error envelope.
@@ -6,6 +6,10 @@ use serde_json::Value;
 /// OpenAI-style APIs typically return:
 /// `{ "error": { "message": "...", "type": "...", "code": "..." } }`
 ///
+/// Context-length (`context_length_exceeded`), content-filter (`content_filter`,
+/// `content_policy_violation`, Azure's `ResponsibleAIPolicyViolation` inner error) and overload
+/// failures map to the normalized `LlmError` kinds.
+///
 /// Returns `None` when the body doesn't match the OpenAI envelope so callers
 /// can fall back to the generic classifier.
 pub fn classify_openai_compatible_http_error(
@@ -27,13 +31,32 @@ pub fn classify_openai_compatible_http_error(
         _ => None,
     });
 
+    let inner_code = error_obj
+        .get("innererror")
+        .and_then(|v| v.get("code"))
+        .and_then(|v| v.as_str());
+    if let Some(normalized) = normalize_provider_failure(
+        status,
+        &[error_code.as_deref(), error_type, inner_code],
+        message,
+    ) {
+        let mut details = HttpErrorDetails::new().with_status(status);
+        if let Some(code) = error_code.as_deref().or(inner_code) {
+            details = details.with_provider_code(code);
+        }
+        if let Some(error_type) = error_type {
+            details = details.with_error_type(error_type);
+        }
+        return Some(normalized.with_http_details(details));
+    }
+
     let details = json.clone();
 
     // Prefer structured `type`, otherwise fall back to message heuristics.
     let mapped = match error_type.unwrap_or("") {
         "authentication_error" => LlmError::AuthenticationError(message.to_string()),
-        "rate_limit_error" => LlmError::RateLimitError(message.to_string()),
-        "insufficient_quota" => LlmError::QuotaExceededError(message.to_string()),
+        "rate_limit_error" => LlmError::rate_limit_error(message.to_string()),
+        "insufficient_quota" => LlmError::quota_exceeded_error(message.to_string()),
         "invalid_request_error" => LlmError::InvalidInput(message.to_string()),
         "not_found_error" => LlmError::NotFound(message.to_string()),
         "" => map_openai_message_heuristics(provider, status, message, details, error_code),
@@ -41,6 +64,7 @@ pub fn classify_openai_compatible_http_error(
             code: status,
             message: format!("{provider} API error ({other}): {message}"),
             details: Some(details),
+            http_details: None,
         },
     };
 
@@ -61,11 +85,11 @@ fn map_openai_message_heuristics(
     }
 
     if status == 429 || lower.contains("rate limit") || lower.contains("ratelimit") {
-        return LlmError::RateLimitError(message.to_string());
+        return LlmError::rate_limit_error(message.to_string());
     }
 
     if lower.contains("quota") || lower.contains("insufficient_quota") {
-        return LlmError::QuotaExceededError(message.to_string());
+        return LlmError::quota_exceeded_error(message.to_string());
     }
 
     if status == 404 {
@@ -86,6 +110,7 @@ fn map_openai_message_heuristics(
             code: status,
             message: format!("{provider} API error: {message}"),
             details: Some(details),
+            http_details: None,
         },
     }
 }
@@ -110,7 +135,7 @@ mod tests {
         let body = r#"{"error":{"message":"You exceeded your current quota","type":"insufficient_quota","code":"insufficient_quota"}}"#;
         let err = classify_openai_compatible_http_error("openai", 429, body).expect("classified");
         match err {
-            LlmError::QuotaExceededError(msg) => assert!(msg.contains("quota")),
+            LlmError::QuotaExceededError { message: msg, .. } => assert!(msg.contains("quota")),
             other => panic!("unexpected error variant: {other:?}"),
         }
     }
@@ -139,9 +164,37 @@ mod tests {
             r#"{"error":{"message":"{\"error\":{\"status\":\"RESOURCE_EXHAUSTED\"}}","type":""}}"#;
         let err = classify_openai_compatible_http_error("openai", 429, body).expect("classified");
         match err {
-            LlmError::RateLimitError(msg) => assert!(msg.contains("RESOURCE_EXHAUSTED")),
+            LlmError::RateLimitError { message: msg, .. } => {
+                assert!(msg.contains("RESOURCE_EXHAUSTED"))
+            }
+            other => panic!("unexpected error variant: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn openai_error_mapping_context_length_exceeded() {
+        let body = r#"{"error":{"message":"This model's maximum context length is 128000 tokens. However, your messages resulted in 130512 tokens. Please reduce the length of the messages.","type":"invalid_request_error","param":"messages","code":"context_length_exceeded"}}"#;
+        let err = classify_openai_compatible_http_error("openai", 400, body).expect("classified");
+        match &err {
+            LlmError::ContextLengthExceeded { message, .. } => {
+                assert!(message.contains("maximum context length"))
+            }
             other => panic!("unexpected error variant: {other:?}"),
         }
+        let details = err.http_details().expect("details");
+        assert_eq!(details.status, Some(400));
+        assert_eq!(
+            details.provider_code.as_deref(),
+            Some("context_length_exceeded")
+        );
+        assert_eq!(details.error_type.as_deref(), Some("invalid_request_error"));
+    }
+
+    #[test]
+    fn openai_error_mapping_overloaded_server_error() {
+        let body = r#"{"error":{"message":"The engine is currently overloaded, please try again later","type":"server_error","param":null,"code":null}}"#;
+        let err = classify_openai_compatible_http_error("openai", 503, body).expect("classified");
+        assert!(matches!(err, LlmError::Overloaded { .. }), "{err:?}");
     }
 
     #[test]
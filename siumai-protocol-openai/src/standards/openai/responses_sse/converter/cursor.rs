@@ -0,0 +1,114 @@
+use std::sync::{Arc, Mutex};
+
+/// Resume cursor for OpenAI Responses SSE streams.
+///
+/// Responses streaming events carry a monotonically increasing `sequence_number`.
+/// For background responses (`background: true`), a dropped stream can be resumed via
+/// `GET /responses/{id}?stream=true&starting_after=N`. This cursor records the response id
+/// and the last observed sequence number so a reconnect knows where to continue, and it
+/// filters out events that are replayed at or below the cursor.
+///
+/// Clones share the same state, so a converter and the code driving reconnects can hold
+/// the same cursor. The cursor also remembers the converter it was attached to, so a
+/// reconnect keeps that converter's configuration and open text/tool-call state.
+#[derive(Debug, Clone, Default)]
+pub struct OpenAiResponsesStreamCursor {
+    inner: Arc<Mutex<OpenAiResponsesStreamCursorState>>,
+}
+
+#[derive(Default)]
+struct OpenAiResponsesStreamCursorState {
+    response_id: Option<String>,
+    last_sequence_number: Option<u64>,
+    /// Converter attached via `with_stream_cursor`, stored without the cursor to avoid a cycle.
+    converter: Option<super::OpenAiResponsesEventConverter>,
+}
+
+impl std::fmt::Debug for OpenAiResponsesStreamCursorState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OpenAiResponsesStreamCursorState")
+            .field("response_id", &self.response_id)
+            .field("last_sequence_number", &self.last_sequence_number)
+            .field("has_converter", &self.converter.is_some())
+            .finish()
+    }
+}
+
+impl OpenAiResponsesStreamCursor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a cursor positioned after `sequence_number` of an existing response.
+    ///
+    /// Use this to resume a stream that was started in another process.
+    pub fn starting_after(response_id: impl Into<String>, sequence_number: Option<u64>) -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(OpenAiResponsesStreamCursorState {
+                response_id: Some(response_id.into()),
+                last_sequence_number: sequence_number,
+                converter: None,
+            })),
+        }
+    }
+
+    /// The response id observed on the stream (from `response.created`), if any.
+    pub fn response_id(&self) -> Option<String> {
+        self.inner.lock().ok()?.response_id.clone()
+    }
+
+    /// The last `sequence_number` delivered to the caller, if any.
+    pub fn last_sequence_number(&self) -> Option<u64> {
+        self.inner.lock().ok()?.last_sequence_number
+    }
+
+    /// A converter that continues the stream this cursor tracks.
+    ///
+    /// When a converter was attached with `with_stream_cursor`, the returned converter shares
+    /// its configuration (metadata key, `store`, request tools) and its open text, reasoning and
+    /// tool-call state, so a reconnect picks up mid-part. Otherwise a default converter is used.
+    pub fn resume_converter(&self) -> super::OpenAiResponsesEventConverter {
+        let attached = self
+            .inner
+            .lock()
+            .ok()
+            .and_then(|state| state.converter.clone());
+        attached
+            .unwrap_or_default()
+            .with_stream_cursor(self.clone())
+    }
+
+    pub(super) fn attach_converter(&self, converter: super::OpenAiResponsesEventConverter) {
+        if let Ok(mut state) = self.inner.lock() {
+            state.converter = Some(converter);
+        }
+    }
+
+    /// Record a raw Responses SSE payload.
+    ///
+    /// Returns `false` when the payload is a replay of an already delivered event
+    /// (its `sequence_number` is at or below the cursor) and should be dropped.
+    pub fn observe(&self, payload: &serde_json::Value) -> bool {
+        let Ok(mut state) = self.inner.lock() else {
+            return true;
+        };
+
+        if state.response_id.is_none()
+            && let Some(id) = payload
+                .get("response")
+                .and_then(|r| r.get("id"))
+                .and_then(|v| v.as_str())
+        {
+            state.response_id = Some(id.to_string());
+        }
+
+        let Some(seq) = payload.get("sequence_number").and_then(|v| v.as_u64()) else {
+            return true;
+        };
+        if state.last_sequence_number.is_some_and(|last| seq <= last) {
+            return false;
+        }
+        state.last_sequence_number = Some(seq);
+        true
+    }
+}
@@ -4,12 +4,14 @@ use std::sync::{Arc, Mutex};
 use chrono::{TimeZone, Utc};
 
 mod state;
+pub use cursor::OpenAiResponsesStreamCursor;
 use state::OpenAiResponsesSerializeState;
 pub use state::{StreamPartsStyle, WebSearchStreamMode};
 
 mod apply_patch;
 mod code_interpreter;
 mod convert;
+mod cursor;
 mod custom_tools;
 mod function_tool;
 mod mcp;
@@ -101,6 +103,9 @@ pub struct OpenAiResponsesEventConverter {
     emitted_tool_search_input_start_ids: Arc<Mutex<HashSet<String>>>,
 
     serialize_state: Arc<Mutex<OpenAiResponsesSerializeState>>,
+
+    /// Optional resume cursor (tracks `sequence_number` and drops replayed events).
+    stream_cursor: Option<OpenAiResponsesStreamCursor>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -166,6 +171,7 @@ impl Default for OpenAiResponsesEventConverter {
             hosted_tool_search_call_ids: Arc::new(Mutex::new(VecDeque::new())),
             emitted_tool_search_input_start_ids: Arc::new(Mutex::new(HashSet::new())),
             serialize_state: Arc::new(Mutex::new(OpenAiResponsesSerializeState::default())),
+            stream_cursor: None,
         }
     }
 }
@@ -226,6 +232,17 @@ impl OpenAiResponsesEventConverter {
         self
     }
 
+    /// Track `sequence_number` on a shared cursor so the stream can be resumed.
+    ///
+    /// Call this after the other builder methods: the cursor snapshots this converter so
+    /// `OpenAiResponsesStreamCursor::resume_converter` can continue with the same settings.
+    pub fn with_stream_cursor(mut self, cursor: OpenAiResponsesStreamCursor) -> Self {
+        self.stream_cursor = None;
+        cursor.attach_converter(self.clone());
+        self.stream_cursor = Some(cursor);
+        self
+    }
+
     fn provider_metadata_json(&self, value: serde_json::Value) -> serde_json::Value {
         let mut out = serde_json::Map::new();
         out.insert(self.provider_metadata_key.clone(), value);
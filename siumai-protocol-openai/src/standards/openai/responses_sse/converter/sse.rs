@@ -36,6 +36,12 @@ impl crate::streaming::SseEventConverter for OpenAiResponsesEventConverter {
                 }
             };
 
+            if let Some(cursor) = self.stream_cursor.as_ref()
+                && !cursor.observe(&json)
+            {
+                return vec![];
+            }
+
             self.update_provider_tool_names(&json);
 
             // Some SSE clients (and our JSON fallback wrapper) default to `event: message`.
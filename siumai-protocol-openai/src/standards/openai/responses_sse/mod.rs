@@ -12,4 +12,7 @@ mod converter;
 #[cfg(test)]
 mod tests;
 
-pub use converter::{OpenAiResponsesEventConverter, StreamPartsStyle, WebSearchStreamMode};
+pub use converter::{
+    OpenAiResponsesEventConverter, OpenAiResponsesStreamCursor, StreamPartsStyle,
+    WebSearchStreamMode,
+};
@@ -4056,3 +4056,146 @@ fn responses_stream_proxy_closes_function_call_on_stream_end() {
                 .is_some_and(|arr| arr.iter().any(|it| it["type"] == "function_call"))
     }));
 }
+
+#[test]
+fn responses_stream_cursor_tracks_sequence_and_drops_replayed_events() {
+    let cursor = OpenAiResponsesStreamCursor::new();
+    let conv = OpenAiResponsesEventConverter::new().with_stream_cursor(cursor.clone());
+
+    let created = eventsource_stream::Event {
+        event: "response.created".to_string(),
+        data: r#"{"type":"response.created","sequence_number":0,"response":{"id":"resp_1","model":"gpt-test","created_at":0}}"#
+            .to_string(),
+        id: "1".to_string(),
+        retry: None,
+    };
+    let _ = futures::executor::block_on(conv.convert_event(created));
+
+    let delta = || {
+        eventsource_stream::Event {
+        event: "response.output_text.delta".to_string(),
+        data: r#"{"type":"response.output_text.delta","sequence_number":1,"item_id":"msg_1","delta":"Hello"}"#
+            .to_string(),
+        id: "2".to_string(),
+        retry: None,
+    }
+    };
+    let first = futures::executor::block_on(conv.convert_event(delta()));
+    assert!(first.iter().any(|event| {
+        matches!(
+            stream_part(event),
+            Some(crate::streaming::TypedStreamPart::TextDelta { delta, .. }) if delta == "Hello"
+        )
+    }));
+
+    let replayed = futures::executor::block_on(conv.convert_event(delta()));
+    assert!(replayed.is_empty());
+
+    assert_eq!(cursor.response_id().as_deref(), Some("resp_1"));
+    assert_eq!(cursor.last_sequence_number(), Some(1));
+}
+
+#[test]
+fn responses_stream_cursor_starting_after_skips_events_up_to_position() {
+    let cursor = OpenAiResponsesStreamCursor::starting_after("resp_2", Some(4));
+
+    assert!(!cursor.observe(&serde_json::json!({ "sequence_number": 3 })));
+    assert!(!cursor.observe(&serde_json::json!({ "sequence_number": 4 })));
+    assert!(cursor.observe(&serde_json::json!({ "sequence_number": 5 })));
+    assert!(cursor.observe(&serde_json::json!({ "type": "keepalive" })));
+    assert_eq!(cursor.response_id().as_deref(), Some("resp_2"));
+    assert_eq!(cursor.last_sequence_number(), Some(5));
+}
+
+#[test]
+fn responses_stream_cursor_resume_converter_continues_open_tool_call() {
+    let cursor = OpenAiResponsesStreamCursor::new();
+    let conv = OpenAiResponsesEventConverter::new()
+        .with_provider_metadata_key("azure")
+        .with_stream_cursor(cursor.clone());
+
+    let event = |seq: u64, data: serde_json::Value| eventsource_stream::Event {
+        event: "".to_string(),
+        data: data.to_string(),
+        id: seq.to_string(),
+        retry: None,
+    };
+    let delta = |seq: u64, delta: &str| {
+        event(
+            seq,
+            serde_json::json!({
+                "type": "response.function_call_arguments.delta",
+                "sequence_number": seq,
+                "item_id": "fc_1",
+                "output_index": 0,
+                "delta": delta
+            }),
+        )
+    };
+
+    let _ = futures::executor::block_on(conv.convert_event(event(
+        0,
+        serde_json::json!({
+            "type": "response.created",
+            "sequence_number": 0,
+            "response": { "id": "resp_1", "model": "gpt-test", "created_at": 0 }
+        }),
+    )));
+    let _ = futures::executor::block_on(conv.convert_event(event(
+        1,
+        serde_json::json!({
+            "type": "response.output_item.added",
+            "sequence_number": 1,
+            "output_index": 0,
+            "item": {
+                "id": "fc_1",
+                "type": "function_call",
+                "call_id": "call_1",
+                "name": "lookup",
+                "status": "in_progress"
+            }
+        }),
+    )));
+    let _ = futures::executor::block_on(conv.convert_event(delta(2, "{\"q\":")));
+
+    // The connection drops mid tool call; the reconnect replays event 2 and continues.
+    let resumed = cursor.resume_converter();
+    assert!(futures::executor::block_on(resumed.convert_event(delta(2, "{\"q\":"))).is_empty());
+
+    let out = futures::executor::block_on(resumed.convert_event(delta(3, "\"x\"}")));
+    assert_eq!(out.len(), 1);
+    match stream_part(&out[0]).expect("tool-input-delta") {
+        crate::streaming::TypedStreamPart::ToolInputDelta { id, delta, .. } => {
+            assert_eq!(id, "call_1");
+            assert_eq!(delta, "\"x\"}");
+        }
+        other => panic!("expected tool-input-delta part, got {other:?}"),
+    }
+
+    let done = futures::executor::block_on(resumed.convert_event(event(
+        4,
+        serde_json::json!({
+            "type": "response.function_call_arguments.done",
+            "sequence_number": 4,
+            "item_id": "fc_1",
+            "output_index": 0,
+            "arguments": "{\"q\":\"x\"}"
+        }),
+    )));
+    let call = done
+        .iter()
+        .find_map(|event| match stream_part(event) {
+            Some(crate::streaming::TypedStreamPart::ToolCall(call)) => Some(call),
+            _ => None,
+        })
+        .expect("tool-call part");
+    assert_eq!(call.tool_call_id, "call_1");
+    assert_eq!(call.tool_name, "lookup");
+    assert!(
+        call.provider_metadata
+            .as_ref()
+            .is_some_and(|meta| meta.contains_key("azure")),
+        "resumed converter should keep the provider metadata key: {call:?}"
+    );
+    assert_eq!(cursor.last_sequence_number(), Some(4));
+}
@@ -73,6 +73,58 @@ impl BedrockCachePoint {
     }
 }
 
+impl From<crate::execution::middleware::presets::PromptCacheTtl> for BedrockCacheTtl {
+    fn from(ttl: crate::execution::middleware::presets::PromptCacheTtl) -> Self {
+        match ttl {
+            crate::execution::middleware::presets::PromptCacheTtl::FiveMinutes => Self::FiveMinutes,
+            crate::execution::middleware::presets::PromptCacheTtl::OneHour => Self::OneHour,
+        }
+    }
+}
+
+/// Prompt-cache marker for automatic cache-point placement.
+///
+/// Used with `PromptCacheMiddleware`; cache points are written as
+/// `providerOptions.bedrock.cachePoint` on system and conversation messages.
+/// Tool cache points are not mapped by the Converse request builder, so tools are skipped.
+#[derive(Debug, Clone, Default)]
+pub struct BedrockPromptCacheMarker;
+
+impl BedrockPromptCacheMarker {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl crate::execution::middleware::presets::PromptCacheMarker for BedrockPromptCacheMarker {
+    fn namespace(&self) -> &str {
+        "bedrock"
+    }
+
+    fn marker(
+        &self,
+        ttl: Option<crate::execution::middleware::presets::PromptCacheTtl>,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        let mut cache_point = BedrockCachePoint::new();
+        cache_point.cache_point.ttl = ttl.map(Into::into);
+        match serde_json::to_value(cache_point) {
+            Ok(serde_json::Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        }
+    }
+
+    fn has_marker(&self, options: &serde_json::Map<String, serde_json::Value>) -> bool {
+        options.contains_key("cachePoint") || options.contains_key("cache_point")
+    }
+
+    fn supports(&self, target: crate::execution::middleware::presets::PromptCacheTarget) -> bool {
+        !matches!(
+            target,
+            crate::execution::middleware::presets::PromptCacheTarget::Tools
+        )
+    }
+}
+
 /// Bedrock reasoning mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -643,4 +695,34 @@ mod tests {
                 .with_output_dimension(1536)
         );
     }
+
+    #[test]
+    fn prompt_cache_marker_writes_cache_points_on_messages_only() {
+        use crate::execution::middleware::presets::{PromptCacheStrategy, PromptCacheTtl};
+        use crate::types::{ChatMessage, ChatRequest, Tool};
+
+        let req = ChatRequest::new(vec![
+            ChatMessage::system("stable instructions ".repeat(400)).build(),
+            ChatMessage::user("hi").build(),
+        ])
+        .with_tools(vec![Tool::function(
+            "lookup",
+            "stable tool ".repeat(400),
+            serde_json::json!({ "type": "object" }),
+        )]);
+
+        let req = PromptCacheStrategy::new()
+            .with_conversation(false)
+            .with_ttl(PromptCacheTtl::OneHour)
+            .apply(req, &BedrockPromptCacheMarker::new());
+
+        let Tool::Function { function } = &req.tools.as_ref().unwrap()[0] else {
+            panic!("expected function tool");
+        };
+        assert!(function.provider_options_map.get("bedrock").is_none());
+        assert_eq!(
+            req.messages[0].provider_options.get("bedrock"),
+            Some(&serde_json::json!({ "cachePoint": { "type": "default", "ttl": "1h" } }))
+        );
+    }
 }
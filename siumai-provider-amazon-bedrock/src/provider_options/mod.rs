@@ -8,7 +8,7 @@ pub use bedrock::{
     AmazonBedrockRerankingModelOptions, BedrockCachePoint, BedrockCachePointConfig,
     BedrockCachePointType, BedrockCacheTtl, BedrockChatOptions, BedrockEmbeddingInputType,
     BedrockEmbeddingOptions, BedrockEmbeddingPurpose, BedrockEmbeddingTruncate,
-    BedrockFilePartCitations, BedrockFilePartProviderOptions, BedrockProviderOptions,
-    BedrockReasoningConfig, BedrockReasoningEffort, BedrockReasoningType, BedrockRerankOptions,
-    BedrockRerankingOptions, BedrockServiceTier,
+    BedrockFilePartCitations, BedrockFilePartProviderOptions, BedrockPromptCacheMarker,
+    BedrockProviderOptions, BedrockReasoningConfig, BedrockReasoningEffort, BedrockReasoningType,
+    BedrockRerankOptions, BedrockRerankingOptions, BedrockServiceTier,
 };
@@ -3,9 +3,10 @@
 //! The Bedrock runtime endpoints usually return JSON error bodies shaped like:
 //! `{ "message": "...", "__type": "..." }`. This module keeps the provider error
 //! message lossless when possible, while mapping status codes into retry-friendly
-//! unified error variants.
+//! unified error variants. `ServiceUnavailableException` maps to `LlmError::Overloaded` and
+//! over-long `ValidationException`s map to `LlmError::ContextLengthExceeded`.
 
-use crate::error::LlmError;
+use crate::error::{HttpErrorDetails, LlmError, normalize_provider_failure};
 use reqwest::header::HeaderMap;
 
 fn extract_message(body_text: &str) -> Option<String> {
@@ -50,13 +51,26 @@ pub fn classify_bedrock_http_error(
 
     let message = extract_message(body_text).unwrap_or_else(|| body_text.to_string());
     let lower = message.to_lowercase();
-    let err_type = extract_error_type(body_text)
-        .unwrap_or_default()
-        .to_lowercase();
+    let raw_type = extract_error_type(body_text);
+    let err_type = raw_type.as_deref().unwrap_or_default().to_lowercase();
+    let details = || {
+        let details = HttpErrorDetails::new().with_status(status);
+        match raw_type.as_deref() {
+            Some(error_type) => details.with_error_type(error_type),
+            None => details,
+        }
+    };
+
+    if err_type.contains("serviceunavailable") {
+        return Some(LlmError::overloaded(message).with_http_details(details()));
+    }
+    if let Some(normalized) = normalize_provider_failure(status, &[], &message) {
+        return Some(normalized.with_http_details(details()));
+    }
 
     let looks_rate_limited = err_type.contains("throttl") || lower.contains("throttl");
     if status == 429 || looks_rate_limited {
-        return Some(LlmError::RateLimitError(message));
+        return Some(LlmError::rate_limit_error(message));
     }
 
     if status == 401 || status == 403 {
@@ -75,5 +89,6 @@ pub fn classify_bedrock_http_error(
         code: status,
         message,
         details: serde_json::from_str::<serde_json::Value>(body_text).ok(),
+        http_details: None,
     })
 }
@@ -416,6 +416,7 @@ impl ResponseTransformer for BedrockImageResponseTransformer {
                     reasons.join(", ")
                 ),
                 details: Some(raw.clone()),
+                http_details: None,
             });
         }
 
@@ -471,6 +471,7 @@ impl AudioCapability for AzureOpenAiClient {
             language,
             confidence: None,
             words: None,
+            segments: crate::standards::openai::audio::parse_stt_segments(&raw),
             duration,
             metadata,
             warnings: None,
@@ -732,17 +732,23 @@ impl AzureOpenAiClient {
             .unwrap_or(false);
         let ctx = self.build_context();
         let spec: Arc<dyn ProviderSpec> = Arc::new(self.completion_spec());
-        let headers_base = spec.build_headers(&ctx)?;
         let url = self.completion_url(&request.common_params.model, &ctx);
-        let request_id = crate::execution::http::interceptor::generate_request_id();
+        let request_ctx = crate::execution::http::interceptor::HttpRequestContext {
+            request_id: crate::execution::http::interceptor::generate_request_id(),
+            provider_id: "azure".to_string(),
+            url: url.clone(),
+            stream: true,
+        };
+        let headers_base = crate::execution::executors::common::build_request_headers(
+            spec.as_ref(),
+            &ctx,
+            &self.http_interceptors,
+            &request_ctx,
+        )?;
+        let request_id = request_ctx.request_id.clone();
         let converter = crate::streaming::InterceptingConverter {
             interceptors: self.http_interceptors.clone(),
-            ctx: crate::execution::http::interceptor::HttpRequestContext {
-                request_id: request_id.clone(),
-                provider_id: "azure".to_string(),
-                url: url.clone(),
-                stream: true,
-            },
+            ctx: request_ctx,
             convert: CompletionSseConverter::new(
                 self.config.provider_metadata_key,
                 warnings,
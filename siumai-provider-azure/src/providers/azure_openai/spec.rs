@@ -688,7 +688,7 @@ mod tests {
             .classify_http_error(429, body, &HeaderMap::new())
             .expect("classified");
         match err {
-            LlmError::RateLimitError(msg) => assert!(msg.contains("Rate limited")),
+            LlmError::RateLimitError { message: msg, .. } => assert!(msg.contains("Rate limited")),
             other => panic!("unexpected error variant: {other:?}"),
         }
     }
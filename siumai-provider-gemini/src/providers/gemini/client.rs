@@ -211,6 +211,15 @@ impl GeminiClient {
         self.files_capability.clone()
     }
 
+    /// Open a Live API session (`BidiGenerateContent`) using this client's credentials.
+    #[cfg(feature = "gemini-live")]
+    pub async fn connect_live(
+        &self,
+        config: super::live::GeminiLiveConfig,
+    ) -> Result<super::live::GeminiLiveSession, LlmError> {
+        super::live::GeminiLiveSession::connect(&self.config, config).await
+    }
+
     /// Get the provider-facing display name.
     pub fn provider_name(&self) -> &str {
         self.config.provider_name()
@@ -734,6 +743,20 @@ impl FileManagementCapability for GeminiClient {
     async fn get_file_content(&self, file_id: String) -> Result<Vec<u8>, LlmError> {
         self.files_capability.get_file_content(file_id).await
     }
+
+    async fn upload_file_stream(
+        &self,
+        request: FileStreamUploadRequest,
+    ) -> Result<FileObject, LlmError> {
+        self.files_capability.upload_file_stream(request).await
+    }
+
+    async fn get_file_content_stream(
+        &self,
+        file_id: String,
+    ) -> Result<FileContentStream, LlmError> {
+        self.files_capability.get_file_content_stream(file_id).await
+    }
 }
 
 impl LlmClient for GeminiClient {
@@ -12,7 +12,8 @@ use secrecy::ExposeSecret;
 use crate::error::LlmError;
 use crate::traits::FileManagementCapability;
 use crate::types::{
-    FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
+    FileContentStream, FileDeleteResponse, FileListQuery, FileListResponse, FileObject,
+    FileStreamUploadRequest, FileUploadRequest, ProviderOptionsMap, UploadSession,
 };
 
 use super::types::GeminiConfig;
@@ -46,16 +47,15 @@ pub struct GeminiFiles {
 
 impl GeminiFiles {
     fn file_provider_options(
-        request: &FileUploadRequest,
+        provider_options: &ProviderOptionsMap,
     ) -> Option<&serde_json::Map<String, serde_json::Value>> {
-        request
-            .provider_options
+        provider_options
             .get_object("gemini")
-            .or_else(|| request.provider_options.get_object("google"))
+            .or_else(|| provider_options.get_object("google"))
     }
 
-    fn poll_interval_ms(request: &FileUploadRequest) -> u64 {
-        Self::file_provider_options(request)
+    fn poll_interval_ms(provider_options: &ProviderOptionsMap) -> u64 {
+        Self::file_provider_options(provider_options)
             .and_then(|options| {
                 options
                     .get("pollIntervalMs")
@@ -66,8 +66,8 @@ impl GeminiFiles {
             .unwrap_or(2000)
     }
 
-    fn poll_timeout_ms(request: &FileUploadRequest) -> u64 {
-        Self::file_provider_options(request)
+    fn poll_timeout_ms(provider_options: &ProviderOptionsMap) -> u64 {
+        Self::file_provider_options(provider_options)
             .and_then(|options| {
                 options
                     .get("pollTimeoutMs")
@@ -100,6 +100,12 @@ impl GeminiFiles {
         self.config.provider_name()
     }
 
+    /// Files API size limit (2 GB).
+    const MAX_FILE_SIZE: u64 = 2 * 1024 * 1024 * 1024;
+
+    /// Resumable uploads require non-final chunks to be multiples of 256 KiB.
+    const UPLOAD_CHUNK_GRANULARITY: usize = 256 * 1024;
+
     /// Validate file upload request
     fn validate_upload_request(&self, request: &FileUploadRequest) -> Result<(), LlmError> {
         if request.content.is_empty() {
@@ -108,20 +114,17 @@ impl GeminiFiles {
             ));
         }
 
-        // Check file size limits.
-        //
-        // Official Files API limits allow much larger files (up to 2 GB), but note that
-        // `FileUploadRequest` is currently in-memory (`Vec<u8>`), so callers should avoid
-        // very large uploads to prevent OOM.
-        const MAX_FILE_SIZE: usize = 2 * 1024 * 1024 * 1024; // 2GB
-        if request.content.len() > MAX_FILE_SIZE {
+        // `FileUploadRequest` is in-memory; use `upload_file_stream` for large files.
+        Self::validate_upload_size(request.content.len() as u64)
+    }
+
+    fn validate_upload_size(size: u64) -> Result<(), LlmError> {
+        if size > Self::MAX_FILE_SIZE {
             return Err(LlmError::InvalidInput(format!(
-                "File size {} bytes exceeds maximum allowed size of {} bytes",
-                request.content.len(),
-                MAX_FILE_SIZE
+                "File size {size} bytes exceeds maximum allowed size of {} bytes",
+                Self::MAX_FILE_SIZE
             )));
         }
-
         Ok(())
     }
 
@@ -164,27 +167,14 @@ impl FileManagementCapability for GeminiFiles {
         // Validate request
         self.validate_upload_request(&request)?;
 
-        let poll_interval_ms = Self::poll_interval_ms(&request);
-        let poll_timeout_ms = Self::poll_timeout_ms(&request);
+        let poll_interval_ms = Self::poll_interval_ms(&request.provider_options);
+        let poll_timeout_ms = Self::poll_timeout_ms(&request.provider_options);
 
         use crate::execution::executors::files::FilesExecutor;
         let exec = self.build_files_executor().await;
         let file = FilesExecutor::upload(&*exec, request).await?;
-
-        match file.status.as_str() {
-            "processing" => {
-                self.wait_for_file_processing_with_options(
-                    file.id.clone(),
-                    poll_timeout_ms,
-                    poll_interval_ms,
-                )
-                .await
-            }
-            "failed" => Err(LlmError::ProcessingError(
-                "File processing failed".to_string(),
-            )),
-            _ => Ok(file),
-        }
+        self.await_uploaded_file(file, poll_timeout_ms, poll_interval_ms)
+            .await
     }
 
     /// List files with optional filtering.
@@ -220,6 +210,34 @@ impl FileManagementCapability for GeminiFiles {
         let exec = self.build_files_executor().await;
         FilesExecutor::get_content(&*exec, file_id).await
     }
+
+    /// Upload a file with the resumable upload protocol.
+    async fn upload_file_stream(
+        &self,
+        request: FileStreamUploadRequest,
+    ) -> Result<FileObject, LlmError> {
+        self.upload_file_resumable(request).await
+    }
+
+    /// Stream file content (file ids or download URIs).
+    async fn get_file_content_stream(
+        &self,
+        file_id: String,
+    ) -> Result<FileContentStream, LlmError> {
+        use futures::TryStreamExt;
+
+        if file_id.starts_with("http://") || file_id.starts_with("https://") {
+            let provider_name = self.provider_name().to_string();
+            let resp = self.open_download_uri(file_id).await?;
+            return Ok(Box::pin(resp.bytes_stream().map_err(move |e| {
+                LlmError::HttpError(format!("Failed to read {provider_name} file bytes: {e}"))
+            })));
+        }
+
+        use crate::execution::executors::files::FilesExecutor;
+        let exec = self.build_files_executor().await;
+        FilesExecutor::get_content_stream(&*exec, file_id).await
+    }
 }
 
 impl GeminiFiles {
@@ -230,7 +248,309 @@ impl GeminiFiles {
             .map_err(|e| LlmError::ParseError(format!("File content is not valid UTF-8: {e}")))
     }
 
+    async fn await_uploaded_file(
+        &self,
+        file: FileObject,
+        poll_timeout_ms: u64,
+        poll_interval_ms: u64,
+    ) -> Result<FileObject, LlmError> {
+        match file.status.as_str() {
+            "processing" => {
+                self.wait_for_file_processing_with_options(
+                    file.id.clone(),
+                    poll_timeout_ms,
+                    poll_interval_ms,
+                )
+                .await
+            }
+            "failed" => Err(LlmError::ProcessingError(
+                "File processing failed".to_string(),
+            )),
+            _ => Ok(file),
+        }
+    }
+
+    /// Upload endpoint for the resumable protocol (`{host}/upload/{version}/files`).
+    fn resumable_upload_url(&self) -> Result<String, LlmError> {
+        let mut url = reqwest::Url::parse(&self.config.base_url)
+            .map_err(|e| LlmError::ConfigurationError(format!("Invalid base URL: {e}")))?;
+        let path = url.path().trim_end_matches('/').to_string();
+        if !path.starts_with("/upload/") {
+            url.set_path(&format!("/upload{path}"));
+        }
+        Ok(crate::utils::url::join_url(url.as_str(), "files"))
+    }
+
+    /// Upload a file via the resumable protocol (`X-Goog-Upload-*` headers).
+    ///
+    /// Chunks are read from the source one at a time. A failed chunk is retried after asking
+    /// the server how many bytes it received (the query is retried too), and `request.resume`
+    /// continues a previous session from the server-reported offset.
+    ///
+    /// <https://ai.google.dev/api/files#method:-media.upload>
+    async fn upload_file_resumable(
+        &self,
+        mut request: FileStreamUploadRequest,
+    ) -> Result<FileObject, LlmError> {
+        use crate::execution::executors::common::execute_raw_request;
+        use crate::types::retry_upload_chunk;
+
+        let exec = self.build_files_executor().await;
+        let config = exec.execution_config();
+        let http_config = request.http_config.clone();
+        let max_retries = request.max_chunk_retries;
+        let chunk_size = (request.chunk_size / Self::UPLOAD_CHUNK_GRANULARITY).max(1)
+            * Self::UPLOAD_CHUNK_GRANULARITY;
+
+        let resume = request.resume.take();
+        let mut session = match resume {
+            Some(mut session) => {
+                session.offset = retry_upload_chunk(max_retries, |_| {
+                    self.query_upload_offset(&config, &session.upload_id, http_config.as_ref())
+                })
+                .await?;
+                session
+            }
+            None => {
+                let total_bytes = match &request.source {
+                    crate::types::FileUploadSource::Path(path) => tokio::fs::metadata(path)
+                        .await
+                        .map_err(|e| {
+                            LlmError::InvalidInput(format!("Cannot stat {}: {e}", path.display()))
+                        })?
+                        .len(),
+                    crate::types::FileUploadSource::Reader { length, .. } => *length,
+                };
+                if total_bytes == 0 {
+                    return Err(LlmError::InvalidInput(
+                        "File content cannot be empty".to_string(),
+                    ));
+                }
+                Self::validate_upload_size(total_bytes)?;
+
+                let mime_type = request
+                    .mime_type
+                    .clone()
+                    .unwrap_or_else(|| crate::utils::guess_mime(None, request.filename.as_deref()));
+                let display_name = Self::file_provider_options(&request.provider_options)
+                    .and_then(|options| {
+                        options
+                            .get("displayName")
+                            .or_else(|| options.get("display_name"))
+                    })
+                    .and_then(|value| value.as_str())
+                    .map(ToOwned::to_owned)
+                    .or_else(|| request.metadata.get("display_name").cloned())
+                    .or_else(|| request.filename.clone());
+                let body = match display_name {
+                    Some(name) => serde_json::json!({ "file": { "display_name": name } }),
+                    None => serde_json::json!({ "file": {} }),
+                };
+
+                let mut headers = reqwest::header::HeaderMap::new();
+                insert_header(&mut headers, "x-goog-upload-protocol", "resumable")?;
+                insert_header(&mut headers, "x-goog-upload-command", "start")?;
+                insert_header(
+                    &mut headers,
+                    "x-goog-upload-header-content-length",
+                    &total_bytes.to_string(),
+                )?;
+                insert_header(
+                    &mut headers,
+                    "x-goog-upload-header-content-type",
+                    &mime_type,
+                )?;
+                insert_header(&mut headers, "content-type", "application/json")?;
+
+                let url = self.resumable_upload_url()?;
+                let resp = execute_raw_request(
+                    &config,
+                    reqwest::Method::POST,
+                    &url,
+                    headers,
+                    Some(bytes::Bytes::from(body.to_string())),
+                    http_config.as_ref(),
+                )
+                .await?;
+                let upload_url = resp
+                    .headers()
+                    .get("x-goog-upload-url")
+                    .and_then(|v| v.to_str().ok())
+                    .ok_or_else(|| {
+                        LlmError::ParseError(
+                            "Resumable upload start response missing X-Goog-Upload-URL".into(),
+                        )
+                    })?;
+                UploadSession {
+                    provider_id: "gemini".to_string(),
+                    upload_id: upload_url.to_string(),
+                    total_bytes,
+                    offset: 0,
+                    part_ids: Vec::new(),
+                }
+            }
+        };
+
+        let source = std::mem::replace(
+            &mut request.source,
+            crate::types::FileUploadSource::reader(tokio::io::empty(), 0),
+        );
+        let mut reader = source.open(session.offset).await?;
+        if reader.length() != session.total_bytes {
+            return Err(LlmError::InvalidInput(format!(
+                "Resumed upload expects {} bytes but the source has {}",
+                session.total_bytes,
+                reader.length()
+            )));
+        }
+
+        // Bytes read from the source but not yet acknowledged by the server. Sends always start
+        // at the acknowledged offset, and non-final sends are trimmed to a multiple of the
+        // upload granularity, so a partially received chunk is re-aligned before it is resent.
+        let mut pending = bytes::BytesMut::new();
+        loop {
+            while pending.len() < chunk_size
+                && let Some(chunk) = reader.next_chunk(chunk_size - pending.len()).await?
+            {
+                pending.extend_from_slice(&chunk);
+            }
+            let is_final = reader.offset() == session.total_bytes;
+            let send_len = if is_final {
+                pending.len()
+            } else {
+                pending.len() / Self::UPLOAD_CHUNK_GRANULARITY * Self::UPLOAD_CHUNK_GRANULARITY
+            };
+            let body = bytes::Bytes::copy_from_slice(&pending[..send_len]);
+            let offset = session.offset;
+
+            let sent = retry_upload_chunk(max_retries, |attempt| {
+                let body = body.clone();
+                let config = &config;
+                let upload_url = session.upload_id.as_str();
+                let http_config = http_config.as_ref();
+                async move {
+                    if attempt > 0 {
+                        let received = self
+                            .query_upload_offset(config, upload_url, http_config)
+                            .await?;
+                        if received < offset {
+                            return Err(LlmError::InvalidInput(format!(
+                                "Resumable upload server reports {received} bytes, behind the acknowledged offset {offset}"
+                            )));
+                        }
+                        if received != offset {
+                            return Ok(ChunkOutcome::Partial(received));
+                        }
+                    }
+
+                    let mut headers = reqwest::header::HeaderMap::new();
+                    insert_header(
+                        &mut headers,
+                        "x-goog-upload-command",
+                        if is_final {
+                            "upload, finalize"
+                        } else {
+                            "upload"
+                        },
+                    )?;
+                    insert_header(&mut headers, "x-goog-upload-offset", &offset.to_string())?;
+                    execute_raw_request(
+                        config,
+                        reqwest::Method::POST,
+                        upload_url,
+                        headers,
+                        Some(body),
+                        http_config,
+                    )
+                    .await
+                    .map(ChunkOutcome::Sent)
+                }
+            })
+            .await?;
+
+            let acked = match &sent {
+                ChunkOutcome::Sent(_) => send_len,
+                ChunkOutcome::Partial(received) => {
+                    (received.saturating_sub(offset) as usize).min(send_len)
+                }
+            };
+            let _ = pending.split_to(acked);
+            session.offset = offset + acked as u64;
+            request.report_progress(&session);
+
+            if let ChunkOutcome::Sent(resp) = sent
+                && is_final
+            {
+                let bytes = resp.bytes().await.map_err(|e| {
+                    LlmError::HttpError(format!("Failed to read upload response: {e}"))
+                })?;
+                let json: serde_json::Value = serde_json::from_slice(&bytes).map_err(|e| {
+                    LlmError::ParseError(format!("Failed to parse upload response: {e}"))
+                })?;
+                let file = exec.transformer.transform_file_object(&json)?;
+                return self
+                    .await_uploaded_file(
+                        file,
+                        Self::poll_timeout_ms(&request.provider_options),
+                        Self::poll_interval_ms(&request.provider_options),
+                    )
+                    .await;
+            }
+        }
+    }
+
+    /// Ask the server how many bytes of a resumable upload it has persisted.
+    async fn query_upload_offset(
+        &self,
+        config: &crate::execution::executors::common::HttpExecutionConfig,
+        upload_url: &str,
+        http_config: Option<&crate::types::HttpConfig>,
+    ) -> Result<u64, LlmError> {
+        let mut headers = reqwest::header::HeaderMap::new();
+        insert_header(&mut headers, "x-goog-upload-command", "query")?;
+        let resp = crate::execution::executors::common::execute_raw_request(
+            config,
+            reqwest::Method::POST,
+            upload_url,
+            headers,
+            Some(bytes::Bytes::new()),
+            http_config,
+        )
+        .await?;
+        if resp
+            .headers()
+            .get("x-goog-upload-status")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|status| status.eq_ignore_ascii_case("final"))
+        {
+            return Err(LlmError::InvalidInput(
+                "Resumable upload session is already finalized".to_string(),
+            ));
+        }
+        resp.headers()
+            .get("x-goog-upload-size-received")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .ok_or_else(|| {
+                LlmError::ParseError(
+                    "Resumable upload query response missing X-Goog-Upload-Size-Received".into(),
+                )
+            })
+    }
+
     async fn download_uri(&self, uri: String) -> Result<Vec<u8>, LlmError> {
+        let resp = self.open_download_uri(uri).await?;
+        let bytes = resp.bytes().await.map_err(|e| {
+            LlmError::HttpError(format!(
+                "Failed to read {} file bytes: {e}",
+                self.provider_name()
+            ))
+        })?;
+        Ok(bytes.to_vec())
+    }
+
+    /// Follow redirects for a download URI and return the successful response unread.
+    async fn open_download_uri(&self, uri: String) -> Result<reqwest::Response, LlmError> {
         use crate::core::ProviderSpec;
 
         fn is_google_host(host: &str) -> bool {
@@ -262,13 +582,7 @@ impl GeminiFiles {
             })?;
 
             if resp.status().is_success() {
-                let bytes = resp.bytes().await.map_err(|e| {
-                    LlmError::HttpError(format!(
-                        "Failed to read {} file bytes: {e}",
-                        self.provider_name()
-                    ))
-                })?;
-                return Ok(bytes.to_vec());
+                return Ok(resp);
             }
 
             if matches!(
@@ -365,3 +679,22 @@ impl GeminiFiles {
         }
     }
 }
+
+/// Result of one resumable upload send.
+enum ChunkOutcome {
+    /// The chunk was accepted.
+    Sent(reqwest::Response),
+    /// A retry found the server at a different offset than the chunk started at.
+    Partial(u64),
+}
+
+fn insert_header(
+    headers: &mut reqwest::header::HeaderMap,
+    name: &'static str,
+    value: &str,
+) -> Result<(), LlmError> {
+    let value = reqwest::header::HeaderValue::from_str(value)
+        .map_err(|e| LlmError::InvalidParameter(format!("Invalid {name} header: {e}")))?;
+    headers.insert(name, value);
+    Ok(())
+}
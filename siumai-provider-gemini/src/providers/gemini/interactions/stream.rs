@@ -64,22 +64,26 @@ pub(super) async fn execute_interactions_stream(
     let http_config =
         interactions_http_config(&model.config().http_config, request.http_config.as_ref());
     let execution_config = build_execution_config(model, http_client, retry_options.clone()).await;
-    let headers_base = execution_config
-        .provider_spec
-        .build_headers(&execution_config.provider_context)?;
+    let url = interactions_url(model.base_url());
+    let ctx = HttpRequestContext {
+        request_id: generate_request_id(),
+        provider_id: execution_config.provider_id.clone(),
+        url: url.clone(),
+        stream: true,
+    };
+    let headers_base = execution_config.build_headers_for(&ctx)?;
     let converter = GoogleInteractionsEventConverter::new(
         model.provider(),
         model.model_id().to_string(),
         model.config().generate_id.clone(),
         prepared.warnings,
     );
-    let request_id = generate_request_id();
     let stream = execute_sse_stream_request_with_headers(
         &execution_config.http_client,
         &execution_config.provider_id,
         Some(execution_config.provider_spec.as_ref()),
-        &interactions_url(model.base_url()),
-        request_id,
+        &url,
+        ctx.request_id,
         headers_base,
         request_body,
         &execution_config.interceptors,
@@ -146,9 +150,6 @@ pub(super) async fn execute_interactions_agent_stream(
     );
     converter.seed_from_interaction_response(&post_result.json);
 
-    let headers_base = execution_config
-        .provider_spec
-        .build_headers(&execution_config.provider_context)?;
     let stream = if is_terminal_response(&post_result.json)? {
         converter
             .stream_from_terminal_response(post_result.json.clone(), post_result.headers.clone())
@@ -157,7 +158,6 @@ pub(super) async fn execute_interactions_agent_stream(
             execution_config,
             model.base_url().to_string(),
             interaction_id.to_string(),
-            headers_base,
             http_config.clone(),
             converter,
             cancel,
@@ -199,7 +199,6 @@ fn stream_agent_interaction_events(
     execution_config: HttpExecutionConfig,
     base_url: String,
     interaction_id: String,
-    headers_base: HeaderMap,
     http_config: crate::types::HttpConfig,
     converter: GoogleInteractionsEventConverter,
     cancel: Option<CancelHandle>,
@@ -221,7 +220,6 @@ fn stream_agent_interaction_events(
             let response = match open_agent_stream_response(
                 &execution_config,
                 &url,
-                &headers_base,
                 &http_config,
                 disable_compression,
             )
@@ -356,13 +354,19 @@ fn event_id_from_sse_data(data: &str) -> Option<String> {
 async fn open_agent_stream_response(
     execution_config: &HttpExecutionConfig,
     url: &str,
-    headers_base: &HeaderMap,
     http_config: &crate::types::HttpConfig,
     disable_compression: bool,
 ) -> Result<AgentStreamResponse, LlmError> {
+    let ctx = HttpRequestContext {
+        request_id: generate_request_id(),
+        provider_id: execution_config.provider_id.clone(),
+        url: url.to_string(),
+        stream: true,
+    };
+    let headers_base = execution_config.build_headers_for(&ctx)?;
     let effective_headers = execution_config
         .provider_spec
-        .merge_request_headers(headers_base.clone(), &http_config.headers);
+        .merge_request_headers(headers_base, &http_config.headers);
     let mut headers = effective_headers.clone();
     headers.insert(ACCEPT, "text/event-stream".parse().expect("valid accept"));
     headers.insert(
@@ -377,12 +381,6 @@ async fn open_agent_stream_response(
         );
     }
 
-    let ctx = HttpRequestContext {
-        request_id: generate_request_id(),
-        provider_id: execution_config.provider_id.clone(),
-        url: url.to_string(),
-        stream: true,
-    };
     let empty_body = serde_json::json!({});
 
     if let Some(transport) = &execution_config.transport {
@@ -0,0 +1,866 @@
+//! Gemini Live API (`BidiGenerateContent`) sessions.
+//!
+//! A Live session is a single WebSocket connection carrying a `setup` message followed by
+//! interleaved client input (turns, realtime audio/video/text, tool responses) and server
+//! output (model content, tool calls, interruption and turn signals, resumption handles).
+//!
+//! Design notes:
+//! - [`GeminiLiveSession::connect`] sends the setup message and waits for `setupComplete`
+//!   before returning, so a returned session is always ready for input.
+//! - Server messages are flattened into [`GeminiLiveEvent`]s; audio output is base64-decoded.
+//! - The latest resumable handle from `sessionResumptionUpdate` is tracked automatically and
+//!   can be passed to [`GeminiLiveConfig::with_resumption_handle`] to reconnect after `goAway`.
+//! - Only the Gemini Developer API endpoint is derived automatically; use
+//!   [`GeminiLiveConfig::with_url`] for proxies or other deployments.
+
+use futures::Stream;
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, StreamExt};
+use secrecy::ExposeSecret;
+use std::collections::VecDeque;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tokio::sync::Mutex;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::protocol::Message;
+use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+use crate::error::LlmError;
+use crate::standards::gemini::headers::build_gemini_headers;
+use crate::types::Tool;
+
+use super::types::{Content, GeminiConfig, GeminiTool, GenerationConfig, Part};
+use protocol::{
+    LiveServerMessage, LiveSessionResumptionConfig, LiveSetup, client_content_message,
+    live_generation_config, realtime_blob, realtime_input_message, setup_message,
+    tool_response_message,
+};
+
+pub use protocol::{GeminiLiveEvent, GeminiLiveFunctionCall, GeminiLiveFunctionResponse};
+
+mod protocol;
+
+type WsStream =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+type LiveEventStream = Pin<Box<dyn Stream<Item = Result<GeminiLiveEvent, LlmError>> + Send>>;
+
+const LIVE_SERVICE_PATH: &str = "GenerativeService.BidiGenerateContent";
+
+/// Setup options for a Gemini Live session.
+#[derive(Debug, Clone, Default)]
+pub struct GeminiLiveConfig {
+    /// Model id; falls back to the client model when unset.
+    pub model: Option<String>,
+    /// Generation config (`responseModalities`, sampling, thinking, ...).
+    pub generation_config: Option<GenerationConfig>,
+    /// Raw `speechConfig` (voice and language selection).
+    pub speech_config: Option<serde_json::Value>,
+    /// System instruction for the whole session.
+    pub system_instruction: Option<Content>,
+    /// Unified tools, converted with the same rules as `generateContent`.
+    pub tools: Vec<Tool>,
+    /// Gemini-native tools appended after the converted unified tools.
+    pub gemini_tools: Vec<GeminiTool>,
+    /// Request `sessionResumptionUpdate` messages from the server.
+    pub session_resumption: bool,
+    /// Resume a previous session from this handle (implies `session_resumption`).
+    pub resumption_handle: Option<String>,
+    /// Request transcriptions of the user's audio input.
+    pub input_audio_transcription: bool,
+    /// Request transcriptions of the model's audio output.
+    pub output_audio_transcription: bool,
+    /// Raw `realtimeInputConfig` (voice activity detection, activity handling).
+    pub realtime_input_config: Option<serde_json::Value>,
+    /// Raw `contextWindowCompression` config.
+    pub context_window_compression: Option<serde_json::Value>,
+    /// Override the WebSocket endpoint derived from the client base URL.
+    pub url: Option<String>,
+}
+
+impl GeminiLiveConfig {
+    /// Create a config for the given Live model.
+    pub fn new(model: impl Into<String>) -> Self {
+        Self {
+            model: Some(model.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Set the generation config.
+    pub fn with_generation_config(mut self, config: GenerationConfig) -> Self {
+        self.generation_config = Some(config);
+        self
+    }
+
+    /// Set the response modalities (e.g. `["AUDIO"]` or `["TEXT"]`).
+    pub fn with_response_modalities(mut self, modalities: Vec<String>) -> Self {
+        self.generation_config
+            .get_or_insert_with(GenerationConfig::default)
+            .response_modalities = Some(modalities);
+        self
+    }
+
+    /// Set a raw `speechConfig`.
+    pub fn with_speech_config(mut self, speech_config: serde_json::Value) -> Self {
+        self.speech_config = Some(speech_config);
+        self
+    }
+
+    /// Select a prebuilt voice by name.
+    pub fn with_voice(self, voice_name: impl Into<String>) -> Self {
+        self.with_speech_config(serde_json::json!({
+            "voiceConfig": { "prebuiltVoiceConfig": { "voiceName": voice_name.into() } }
+        }))
+    }
+
+    /// Set a plain-text system instruction.
+    pub fn with_system_instruction(mut self, instruction: impl Into<String>) -> Self {
+        self.system_instruction = Some(Content {
+            role: None,
+            parts: vec![Part::Text {
+                text: instruction.into(),
+                thought: None,
+                thought_signature: None,
+            }],
+        });
+        self
+    }
+
+    /// Set unified tools.
+    pub fn with_tools(mut self, tools: Vec<Tool>) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// Set Gemini-native tools.
+    pub fn with_gemini_tools(mut self, tools: Vec<GeminiTool>) -> Self {
+        self.gemini_tools = tools;
+        self
+    }
+
+    /// Enable session resumption updates.
+    pub fn with_session_resumption(mut self) -> Self {
+        self.session_resumption = true;
+        self
+    }
+
+    /// Resume a previous session using a handle from [`GeminiLiveEvent::SessionResumptionUpdate`].
+    pub fn with_resumption_handle(mut self, handle: impl Into<String>) -> Self {
+        self.session_resumption = true;
+        self.resumption_handle = Some(handle.into());
+        self
+    }
+
+    /// Enable input audio transcription.
+    pub fn with_input_audio_transcription(mut self) -> Self {
+        self.input_audio_transcription = true;
+        self
+    }
+
+    /// Enable output audio transcription.
+    pub fn with_output_audio_transcription(mut self) -> Self {
+        self.output_audio_transcription = true;
+        self
+    }
+
+    /// Set a raw `realtimeInputConfig`.
+    pub fn with_realtime_input_config(mut self, config: serde_json::Value) -> Self {
+        self.realtime_input_config = Some(config);
+        self
+    }
+
+    /// Set a raw `contextWindowCompression` config.
+    pub fn with_context_window_compression(mut self, config: serde_json::Value) -> Self {
+        self.context_window_compression = Some(config);
+        self
+    }
+
+    /// Override the WebSocket endpoint.
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    fn build_setup(&self, model: &str) -> Result<LiveSetup, LlmError> {
+        let mut tools = if self.tools.is_empty() {
+            Vec::new()
+        } else {
+            crate::standards::gemini::convert::convert_tools_to_gemini(model, &self.tools)?
+        };
+        tools.extend(self.gemini_tools.iter().cloned());
+
+        let enabled = || Some(serde_json::json!({}));
+        Ok(LiveSetup {
+            model: if model.contains('/') {
+                model.to_string()
+            } else {
+                format!("models/{model}")
+            },
+            generation_config: live_generation_config(
+                self.generation_config.as_ref(),
+                self.speech_config.as_ref(),
+            )?,
+            system_instruction: self.system_instruction.clone(),
+            tools,
+            session_resumption: (self.session_resumption || self.resumption_handle.is_some()).then(
+                || LiveSessionResumptionConfig {
+                    handle: self.resumption_handle.clone(),
+                },
+            ),
+            input_audio_transcription: self.input_audio_transcription.then(enabled).flatten(),
+            output_audio_transcription: self.output_audio_transcription.then(enabled).flatten(),
+            realtime_input_config: self.realtime_input_config.clone(),
+            context_window_compression: self.context_window_compression.clone(),
+        })
+    }
+}
+
+/// Derive the Live WebSocket endpoint from a Gemini REST base URL.
+///
+/// `https://host/v1beta` becomes
+/// `wss://host/ws/google.ai.generativelanguage.v1beta.GenerativeService.BidiGenerateContent`.
+pub fn live_ws_url(base_url: &str) -> Result<String, LlmError> {
+    let trimmed = base_url.trim().trim_end_matches('/');
+    let (scheme, rest) = if let Some(r) = trimmed.strip_prefix("https://") {
+        ("wss://", r)
+    } else if let Some(r) = trimmed.strip_prefix("http://") {
+        ("ws://", r)
+    } else if let Some(r) = trimmed.strip_prefix("wss://") {
+        ("wss://", r)
+    } else if let Some(r) = trimmed.strip_prefix("ws://") {
+        ("ws://", r)
+    } else {
+        return Err(LlmError::ConfigurationError(format!(
+            "Unsupported URL scheme for Gemini Live: {base_url}"
+        )));
+    };
+
+    let (prefix, version) = match rest.rsplit_once('/') {
+        Some((prefix, last)) if last.starts_with('v') => (prefix, last),
+        _ => (rest, "v1beta"),
+    };
+    Ok(format!(
+        "{scheme}{prefix}/ws/google.ai.generativelanguage.{version}.{LIVE_SERVICE_PATH}"
+    ))
+}
+
+#[derive(Debug, Default)]
+struct LiveState {
+    resumption_handle: Option<String>,
+}
+
+/// Sending half of a Live session. Cheap to clone and safe to share across tasks.
+#[derive(Clone)]
+pub struct GeminiLiveSender {
+    sink: Arc<Mutex<SplitSink<WsStream, Message>>>,
+    state: Arc<std::sync::Mutex<LiveState>>,
+}
+
+impl std::fmt::Debug for GeminiLiveSender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeminiLiveSender").finish_non_exhaustive()
+    }
+}
+
+impl GeminiLiveSender {
+    /// Send a raw client message (escape hatch for protocol fields not modeled here).
+    pub async fn send_json(&self, message: serde_json::Value) -> Result<(), LlmError> {
+        self.sink
+            .lock()
+            .await
+            .send(Message::Text(message.to_string().into()))
+            .await
+            .map_err(|e| LlmError::ConnectionError(format!("Gemini Live send failed: {e}")))
+    }
+
+    /// Append turns to the conversation (`clientContent`).
+    pub async fn send_client_content(
+        &self,
+        turns: Vec<Content>,
+        turn_complete: bool,
+    ) -> Result<(), LlmError> {
+        self.send_json(client_content_message(&turns, turn_complete))
+            .await
+    }
+
+    /// Send a complete user text turn.
+    pub async fn send_text(&self, text: impl Into<String>) -> Result<(), LlmError> {
+        let turn = Content {
+            role: Some("user".to_string()),
+            parts: vec![Part::Text {
+                text: text.into(),
+                thought: None,
+                thought_signature: None,
+            }],
+        };
+        self.send_client_content(vec![turn], true).await
+    }
+
+    /// Stream a chunk of realtime audio (e.g. `audio/pcm;rate=16000`).
+    pub async fn send_audio(&self, data: &[u8], mime_type: &str) -> Result<(), LlmError> {
+        self.send_json(realtime_input_message(
+            serde_json::json!({ "audio": realtime_blob(mime_type, data) }),
+        ))
+        .await
+    }
+
+    /// Stream a realtime video frame (e.g. `image/jpeg`).
+    pub async fn send_video(&self, data: &[u8], mime_type: &str) -> Result<(), LlmError> {
+        self.send_json(realtime_input_message(
+            serde_json::json!({ "video": realtime_blob(mime_type, data) }),
+        ))
+        .await
+    }
+
+    /// Send realtime text input.
+    pub async fn send_realtime_text(&self, text: impl Into<String>) -> Result<(), LlmError> {
+        self.send_json(realtime_input_message(
+            serde_json::json!({ "text": text.into() }),
+        ))
+        .await
+    }
+
+    /// Signal that the audio stream has paused (flushes server-side VAD).
+    pub async fn send_audio_stream_end(&self) -> Result<(), LlmError> {
+        self.send_json(realtime_input_message(
+            serde_json::json!({ "audioStreamEnd": true }),
+        ))
+        .await
+    }
+
+    /// Mark the start of user activity (when automatic activity detection is disabled).
+    pub async fn send_activity_start(&self) -> Result<(), LlmError> {
+        self.send_json(realtime_input_message(
+            serde_json::json!({ "activityStart": {} }),
+        ))
+        .await
+    }
+
+    /// Mark the end of user activity (when automatic activity detection is disabled).
+    pub async fn send_activity_end(&self) -> Result<(), LlmError> {
+        self.send_json(realtime_input_message(
+            serde_json::json!({ "activityEnd": {} }),
+        ))
+        .await
+    }
+
+    /// Answer tool calls received via [`GeminiLiveEvent::ToolCall`].
+    pub async fn send_tool_response(
+        &self,
+        responses: Vec<GeminiLiveFunctionResponse>,
+    ) -> Result<(), LlmError> {
+        self.send_json(tool_response_message(&responses)).await
+    }
+
+    /// Latest resumable session handle reported by the server, if any.
+    pub fn resumption_handle(&self) -> Option<String> {
+        self.state
+            .lock()
+            .ok()
+            .and_then(|st| st.resumption_handle.clone())
+    }
+
+    /// Close the WebSocket connection.
+    pub async fn close(&self) -> Result<(), LlmError> {
+        self.sink
+            .lock()
+            .await
+            .close()
+            .await
+            .map_err(|e| LlmError::ConnectionError(format!("Gemini Live close failed: {e}")))
+    }
+}
+
+/// Receiving half of a Live session; yields [`GeminiLiveEvent`]s.
+pub struct GeminiLiveReceiver {
+    inner: LiveEventStream,
+    pending: VecDeque<GeminiLiveEvent>,
+}
+
+impl std::fmt::Debug for GeminiLiveReceiver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GeminiLiveReceiver")
+            .field("pending", &self.pending.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl GeminiLiveReceiver {
+    fn new(ws: SplitStream<WsStream>, state: Arc<std::sync::Mutex<LiveState>>) -> Self {
+        Self {
+            inner: Box::pin(live_event_stream(ws, state)),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Receive the next event; `None` once the server closed the connection.
+    pub async fn next_event(&mut self) -> Option<Result<GeminiLiveEvent, LlmError>> {
+        self.next().await
+    }
+
+    /// Stream decoded audio chunks for the current model turn.
+    ///
+    /// The stream ends after `turnComplete` or `interrupted`. Non-audio events seen meanwhile
+    /// (including the terminal signal and any tool calls) are buffered and returned by
+    /// subsequent [`next_event`](Self::next_event) calls, so nothing is lost.
+    pub fn audio_turn(&mut self) -> impl Stream<Item = Result<Vec<u8>, LlmError>> + Send + '_ {
+        async_stream::stream! {
+            loop {
+                match self.inner.next().await {
+                    Some(Ok(GeminiLiveEvent::Audio { data, .. })) => yield Ok(data),
+                    Some(Ok(event)) => {
+                        let done = matches!(
+                            event,
+                            GeminiLiveEvent::TurnComplete | GeminiLiveEvent::Interrupted
+                        );
+                        self.pending.push_back(event);
+                        if done {
+                            break;
+                        }
+                    }
+                    Some(Err(e)) => {
+                        yield Err(e);
+                        break;
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+}
+
+impl Stream for GeminiLiveReceiver {
+    type Item = Result<GeminiLiveEvent, LlmError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Some(event) = self.pending.pop_front() {
+            return Poll::Ready(Some(Ok(event)));
+        }
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+fn live_event_stream(
+    mut ws: SplitStream<WsStream>,
+    state: Arc<std::sync::Mutex<LiveState>>,
+) -> impl Stream<Item = Result<GeminiLiveEvent, LlmError>> + Send {
+    async_stream::stream! {
+        while let Some(msg) = ws.next().await {
+            let text = match msg {
+                Ok(Message::Text(t)) => t.as_str().to_string(),
+                // The Live API delivers JSON payloads in binary frames.
+                Ok(Message::Binary(b)) => match String::from_utf8(b.to_vec()) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        yield Err(LlmError::ParseError(format!(
+                            "Gemini Live sent a non UTF-8 frame: {e}"
+                        )));
+                        continue;
+                    }
+                },
+                Ok(Message::Close(frame)) => {
+                    if let Some(frame) = frame
+                        && frame.code != CloseCode::Normal
+                    {
+                        yield Err(live_closed_error(u16::from(frame.code), frame.reason.as_str()));
+                    }
+                    break;
+                }
+                Ok(_) => continue,
+                Err(e) => {
+                    yield Err(LlmError::ConnectionError(format!(
+                        "Gemini Live connection error: {e}"
+                    )));
+                    break;
+                }
+            };
+
+            let events = match LiveServerMessage::parse(&text).and_then(|m| m.into_events()) {
+                Ok(events) => events,
+                Err(e) => {
+                    yield Err(e);
+                    continue;
+                }
+            };
+            for event in events {
+                if let GeminiLiveEvent::SessionResumptionUpdate {
+                    new_handle: Some(handle),
+                    resumable: true,
+                } = &event
+                    && let Ok(mut st) = state.lock()
+                {
+                    st.resumption_handle = Some(handle.clone());
+                }
+                yield Ok(event);
+            }
+        }
+    }
+}
+
+fn live_closed_error(code: u16, reason: &str) -> LlmError {
+    LlmError::ProviderError {
+        provider: "gemini".to_string(),
+        message: format!("Gemini Live session closed ({code}): {reason}"),
+        error_code: Some("live_session_closed".to_string()),
+    }
+}
+
+/// A connected Gemini Live session.
+///
+/// Sending methods are available through `Deref` to [`GeminiLiveSender`]; use
+/// [`split`](Self::split) to send and receive from different tasks.
+#[derive(Debug)]
+pub struct GeminiLiveSession {
+    sender: GeminiLiveSender,
+    receiver: GeminiLiveReceiver,
+}
+
+impl GeminiLiveSession {
+    /// Connect, send the setup message, and wait for `setupComplete`.
+    pub async fn connect(config: &GeminiConfig, live: GeminiLiveConfig) -> Result<Self, LlmError> {
+        let model = live
+            .model
+            .clone()
+            .filter(|m| !m.trim().is_empty())
+            .unwrap_or_else(|| config.model.clone());
+        if model.trim().is_empty() {
+            return Err(LlmError::ConfigurationError(
+                "Gemini Live session requires a non-empty model id".to_string(),
+            ));
+        }
+        let setup = live.build_setup(&model)?;
+
+        let url = match &live.url {
+            Some(url) => url.clone(),
+            None => live_ws_url(&config.base_url)?,
+        };
+        let mut request = url
+            .into_client_request()
+            .map_err(|e| LlmError::ConfigurationError(format!("Invalid Gemini Live URL: {e}")))?;
+
+        let ctx = super::context::build_context(config).await;
+        let headers =
+            build_gemini_headers(config.api_key.expose_secret(), &ctx.http_extra_headers)?;
+        for (name, value) in headers.iter() {
+            if name == reqwest::header::CONTENT_TYPE {
+                continue;
+            }
+            request.headers_mut().insert(name, value.clone());
+        }
+
+        let (ws, _resp) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|e| LlmError::ConnectionError(format!("Gemini Live connect failed: {e}")))?;
+        let (sink, stream) = ws.split();
+
+        let state = Arc::new(std::sync::Mutex::new(LiveState::default()));
+        let sender = GeminiLiveSender {
+            sink: Arc::new(Mutex::new(sink)),
+            state: Arc::clone(&state),
+        };
+        let mut receiver = GeminiLiveReceiver::new(stream, state);
+
+        sender.send_json(setup_message(&setup)).await?;
+        loop {
+            match receiver.inner.next().await {
+                Some(Ok(GeminiLiveEvent::SetupComplete)) => break,
+                Some(Ok(event)) => receiver.pending.push_back(event),
+                Some(Err(e)) => return Err(e),
+                None => {
+                    return Err(LlmError::ConnectionError(
+                        "Gemini Live connection closed before setupComplete".to_string(),
+                    ));
+                }
+            }
+        }
+
+        Ok(Self { sender, receiver })
+    }
+
+    /// Sending half (clonable).
+    pub fn sender(&self) -> &GeminiLiveSender {
+        &self.sender
+    }
+
+    /// Receive the next event; `None` once the server closed the connection.
+    pub async fn next_event(&mut self) -> Option<Result<GeminiLiveEvent, LlmError>> {
+        self.receiver.next_event().await
+    }
+
+    /// Stream decoded audio chunks for the current model turn.
+    ///
+    /// See [`GeminiLiveReceiver::audio_turn`].
+    pub fn audio_turn(&mut self) -> impl Stream<Item = Result<Vec<u8>, LlmError>> + Send + '_ {
+        self.receiver.audio_turn()
+    }
+
+    /// Split into independently owned sending and receiving halves.
+    pub fn split(self) -> (GeminiLiveSender, GeminiLiveReceiver) {
+        (self.sender, self.receiver)
+    }
+}
+
+impl Deref for GeminiLiveSession {
+    type Target = GeminiLiveSender;
+
+    fn deref(&self) -> &Self::Target {
+        &self.sender
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::result_large_err)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+
+    async fn next_json(
+        ws: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+    ) -> serde_json::Value {
+        let Message::Text(text) = ws.next().await.unwrap().unwrap() else {
+            panic!("expected text message");
+        };
+        serde_json::from_str(text.as_str()).unwrap()
+    }
+
+    async fn send_json(
+        ws: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+        value: serde_json::Value,
+    ) {
+        // Mirror the real service, which sends JSON in binary frames.
+        ws.send(Message::Binary(value.to_string().into_bytes().into()))
+            .await
+            .unwrap();
+    }
+
+    fn config(addr: std::net::SocketAddr) -> GeminiConfig {
+        GeminiConfig::new("test-key")
+            .with_base_url(format!("http://{addr}/v1beta"))
+            .with_model("gemini-live-2.5-flash-preview".to_string())
+    }
+
+    #[test]
+    fn live_ws_url_maps_rest_base_url() {
+        assert_eq!(
+            live_ws_url("https://generativelanguage.googleapis.com/v1beta/").unwrap(),
+            "wss://generativelanguage.googleapis.com/ws/google.ai.generativelanguage.v1beta.GenerativeService.BidiGenerateContent"
+        );
+        assert_eq!(
+            live_ws_url("http://127.0.0.1:9000/proxy").unwrap(),
+            "ws://127.0.0.1:9000/proxy/ws/google.ai.generativelanguage.v1beta.GenerativeService.BidiGenerateContent"
+        );
+        assert!(live_ws_url("ftp://example.com").is_err());
+    }
+
+    #[tokio::test]
+    async fn live_session_round_trips_setup_audio_and_tool_calls() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_hdr_async(tcp, |req: &Request, resp: Response| {
+                assert_eq!(
+                    req.uri().path(),
+                    "/ws/google.ai.generativelanguage.v1beta.GenerativeService.BidiGenerateContent"
+                );
+                assert_eq!(
+                    req.headers()
+                        .get("x-goog-api-key")
+                        .and_then(|v| v.to_str().ok()),
+                    Some("test-key")
+                );
+                Ok(resp)
+            })
+            .await
+            .unwrap();
+
+            let setup = next_json(&mut ws).await;
+            assert_eq!(
+                setup["setup"]["model"],
+                "models/gemini-live-2.5-flash-preview"
+            );
+            assert_eq!(
+                setup["setup"]["generationConfig"]["responseModalities"],
+                serde_json::json!(["AUDIO"])
+            );
+            assert_eq!(
+                setup["setup"]["systemInstruction"]["parts"][0]["text"],
+                "be brief"
+            );
+            assert_eq!(
+                setup["setup"]["tools"][0]["functionDeclarations"][0]["name"],
+                "lookup"
+            );
+            assert_eq!(setup["setup"]["sessionResumption"], serde_json::json!({}));
+            send_json(&mut ws, serde_json::json!({ "setupComplete": {} })).await;
+
+            let audio_in = next_json(&mut ws).await;
+            assert_eq!(
+                audio_in["realtimeInput"]["audio"]["mimeType"],
+                "audio/pcm;rate=16000"
+            );
+            assert_eq!(audio_in["realtimeInput"]["audio"]["data"], "AAE=");
+
+            send_json(
+                &mut ws,
+                serde_json::json!({
+                    "toolCall": { "functionCalls": [
+                        { "id": "c1", "name": "lookup", "args": { "q": "rust" } }
+                    ]}
+                }),
+            )
+            .await;
+
+            let tool_response = next_json(&mut ws).await;
+            assert_eq!(
+                tool_response["toolResponse"]["functionResponses"][0],
+                serde_json::json!({ "id": "c1", "name": "lookup", "response": { "ok": true } })
+            );
+
+            let chunk = base64::engine::general_purpose::STANDARD.encode([7u8, 8]);
+            send_json(
+                &mut ws,
+                serde_json::json!({
+                    "serverContent": { "modelTurn": { "parts": [
+                        { "inlineData": { "mimeType": "audio/pcm;rate=24000", "data": chunk } }
+                    ]}}
+                }),
+            )
+            .await;
+            send_json(
+                &mut ws,
+                serde_json::json!({
+                    "serverContent": {
+                        "modelTurn": { "parts": [
+                            { "inlineData": { "mimeType": "audio/pcm;rate=24000", "data": chunk } }
+                        ]},
+                        "turnComplete": true
+                    }
+                }),
+            )
+            .await;
+            send_json(
+                &mut ws,
+                serde_json::json!({
+                    "sessionResumptionUpdate": { "newHandle": "handle-1", "resumable": true }
+                }),
+            )
+            .await;
+            send_json(
+                &mut ws,
+                serde_json::json!({ "serverContent": { "interrupted": true } }),
+            )
+            .await;
+            ws.close(None).await.unwrap();
+        });
+
+        let live = GeminiLiveConfig::default()
+            .with_response_modalities(vec!["AUDIO".to_string()])
+            .with_system_instruction("be brief")
+            .with_tools(vec![Tool::function(
+                "lookup",
+                "Look something up",
+                serde_json::json!({ "type": "object", "properties": { "q": { "type": "string" } } }),
+            )])
+            .with_session_resumption();
+        let mut session = GeminiLiveSession::connect(&config(addr), live)
+            .await
+            .unwrap();
+
+        session
+            .send_audio(&[0, 1], "audio/pcm;rate=16000")
+            .await
+            .unwrap();
+
+        let Some(Ok(GeminiLiveEvent::ToolCall { calls })) = session.next_event().await else {
+            panic!("expected tool call");
+        };
+        session
+            .send_tool_response(vec![GeminiLiveFunctionResponse::for_call(
+                &calls[0],
+                serde_json::json!({ "ok": true }),
+            )])
+            .await
+            .unwrap();
+
+        let audio: Vec<Vec<u8>> = session
+            .audio_turn()
+            .map(|chunk| chunk.unwrap())
+            .collect()
+            .await;
+        assert_eq!(audio, vec![vec![7, 8], vec![7, 8]]);
+        assert_eq!(
+            session.next_event().await.unwrap().unwrap(),
+            GeminiLiveEvent::TurnComplete
+        );
+
+        assert!(matches!(
+            session.next_event().await,
+            Some(Ok(GeminiLiveEvent::SessionResumptionUpdate { .. }))
+        ));
+        assert_eq!(session.resumption_handle().as_deref(), Some("handle-1"));
+        assert_eq!(
+            session.next_event().await.unwrap().unwrap(),
+            GeminiLiveEvent::Interrupted
+        );
+        assert!(session.next_event().await.is_none());
+
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn live_session_resumes_with_handle_and_surfaces_close_errors() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+
+            let setup = next_json(&mut ws).await;
+            assert_eq!(
+                setup["setup"]["sessionResumption"],
+                serde_json::json!({ "handle": "handle-1" })
+            );
+            send_json(&mut ws, serde_json::json!({ "setupComplete": {} })).await;
+
+            let text = next_json(&mut ws).await;
+            assert_eq!(
+                text["clientContent"],
+                serde_json::json!({
+                    "turns": [{ "role": "user", "parts": [{ "text": "hello" }] }],
+                    "turnComplete": true
+                })
+            );
+            ws.close(Some(tokio_tungstenite::tungstenite::protocol::CloseFrame {
+                code: CloseCode::Policy,
+                reason: "quota exceeded".into(),
+            }))
+            .await
+            .unwrap();
+        });
+
+        let live =
+            GeminiLiveConfig::new("gemini-2.0-flash-live-001").with_resumption_handle("handle-1");
+        let mut session = GeminiLiveSession::connect(&config(addr), live)
+            .await
+            .unwrap();
+        session.send_text("hello").await.unwrap();
+
+        let err = session.next_event().await.unwrap().unwrap_err();
+        assert!(
+            matches!(&err, LlmError::ProviderError { error_code: Some(code), message, .. }
+                if code == "live_session_closed" && message.contains("quota exceeded")),
+            "unexpected error: {err:?}"
+        );
+        assert!(session.next_event().await.is_none());
+
+        server.await.unwrap();
+    }
+}
@@ -0,0 +1,418 @@
+//! Wire types for the Gemini Live `BidiGenerateContent` protocol.
+//!
+//! Client messages are serialized as single-key JSON envelopes (`setup`, `clientContent`,
+//! `realtimeInput`, `toolResponse`). Server messages are parsed leniently: unknown keys are
+//! ignored so new server fields do not break existing sessions.
+
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+
+use crate::error::LlmError;
+use crate::providers::gemini::types::{Blob, Content, GeminiTool, GenerationConfig, Part};
+
+/// `BidiGenerateContentSetup` payload (first message on every connection).
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct LiveSetup {
+    pub model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub generation_config: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_instruction: Option<Content>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<GeminiTool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_resumption: Option<LiveSessionResumptionConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_audio_transcription: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_audio_transcription: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub realtime_input_config: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_window_compression: Option<serde_json::Value>,
+}
+
+/// `SessionResumptionConfig` (an empty object enables resumption updates).
+#[derive(Debug, Clone, Default, Serialize)]
+pub(super) struct LiveSessionResumptionConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handle: Option<String>,
+}
+
+/// Merge the typed generation config with Live-only `speechConfig`.
+pub(super) fn live_generation_config(
+    generation_config: Option<&GenerationConfig>,
+    speech_config: Option<&serde_json::Value>,
+) -> Result<Option<serde_json::Value>, LlmError> {
+    if generation_config.is_none() && speech_config.is_none() {
+        return Ok(None);
+    }
+
+    let mut value = match generation_config {
+        Some(cfg) => serde_json::to_value(cfg).map_err(|e| {
+            LlmError::ParseError(format!(
+                "Failed to serialize Gemini Live generation config: {e}"
+            ))
+        })?,
+        None => serde_json::Value::Object(serde_json::Map::new()),
+    };
+    if let (Some(speech), Some(obj)) = (speech_config, value.as_object_mut()) {
+        obj.insert("speechConfig".to_string(), speech.clone());
+    }
+    Ok(Some(value))
+}
+
+pub(super) fn setup_message(setup: &LiveSetup) -> serde_json::Value {
+    serde_json::json!({ "setup": setup })
+}
+
+pub(super) fn client_content_message(turns: &[Content], turn_complete: bool) -> serde_json::Value {
+    serde_json::json!({
+        "clientContent": {
+            "turns": turns,
+            "turnComplete": turn_complete,
+        }
+    })
+}
+
+pub(super) fn realtime_input_message(input: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({ "realtimeInput": input })
+}
+
+pub(super) fn realtime_blob(mime_type: &str, data: &[u8]) -> Blob {
+    Blob {
+        mime_type: mime_type.to_string(),
+        data: base64::engine::general_purpose::STANDARD.encode(data),
+    }
+}
+
+pub(super) fn tool_response_message(responses: &[GeminiLiveFunctionResponse]) -> serde_json::Value {
+    serde_json::json!({
+        "toolResponse": {
+            "functionResponses": responses,
+        }
+    })
+}
+
+/// A function call requested by the model during a Live session.
+///
+/// Unlike `generateContent`, Live calls carry an `id` that must be echoed back in the
+/// matching [`GeminiLiveFunctionResponse`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeminiLiveFunctionCall {
+    #[serde(default)]
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub args: serde_json::Value,
+}
+
+/// Result of a client-side tool execution sent back to the Live session.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeminiLiveFunctionResponse {
+    pub id: String,
+    pub name: String,
+    pub response: serde_json::Value,
+}
+
+impl GeminiLiveFunctionResponse {
+    /// Build a response for a previously received call.
+    pub fn for_call(call: &GeminiLiveFunctionCall, response: serde_json::Value) -> Self {
+        Self {
+            id: call.id.clone(),
+            name: call.name.clone(),
+            response,
+        }
+    }
+}
+
+/// Normalized events produced by a Gemini Live session.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeminiLiveEvent {
+    /// The server accepted the setup message.
+    SetupComplete,
+    /// Model text output (`thought` marks thought summaries).
+    Text { text: String, thought: bool },
+    /// Decoded model audio output (typically `audio/pcm;rate=24000`).
+    Audio { data: Vec<u8>, mime_type: String },
+    /// Any other model turn part (executable code, code execution results, ...).
+    ModelPart(serde_json::Value),
+    /// Transcription of the user's audio input.
+    InputTranscription { text: String },
+    /// Transcription of the model's audio output.
+    OutputTranscription { text: String },
+    /// The model requested client-side tool execution.
+    ToolCall { calls: Vec<GeminiLiveFunctionCall> },
+    /// Previously requested tool calls are no longer needed.
+    ToolCallCancellation { ids: Vec<String> },
+    /// The model's generation was interrupted by user activity.
+    Interrupted,
+    /// The model finished generating (playback may still be pending).
+    GenerationComplete,
+    /// The model's turn is complete.
+    TurnComplete,
+    /// The server will close the connection soon; `time_left` is a protobuf duration string.
+    GoAway { time_left: Option<String> },
+    /// A new session resumption handle is available.
+    SessionResumptionUpdate {
+        new_handle: Option<String>,
+        resumable: bool,
+    },
+    /// Token usage reported by the server.
+    UsageMetadata(serde_json::Value),
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct LiveServerMessage {
+    #[serde(default)]
+    setup_complete: Option<serde_json::Value>,
+    #[serde(default)]
+    server_content: Option<LiveServerContent>,
+    #[serde(default)]
+    tool_call: Option<LiveToolCall>,
+    #[serde(default)]
+    tool_call_cancellation: Option<LiveToolCallCancellation>,
+    #[serde(default)]
+    go_away: Option<LiveGoAway>,
+    #[serde(default)]
+    session_resumption_update: Option<LiveSessionResumptionUpdate>,
+    #[serde(default)]
+    usage_metadata: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveServerContent {
+    #[serde(default)]
+    model_turn: Option<Content>,
+    #[serde(default)]
+    turn_complete: bool,
+    #[serde(default)]
+    interrupted: bool,
+    #[serde(default)]
+    generation_complete: bool,
+    #[serde(default)]
+    input_transcription: Option<LiveTranscription>,
+    #[serde(default)]
+    output_transcription: Option<LiveTranscription>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct LiveTranscription {
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveToolCall {
+    #[serde(default)]
+    function_calls: Vec<GeminiLiveFunctionCall>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct LiveToolCallCancellation {
+    #[serde(default)]
+    ids: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveGoAway {
+    #[serde(default)]
+    time_left: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveSessionResumptionUpdate {
+    #[serde(default)]
+    new_handle: Option<String>,
+    #[serde(default)]
+    resumable: bool,
+}
+
+impl LiveServerMessage {
+    pub(super) fn parse(text: &str) -> Result<Self, LlmError> {
+        serde_json::from_str(text).map_err(|e| {
+            LlmError::ParseError(format!("Failed to parse Gemini Live server message: {e}"))
+        })
+    }
+
+    /// Flatten one server message into ordered events.
+    pub(super) fn into_events(self) -> Result<Vec<GeminiLiveEvent>, LlmError> {
+        let mut out = Vec::new();
+
+        if self.setup_complete.is_some() {
+            out.push(GeminiLiveEvent::SetupComplete);
+        }
+
+        if let Some(content) = self.server_content {
+            if let Some(t) = content.input_transcription {
+                out.push(GeminiLiveEvent::InputTranscription { text: t.text });
+            }
+            if let Some(turn) = content.model_turn {
+                for part in turn.parts {
+                    out.push(part_event(part)?);
+                }
+            }
+            if let Some(t) = content.output_transcription {
+                out.push(GeminiLiveEvent::OutputTranscription { text: t.text });
+            }
+            if content.interrupted {
+                out.push(GeminiLiveEvent::Interrupted);
+            }
+            if content.generation_complete {
+                out.push(GeminiLiveEvent::GenerationComplete);
+            }
+            if content.turn_complete {
+                out.push(GeminiLiveEvent::TurnComplete);
+            }
+        }
+
+        if let Some(call) = self.tool_call {
+            out.push(GeminiLiveEvent::ToolCall {
+                calls: call.function_calls,
+            });
+        }
+        if let Some(cancel) = self.tool_call_cancellation {
+            out.push(GeminiLiveEvent::ToolCallCancellation { ids: cancel.ids });
+        }
+        if let Some(update) = self.session_resumption_update {
+            out.push(GeminiLiveEvent::SessionResumptionUpdate {
+                new_handle: update.new_handle,
+                resumable: update.resumable,
+            });
+        }
+        if let Some(go_away) = self.go_away {
+            out.push(GeminiLiveEvent::GoAway {
+                time_left: go_away.time_left,
+            });
+        }
+        if let Some(usage) = self.usage_metadata {
+            out.push(GeminiLiveEvent::UsageMetadata(usage));
+        }
+
+        Ok(out)
+    }
+}
+
+fn part_event(part: Part) -> Result<GeminiLiveEvent, LlmError> {
+    match part {
+        Part::Text { text, thought, .. } => Ok(GeminiLiveEvent::Text {
+            text,
+            thought: thought.unwrap_or(false),
+        }),
+        Part::InlineData { inline_data, .. } if inline_data.mime_type.starts_with("audio/") => {
+            let data = base64::engine::general_purpose::STANDARD
+                .decode(inline_data.data.as_bytes())
+                .map_err(|e| {
+                    LlmError::ParseError(format!("Invalid base64 audio in Gemini Live output: {e}"))
+                })?;
+            Ok(GeminiLiveEvent::Audio {
+                data,
+                mime_type: inline_data.mime_type,
+            })
+        }
+        other => Ok(GeminiLiveEvent::ModelPart(
+            serde_json::to_value(other).unwrap_or(serde_json::Value::Null),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn events(json: serde_json::Value) -> Vec<GeminiLiveEvent> {
+        LiveServerMessage::parse(&json.to_string())
+            .unwrap()
+            .into_events()
+            .unwrap()
+    }
+
+    #[test]
+    fn server_content_flattens_parts_in_order() {
+        let audio = base64::engine::general_purpose::STANDARD.encode([1u8, 2, 3]);
+        let out = events(serde_json::json!({
+            "serverContent": {
+                "modelTurn": { "parts": [
+                    { "text": "hi" },
+                    { "inlineData": { "mimeType": "audio/pcm;rate=24000", "data": audio } }
+                ]},
+                "outputTranscription": { "text": "hi" },
+                "turnComplete": true
+            }
+        }));
+        assert_eq!(
+            out,
+            vec![
+                GeminiLiveEvent::Text {
+                    text: "hi".to_string(),
+                    thought: false
+                },
+                GeminiLiveEvent::Audio {
+                    data: vec![1, 2, 3],
+                    mime_type: "audio/pcm;rate=24000".to_string()
+                },
+                GeminiLiveEvent::OutputTranscription {
+                    text: "hi".to_string()
+                },
+                GeminiLiveEvent::TurnComplete,
+            ]
+        );
+    }
+
+    #[test]
+    fn tool_call_and_resumption_messages_are_parsed() {
+        let out = events(serde_json::json!({
+            "toolCall": { "functionCalls": [
+                { "id": "call-1", "name": "lookup", "args": { "q": "x" } }
+            ]}
+        }));
+        assert_eq!(
+            out,
+            vec![GeminiLiveEvent::ToolCall {
+                calls: vec![GeminiLiveFunctionCall {
+                    id: "call-1".to_string(),
+                    name: "lookup".to_string(),
+                    args: serde_json::json!({ "q": "x" }),
+                }]
+            }]
+        );
+
+        let out = events(serde_json::json!({
+            "sessionResumptionUpdate": { "newHandle": "h-2", "resumable": true },
+            "unknownField": {}
+        }));
+        assert_eq!(
+            out,
+            vec![GeminiLiveEvent::SessionResumptionUpdate {
+                new_handle: Some("h-2".to_string()),
+                resumable: true
+            }]
+        );
+    }
+
+    #[test]
+    fn generation_config_merges_speech_config() {
+        let cfg = GenerationConfig {
+            response_modalities: Some(vec!["AUDIO".to_string()]),
+            ..Default::default()
+        };
+        let speech = serde_json::json!({
+            "voiceConfig": { "prebuiltVoiceConfig": { "voiceName": "Puck" } }
+        });
+        let merged = live_generation_config(Some(&cfg), Some(&speech))
+            .unwrap()
+            .unwrap();
+        assert_eq!(merged["responseModalities"], serde_json::json!(["AUDIO"]));
+        assert_eq!(
+            merged["speechConfig"]["voiceConfig"]["prebuiltVoiceConfig"]["voiceName"],
+            "Puck"
+        );
+        assert!(live_generation_config(None, None).unwrap().is_none());
+    }
+}
@@ -10,6 +10,7 @@
 //! - `files.rs` - File management capability implementation
 //! - `code_execution.rs` - Code execution feature implementation
 //! - `streaming.rs` - Streaming functionality with JSON buffering
+//! - `live.rs` - Live API WebSocket sessions (`gemini-live` feature)
 //! - Embeddings are executed via Executors + Transformers (no standalone HTTP module)
 //!
 //! # Example Usage
@@ -46,6 +47,8 @@ pub mod ext;
 pub mod file_search_stores;
 pub mod files;
 pub mod interactions;
+#[cfg(feature = "gemini-live")]
+pub mod live;
 pub mod middleware;
 pub mod model_constants;
 pub mod models;
@@ -90,6 +93,11 @@ pub use ext::{
 pub use file_search_stores::GeminiFileSearchStores;
 pub use files::GeminiFiles;
 pub use interactions::{GoogleInteractionsLanguageModel, GoogleInteractionsModelInput};
+#[cfg(feature = "gemini-live")]
+pub use live::{
+    GeminiLiveConfig, GeminiLiveEvent, GeminiLiveFunctionCall, GeminiLiveFunctionResponse,
+    GeminiLiveReceiver, GeminiLiveSender, GeminiLiveSession,
+};
 pub use middleware::GeminiToolWarningsMiddleware;
 pub use models::GeminiModels;
 #[allow(deprecated)]
@@ -1103,6 +1103,11 @@ data: [DONE]
             serde_json::json!("req_01jrh9nn61f24rydqq1r4b3yg5")
         );
         assert_eq!(response.metadata["segments"][0]["text"], "Hello world!");
+        let segments = response.segments.expect("typed segments");
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].end, 2.48);
+        assert_eq!(segments[0].avg_logprob, Some(-0.29010406));
+        assert_eq!(segments[0].no_speech_prob, Some(0.032802984));
     }
 }
 
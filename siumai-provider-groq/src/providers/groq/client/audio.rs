@@ -116,6 +116,7 @@ impl AudioCapability for GroqClient {
             language,
             confidence: None,
             words,
+            segments: crate::standards::openai::audio::parse_stt_segments(&raw),
             duration,
             metadata,
             warnings: None,
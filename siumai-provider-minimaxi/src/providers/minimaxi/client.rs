@@ -13,13 +13,14 @@ use crate::streaming::ChatStream;
 use crate::traits::{
     AudioCapability, ChatCapability, FileManagementCapability, ImageExtras,
     ImageGenerationCapability, MusicGenerationCapability, ProviderCapabilities,
-    VideoGenerationCapability,
+    VideoGenerationCapability, VoiceManagementCapability,
 };
 use crate::types::*;
 use std::sync::Arc;
 
 use super::config::MinimaxiConfig;
 use super::files::MinimaxiFiles;
+use super::voices::MinimaxiVoices;
 
 /// MiniMaxi client that implements all capabilities
 pub struct MinimaxiClient {
@@ -202,6 +203,17 @@ impl MinimaxiClient {
         )
     }
 
+    fn voices(&self) -> MinimaxiVoices {
+        MinimaxiVoices::new(
+            self.config.clone(),
+            self.http_client.clone(),
+            self.http_config.clone(),
+            self.http_interceptors.clone(),
+            self.retry_options.clone(),
+            self.http_transport.clone(),
+        )
+    }
+
     fn build_chat_executor(
         &self,
         request: &ChatRequest,
@@ -316,6 +328,10 @@ impl LlmClient for MinimaxiClient {
         Some(self)
     }
 
+    fn as_voice_management_capability(&self) -> Option<&dyn VoiceManagementCapability> {
+        Some(self)
+    }
+
     fn as_video_generation_capability(
         &self,
     ) -> Option<&dyn crate::traits::VideoGenerationCapability> {
@@ -431,6 +447,35 @@ impl AudioCapability for MinimaxiClient {
             "Speech-to-text is not yet supported for MiniMaxi".to_string(),
         ))
     }
+
+    async fn get_voices(&self) -> Result<Vec<VoiceInfo>, LlmError> {
+        self.voices().list_voices().await
+    }
+}
+
+#[async_trait]
+impl VoiceManagementCapability for MinimaxiClient {
+    async fn list_voices(&self) -> Result<Vec<VoiceInfo>, LlmError> {
+        self.voices().list_voices().await
+    }
+
+    async fn clone_voice(
+        &self,
+        request: VoiceCloneRequest,
+    ) -> Result<VoiceCreationResponse, LlmError> {
+        self.voices().clone_voice(request).await
+    }
+
+    async fn design_voice(
+        &self,
+        request: VoiceDesignRequest,
+    ) -> Result<VoiceCreationResponse, LlmError> {
+        self.voices().design_voice(request).await
+    }
+
+    async fn delete_voice(&self, voice_id: String) -> Result<VoiceDeleteResponse, LlmError> {
+        self.voices().delete_voice(voice_id).await
+    }
 }
 
 #[async_trait]
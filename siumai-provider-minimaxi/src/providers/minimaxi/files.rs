@@ -70,7 +70,7 @@ fn parse_file_id(file_id: &str) -> Result<i64, LlmError> {
     })
 }
 
-fn check_base_resp(provider: &str, json: &serde_json::Value) -> Result<(), LlmError> {
+pub(super) fn check_base_resp(provider: &str, json: &serde_json::Value) -> Result<(), LlmError> {
     let base = json.get("base_resp").ok_or_else(|| {
         LlmError::ParseError(format!("{provider}: missing 'base_resp' in response"))
     })?;
@@ -184,7 +184,7 @@ impl MinimaxiFiles {
         }
     }
 
-    fn build_http_config(&self) -> HttpExecutionConfig {
+    pub(super) fn build_http_config(&self) -> HttpExecutionConfig {
         let base_url = resolve_api_root_base_url(&self.config.base_url);
         let mut wiring = HttpExecutionWiring::new(
             "minimaxi",
@@ -201,7 +201,7 @@ impl MinimaxiFiles {
         wiring.config(std::sync::Arc::new(MinimaxiFilesSpec))
     }
 
-    fn base_url(&self) -> String {
+    pub(super) fn base_url(&self) -> String {
         resolve_api_root_base_url(&self.config.base_url)
             .trim_end_matches('/')
             .to_string()
@@ -55,6 +55,7 @@ pub mod spec;
 pub mod transformers;
 pub mod types;
 mod utils;
+pub mod voices;
 
 // Capability modules
 pub mod audio;
@@ -75,6 +76,7 @@ pub use config::MinimaxiConfig;
 pub use files::MinimaxiFiles;
 pub use spec::MinimaxiSpec;
 pub use types::*;
+pub use voices::MinimaxiVoices;
 
 // Typed provider metadata views (provider-owned; re-exported via this provider for ergonomics).
 pub use crate::provider_metadata::minimaxi::{
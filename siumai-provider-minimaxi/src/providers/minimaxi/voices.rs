@@ -0,0 +1,314 @@
+//! MiniMaxi voice management implementation.
+//!
+//! MiniMaxi exposes voice endpoints under the API root:
+//! - `POST /v1/get_voice` (system, cloned and generated voices)
+//! - `POST /v1/voice_clone` (clone from a file uploaded with purpose `voice_clone`)
+//! - `POST /v1/voice_design` (generate a voice from a text prompt)
+//! - `POST /v1/delete_voice` (JSON body)
+//!
+//! Cloned and designed voice ids are accepted as `voice_setting.voice_id` by the TTS endpoint.
+
+use async_trait::async_trait;
+
+use crate::error::LlmError;
+use crate::execution::executors::common::{HttpBody, execute_json_request};
+use crate::execution::http::interceptor::HttpInterceptor;
+use crate::retry_api::RetryOptions;
+use crate::traits::{FileManagementCapability, VoiceManagementCapability};
+use crate::types::{
+    FileUploadRequest, ProviderOptionsMap, VoiceCloneRequest, VoiceCreationResponse,
+    VoiceDeleteResponse, VoiceDesignRequest, VoiceInfo,
+};
+
+use super::config::MinimaxiConfig;
+use super::files::{MinimaxiFiles, check_base_resp};
+
+/// Voice list sections returned by `get_voice`, paired with the category reported in `VoiceInfo`.
+const VOICE_SECTIONS: &[(&str, &str)] = &[
+    ("system_voice", "system"),
+    ("voice_cloning", "cloned"),
+    ("voice_generation", "generated"),
+];
+
+fn map_voice(raw: &serde_json::Value, category: &str) -> Option<VoiceInfo> {
+    let id = raw.get("voice_id").and_then(|v| v.as_str())?;
+    let name = raw
+        .get("voice_name")
+        .and_then(|v| v.as_str())
+        .filter(|name| !name.is_empty())
+        .unwrap_or(id);
+
+    let mut voice = VoiceInfo::new(id, name).with_category(category);
+    let description = match raw.get("description") {
+        Some(serde_json::Value::String(text)) => Some(text.clone()),
+        Some(serde_json::Value::Array(lines)) => Some(
+            lines
+                .iter()
+                .filter_map(|line| line.as_str())
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        _ => None,
+    };
+    if let Some(description) = description.filter(|d| !d.is_empty()) {
+        voice = voice.with_description(description);
+    }
+    Some(voice)
+}
+
+/// Map the MiniMaxi voice category used in `VoiceInfo` back to a `delete_voice` voice type.
+fn delete_voice_type(category: &str) -> Option<&'static str> {
+    match category {
+        "cloned" | "voice_cloning" => Some("voice_cloning"),
+        "generated" | "voice_generation" => Some("voice_generation"),
+        _ => None,
+    }
+}
+
+fn merge_provider_options(body: &mut serde_json::Value, provider_options: &ProviderOptionsMap) {
+    if let (Some(target), Some(options)) = (
+        body.as_object_mut(),
+        provider_options.get_object("minimaxi"),
+    ) {
+        for (key, value) in options {
+            target.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+fn reference_filename(media_type: Option<&str>) -> &'static str {
+    match media_type {
+        Some("audio/wav" | "audio/x-wav" | "audio/wave") => "reference.wav",
+        Some("audio/mp4" | "audio/m4a" | "audio/x-m4a") => "reference.m4a",
+        _ => "reference.mp3",
+    }
+}
+
+/// MiniMaxi voice management client (extension capability).
+///
+/// Reference audio for cloning goes through [`MinimaxiFiles`], so the same base URL
+/// normalization, interceptors and retry options apply to every voice call.
+#[derive(Clone)]
+pub struct MinimaxiVoices {
+    files: MinimaxiFiles,
+}
+
+impl MinimaxiVoices {
+    pub fn new(
+        config: MinimaxiConfig,
+        http_client: reqwest::Client,
+        http_config: crate::types::HttpConfig,
+        http_interceptors: Vec<std::sync::Arc<dyn HttpInterceptor>>,
+        retry_options: Option<RetryOptions>,
+        http_transport: Option<
+            std::sync::Arc<dyn crate::execution::http::transport::HttpTransport>,
+        >,
+    ) -> Self {
+        Self {
+            files: MinimaxiFiles::new(
+                config,
+                http_client,
+                http_config,
+                http_interceptors,
+                retry_options,
+                http_transport,
+            ),
+        }
+    }
+
+    async fn post_json(
+        &self,
+        endpoint: &str,
+        body: serde_json::Value,
+        http_config: Option<&crate::types::HttpConfig>,
+    ) -> Result<serde_json::Value, LlmError> {
+        let cfg = self.files.build_http_config();
+        let url = format!("{}{endpoint}", self.files.base_url());
+        let res =
+            execute_json_request(&cfg, &url, HttpBody::Json(body), http_config, false).await?;
+        check_base_resp("minimaxi", &res.json)?;
+        Ok(res.json)
+    }
+
+    fn split_voice_id_and_type(voice_id: &str) -> (String, Option<String>) {
+        let trimmed = voice_id.trim();
+        if let Some((id, voice_type)) = trimmed.split_once(':') {
+            (id.trim().to_string(), Some(voice_type.trim().to_string()))
+        } else {
+            (trimmed.to_string(), None)
+        }
+    }
+}
+
+#[async_trait]
+impl VoiceManagementCapability for MinimaxiVoices {
+    async fn list_voices(&self) -> Result<Vec<VoiceInfo>, LlmError> {
+        let json = self
+            .post_json(
+                "/v1/get_voice",
+                serde_json::json!({ "voice_type": "all" }),
+                None,
+            )
+            .await?;
+
+        let mut voices = Vec::new();
+        for (section, category) in VOICE_SECTIONS {
+            if let Some(items) = json.get(*section).and_then(|v| v.as_array()) {
+                voices.extend(items.iter().filter_map(|raw| map_voice(raw, category)));
+            }
+        }
+        Ok(voices)
+    }
+
+    async fn clone_voice(
+        &self,
+        request: VoiceCloneRequest,
+    ) -> Result<VoiceCreationResponse, LlmError> {
+        let voice_id = request.voice_id.clone().ok_or_else(|| {
+            LlmError::InvalidInput(
+                "MiniMaxi voice cloning requires VoiceCloneRequest.voice_id".into(),
+            )
+        })?;
+
+        let filename = request
+            .filename
+            .clone()
+            .unwrap_or_else(|| reference_filename(request.media_type.as_deref()).to_string());
+        let uploaded = self
+            .files
+            .upload_file(FileUploadRequest {
+                content: request.audio.clone(),
+                filename: Some(filename),
+                mime_type: request.media_type.clone(),
+                purpose: "voice_clone".to_string(),
+                metadata: Default::default(),
+                provider_options: Default::default(),
+                http_config: request.http_config.clone(),
+            })
+            .await?;
+        let file_id = uploaded.id.parse::<i64>().map_err(|e| {
+            LlmError::ParseError(format!(
+                "minimaxi: invalid uploaded file id '{}': {e}",
+                uploaded.id
+            ))
+        })?;
+
+        let mut body = serde_json::json!({
+            "file_id": file_id,
+            "voice_id": voice_id,
+        });
+        if let Some(text) = &request.preview_text {
+            // A preview clip is only rendered when both `text` and `model` are present.
+            body["text"] = serde_json::json!(text);
+            body["model"] = serde_json::json!(
+                request
+                    .model
+                    .as_deref()
+                    .unwrap_or(super::model_constants::audio::SPEECH_2_6_HD)
+            );
+        }
+        merge_provider_options(&mut body, &request.provider_options);
+
+        let json = self
+            .post_json("/v1/voice_clone", body, request.http_config.as_ref())
+            .await?;
+
+        let preview_url = json
+            .get("demo_audio")
+            .and_then(|v| v.as_str())
+            .filter(|url| !url.is_empty())
+            .map(ToOwned::to_owned);
+
+        Ok(VoiceCreationResponse {
+            voice_id,
+            preview_audio: None,
+            preview_url,
+            provider_metadata: None,
+        })
+    }
+
+    async fn design_voice(
+        &self,
+        request: VoiceDesignRequest,
+    ) -> Result<VoiceCreationResponse, LlmError> {
+        let preview_text = request.preview_text.clone().ok_or_else(|| {
+            LlmError::InvalidInput(
+                "MiniMaxi voice design requires VoiceDesignRequest.preview_text".into(),
+            )
+        })?;
+
+        let mut body = serde_json::json!({
+            "prompt": request.prompt,
+            "preview_text": preview_text,
+        });
+        if let Some(voice_id) = &request.voice_id {
+            body["voice_id"] = serde_json::json!(voice_id);
+        }
+        merge_provider_options(&mut body, &request.provider_options);
+
+        let json = self
+            .post_json("/v1/voice_design", body, request.http_config.as_ref())
+            .await?;
+
+        let voice_id = json
+            .get("voice_id")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| LlmError::ParseError("minimaxi: missing 'voice_id' in response".into()))?
+            .to_string();
+        let preview_audio = json
+            .get("trial_audio")
+            .and_then(|v| v.as_str())
+            .filter(|audio| !audio.is_empty())
+            .map(|audio| {
+                hex::decode(audio).map_err(|e| {
+                    LlmError::ParseError(format!("Failed to decode hex audio data: {e}"))
+                })
+            })
+            .transpose()?;
+
+        Ok(VoiceCreationResponse {
+            voice_id,
+            preview_audio,
+            preview_url: None,
+            provider_metadata: None,
+        })
+    }
+
+    async fn delete_voice(&self, voice_id: String) -> Result<VoiceDeleteResponse, LlmError> {
+        let (id, type_override) = Self::split_voice_id_and_type(&voice_id);
+
+        let voice_type = match type_override {
+            Some(voice_type) => delete_voice_type(&voice_type)
+                .ok_or_else(|| {
+                    LlmError::InvalidParameter(format!(
+                        "Unsupported MiniMaxi voice type: {voice_type}"
+                    ))
+                })?
+                .to_string(),
+            None => {
+                let voices = self.list_voices().await?;
+                voices
+                    .iter()
+                    .find(|voice| voice.id == id)
+                    .and_then(|voice| voice.category.as_deref())
+                    .and_then(delete_voice_type)
+                    .ok_or_else(|| {
+                        LlmError::InvalidInput(format!("'{id}' is not a MiniMaxi custom voice"))
+                    })?
+                    .to_string()
+            }
+        };
+
+        self.post_json(
+            "/v1/delete_voice",
+            serde_json::json!({
+                "voice_type": voice_type,
+                "voice_id": id,
+            }),
+            None,
+        )
+        .await?;
+
+        Ok(VoiceDeleteResponse { id, deleted: true })
+    }
+}
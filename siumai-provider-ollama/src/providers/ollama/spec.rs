@@ -190,9 +190,10 @@ fn classify_ollama_http_error(status: u16, body_text: &str) -> LlmError {
         .filter(|s| !s.trim().is_empty())
         .unwrap_or_else(|| body_text.to_string());
 
-    LlmError::ApiError {
+    crate::error::normalize_provider_failure(status, &[], &message).unwrap_or(LlmError::ApiError {
         code: status,
         message,
         details: None,
-    }
+        http_details: None,
+    })
 }
@@ -213,6 +213,7 @@ impl ProviderSpec for AlibabaVideoSpec {
                 "code": error.code,
                 "requestId": error.request_id
             })),
+            http_details: None,
         })
     }
 }
@@ -598,6 +599,7 @@ impl VideoGenerationCapability for AlibabaVideoModel {
                 code: result.status,
                 message: "No task_id returned from Alibaba video API".to_string(),
                 details: Some(result.json.clone()),
+                http_details: None,
             })?;
 
         let mut warnings = Vec::new();
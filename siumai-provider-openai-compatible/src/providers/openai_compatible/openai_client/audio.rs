@@ -141,6 +141,7 @@ impl AudioCapability for OpenAiCompatibleClient {
             language,
             confidence: None,
             words,
+            segments: crate::standards::openai::audio::parse_stt_segments(&raw),
             duration,
             metadata,
             warnings: None,
@@ -210,17 +210,23 @@ impl OpenAiCompatibleClient {
             .unwrap_or(false);
         let ctx = self.build_context().await?;
         let spec = Arc::new(self.compat_spec());
-        let headers_base = spec.build_headers(&ctx)?;
         let url = self.completion_url();
-        let request_id = crate::execution::http::interceptor::generate_request_id();
+        let request_ctx = crate::execution::http::interceptor::HttpRequestContext {
+            request_id: crate::execution::http::interceptor::generate_request_id(),
+            provider_id: self.config.provider_id.clone(),
+            url: url.clone(),
+            stream: true,
+        };
+        let headers_base = crate::execution::executors::common::build_request_headers(
+            spec.as_ref(),
+            &ctx,
+            &self.http_interceptors,
+            &request_ctx,
+        )?;
+        let request_id = request_ctx.request_id.clone();
         let converter = crate::streaming::InterceptingConverter {
             interceptors: self.http_interceptors.clone(),
-            ctx: crate::execution::http::interceptor::HttpRequestContext {
-                request_id: request_id.clone(),
-                provider_id: self.config.provider_id.clone(),
-                url: url.clone(),
-                stream: true,
-            },
+            ctx: request_ctx,
             convert: CompletionSseConverter::new(
                 self.config.provider_id.clone(),
                 completion_provider_options_key(&self.config.provider_id),
@@ -31,9 +31,11 @@ mod models;
 mod moderation;
 mod rerank;
 mod responses_admin;
+mod responses_resume;
 mod speech_streaming;
 mod sse_helpers;
 pub(crate) mod transcription_streaming;
+mod voices;
 
 /// `OpenAI` Client
 pub struct OpenAiClient {
@@ -69,6 +71,8 @@ pub struct OpenAiClient {
     _tracing_guard: Option<()>,
     /// Unified retry options for chat
     retry_options: Option<RetryOptions>,
+    /// How many times a dropped background Responses stream is resumed (0 disables resumption)
+    responses_stream_resume_attempts: u32,
     /// Optional HTTP interceptors applied to all chat requests
     http_interceptors:
         Vec<std::sync::Arc<dyn crate::execution::http::interceptor::HttpInterceptor>>,
@@ -96,6 +100,7 @@ impl Clone for OpenAiClient {
             tracing_config: self.tracing_config.clone(),
             _tracing_guard: None, // Don't clone the tracing guard
             retry_options: self.retry_options.clone(),
+            responses_stream_resume_attempts: self.responses_stream_resume_attempts,
             http_interceptors: self.http_interceptors.clone(),
             model_middlewares: Vec::new(),
         }
@@ -343,6 +348,8 @@ impl OpenAiClient {
             tracing_config: None,
             _tracing_guard: None,
             retry_options: None,
+            responses_stream_resume_attempts:
+                responses_resume::DEFAULT_RESPONSES_STREAM_RESUME_ATTEMPTS,
             http_interceptors: Vec::new(),
             model_middlewares: Vec::new(),
         }
@@ -361,6 +368,13 @@ impl OpenAiClient {
         self.retry_options = options;
     }
 
+    /// Set how many times a dropped background Responses stream is transparently resumed.
+    ///
+    /// Only applies to streams created with `background: true`; `0` disables resumption.
+    pub fn set_responses_stream_resume_attempts(&mut self, attempts: u32) {
+        self.responses_stream_resume_attempts = attempts;
+    }
+
     /// Force routing chat requests through the OpenAI Responses API (`POST /responses`).
     pub fn set_forced_responses_api(
         &mut self,
@@ -436,19 +450,31 @@ impl OpenAiClient {
         wiring
     }
 
+    fn chat_spec(&self) -> crate::providers::openai::spec::OpenAiSpec {
+        let mut spec = crate::providers::openai::spec::OpenAiSpec::new();
+        if let Some(cfg) = self.forced_responses_api.clone() {
+            spec = spec.with_forced_responses_api(cfg);
+        }
+        spec
+    }
+
     /// Helper: Build ChatExecutor with common configuration
     fn build_chat_executor(
         &self,
         request: &ChatRequest,
+    ) -> Arc<crate::execution::executors::chat::HttpChatExecutor> {
+        self.build_chat_executor_with_spec(request, self.chat_spec())
+    }
+
+    fn build_chat_executor_with_spec(
+        &self,
+        request: &ChatRequest,
+        spec: crate::providers::openai::spec::OpenAiSpec,
     ) -> Arc<crate::execution::executors::chat::HttpChatExecutor> {
         use crate::core::ProviderSpec;
         use crate::execution::executors::chat::ChatExecutorBuilder;
 
         let ctx = self.build_context();
-        let mut spec = crate::providers::openai::spec::OpenAiSpec::new();
-        if let Some(cfg) = self.forced_responses_api.clone() {
-            spec = spec.with_forced_responses_api(cfg);
-        }
         let spec = Arc::new(spec);
         let bundle = spec.choose_chat_transformers(request, &ctx);
 
@@ -879,6 +905,12 @@ impl LlmClient for OpenAiClient {
         Some(self)
     }
 
+    fn as_voice_management_capability(
+        &self,
+    ) -> Option<&dyn crate::traits::VoiceManagementCapability> {
+        Some(self)
+    }
+
     fn as_moderation_capability(&self) -> Option<&dyn crate::traits::ModerationCapability> {
         Some(self)
     }
@@ -88,6 +88,10 @@ impl AudioCapability for OpenAiClient {
         self.tts_sse_stream(request).await
     }
 
+    async fn get_voices(&self) -> Result<Vec<crate::types::VoiceInfo>, LlmError> {
+        Ok(super::voices::openai_tts_voices())
+    }
+
     async fn speech_to_text(
         &self,
         request: crate::types::SttRequest,
@@ -145,6 +149,7 @@ impl AudioCapability for OpenAiClient {
             language,
             confidence: None,
             words,
+            segments: crate::standards::openai::audio::parse_stt_segments(&raw),
             duration,
             metadata,
             warnings: None,
@@ -379,6 +384,7 @@ impl AudioCapability for OpenAiClient {
                     .map(|s| s.to_string()),
                 confidence: None,
                 words: None,
+                segments: crate::standards::openai::audio::parse_stt_segments(&json),
                 duration: json
                     .get("duration")
                     .and_then(|v| v.as_f64())
@@ -401,6 +407,7 @@ impl AudioCapability for OpenAiClient {
             language: None,
             confidence: None,
             words: None,
+            segments: None,
             duration: None,
             metadata,
             warnings: None,
@@ -158,6 +158,9 @@ impl OpenAiClient {
     ) -> Result<ChatStream, LlmError> {
         use crate::execution::executors::chat::ChatExecutor;
         let request = self.prepare_chat_request(request, true)?;
+        if self.streams_background_responses(&request) {
+            return self.chat_stream_background_responses(request).await;
+        }
         let exec = self.build_chat_executor(&request);
         ChatExecutor::execute_stream(&*exec, request).await
     }
@@ -485,4 +488,204 @@ mod tests {
 
         server.await.unwrap();
     }
+
+    async fn read_request_head(tcp: &mut tokio::net::TcpStream) -> String {
+        let mut buf = Vec::<u8>::new();
+        let mut tmp = [0u8; 1024];
+        loop {
+            let n = tcp.read(&mut tmp).await.unwrap();
+            if n == 0 {
+                break;
+            }
+            buf.extend_from_slice(&tmp[..n]);
+            if buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                break;
+            }
+        }
+        String::from_utf8_lossy(&buf).to_string()
+    }
+
+    #[tokio::test]
+    async fn openai_background_stream_resumes_after_connection_drop() {
+        use crate::provider_options::openai::{OpenAiOptions, ResponsesApiConfig};
+        use crate::providers::openai::ext::OpenAiChatRequestExt;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let sse_headers = concat!(
+                "HTTP/1.1 200 OK\r\n",
+                "content-type: text/event-stream\r\n",
+                "transfer-encoding: chunked\r\n",
+                "\r\n"
+            );
+
+            // 1) Initial `POST /responses` stream, dropped mid-response.
+            let (mut tcp_stream, _) = listener.accept().await.unwrap();
+            let head = read_request_head(&mut tcp_stream).await;
+            assert!(
+                head.starts_with("POST /v1/responses "),
+                "unexpected request: {head}"
+            );
+
+            tcp_stream.write_all(sse_headers.as_bytes()).await.unwrap();
+            let first = concat!(
+                "event: response.created\n",
+                "data: {\"type\":\"response.created\",\"sequence_number\":0,\"response\":{\"id\":\"resp_1\",\"model\":\"gpt-test\",\"created_at\":0}}\n\n",
+                "event: response.output_text.delta\n",
+                "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"item_id\":\"msg_1\",\"delta\":\"Hel\"}\n\n",
+            );
+            write_chunk(&mut tcp_stream, first.as_bytes())
+                .await
+                .unwrap();
+            // Abort without the terminating chunk so the client sees a transport error.
+            tcp_stream.write_all(b"ff\r\npartial").await.unwrap();
+            drop(tcp_stream);
+
+            // 2) Resume: `GET /responses/resp_1?stream=true&starting_after=1`.
+            let (mut tcp_resume, _) = listener.accept().await.unwrap();
+            let head = read_request_head(&mut tcp_resume).await;
+            let first_line = head.lines().next().unwrap_or("").to_string();
+            assert!(
+                first_line.starts_with("GET /v1/responses/resp_1?stream=true&starting_after=1 "),
+                "unexpected resume request line: {first_line}"
+            );
+
+            tcp_resume.write_all(sse_headers.as_bytes()).await.unwrap();
+            let rest = concat!(
+                // Replayed event at the cursor must be de-duplicated.
+                "event: response.output_text.delta\n",
+                "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"item_id\":\"msg_1\",\"delta\":\"Hel\"}\n\n",
+                "event: response.output_text.delta\n",
+                "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":2,\"item_id\":\"msg_1\",\"delta\":\"lo\"}\n\n",
+                "event: response.completed\n",
+                "data: {\"type\":\"response.completed\",\"sequence_number\":3,\"response\":{\"id\":\"resp_1\",\"model\":\"gpt-test\",\"created_at\":0,\"status\":\"completed\",\"output\":[{\"id\":\"msg_1\",\"type\":\"message\",\"status\":\"completed\",\"role\":\"assistant\",\"content\":[{\"type\":\"output_text\",\"text\":\"Hello\",\"annotations\":[]}]}],\"usage\":{\"input_tokens\":1,\"output_tokens\":2,\"total_tokens\":3}}}\n\n",
+            );
+            write_chunk(&mut tcp_resume, rest.as_bytes()).await.unwrap();
+            tcp_resume.write_all(b"0\r\n\r\n").await.unwrap();
+            let _ = tcp_resume.shutdown().await;
+        });
+
+        let client =
+            crate::providers::openai::OpenAiBuilder::new(crate::builder::BuilderBase::default())
+                .api_key("test")
+                .base_url(format!("http://{addr}/v1"))
+                .model("gpt-test")
+                .build()
+                .await
+                .unwrap();
+
+        let request = ChatRequest::new(vec![ChatMessage::user("hi").build()]).with_openai_options(
+            OpenAiOptions::new()
+                .with_responses_api(ResponsesApiConfig::new().with_background(true)),
+        );
+
+        let mut stream = client.chat_stream_request(request).await.unwrap();
+        let mut text = String::new();
+        let mut saw_end = false;
+        while let Some(item) = stream.next().await {
+            let ev = item.expect("resumed stream should not surface transport errors");
+            if let Some(delta) = ev.text_delta() {
+                text.push_str(delta);
+            }
+            if matches!(ev, crate::types::ChatStreamEvent::StreamEnd { .. }) {
+                saw_end = true;
+            }
+        }
+
+        assert_eq!(text, "Hello");
+        assert!(saw_end);
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn openai_background_stream_resumes_after_clean_end_without_completion() {
+        use crate::provider_options::openai::{OpenAiOptions, ResponsesApiConfig};
+        use crate::providers::openai::ext::OpenAiChatRequestExt;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let server = tokio::spawn(async move {
+            let sse_headers = concat!(
+                "HTTP/1.1 200 OK\r\n",
+                "content-type: text/event-stream\r\n",
+                "transfer-encoding: chunked\r\n",
+                "\r\n"
+            );
+
+            // 1) Initial stream closes cleanly before `response.completed`.
+            let (mut tcp_stream, _) = listener.accept().await.unwrap();
+            let head = read_request_head(&mut tcp_stream).await;
+            assert!(
+                head.starts_with("POST /v1/responses "),
+                "unexpected request: {head}"
+            );
+
+            tcp_stream.write_all(sse_headers.as_bytes()).await.unwrap();
+            let first = concat!(
+                "event: response.created\n",
+                "data: {\"type\":\"response.created\",\"sequence_number\":0,\"response\":{\"id\":\"resp_1\",\"model\":\"gpt-test\",\"created_at\":0}}\n\n",
+                "event: response.output_text.delta\n",
+                "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":1,\"item_id\":\"msg_1\",\"delta\":\"Hel\"}\n\n",
+            );
+            write_chunk(&mut tcp_stream, first.as_bytes())
+                .await
+                .unwrap();
+            tcp_stream.write_all(b"0\r\n\r\n").await.unwrap();
+            let _ = tcp_stream.shutdown().await;
+
+            // 2) Resume: `GET /responses/resp_1?stream=true&starting_after=1`.
+            let (mut tcp_resume, _) = listener.accept().await.unwrap();
+            let head = read_request_head(&mut tcp_resume).await;
+            let first_line = head.lines().next().unwrap_or("").to_string();
+            assert!(
+                first_line.starts_with("GET /v1/responses/resp_1?stream=true&starting_after=1 "),
+                "unexpected resume request line: {first_line}"
+            );
+
+            tcp_resume.write_all(sse_headers.as_bytes()).await.unwrap();
+            let rest = concat!(
+                "event: response.output_text.delta\n",
+                "data: {\"type\":\"response.output_text.delta\",\"sequence_number\":2,\"item_id\":\"msg_1\",\"delta\":\"lo\"}\n\n",
+                "event: response.completed\n",
+                "data: {\"type\":\"response.completed\",\"sequence_number\":3,\"response\":{\"id\":\"resp_1\",\"model\":\"gpt-test\",\"created_at\":0,\"status\":\"completed\",\"output\":[{\"id\":\"msg_1\",\"type\":\"message\",\"status\":\"completed\",\"role\":\"assistant\",\"content\":[{\"type\":\"output_text\",\"text\":\"Hello\",\"annotations\":[]}]}],\"usage\":{\"input_tokens\":1,\"output_tokens\":2,\"total_tokens\":3}}}\n\n",
+            );
+            write_chunk(&mut tcp_resume, rest.as_bytes()).await.unwrap();
+            tcp_resume.write_all(b"0\r\n\r\n").await.unwrap();
+            let _ = tcp_resume.shutdown().await;
+        });
+
+        let client =
+            crate::providers::openai::OpenAiBuilder::new(crate::builder::BuilderBase::default())
+                .api_key("test")
+                .base_url(format!("http://{addr}/v1"))
+                .model("gpt-test")
+                .build()
+                .await
+                .unwrap();
+
+        let request = ChatRequest::new(vec![ChatMessage::user("hi").build()]).with_openai_options(
+            OpenAiOptions::new()
+                .with_responses_api(ResponsesApiConfig::new().with_background(true)),
+        );
+
+        let mut stream = client.chat_stream_request(request).await.unwrap();
+        let mut text = String::new();
+        let mut stream_ends = 0;
+        while let Some(item) = stream.next().await {
+            let ev = item.expect("resumed stream should not surface errors");
+            if let Some(delta) = ev.text_delta() {
+                text.push_str(delta);
+            }
+            if matches!(ev, crate::types::ChatStreamEvent::StreamEnd { .. }) {
+                stream_ends += 1;
+            }
+        }
+
+        assert_eq!(text, "Hello");
+        assert_eq!(stream_ends, 1);
+        server.await.unwrap();
+    }
 }
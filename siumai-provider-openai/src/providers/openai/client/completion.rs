@@ -451,17 +451,23 @@ impl OpenAiClient {
         let ctx = self.build_context();
         let spec: Arc<dyn crate::core::ProviderSpec> =
             Arc::new(crate::providers::openai::spec::OpenAiSpec::new());
-        let headers_base = spec.build_headers(&ctx)?;
         let url = self.completion_url();
-        let request_id = crate::execution::http::interceptor::generate_request_id();
+        let request_ctx = crate::execution::http::interceptor::HttpRequestContext {
+            request_id: crate::execution::http::interceptor::generate_request_id(),
+            provider_id: "openai".to_string(),
+            url: url.clone(),
+            stream: true,
+        };
+        let headers_base = crate::execution::executors::common::build_request_headers(
+            spec.as_ref(),
+            &ctx,
+            &self.http_interceptors,
+            &request_ctx,
+        )?;
+        let request_id = request_ctx.request_id.clone();
         let converter = crate::streaming::InterceptingConverter {
             interceptors: self.http_interceptors.clone(),
-            ctx: crate::execution::http::interceptor::HttpRequestContext {
-                request_id: request_id.clone(),
-                provider_id: "openai".to_string(),
-                url: url.clone(),
-                stream: true,
-            },
+            ctx: request_ctx,
             convert: CompletionSseConverter::new(
                 "openai",
                 warnings,
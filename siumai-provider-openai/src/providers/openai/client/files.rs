@@ -128,4 +128,18 @@ impl crate::traits::FileManagementCapability for OpenAiClient {
         );
         files.get_file_content(file_id).await
     }
+
+    async fn upload_file_stream(
+        &self,
+        request: crate::types::FileStreamUploadRequest,
+    ) -> Result<crate::types::FileObject, LlmError> {
+        self.files().upload_file_stream(request).await
+    }
+
+    async fn get_file_content_stream(
+        &self,
+        file_id: String,
+    ) -> Result<crate::types::FileContentStream, LlmError> {
+        self.files().get_file_content_stream(file_id).await
+    }
 }
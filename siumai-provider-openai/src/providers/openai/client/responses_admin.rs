@@ -12,13 +12,13 @@ use crate::execution::executors::common::{
 use std::sync::Arc;
 
 impl OpenAiClient {
-    fn responses_admin_config(&self) -> HttpExecutionConfig {
+    pub(super) fn responses_admin_config(&self) -> HttpExecutionConfig {
         let spec: Arc<dyn crate::core::ProviderSpec> =
             Arc::new(crate::providers::openai::spec::OpenAiSpec::new());
         self.http_wiring().config(spec)
     }
 
-    fn responses_url(&self, suffix: &str) -> String {
+    pub(super) fn responses_url(&self, suffix: &str) -> String {
         format!(
             "{}/{}",
             self.base_url.trim_end_matches('/'),
@@ -26,7 +26,7 @@ impl OpenAiClient {
         )
     }
 
-    fn with_query_params(
+    pub(super) fn with_query_params(
         &self,
         url: &str,
         params: Vec<(String, String)>,
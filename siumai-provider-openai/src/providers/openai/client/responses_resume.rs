@@ -0,0 +1,195 @@
+//! Resumable streaming for OpenAI Responses background mode (provider-specific).
+//!
+//! Background responses (`background: true`) keep generating server-side when the client
+//! connection drops. Every Responses SSE event carries a `sequence_number`, and the stream can
+//! be re-attached with `GET /responses/{id}?stream=true&starting_after=N`.
+//!
+//! Streams created here track the last delivered sequence number on a shared
+//! `OpenAiResponsesStreamCursor`, reconnect on transport errors or when the connection closes
+//! before `response.completed`, and drop replayed events.
+
+use super::OpenAiClient;
+use crate::error::LlmError;
+use crate::execution::http::interceptor::HttpRequestContext;
+use crate::providers::openai::responses::OpenAiResponsesStreamCursor;
+use crate::streaming::{ChatStream, StreamFactory};
+use crate::types::{ChatRequest, ChatStreamEvent};
+
+/// Default number of transparent reconnects for a dropped background Responses stream.
+pub(super) const DEFAULT_RESPONSES_STREAM_RESUME_ATTEMPTS: u32 = 3;
+
+fn is_resumable_stream_error(err: &LlmError) -> bool {
+    matches!(
+        err,
+        LlmError::StreamError(_)
+            | LlmError::HttpError(_)
+            | LlmError::ConnectionError(_)
+            | LlmError::TimeoutError(_)
+    )
+}
+
+impl OpenAiClient {
+    /// Resume streaming a background Responses API response.
+    ///
+    /// `cursor` is the last `sequence_number` the caller has already processed; events up to
+    /// and including it are skipped. Pass `None` to replay the response from the beginning.
+    /// The returned stream reconnects transparently on transport errors, like streams created
+    /// with `background: true`.
+    pub async fn resume_stream(
+        &self,
+        response_id: &str,
+        cursor: Option<u64>,
+    ) -> Result<ChatStream, LlmError> {
+        if response_id.trim().is_empty() {
+            return Err(LlmError::InvalidParameter(
+                "OpenAI resume_stream requires a response id".to_string(),
+            ));
+        }
+
+        let cursor = OpenAiResponsesStreamCursor::starting_after(response_id, cursor);
+        let stream = self.open_responses_resume_stream(&cursor).await?;
+        Ok(self.clone().into_resumable_responses_stream(stream, cursor))
+    }
+
+    /// Whether a streaming chat request runs as a background Responses API response.
+    pub(super) fn streams_background_responses(&self, request: &ChatRequest) -> bool {
+        self.responses_stream_resume_attempts > 0
+            && self
+                .chat_spec()
+                .uses_background_responses(request, &self.build_context())
+    }
+
+    /// Stream a background Responses API request, resuming the stream when the connection drops.
+    pub(super) async fn chat_stream_background_responses(
+        &self,
+        request: ChatRequest,
+    ) -> Result<ChatStream, LlmError> {
+        use crate::execution::executors::chat::ChatExecutor;
+
+        let cursor = OpenAiResponsesStreamCursor::new();
+        let spec = self
+            .chat_spec()
+            .with_responses_stream_cursor(cursor.clone());
+        let exec = self.build_chat_executor_with_spec(&request, spec);
+        let stream = ChatExecutor::execute_stream(&*exec, request).await?;
+        Ok(self.clone().into_resumable_responses_stream(stream, cursor))
+    }
+
+    async fn open_responses_resume_stream(
+        &self,
+        cursor: &OpenAiResponsesStreamCursor,
+    ) -> Result<ChatStream, LlmError> {
+        let Some(response_id) = cursor.response_id() else {
+            return Err(LlmError::InvalidParameter(
+                "Cannot resume an OpenAI Responses stream without a response id".to_string(),
+            ));
+        };
+
+        let mut params = vec![("stream".to_string(), "true".to_string())];
+        if let Some(seq) = cursor.last_sequence_number() {
+            params.push(("starting_after".to_string(), seq.to_string()));
+        }
+        let url = self.with_query_params(
+            &self.responses_url(&format!("responses/{response_id}")),
+            params,
+        )?;
+
+        let config = self.responses_admin_config();
+        let ctx = HttpRequestContext {
+            request_id: crate::execution::http::interceptor::generate_request_id(),
+            provider_id: config.provider_id.clone(),
+            url: url.clone(),
+            stream: true,
+        };
+        let retry_401 = config
+            .retry_options
+            .as_ref()
+            .map(|opts| opts.retry_401)
+            .unwrap_or(true);
+
+        let build_request = || -> Result<reqwest::RequestBuilder, LlmError> {
+            let headers = config.build_headers_for(&ctx)?;
+            let mut rb = config
+                .http_client
+                .get(&url)
+                .headers(headers.clone())
+                .header(reqwest::header::ACCEPT, "text/event-stream")
+                .header(reqwest::header::CACHE_CONTROL, "no-cache");
+            let empty_json = serde_json::json!({});
+            for interceptor in &config.interceptors {
+                rb = interceptor.on_before_send(&ctx, rb, &empty_json, &headers)?;
+            }
+            Ok(rb)
+        };
+
+        let converter = cursor.resume_converter();
+
+        StreamFactory::create_eventsource_stream_with_retry_options(
+            &config.provider_id,
+            Some(config.provider_spec.as_ref()),
+            &url,
+            retry_401,
+            build_request,
+            converter,
+            &config.interceptors,
+            ctx.clone(),
+            config.retry_options.clone(),
+        )
+        .await
+    }
+
+    fn into_resumable_responses_stream(
+        self,
+        stream: ChatStream,
+        cursor: OpenAiResponsesStreamCursor,
+    ) -> ChatStream {
+        let max_resumes = self.responses_stream_resume_attempts;
+
+        let wrapped = async_stream::stream! {
+            use futures_util::StreamExt;
+
+            let mut inner = stream;
+            let mut resumes = 0u32;
+            let mut finished = false;
+
+            loop {
+                let can_resume =
+                    !finished && resumes < max_resumes && cursor.response_id().is_some();
+                let reason = match inner.next().await {
+                    Some(Err(err)) if can_resume && is_resumable_stream_error(&err) => {
+                        err.to_string()
+                    }
+                    // A clean end before `response.completed` is a dropped connection too.
+                    None if can_resume => "stream ended before response.completed".to_string(),
+                    None => break,
+                    Some(other) => {
+                        if matches!(other, Ok(ChatStreamEvent::StreamEnd { .. })) {
+                            finished = true;
+                        }
+                        yield other;
+                        continue;
+                    }
+                };
+
+                resumes += 1;
+                tracing::debug!(
+                    target: "siumai::openai::client",
+                    reason = %reason,
+                    response_id = ?cursor.response_id(),
+                    starting_after = ?cursor.last_sequence_number(),
+                    attempt = resumes,
+                    "Resuming dropped Responses stream"
+                );
+                match self.open_responses_resume_stream(&cursor).await {
+                    Ok(next) => inner = next,
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                }
+            }
+        };
+
+        Box::pin(wrapped)
+    }
+}
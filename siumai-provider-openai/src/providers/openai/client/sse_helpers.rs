@@ -7,6 +7,7 @@ pub(crate) fn openai_sse_error_event(label: &str, payload: &serde_json::Value) -
         code: 200,
         message: format!("OpenAI {label} SSE error event"),
         details: Some(err.clone()),
+        http_details: None,
     })
 }
 
@@ -0,0 +1,62 @@
+use super::OpenAiClient;
+use crate::error::LlmError;
+use crate::traits::VoiceManagementCapability;
+use crate::types::VoiceInfo;
+use async_trait::async_trait;
+
+/// Built-in speech voices: `(voice id, description)`.
+///
+/// OpenAI does not expose a voice listing endpoint, so the catalog mirrors the documented set.
+/// Every voice works with `gpt-4o-mini-tts`; the older `tts-1` family does not accept the
+/// voices marked below.
+const OPENAI_TTS_VOICES: &[(&str, &str)] = &[
+    ("alloy", "Built-in voice."),
+    ("ash", "Built-in voice."),
+    ("ballad", "Built-in voice (gpt-4o-mini-tts only)."),
+    ("coral", "Built-in voice."),
+    ("echo", "Built-in voice."),
+    ("fable", "Built-in voice."),
+    ("nova", "Built-in voice."),
+    ("onyx", "Built-in voice."),
+    ("sage", "Built-in voice."),
+    ("shimmer", "Built-in voice."),
+    ("verse", "Built-in voice (gpt-4o-mini-tts only)."),
+    ("marin", "Built-in voice (gpt-4o-mini-tts only)."),
+    ("cedar", "Built-in voice (gpt-4o-mini-tts only)."),
+];
+
+pub(super) fn openai_tts_voices() -> Vec<VoiceInfo> {
+    OPENAI_TTS_VOICES
+        .iter()
+        .map(|(id, description)| {
+            let mut name = id.to_string();
+            name[..1].make_ascii_uppercase();
+            VoiceInfo::new(*id, name)
+                .with_description(*description)
+                .with_category("built-in")
+        })
+        .collect()
+}
+
+#[async_trait]
+impl VoiceManagementCapability for OpenAiClient {
+    async fn list_voices(&self) -> Result<Vec<VoiceInfo>, LlmError> {
+        Ok(openai_tts_voices())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn openai_voice_catalog_ids_are_tts_voice_values() {
+        let voices = openai_tts_voices();
+        assert_eq!(voices.len(), OPENAI_TTS_VOICES.len());
+
+        let alloy = voices.iter().find(|v| v.id == "alloy").expect("alloy");
+        assert_eq!(alloy.name, "Alloy");
+        assert_eq!(alloy.category.as_deref(), Some("built-in"));
+        assert!(voices.iter().all(|v| v.id == v.id.to_ascii_lowercase()));
+    }
+}
@@ -10,7 +10,8 @@ use secrecy::ExposeSecret;
 use crate::error::LlmError;
 use crate::traits::FileManagementCapability;
 use crate::types::{
-    FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
+    FileContentStream, FileDeleteResponse, FileListQuery, FileListResponse, FileObject,
+    FileStreamUploadRequest, FileUploadRequest, UploadSession,
 };
 
 use super::config::OpenAiConfig;
@@ -143,6 +144,171 @@ impl OpenAiFiles {
         Ok(())
     }
 
+    /// Maximum size accepted by the Uploads API (8 GB).
+    const MAX_UPLOAD_SIZE: u64 = 8 * 1024 * 1024 * 1024;
+
+    /// Maximum size of a single Uploads API part (64 MB).
+    const MAX_PART_SIZE: usize = 64 * 1024 * 1024;
+
+    /// Upload a large file with the multipart Uploads API.
+    ///
+    /// Creates an upload (or resumes `request.resume`), adds one part per chunk with per-part
+    /// retries, and completes the upload with the ordered part ids.
+    ///
+    /// <https://platform.openai.com/docs/api-reference/uploads>
+    async fn upload_file_multipart(
+        &self,
+        mut request: FileStreamUploadRequest,
+    ) -> Result<FileObject, LlmError> {
+        use crate::execution::executors::common::{
+            HttpBody, execute_json_request, execute_multipart_request,
+        };
+
+        let mut merged = self.config.provider_options_map.clone();
+        merged.merge_overrides(std::mem::take(&mut request.provider_options));
+        request.provider_options = merged;
+
+        let purpose = request
+            .provider_options
+            .get_object("openai")
+            .and_then(|options| options.get("purpose"))
+            .and_then(|value| value.as_str())
+            .map(str::trim)
+            .filter(|purpose| !purpose.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| request.purpose.trim().to_string());
+        if purpose.is_empty() {
+            return Err(LlmError::InvalidInput(
+                "File purpose cannot be empty".to_string(),
+            ));
+        }
+
+        let exec = self.build_files_executor();
+        let config = exec.execution_config();
+        let http_config = request.http_config.clone();
+        let chunk_size = request.chunk_size.min(Self::MAX_PART_SIZE);
+        let max_retries = request.max_chunk_retries;
+
+        let resume = request.resume.take();
+        let offset = resume.as_ref().map(|session| session.offset).unwrap_or(0);
+        let source = std::mem::replace(
+            &mut request.source,
+            crate::types::FileUploadSource::reader(tokio::io::empty(), 0),
+        );
+        let mut reader = source.open(offset).await?;
+        if reader.length() == 0 {
+            return Err(LlmError::InvalidInput(
+                "File content cannot be empty".to_string(),
+            ));
+        }
+        if reader.length() > Self::MAX_UPLOAD_SIZE {
+            return Err(LlmError::InvalidInput(format!(
+                "File size {} bytes exceeds maximum allowed size of {} bytes",
+                reader.length(),
+                Self::MAX_UPLOAD_SIZE
+            )));
+        }
+
+        let mut session = match resume {
+            Some(session) => {
+                if session.total_bytes != reader.length() {
+                    return Err(LlmError::InvalidInput(format!(
+                        "Resumed upload expects {} bytes but the source has {}",
+                        session.total_bytes,
+                        reader.length()
+                    )));
+                }
+                session
+            }
+            None => {
+                let filename = request
+                    .filename
+                    .clone()
+                    .unwrap_or_else(|| "upload.bin".to_string());
+                let mime_type = request
+                    .mime_type
+                    .clone()
+                    .unwrap_or_else(|| crate::utils::mime::guess_mime(None, Some(&filename)));
+                let body = serde_json::json!({
+                    "filename": filename,
+                    "purpose": purpose,
+                    "bytes": reader.length(),
+                    "mime_type": mime_type,
+                });
+                let url = crate::utils::url::join_url(&self.config.base_url, "uploads");
+                let created = execute_json_request(
+                    &config,
+                    &url,
+                    HttpBody::Json(body),
+                    http_config.as_ref(),
+                    false,
+                )
+                .await?;
+                let upload_id = created
+                    .json
+                    .get("id")
+                    .and_then(|v| v.as_str())
+                    .ok_or_else(|| LlmError::ParseError("Upload response missing id".into()))?;
+                UploadSession {
+                    provider_id: "openai".to_string(),
+                    upload_id: upload_id.to_string(),
+                    total_bytes: reader.length(),
+                    offset: 0,
+                    part_ids: Vec::new(),
+                }
+            }
+        };
+
+        let parts_url = crate::utils::url::join_url(
+            &self.config.base_url,
+            &format!("uploads/{}/parts", session.upload_id),
+        );
+        while let Some(chunk) = reader.next_chunk(chunk_size).await? {
+            let part = crate::types::retry_upload_chunk(max_retries, |_| {
+                let chunk = chunk.clone();
+                execute_multipart_request(
+                    &config,
+                    &parts_url,
+                    move || {
+                        let part = reqwest::multipart::Part::bytes(chunk.to_vec())
+                            .file_name("part")
+                            .mime_str("application/octet-stream")
+                            .map_err(|e| LlmError::InvalidParameter(e.to_string()))?;
+                        Ok(reqwest::multipart::Form::new().part("data", part))
+                    },
+                    http_config.as_ref(),
+                )
+            })
+            .await?;
+            let part_id = part
+                .json
+                .get("id")
+                .and_then(|v| v.as_str())
+                .ok_or_else(|| LlmError::ParseError("Upload part response missing id".into()))?;
+            session.part_ids.push(part_id.to_string());
+            session.offset = reader.offset();
+            request.report_progress(&session);
+        }
+
+        let complete_url = crate::utils::url::join_url(
+            &self.config.base_url,
+            &format!("uploads/{}/complete", session.upload_id),
+        );
+        let completed = execute_json_request(
+            &config,
+            &complete_url,
+            HttpBody::Json(serde_json::json!({ "part_ids": session.part_ids })),
+            http_config.as_ref(),
+            false,
+        )
+        .await?;
+        let file = completed
+            .json
+            .get("file")
+            .ok_or_else(|| LlmError::ParseError("Completed upload response missing file".into()))?;
+        exec.transformer.transform_file_object(file)
+    }
+
     fn merge_default_provider_options(&self, request: &mut FileUploadRequest) {
         let mut merged = self.config.provider_options_map.clone();
         merged.merge_overrides(std::mem::take(&mut request.provider_options));
@@ -238,6 +404,24 @@ impl FileManagementCapability for OpenAiFiles {
         let exec = self.build_files_executor();
         FilesExecutor::get_content(&*exec, file_id).await
     }
+
+    /// Upload a file in parts via the Uploads API.
+    async fn upload_file_stream(
+        &self,
+        request: FileStreamUploadRequest,
+    ) -> Result<FileObject, LlmError> {
+        self.upload_file_multipart(request).await
+    }
+
+    /// Stream file content.
+    async fn get_file_content_stream(
+        &self,
+        file_id: String,
+    ) -> Result<FileContentStream, LlmError> {
+        use crate::execution::executors::files::FilesExecutor;
+        let exec = self.build_files_executor();
+        FilesExecutor::get_content_stream(&*exec, file_id).await
+    }
 }
 
 #[cfg(test)]
@@ -4,4 +4,6 @@
 //! `standards::openai::responses_sse`. This module preserves the historical
 //! import path: `siumai::providers::openai::responses::OpenAiResponsesEventConverter`.
 
-pub use crate::standards::openai::responses_sse::OpenAiResponsesEventConverter;
+pub use crate::standards::openai::responses_sse::{
+    OpenAiResponsesEventConverter, OpenAiResponsesStreamCursor,
+};
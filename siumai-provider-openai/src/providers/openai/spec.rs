@@ -75,6 +75,11 @@ pub struct OpenAiSpec {
     /// to route all chat requests through `/responses` without requiring every
     /// request to carry an `openai` entry in `providerOptions`.
     forced_responses_api: Option<crate::provider_options::openai::ResponsesApiConfig>,
+    /// Optional resume cursor attached to the Responses SSE converter.
+    ///
+    /// Set by `OpenAiClient` for background streams so dropped connections can be resumed.
+    responses_stream_cursor:
+        Option<crate::standards::openai::responses_sse::OpenAiResponsesStreamCursor>,
 }
 
 impl OpenAiSpec {
@@ -84,6 +89,7 @@ impl OpenAiSpec {
             embedding_standard: OpenAiEmbeddingStandard::new(),
             image_standard: OpenAiImageStandard::new(),
             forced_responses_api: None,
+            responses_stream_cursor: None,
         }
     }
 
@@ -95,6 +101,32 @@ impl OpenAiSpec {
         self
     }
 
+    pub fn with_responses_stream_cursor(
+        mut self,
+        cursor: crate::standards::openai::responses_sse::OpenAiResponsesStreamCursor,
+    ) -> Self {
+        self.responses_stream_cursor = Some(cursor);
+        self
+    }
+
+    /// Whether this request streams a background Responses API response (`background: true`).
+    pub(crate) fn uses_background_responses(
+        &self,
+        req: &ChatRequest,
+        ctx: &ProviderContext,
+    ) -> bool {
+        if !self.use_responses_api(req, ctx) {
+            return false;
+        }
+        self.requested_background_from_provider_options_map(req)
+            .or_else(|| {
+                self.forced_responses_api
+                    .as_ref()
+                    .and_then(|cfg| cfg.background)
+            })
+            .unwrap_or(false)
+    }
+
     fn use_responses_api(&self, req: &ChatRequest, _ctx: &ProviderContext) -> bool {
         if let Some(cfg) = self.forced_responses_api.as_ref() {
             return cfg.enabled;
@@ -270,6 +302,15 @@ impl OpenAiSpec {
             })
     }
 
+    fn requested_background_from_provider_options_map(&self, req: &ChatRequest) -> Option<bool> {
+        let value = req.provider_options_map.get("openai")?;
+        let normalized = self.normalize_openai_provider_options_json(value);
+        normalized
+            .get("responses_api")
+            .and_then(|value| value.get("background"))
+            .and_then(|value| value.as_bool())
+    }
+
     fn auto_responses_api_include_from_tools(&self, req: &ChatRequest) -> Vec<String> {
         use crate::types::Tool;
 
@@ -431,11 +472,14 @@ impl ProviderSpec for OpenAiSpec {
             let requested_store = self
                 .requested_store_from_provider_options_map(req)
                 .or_else(|| self.forced_responses_api.as_ref().and_then(|cfg| cfg.store));
-            let converter =
+            let mut converter =
                 crate::providers::openai::responses::OpenAiResponsesEventConverter::new()
                     .with_provider_metadata_key(provider_metadata_key)
                     .with_requested_store(requested_store)
                     .with_request_tools(req.tools.as_deref().unwrap_or(&[]));
+            if let Some(cursor) = self.responses_stream_cursor.clone() {
+                converter = converter.with_stream_cursor(cursor);
+            }
             let stream_tx =
                 crate::providers::openai::transformers::OpenAiResponsesStreamChunkTransformer {
                     provider_id: "openai_responses".to_string(),
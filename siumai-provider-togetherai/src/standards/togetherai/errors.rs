@@ -3,7 +3,7 @@
 //! This module preserves TogetherAI error messages losslessly when possible
 //! and maps status codes into retry-friendly error variants.
 
-use crate::error::LlmError;
+use crate::error::{LlmError, normalize_provider_failure};
 use reqwest::header::HeaderMap;
 
 fn extract_message(body_text: &str) -> Option<String> {
@@ -40,8 +40,16 @@ pub fn classify_togetherai_http_error(
     let message = extract_message(body_text).unwrap_or_else(|| body_text.to_string());
     let lower = body_text.to_lowercase();
 
+    // Context-length messages mention "exceed", so normalize before the quota heuristics.
+    if let Some(normalized) = normalize_provider_failure(status, &[], &message) {
+        return Some(normalized);
+    }
+    // TogetherAI reports context overflows as input validation errors on the token budget.
+    if status == 400 && lower.contains("max_new_tokens") && lower.contains("must be <=") {
+        return Some(LlmError::context_length_exceeded(message));
+    }
     if status == 429 {
-        return Some(LlmError::RateLimitError(message));
+        return Some(LlmError::rate_limit_error(message));
     }
     if status == 401 || status == 403 {
         return Some(LlmError::AuthenticationError(message));
@@ -59,10 +67,10 @@ pub fn classify_togetherai_http_error(
                 || lower.contains("ratelimitexceeded")
                 || lower.contains("ratelimit exceeded");
             if quota_like {
-                return Some(LlmError::QuotaExceededError(message));
+                return Some(LlmError::quota_exceeded_error(message));
             }
             if rate_like {
-                return Some(LlmError::RateLimitError(message));
+                return Some(LlmError::rate_limit_error(message));
             }
         }
         return Some(LlmError::InvalidInput(message));
@@ -72,5 +80,6 @@ pub fn classify_togetherai_http_error(
         code: status,
         message,
         details: serde_json::from_str::<serde_json::Value>(body_text).ok(),
+        http_details: None,
     })
 }
@@ -5,8 +5,8 @@ use crate::execution::executors::audio::{AudioExecutor, AudioExecutorBuilder};
 use crate::execution::transformers::audio::{
     AudioHttpBody, AudioTransformer as RequestAudioTransformer,
 };
-use crate::traits::{AudioCapability, ProviderCapabilities};
-use crate::types::{AudioFeature, SttRequest, SttResponse, TtsRequest, TtsResponse};
+use crate::traits::{AudioCapability, ProviderCapabilities, VoiceManagementCapability};
+use crate::types::{AudioFeature, SttRequest, SttResponse, TtsRequest, TtsResponse, VoiceInfo};
 use async_trait::async_trait;
 use reqwest::header::HeaderMap;
 use std::sync::Arc;
@@ -17,6 +17,27 @@ const DEFAULT_CODEC: &str = "mp3";
 const DEFAULT_SAMPLE_RATE: u64 = 24_000;
 const DEFAULT_BIT_RATE: u64 = 128_000;
 
+/// Built-in speech voices: `(voice id, display name, gender, description)`.
+const XAI_TTS_VOICES: &[(&str, &str, &str, &str)] = &[
+    ("ara", "Ara", "female", "Warm and friendly."),
+    ("eve", "Eve", "female", "Energetic and upbeat."),
+    ("leo", "Leo", "male", "Authoritative and strong."),
+    ("rex", "Rex", "male", "Confident and clear."),
+    ("sal", "Sal", "neutral", "Smooth and balanced."),
+];
+
+fn xai_tts_voices() -> Vec<VoiceInfo> {
+    XAI_TTS_VOICES
+        .iter()
+        .map(|(id, name, gender, description)| {
+            VoiceInfo::new(*id, *name)
+                .with_gender(*gender)
+                .with_description(*description)
+                .with_category("built-in")
+        })
+        .collect()
+}
+
 #[derive(Clone, Copy, Default)]
 struct XaiSpeechSpec;
 
@@ -238,6 +259,17 @@ impl AudioCapability for XaiClient {
             "xAI provider-owned audio path currently supports text-to-speech only".to_string(),
         ))
     }
+
+    async fn get_voices(&self) -> Result<Vec<VoiceInfo>, LlmError> {
+        Ok(xai_tts_voices())
+    }
+}
+
+#[async_trait]
+impl VoiceManagementCapability for XaiClient {
+    async fn list_voices(&self) -> Result<Vec<VoiceInfo>, LlmError> {
+        Ok(xai_tts_voices())
+    }
 }
 
 #[cfg(test)]
@@ -345,6 +377,7 @@ mod tests {
         assert!(client.as_audio_capability().is_some());
         assert!(client.as_speech_capability().is_some());
         assert!(client.as_speech_extras().is_none());
+        assert!(client.as_voice_management_capability().is_some());
         assert!(client.as_transcription_capability().is_none());
         assert!(client.as_transcription_extras().is_none());
         assert_eq!(
@@ -356,6 +389,30 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn xai_client_lists_builtin_voices_usable_as_tts_voice() {
+        let cfg = XaiConfig::new("test-key")
+            .with_model("grok-4")
+            .with_base_url("https://example.com/v1");
+        let client = XaiClient::with_http_client(cfg, reqwest::Client::new())
+            .await
+            .expect("build xai client");
+
+        let voices = client.list_voices().await.expect("list voices");
+        let default_voice = voices
+            .iter()
+            .find(|voice| voice.id == DEFAULT_VOICE_ID)
+            .expect("default voice is listed");
+        assert_eq!(default_voice.gender.as_deref(), Some("female"));
+        assert_eq!(AudioCapability::get_voices(&client).await.unwrap(), voices);
+
+        let err = client
+            .delete_voice("eve".to_string())
+            .await
+            .expect_err("built-in voices cannot be deleted");
+        assert!(matches!(err, LlmError::UnsupportedOperation(_)));
+    }
+
     #[tokio::test]
     async fn xai_client_speech_to_text_is_unsupported() {
         let cfg = XaiConfig::new("test-key")
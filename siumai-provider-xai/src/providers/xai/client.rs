@@ -1295,6 +1295,12 @@ impl LlmClient for XaiClient {
         Some(self)
     }
 
+    fn as_voice_management_capability(
+        &self,
+    ) -> Option<&dyn crate::traits::VoiceManagementCapability> {
+        Some(self)
+    }
+
     fn as_rerank_capability(&self) -> Option<&dyn RerankCapability> {
         None
     }
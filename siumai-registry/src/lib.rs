@@ -28,10 +28,15 @@ pub mod experimental {
 // Note: `siumai-registry` intentionally does not re-export provider crates.
 // Use the `siumai` facade for stable entry points (`provider_ext`, `prelude::unified`, etc.).
 
+pub mod model_capabilities;
 pub mod provider;
 pub mod provider_builders;
 pub mod registry;
 
+/// Scripted mock provider for downstream tests.
+#[cfg(feature = "testing")]
+pub mod testing;
+
 #[cfg(test)]
 pub(crate) mod test_support;
 
@@ -0,0 +1,476 @@
+//! Model capability catalog.
+//!
+//! A [`ModelCatalog`] holds one [`CatalogModel`] per `provider` + `model` pair: input/output
+//! modalities, tool calling, structured output, reasoning, context window, max output,
+//! knowledge cutoff and pricing. It starts from data embedded in this crate
+//! ([`ModelCatalog::embedded`]), can be patched from an override file
+//! ([`ModelCatalog::apply_overrides_file`]) and extended from a provider's model listing
+//! ([`ModelCatalog::refresh_from_listing`]).
+//!
+//! [`ModelQuery`] filters and orders models; [`ModelCatalog::select_for_registry`] restricts the
+//! result to providers registered in a [`ProviderRegistryHandle`] and returns registry ids:
+//!
+//! ```rust,no_run
+//! use siumai_registry::model_capabilities::{Modality, ModelCatalog, ModelQuery};
+//! # fn demo(registry: &siumai_registry::registry::entry::ProviderRegistryHandle) {
+//! let catalog = ModelCatalog::embedded();
+//! let query = ModelQuery::new()
+//!     .with_input(Modality::Image)
+//!     .with_tool_calling()
+//!     .with_min_context_window(128_000);
+//! // Cheapest first (input + output price per token), as registry ids such as "openai:gpt-4.1-nano".
+//! let best = catalog.select_for_registry(registry, &query).into_iter().next();
+//! # }
+//! ```
+//!
+//! Override files use the embedded format: a JSON array of entries keyed by `provider` and
+//! `model`. Fields present in an override replace the catalog values; unknown models are added.
+//!
+//! ```json
+//! [
+//!   { "provider": "openai", "model": "gpt-4o", "input_cost_per_token": 2.0e-6 },
+//!   { "provider": "ollama", "model": "llama3.2", "tool_calling": true, "context_window": 131072 }
+//! ]
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::LlmError;
+use crate::registry::entry::ProviderRegistryHandle;
+use crate::traits::ModelListingCapability;
+use crate::types::ModelInfo;
+
+#[cfg(test)]
+mod tests;
+
+const EMBEDDED_MODELS: &str = include_str!("models.json");
+
+/// Kind of content a model accepts or produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Modality {
+    Text,
+    Image,
+    Audio,
+    Video,
+    /// Documents such as PDFs.
+    File,
+    /// Embedding vectors (output only).
+    Embedding,
+}
+
+fn text_only() -> Vec<Modality> {
+    vec![Modality::Text]
+}
+
+/// Capabilities and limits of one model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CatalogModel {
+    /// Canonical provider id (e.g. `"openai"`, `"gemini"`).
+    pub provider: String,
+    /// Provider model id.
+    pub model: String,
+    /// Human-readable name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default = "text_only")]
+    pub input_modalities: Vec<Modality>,
+    #[serde(default = "text_only")]
+    pub output_modalities: Vec<Modality>,
+    #[serde(default)]
+    pub tool_calling: bool,
+    /// Native JSON / JSON Schema output.
+    #[serde(default)]
+    pub structured_output: bool,
+    #[serde(default)]
+    pub reasoning: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_window: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<u32>,
+    /// Training data cutoff as `YYYY-MM`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub knowledge_cutoff: Option<String>,
+    /// USD per input token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_cost_per_token: Option<f64>,
+    /// USD per output token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_cost_per_token: Option<f64>,
+}
+
+impl CatalogModel {
+    /// A text-in/text-out model with no other capabilities recorded.
+    pub fn new(provider: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            provider: provider.into(),
+            model: model.into(),
+            name: None,
+            input_modalities: text_only(),
+            output_modalities: text_only(),
+            tool_calling: false,
+            structured_output: false,
+            reasoning: false,
+            context_window: None,
+            max_output_tokens: None,
+            knowledge_cutoff: None,
+            input_cost_per_token: None,
+            output_cost_per_token: None,
+        }
+    }
+
+    /// Registry id (`provider{separator}model`).
+    pub fn registry_id(&self, separator: char) -> String {
+        format!("{}{}{}", self.provider, separator, self.model)
+    }
+
+    /// Input plus output price per token, when both are known.
+    pub fn blended_cost_per_token(&self) -> Option<f64> {
+        Some(self.input_cost_per_token? + self.output_cost_per_token?)
+    }
+
+    /// Build an entry from a listed model, mapping its capability tags.
+    fn from_listing(provider: &str, info: &ModelInfo) -> Self {
+        let mut model = Self::new(provider, info.id.clone());
+        model.name = info.name.clone();
+        for tag in &info.capabilities {
+            match tag.as_str() {
+                "vision" | "multimodal" => model.add_input(Modality::Image),
+                "audio" => model.add_input(Modality::Audio),
+                "tools" | "function_calling" => model.tool_calling = true,
+                "reasoning" | "thinking" | "advanced_reasoning" => model.reasoning = true,
+                "json" | "structured_output" => model.structured_output = true,
+                "image_generation" => model.output_modalities = vec![Modality::Image],
+                "embedding" => model.output_modalities = vec![Modality::Embedding],
+                _ => {}
+            }
+        }
+        model.fill_from_listing(info);
+        model
+    }
+
+    /// Fill limits and prices the entry does not know yet; curated values win.
+    fn fill_from_listing(&mut self, info: &ModelInfo) {
+        self.name = self.name.take().or_else(|| info.name.clone());
+        self.context_window = self.context_window.or(info.context_window);
+        self.max_output_tokens = self.max_output_tokens.or(info.max_output_tokens);
+        self.input_cost_per_token = self.input_cost_per_token.or(info.input_cost_per_token);
+        self.output_cost_per_token = self.output_cost_per_token.or(info.output_cost_per_token);
+    }
+
+    fn add_input(&mut self, modality: Modality) {
+        if !self.input_modalities.contains(&modality) {
+            self.input_modalities.push(modality);
+        }
+    }
+}
+
+/// Result ordering for [`ModelQuery`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ModelOrder {
+    /// Lowest blended price first; unpriced models last.
+    ///
+    /// The blended price is [`CatalogModel::blended_cost_per_token`]: input plus output price per
+    /// token, weighted equally. Workloads dominated by long prompts or long completions may rank
+    /// differently; sort on the individual prices in that case.
+    #[default]
+    Cheapest,
+    /// Largest context window first; unknown windows last.
+    LargestContext,
+}
+
+/// Requirements for selecting models from a [`ModelCatalog`].
+///
+/// Ties are broken by provider and model id, so results are deterministic.
+#[derive(Debug, Clone, Default)]
+pub struct ModelQuery {
+    /// Modalities the model must accept.
+    pub inputs: BTreeSet<Modality>,
+    /// Modalities the model must produce.
+    pub outputs: BTreeSet<Modality>,
+    pub tool_calling: bool,
+    pub structured_output: bool,
+    pub reasoning: bool,
+    /// Minimum context window; models with an unknown window never match.
+    pub min_context_window: Option<u32>,
+    /// Minimum max output; models with an unknown limit never match.
+    pub min_output_tokens: Option<u32>,
+    /// Only consider these providers (`None` = any).
+    pub providers: Option<BTreeSet<String>>,
+    pub order: ModelOrder,
+}
+
+impl ModelQuery {
+    /// A query matching every model, cheapest first.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require an input modality.
+    pub fn with_input(mut self, modality: Modality) -> Self {
+        self.inputs.insert(modality);
+        self
+    }
+
+    /// Require an output modality.
+    pub fn with_output(mut self, modality: Modality) -> Self {
+        self.outputs.insert(modality);
+        self
+    }
+
+    /// Require tool calling.
+    pub fn with_tool_calling(mut self) -> Self {
+        self.tool_calling = true;
+        self
+    }
+
+    /// Require native structured output.
+    pub fn with_structured_output(mut self) -> Self {
+        self.structured_output = true;
+        self
+    }
+
+    /// Require a reasoning model.
+    pub fn with_reasoning(mut self) -> Self {
+        self.reasoning = true;
+        self
+    }
+
+    /// Require at least `tokens` of context.
+    pub fn with_min_context_window(mut self, tokens: u32) -> Self {
+        self.min_context_window = Some(tokens);
+        self
+    }
+
+    /// Require at least `tokens` of output.
+    pub fn with_min_output_tokens(mut self, tokens: u32) -> Self {
+        self.min_output_tokens = Some(tokens);
+        self
+    }
+
+    /// Restrict to the given providers.
+    pub fn with_providers<I, S>(mut self, providers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.providers = Some(providers.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Set the result ordering.
+    pub fn with_order(mut self, order: ModelOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Whether `model` satisfies every requirement.
+    pub fn matches(&self, model: &CatalogModel) -> bool {
+        let at_least = |value: Option<u32>, min: Option<u32>| match min {
+            Some(min) => value.is_some_and(|value| value >= min),
+            None => true,
+        };
+        self.inputs
+            .iter()
+            .all(|m| model.input_modalities.contains(m))
+            && self
+                .outputs
+                .iter()
+                .all(|m| model.output_modalities.contains(m))
+            && (!self.tool_calling || model.tool_calling)
+            && (!self.structured_output || model.structured_output)
+            && (!self.reasoning || model.reasoning)
+            && at_least(model.context_window, self.min_context_window)
+            && at_least(model.max_output_tokens, self.min_output_tokens)
+            && self
+                .providers
+                .as_ref()
+                .is_none_or(|providers| providers.contains(&model.provider))
+    }
+
+    fn sort(&self, models: &mut [&CatalogModel]) {
+        models.sort_by(|a, b| {
+            let primary = match self.order {
+                ModelOrder::Cheapest => {
+                    match (a.blended_cost_per_token(), b.blended_cost_per_token()) {
+                        (Some(a), Some(b)) => a.total_cmp(&b),
+                        (Some(_), None) => Ordering::Less,
+                        (None, Some(_)) => Ordering::Greater,
+                        (None, None) => Ordering::Equal,
+                    }
+                }
+                ModelOrder::LargestContext => b.context_window.cmp(&a.context_window),
+            };
+            primary
+                .then_with(|| a.provider.cmp(&b.provider))
+                .then_with(|| a.model.cmp(&b.model))
+        });
+    }
+}
+
+/// Queryable set of [`CatalogModel`]s.
+#[derive(Debug, Clone, Default)]
+pub struct ModelCatalog {
+    models: BTreeMap<(String, String), CatalogModel>,
+}
+
+impl ModelCatalog {
+    /// An empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The catalog shipped with this crate.
+    pub fn embedded() -> Self {
+        Self::from_json(EMBEDDED_MODELS).expect("embedded model catalog must be valid")
+    }
+
+    /// Parse a catalog from a JSON array of [`CatalogModel`] entries.
+    pub fn from_json(text: &str) -> Result<Self, LlmError> {
+        let mut de = serde_json::Deserializer::from_str(text);
+        let models: Vec<CatalogModel> = serde_path_to_error::deserialize(&mut de)
+            .map_err(|e| LlmError::ConfigurationError(format!("model catalog: {e}")))?;
+        let mut catalog = Self::new();
+        for model in models {
+            catalog.insert(model);
+        }
+        Ok(catalog)
+    }
+
+    /// Apply overrides from a JSON array (see the module docs).
+    pub fn apply_overrides_json(&mut self, text: &str) -> Result<(), LlmError> {
+        let entries: Vec<Value> = serde_json::from_str(text)
+            .map_err(|e| LlmError::ConfigurationError(format!("model catalog overrides: {e}")))?;
+        for (index, entry) in entries.into_iter().enumerate() {
+            self.apply_override(entry).map_err(|e| {
+                LlmError::ConfigurationError(format!("model catalog overrides[{index}]: {e}"))
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Apply overrides from a JSON file.
+    pub fn apply_overrides_file(&mut self, path: impl AsRef<Path>) -> Result<(), LlmError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            LlmError::ConfigurationError(format!(
+                "failed to read model catalog overrides {}: {e}",
+                path.display()
+            ))
+        })?;
+        self.apply_overrides_json(&text)
+    }
+
+    fn apply_override(&mut self, entry: Value) -> Result<(), String> {
+        let Value::Object(fields) = entry else {
+            return Err("expected an object".into());
+        };
+        let key = |name: &str| match fields.get(name) {
+            Some(Value::String(value)) => Ok(value.clone()),
+            _ => Err(format!("missing string field `{name}`")),
+        };
+        let (provider, model) = (key("provider")?, key("model")?);
+
+        let mut merged = match self.get(&provider, &model) {
+            Some(existing) => serde_json::to_value(existing).map_err(|e| e.to_string())?,
+            None => Value::Object(Default::default()),
+        };
+        if let Value::Object(merged) = &mut merged {
+            merged.extend(fields);
+        }
+        let model: CatalogModel =
+            serde_path_to_error::deserialize(merged).map_err(|e| e.to_string())?;
+        self.insert(model);
+        Ok(())
+    }
+
+    /// Add or replace an entry, returning the previous one.
+    pub fn insert(&mut self, model: CatalogModel) -> Option<CatalogModel> {
+        self.models
+            .insert((model.provider.clone(), model.model.clone()), model)
+    }
+
+    /// Look up a model.
+    pub fn get(&self, provider: &str, model: &str) -> Option<&CatalogModel> {
+        self.models.get(&(provider.to_string(), model.to_string()))
+    }
+
+    /// All entries, ordered by provider and model id.
+    pub fn models(&self) -> impl Iterator<Item = &CatalogModel> {
+        self.models.values()
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.models.len()
+    }
+
+    /// Whether the catalog has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty()
+    }
+
+    /// Merge a provider's model listing into the catalog.
+    ///
+    /// Unknown models are added with capabilities derived from their `ModelInfo` tags; known
+    /// models only gain limits and prices they were missing. Returns the number of added models.
+    pub async fn refresh_from_listing(
+        &mut self,
+        provider: &str,
+        listing: &dyn ModelListingCapability,
+    ) -> Result<usize, LlmError> {
+        let mut added = 0;
+        for info in listing.list_models().await? {
+            match self
+                .models
+                .get_mut(&(provider.to_string(), info.id.clone()))
+            {
+                Some(existing) => existing.fill_from_listing(&info),
+                None => {
+                    self.insert(CatalogModel::from_listing(provider, &info));
+                    added += 1;
+                }
+            }
+        }
+        Ok(added)
+    }
+
+    /// Models matching `query`, in query order.
+    pub fn select(&self, query: &ModelQuery) -> Vec<&CatalogModel> {
+        let mut models: Vec<_> = self.models().filter(|m| query.matches(m)).collect();
+        query.sort(&mut models);
+        models
+    }
+
+    /// Registry ids of matching models whose provider is registered in `registry`, in query order.
+    ///
+    /// Registered alias ids (e.g. `google`) match their canonical catalog provider (`gemini`),
+    /// and the returned id uses the registered name so it resolves as-is.
+    pub fn select_for_registry(
+        &self,
+        registry: &ProviderRegistryHandle,
+        query: &ModelQuery,
+    ) -> Vec<String> {
+        let mut registered: BTreeMap<String, &str> = BTreeMap::new();
+        for id in registry.provider_ids() {
+            let canonical = crate::provider::resolver::normalize_provider_id(id);
+            // A provider registered under its canonical id wins over an alias.
+            if canonical == id || !registered.contains_key(&canonical) {
+                registered.insert(canonical, id);
+            }
+        }
+        self.select(query)
+            .into_iter()
+            .filter_map(|model| {
+                let provider = registered.get(&model.provider)?;
+                Some(format!("{provider}{}{}", registry.separator(), model.model))
+            })
+            .collect()
+    }
+}
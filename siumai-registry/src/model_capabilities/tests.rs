@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::*;
+use crate::registry::entry::{ProviderFactory, TestProviderFactory, create_provider_registry};
+
+fn registry(ids: &[&'static str]) -> ProviderRegistryHandle {
+    let providers: HashMap<String, Arc<dyn ProviderFactory>> = ids
+        .iter()
+        .map(|id| {
+            (
+                id.to_string(),
+                Arc::new(TestProviderFactory::new(id)) as Arc<dyn ProviderFactory>,
+            )
+        })
+        .collect();
+    create_provider_registry(providers, None)
+}
+
+fn vision_tools_128k() -> ModelQuery {
+    ModelQuery::new()
+        .with_input(Modality::Image)
+        .with_tool_calling()
+        .with_min_context_window(128_000)
+}
+
+#[test]
+fn embedded_catalog_parses_with_canonical_provider_ids() {
+    let catalog = ModelCatalog::embedded();
+    assert!(!catalog.is_empty());
+
+    let gpt = catalog.get("openai", "gpt-4o").expect("gpt-4o");
+    assert!(gpt.input_modalities.contains(&Modality::Image));
+    assert!(gpt.tool_calling && gpt.structured_output && !gpt.reasoning);
+    assert_eq!(gpt.context_window, Some(128_000));
+    assert_eq!(gpt.knowledge_cutoff.as_deref(), Some("2023-10"));
+
+    for model in catalog.models() {
+        assert_eq!(
+            crate::provider::resolver::normalize_provider_id(&model.provider),
+            model.provider
+        );
+    }
+}
+
+#[test]
+fn select_orders_cheapest_first_and_filters_requirements() {
+    let catalog = ModelCatalog::embedded();
+    let ids: Vec<String> = catalog
+        .select(&vision_tools_128k())
+        .into_iter()
+        .map(|m| m.registry_id(':'))
+        .collect();
+
+    // Equal price: ties are broken by provider id.
+    assert_eq!(ids[0], "gemini:gemini-2.0-flash");
+    assert_eq!(ids[1], "openai:gpt-4.1-nano");
+    assert!(!ids.iter().any(|id| id.starts_with("deepseek:")));
+    assert!(!ids.contains(&"openai:o3-mini".to_string()));
+
+    let largest = catalog
+        .select(
+            &ModelQuery::new()
+                .with_reasoning()
+                .with_order(ModelOrder::LargestContext),
+        )
+        .into_iter()
+        .next()
+        .expect("reasoning model");
+    assert_eq!(largest.context_window, Some(1_048_576));
+}
+
+#[test]
+fn select_for_registry_returns_registered_ids_only() {
+    let catalog = ModelCatalog::embedded();
+    let reg = registry(&["openai", "google", "groq"]);
+
+    let ids = catalog.select_for_registry(&reg, &vision_tools_128k());
+    assert_eq!(ids[0], "google:gemini-2.0-flash");
+    assert!(ids.contains(&"openai:gpt-4o".to_string()));
+    assert!(!ids.iter().any(|id| id.starts_with("anthropic:")));
+
+    let reg = registry(&["anthropic"]);
+    assert_eq!(
+        catalog
+            .select_for_registry(&reg, &vision_tools_128k())
+            .first()
+            .map(String::as_str),
+        Some("anthropic:claude-3-5-haiku-20241022")
+    );
+    assert!(
+        catalog
+            .select_for_registry(&registry(&["groq"]), &vision_tools_128k())
+            .is_empty()
+    );
+}
+
+#[test]
+fn overrides_patch_existing_entries_and_add_new_ones() {
+    let mut catalog = ModelCatalog::embedded();
+    catalog
+        .apply_overrides_json(
+            r#"[
+                { "provider": "openai", "model": "gpt-4o", "input_cost_per_token": 1.0e-6 },
+                { "provider": "ollama", "model": "llama3.2", "tool_calling": true }
+            ]"#,
+        )
+        .expect("overrides");
+
+    let gpt = catalog.get("openai", "gpt-4o").expect("gpt-4o");
+    assert_eq!(gpt.input_cost_per_token, Some(1.0e-6));
+    assert_eq!(gpt.output_cost_per_token, Some(1.0e-5));
+    assert!(gpt.input_modalities.contains(&Modality::Image));
+
+    let llama = catalog.get("ollama", "llama3.2").expect("added");
+    assert!(llama.tool_calling);
+    assert_eq!(llama.input_modalities, vec![Modality::Text]);
+}
+
+#[test]
+fn overrides_report_the_offending_entry() {
+    let mut catalog = ModelCatalog::new();
+    let err = catalog
+        .apply_overrides_json(r#"[{ "provider": "openai", "model": "x", "tools": true }]"#)
+        .expect_err("unknown field");
+    assert!(err.to_string().contains("overrides[0]"), "{err}");
+
+    let err = catalog
+        .apply_overrides_json(r#"[{ "model": "x" }]"#)
+        .expect_err("missing provider");
+    assert!(err.to_string().contains("provider"), "{err}");
+}
+
+#[test]
+fn overrides_file_is_read_from_disk() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("models.json");
+    std::fs::write(
+        &path,
+        r#"[{ "provider": "openai", "model": "gpt-4o", "context_window": 64000 }]"#,
+    )
+    .expect("write");
+
+    let mut catalog = ModelCatalog::embedded();
+    catalog.apply_overrides_file(&path).expect("overrides");
+    assert_eq!(
+        catalog
+            .get("openai", "gpt-4o")
+            .and_then(|m| m.context_window),
+        Some(64_000)
+    );
+    assert!(
+        catalog
+            .apply_overrides_file(dir.path().join("missing.json"))
+            .is_err()
+    );
+}
+
+fn info(id: &str, capabilities: &[&str], context_window: u32) -> ModelInfo {
+    ModelInfo {
+        id: id.to_string(),
+        name: None,
+        description: None,
+        owned_by: "openai".to_string(),
+        created: None,
+        capabilities: capabilities.iter().map(|c| c.to_string()).collect(),
+        context_window: Some(context_window),
+        max_output_tokens: None,
+        input_cost_per_token: None,
+        output_cost_per_token: None,
+    }
+}
+
+struct Listing(Vec<ModelInfo>);
+
+#[async_trait::async_trait]
+impl ModelListingCapability for Listing {
+    async fn list_models(&self) -> Result<Vec<ModelInfo>, LlmError> {
+        Ok(self.0.clone())
+    }
+
+    async fn get_model(&self, model_id: String) -> Result<ModelInfo, LlmError> {
+        self.0
+            .iter()
+            .find(|m| m.id == model_id)
+            .cloned()
+            .ok_or(LlmError::NotFound(model_id))
+    }
+}
+
+#[tokio::test]
+async fn refresh_from_listing_adds_models_and_keeps_curated_values() {
+    let listing = Listing(vec![
+        info("gpt-4o", &[], 1),
+        info("gpt-new", &["chat", "tools", "vision"], 400_000),
+    ]);
+
+    let mut catalog = ModelCatalog::embedded();
+    let added = catalog
+        .refresh_from_listing("openai", &listing)
+        .await
+        .expect("refresh");
+    assert_eq!(added, 1);
+
+    assert_eq!(
+        catalog
+            .get("openai", "gpt-4o")
+            .and_then(|m| m.context_window),
+        Some(128_000)
+    );
+    let new = catalog.get("openai", "gpt-new").expect("added");
+    assert!(new.tool_calling);
+    assert_eq!(new.input_modalities, vec![Modality::Text, Modality::Image]);
+    assert!(
+        catalog
+            .select(&vision_tools_128k().with_providers(["openai"]))
+            .iter()
+            .any(|m| m.model == "gpt-new")
+    );
+}
+
+/// Catalog ids of `provider`, so each provider's constants can be checked against them.
+#[cfg(any(
+    feature = "openai",
+    feature = "anthropic",
+    feature = "google",
+    feature = "deepseek",
+    feature = "groq",
+    feature = "xai"
+))]
+fn catalog_ids(provider: &str) -> Vec<String> {
+    let ids: Vec<String> = ModelCatalog::embedded()
+        .models()
+        .filter(|m| m.provider == provider)
+        .map(|m| m.model.clone())
+        .collect();
+    assert!(!ids.is_empty(), "no {provider} models in the catalog");
+    ids
+}
+
+#[cfg(feature = "openai")]
+#[test]
+fn openai_catalog_models_have_constants() {
+    use siumai_provider_openai::providers::openai::model_constants as openai;
+
+    let mut known = openai::all_chat_models();
+    known.extend_from_slice(openai::embeddings::ALL);
+    for id in catalog_ids("openai") {
+        assert!(known.contains(&id.as_str()), "no openai constant for {id}");
+    }
+}
+
+#[cfg(feature = "anthropic")]
+#[test]
+fn anthropic_catalog_models_have_constants() {
+    use siumai_provider_anthropic::providers::anthropic::model_constants as anthropic;
+
+    let known = anthropic::all_chat_models();
+    for id in catalog_ids("anthropic") {
+        assert!(
+            known.contains(&id.as_str()),
+            "no anthropic constant for {id}"
+        );
+    }
+}
+
+#[cfg(feature = "google")]
+#[test]
+fn gemini_catalog_models_have_constants() {
+    use siumai_provider_gemini::providers::gemini::model_constants as gemini;
+
+    let known = gemini::all_chat_models();
+    for id in catalog_ids("gemini") {
+        assert!(known.contains(&id.as_str()), "no gemini constant for {id}");
+    }
+}
+
+#[cfg(feature = "deepseek")]
+#[test]
+fn deepseek_catalog_models_are_supported() {
+    use siumai_provider_openai_compatible::providers::openai_compatible::providers::models;
+
+    let known = siumai_provider_deepseek::providers::deepseek::models::all_models();
+    for id in catalog_ids("deepseek") {
+        assert!(
+            known.contains(&id.as_str()),
+            "no deepseek constant for {id}"
+        );
+        assert!(models::is_model_supported("deepseek", &id), "{id}");
+    }
+}
+
+#[cfg(feature = "groq")]
+#[test]
+fn groq_catalog_models_are_supported() {
+    use siumai_provider_openai_compatible::providers::openai_compatible::providers::models;
+
+    let known = siumai_provider_groq::providers::groq::models::all_models();
+    for id in catalog_ids("groq") {
+        assert!(known.contains(&id.as_str()), "no groq constant for {id}");
+        assert!(models::is_model_supported("groq", &id), "{id}");
+    }
+}
+
+#[cfg(feature = "xai")]
+#[test]
+fn xai_catalog_models_are_supported() {
+    use siumai_provider_openai_compatible::providers::openai_compatible::providers::models;
+
+    let known = siumai_provider_xai::providers::xai::models::all_models();
+    for id in catalog_ids("xai") {
+        assert!(known.contains(&id.as_str()), "no xai constant for {id}");
+        assert!(models::is_model_supported("xai", &id), "{id}");
+    }
+}
@@ -21,6 +21,7 @@ mod music;
 mod rerank;
 mod skills;
 mod video;
+mod voice;
 
 /// Historical method-style wrapper over a provider `LlmClient`.
 ///
@@ -59,4 +59,32 @@ impl FileManagementCapability for Siumai {
             )))
         }
     }
+
+    async fn upload_file_stream(
+        &self,
+        request: FileStreamUploadRequest,
+    ) -> Result<FileObject, LlmError> {
+        if let Some(files) = self.client.as_file_management_capability() {
+            files.upload_file_stream(request).await
+        } else {
+            Err(LlmError::UnsupportedOperation(format!(
+                "Provider {} does not support file management.",
+                self.client.provider_id()
+            )))
+        }
+    }
+
+    async fn get_file_content_stream(
+        &self,
+        file_id: String,
+    ) -> Result<FileContentStream, LlmError> {
+        if let Some(files) = self.client.as_file_management_capability() {
+            files.get_file_content_stream(file_id).await
+        } else {
+            Err(LlmError::UnsupportedOperation(format!(
+                "Provider {} does not support file management.",
+                self.client.provider_id()
+            )))
+        }
+    }
 }
@@ -68,6 +68,10 @@ impl LlmClient for Siumai {
         self.client.as_skills_capability()
     }
 
+    fn as_voice_management_capability(&self) -> Option<&dyn VoiceManagementCapability> {
+        self.client.as_voice_management_capability()
+    }
+
     fn as_moderation_capability(&self) -> Option<&dyn ModerationCapability> {
         self.client.as_moderation_capability()
     }
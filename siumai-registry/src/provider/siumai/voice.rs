@@ -0,0 +1,59 @@
+use super::Siumai;
+use crate::error::LlmError;
+use crate::traits::VoiceManagementCapability;
+use crate::types::{
+    VoiceCloneRequest, VoiceCreationResponse, VoiceDeleteResponse, VoiceDesignRequest, VoiceInfo,
+};
+
+#[async_trait::async_trait]
+impl VoiceManagementCapability for Siumai {
+    async fn list_voices(&self) -> Result<Vec<VoiceInfo>, LlmError> {
+        if let Some(voices) = self.client.as_voice_management_capability() {
+            voices.list_voices().await
+        } else {
+            Err(LlmError::UnsupportedOperation(format!(
+                "Provider {} does not support voice listing.",
+                self.client.provider_id()
+            )))
+        }
+    }
+
+    async fn clone_voice(
+        &self,
+        request: VoiceCloneRequest,
+    ) -> Result<VoiceCreationResponse, LlmError> {
+        if let Some(voices) = self.client.as_voice_management_capability() {
+            voices.clone_voice(request).await
+        } else {
+            Err(LlmError::UnsupportedOperation(format!(
+                "Provider {} does not support voice cloning.",
+                self.client.provider_id()
+            )))
+        }
+    }
+
+    async fn design_voice(
+        &self,
+        request: VoiceDesignRequest,
+    ) -> Result<VoiceCreationResponse, LlmError> {
+        if let Some(voices) = self.client.as_voice_management_capability() {
+            voices.design_voice(request).await
+        } else {
+            Err(LlmError::UnsupportedOperation(format!(
+                "Provider {} does not support voice design.",
+                self.client.provider_id()
+            )))
+        }
+    }
+
+    async fn delete_voice(&self, voice_id: String) -> Result<VoiceDeleteResponse, LlmError> {
+        if let Some(voices) = self.client.as_voice_management_capability() {
+            voices.delete_voice(voice_id).await
+        } else {
+            Err(LlmError::UnsupportedOperation(format!(
+                "Provider {} does not support voice deletion.",
+                self.client.provider_id()
+            )))
+        }
+    }
+}
@@ -383,6 +383,18 @@ impl SiumaiBuilder {
         self
     }
 
+    /// Use a pool of API keys instead of a single key.
+    ///
+    /// The pool's primary key configures the provider and the pool is installed as an HTTP
+    /// interceptor that rotates keys per attempt and quarantines failing ones.
+    pub fn api_key_pool(mut self, pool: crate::auth::ApiKeyPool) -> Self {
+        if let Some(key) = pool.primary_key() {
+            self.api_key = Some(key);
+        }
+        self.http_interceptors.push(Arc::new(pool));
+        self
+    }
+
     /// Set the base URL
     pub fn base_url<S: Into<String>>(mut self, base_url: S) -> Self {
         self.base_url = Some(base_url.into());
@@ -43,6 +43,8 @@ pub struct RegistryBuilder {
     max_cache_entries: Option<usize>,
     client_ttl: Option<Duration>,
     auto_middleware: bool,
+    model_aliases: HashMap<String, String>,
+    middleware_presets: HashMap<String, Arc<dyn LanguageModelMiddleware>>,
 }
 
 impl RegistryBuilder {
@@ -66,9 +68,35 @@ impl RegistryBuilder {
             max_cache_entries: None,
             client_ttl: None,
             auto_middleware: true,
+            model_aliases: HashMap::new(),
+            middleware_presets: HashMap::new(),
         }
     }
 
+    /// Create a builder from a declarative config file (TOML, YAML or JSON, by extension).
+    ///
+    /// Built-in factories are registered for every provider referenced by the config.
+    /// See [`RegistryConfig`](super::config::RegistryConfig) for the schema.
+    #[cfg(feature = "builtins")]
+    pub fn from_config_file(path: impl AsRef<std::path::Path>) -> Result<Self, LlmError> {
+        let config = super::config::RegistryConfig::from_file(path)?;
+        let mut providers = HashMap::new();
+        for provider_id in config.referenced_providers() {
+            let factory = super::helpers::builtin_provider_factory(&provider_id).map_err(|e| {
+                LlmError::ConfigurationError(format!("providers.{provider_id}: {e}"))
+            })?;
+            providers.insert(provider_id, factory);
+        }
+        Self::new(providers).with_config(&config)
+    }
+
+    /// Apply a declarative [`RegistryConfig`](super::config::RegistryConfig).
+    ///
+    /// Providers referenced by the config must already be registered on this builder.
+    pub fn with_config(self, config: &super::config::RegistryConfig) -> Result<Self, LlmError> {
+        config.apply(self)
+    }
+
     /// Set the provider/model separator (default: `':'`).
     pub fn separator(mut self, sep: char) -> Self {
         self.separator = sep;
@@ -87,6 +115,28 @@ impl RegistryBuilder {
         self
     }
 
+    /// Register a named middleware that config files can reference by name.
+    ///
+    /// Built-in names (`default-params`, `clamp-top-p`, `extract-reasoning`) can be overridden.
+    pub fn with_middleware_preset<S: Into<String>>(
+        mut self,
+        name: S,
+        mw: Arc<dyn LanguageModelMiddleware>,
+    ) -> Self {
+        self.middleware_presets.insert(name.into(), mw);
+        self
+    }
+
+    /// Add a model alias (e.g. `"fast"` -> `"groq:llama-3.1-8b-instant"`).
+    pub fn with_model_alias<A: Into<String>, T: Into<String>>(
+        mut self,
+        alias: A,
+        target: T,
+    ) -> Self {
+        self.model_aliases.insert(alias.into(), target.into());
+        self
+    }
+
     /// Add an HTTP interceptor applied to all clients created via the registry.
     pub fn with_http_interceptor(mut self, interceptor: Arc<dyn HttpInterceptor>) -> Self {
         self.http_interceptors.push(interceptor);
@@ -174,6 +224,26 @@ impl RegistryBuilder {
         self.merge_provider_build_overrides(provider_id, ProviderBuildOverrides::api_key(api_key))
     }
 
+    /// Set a provider-specific API key pool.
+    ///
+    /// The pool is bound to `provider_id` and installed as an HTTP interceptor on that
+    /// provider's handles only. Executors take the key for each attempt from the pool and pass it
+    /// to the provider's `build_headers`; the pool's primary key becomes the API key override so
+    /// client construction succeeds.
+    pub fn with_provider_api_key_pool<S: Into<String>>(
+        self,
+        provider_id: S,
+        pool: crate::auth::ApiKeyPool,
+    ) -> Self {
+        let provider_id = provider_id.into();
+        let pool = pool.for_provider(provider_id.clone());
+        let mut overrides = ProviderBuildOverrides::new();
+        if let Some(key) = pool.primary_key() {
+            overrides = overrides.with_api_key(key);
+        }
+        self.merge_provider_build_overrides(provider_id, overrides.with_api_key_pool(pool))
+    }
+
     /// Set a provider-specific base URL override.
     pub fn with_provider_base_url<S: Into<String>, U: Into<String>>(
         self,
@@ -370,10 +440,37 @@ impl RegistryBuilder {
             client_ttl: self.client_ttl,
             auto_middleware: self.auto_middleware,
         };
-        Ok(create_provider_registry(self.providers, Some(opts)))
+        Ok(create_provider_registry(self.providers, Some(opts))
+            .with_model_aliases(self.model_aliases))
+    }
+
+    pub(crate) fn separator_char(&self) -> char {
+        self.separator
+    }
+
+    pub(crate) fn has_provider(&self, provider_id: &str) -> bool {
+        self.providers.contains_key(provider_id)
+    }
+
+    pub(crate) fn middleware_preset(&self, name: &str) -> Option<Arc<dyn LanguageModelMiddleware>> {
+        if let Some(mw) = self.middleware_presets.get(name) {
+            return Some(mw.clone());
+        }
+        use crate::execution::middleware::presets::{
+            ExtractReasoningConfig, ExtractReasoningMiddleware,
+        };
+        use crate::execution::middleware::samples::{ClampTopPMiddleware, DefaultParamsMiddleware};
+        match name {
+            "default-params" => Some(Arc::new(DefaultParamsMiddleware)),
+            "clamp-top-p" => Some(Arc::new(ClampTopPMiddleware)),
+            "extract-reasoning" => Some(Arc::new(ExtractReasoningMiddleware::new(
+                ExtractReasoningConfig::default(),
+            ))),
+            _ => None,
+        }
     }
 
-    fn merge_provider_build_overrides<S: Into<String>>(
+    pub(crate) fn merge_provider_build_overrides<S: Into<String>>(
         mut self,
         provider_id: S,
         overrides: ProviderBuildOverrides,
@@ -0,0 +1,713 @@
+//! Declarative registry configuration.
+//!
+//! A [`RegistryConfig`] describes a registry in a config file instead of code:
+//! per-provider credentials and HTTP settings, model aliases, retry options and
+//! named middleware presets. JSON is always supported; TOML and YAML are available
+//! behind the `config-toml` / `config-yaml` features.
+//!
+//! String values may reference environment variables as `${VAR}` or
+//! `${VAR:-default}` (`$${` escapes a literal `${`). Interpolation happens before
+//! deserialization, so numbers and booleans must be written as literals.
+//!
+//! ```toml
+//! middlewares = ["default-params"]
+//!
+//! [retry]
+//! max_attempts = 4
+//!
+//! [providers.openai]
+//! api_key = "${OPENAI_API_KEY}"
+//!
+//! [providers.groq]
+//! api_keys = ["${GROQ_KEY_A}", "${GROQ_KEY_B}"]
+//! http = { timeout_secs = 30 }
+//!
+//! [aliases]
+//! fast = "groq:llama-3.1-8b-instant"
+//! smart = "openai:gpt-4o"
+//! ```
+//!
+//! Every error carries the path of the offending value (e.g.
+//! `providers.groq.http.timeout_secs: invalid type ...`).
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::LlmError;
+use crate::retry_api::RetryOptions;
+use crate::types::HttpConfig;
+
+use super::ProviderBuildOverrides;
+use super::builder::RegistryBuilder;
+
+/// Source format of a registry config document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Json,
+    #[cfg(feature = "config-toml")]
+    Toml,
+    #[cfg(feature = "config-yaml")]
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Detect the format from a file extension (`json`, `toml`, `yaml`/`yml`).
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_ascii_lowercase();
+        match ext.as_str() {
+            "json" => Some(Self::Json),
+            #[cfg(feature = "config-toml")]
+            "toml" => Some(Self::Toml),
+            #[cfg(feature = "config-yaml")]
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// Top-level registry configuration document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RegistryConfig {
+    /// Provider/model separator (default: `':'`).
+    pub separator: Option<char>,
+    /// Registry-level API key fallback.
+    pub api_key: Option<String>,
+    /// Registry-level base URL fallback.
+    pub base_url: Option<String>,
+    /// HTTP settings applied to every provider.
+    pub http: Option<HttpSettings>,
+    /// Retry options applied to every provider.
+    pub retry: Option<RetrySettings>,
+    /// Middleware preset names applied to all language models, in order.
+    pub middlewares: Vec<String>,
+    /// Whether to add model-specific middlewares automatically.
+    pub auto_middleware: Option<bool>,
+    /// LRU capacity of the client cache.
+    pub max_cache_entries: Option<usize>,
+    /// TTL of cached clients, in seconds.
+    pub client_ttl_secs: Option<u64>,
+    /// Unified reasoning flag.
+    pub reasoning: Option<bool>,
+    /// Unified reasoning budget.
+    pub reasoning_budget: Option<i32>,
+    /// Per-provider settings keyed by provider id.
+    pub providers: BTreeMap<String, ProviderSettings>,
+    /// Model aliases (`alias -> "provider:model"`).
+    pub aliases: BTreeMap<String, String>,
+}
+
+/// Settings for a single provider.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProviderSettings {
+    /// API key for this provider.
+    pub api_key: Option<String>,
+    /// Multiple API keys, installed as an [`ApiKeyPool`](crate::auth::ApiKeyPool).
+    pub api_keys: Vec<String>,
+    /// Base URL override.
+    pub base_url: Option<String>,
+    /// HTTP settings override (merged over the registry-level settings).
+    pub http: Option<HttpSettings>,
+    /// Reasoning flag override.
+    pub reasoning: Option<bool>,
+    /// Reasoning budget override.
+    pub reasoning_budget: Option<i32>,
+}
+
+/// File-friendly HTTP settings (durations in seconds).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpSettings {
+    pub timeout_secs: Option<u64>,
+    pub connect_timeout_secs: Option<u64>,
+    pub headers: BTreeMap<String, String>,
+    pub proxy: Option<String>,
+    pub user_agent: Option<String>,
+    pub stream_disable_compression: Option<bool>,
+}
+
+impl HttpSettings {
+    /// Convert into the runtime `HttpConfig`.
+    pub fn to_http_config(&self) -> HttpConfig {
+        let mut config = HttpConfig {
+            timeout: self.timeout_secs.map(Duration::from_secs),
+            connect_timeout: self.connect_timeout_secs.map(Duration::from_secs),
+            headers: self
+                .headers
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect::<HashMap<_, _>>(),
+            proxy: self.proxy.clone(),
+            user_agent: self.user_agent.clone(),
+            ..Default::default()
+        };
+        if let Some(disable) = self.stream_disable_compression {
+            config.stream_disable_compression = disable;
+        }
+        config
+    }
+}
+
+/// File-friendly retry settings (policy backend, delays in milliseconds).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RetrySettings {
+    pub max_attempts: Option<u32>,
+    pub initial_delay_ms: Option<u64>,
+    pub max_delay_ms: Option<u64>,
+    pub backoff_multiplier: Option<f64>,
+    pub jitter: Option<bool>,
+    pub retry_401: Option<bool>,
+    pub idempotent: Option<bool>,
+    /// Honor server-provided `retry-after` delays (capped by `max_delay_ms`).
+    pub respect_retry_after: Option<bool>,
+}
+
+impl RetrySettings {
+    /// Convert into runtime `RetryOptions` using the policy backend.
+    pub fn to_retry_options(&self) -> RetryOptions {
+        let mut options = RetryOptions::policy_default();
+        if let Some(mut policy) = options.policy.take() {
+            if let Some(n) = self.max_attempts {
+                policy = policy.with_max_attempts(n);
+            }
+            if let Some(ms) = self.initial_delay_ms {
+                policy = policy.with_initial_delay(Duration::from_millis(ms));
+            }
+            if let Some(ms) = self.max_delay_ms {
+                policy = policy.with_max_delay(Duration::from_millis(ms));
+            }
+            if let Some(m) = self.backoff_multiplier {
+                policy = policy.with_backoff_multiplier(m);
+            }
+            if let Some(j) = self.jitter {
+                policy = policy.with_jitter(j);
+            }
+            if let Some(respect) = self.respect_retry_after {
+                policy = policy.with_respect_retry_after(respect);
+            }
+            options.policy = Some(policy);
+        }
+        if let Some(v) = self.retry_401 {
+            options = options.with_retry_401(v);
+        }
+        if let Some(v) = self.idempotent {
+            options = options.with_idempotent(v);
+        }
+        options
+    }
+}
+
+impl RegistryConfig {
+    /// Load a config file, picking the format from its extension.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, LlmError> {
+        let path = path.as_ref();
+        let format = ConfigFormat::from_path(path).ok_or_else(|| {
+            LlmError::ConfigurationError(format!(
+                "Unsupported registry config format: {} (enable `config-toml` / `config-yaml` for TOML/YAML)",
+                path.display()
+            ))
+        })?;
+        let text = std::fs::read_to_string(path).map_err(|e| {
+            LlmError::ConfigurationError(format!(
+                "Failed to read registry config {}: {e}",
+                path.display()
+            ))
+        })?;
+        Self::parse(&text, format)
+    }
+
+    /// Parse a config document, interpolating `${VAR}` from the process environment.
+    pub fn parse(text: &str, format: ConfigFormat) -> Result<Self, LlmError> {
+        Self::parse_with_env(text, format, |name| std::env::var(name).ok())
+    }
+
+    /// Parse a config document with a custom variable lookup.
+    pub fn parse_with_env(
+        text: &str,
+        format: ConfigFormat,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Self, LlmError> {
+        let mut value = parse_value(text, format)?;
+
+        let mut errors = Vec::new();
+        interpolate_value(&mut value, String::new(), &lookup, &mut errors);
+        if !errors.is_empty() {
+            return Err(invalid(errors));
+        }
+
+        let config: Self = serde_path_to_error::deserialize(value).map_err(|e| {
+            let path = e.path().to_string();
+            let inner = e.into_inner();
+            if path == "." {
+                invalid(vec![inner.to_string()])
+            } else {
+                invalid(vec![format!("{path}: {inner}")])
+            }
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Validate values that the schema alone cannot express.
+    pub fn validate(&self) -> Result<(), LlmError> {
+        let mut errors = Vec::new();
+        let separator = self.separator.unwrap_or(':');
+
+        if let Some(base_url) = &self.base_url {
+            check_url("base_url", base_url, &mut errors);
+        }
+        if self.max_cache_entries == Some(0) {
+            errors.push("max_cache_entries: must be greater than 0".to_string());
+        }
+
+        for (id, provider) in &self.providers {
+            let path = format!("providers.{id}");
+            if id.is_empty() || id.contains(separator) {
+                errors.push(format!(
+                    "{path}: provider id must be non-empty and must not contain '{separator}'"
+                ));
+            }
+            if provider.api_key.is_some() && !provider.api_keys.is_empty() {
+                errors.push(format!(
+                    "{path}: `api_key` and `api_keys` are mutually exclusive"
+                ));
+            }
+            for (i, key) in provider.api_keys.iter().enumerate() {
+                if key.is_empty() {
+                    errors.push(format!("{path}.api_keys[{i}]: must not be empty"));
+                }
+            }
+            if let Some(base_url) = &provider.base_url {
+                check_url(&format!("{path}.base_url"), base_url, &mut errors);
+            }
+        }
+
+        for (alias, target) in &self.aliases {
+            let path = format!("aliases.{alias}");
+            if alias.is_empty() || alias.contains(separator) {
+                errors.push(format!(
+                    "{path}: alias must be non-empty and must not contain '{separator}'"
+                ));
+            }
+            match target.split_once(separator) {
+                Some((p, m)) if !p.is_empty() && !m.is_empty() => {}
+                _ => errors.push(format!(
+                    "{path}: target `{target}` must be 'provider{separator}model'"
+                )),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid(errors))
+        }
+    }
+
+    /// Provider ids referenced by the config (provider sections and alias targets).
+    pub fn referenced_providers(&self) -> Vec<String> {
+        let separator = self.separator.unwrap_or(':');
+        let mut ids: BTreeSet<String> = self.providers.keys().cloned().collect();
+        for target in self.aliases.values() {
+            if let Some((provider, _)) = target.split_once(separator) {
+                ids.insert(provider.to_string());
+            }
+        }
+        ids.into_iter().collect()
+    }
+
+    /// Apply this config to a builder whose providers are already registered.
+    pub(crate) fn apply(&self, builder: RegistryBuilder) -> Result<RegistryBuilder, LlmError> {
+        self.validate()?;
+
+        let mut errors = Vec::new();
+        let mut b = builder;
+
+        if let Some(separator) = self.separator {
+            b = b.separator(separator);
+        }
+        if let Some(api_key) = &self.api_key {
+            b = b.with_api_key(api_key.clone());
+        }
+        if let Some(base_url) = &self.base_url {
+            b = b.with_base_url(base_url.clone());
+        }
+        if let Some(http) = &self.http {
+            b = b.with_http_config(http.to_http_config());
+        }
+        if let Some(retry) = &self.retry {
+            b = b.with_retry_options(retry.to_retry_options());
+        }
+        if let Some(enabled) = self.auto_middleware {
+            b = b.auto_middleware(enabled);
+        }
+        if let Some(max) = self.max_cache_entries {
+            b = b.with_max_cache_entries(max);
+        }
+        if let Some(secs) = self.client_ttl_secs {
+            b = b.with_client_ttl(Duration::from_secs(secs));
+        }
+        if let Some(enabled) = self.reasoning {
+            b = b.with_reasoning(enabled);
+        }
+        if let Some(budget) = self.reasoning_budget {
+            b = b.with_reasoning_budget(budget);
+        }
+
+        for (i, name) in self.middlewares.iter().enumerate() {
+            match b.middleware_preset(name) {
+                Some(mw) => b = b.with_middleware(mw),
+                None => errors.push(format!(
+                    "middlewares[{i}]: unknown middleware preset `{name}`"
+                )),
+            }
+        }
+
+        for (id, provider) in &self.providers {
+            if !b.has_provider(id) {
+                errors.push(format!("providers.{id}: provider is not registered"));
+                continue;
+            }
+            if let Some(api_key) = &provider.api_key {
+                b = b.with_provider_api_key(id.clone(), api_key.clone());
+            }
+            if !provider.api_keys.is_empty() {
+                b = b.with_provider_api_key_pool(
+                    id.clone(),
+                    crate::auth::ApiKeyPool::new(provider.api_keys.iter().cloned()),
+                );
+            }
+            if let Some(base_url) = &provider.base_url {
+                b = b.with_provider_base_url(id.clone(), base_url.clone());
+            }
+            if let Some(http) = &provider.http {
+                b = b.with_provider_http_config(id.clone(), http.to_http_config());
+            }
+            let mut overrides = ProviderBuildOverrides::new();
+            if let Some(enabled) = provider.reasoning {
+                overrides = overrides.with_reasoning(enabled);
+            }
+            if let Some(budget) = provider.reasoning_budget {
+                overrides = overrides.with_reasoning_budget(budget);
+            }
+            if provider.reasoning.is_some() || provider.reasoning_budget.is_some() {
+                b = b.merge_provider_build_overrides(id.clone(), overrides);
+            }
+        }
+
+        let separator = b.separator_char();
+        for (alias, target) in &self.aliases {
+            if let Some((provider, _)) = target.split_once(separator)
+                && !b.has_provider(provider)
+            {
+                errors.push(format!(
+                    "aliases.{alias}: provider `{provider}` is not registered"
+                ));
+                continue;
+            }
+            b = b.with_model_alias(alias.clone(), target.clone());
+        }
+
+        if errors.is_empty() {
+            Ok(b)
+        } else {
+            Err(invalid(errors))
+        }
+    }
+}
+
+fn invalid(errors: Vec<String>) -> LlmError {
+    LlmError::ConfigurationError(format!("Invalid registry config: {}", errors.join("; ")))
+}
+
+fn check_url(path: &str, url: &str, errors: &mut Vec<String>) {
+    if let Err(e) = reqwest::Url::parse(url) {
+        errors.push(format!("{path}: invalid URL `{url}`: {e}"));
+    }
+}
+
+fn parse_value(text: &str, format: ConfigFormat) -> Result<Value, LlmError> {
+    let parsed = match format {
+        ConfigFormat::Json => serde_json::from_str::<Value>(text).map_err(|e| e.to_string()),
+        #[cfg(feature = "config-toml")]
+        ConfigFormat::Toml => toml::from_str::<Value>(text).map_err(|e| e.to_string()),
+        #[cfg(feature = "config-yaml")]
+        ConfigFormat::Yaml => serde_yaml_ng::from_str::<Value>(text).map_err(|e| e.to_string()),
+    };
+    parsed.map_err(|e| {
+        LlmError::ConfigurationError(format!("Failed to parse registry config ({format:?}): {e}"))
+    })
+}
+
+fn interpolate_value(
+    value: &mut Value,
+    path: String,
+    lookup: &impl Fn(&str) -> Option<String>,
+    errors: &mut Vec<String>,
+) {
+    match value {
+        Value::String(s) => match interpolate_str(s, lookup) {
+            Ok(Some(expanded)) => *s = expanded,
+            Ok(None) => {}
+            Err(e) => errors.push(format!("{}: {e}", display_path(&path))),
+        },
+        Value::Array(items) => {
+            for (i, item) in items.iter_mut().enumerate() {
+                interpolate_value(item, format!("{path}[{i}]"), lookup, errors);
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                interpolate_value(item, child, lookup, errors);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() { "." } else { path }
+}
+
+/// Expand `${VAR}` / `${VAR:-default}` references; `$${` yields a literal `${`.
+///
+/// Returns `Ok(None)` when the string contains no references.
+fn interpolate_str(
+    input: &str,
+    lookup: &impl Fn(&str) -> Option<String>,
+) -> Result<Option<String>, String> {
+    if !input.contains("${") {
+        return Ok(None);
+    }
+
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+        if let Some(after) = tail.strip_prefix("$${") {
+            out.push_str("${");
+            rest = after;
+        } else if let Some(body) = tail.strip_prefix("${") {
+            let end = body
+                .find('}')
+                .ok_or_else(|| "unterminated `${` in value".to_string())?;
+            let expr = &body[..end];
+            let (name, default) = match expr.split_once(":-") {
+                Some((name, default)) => (name, Some(default)),
+                None => (expr, None),
+            };
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                return Err(format!("invalid environment variable name `{name}`"));
+            }
+            match lookup(name).or_else(|| default.map(str::to_string)) {
+                Some(v) => out.push_str(&v),
+                None => return Err(format!("environment variable `{name}` is not set")),
+            }
+            rest = &body[end + 1..];
+        } else {
+            out.push('$');
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+    Ok(Some(out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::ProviderFactory;
+    use crate::registry::entry::{TestProviderFactory, reg_test_guard};
+    use std::sync::Arc;
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "OPENAI_KEY" => Some("sk-openai".to_string()),
+            "GROQ_A" => Some("gsk-a".to_string()),
+            _ => None,
+        }
+    }
+
+    fn test_builder() -> RegistryBuilder {
+        let mut providers = HashMap::new();
+        for id in ["openai", "groq"] {
+            providers.insert(
+                id.to_string(),
+                Arc::new(TestProviderFactory::new(id)) as Arc<dyn ProviderFactory>,
+            );
+        }
+        RegistryBuilder::new(providers)
+    }
+
+    #[test]
+    fn parses_json_with_env_interpolation() {
+        let text = r#"{
+            "middlewares": ["clamp-top-p"],
+            "retry": { "max_attempts": 5, "retry_401": false },
+            "providers": {
+                "openai": { "api_key": "${OPENAI_KEY}", "http": { "timeout_secs": 30 } },
+                "groq": { "api_keys": ["${GROQ_A}", "${GROQ_B:-gsk-b}"] }
+            },
+            "aliases": { "fast": "groq:llama-3.1-8b-instant" }
+        }"#;
+        let config = RegistryConfig::parse_with_env(text, ConfigFormat::Json, env).unwrap();
+
+        assert_eq!(
+            config.providers["openai"].api_key.as_deref(),
+            Some("sk-openai")
+        );
+        assert_eq!(config.providers["groq"].api_keys, vec!["gsk-a", "gsk-b"]);
+        let http = config.providers["openai"].http.as_ref().unwrap();
+        assert_eq!(http.to_http_config().timeout, Some(Duration::from_secs(30)));
+        let retry = config.retry.as_ref().unwrap().to_retry_options();
+        assert_eq!(retry.policy.unwrap().max_attempts, 5);
+        assert!(!retry.retry_401);
+        assert_eq!(config.referenced_providers(), vec!["groq", "openai"]);
+    }
+
+    #[test]
+    fn interpolation_handles_escapes_and_missing_vars() {
+        assert_eq!(
+            interpolate_str("a-$${LIT}-${OPENAI_KEY}", &env).unwrap(),
+            Some("a-${LIT}-sk-openai".to_string())
+        );
+        assert_eq!(interpolate_str("plain $ text", &env).unwrap(), None);
+
+        let err = RegistryConfig::parse_with_env(
+            r#"{ "providers": { "openai": { "api_keys": ["${MISSING}"] } } }"#,
+            ConfigFormat::Json,
+            env,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "providers.openai.api_keys[0]: environment variable `MISSING` is not set"
+            ),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn errors_carry_value_paths() {
+        let err = RegistryConfig::parse_with_env(
+            r#"{ "providers": { "groq": { "http": { "timeout_secs": "soon" } } } }"#,
+            ConfigFormat::Json,
+            env,
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("providers.groq.http.timeout_secs:"),
+            "{err}"
+        );
+
+        let err = RegistryConfig::parse_with_env(
+            r#"{ "providers": { "groq": { "api_kye": "x" } } }"#,
+            ConfigFormat::Json,
+            env,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("providers.groq"), "{err}");
+
+        let err = RegistryConfig::parse_with_env(
+            r#"{
+                "providers": { "groq": { "api_key": "a", "api_keys": ["b"] } },
+                "aliases": { "fast": "no-separator" }
+            }"#,
+            ConfigFormat::Json,
+            env,
+        )
+        .unwrap_err();
+        let msg = err.to_string();
+        assert!(
+            msg.contains("providers.groq: `api_key` and `api_keys`"),
+            "{msg}"
+        );
+        assert!(msg.contains("aliases.fast: target `no-separator`"), "{msg}");
+    }
+
+    #[test]
+    fn apply_rejects_unknown_presets_and_providers() {
+        let config = RegistryConfig::parse_with_env(
+            r#"{
+                "middlewares": ["default-params", "nope"],
+                "aliases": { "local": "ollama:llama3" }
+            }"#,
+            ConfigFormat::Json,
+            env,
+        )
+        .unwrap();
+        let err = match test_builder().with_config(&config) {
+            Ok(_) => panic!("expected config errors"),
+            Err(e) => e.to_string(),
+        };
+        assert!(
+            err.contains("middlewares[1]: unknown middleware preset `nope`"),
+            "{err}"
+        );
+        assert!(
+            err.contains("aliases.local: provider `ollama` is not registered"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn aliases_resolve_through_registry() {
+        let _g = reg_test_guard();
+        let config = RegistryConfig::parse_with_env(
+            r#"{ "aliases": { "fast": "groq:llama-3.1-8b-instant" } }"#,
+            ConfigFormat::Json,
+            env,
+        )
+        .unwrap();
+        let reg = test_builder()
+            .with_config(&config)
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let handle = reg.language_model("fast").unwrap();
+        assert_eq!(handle.provider_id, "groq");
+        assert_eq!(handle.model_id, "llama-3.1-8b-instant");
+        assert!(reg.language_model("openai:gpt-4o").is_ok());
+    }
+
+    #[cfg(feature = "config-toml")]
+    #[test]
+    fn parses_toml() {
+        let text = r#"
+            separator = "/"
+
+            [providers.openai]
+            api_key = "${OPENAI_KEY}"
+            base_url = "https://proxy.example.com/v1"
+
+            [aliases]
+            smart = "openai/gpt-4o"
+        "#;
+        let config = RegistryConfig::parse_with_env(text, ConfigFormat::Toml, env).unwrap();
+        assert_eq!(config.separator, Some('/'));
+        assert_eq!(config.aliases["smart"], "openai/gpt-4o");
+        assert_eq!(config.referenced_providers(), vec!["openai"]);
+    }
+
+    #[cfg(feature = "config-yaml")]
+    #[test]
+    fn parses_yaml() {
+        let text = "providers:\n  groq:\n    api_keys:\n      - ${GROQ_A}\naliases:\n  fast: groq:llama3\n";
+        let config = RegistryConfig::parse_with_env(text, ConfigFormat::Yaml, env).unwrap();
+        assert_eq!(config.providers["groq"].api_keys, vec!["gsk-a"]);
+    }
+}
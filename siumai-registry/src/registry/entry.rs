@@ -179,11 +179,39 @@ pub struct ProviderRegistryHandle {
     auto_middleware: bool,
     /// Registry-level retry options applied during client build (optional)
     retry_options: Option<RetryOptions>,
+    /// Model aliases resolved before parsing "provider:model" ids.
+    model_aliases: HashMap<String, String>,
 }
 
 impl ProviderRegistryHandle {
+    /// Resolve a model alias to its "provider:model" target (ids without an alias pass through).
+    pub fn resolve_model_alias<'a>(&'a self, id: &'a str) -> &'a str {
+        self.model_aliases.get(id).map(String::as_str).unwrap_or(id)
+    }
+
+    /// Replace the model aliases resolved before parsing "provider:model" ids.
+    pub(crate) fn with_model_aliases(mut self, aliases: HashMap<String, String>) -> Self {
+        self.model_aliases = aliases;
+        self
+    }
+
+    /// Ids of the registered providers, sorted.
+    pub fn provider_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.providers.keys().map(String::as_str).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Separator between provider and model in registry ids.
+    pub fn separator(&self) -> char {
+        self.separator
+    }
+
     /// Split a registry model id like "provider:model" into (provider, model).
+    ///
+    /// Model aliases are resolved first.
     fn split_id(&self, id: &str) -> Result<(String, String), LlmError> {
+        let id = self.resolve_model_alias(id);
         if let Some((p, m)) = id.split_once(self.separator) {
             if p.is_empty() || m.is_empty() {
                 return Err(LlmError::InvalidParameter(format!(
@@ -220,10 +248,23 @@ impl ProviderRegistryHandle {
             base_url: self.base_url.clone(),
             reasoning_enabled: self.reasoning_enabled,
             reasoning_budget: self.reasoning_budget,
+            api_key_pool: None,
         }
         .merged_with(self.provider_build_overrides.get(provider_id))
     }
 
+    /// Registry-wide interceptors plus the provider's API key pool, if one is configured.
+    fn provider_http_interceptors(
+        &self,
+        build_overrides: &ProviderBuildOverrides,
+    ) -> Vec<Arc<dyn HttpInterceptor>> {
+        let mut interceptors = self.http_interceptors.clone();
+        if let Some(pool) = &build_overrides.api_key_pool {
+            interceptors.push(Arc::new(pool.clone()));
+        }
+        interceptors
+    }
+
     /// Resolve language model - returns a handle that delegates to the factory
     ///
     /// Uses LRU cache with optional TTL to avoid rebuilding clients repeatedly.
@@ -286,7 +327,7 @@ impl ProviderRegistryHandle {
             middlewares,
             cache: self.language_model_cache.clone(),
             client_ttl: self.client_ttl,
-            http_interceptors: self.http_interceptors.clone(),
+            http_interceptors: self.provider_http_interceptors(&build_overrides),
             http_client: build_overrides.http_client,
             http_transport: build_overrides.http_transport,
             http_config: build_overrides.http_config,
@@ -340,7 +381,7 @@ impl ProviderRegistryHandle {
             provider_id,
             model_id,
             middlewares,
-            http_interceptors: self.http_interceptors.clone(),
+            http_interceptors: self.provider_http_interceptors(&build_overrides),
             http_client: build_overrides.http_client,
             http_transport: build_overrides.http_transport,
             http_config: build_overrides.http_config,
@@ -372,7 +413,7 @@ impl ProviderRegistryHandle {
             factory: factory.clone(),
             provider_id,
             model_id,
-            http_interceptors: self.http_interceptors.clone(),
+            http_interceptors: self.provider_http_interceptors(&build_overrides),
             http_client: build_overrides.http_client,
             http_transport: build_overrides.http_transport,
             http_config: build_overrides.http_config,
@@ -405,7 +446,7 @@ impl ProviderRegistryHandle {
             factory: factory.clone(),
             provider_id,
             model_id,
-            http_interceptors: self.http_interceptors.clone(),
+            http_interceptors: self.provider_http_interceptors(&build_overrides),
             http_client: build_overrides.http_client,
             http_transport: build_overrides.http_transport,
             http_config: build_overrides.http_config,
@@ -432,7 +473,7 @@ impl ProviderRegistryHandle {
             factory: factory.clone(),
             provider_id,
             model_id,
-            http_interceptors: self.http_interceptors.clone(),
+            http_interceptors: self.provider_http_interceptors(&build_overrides),
             http_client: build_overrides.http_client,
             http_transport: build_overrides.http_transport,
             http_config: build_overrides.http_config,
@@ -461,7 +502,7 @@ impl ProviderRegistryHandle {
             factory: factory.clone(),
             provider_id,
             model_id,
-            http_interceptors: self.http_interceptors.clone(),
+            http_interceptors: self.provider_http_interceptors(&build_overrides),
             http_client: build_overrides.http_client,
             http_transport: build_overrides.http_transport,
             http_config: build_overrides.http_config,
@@ -490,7 +531,7 @@ impl ProviderRegistryHandle {
             factory: factory.clone(),
             provider_id,
             model_id,
-            http_interceptors: self.http_interceptors.clone(),
+            http_interceptors: self.provider_http_interceptors(&build_overrides),
             http_client: build_overrides.http_client,
             http_transport: build_overrides.http_transport,
             http_config: build_overrides.http_config,
@@ -519,7 +560,7 @@ impl ProviderRegistryHandle {
             factory: factory.clone(),
             provider_id,
             model_id,
-            http_interceptors: self.http_interceptors.clone(),
+            http_interceptors: self.provider_http_interceptors(&build_overrides),
             http_client: build_overrides.http_client,
             http_transport: build_overrides.http_transport,
             http_config: build_overrides.http_config,
@@ -620,6 +661,7 @@ pub fn create_provider_registry(
         reasoning_budget,
         provider_build_overrides,
         retry_options,
+        model_aliases: HashMap::new(),
     }
 }
 
@@ -22,6 +22,8 @@ pub struct ProviderBuildOverrides {
     pub reasoning_enabled: Option<bool>,
     /// Optional unified reasoning budget for registry-built language models.
     pub reasoning_budget: Option<i32>,
+    /// Optional API key pool; installed as an interceptor on this provider's handles only.
+    pub api_key_pool: Option<crate::auth::ApiKeyPool>,
 }
 
 impl ProviderBuildOverrides {
@@ -118,6 +120,11 @@ impl ProviderBuildOverrides {
         self
     }
 
+    pub fn with_api_key_pool(mut self, pool: crate::auth::ApiKeyPool) -> Self {
+        self.api_key_pool = Some(pool);
+        self
+    }
+
     pub(crate) fn merged_with(&self, provider_override: Option<&ProviderBuildOverrides>) -> Self {
         if let Some(provider_override) = provider_override {
             Self {
@@ -145,6 +152,10 @@ impl ProviderBuildOverrides {
                     .reasoning_enabled
                     .or(self.reasoning_enabled),
                 reasoning_budget: provider_override.reasoning_budget.or(self.reasoning_budget),
+                api_key_pool: provider_override
+                    .api_key_pool
+                    .clone()
+                    .or_else(|| self.api_key_pool.clone()),
             }
         } else {
             self.clone()
@@ -303,3 +303,55 @@ async fn registry_builder_merges_provider_specific_shortcuts() {
         }
     );
 }
+
+#[tokio::test]
+async fn registry_builder_installs_provider_api_key_pool() {
+    let seen = Arc::new(Mutex::new(None));
+    let mut providers = HashMap::new();
+    providers.insert(
+        "testprov_pool".to_string(),
+        Arc::new(ContextCapturingFactory {
+            id: "testprov_pool",
+            seen: seen.clone(),
+        }) as Arc<dyn ProviderFactory>,
+    );
+    providers.insert(
+        "testprov_other".to_string(),
+        Arc::new(ContextCapturingFactory {
+            id: "testprov_other",
+            seen: Arc::new(Mutex::new(None)),
+        }) as Arc<dyn ProviderFactory>,
+    );
+
+    let pool = crate::auth::ApiKeyPool::new(["pool-key-a", "pool-key-b"]);
+    let reg = crate::registry::builder::RegistryBuilder::new(providers)
+        .with_provider_api_key_pool("testprov_pool", pool.clone())
+        .auto_middleware(false)
+        .build()
+        .expect("build registry");
+
+    let handle = reg
+        .language_model("testprov_pool:model")
+        .expect("build language handle");
+    assert_eq!(handle.http_interceptors.len(), 1);
+    let other = reg
+        .language_model("testprov_other:model")
+        .expect("build other language handle");
+    assert!(other.http_interceptors.is_empty());
+    let _ = handle.chat(vec![]).await.expect("chat response");
+
+    let observed = seen
+        .lock()
+        .unwrap()
+        .clone()
+        .expect("captured provider build context");
+    assert_eq!(observed.api_key.as_deref(), Some("pool-key-a"));
+    assert_eq!(pool.len(), 2);
+    assert_eq!(
+        reg.provider_build_overrides["testprov_pool"]
+            .api_key_pool
+            .as_ref()
+            .and_then(|pool| pool.provider_id()),
+        Some("testprov_pool")
+    );
+}
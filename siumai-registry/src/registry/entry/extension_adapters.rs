@@ -4,9 +4,9 @@ use crate::client::LlmClient;
 use crate::error::LlmError;
 use crate::traits::{FileManagementCapability, MusicGenerationCapability, SkillsCapability};
 use crate::types::{
-    FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
-    MusicGenerationRequest, MusicGenerationResponse, MusicStyle, SkillUploadRequest,
-    SkillUploadResult,
+    FileContentStream, FileDeleteResponse, FileListQuery, FileListResponse, FileObject,
+    FileStreamUploadRequest, FileUploadRequest, MusicGenerationRequest, MusicGenerationResponse,
+    MusicStyle, SkillUploadRequest, SkillUploadResult,
 };
 
 fn unsupported(provider_id: &str, extension: &str) -> LlmError {
@@ -56,6 +56,20 @@ impl FileManagementCapability for ClientBackedFileManagementCapability {
     async fn get_file_content(&self, file_id: String) -> Result<Vec<u8>, LlmError> {
         self.capability()?.get_file_content(file_id).await
     }
+
+    async fn upload_file_stream(
+        &self,
+        request: FileStreamUploadRequest,
+    ) -> Result<FileObject, LlmError> {
+        self.capability()?.upload_file_stream(request).await
+    }
+
+    async fn get_file_content_stream(
+        &self,
+        file_id: String,
+    ) -> Result<FileContentStream, LlmError> {
+        self.capability()?.get_file_content_stream(file_id).await
+    }
 }
 
 pub(in crate::registry::entry) struct ClientBackedSkillsCapability {
@@ -16,10 +16,10 @@ use crate::traits::{
     SkillsCapability, VideoGenerationCapability,
 };
 use crate::types::{
-    ChatMessage, ChatRequest, ChatResponse, FileDeleteResponse, FileListQuery, FileListResponse,
-    FileObject, FileUploadRequest, MusicGenerationRequest, MusicGenerationResponse,
-    SkillUploadRequest, SkillUploadResult, Tool, VideoGenerationRequest, VideoGenerationResponse,
-    VideoTaskStatusResponse,
+    ChatMessage, ChatRequest, ChatResponse, FileContentStream, FileDeleteResponse, FileListQuery,
+    FileListResponse, FileObject, FileStreamUploadRequest, FileUploadRequest,
+    MusicGenerationRequest, MusicGenerationResponse, SkillUploadRequest, SkillUploadResult, Tool,
+    VideoGenerationRequest, VideoGenerationResponse, VideoTaskStatusResponse,
 };
 use siumai_core::video::VideoModel as FamilyVideoModel;
 
@@ -539,6 +539,26 @@ impl FileManagementCapability for LanguageModelHandle {
             .await?;
         files.get_file_content(file_id).await
     }
+
+    async fn upload_file_stream(
+        &self,
+        request: FileStreamUploadRequest,
+    ) -> Result<FileObject, LlmError> {
+        let files = self
+            .build_file_management_capability(&self.model_id)
+            .await?;
+        files.upload_file_stream(request).await
+    }
+
+    async fn get_file_content_stream(
+        &self,
+        file_id: String,
+    ) -> Result<FileContentStream, LlmError> {
+        let files = self
+            .build_file_management_capability(&self.model_id)
+            .await?;
+        files.get_file_content_stream(file_id).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -16,6 +16,7 @@ impl crate::traits::TranscriptionCapability for BridgeTranscriptionClient {
             language: Some("en".to_string()),
             confidence: Some(0.99),
             words: None,
+            segments: None,
             duration: None,
             metadata: HashMap::new(),
             request: None,
@@ -162,6 +163,7 @@ async fn provider_factory_native_transcription_family_path_works() {
                 language: Some("zh".to_string()),
                 confidence: Some(0.9),
                 words: None,
+                segments: None,
                 duration: None,
                 metadata: HashMap::new(),
                 request: None,
@@ -255,6 +257,7 @@ async fn transcription_model_handle_uses_native_family_path_when_available() {
                 language: Some("ja".to_string()),
                 confidence: Some(0.88),
                 words: None,
+                segments: None,
                 duration: None,
                 metadata: HashMap::new(),
                 request: None,
@@ -346,6 +349,7 @@ async fn transcription_model_handle_reuses_cached_family_model() {
                 language: Some("en".to_string()),
                 confidence: Some(1.0),
                 words: None,
+                segments: None,
                 duration: None,
                 metadata: HashMap::new(),
                 request: None,
@@ -6,6 +6,7 @@
 
 use super::*;
 use crate::core::{ProviderContext, ProviderSpec};
+use crate::error::normalize_provider_failure;
 use crate::execution::executors::common::{
     HttpBody, execute_json_request, execute_multipart_request,
 };
@@ -176,10 +177,14 @@ impl ProviderSpec for DeepInfraImageSpec {
                 .and_then(|detail| detail.get("error"))
                 .and_then(Value::as_str)
         {
+            if let Some(normalized) = normalize_provider_failure(status, &[], message) {
+                return Some(normalized);
+            }
             return Some(LlmError::ApiError {
                 code: status,
                 message: message.to_string(),
                 details: Some(json),
+                http_details: None,
             });
         }
 
@@ -351,6 +356,7 @@ async fn load_image_part_bytes(
                     code: status.as_u16(),
                     message: format!("Failed to download DeepInfra image input from {url}"),
                     details: Some(serde_json::json!({ "url": url, "body": body })),
+                    http_details: None,
                 });
             }
 
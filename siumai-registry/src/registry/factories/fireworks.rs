@@ -6,6 +6,7 @@
 
 use super::*;
 use crate::core::{ProviderContext, ProviderSpec};
+use crate::error::normalize_provider_failure;
 use crate::execution::executors::common::{
     HttpBody, execute_bytes_request, execute_get_binary, execute_json_request,
 };
@@ -244,10 +245,14 @@ impl ProviderSpec for FireworksImageSpec {
         if let Ok(json) = serde_json::from_str::<Value>(body_text)
             && let Some(message) = json.get("error").and_then(Value::as_str)
         {
+            if let Some(normalized) = normalize_provider_failure(status, &[], message) {
+                return Some(normalized);
+            }
             return Some(LlmError::ApiError {
                 code: status,
                 message: message.to_string(),
                 details: Some(json),
+                http_details: None,
             });
         }
 
@@ -641,6 +646,7 @@ impl FireworksImageClient {
                             "Failed to download Fireworks polled image result from {url}"
                         ),
                         details: Some(serde_json::json!({ "url": url, "body": body })),
+                        http_details: None,
                     });
                 }
 
@@ -785,6 +791,7 @@ impl FireworksImageClient {
                             poll.status
                         ),
                         details: None,
+                        http_details: None,
                     });
                 }
                 _ => sleep(self.poll_interval()).await,
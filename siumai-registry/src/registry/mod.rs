@@ -12,6 +12,7 @@
 //! provider crates without pulling in built-in provider implementations by default.
 
 pub mod builder;
+pub mod config;
 pub mod entry;
 
 // Built-in provider factory implementations (feature-gated; depend on provider crates).
@@ -21,6 +22,7 @@ pub mod factories;
 pub mod factory;
 
 pub mod helpers;
+pub mod routing;
 
 // -----------------------------------------------------------------------------
 // Built-in provider catalog (optional)
@@ -683,6 +685,7 @@ pub use entry::{
     VideoModelHandle, create_provider_registry,
 };
 
+pub use config::{ConfigFormat, RegistryConfig};
 pub use helpers::{create_bare_registry, create_empty_registry};
 
 #[cfg(feature = "builtins")]
@@ -0,0 +1,148 @@
+//! Hedged requests.
+
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use futures::stream::FuturesUnordered;
+use futures::{FutureExt, StreamExt};
+
+use crate::error::{LlmError, LlmErrorExt};
+use crate::streaming::ChatStream;
+use crate::traits::ChatCapability;
+use crate::types::{ChatMessage, ChatRequest, ChatResponse, Tool};
+
+use super::{OpenedStream, RouteCandidate, RoutingDecision};
+
+/// Sends a request to a primary model and hedges with alternates when it is slow to respond.
+///
+/// Attempt `n + 1` starts when no attempt has produced output for `hedge_after`, or immediately
+/// when every running attempt has failed with a retryable error; any other error is returned
+/// immediately and cancels the remaining attempts. For streams, "output" is the first content event
+/// (text, reasoning or tool input); for non-streaming calls it is the complete response.
+#[derive(Debug, Clone)]
+pub struct HedgedLanguageModel {
+    candidates: Vec<RouteCandidate>,
+    hedge_after: Duration,
+}
+
+impl HedgedLanguageModel {
+    pub fn new(primary: RouteCandidate, hedge_after: Duration) -> Self {
+        Self {
+            candidates: vec![primary],
+            hedge_after,
+        }
+    }
+
+    /// Add an alternate; alternates are tried in registration order.
+    pub fn with_alternate(mut self, alternate: RouteCandidate) -> Self {
+        self.candidates.push(alternate);
+        self
+    }
+
+    pub fn hedge_after(&self) -> Duration {
+        self.hedge_after
+    }
+
+    pub fn candidates(&self) -> &[RouteCandidate] {
+        &self.candidates
+    }
+
+    /// Run attempts until one succeeds; returns the winner index, its value and the attempt count.
+    async fn race<T: Send + 'static>(
+        &self,
+        launch: impl Fn(&RouteCandidate) -> BoxFuture<'static, Result<T, LlmError>>,
+    ) -> Result<(usize, T, usize), LlmError> {
+        let mut running = FuturesUnordered::new();
+        let start = |idx: usize| launch(&self.candidates[idx]).map(move |r| (idx, r));
+        running.push(start(0));
+        let mut launched = 1;
+        // The hedge deadline restarts only when an attempt launches, not when one fails.
+        let hedge = tokio::time::sleep(self.hedge_after);
+        tokio::pin!(hedge);
+
+        loop {
+            tokio::select! {
+                Some((idx, result)) = running.next() => match result {
+                    Ok(value) => return Ok((idx, value, launched)),
+                    Err(e) if e.is_retryable() => {
+                        tracing::debug!(
+                            candidate = %self.candidates[idx].id,
+                            error = %e,
+                            "hedged attempt failed"
+                        );
+                        if running.is_empty() {
+                            if launched == self.candidates.len() {
+                                return Err(e);
+                            }
+                            running.push(start(launched));
+                            launched += 1;
+                            hedge.as_mut().reset(tokio::time::Instant::now() + self.hedge_after);
+                        }
+                    }
+                    Err(e) => return Err(e),
+                },
+                _ = &mut hedge, if launched < self.candidates.len() => {
+                    running.push(start(launched));
+                    launched += 1;
+                    hedge.as_mut().reset(tokio::time::Instant::now() + self.hedge_after);
+                }
+            }
+        }
+    }
+
+    fn decision(&self, winner: usize, attempts: usize) -> RoutingDecision {
+        RoutingDecision {
+            strategy: "hedge".to_string(),
+            candidate: self.candidates[winner].id.clone(),
+            attempts,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatCapability for HedgedLanguageModel {
+    async fn chat_with_tools(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatResponse, LlmError> {
+        let mut request = ChatRequest::new(messages);
+        if let Some(tools) = tools {
+            request = request.with_tools(tools);
+        }
+        self.chat_request(request).await
+    }
+
+    async fn chat_stream(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatStream, LlmError> {
+        let mut request = ChatRequest::new(messages).with_streaming(true);
+        if let Some(tools) = tools {
+            request = request.with_tools(tools);
+        }
+        self.chat_stream_request(request).await
+    }
+
+    async fn chat_request(&self, request: ChatRequest) -> Result<ChatResponse, LlmError> {
+        let (winner, mut response, attempts) = self
+            .race(|candidate| {
+                let model = candidate.model.clone();
+                let request = request.clone();
+                async move { model.chat_request(request).await }.boxed()
+            })
+            .await?;
+        self.decision(winner, attempts).record(&mut response);
+        Ok(response)
+    }
+
+    async fn chat_stream_request(&self, request: ChatRequest) -> Result<ChatStream, LlmError> {
+        let (winner, opened, attempts) = self
+            .race(|candidate| OpenedStream::open(candidate.model.clone(), request.clone()).boxed())
+            .await?;
+        Ok(self
+            .decision(winner, attempts)
+            .annotate(opened.into_stream()))
+    }
+}
@@ -0,0 +1,308 @@
+//! Latency-aware routing.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+
+use crate::error::{LlmError, LlmErrorExt};
+use crate::streaming::ChatStream;
+use crate::traits::ChatCapability;
+use crate::types::{ChatMessage, ChatRequest, ChatResponse, ChatStreamEvent, Tool};
+
+use super::{OpenedStream, RouteCandidate, RoutingDecision};
+
+/// Which TTFT percentile the router minimizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LatencyObjective {
+    P50,
+    #[default]
+    P95,
+}
+
+/// [`LatencyRouter`] tuning.
+#[derive(Debug, Clone)]
+pub struct LatencyRouterConfig {
+    /// Outcomes kept per candidate.
+    pub window: usize,
+    /// Candidates with fewer samples are tried first so every candidate gets measured.
+    pub min_samples: usize,
+    pub objective: LatencyObjective,
+    /// Score multiplier per unit of error rate: `latency * (1 + weight * error_rate)`.
+    pub error_rate_weight: f64,
+    /// Try the next-best candidate when the chosen one fails with a retryable error before
+    /// producing output. Other errors (bad requests, auth) are returned immediately and do not
+    /// count against the candidate.
+    pub failover: bool,
+}
+
+impl Default for LatencyRouterConfig {
+    fn default() -> Self {
+        Self {
+            window: 100,
+            min_samples: 3,
+            objective: LatencyObjective::P95,
+            error_rate_weight: 4.0,
+            failover: true,
+        }
+    }
+}
+
+impl LatencyRouterConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_window(mut self, window: usize) -> Self {
+        self.window = window.max(1);
+        self
+    }
+
+    pub fn with_min_samples(mut self, min_samples: usize) -> Self {
+        self.min_samples = min_samples;
+        self
+    }
+
+    pub fn with_objective(mut self, objective: LatencyObjective) -> Self {
+        self.objective = objective;
+        self
+    }
+
+    pub fn with_error_rate_weight(mut self, weight: f64) -> Self {
+        self.error_rate_weight = weight;
+        self
+    }
+
+    pub fn with_failover(mut self, failover: bool) -> Self {
+        self.failover = failover;
+        self
+    }
+}
+
+/// Rolling statistics for one candidate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateStats {
+    pub id: String,
+    /// Outcomes in the window (successes and errors).
+    pub samples: usize,
+    pub p50_ttft: Option<Duration>,
+    pub p95_ttft: Option<Duration>,
+    pub error_rate: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Outcome {
+    Ok(Duration),
+    Err,
+}
+
+fn percentile(sorted: &[Duration], q: f64) -> Option<Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((q * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len());
+    Some(sorted[rank - 1])
+}
+
+/// Picks the candidate with the best recent TTFT, penalized by its error rate.
+///
+/// Non-streaming calls use total latency as TTFT. Streaming calls are measured up to the first
+/// output event; a stream that fails after that is recorded as an additional error sample.
+#[derive(Debug, Clone)]
+pub struct LatencyRouter {
+    candidates: Vec<RouteCandidate>,
+    config: LatencyRouterConfig,
+    windows: Arc<Mutex<HashMap<String, VecDeque<Outcome>>>>,
+}
+
+impl LatencyRouter {
+    pub fn new(
+        candidates: Vec<RouteCandidate>,
+        config: LatencyRouterConfig,
+    ) -> Result<Self, LlmError> {
+        if candidates.is_empty() {
+            return Err(LlmError::ConfigurationError(
+                "latency router needs at least one candidate".to_string(),
+            ));
+        }
+        Ok(Self {
+            candidates,
+            config,
+            windows: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    pub fn config(&self) -> &LatencyRouterConfig {
+        &self.config
+    }
+
+    pub fn candidates(&self) -> &[RouteCandidate] {
+        &self.candidates
+    }
+
+    /// Current statistics, in candidate order.
+    pub fn stats(&self) -> Vec<CandidateStats> {
+        let windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        self.candidates
+            .iter()
+            .map(|c| Self::summarize(&c.id, windows.get(&c.id)))
+            .collect()
+    }
+
+    fn summarize(id: &str, window: Option<&VecDeque<Outcome>>) -> CandidateStats {
+        let outcomes: Vec<Outcome> = window
+            .map(|w| w.iter().copied().collect())
+            .unwrap_or_default();
+        let mut ttfts: Vec<Duration> = outcomes
+            .iter()
+            .filter_map(|o| match o {
+                Outcome::Ok(d) => Some(*d),
+                Outcome::Err => None,
+            })
+            .collect();
+        ttfts.sort();
+        let errors = outcomes.len() - ttfts.len();
+        CandidateStats {
+            id: id.to_string(),
+            samples: outcomes.len(),
+            p50_ttft: percentile(&ttfts, 0.5),
+            p95_ttft: percentile(&ttfts, 0.95),
+            error_rate: if outcomes.is_empty() {
+                0.0
+            } else {
+                errors as f64 / outcomes.len() as f64
+            },
+        }
+    }
+
+    fn record(&self, id: &str, outcome: Outcome) {
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        let window = windows.entry(id.to_string()).or_default();
+        window.push_back(outcome);
+        while window.len() > self.config.window {
+            window.pop_front();
+        }
+    }
+
+    /// Candidate indices, best first.
+    fn ranked(&self) -> Vec<usize> {
+        let stats = self.stats();
+        let score = |s: &CandidateStats| {
+            let latency = match self.config.objective {
+                LatencyObjective::P50 => s.p50_ttft,
+                LatencyObjective::P95 => s.p95_ttft,
+            };
+            latency.map_or(f64::INFINITY, |d| d.as_secs_f64())
+                * (1.0 + self.config.error_rate_weight * s.error_rate)
+        };
+        let mut order: Vec<usize> = (0..self.candidates.len()).collect();
+        // Under-sampled candidates first (fewest samples leading), then by score.
+        order.sort_by(|&a, &b| {
+            let (sa, sb) = (&stats[a], &stats[b]);
+            let explore_a = sa.samples < self.config.min_samples;
+            let explore_b = sb.samples < self.config.min_samples;
+            explore_b
+                .cmp(&explore_a)
+                .then_with(|| {
+                    if explore_a && explore_b {
+                        sa.samples.cmp(&sb.samples)
+                    } else {
+                        score(sa).total_cmp(&score(sb))
+                    }
+                })
+                .then_with(|| a.cmp(&b))
+        });
+        if !self.config.failover {
+            order.truncate(1);
+        }
+        order
+    }
+
+    fn decision(&self, idx: usize, attempts: usize) -> RoutingDecision {
+        RoutingDecision {
+            strategy: "latency".to_string(),
+            candidate: self.candidates[idx].id.clone(),
+            attempts,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatCapability for LatencyRouter {
+    async fn chat_with_tools(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatResponse, LlmError> {
+        let mut request = ChatRequest::new(messages);
+        if let Some(tools) = tools {
+            request = request.with_tools(tools);
+        }
+        self.chat_request(request).await
+    }
+
+    async fn chat_stream(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatStream, LlmError> {
+        let mut request = ChatRequest::new(messages).with_streaming(true);
+        if let Some(tools) = tools {
+            request = request.with_tools(tools);
+        }
+        self.chat_stream_request(request).await
+    }
+
+    async fn chat_request(&self, request: ChatRequest) -> Result<ChatResponse, LlmError> {
+        let mut last_error = None;
+        for (attempt, idx) in self.ranked().into_iter().enumerate() {
+            let candidate = &self.candidates[idx];
+            let started = Instant::now();
+            match candidate.model.chat_request(request.clone()).await {
+                Ok(mut response) => {
+                    self.record(&candidate.id, Outcome::Ok(started.elapsed()));
+                    self.decision(idx, attempt + 1).record(&mut response);
+                    return Ok(response);
+                }
+                Err(e) if e.is_retryable() => {
+                    self.record(&candidate.id, Outcome::Err);
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.expect("router has at least one candidate"))
+    }
+
+    async fn chat_stream_request(&self, request: ChatRequest) -> Result<ChatStream, LlmError> {
+        let mut last_error = None;
+        for (attempt, idx) in self.ranked().into_iter().enumerate() {
+            let candidate = &self.candidates[idx];
+            let started = Instant::now();
+            match OpenedStream::open(candidate.model.clone(), request.clone()).await {
+                Ok(opened) => {
+                    self.record(&candidate.id, Outcome::Ok(started.elapsed()));
+                    let router = self.clone();
+                    let id = candidate.id.clone();
+                    let stream: ChatStream = Box::pin(opened.into_stream().inspect(move |item| {
+                        let failed = match item {
+                            Err(e) => e.is_retryable(),
+                            Ok(event) => matches!(event, ChatStreamEvent::Error { .. }),
+                        };
+                        if failed {
+                            router.record(&id, Outcome::Err);
+                        }
+                    }));
+                    return Ok(self.decision(idx, attempt + 1).annotate(stream));
+                }
+                Err(e) if e.is_retryable() => {
+                    self.record(&candidate.id, Outcome::Err);
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Err(last_error.expect("router has at least one candidate"))
+    }
+}
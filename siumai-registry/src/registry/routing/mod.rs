@@ -0,0 +1,234 @@
+//! Routing across registry language models.
+//!
+//! - [`HedgedLanguageModel`] sends the request to a primary model and, if no output token has
+//!   arrived within a deadline, fires the same request at the next alternate. The first attempt
+//!   to produce output wins; the others are dropped, which cancels their HTTP requests.
+//! - [`LatencyRouter`] keeps rolling time-to-first-token (TTFT) and error-rate statistics per
+//!   registry model id and sends each call to the best-scoring candidate.
+//! - [`TrafficSplit`] assigns calls to weighted arms, sticky per user or session, for A/B tests
+//!   and gradual model migrations.
+//! - [`ShadowLanguageModel`] serves calls from a primary model and mirrors them to a candidate
+//!   in the background, handing both responses to a [`ShadowSink`].
+//!
+//! All of them implement [`ChatCapability`] and record the chosen candidate as a [`RoutingDecision`]
+//! under the `"siumai"` key of the response's `provider_metadata` (on `StreamEnd` for streams).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::error::LlmError;
+use crate::streaming::ChatStream;
+use crate::traits::ChatCapability;
+use crate::types::{ChatResponse, ChatStreamEvent, ChatStreamPart};
+
+use super::entry::ProviderRegistryHandle;
+
+mod hedge;
+mod latency;
+mod shadow;
+mod split;
+
+pub use hedge::HedgedLanguageModel;
+pub use latency::{CandidateStats, LatencyObjective, LatencyRouter, LatencyRouterConfig};
+pub use shadow::{
+    InMemoryShadowSink, ShadowLanguageModel, ShadowOutcome, ShadowRecord, ShadowSink,
+};
+pub use split::{StickyKey, TrafficSplit};
+
+/// Provider metadata namespace used for routing decisions.
+const METADATA_NAMESPACE: &str = "siumai";
+
+/// A routable model: the registry id it was resolved from and its chat capability.
+#[derive(Clone)]
+pub struct RouteCandidate {
+    id: String,
+    model: Arc<dyn ChatCapability>,
+}
+
+impl std::fmt::Debug for RouteCandidate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RouteCandidate")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RouteCandidate {
+    pub fn new(id: impl Into<String>, model: Arc<dyn ChatCapability>) -> Self {
+        Self {
+            id: id.into(),
+            model,
+        }
+    }
+
+    /// Registry model id (e.g. `"openai:gpt-4o-mini"` or an alias).
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+}
+
+/// Which candidate served a routed call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoutingDecision {
+    /// `"hedge"`, `"latency"`, `"split"` or `"shadow"`.
+    pub strategy: String,
+    /// Registry id of the candidate whose output was returned.
+    pub candidate: String,
+    /// Number of candidates that were called.
+    pub attempts: usize,
+}
+
+impl RoutingDecision {
+    /// Read the decision recorded on a routed response.
+    pub fn from_response(response: &ChatResponse) -> Option<Self> {
+        let routing = response
+            .provider_metadata
+            .as_ref()?
+            .get(METADATA_NAMESPACE)?
+            .get("routing")?;
+        serde_json::from_value(routing.clone()).ok()
+    }
+
+    fn record(&self, response: &mut ChatResponse) {
+        let Ok(value) = serde_json::to_value(self) else {
+            return;
+        };
+        let entry = response
+            .provider_metadata
+            .get_or_insert_with(Default::default)
+            .entry(METADATA_NAMESPACE.to_string())
+            .or_insert_with(|| serde_json::json!({}));
+        if let Some(map) = entry.as_object_mut() {
+            map.insert("routing".to_string(), value);
+        }
+    }
+
+    /// Attach the decision to the `StreamEnd` response of `stream`.
+    fn annotate(self, stream: ChatStream) -> ChatStream {
+        Box::pin(stream.map(move |item| match item {
+            Ok(ChatStreamEvent::StreamEnd { mut response }) => {
+                self.record(&mut response);
+                Ok(ChatStreamEvent::StreamEnd { response })
+            }
+            other => other,
+        }))
+    }
+}
+
+/// Whether `event` carries model output (as opposed to stream bookkeeping).
+fn is_first_output(event: &ChatStreamEvent) -> bool {
+    match event {
+        ChatStreamEvent::StreamEnd { .. } => true,
+        ChatStreamEvent::Part { part } | ChatStreamEvent::PartWithReplay { part, .. } => matches!(
+            part,
+            ChatStreamPart::TextDelta { .. }
+                | ChatStreamPart::ReasoningDelta { .. }
+                | ChatStreamPart::ToolInputStart { .. }
+                | ChatStreamPart::ToolInputDelta { .. }
+                | ChatStreamPart::ToolCall(_)
+                | ChatStreamPart::File(_)
+        ),
+        _ => false,
+    }
+}
+
+/// A stream whose leading events were consumed while waiting for the first output.
+struct OpenedStream {
+    buffered: Vec<ChatStreamEvent>,
+    rest: ChatStream,
+}
+
+impl OpenedStream {
+    /// Open a stream on `model` and read until the first output event.
+    async fn open(
+        model: Arc<dyn ChatCapability>,
+        request: crate::types::ChatRequest,
+    ) -> Result<Self, LlmError> {
+        let mut rest = model.chat_stream_request(request).await?;
+        let mut buffered = Vec::new();
+        while let Some(item) = rest.next().await {
+            let event = item?;
+            if let ChatStreamEvent::Error { error } = &event {
+                return Err(LlmError::StreamError(error.clone()));
+            }
+            let done = is_first_output(&event);
+            buffered.push(event);
+            if done {
+                break;
+            }
+        }
+        Ok(Self { buffered, rest })
+    }
+
+    fn into_stream(self) -> ChatStream {
+        Box::pin(futures::stream::iter(self.buffered.into_iter().map(Ok)).chain(self.rest))
+    }
+}
+
+impl ProviderRegistryHandle {
+    /// Resolve a registry id into a [`RouteCandidate`] backed by a language model handle.
+    pub fn route_candidate(&self, id: &str) -> Result<RouteCandidate, LlmError> {
+        let handle = self.language_model(id)?;
+        Ok(RouteCandidate::new(id, Arc::new(handle)))
+    }
+
+    /// Hedge `primary` with `alternates`, firing the next one after `hedge_after` without output.
+    pub fn hedged_language_model(
+        &self,
+        primary: &str,
+        alternates: &[&str],
+        hedge_after: Duration,
+    ) -> Result<HedgedLanguageModel, LlmError> {
+        let mut model = HedgedLanguageModel::new(self.route_candidate(primary)?, hedge_after);
+        for id in alternates {
+            model = model.with_alternate(self.route_candidate(id)?);
+        }
+        Ok(model)
+    }
+
+    /// Route calls across `ids` by observed TTFT and error rate.
+    pub fn latency_router(
+        &self,
+        ids: &[&str],
+        config: LatencyRouterConfig,
+    ) -> Result<LatencyRouter, LlmError> {
+        let candidates = ids
+            .iter()
+            .map(|id| self.route_candidate(id))
+            .collect::<Result<Vec<_>, _>>()?;
+        LatencyRouter::new(candidates, config)
+    }
+
+    /// Split traffic across weighted `arms` under the given experiment name.
+    pub fn traffic_split(
+        &self,
+        experiment: &str,
+        arms: &[(&str, u32)],
+    ) -> Result<TrafficSplit, LlmError> {
+        let mut split = TrafficSplit::new(experiment);
+        for (id, weight) in arms {
+            split = split.with_arm(self.route_candidate(id)?, *weight);
+        }
+        Ok(split)
+    }
+
+    /// Serve from `primary` and mirror calls to `shadow`, recording pairs to `sink`.
+    pub fn shadow_language_model(
+        &self,
+        primary: &str,
+        shadow: &str,
+        sink: Arc<dyn ShadowSink>,
+    ) -> Result<ShadowLanguageModel, LlmError> {
+        Ok(ShadowLanguageModel::new(
+            self.route_candidate(primary)?,
+            self.route_candidate(shadow)?,
+            sink,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests;
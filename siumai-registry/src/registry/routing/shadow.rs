@@ -0,0 +1,235 @@
+//! Shadow traffic.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::StreamExt;
+use tokio::sync::oneshot;
+
+use crate::error::LlmError;
+use crate::streaming::ChatStream;
+use crate::traits::ChatCapability;
+use crate::types::{ChatMessage, ChatRequest, ChatResponse, ChatStreamEvent, Tool};
+
+use super::{RouteCandidate, RoutingDecision};
+
+/// One side of a shadowed call.
+#[derive(Debug, Clone)]
+pub struct ShadowOutcome {
+    pub candidate: String,
+    /// Time until the full response (or error) was available.
+    pub latency: Duration,
+    pub result: Result<ChatResponse, LlmError>,
+}
+
+/// A primary response paired with the shadow candidate's response to the same request.
+#[derive(Debug, Clone)]
+pub struct ShadowRecord {
+    pub request: ChatRequest,
+    pub primary: ShadowOutcome,
+    pub shadow: ShadowOutcome,
+}
+
+/// Receives paired responses for offline comparison.
+///
+/// Called from a background task after both sides finished; slow sinks never delay callers.
+#[async_trait::async_trait]
+pub trait ShadowSink: Send + Sync {
+    async fn record(&self, record: ShadowRecord);
+}
+
+/// Keeps records in memory; useful for tests and small evaluations.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryShadowSink {
+    records: Arc<Mutex<Vec<ShadowRecord>>>,
+}
+
+impl InMemoryShadowSink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn records(&self) -> Vec<ShadowRecord> {
+        self.records
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl ShadowSink for InMemoryShadowSink {
+    async fn record(&self, record: ShadowRecord) {
+        self.records
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(record);
+    }
+}
+
+/// Serves every call from `primary` and mirrors a sample of calls to `shadow` in the background.
+///
+/// The shadow call is always non-streaming and its result, success or failure, never reaches
+/// the caller. For streamed primary calls the pair is recorded once the stream ends; if the
+/// caller drops the stream early the primary side is recorded as a `StreamError`.
+#[derive(Clone)]
+pub struct ShadowLanguageModel {
+    primary: RouteCandidate,
+    shadow: RouteCandidate,
+    sink: Arc<dyn ShadowSink>,
+    sample_rate: f64,
+}
+
+impl std::fmt::Debug for ShadowLanguageModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShadowLanguageModel")
+            .field("primary", &self.primary)
+            .field("shadow", &self.shadow)
+            .field("sample_rate", &self.sample_rate)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ShadowLanguageModel {
+    pub fn new(primary: RouteCandidate, shadow: RouteCandidate, sink: Arc<dyn ShadowSink>) -> Self {
+        Self {
+            primary,
+            shadow,
+            sink,
+            sample_rate: 1.0,
+        }
+    }
+
+    /// Fraction of calls mirrored to the shadow candidate (clamped to `0.0..=1.0`).
+    pub fn with_sample_rate(mut self, sample_rate: f64) -> Self {
+        self.sample_rate = sample_rate.clamp(0.0, 1.0);
+        self
+    }
+
+    fn sampled(&self) -> bool {
+        if self.sample_rate >= 1.0 {
+            return true;
+        }
+        let draw = uuid::Uuid::new_v4().as_u64_pair().0 as f64 / u64::MAX as f64;
+        draw < self.sample_rate
+    }
+
+    /// Start the shadow call and return the channel the primary outcome is reported on.
+    fn spawn_shadow(&self, request: &ChatRequest) -> oneshot::Sender<ShadowOutcome> {
+        let (tx, rx) = oneshot::channel::<ShadowOutcome>();
+        let shadow = self.shadow.clone();
+        let primary_id = self.primary.id.clone();
+        let sink = self.sink.clone();
+        let request = request.clone();
+        tokio::spawn(async move {
+            let started = Instant::now();
+            let result = shadow
+                .model
+                .chat_request(request.clone().with_streaming(false))
+                .await;
+            let shadow = ShadowOutcome {
+                candidate: shadow.id,
+                latency: started.elapsed(),
+                result,
+            };
+            let primary = rx.await.unwrap_or_else(|_| ShadowOutcome {
+                candidate: primary_id,
+                latency: started.elapsed(),
+                result: Err(LlmError::StreamError(
+                    "primary stream was dropped before completion".to_string(),
+                )),
+            });
+            sink.record(ShadowRecord {
+                request,
+                primary,
+                shadow,
+            })
+            .await;
+        });
+        tx
+    }
+
+    fn decision(&self) -> RoutingDecision {
+        RoutingDecision {
+            strategy: "shadow".to_string(),
+            candidate: self.primary.id.clone(),
+            attempts: 1,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatCapability for ShadowLanguageModel {
+    async fn chat_with_tools(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatResponse, LlmError> {
+        let mut request = ChatRequest::new(messages);
+        if let Some(tools) = tools {
+            request = request.with_tools(tools);
+        }
+        self.chat_request(request).await
+    }
+
+    async fn chat_stream(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatStream, LlmError> {
+        let mut request = ChatRequest::new(messages).with_streaming(true);
+        if let Some(tools) = tools {
+            request = request.with_tools(tools);
+        }
+        self.chat_stream_request(request).await
+    }
+
+    async fn chat_request(&self, request: ChatRequest) -> Result<ChatResponse, LlmError> {
+        let tx = self.sampled().then(|| self.spawn_shadow(&request));
+        let started = Instant::now();
+        let mut result = self.primary.model.chat_request(request).await;
+        if let Some(tx) = tx {
+            let _ = tx.send(ShadowOutcome {
+                candidate: self.primary.id.clone(),
+                latency: started.elapsed(),
+                result: result.clone(),
+            });
+        }
+        if let Ok(response) = &mut result {
+            self.decision().record(response);
+        }
+        result
+    }
+
+    async fn chat_stream_request(&self, request: ChatRequest) -> Result<ChatStream, LlmError> {
+        let mut tx = self.sampled().then(|| self.spawn_shadow(&request));
+        let started = Instant::now();
+        let candidate = self.primary.id.clone();
+        let mut report = move |result: Result<ChatResponse, LlmError>| {
+            if let Some(tx) = tx.take() {
+                let _ = tx.send(ShadowOutcome {
+                    candidate: candidate.clone(),
+                    latency: started.elapsed(),
+                    result,
+                });
+            }
+        };
+
+        let stream = match self.primary.model.chat_stream_request(request).await {
+            Ok(stream) => stream,
+            Err(e) => {
+                report(Err(e.clone()));
+                return Err(e);
+            }
+        };
+        let stream: ChatStream = Box::pin(stream.inspect(move |item| match item {
+            Ok(ChatStreamEvent::StreamEnd { response }) => report(Ok(response.clone())),
+            Ok(ChatStreamEvent::Error { error }) => {
+                report(Err(LlmError::StreamError(error.clone())))
+            }
+            Err(e) => report(Err(e.clone())),
+            Ok(_) => {}
+        }));
+        Ok(self.decision().annotate(stream))
+    }
+}
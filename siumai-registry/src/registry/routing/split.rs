@@ -0,0 +1,153 @@
+//! Weighted traffic splitting.
+
+use crate::error::LlmError;
+use crate::streaming::ChatStream;
+use crate::traits::ChatCapability;
+use crate::types::{ChatMessage, ChatRequest, ChatResponse, Tool};
+use crate::utils::stable_hash;
+
+use super::{RouteCandidate, RoutingDecision};
+
+/// Where a [`TrafficSplit`] reads the assignment key from.
+///
+/// Keys come from the request's telemetry config. Requests without a key are assigned at random.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum StickyKey {
+    /// `telemetry.user_id`, falling back to `telemetry.session_id`.
+    #[default]
+    UserOrSession,
+    /// `telemetry.user_id`.
+    User,
+    /// `telemetry.session_id`.
+    Session,
+    /// An entry of `telemetry.metadata`.
+    Metadata(String),
+}
+
+impl StickyKey {
+    fn resolve<'a>(&self, request: &'a ChatRequest) -> Option<&'a str> {
+        let telemetry = request.telemetry.as_ref()?;
+        match self {
+            Self::UserOrSession => telemetry
+                .user_id
+                .as_deref()
+                .or(telemetry.session_id.as_deref()),
+            Self::User => telemetry.user_id.as_deref(),
+            Self::Session => telemetry.session_id.as_deref(),
+            Self::Metadata(key) => telemetry.metadata.get(key).map(String::as_str),
+        }
+    }
+}
+
+/// Splits traffic across candidates by weight, e.g. 90/10 during a model migration.
+///
+/// Requests carrying a sticky key always land on the same arm for a given experiment name and
+/// arm layout, so a user keeps seeing one model. Changing the experiment name reshuffles users.
+#[derive(Debug, Clone)]
+pub struct TrafficSplit {
+    experiment: String,
+    arms: Vec<(RouteCandidate, u32)>,
+    sticky_key: StickyKey,
+}
+
+impl TrafficSplit {
+    pub fn new(experiment: impl Into<String>) -> Self {
+        Self {
+            experiment: experiment.into(),
+            arms: Vec::new(),
+            sticky_key: StickyKey::default(),
+        }
+    }
+
+    /// Add an arm; a request goes to it with probability `weight / sum(weights)`.
+    pub fn with_arm(mut self, candidate: RouteCandidate, weight: u32) -> Self {
+        self.arms.push((candidate, weight));
+        self
+    }
+
+    pub fn with_sticky_key(mut self, sticky_key: StickyKey) -> Self {
+        self.sticky_key = sticky_key;
+        self
+    }
+
+    pub fn experiment(&self) -> &str {
+        &self.experiment
+    }
+
+    fn total_weight(&self) -> Result<u64, LlmError> {
+        let total: u64 = self.arms.iter().map(|(_, w)| u64::from(*w)).sum();
+        if total == 0 {
+            return Err(LlmError::ConfigurationError(format!(
+                "traffic split '{}' has no arm with a positive weight",
+                self.experiment
+            )));
+        }
+        Ok(total)
+    }
+
+    /// The arm `request` is assigned to.
+    pub fn assign(&self, request: &ChatRequest) -> Result<&RouteCandidate, LlmError> {
+        let total = self.total_weight()?;
+        let bucket = match self.sticky_key.resolve(request) {
+            Some(key) => stable_hash(&[&self.experiment, key]),
+            None => uuid::Uuid::new_v4().as_u64_pair().0,
+        } % total;
+
+        let mut upper = 0;
+        for (candidate, weight) in &self.arms {
+            upper += u64::from(*weight);
+            if bucket < upper {
+                return Ok(candidate);
+            }
+        }
+        unreachable!("bucket is below the total weight")
+    }
+
+    fn decision(&self, candidate: &RouteCandidate) -> RoutingDecision {
+        RoutingDecision {
+            strategy: "split".to_string(),
+            candidate: candidate.id.clone(),
+            attempts: 1,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatCapability for TrafficSplit {
+    async fn chat_with_tools(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatResponse, LlmError> {
+        let mut request = ChatRequest::new(messages);
+        if let Some(tools) = tools {
+            request = request.with_tools(tools);
+        }
+        self.chat_request(request).await
+    }
+
+    async fn chat_stream(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatStream, LlmError> {
+        let mut request = ChatRequest::new(messages).with_streaming(true);
+        if let Some(tools) = tools {
+            request = request.with_tools(tools);
+        }
+        self.chat_stream_request(request).await
+    }
+
+    async fn chat_request(&self, request: ChatRequest) -> Result<ChatResponse, LlmError> {
+        let candidate = self.assign(&request)?;
+        let mut response = candidate.model.chat_request(request).await?;
+        self.decision(candidate).record(&mut response);
+        Ok(response)
+    }
+
+    async fn chat_stream_request(&self, request: ChatRequest) -> Result<ChatStream, LlmError> {
+        let candidate = self.assign(&request)?;
+        let stream = candidate.model.chat_stream_request(request).await?;
+        Ok(self.decision(candidate).annotate(stream))
+    }
+}
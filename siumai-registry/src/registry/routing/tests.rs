@@ -0,0 +1,513 @@
+#![allow(clippy::await_holding_lock)]
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::registry::entry::{
+    ProviderFactory, RegistryOptions, TestProviderFactory, create_provider_registry, reg_test_guard,
+};
+use crate::types::{ChatMessage, ChatRequest, MessageContent, Tool};
+
+use super::*;
+
+/// Fake model that waits `delay` before its first output and can be told to fail.
+struct DelayedModel {
+    text: &'static str,
+    delay: Duration,
+    fail: AtomicBool,
+    /// Fail with a non-retryable 400 instead of a 503.
+    reject: AtomicBool,
+    /// Fail with an authentication error.
+    unauthorized: AtomicBool,
+    calls: AtomicUsize,
+    /// Set when a stream or call future is dropped before completing.
+    cancelled: Arc<AtomicBool>,
+}
+
+impl DelayedModel {
+    fn new(text: &'static str, delay_ms: u64) -> Arc<Self> {
+        Arc::new(Self {
+            text,
+            delay: Duration::from_millis(delay_ms),
+            fail: AtomicBool::new(false),
+            reject: AtomicBool::new(false),
+            unauthorized: AtomicBool::new(false),
+            calls: AtomicUsize::new(0),
+            cancelled: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    fn failing(text: &'static str, delay_ms: u64) -> Arc<Self> {
+        let model = Self::new(text, delay_ms);
+        model.fail.store(true, Ordering::SeqCst);
+        model
+    }
+}
+
+/// Flags cancellation when dropped while still armed.
+struct DropFlag(Option<Arc<AtomicBool>>);
+
+impl Drop for DropFlag {
+    fn drop(&mut self) {
+        if let Some(flag) = self.0.take() {
+            flag.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatCapability for DelayedModel {
+    async fn chat_with_tools(
+        &self,
+        _messages: Vec<ChatMessage>,
+        _tools: Option<Vec<Tool>>,
+    ) -> Result<ChatResponse, LlmError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let mut guard = DropFlag(Some(self.cancelled.clone()));
+        tokio::time::sleep(self.delay).await;
+        guard.0 = None;
+        if self.unauthorized.load(Ordering::SeqCst) {
+            return Err(LlmError::AuthenticationError("invalid api key".to_string()));
+        }
+        if self.reject.load(Ordering::SeqCst) {
+            return Err(LlmError::InvalidParameter("bad request".to_string()));
+        }
+        if self.fail.load(Ordering::SeqCst) {
+            return Err(LlmError::ApiError {
+                code: 503,
+                message: "unavailable".to_string(),
+                details: None,
+                http_details: None,
+            });
+        }
+        Ok(ChatResponse::new(MessageContent::Text(
+            self.text.to_string(),
+        )))
+    }
+
+    async fn chat_stream(
+        &self,
+        _messages: Vec<ChatMessage>,
+        _tools: Option<Vec<Tool>>,
+    ) -> Result<ChatStream, LlmError> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        let text = self.text;
+        let delay = self.delay;
+        let fail = self.fail.load(Ordering::SeqCst);
+        let cancelled = self.cancelled.clone();
+        let head = futures::stream::iter([Ok(ChatStreamEvent::StreamStart {
+            metadata: crate::types::ResponseMetadata {
+                id: None,
+                model: None,
+                created: None,
+                provider: "fake".to_string(),
+                request_id: None,
+                headers: None,
+                body: None,
+            },
+        })]);
+        let tail = futures::stream::once(async move {
+            let mut guard = DropFlag(Some(cancelled));
+            tokio::time::sleep(delay).await;
+            guard.0 = None;
+            if fail {
+                return vec![Err(LlmError::StreamError("connection reset".to_string()))];
+            }
+            vec![
+                Ok(ChatStreamEvent::text_delta_part("0", text)),
+                Ok(ChatStreamEvent::StreamEnd {
+                    response: ChatResponse::new(MessageContent::Text(text.to_string())),
+                }),
+            ]
+        })
+        .flat_map(futures::stream::iter);
+        Ok(Box::pin(head.chain(tail)))
+    }
+}
+
+fn candidate(id: &str, model: &Arc<DelayedModel>) -> RouteCandidate {
+    RouteCandidate::new(id, model.clone() as Arc<dyn ChatCapability>)
+}
+
+fn request() -> ChatRequest {
+    ChatRequest::new(vec![ChatMessage::user("hi").build()])
+}
+
+async fn collect(stream: ChatStream) -> (String, ChatResponse) {
+    let events: Vec<ChatStreamEvent> = stream.map(Result::unwrap).collect().await;
+    let text = events.iter().filter_map(|e| e.text_delta()).collect();
+    let Some(ChatStreamEvent::StreamEnd { response }) = events.last().cloned() else {
+        panic!("expected StreamEnd");
+    };
+    (text, response)
+}
+
+#[tokio::test]
+async fn hedge_fires_alternate_and_cancels_slow_primary() {
+    let slow = DelayedModel::new("slow", 500);
+    let fast = DelayedModel::new("fast", 10);
+    let model = HedgedLanguageModel::new(candidate("a:slow", &slow), Duration::from_millis(30))
+        .with_alternate(candidate("b:fast", &fast));
+
+    let (text, response) = collect(model.chat_stream_request(request()).await.unwrap()).await;
+    assert_eq!(text, "fast");
+    assert_eq!(
+        RoutingDecision::from_response(&response),
+        Some(RoutingDecision {
+            strategy: "hedge".to_string(),
+            candidate: "b:fast".to_string(),
+            attempts: 2,
+        })
+    );
+    assert!(slow.cancelled.load(Ordering::SeqCst));
+
+    let response = model.chat_request(request()).await.unwrap();
+    assert_eq!(response.content.all_text(), "fast");
+}
+
+#[tokio::test]
+async fn hedge_keeps_fast_primary_and_fails_over_on_error() {
+    let primary = DelayedModel::new("primary", 5);
+    let alternate = DelayedModel::new("alternate", 5);
+    let model = HedgedLanguageModel::new(candidate("a:p", &primary), Duration::from_millis(200))
+        .with_alternate(candidate("b:alt", &alternate));
+    let (text, response) = collect(model.chat_stream_request(request()).await.unwrap()).await;
+    assert_eq!(text, "primary");
+    assert_eq!(
+        RoutingDecision::from_response(&response).unwrap().attempts,
+        1
+    );
+    assert_eq!(alternate.calls.load(Ordering::SeqCst), 0);
+
+    // A failure before the hedge deadline launches the alternate immediately.
+    let broken = DelayedModel::failing("broken", 5);
+    let model = HedgedLanguageModel::new(candidate("a:broken", &broken), Duration::from_secs(5))
+        .with_alternate(candidate("b:alt", &alternate));
+    let started = std::time::Instant::now();
+    let response = model.chat_request(request()).await.unwrap();
+    assert!(started.elapsed() < Duration::from_secs(1));
+    assert_eq!(
+        RoutingDecision::from_response(&response).unwrap().candidate,
+        "b:alt"
+    );
+
+    let only_broken = HedgedLanguageModel::new(candidate("a:broken", &broken), Duration::ZERO);
+    assert!(only_broken.chat_stream_request(request()).await.is_err());
+}
+
+#[tokio::test]
+async fn hedge_returns_non_retryable_errors_without_calling_alternates() {
+    let locked = DelayedModel::new("locked", 5);
+    locked.unauthorized.store(true, Ordering::SeqCst);
+    let alternate = DelayedModel::new("alternate", 5);
+    let model = HedgedLanguageModel::new(candidate("a:locked", &locked), Duration::from_secs(5))
+        .with_alternate(candidate("b:alt", &alternate));
+
+    let err = model.chat_request(request()).await.unwrap_err();
+    assert!(matches!(err, LlmError::AuthenticationError(_)), "{err:?}");
+    assert_eq!(alternate.calls.load(Ordering::SeqCst), 0);
+}
+
+#[tokio::test]
+async fn hedge_deadline_is_not_restarted_by_failed_attempts() {
+    // `a` never answers in time. `b` launches at 300ms and fails at 550ms while `a` is still
+    // running, so `c` waits for the deadline armed when `b` launched (600ms), not 850ms.
+    let slow = DelayedModel::new("slow", 5_000);
+    let broken = DelayedModel::failing("broken", 250);
+    let last = DelayedModel::new("last", 5);
+    let model = HedgedLanguageModel::new(candidate("a:slow", &slow), Duration::from_millis(300))
+        .with_alternate(candidate("b:broken", &broken))
+        .with_alternate(candidate("c:last", &last));
+
+    let started = std::time::Instant::now();
+    let response = model.chat_request(request()).await.unwrap();
+    assert!(started.elapsed() < Duration::from_millis(750));
+    assert_eq!(
+        RoutingDecision::from_response(&response).unwrap().candidate,
+        "c:last"
+    );
+}
+
+#[tokio::test]
+async fn latency_router_prefers_fast_candidate_after_exploring() {
+    let slow = DelayedModel::new("slow", 40);
+    let fast = DelayedModel::new("fast", 5);
+    let router = LatencyRouter::new(
+        vec![candidate("a:slow", &slow), candidate("b:fast", &fast)],
+        LatencyRouterConfig::new().with_min_samples(2),
+    )
+    .unwrap();
+
+    for _ in 0..4 {
+        router.chat_request(request()).await.unwrap();
+    }
+    assert_eq!(slow.calls.load(Ordering::SeqCst), 2);
+    assert_eq!(fast.calls.load(Ordering::SeqCst), 2);
+
+    let (text, response) = collect(router.chat_stream_request(request()).await.unwrap()).await;
+    assert_eq!(text, "fast");
+    let decision = RoutingDecision::from_response(&response).unwrap();
+    assert_eq!(
+        (decision.strategy.as_str(), decision.candidate.as_str()),
+        ("latency", "b:fast")
+    );
+
+    let stats = router.stats();
+    assert_eq!(stats[0].id, "a:slow");
+    assert_eq!(stats[1].samples, 3);
+    assert!(stats[0].p95_ttft.unwrap() > stats[1].p95_ttft.unwrap());
+}
+
+#[tokio::test]
+async fn latency_router_penalizes_errors_and_fails_over() {
+    let flaky = DelayedModel::failing("flaky", 1);
+    let steady = DelayedModel::new("steady", 20);
+    let router = LatencyRouter::new(
+        vec![candidate("a:flaky", &flaky), candidate("b:steady", &steady)],
+        LatencyRouterConfig::new().with_min_samples(1),
+    )
+    .unwrap();
+
+    let response = router.chat_request(request()).await.unwrap();
+    let decision = RoutingDecision::from_response(&response).unwrap();
+    assert_eq!(
+        (decision.candidate.as_str(), decision.attempts),
+        ("b:steady", 2)
+    );
+    assert_eq!(router.stats()[0].error_rate, 1.0);
+
+    // The flaky candidate has no successful TTFT and now ranks last.
+    router.chat_request(request()).await.unwrap();
+    assert_eq!(flaky.calls.load(Ordering::SeqCst), 1);
+
+    let no_failover = LatencyRouter::new(
+        vec![candidate("a:flaky", &flaky)],
+        LatencyRouterConfig::new().with_failover(false),
+    )
+    .unwrap();
+    assert!(no_failover.chat_request(request()).await.is_err());
+    assert!(LatencyRouter::new(vec![], LatencyRouterConfig::default()).is_err());
+}
+
+#[tokio::test]
+async fn latency_router_returns_non_retryable_errors_without_failover() {
+    let picky = DelayedModel::new("picky", 1);
+    picky.reject.store(true, Ordering::SeqCst);
+    let steady = DelayedModel::new("steady", 1);
+    let router = LatencyRouter::new(
+        vec![candidate("a:picky", &picky), candidate("b:steady", &steady)],
+        LatencyRouterConfig::new().with_min_samples(1),
+    )
+    .unwrap();
+
+    let err = router.chat_request(request()).await.unwrap_err();
+    assert!(matches!(err, LlmError::InvalidParameter(_)), "{err:?}");
+    assert_eq!(steady.calls.load(Ordering::SeqCst), 0);
+    // A rejected request says nothing about the candidate's health.
+    assert_eq!(router.stats()[0].samples, 0);
+}
+
+#[tokio::test]
+async fn registry_builds_routed_language_models() {
+    let _g = reg_test_guard();
+    let mut providers = HashMap::new();
+    providers.insert(
+        "testprov".to_string(),
+        Arc::new(TestProviderFactory::new("testprov")) as Arc<dyn ProviderFactory>,
+    );
+    let reg = create_provider_registry(
+        providers,
+        Some(RegistryOptions {
+            auto_middleware: false,
+            ..Default::default()
+        }),
+    );
+
+    let hedged = reg
+        .hedged_language_model("testprov:a", &["testprov:b"], Duration::from_secs(1))
+        .unwrap();
+    let response = hedged.chat_request(request()).await.unwrap();
+    assert_eq!(response.content.all_text(), "ok");
+    assert_eq!(
+        RoutingDecision::from_response(&response).unwrap().candidate,
+        "testprov:a"
+    );
+
+    let router = reg
+        .latency_router(
+            &["testprov:a", "testprov:b"],
+            LatencyRouterConfig::default(),
+        )
+        .unwrap();
+    router.chat_request(request()).await.unwrap();
+    assert_eq!(router.stats().iter().map(|s| s.samples).sum::<usize>(), 1);
+
+    assert!(
+        reg.latency_router(&["missing:model"], LatencyRouterConfig::default())
+            .is_err()
+    );
+}
+
+fn request_for(user: &str) -> ChatRequest {
+    let mut req = request();
+    req.telemetry = Some(
+        crate::observability::telemetry::TelemetryConfig::builder()
+            .user_id(user)
+            .build(),
+    );
+    req
+}
+
+#[tokio::test]
+async fn traffic_split_is_sticky_and_follows_weights() {
+    let control = DelayedModel::new("control", 0);
+    let treatment = DelayedModel::new("treatment", 0);
+    let split = TrafficSplit::new("migration-1")
+        .with_arm(candidate("a:control", &control), 80)
+        .with_arm(candidate("b:treatment", &treatment), 20);
+
+    for user in ["ada", "grace", "linus"] {
+        let first = split.assign(&request_for(user)).unwrap().id().to_string();
+        for _ in 0..5 {
+            assert_eq!(split.assign(&request_for(user)).unwrap().id(), first);
+        }
+    }
+
+    let treated = (0..2000)
+        .filter(|i| {
+            split
+                .assign(&request_for(&format!("user-{i}")))
+                .unwrap()
+                .id()
+                == "b:treatment"
+        })
+        .count();
+    assert!((300..500).contains(&treated), "treated {treated} of 2000");
+
+    let response = split.chat_request(request_for("ada")).await.unwrap();
+    let decision = RoutingDecision::from_response(&response).unwrap();
+    assert_eq!(decision.strategy, "split");
+    assert_eq!(response.content.all_text(), &decision.candidate[2..]);
+
+    // Session keys and metadata keys select independently of the user id.
+    let by_tenant = TrafficSplit::new("tenant-rollout")
+        .with_arm(candidate("a:control", &control), 1)
+        .with_arm(candidate("b:treatment", &treatment), 1)
+        .with_sticky_key(StickyKey::Metadata("tenant".to_string()));
+    let mut tenant_request = request();
+    tenant_request.telemetry = Some(
+        crate::observability::telemetry::TelemetryConfig::builder()
+            .metadata("tenant", "acme")
+            .build(),
+    );
+    let arm = by_tenant.assign(&tenant_request).unwrap().id().to_string();
+    tenant_request.telemetry.as_mut().unwrap().user_id = Some("someone".to_string());
+    assert_eq!(by_tenant.assign(&tenant_request).unwrap().id(), arm);
+
+    let empty = TrafficSplit::new("empty").with_arm(candidate("a:control", &control), 0);
+    assert!(matches!(
+        empty.assign(&request()),
+        Err(LlmError::ConfigurationError(_))
+    ));
+}
+
+async fn wait_for_records(sink: &InMemoryShadowSink, count: usize) -> Vec<ShadowRecord> {
+    for _ in 0..100 {
+        let records = sink.records();
+        if records.len() >= count {
+            return records;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    panic!(
+        "expected {count} shadow records, got {}",
+        sink.records().len()
+    );
+}
+
+#[tokio::test]
+async fn shadow_mirrors_calls_without_affecting_the_caller() {
+    let primary = DelayedModel::new("primary", 5);
+    let shadow = DelayedModel::failing("shadow", 50);
+    let sink = InMemoryShadowSink::new();
+    let model = ShadowLanguageModel::new(
+        candidate("a:primary", &primary),
+        candidate("b:shadow", &shadow),
+        Arc::new(sink.clone()),
+    );
+
+    let started = std::time::Instant::now();
+    let response = model.chat_request(request()).await.unwrap();
+    assert!(started.elapsed() < Duration::from_millis(50));
+    assert_eq!(response.content.all_text(), "primary");
+    assert_eq!(
+        RoutingDecision::from_response(&response).unwrap().candidate,
+        "a:primary"
+    );
+
+    let (text, _) = collect(model.chat_stream_request(request()).await.unwrap()).await;
+    assert_eq!(text, "primary");
+
+    let records = wait_for_records(&sink, 2).await;
+    for record in &records {
+        assert_eq!(record.primary.candidate, "a:primary");
+        assert_eq!(
+            record.primary.result.as_ref().unwrap().content.all_text(),
+            "primary"
+        );
+        assert_eq!(record.shadow.candidate, "b:shadow");
+        assert!(record.shadow.result.is_err());
+        assert!(record.shadow.latency >= Duration::from_millis(50));
+    }
+
+    // Dropping a primary stream early still records the pair.
+    drop(model.chat_stream_request(request()).await.unwrap());
+    let records = wait_for_records(&sink, 3).await;
+    assert!(matches!(
+        records[2].primary.result,
+        Err(LlmError::StreamError(_))
+    ));
+
+    let unsampled = ShadowLanguageModel::new(
+        candidate("a:primary", &primary),
+        candidate("b:shadow", &shadow),
+        Arc::new(sink.clone()),
+    )
+    .with_sample_rate(0.0);
+    let calls = shadow.calls.load(Ordering::SeqCst);
+    unsampled.chat_request(request()).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(shadow.calls.load(Ordering::SeqCst), calls);
+}
+
+#[tokio::test]
+async fn registry_builds_split_and_shadow_models() {
+    let _g = reg_test_guard();
+    let mut providers = HashMap::new();
+    providers.insert(
+        "testprov".to_string(),
+        Arc::new(TestProviderFactory::new("testprov")) as Arc<dyn ProviderFactory>,
+    );
+    let reg = create_provider_registry(
+        providers,
+        Some(RegistryOptions {
+            auto_middleware: false,
+            ..Default::default()
+        }),
+    );
+
+    let split = reg
+        .traffic_split("exp", &[("testprov:a", 1), ("testprov:b", 1)])
+        .unwrap();
+    let response = split.chat_request(request_for("ada")).await.unwrap();
+    assert_eq!(response.content.all_text(), "ok");
+
+    let sink = InMemoryShadowSink::new();
+    let shadowed = reg
+        .shadow_language_model("testprov:a", "testprov:b", Arc::new(sink.clone()))
+        .unwrap();
+    shadowed.chat_request(request()).await.unwrap();
+    let record = wait_for_records(&sink, 1).await.remove(0);
+    assert_eq!(record.shadow.candidate, "testprov:b");
+    assert!(record.shadow.result.is_ok());
+}
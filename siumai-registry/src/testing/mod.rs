@@ -0,0 +1,322 @@
+//! Scripted mock provider for tests (feature: `testing`).
+//!
+//! [`MockProvider`] is a provider factory that never touches the network. Tests queue the
+//! responses it should return, register it as `mock`, and assert on the requests it received
+//! afterwards:
+//!
+//! ```rust,ignore
+//! use siumai_registry::testing::{MockProvider, MockStream};
+//!
+//! let mock = MockProvider::new();
+//! mock.push_tool_call("call_1", "weather", serde_json::json!({ "city": "Paris" }))
+//!     .push_stream(MockStream::new().reasoning("Looking it up").text("Sunny").finish_stop());
+//!
+//! let registry = mock.registry();
+//! let model = registry.language_model("mock:gpt-test")?;
+//! // ... drive the model (or `mock.language_model("gpt-test")` with `ToolLoopAgent`)
+//!
+//! assert_eq!(mock.chat_requests().len(), 2);
+//! ```
+//!
+//! Chat calls consume the queue in order and fail when it is empty, so an unexpected extra
+//! model turn shows up as a test error. A queued [`ChatResponse`] is replayed as a stream when
+//! consumed by a streaming call. Embedding, image, rerank, speech and transcription calls use
+//! their own queues and fall back to deterministic responses when nothing is queued.
+
+mod models;
+mod stream;
+
+#[cfg(test)]
+mod tests;
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use crate::error::LlmError;
+use crate::registry::{ProviderFactory, ProviderRegistryHandle, create_provider_registry};
+use crate::types::{
+    ChatRequest, ChatResponse, ContentPart, EmbeddingRequest, EmbeddingResponse, FinishReason,
+    ImageGenerationRequest, ImageGenerationResponse, MessageContent, RerankRequest, RerankResponse,
+    SttRequest, SttResponse, TtsRequest, TtsResponse,
+};
+
+pub use models::MockModel;
+pub use stream::MockStream;
+
+/// Provider id used by [`MockProvider`] (`mock:<model>`).
+pub const MOCK_PROVIDER_ID: &str = "mock";
+
+/// Model family served by the mock provider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MockFamily {
+    Chat,
+    Embedding,
+    Image,
+    Rerank,
+    Speech,
+    Transcription,
+}
+
+/// A request received by the mock provider, with the model id it was addressed to.
+#[derive(Debug, Clone)]
+pub struct MockCall<R> {
+    pub model_id: String,
+    pub request: R,
+}
+
+pub(crate) enum ChatTurn {
+    Response(Box<ChatResponse>),
+    Stream(MockStream),
+    Error(LlmError),
+}
+
+#[derive(Default)]
+pub(crate) struct MockState {
+    pub(crate) latency: Option<Duration>,
+    pub(crate) embedding_dimension: Option<usize>,
+
+    pub(crate) chat: VecDeque<ChatTurn>,
+    pub(crate) embedding: VecDeque<Result<EmbeddingResponse, LlmError>>,
+    pub(crate) image: VecDeque<Result<ImageGenerationResponse, LlmError>>,
+    pub(crate) rerank: VecDeque<Result<RerankResponse, LlmError>>,
+    pub(crate) speech: VecDeque<Result<TtsResponse, LlmError>>,
+    pub(crate) transcription: VecDeque<Result<SttResponse, LlmError>>,
+
+    pub(crate) chat_calls: Vec<MockCall<ChatRequest>>,
+    pub(crate) embedding_calls: Vec<MockCall<EmbeddingRequest>>,
+    pub(crate) image_calls: Vec<MockCall<ImageGenerationRequest>>,
+    pub(crate) rerank_calls: Vec<MockCall<RerankRequest>>,
+    pub(crate) speech_calls: Vec<MockCall<TtsRequest>>,
+    pub(crate) transcription_calls: Vec<MockCall<SttRequest>>,
+}
+
+/// Scripted, in-memory provider for downstream tests.
+///
+/// Cloning is cheap; clones share the same script queues and call records, so a test can keep
+/// one handle for assertions while the registry or an agent owns another.
+#[derive(Clone, Default)]
+pub struct MockProvider {
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockProvider {
+    /// Create an empty mock provider.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sleep for `latency` before every call (useful for timeout/hedging tests).
+    pub fn with_latency(self, latency: Duration) -> Self {
+        self.lock().latency = Some(latency);
+        self
+    }
+
+    /// Dimension of the deterministic fallback embeddings (default: 8).
+    pub fn with_embedding_dimension(self, dimension: usize) -> Self {
+        self.lock().embedding_dimension = Some(dimension.max(1));
+        self
+    }
+
+    /// Create a language model bound to this provider (usable directly with agents).
+    pub fn language_model(&self, model_id: impl Into<String>) -> MockModel {
+        MockModel::new(self.clone(), model_id.into())
+    }
+
+    /// Build a registry with this provider registered as `mock`.
+    pub fn registry(&self) -> ProviderRegistryHandle {
+        let mut providers = std::collections::HashMap::new();
+        providers.insert(
+            MOCK_PROVIDER_ID.to_string(),
+            Arc::new(self.clone()) as Arc<dyn ProviderFactory>,
+        );
+        create_provider_registry(providers, None)
+    }
+
+    // -------------------------------------------------------------------------
+    // Scripting
+    // -------------------------------------------------------------------------
+
+    /// Queue a chat response.
+    pub fn push_response(&self, response: ChatResponse) -> &Self {
+        self.lock()
+            .chat
+            .push_back(ChatTurn::Response(Box::new(response)));
+        self
+    }
+
+    /// Queue a plain-text chat response.
+    pub fn push_text(&self, text: impl Into<String>) -> &Self {
+        let mut response = ChatResponse::new(MessageContent::Text(text.into()));
+        response.finish_reason = Some(FinishReason::Stop);
+        self.push_response(response)
+    }
+
+    /// Queue a chat response containing a single tool call.
+    pub fn push_tool_call(
+        &self,
+        tool_call_id: impl Into<String>,
+        tool_name: impl Into<String>,
+        arguments: serde_json::Value,
+    ) -> &Self {
+        let mut response =
+            ChatResponse::new(MessageContent::MultiModal(vec![ContentPart::tool_call(
+                tool_call_id,
+                tool_name,
+                arguments,
+                None,
+            )]));
+        response.finish_reason = Some(FinishReason::ToolCalls);
+        self.push_response(response)
+    }
+
+    /// Queue a scripted stream.
+    ///
+    /// A stream consumed by a non-streaming call is collected into its final response.
+    pub fn push_stream(&self, stream: MockStream) -> &Self {
+        self.lock().chat.push_back(ChatTurn::Stream(stream));
+        self
+    }
+
+    /// Queue an error for the next call of the given family.
+    pub fn push_error(&self, family: MockFamily, error: LlmError) -> &Self {
+        let mut state = self.lock();
+        match family {
+            MockFamily::Chat => state.chat.push_back(ChatTurn::Error(error)),
+            MockFamily::Embedding => state.embedding.push_back(Err(error)),
+            MockFamily::Image => state.image.push_back(Err(error)),
+            MockFamily::Rerank => state.rerank.push_back(Err(error)),
+            MockFamily::Speech => state.speech.push_back(Err(error)),
+            MockFamily::Transcription => state.transcription.push_back(Err(error)),
+        }
+        drop(state);
+        self
+    }
+
+    /// Queue an embedding response.
+    pub fn push_embedding(&self, response: EmbeddingResponse) -> &Self {
+        self.lock().embedding.push_back(Ok(response));
+        self
+    }
+
+    /// Queue an image generation response.
+    pub fn push_image(&self, response: ImageGenerationResponse) -> &Self {
+        self.lock().image.push_back(Ok(response));
+        self
+    }
+
+    /// Queue a rerank response.
+    pub fn push_rerank(&self, response: RerankResponse) -> &Self {
+        self.lock().rerank.push_back(Ok(response));
+        self
+    }
+
+    /// Queue a speech (TTS) response.
+    pub fn push_speech(&self, response: TtsResponse) -> &Self {
+        self.lock().speech.push_back(Ok(response));
+        self
+    }
+
+    /// Queue a transcription (STT) response.
+    pub fn push_transcription(&self, response: SttResponse) -> &Self {
+        self.lock().transcription.push_back(Ok(response));
+        self
+    }
+
+    /// Number of queued entries not consumed yet.
+    pub fn pending(&self, family: MockFamily) -> usize {
+        let state = self.lock();
+        match family {
+            MockFamily::Chat => state.chat.len(),
+            MockFamily::Embedding => state.embedding.len(),
+            MockFamily::Image => state.image.len(),
+            MockFamily::Rerank => state.rerank.len(),
+            MockFamily::Speech => state.speech.len(),
+            MockFamily::Transcription => state.transcription.len(),
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Recorded calls
+    // -------------------------------------------------------------------------
+
+    /// Chat requests received so far, in order.
+    pub fn chat_requests(&self) -> Vec<ChatRequest> {
+        self.lock()
+            .chat_calls
+            .iter()
+            .map(|call| call.request.clone())
+            .collect()
+    }
+
+    /// The most recent chat request.
+    pub fn last_chat_request(&self) -> Option<ChatRequest> {
+        self.lock()
+            .chat_calls
+            .last()
+            .map(|call| call.request.clone())
+    }
+
+    /// Chat calls (model id + request) received so far.
+    pub fn chat_calls(&self) -> Vec<MockCall<ChatRequest>> {
+        self.lock().chat_calls.clone()
+    }
+
+    /// Embedding calls received so far.
+    pub fn embedding_calls(&self) -> Vec<MockCall<EmbeddingRequest>> {
+        self.lock().embedding_calls.clone()
+    }
+
+    /// Image generation calls received so far.
+    pub fn image_calls(&self) -> Vec<MockCall<ImageGenerationRequest>> {
+        self.lock().image_calls.clone()
+    }
+
+    /// Rerank calls received so far.
+    pub fn rerank_calls(&self) -> Vec<MockCall<RerankRequest>> {
+        self.lock().rerank_calls.clone()
+    }
+
+    /// Speech calls received so far.
+    pub fn speech_calls(&self) -> Vec<MockCall<TtsRequest>> {
+        self.lock().speech_calls.clone()
+    }
+
+    /// Transcription calls received so far.
+    pub fn transcription_calls(&self) -> Vec<MockCall<SttRequest>> {
+        self.lock().transcription_calls.clone()
+    }
+
+    /// Drop all queued scripts and recorded calls (latency and dimension settings are kept).
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        let latency = state.latency;
+        let embedding_dimension = state.embedding_dimension;
+        *state = MockState {
+            latency,
+            embedding_dimension,
+            ..Default::default()
+        };
+    }
+
+    pub(crate) fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) async fn simulate_latency(&self) {
+        let latency = self.lock().latency;
+        if let Some(latency) = latency {
+            tokio::time::sleep(latency).await;
+        }
+    }
+}
+
+impl std::fmt::Debug for MockProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = self.lock();
+        f.debug_struct("MockProvider")
+            .field("pending_chat", &state.chat.len())
+            .field("chat_calls", &state.chat_calls.len())
+            .finish_non_exhaustive()
+    }
+}
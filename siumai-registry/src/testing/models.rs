@@ -0,0 +1,402 @@
+//! Mock model and provider factory implementations.
+
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::embedding::EmbeddingModel;
+use crate::error::LlmError;
+use crate::image::ImageModel;
+use crate::registry::{BuildContext, ProviderFactory};
+use crate::streaming::ChatStream;
+use crate::traits::{ChatCapability, ModelMetadata, ProviderCapabilities};
+use crate::types::{
+    BatchEmbeddingRequest, BatchEmbeddingResponse, ChatMessage, ChatRequest, ChatResponse,
+    EmbeddingRequest, EmbeddingResponse, GeneratedImage, ImageGenerationRequest,
+    ImageGenerationResponse, RerankDocument, RerankDocuments, RerankRankingEntry, RerankRequest,
+    RerankResponse, RerankTokenUsage, SttRequest, SttResponse, Tool, TtsRequest, TtsResponse,
+};
+use siumai_core::rerank::RerankingModel;
+use siumai_core::speech::SpeechModel;
+use siumai_core::transcription::TranscriptionModel;
+
+use super::stream::MockStream;
+use super::{ChatTurn, MOCK_PROVIDER_ID, MockCall, MockProvider};
+
+const DEFAULT_EMBEDDING_DIMENSION: usize = 8;
+
+/// A model served by [`MockProvider`]; implements every family the mock supports.
+///
+/// Clones share the provider's scripts and call records.
+#[derive(Clone, Debug)]
+pub struct MockModel {
+    provider: MockProvider,
+    model_id: String,
+}
+
+impl MockModel {
+    pub(crate) fn new(provider: MockProvider, model_id: String) -> Self {
+        Self { provider, model_id }
+    }
+
+    /// The provider this model reads scripts from.
+    pub fn provider(&self) -> &MockProvider {
+        &self.provider
+    }
+
+    fn call<R>(&self, request: R) -> MockCall<R> {
+        MockCall {
+            model_id: self.model_id.clone(),
+            request,
+        }
+    }
+
+    fn next_chat_turn(&self, request: ChatRequest) -> Result<ChatTurn, LlmError> {
+        let mut state = self.provider.lock();
+        state.chat_calls.push(self.call(request));
+        state.chat.pop_front().ok_or_else(|| {
+            LlmError::InternalError(format!(
+                "MockProvider: no scripted chat response left for model '{}'",
+                self.model_id
+            ))
+        })
+    }
+}
+
+impl ModelMetadata for MockModel {
+    fn provider_id(&self) -> &str {
+        MOCK_PROVIDER_ID
+    }
+
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+}
+
+#[async_trait::async_trait]
+impl ChatCapability for MockModel {
+    async fn chat_with_tools(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatResponse, LlmError> {
+        let mut request = ChatRequest::new(messages);
+        request.tools = tools;
+        self.chat_request(request).await
+    }
+
+    async fn chat_stream(
+        &self,
+        messages: Vec<ChatMessage>,
+        tools: Option<Vec<Tool>>,
+    ) -> Result<ChatStream, LlmError> {
+        let mut request = ChatRequest::new(messages);
+        request.tools = tools;
+        self.chat_stream_request(request).await
+    }
+
+    async fn chat_request(&self, request: ChatRequest) -> Result<ChatResponse, LlmError> {
+        self.provider.simulate_latency().await;
+        match self.next_chat_turn(request)? {
+            ChatTurn::Response(response) => Ok(*response),
+            ChatTurn::Stream(stream) => stream.into_response(),
+            ChatTurn::Error(error) => Err(error),
+        }
+    }
+
+    async fn chat_stream_request(&self, request: ChatRequest) -> Result<ChatStream, LlmError> {
+        self.provider.simulate_latency().await;
+        match self.next_chat_turn(request)? {
+            ChatTurn::Response(response) => {
+                Ok(MockStream::from_response(*response).into_chat_stream())
+            }
+            ChatTurn::Stream(stream) => Ok(stream.into_chat_stream()),
+            ChatTurn::Error(error) => Err(error),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EmbeddingModel for MockModel {
+    async fn embed(&self, request: EmbeddingRequest) -> Result<EmbeddingResponse, LlmError> {
+        self.provider.simulate_latency().await;
+        let (scripted, dimension) = {
+            let mut state = self.provider.lock();
+            state.embedding_calls.push(self.call(request.clone()));
+            (state.embedding.pop_front(), state.embedding_dimension)
+        };
+        if let Some(scripted) = scripted {
+            return scripted;
+        }
+        let dimension = request
+            .dimensions
+            .map(|d| d as usize)
+            .or(dimension)
+            .unwrap_or(DEFAULT_EMBEDDING_DIMENSION)
+            .max(1);
+        let embeddings = request
+            .input
+            .iter()
+            .map(|text| hashed_embedding(text, dimension))
+            .collect();
+        Ok(EmbeddingResponse::new(embeddings, self.model_id.clone()))
+    }
+
+    async fn embed_many(
+        &self,
+        requests: BatchEmbeddingRequest,
+    ) -> Result<BatchEmbeddingResponse, LlmError> {
+        let mut responses = Vec::new();
+        for request in requests.requests {
+            let result = EmbeddingModel::embed(self, request)
+                .await
+                .map_err(|e| e.to_string());
+            responses.push(result);
+            if requests.batch_options.fail_fast && responses.last().is_some_and(|r| r.is_err()) {
+                break;
+            }
+        }
+        Ok(BatchEmbeddingResponse {
+            responses,
+            metadata: HashMap::new(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ImageModel for MockModel {
+    async fn generate(
+        &self,
+        request: ImageGenerationRequest,
+    ) -> Result<ImageGenerationResponse, LlmError> {
+        self.provider.simulate_latency().await;
+        let scripted = {
+            let mut state = self.provider.lock();
+            state.image_calls.push(self.call(request.clone()));
+            state.image.pop_front()
+        };
+        if let Some(scripted) = scripted {
+            return scripted;
+        }
+        let images = (0..request.count.max(1))
+            .map(|i| GeneratedImage {
+                url: Some(format!("mock://image/{}/{i}", self.model_id)),
+                b64_json: None,
+                format: None,
+                width: None,
+                height: None,
+                revised_prompt: None,
+                metadata: HashMap::new(),
+            })
+            .collect();
+        Ok(ImageGenerationResponse {
+            images,
+            metadata: HashMap::new(),
+            warnings: None,
+            response: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RerankingModel for MockModel {
+    async fn rerank(&self, request: RerankRequest) -> Result<RerankResponse, LlmError> {
+        self.provider.simulate_latency().await;
+        let scripted = {
+            let mut state = self.provider.lock();
+            state.rerank_calls.push(self.call(request.clone()));
+            state.rerank.pop_front()
+        };
+        if let Some(scripted) = scripted {
+            return scripted;
+        }
+
+        let documents: Vec<String> = match &request.documents {
+            RerankDocuments::Text(values) => values.clone(),
+            RerankDocuments::Object(values) => values.iter().map(|v| v.to_string()).collect(),
+        };
+        let query_terms = terms(&request.query);
+        let mut results: Vec<RerankRankingEntry> = documents
+            .iter()
+            .enumerate()
+            .map(|(index, text)| {
+                let doc_terms = terms(text);
+                let hits = query_terms.iter().filter(|t| doc_terms.contains(t)).count();
+                RerankRankingEntry {
+                    document: request
+                        .return_documents
+                        .unwrap_or(false)
+                        .then(|| RerankDocument { text: text.clone() }),
+                    index: index as u32,
+                    relevance_score: hits as f64 / query_terms.len().max(1) as f64,
+                }
+            })
+            .collect();
+        results.sort_by(|a, b| b.relevance_score.total_cmp(&a.relevance_score));
+        if let Some(top_n) = request.top_n {
+            results.truncate(top_n as usize);
+        }
+        Ok(RerankResponse {
+            id: format!("mock-rerank-{}", self.model_id),
+            results,
+            tokens: RerankTokenUsage {
+                input_tokens: 0,
+                output_tokens: 0,
+            },
+            response: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl SpeechModel for MockModel {
+    /// Fallback audio is the UTF-8 text itself, so it round-trips through transcription.
+    async fn synthesize(&self, request: TtsRequest) -> Result<TtsResponse, LlmError> {
+        self.provider.simulate_latency().await;
+        let scripted = {
+            let mut state = self.provider.lock();
+            state.speech_calls.push(self.call(request.clone()));
+            state.speech.pop_front()
+        };
+        if let Some(scripted) = scripted {
+            return scripted;
+        }
+        Ok(TtsResponse {
+            audio_data: request.text.into_bytes(),
+            format: request.format.unwrap_or_else(|| "mp3".to_string()),
+            duration: None,
+            sample_rate: None,
+            metadata: HashMap::new(),
+            warnings: None,
+            provider_metadata: None,
+            request: None,
+            response: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl TranscriptionModel for MockModel {
+    /// Fallback transcript is the audio bytes decoded as (lossy) UTF-8.
+    async fn transcribe(&self, request: SttRequest) -> Result<SttResponse, LlmError> {
+        self.provider.simulate_latency().await;
+        let scripted = {
+            let mut state = self.provider.lock();
+            state.transcription_calls.push(self.call(request.clone()));
+            state.transcription.pop_front()
+        };
+        if let Some(scripted) = scripted {
+            return scripted;
+        }
+        let bytes = request
+            .audio
+            .as_bytes()
+            .map_err(|e| LlmError::InvalidInput(e.to_string()))?;
+        Ok(SttResponse {
+            text: String::from_utf8_lossy(&bytes).into_owned(),
+            language: None,
+            confidence: None,
+            words: None,
+            segments: None,
+            duration: None,
+            metadata: HashMap::new(),
+            warnings: None,
+            provider_metadata: None,
+            request: None,
+            response: None,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ProviderFactory for MockProvider {
+    async fn language_model_text_with_ctx(
+        &self,
+        model_id: &str,
+        _ctx: &BuildContext,
+    ) -> Result<Arc<dyn crate::text::LanguageModel>, LlmError> {
+        Ok(Arc::new(self.language_model(model_id)))
+    }
+
+    async fn embedding_model_family_with_ctx(
+        &self,
+        model_id: &str,
+        _ctx: &BuildContext,
+    ) -> Result<Arc<dyn EmbeddingModel>, LlmError> {
+        Ok(Arc::new(self.language_model(model_id)))
+    }
+
+    async fn image_model_family_with_ctx(
+        &self,
+        model_id: &str,
+        _ctx: &BuildContext,
+    ) -> Result<Arc<dyn ImageModel>, LlmError> {
+        Ok(Arc::new(self.language_model(model_id)))
+    }
+
+    async fn reranking_model_family_with_ctx(
+        &self,
+        model_id: &str,
+        _ctx: &BuildContext,
+    ) -> Result<Arc<dyn RerankingModel>, LlmError> {
+        Ok(Arc::new(self.language_model(model_id)))
+    }
+
+    async fn speech_model_family_with_ctx(
+        &self,
+        model_id: &str,
+        _ctx: &BuildContext,
+    ) -> Result<Arc<dyn SpeechModel>, LlmError> {
+        Ok(Arc::new(self.language_model(model_id)))
+    }
+
+    async fn transcription_model_family_with_ctx(
+        &self,
+        model_id: &str,
+        _ctx: &BuildContext,
+    ) -> Result<Arc<dyn TranscriptionModel>, LlmError> {
+        Ok(Arc::new(self.language_model(model_id)))
+    }
+
+    fn provider_id(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(MOCK_PROVIDER_ID)
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities::new()
+            .with_chat()
+            .with_streaming()
+            .with_tools()
+            .with_embedding()
+            .with_image_generation()
+            .with_rerank()
+            .with_speech()
+            .with_transcription()
+    }
+}
+
+fn terms(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Bag-of-words feature hashing: texts sharing words get similar (unit-length) vectors.
+fn hashed_embedding(text: &str, dimension: usize) -> Vec<f32> {
+    let mut vector = vec![0.0f32; dimension];
+    for term in terms(text) {
+        let mut hasher = DefaultHasher::new();
+        term.hash(&mut hasher);
+        let hash = hasher.finish();
+        let index = (hash % dimension as u64) as usize;
+        let sign = if hash & (1 << 63) == 0 { 1.0 } else { -1.0 };
+        vector[index] += sign;
+    }
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+    vector
+}
@@ -0,0 +1,210 @@
+//! Scripted chat streams for the mock provider.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures::StreamExt;
+
+use crate::error::LlmError;
+use crate::streaming::ChatStream;
+use crate::types::{
+    ChatResponse, ChatStreamEvent, ContentPart, FinishReason, MessageContent, Usage,
+};
+
+enum Step {
+    Event(Box<ChatStreamEvent>),
+    Error(LlmError),
+    Delay(Duration),
+}
+
+/// A scripted sequence of stream events, errors and delays.
+///
+/// Text, reasoning and tool-call steps are also accumulated so that [`MockStream::finish`] can
+/// close the stream with a `StreamEnd` carrying the matching final [`ChatResponse`].
+#[derive(Default)]
+pub struct MockStream {
+    steps: Vec<Step>,
+    text: String,
+    reasoning: String,
+    tool_calls: Vec<ContentPart>,
+}
+
+impl MockStream {
+    /// Create an empty stream script.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Script that replays a complete response: reasoning, text and tool calls, then finish.
+    pub fn from_response(response: ChatResponse) -> Self {
+        let mut stream = Self::new();
+        for reasoning in response.reasoning() {
+            stream = stream.reasoning(reasoning);
+        }
+        let text = response.content.all_text();
+        if !text.is_empty() {
+            stream = stream.text(text);
+        }
+        for part in response.tool_calls() {
+            if let ContentPart::ToolCall {
+                tool_call_id,
+                tool_name,
+                arguments,
+                ..
+            } = part
+            {
+                stream = stream.event(ChatStreamEvent::tool_call_part(
+                    tool_call_id.clone(),
+                    tool_name.clone(),
+                    arguments.to_string(),
+                ));
+            }
+        }
+        let usage = response.usage.clone().unwrap_or_default();
+        let finish_reason = response.finish_reason.clone().unwrap_or(FinishReason::Stop);
+        stream
+            .event(ChatStreamEvent::finish_part(usage, finish_reason))
+            .event(ChatStreamEvent::StreamEnd { response })
+    }
+
+    /// Emit an arbitrary event.
+    pub fn event(mut self, event: ChatStreamEvent) -> Self {
+        self.steps.push(Step::Event(Box::new(event)));
+        self
+    }
+
+    /// Emit a text delta.
+    pub fn text(mut self, delta: impl Into<String>) -> Self {
+        let delta = delta.into();
+        self.text.push_str(&delta);
+        self.event(ChatStreamEvent::text_delta_part("0", delta))
+    }
+
+    /// Emit a reasoning delta.
+    pub fn reasoning(mut self, delta: impl Into<String>) -> Self {
+        let delta = delta.into();
+        self.reasoning.push_str(&delta);
+        self.event(ChatStreamEvent::reasoning_delta_part("reasoning-0", delta))
+    }
+
+    /// Emit a complete tool call (input start, input delta, input end, call).
+    pub fn tool_call(
+        mut self,
+        tool_call_id: impl Into<String>,
+        tool_name: impl Into<String>,
+        arguments: serde_json::Value,
+    ) -> Self {
+        let id = tool_call_id.into();
+        let name = tool_name.into();
+        let input = arguments.to_string();
+        self.tool_calls.push(ContentPart::tool_call(
+            id.clone(),
+            name.clone(),
+            arguments,
+            None,
+        ));
+        self.event(ChatStreamEvent::tool_input_start_part(
+            id.clone(),
+            name.clone(),
+        ))
+        .event(ChatStreamEvent::tool_input_delta_part(
+            id.clone(),
+            input.clone(),
+        ))
+        .event(ChatStreamEvent::tool_input_end_part(id.clone()))
+        .event(ChatStreamEvent::tool_call_part(id, name, input))
+    }
+
+    /// Yield an error item (the stream continues with the remaining steps, if any).
+    pub fn error(mut self, error: LlmError) -> Self {
+        self.steps.push(Step::Error(error));
+        self
+    }
+
+    /// Pause before the next step.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.steps.push(Step::Delay(delay));
+        self
+    }
+
+    /// Emit a finish part and a `StreamEnd` with the accumulated response.
+    pub fn finish(self, finish_reason: FinishReason) -> Self {
+        self.finish_with_usage(finish_reason, Usage::default())
+    }
+
+    /// Finish with `FinishReason::Stop`, or `ToolCalls` when tool calls were scripted.
+    pub fn finish_stop(self) -> Self {
+        let reason = if self.tool_calls.is_empty() {
+            FinishReason::Stop
+        } else {
+            FinishReason::ToolCalls
+        };
+        self.finish(reason)
+    }
+
+    /// Like [`MockStream::finish`] with explicit usage.
+    pub fn finish_with_usage(self, finish_reason: FinishReason, usage: Usage) -> Self {
+        let mut response = ChatResponse::new(self.accumulated_content());
+        response.usage = Some(usage.clone());
+        response.finish_reason = Some(finish_reason.clone());
+        self.event(ChatStreamEvent::finish_part(usage, finish_reason))
+            .event(ChatStreamEvent::StreamEnd { response })
+    }
+
+    fn accumulated_content(&self) -> MessageContent {
+        if self.reasoning.is_empty() && self.tool_calls.is_empty() {
+            return MessageContent::Text(self.text.clone());
+        }
+        let mut parts = Vec::new();
+        if !self.reasoning.is_empty() {
+            parts.push(ContentPart::reasoning(self.reasoning.clone()));
+        }
+        if !self.text.is_empty() {
+            parts.push(ContentPart::text(self.text.clone()));
+        }
+        parts.extend(self.tool_calls.iter().cloned());
+        MessageContent::MultiModal(parts)
+    }
+
+    /// Collect the scripted response for non-streaming calls.
+    ///
+    /// Returns the `StreamEnd` response when present, otherwise the accumulated content. The
+    /// first scripted error is returned as-is.
+    pub(crate) fn into_response(self) -> Result<ChatResponse, LlmError> {
+        let mut end = None;
+        for step in &self.steps {
+            match step {
+                Step::Error(error) => return Err(error.clone()),
+                Step::Event(event) => {
+                    if let ChatStreamEvent::StreamEnd { response } = event.as_ref() {
+                        end = Some(response.clone());
+                    }
+                }
+                Step::Delay(_) => {}
+            }
+        }
+        Ok(end.unwrap_or_else(|| ChatResponse::new(self.accumulated_content())))
+    }
+
+    pub(crate) fn into_chat_stream(self) -> ChatStream {
+        let steps: VecDeque<Step> = self.steps.into();
+        futures::stream::unfold(steps, |mut steps| async move {
+            loop {
+                match steps.pop_front()? {
+                    Step::Delay(delay) => tokio::time::sleep(delay).await,
+                    Step::Event(event) => return Some((Ok(*event), steps)),
+                    Step::Error(error) => return Some((Err(error), steps)),
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+impl std::fmt::Debug for MockStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MockStream")
+            .field("steps", &self.steps.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use serde_json::json;
+
+use super::*;
+use crate::embedding::EmbeddingModel;
+use crate::traits::ChatCapability;
+use crate::types::{ChatMessage, ChatStreamEvent, RerankRequest};
+use siumai_core::rerank::RerankingModel;
+use siumai_core::speech::SpeechModel;
+use siumai_core::transcription::TranscriptionModel;
+
+#[tokio::test]
+async fn registry_replays_scripted_turns_and_records_requests() {
+    let mock = MockProvider::new();
+    mock.push_tool_call("call_1", "weather", json!({ "city": "Paris" }))
+        .push_text("It is sunny in Paris.");
+
+    let model = mock.registry().language_model("mock:agent-model").unwrap();
+
+    let first = model
+        .chat(vec![ChatMessage::user("Weather in Paris?").build()])
+        .await
+        .unwrap();
+    assert!(first.has_tool_calls());
+    assert_eq!(first.finish_reason, Some(FinishReason::ToolCalls));
+
+    let second = model
+        .chat(vec![ChatMessage::user("and now?").build()])
+        .await
+        .unwrap();
+    assert_eq!(second.content_text(), Some("It is sunny in Paris."));
+
+    let calls = mock.chat_calls();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(calls[0].model_id, "agent-model");
+    assert_eq!(
+        mock.last_chat_request().unwrap().messages[0]
+            .content
+            .all_text(),
+        "and now?"
+    );
+    assert_eq!(mock.pending(MockFamily::Chat), 0);
+
+    let err = model.chat(vec![]).await.unwrap_err();
+    assert!(err.to_string().contains("no scripted chat response"));
+}
+
+#[tokio::test]
+async fn scripted_stream_emits_events_errors_and_final_response() {
+    let mock = MockProvider::new();
+    mock.push_stream(
+        MockStream::new()
+            .reasoning("thinking")
+            .delay(Duration::from_millis(5))
+            .text("Hel")
+            .error(LlmError::StreamError("transient".into()))
+            .text("lo")
+            .tool_call("call_1", "lookup", json!({ "q": "x" }))
+            .finish_stop(),
+    );
+
+    let model = mock.language_model("m");
+    let events: Vec<_> = model
+        .chat_stream(vec![], None)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert!(
+        events
+            .iter()
+            .any(|e| matches!(e, Err(LlmError::StreamError(_))))
+    );
+    let text: String = events
+        .iter()
+        .filter_map(|e| e.as_ref().ok().and_then(|e| e.text_delta()))
+        .collect();
+    assert_eq!(text, "Hello");
+
+    let Some(Ok(ChatStreamEvent::StreamEnd { response })) = events.last() else {
+        panic!("expected StreamEnd last");
+    };
+    assert_eq!(response.reasoning(), vec!["thinking"]);
+    assert_eq!(response.tool_calls().len(), 1);
+    assert_eq!(response.finish_reason, Some(FinishReason::ToolCalls));
+}
+
+#[tokio::test]
+async fn queued_response_is_streamed_and_errors_are_returned() {
+    let mock = MockProvider::new();
+    mock.push_text("streamed")
+        .push_error(MockFamily::Chat, LlmError::rate_limit_error("slow down"));
+
+    let model = mock.language_model("m");
+    let events: Vec<_> = model
+        .chat_stream(vec![], None)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert!(matches!(
+        events.last(),
+        Some(Ok(ChatStreamEvent::StreamEnd { .. }))
+    ));
+
+    let err = model.chat(vec![]).await.unwrap_err();
+    assert!(matches!(err, LlmError::RateLimitError { .. }));
+}
+
+#[tokio::test]
+async fn other_families_fall_back_to_deterministic_responses() {
+    let mock = MockProvider::new().with_embedding_dimension(16);
+    let registry = mock.registry();
+
+    let embeddings = registry
+        .embedding_model("mock:embed")
+        .unwrap()
+        .embed(EmbeddingRequest::new(vec![
+            "red apple".to_string(),
+            "red apple".to_string(),
+        ]))
+        .await
+        .unwrap();
+    assert_eq!(embeddings.embeddings[0].len(), 16);
+    assert_eq!(embeddings.embeddings[0], embeddings.embeddings[1]);
+
+    let ranked = registry
+        .reranking_model("mock:rerank")
+        .unwrap()
+        .rerank(RerankRequest::new(
+            "rerank".to_string(),
+            "rust async".to_string(),
+            vec!["python".to_string(), "async rust runtime".to_string()],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(ranked.results[0].index, 1);
+
+    let speech = registry
+        .speech_model("mock:tts")
+        .unwrap()
+        .synthesize(TtsRequest::new("hello there".to_string()))
+        .await
+        .unwrap();
+    let transcript = registry
+        .transcription_model("mock:stt")
+        .unwrap()
+        .transcribe(SttRequest::from_audio(speech.audio_data, "audio/mpeg"))
+        .await
+        .unwrap();
+    assert_eq!(transcript.text, "hello there");
+
+    assert_eq!(mock.embedding_calls().len(), 1);
+    assert_eq!(mock.rerank_calls()[0].model_id, "rerank");
+    assert_eq!(mock.speech_calls().len(), 1);
+    assert_eq!(mock.transcription_calls().len(), 1);
+}
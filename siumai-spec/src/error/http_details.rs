@@ -0,0 +1,81 @@
+//! Structured HTTP error details
+//!
+//! `HttpErrorDetails` carries what a provider told us about a failed request beyond the message:
+//! HTTP status, provider error code/type, provider request id, a retry-after hint and the raw
+//! response headers. Retry executors use `retry_after` to wait as long as the server asked.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Structured details of a failed provider request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HttpErrorDetails {
+    /// HTTP status code.
+    pub status: Option<u16>,
+    /// Provider error code (for example `"rate_limit_exceeded"` or `"insufficient_quota"`).
+    pub provider_code: Option<String>,
+    /// Provider error type (for example `"rate_limit_error"` or `"RESOURCE_EXHAUSTED"`).
+    pub error_type: Option<String>,
+    /// Provider request id (`x-request-id`, `request-id`, ...).
+    pub request_id: Option<String>,
+    /// How long the server asked us to wait before retrying.
+    pub retry_after: Option<Duration>,
+    /// Raw response headers (lower-cased names).
+    pub headers: HashMap<String, String>,
+}
+
+impl HttpErrorDetails {
+    /// Create empty details.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the HTTP status code.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Set the provider error code.
+    pub fn with_provider_code(mut self, code: impl Into<String>) -> Self {
+        self.provider_code = Some(code.into());
+        self
+    }
+
+    /// Set the provider error type.
+    pub fn with_error_type(mut self, error_type: impl Into<String>) -> Self {
+        self.error_type = Some(error_type.into());
+        self
+    }
+
+    /// Set the provider request id.
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    /// Set the retry-after hint.
+    pub fn with_retry_after(mut self, retry_after: Duration) -> Self {
+        self.retry_after = Some(retry_after);
+        self
+    }
+
+    /// Set the raw response headers.
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Fill fields that are unset here from `other`.
+    pub fn merge(mut self, other: HttpErrorDetails) -> Self {
+        self.status = self.status.or(other.status);
+        self.provider_code = self.provider_code.or(other.provider_code);
+        self.error_type = self.error_type.or(other.error_type);
+        self.request_id = self.request_id.or(other.request_id);
+        self.retry_after = self.retry_after.or(other.retry_after);
+        for (name, value) in other.headers {
+            self.headers.entry(name).or_insert(value);
+        }
+        self
+    }
+}
@@ -3,6 +3,8 @@
 //! This module is intentionally dependency-light and shared across crates.
 
 mod conversions;
+mod http_details;
 pub mod types;
 
+pub use http_details::HttpErrorDetails;
 pub use types::*;
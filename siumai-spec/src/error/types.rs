@@ -6,6 +6,8 @@
 
 use thiserror::Error;
 
+use super::http_details::HttpErrorDetails;
+
 /// The primary error type for the LLM library.
 #[derive(Error, Debug, Clone)]
 pub enum LlmError {
@@ -92,6 +94,8 @@ pub enum LlmError {
         code: u16,
         message: String,
         details: Option<serde_json::Value>,
+        /// HTTP status, provider code, request id, headers and retry-after hint, when known.
+        http_details: Option<Box<HttpErrorDetails>>,
     },
 
     /// Authentication error
@@ -99,12 +103,49 @@ pub enum LlmError {
     AuthenticationError(String),
 
     /// Rate limit error
-    #[error("Rate limit exceeded: {0}")]
-    RateLimitError(String),
+    #[error("Rate limit exceeded: {message}")]
+    RateLimitError {
+        /// Error message.
+        message: String,
+        /// HTTP status, provider code, request id and retry-after hint, when known.
+        details: Option<Box<HttpErrorDetails>>,
+    },
 
     /// Quota exceeded error
-    #[error("Quota exceeded: {0}")]
-    QuotaExceededError(String),
+    #[error("Quota exceeded: {message}")]
+    QuotaExceededError {
+        /// Error message.
+        message: String,
+        /// HTTP status, provider code, request id and retry-after hint, when known.
+        details: Option<Box<HttpErrorDetails>>,
+    },
+
+    /// The prompt does not fit the model's context window.
+    #[error("Context length exceeded: {message}")]
+    ContextLengthExceeded {
+        /// Error message.
+        message: String,
+        /// HTTP status, provider code and request id, when known.
+        details: Option<Box<HttpErrorDetails>>,
+    },
+
+    /// The provider's safety or content filters blocked the prompt or the completion.
+    #[error("Content filtered: {message}")]
+    ContentFiltered {
+        /// Error message.
+        message: String,
+        /// HTTP status, provider code and request id, when known.
+        details: Option<Box<HttpErrorDetails>>,
+    },
+
+    /// The provider is temporarily over capacity.
+    #[error("Provider overloaded: {message}")]
+    Overloaded {
+        /// Error message.
+        message: String,
+        /// HTTP status, provider code, request id and retry-after hint, when known.
+        details: Option<Box<HttpErrorDetails>>,
+    },
 
     /// Model not supported error
     #[error("Model not supported: {0}")]
@@ -158,6 +199,19 @@ pub enum LlmError {
     #[error("Unsupported tool type: {0}")]
     UnsupportedToolType(String),
 
+    /// A guardrail rejected the request before it was sent or the model output after it arrived.
+    #[error("Guardrail violation ({stage}, {policy}): {message}")]
+    GuardrailViolation {
+        /// Where the violation was detected: `"input"` or `"output"`.
+        stage: String,
+        /// Name of the policy that fired (for example `"moderation"`).
+        policy: String,
+        /// Human-readable reason.
+        message: String,
+        /// Flagged categories, when the policy reports any.
+        categories: Vec<String>,
+    },
+
     /// Context-aware error with additional metadata
     #[error("Error in {context}: {message}")]
     ContextualError {
@@ -179,6 +233,7 @@ impl LlmError {
             code,
             message: message.into(),
             details: None,
+            http_details: None,
         }
     }
 
@@ -192,7 +247,102 @@ impl LlmError {
             code,
             message: message.into(),
             details: Some(details),
+            http_details: None,
+        }
+    }
+
+    /// Creates a new rate limit error without structured details.
+    pub fn rate_limit_error(message: impl Into<String>) -> Self {
+        Self::RateLimitError {
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Creates a new quota exceeded error without structured details.
+    pub fn quota_exceeded_error(message: impl Into<String>) -> Self {
+        Self::QuotaExceededError {
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Creates a new context length error without structured details.
+    pub fn context_length_exceeded(message: impl Into<String>) -> Self {
+        Self::ContextLengthExceeded {
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Creates a new content filter error without structured details.
+    pub fn content_filtered(message: impl Into<String>) -> Self {
+        Self::ContentFiltered {
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Creates a new overload error without structured details.
+    pub fn overloaded(message: impl Into<String>) -> Self {
+        Self::Overloaded {
+            message: message.into(),
+            details: None,
+        }
+    }
+
+    /// Attach structured details to an API, rate limit, quota, context length, content filter
+    /// or overload error.
+    ///
+    /// Fields already set on the error win over `details`. Other variants are returned
+    /// unchanged.
+    pub fn with_http_details(mut self, details: HttpErrorDetails) -> Self {
+        if let Self::ApiError {
+            http_details: current,
+            ..
+        }
+        | Self::RateLimitError {
+            details: current, ..
+        }
+        | Self::QuotaExceededError {
+            details: current, ..
+        }
+        | Self::ContextLengthExceeded {
+            details: current, ..
         }
+        | Self::ContentFiltered {
+            details: current, ..
+        }
+        | Self::Overloaded {
+            details: current, ..
+        } = &mut self
+        {
+            let merged = match current.take() {
+                Some(existing) => existing.merge(details),
+                None => details,
+            };
+            *current = Some(Box::new(merged));
+        }
+        self
+    }
+
+    /// Structured details of an API, rate limit, quota, context length, content filter or
+    /// overload error, when known.
+    pub fn http_details(&self) -> Option<&HttpErrorDetails> {
+        match self {
+            Self::ApiError { http_details, .. } => http_details.as_deref(),
+            Self::RateLimitError { details, .. }
+            | Self::QuotaExceededError { details, .. }
+            | Self::ContextLengthExceeded { details, .. }
+            | Self::ContentFiltered { details, .. }
+            | Self::Overloaded { details, .. } => details.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The delay the server asked for before retrying, when known.
+    pub fn retry_after(&self) -> Option<std::time::Duration> {
+        self.http_details().and_then(|details| details.retry_after)
     }
 
     /// Creates a new provider error.
@@ -14,6 +14,7 @@
 //! - **`embedding`** - Embedding request/response types
 //! - **`image`** - Image generation types
 //! - **`audio`** - Audio transcription/generation types
+//! - **`voice`** - Voice cloning/design/deletion types
 //! - **`tools`** - Tool/function calling types
 //! - **`streaming`** - Streaming response types
 //! - **`provider_options/`** - Provider options transport helpers (provider-agnostic)
@@ -92,6 +93,7 @@ pub mod streaming;
 pub mod tools;
 pub mod usage;
 pub mod video;
+pub mod voice;
 
 // Re-export all types for convenience
 pub use ai_sdk::*;
@@ -119,5 +121,6 @@ pub use streaming::*;
 pub use tools::*;
 pub use usage::*;
 pub use video::*;
+pub use voice::*;
 
 // Provider-specific typed metadata types are intentionally owned by provider crates.
@@ -343,6 +343,8 @@ pub struct SttResponse {
     pub confidence: Option<f32>,
     /// Word-level timestamps
     pub words: Option<Vec<WordTimestamp>>,
+    /// Segment-level timestamps (with speaker labels for diarized transcriptions)
+    pub segments: Option<Vec<SttSegment>>,
     /// Duration of audio in seconds
     pub duration: Option<f32>,
     /// Additional metadata
@@ -370,6 +372,48 @@ pub struct WordTimestamp {
     pub confidence: Option<f32>,
 }
 
+/// Segment-level transcription information
+#[derive(Debug, Clone, PartialEq)]
+pub struct SttSegment {
+    /// Segment text
+    pub text: String,
+    /// Start time in seconds
+    pub start: f32,
+    /// End time in seconds
+    pub end: f32,
+    /// Average token log probability, when reported
+    pub avg_logprob: Option<f32>,
+    /// Probability that the segment contains no speech, when reported
+    pub no_speech_prob: Option<f32>,
+    /// Speaker label for diarized transcriptions
+    pub speaker: Option<String>,
+}
+
+impl SttSegment {
+    /// Create a segment with text and timing only.
+    pub fn new(text: impl Into<String>, start: f32, end: f32) -> Self {
+        Self {
+            text: text.into(),
+            start,
+            end,
+            avg_logprob: None,
+            no_speech_prob: None,
+            speaker: None,
+        }
+    }
+
+    /// Attach a speaker label.
+    pub fn with_speaker(mut self, speaker: impl Into<String>) -> Self {
+        self.speaker = Some(speaker.into());
+        self
+    }
+
+    /// Segment duration in seconds.
+    pub fn duration(&self) -> f32 {
+        (self.end - self.start).max(0.0)
+    }
+}
+
 /// Audio translation request (speech to English text)
 #[derive(Debug, Clone)]
 pub struct AudioTranslationRequest {
@@ -485,7 +529,9 @@ impl AudioTranslationRequest {
 }
 
 /// Voice information
-#[derive(Debug, Clone)]
+///
+/// `id` is the value to pass as `TtsRequest::voice`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct VoiceInfo {
     /// Voice ID/name
     pub id: String,
@@ -497,8 +543,55 @@ pub struct VoiceInfo {
     pub language: Option<String>,
     /// Gender (male, female, neutral)
     pub gender: Option<String>,
-    /// Voice category (standard, premium, neural, etc.)
+    /// Voice category (standard, premium, neural, cloned, generated, etc.)
     pub category: Option<String>,
+    /// URL of a short sample clip, when the provider publishes one
+    pub preview_url: Option<String>,
+}
+
+impl VoiceInfo {
+    /// Create a voice entry with an id and display name.
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            description: None,
+            language: None,
+            gender: None,
+            category: None,
+            preview_url: None,
+        }
+    }
+
+    /// Set the voice description.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the language code.
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Set the gender.
+    pub fn with_gender(mut self, gender: impl Into<String>) -> Self {
+        self.gender = Some(gender.into());
+        self
+    }
+
+    /// Set the voice category.
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    /// Set the preview clip URL.
+    pub fn with_preview_url(mut self, preview_url: impl Into<String>) -> Self {
+        self.preview_url = Some(preview_url.into());
+        self
+    }
 }
 
 /// Language information
@@ -0,0 +1,166 @@
+//! Voice management types (cloning, design and deletion of custom voices)
+//!
+//! Voice ids returned by these operations are provider-owned and can be passed
+//! directly as `TtsRequest::voice` to the same provider.
+
+use super::{HttpConfig, ProviderMetadataMap, ProviderOptionsMap};
+
+/// Request payload for cloning a voice from reference audio.
+#[derive(Debug, Clone, Default)]
+pub struct VoiceCloneRequest {
+    /// Reference audio bytes.
+    pub audio: Vec<u8>,
+    /// Optional media type of the reference audio (e.g. "audio/mpeg").
+    pub media_type: Option<String>,
+    /// Optional filename sent with the upload.
+    pub filename: Option<String>,
+    /// Requested voice id. Some providers require callers to choose one.
+    pub voice_id: Option<String>,
+    /// Optional human-readable name.
+    pub name: Option<String>,
+    /// Optional text used to render a preview clip with the new voice.
+    pub preview_text: Option<String>,
+    /// Optional model used to render the preview clip.
+    pub model: Option<String>,
+    /// Optional provider-specific options (`providerOptions`).
+    pub provider_options: ProviderOptionsMap,
+    /// Optional per-request HTTP overrides.
+    pub http_config: Option<HttpConfig>,
+}
+
+impl VoiceCloneRequest {
+    /// Create a request from reference audio bytes.
+    pub fn new(audio: Vec<u8>) -> Self {
+        Self {
+            audio,
+            ..Default::default()
+        }
+    }
+
+    /// Set the reference audio media type.
+    pub fn with_media_type(mut self, media_type: impl Into<String>) -> Self {
+        self.media_type = Some(media_type.into());
+        self
+    }
+
+    /// Set the upload filename.
+    pub fn with_filename(mut self, filename: impl Into<String>) -> Self {
+        self.filename = Some(filename.into());
+        self
+    }
+
+    /// Set the requested voice id.
+    pub fn with_voice_id(mut self, voice_id: impl Into<String>) -> Self {
+        self.voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Set the human-readable name.
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the preview text.
+    pub fn with_preview_text(mut self, preview_text: impl Into<String>) -> Self {
+        self.preview_text = Some(preview_text.into());
+        self
+    }
+
+    /// Set the preview model.
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        self.model = Some(model.into());
+        self
+    }
+
+    /// Insert one provider option entry.
+    pub fn with_provider_option(
+        mut self,
+        provider_id: impl AsRef<str>,
+        value: serde_json::Value,
+    ) -> Self {
+        self.provider_options.insert(provider_id, value);
+        self
+    }
+
+    /// Set per-request HTTP configuration.
+    pub fn with_http_config(mut self, http_config: HttpConfig) -> Self {
+        self.http_config = Some(http_config);
+        self
+    }
+}
+
+/// Request payload for designing a voice from a text description.
+#[derive(Debug, Clone, Default)]
+pub struct VoiceDesignRequest {
+    /// Description of the desired voice (timbre, age, accent, style...).
+    pub prompt: String,
+    /// Optional text used to render a preview clip with the new voice.
+    pub preview_text: Option<String>,
+    /// Requested voice id, when the provider lets callers choose one.
+    pub voice_id: Option<String>,
+    /// Optional provider-specific options (`providerOptions`).
+    pub provider_options: ProviderOptionsMap,
+    /// Optional per-request HTTP overrides.
+    pub http_config: Option<HttpConfig>,
+}
+
+impl VoiceDesignRequest {
+    /// Create a request from a voice description.
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the preview text.
+    pub fn with_preview_text(mut self, preview_text: impl Into<String>) -> Self {
+        self.preview_text = Some(preview_text.into());
+        self
+    }
+
+    /// Set the requested voice id.
+    pub fn with_voice_id(mut self, voice_id: impl Into<String>) -> Self {
+        self.voice_id = Some(voice_id.into());
+        self
+    }
+
+    /// Insert one provider option entry.
+    pub fn with_provider_option(
+        mut self,
+        provider_id: impl AsRef<str>,
+        value: serde_json::Value,
+    ) -> Self {
+        self.provider_options.insert(provider_id, value);
+        self
+    }
+
+    /// Set per-request HTTP configuration.
+    pub fn with_http_config(mut self, http_config: HttpConfig) -> Self {
+        self.http_config = Some(http_config);
+        self
+    }
+}
+
+/// Result of cloning or designing a voice.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VoiceCreationResponse {
+    /// Provider-owned voice id, usable as `TtsRequest::voice`.
+    pub voice_id: String,
+    /// Preview clip bytes, when the provider returns audio inline.
+    pub preview_audio: Option<Vec<u8>>,
+    /// Preview clip URL, when the provider hosts the clip.
+    pub preview_url: Option<String>,
+    /// Provider-owned metadata under the provider id root.
+    pub provider_metadata: Option<ProviderMetadataMap>,
+}
+
+/// Result of deleting a custom voice.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VoiceDeleteResponse {
+    /// Voice id that was deleted
+    pub id: String,
+    /// Whether deletion was successful
+    pub deleted: bool,
+}
@@ -81,7 +81,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 LlmError::HttpError(msg) => {
                     println!("  ❌ HTTP Error: {}", msg);
                 }
-                LlmError::RateLimitError(msg) => {
+                LlmError::RateLimitError { message: msg, .. } => {
                     println!("  ❌ Rate Limited: {}", msg);
                 }
                 _ => {
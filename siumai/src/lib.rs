@@ -205,6 +205,8 @@ pub mod embedding;
 /// High-level file upload helper aligned with AI SDK `uploadFile`.
 pub mod files;
 pub mod image;
+/// Reusable prompt templates with typed variables, partials and versions.
+pub mod prompt_template;
 pub mod rerank;
 /// High-level skill upload helper aligned with AI SDK `uploadSkill`.
 pub mod skills;
@@ -212,10 +214,12 @@ pub mod speech;
 /// Structured output helpers (JSON extraction + parsing).
 pub mod structured_output;
 pub use structured_output::{
-    GenerateObjectOptions, GenerateObjectResult, GenerateObjectSchema, PartialJsonParseResult,
-    PartialJsonParseState, PartialJsonValueStream, PartialJsonValueStreamEvent, RepairTextContext,
-    RepairTextFunction, RepairTextFuture, fix_partial_json, generate_array, generate_choice,
-    generate_enum, generate_json, generate_object, parse_partial_json, partial_json_value_stream,
+    GenerateObjectOptions, GenerateObjectResult, GenerateObjectSchema, JsonArrayElementStream,
+    JsonArrayElementStreamEvent, PartialJsonParseResult, PartialJsonParseState,
+    PartialJsonValueStream, PartialJsonValueStreamEvent, RepairTextContext, RepairTextFunction,
+    RepairTextFuture, fix_partial_json, generate_array, generate_choice, generate_enum,
+    generate_json, generate_object, json_array_element_stream, parse_partial_json,
+    partial_json_value_stream,
 };
 pub mod text;
 pub use text::generate_text;
@@ -422,7 +426,9 @@ pub mod experimental {
     /// Generic token provider contracts remain core-owned. Provider-specific auth helpers are
     /// re-exported from the provider crate that owns them.
     pub mod auth {
-        pub use siumai_core::auth::{StaticTokenProvider, TokenProvider};
+        pub use siumai_core::auth::{
+            ApiKeyPool, ApiKeyUsage, KeyRotationStrategy, StaticTokenProvider, TokenProvider,
+        };
 
         #[cfg(feature = "gcp")]
         pub use siumai_provider_google_vertex::auth::{adc, service_account};
@@ -491,6 +497,13 @@ pub mod experimental {
 
 pub use siumai_registry::registry;
 
+/// Model capability catalog with registry-aware selection.
+pub use siumai_registry::model_capabilities;
+
+/// Scripted mock provider for tests without HTTP (feature: `testing`).
+#[cfg(feature = "testing")]
+pub use siumai_registry::testing;
+
 /// Stable alias for provider-specific extension surface.
 ///
 /// This is a naming convenience for Vercel AI SDK alignment: provider packages expose
@@ -511,17 +524,20 @@ pub mod extensions {
         AudioCapability, EmbeddingCapability, FileManagementCapability, ImageExtras,
         ModelListingCapability, ModerationCapability, MusicGenerationCapability, RerankCapability,
         SkillsCapability, SpeechExtras, TimeoutCapability, TranscriptionExtras,
-        VideoGenerationCapability,
+        VideoGenerationCapability, VoiceManagementCapability,
     };
 
     /// Types used by non-unified extension capabilities.
     pub mod types {
         pub use siumai_core::types::{
-            FileDeleteResponse, FileListQuery, FileListResponse, FileObject, FileUploadRequest,
-            ImageEditInput, ImageEditRequest, ImageVariationRequest, ModerationRequest,
-            ModerationResponse, SkillFileContent, SkillProviderMetadata, SkillUploadFile,
-            SkillUploadRequest, SkillUploadResult, VideoGenerationInput, VideoGenerationRequest,
-            VideoGenerationResponse, VideoTaskStatus, VideoTaskStatusResponse,
+            FileContentStream, FileDeleteResponse, FileListQuery, FileListResponse, FileObject,
+            FileStreamUploadRequest, FileUploadRequest, FileUploadSource, ImageEditInput,
+            ImageEditRequest, ImageVariationRequest, ModerationRequest, ModerationResponse,
+            SkillFileContent, SkillProviderMetadata, SkillUploadFile, SkillUploadRequest,
+            SkillUploadResult, UploadProgressCallback, UploadSession, VideoGenerationInput,
+            VideoGenerationRequest, VideoGenerationResponse, VideoTaskStatus,
+            VideoTaskStatusResponse, VoiceCloneRequest, VoiceCreationResponse, VoiceDeleteResponse,
+            VoiceDesignRequest, VoiceInfo,
         };
     }
 }
@@ -551,10 +567,11 @@ pub mod prelude {
     pub mod unified {
         pub use crate::structured_output::{
             GenerateObjectOptions, GenerateObjectResult, GenerateObjectSchema,
-            PartialJsonParseResult, PartialJsonParseState, PartialJsonValueStream,
-            PartialJsonValueStreamEvent, RepairTextContext, RepairTextFunction, RepairTextFuture,
-            fix_partial_json, generate_array, generate_choice, generate_enum, generate_json,
-            generate_object, parse_partial_json, partial_json_value_stream,
+            JsonArrayElementStream, JsonArrayElementStreamEvent, PartialJsonParseResult,
+            PartialJsonParseState, PartialJsonValueStream, PartialJsonValueStreamEvent,
+            RepairTextContext, RepairTextFunction, RepairTextFuture, fix_partial_json,
+            generate_array, generate_choice, generate_enum, generate_json, generate_object,
+            json_array_element_stream, parse_partial_json, partial_json_value_stream,
         };
         pub use crate::tools;
         pub use crate::{
@@ -588,7 +605,7 @@ pub mod prelude {
         };
         pub use crate::{system, tool, user, user_with_image};
         pub use siumai_core::completion::CompletionModel;
-        pub use siumai_core::error::{ErrorCategory, LlmError, LlmErrorExt};
+        pub use siumai_core::error::{ErrorCategory, HttpErrorDetails, LlmError, LlmErrorExt};
         pub use siumai_core::image::{ImageModel, ImageModelV4};
         pub use siumai_core::rerank::RerankingModel;
         pub use siumai_core::speech::SpeechModel;
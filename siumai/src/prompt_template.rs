@@ -0,0 +1,5 @@
+//! Prompt templates (typed variables, partials, versioned libraries).
+//!
+//! This module re-exports `siumai_core::prompt_template` for the facade crate.
+
+pub use siumai_core::prompt_template::*;
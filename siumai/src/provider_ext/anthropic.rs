@@ -108,3 +108,6 @@ pub mod resources {
 
 // Legacy Anthropic parameter structs (provider-owned).
 pub use siumai_provider_anthropic::params::anthropic::{AnthropicParams, CacheControl};
+
+/// Prompt-cache marker for `PromptCacheMiddleware` (automatic `cacheControl` breakpoints).
+pub use siumai_provider_anthropic::providers::anthropic::cache::AnthropicPromptCacheMarker;
@@ -42,9 +42,9 @@ pub mod options {
         AmazonBedrockRerankingModelOptions, BedrockCachePoint, BedrockCachePointConfig,
         BedrockCachePointType, BedrockCacheTtl, BedrockChatOptions, BedrockEmbeddingInputType,
         BedrockEmbeddingOptions, BedrockEmbeddingPurpose, BedrockEmbeddingTruncate,
-        BedrockFilePartCitations, BedrockFilePartProviderOptions, BedrockProviderOptions,
-        BedrockReasoningConfig, BedrockReasoningEffort, BedrockReasoningType, BedrockRerankOptions,
-        BedrockRerankingOptions, BedrockServiceTier,
+        BedrockFilePartCitations, BedrockFilePartProviderOptions, BedrockPromptCacheMarker,
+        BedrockProviderOptions, BedrockReasoningConfig, BedrockReasoningEffort,
+        BedrockReasoningType, BedrockRerankOptions, BedrockRerankingOptions, BedrockServiceTier,
     };
     pub use siumai_provider_amazon_bedrock::providers::bedrock::{
         BedrockChatRequestExt, BedrockEmbeddingRequestExt, BedrockMessageExt,
@@ -69,8 +69,8 @@ pub use options::{
     BedrockCachePointType, BedrockCacheTtl, BedrockChatOptions, BedrockChatRequestExt,
     BedrockEmbeddingInputType, BedrockEmbeddingOptions, BedrockEmbeddingPurpose,
     BedrockEmbeddingRequestExt, BedrockEmbeddingTruncate, BedrockFilePartCitations,
-    BedrockFilePartProviderOptions, BedrockMessageExt, BedrockProviderOptions,
-    BedrockReasoningConfig, BedrockReasoningEffort, BedrockReasoningType,
+    BedrockFilePartProviderOptions, BedrockMessageExt, BedrockPromptCacheMarker,
+    BedrockProviderOptions, BedrockReasoningConfig, BedrockReasoningEffort, BedrockReasoningType,
     BedrockRequestContentPartExt, BedrockRerankOptions, BedrockRerankRequestExt,
     BedrockRerankingOptions, BedrockServiceTier,
 };
@@ -95,6 +95,15 @@ pub mod ext {
     };
 }
 
+/// Live API (`BidiGenerateContent`) sessions.
+#[cfg(feature = "gemini-live")]
+pub mod live {
+    pub use siumai_provider_gemini::providers::gemini::live::{
+        GeminiLiveConfig, GeminiLiveEvent, GeminiLiveFunctionCall, GeminiLiveFunctionResponse,
+        GeminiLiveReceiver, GeminiLiveSender, GeminiLiveSession, live_ws_url,
+    };
+}
+
 /// Provider-specific resources not covered by the unified families.
 pub mod resources {
     pub use siumai_provider_gemini::providers::gemini::{
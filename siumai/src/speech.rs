@@ -2,9 +2,12 @@
 //!
 //! This is the recommended Rust-first surface for TTS:
 //! - `synthesize`
+//! - `speak_chat_stream` / `speak_chat_stream_with_extras`
 //!
 //! `synthesize` accepts the metadata-bearing `SpeechModel` family trait and returns
 //! a high-level helper result closer in role to AI SDK `generateSpeech()`.
+//! `speak_chat_stream` pipes a `ChatStream` into sentence-chunked synthesis and
+//! returns one ordered, cancellable audio stream.
 
 use crate::request_options::{EffectiveRequestOptions, retry_or_call_with_abort};
 use crate::retry_api::RetryOptions;
@@ -19,6 +22,10 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 pub use siumai_core::speech::SpeechModel;
+pub use siumai_core::speech::pipeline::{
+    SentenceSegmenter, SpeechPipelineOptions, SpeechStreamHandle, speak_chat_stream,
+    speak_chat_stream_with_extras,
+};
 pub use siumai_core::types::{TtsRequest, TtsResponse};
 
 /// Options for `speech::synthesize`.
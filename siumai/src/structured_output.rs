@@ -20,8 +20,9 @@ use std::sync::Arc;
 use crate::text::{GenerateOptions, LanguageModel, TextRequest};
 
 pub use siumai_core::structured_output::{
+    JsonArrayElementScanner, JsonArrayElementStream, JsonArrayElementStreamEvent,
     PartialJsonParseResult, PartialJsonParseState, PartialJsonValueStream,
-    PartialJsonValueStreamEvent,
+    PartialJsonValueStreamEvent, ScannedJsonArrayElement,
 };
 
 /// Context passed to a structured-output repair callback.
@@ -531,6 +532,11 @@ pub fn partial_json_value_stream(stream: ChatStream) -> PartialJsonValueStream {
     siumai_core::structured_output::partial_json_value_stream(stream)
 }
 
+/// Emit each completed element of a streamed JSON array exactly once.
+pub fn json_array_element_stream(stream: ChatStream) -> JsonArrayElementStream {
+    siumai_core::structured_output::json_array_element_stream(stream)
+}
+
 /// Extract a `serde_json::Value` from a unified chat response.
 pub fn extract_json_value_from_response(
     response: &ChatResponse,
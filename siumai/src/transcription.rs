@@ -2,9 +2,14 @@
 //!
 //! This is the recommended Rust-first surface for STT:
 //! - `transcribe`
+//! - `transcribe_long`
+//! - `to_srt` / `to_webvtt`
 //!
 //! `transcribe` accepts the metadata-bearing `TranscriptionModel` family trait and
 //! returns a high-level helper result closer in role to AI SDK `transcribe()`.
+//! `transcribe_long` splits recordings that exceed provider upload limits into
+//! overlapping chunks and stitches the chunk transcripts back together.
+//! `to_srt` and `to_webvtt` render transcript segments as subtitles.
 
 use crate::request_options::{EffectiveRequestOptions, retry_or_call_with_abort};
 use crate::retry_api::RetryOptions;
@@ -16,8 +21,15 @@ use siumai_core::types::{
 use std::collections::HashMap;
 use std::time::Duration;
 
+mod long_form;
+mod subtitles;
+
+pub use long_form::{
+    AudioDecoder, DecodedAudio, LongTranscriptionOptions, decode_wav, transcribe_long,
+};
 pub use siumai_core::transcription::TranscriptionModel;
-pub use siumai_core::types::{AudioTranslationRequest, SttRequest, SttResponse};
+pub use siumai_core::types::{AudioTranslationRequest, SttRequest, SttResponse, SttSegment};
+pub use subtitles::{SubtitleOptions, to_srt, to_webvtt};
 
 /// Options for `transcription::transcribe`.
 #[derive(Debug, Clone, Default)]
@@ -48,6 +60,38 @@ pub struct TranscriptionSegment {
     pub start_second: f32,
     /// Segment end time in seconds.
     pub end_second: f32,
+    /// Speaker label for diarized transcriptions.
+    pub speaker: Option<String>,
+    /// Average token log probability, when reported.
+    pub avg_logprob: Option<f32>,
+    /// Probability that the segment contains no speech, when reported.
+    pub no_speech_prob: Option<f32>,
+}
+
+impl From<SttSegment> for TranscriptionSegment {
+    fn from(segment: SttSegment) -> Self {
+        Self {
+            text: segment.text,
+            start_second: segment.start,
+            end_second: segment.end,
+            speaker: segment.speaker,
+            avg_logprob: segment.avg_logprob,
+            no_speech_prob: segment.no_speech_prob,
+        }
+    }
+}
+
+impl From<TranscriptionSegment> for SttSegment {
+    fn from(segment: TranscriptionSegment) -> Self {
+        Self {
+            text: segment.text,
+            start: segment.start_second,
+            end: segment.end_second,
+            avg_logprob: segment.avg_logprob,
+            no_speech_prob: segment.no_speech_prob,
+            speaker: segment.speaker,
+        }
+    }
 }
 
 /// High-level transcription helper result, closer to AI SDK `TranscriptionResult`.
@@ -95,6 +139,8 @@ impl TranscriptionResult {
             language: self.language,
             confidence: self.confidence,
             words: self.words,
+            segments: (!self.segments.is_empty())
+                .then(|| self.segments.into_iter().map(SttSegment::from).collect()),
             duration: self.duration,
             metadata: self.metadata,
             request: self.request,
@@ -154,9 +200,14 @@ fn parse_segment_time(
 }
 
 fn extract_transcription_segments(
+    segments: Option<&[SttSegment]>,
     metadata: &HashMap<String, serde_json::Value>,
     words: Option<&[WordTimestamp]>,
 ) -> Vec<TranscriptionSegment> {
+    if let Some(segments) = segments.filter(|segments| !segments.is_empty()) {
+        return segments.iter().cloned().map(Into::into).collect();
+    }
+
     let from_metadata = metadata
         .get("segments")
         .and_then(|value| value.as_array())
@@ -172,6 +223,12 @@ fn extract_transcription_segments(
                         text,
                         start_second: start,
                         end_second: end,
+                        speaker: object
+                            .get("speaker")
+                            .and_then(|value| value.as_str())
+                            .map(str::to_string),
+                        avg_logprob: None,
+                        no_speech_prob: None,
                     })
                 })
                 .collect::<Vec<_>>()
@@ -190,6 +247,9 @@ fn extract_transcription_segments(
                     text: word.word.clone(),
                     start_second: word.start,
                     end_second: word.end,
+                    speaker: None,
+                    avg_logprob: None,
+                    no_speech_prob: None,
                 })
                 .collect()
         })
@@ -200,7 +260,11 @@ fn transcription_result_from_response(
     provider_id: &str,
     response: SttResponse,
 ) -> TranscriptionResult {
-    let segments = extract_transcription_segments(&response.metadata, response.words.as_deref());
+    let segments = extract_transcription_segments(
+        response.segments.as_deref(),
+        &response.metadata,
+        response.words.as_deref(),
+    );
     let provider_metadata = provider_metadata_from_legacy_audio_metadata(
         provider_id,
         &response.metadata,
@@ -264,6 +328,16 @@ pub async fn experimental_transcribe<M: TranscriptionModel + ?Sized>(
     transcribe(model, request, options).await
 }
 
+/// Whether `c` belongs to a script written without spaces between words (CJK, kana, Hangul).
+fn is_unspaced_script(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30ff}'
+        | '\u{3400}'..='\u{4dbf}'
+        | '\u{4e00}'..='\u{9fff}'
+        | '\u{ac00}'..='\u{d7af}'
+        | '\u{f900}'..='\u{faff}')
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -290,6 +364,7 @@ mod tests {
                 language: None,
                 confidence: None,
                 words: None,
+                segments: None,
                 duration: None,
                 metadata: HashMap::from([("requestId".to_string(), serde_json::json!("req_1"))]),
                 request: None,
@@ -361,6 +436,7 @@ mod tests {
                         confidence: Some(0.93),
                     },
                 ]),
+                segments: None,
                 duration: Some(1.0),
                 metadata: HashMap::from([
                     ("requestId".to_string(), serde_json::json!("req_2")),
@@ -0,0 +1,891 @@
+//! Long-form transcription with automatic segmentation.
+//!
+//! Hosted speech-to-text endpoints cap uploads (around 25 MB for the common Whisper-style APIs),
+//! so `transcribe_long` decodes the input, splits it into overlapping WAV chunks under the limit,
+//! transcribes the chunks concurrently and stitches the results:
+//! - text in overlap regions is de-duplicated by aligning the token run shared by adjacent chunks;
+//! - word timestamps and segments (typed and legacy `segments` metadata) are shifted to global
+//!   time, and each overlap keeps the items of whichever chunk owns that side of the overlap
+//!   midpoint.
+//!
+//! WAV and raw 16-bit PCM are decoded natively. Compressed formats need an [`AudioDecoder`];
+//! inputs that already fit the limits are sent unchanged, so no decoder is needed for them.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::{StreamExt, TryStreamExt};
+use siumai_core::error::LlmError;
+use siumai_core::types::{AudioInputData, WordTimestamp, merge_provider_metadata};
+
+use super::{
+    SttRequest, SttResponse, SttSegment, TranscribeOptions, TranscriptionModel,
+    TranscriptionResult, apply_stt_call_options, is_unspaced_script, transcribe,
+    transcription_result_from_response,
+};
+use crate::request_options::{EffectiveRequestOptions, retry_or_call_with_abort};
+
+/// Size of the canonical PCM WAV header written for each chunk.
+const WAV_HEADER_LEN: usize = 44;
+/// Number of tokens compared at each chunk boundary when de-duplicating overlap text.
+const OVERLAP_TOKEN_WINDOW: usize = 48;
+
+/// Decoded audio as interleaved signed 16-bit PCM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedAudio {
+    /// Sample rate in Hz.
+    pub sample_rate: u32,
+    /// Number of interleaved channels.
+    pub channels: u16,
+    /// Interleaved samples.
+    pub samples: Vec<i16>,
+}
+
+impl DecodedAudio {
+    /// Number of sample frames (samples per channel).
+    pub fn frames(&self) -> usize {
+        self.samples.len() / usize::from(self.channels.max(1))
+    }
+
+    /// Duration in seconds.
+    pub fn duration_seconds(&self) -> f32 {
+        self.frames() as f32 / self.sample_rate.max(1) as f32
+    }
+
+    /// Encode the frames in `start..end` as a 16-bit PCM WAV file.
+    pub fn to_wav(&self, start_frame: usize, end_frame: usize) -> Vec<u8> {
+        let channels = usize::from(self.channels.max(1));
+        let end_frame = end_frame.min(self.frames());
+        let start_frame = start_frame.min(end_frame);
+        let samples = &self.samples[start_frame * channels..end_frame * channels];
+
+        let data_len = (samples.len() * 2) as u32;
+        let block_align = self.channels.max(1) * 2;
+        let mut wav = Vec::with_capacity(WAV_HEADER_LEN + samples.len() * 2);
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(36 + data_len).to_le_bytes());
+        wav.extend_from_slice(b"WAVEfmt ");
+        wav.extend_from_slice(&16u32.to_le_bytes());
+        wav.extend_from_slice(&1u16.to_le_bytes());
+        wav.extend_from_slice(&self.channels.max(1).to_le_bytes());
+        wav.extend_from_slice(&self.sample_rate.to_le_bytes());
+        wav.extend_from_slice(&(self.sample_rate * u32::from(block_align)).to_le_bytes());
+        wav.extend_from_slice(&block_align.to_le_bytes());
+        wav.extend_from_slice(&16u16.to_le_bytes());
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        for sample in samples {
+            wav.extend_from_slice(&sample.to_le_bytes());
+        }
+        wav
+    }
+}
+
+/// Decoder hook for audio formats that are not decoded natively (MP3, M4A, Ogg, ...).
+pub trait AudioDecoder: Send + Sync {
+    /// Decode `audio` (with IANA `media_type`) into interleaved 16-bit PCM.
+    fn decode(&self, audio: &[u8], media_type: &str) -> Result<DecodedAudio, LlmError>;
+}
+
+/// Options for [`transcribe_long`].
+#[derive(Clone)]
+pub struct LongTranscriptionOptions {
+    /// Maximum encoded size of one chunk in bytes (WAV header included).
+    pub max_chunk_bytes: usize,
+    /// Optional maximum chunk duration, for providers that also cap audio length.
+    pub max_chunk_duration: Option<Duration>,
+    /// Audio shared by adjacent chunks so words on a boundary are heard whole at least once.
+    pub overlap: Duration,
+    /// Maximum number of chunks transcribed concurrently (at least 1).
+    pub max_concurrency: usize,
+    /// Sample rate and channel count for raw PCM input without `rate`/`channels` parameters.
+    pub pcm_format: Option<(u32, u16)>,
+    /// Decoder for compressed formats.
+    pub decoder: Option<Arc<dyn AudioDecoder>>,
+    /// Retry, timeout and header options applied to every chunk request.
+    pub transcribe: TranscribeOptions,
+}
+
+impl std::fmt::Debug for LongTranscriptionOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LongTranscriptionOptions")
+            .field("max_chunk_bytes", &self.max_chunk_bytes)
+            .field("max_chunk_duration", &self.max_chunk_duration)
+            .field("overlap", &self.overlap)
+            .field("max_concurrency", &self.max_concurrency)
+            .field("pcm_format", &self.pcm_format)
+            .field("decoder", &self.decoder.as_ref().map(|_| "<decoder>"))
+            .field("transcribe", &self.transcribe)
+            .finish()
+    }
+}
+
+impl Default for LongTranscriptionOptions {
+    fn default() -> Self {
+        Self {
+            max_chunk_bytes: 24 * 1024 * 1024,
+            max_chunk_duration: None,
+            overlap: Duration::from_secs(2),
+            max_concurrency: 4,
+            pcm_format: None,
+            decoder: None,
+            transcribe: TranscribeOptions::default(),
+        }
+    }
+}
+
+impl LongTranscriptionOptions {
+    /// Set the maximum encoded chunk size in bytes.
+    pub fn with_max_chunk_bytes(mut self, max_chunk_bytes: usize) -> Self {
+        self.max_chunk_bytes = max_chunk_bytes;
+        self
+    }
+
+    /// Set the maximum chunk duration.
+    pub fn with_max_chunk_duration(mut self, max_chunk_duration: Duration) -> Self {
+        self.max_chunk_duration = Some(max_chunk_duration);
+        self
+    }
+
+    /// Set the overlap between adjacent chunks.
+    pub fn with_overlap(mut self, overlap: Duration) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// Set the number of chunks transcribed concurrently.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Describe raw PCM input (`audio/pcm` is little-endian, `audio/L16` big-endian).
+    pub fn with_pcm_format(mut self, sample_rate: u32, channels: u16) -> Self {
+        self.pcm_format = Some((sample_rate, channels));
+        self
+    }
+
+    /// Plug in a decoder for compressed formats.
+    pub fn with_decoder(mut self, decoder: Arc<dyn AudioDecoder>) -> Self {
+        self.decoder = Some(decoder);
+        self
+    }
+
+    /// Set per-chunk retry/timeout/header options.
+    pub fn with_transcribe_options(mut self, transcribe: TranscribeOptions) -> Self {
+        self.transcribe = transcribe;
+        self
+    }
+}
+
+/// Transcribe audio of any length by splitting it into overlapping chunks.
+///
+/// Inputs within `max_chunk_bytes` (and `max_chunk_duration`, when set) are forwarded to
+/// [`transcribe`] unchanged. Longer inputs are decoded, split, transcribed with up to
+/// `max_concurrency` requests in flight, and merged into one result with global timings.
+pub async fn transcribe_long<M: TranscriptionModel + ?Sized>(
+    model: &M,
+    mut request: SttRequest,
+    options: LongTranscriptionOptions,
+) -> Result<TranscriptionResult, LlmError> {
+    let audio = request
+        .audio
+        .as_bytes()
+        .map_err(|e| LlmError::InvalidInput(format!("invalid transcription audio: {e}")))?;
+    if audio.len() <= options.max_chunk_bytes && options.max_chunk_duration.is_none() {
+        return transcribe(model, request, options.transcribe).await;
+    }
+
+    let decoded = decode_audio(&audio, &request.media_type, &options)?;
+    let chunks = plan_chunks(&decoded, &options)?;
+    if chunks.len() == 1 && audio.len() <= options.max_chunk_bytes {
+        return transcribe(model, request, options.transcribe).await;
+    }
+    drop(audio);
+
+    let transcribe_options = options.transcribe;
+    let effective = EffectiveRequestOptions::from_parts(
+        transcribe_options.request_options,
+        transcribe_options.retry,
+        transcribe_options.timeout,
+        transcribe_options.headers,
+    );
+    request.audio = AudioInputData::binary(Vec::new());
+    request.media_type = "audio/wav".to_string();
+    request.format = Some("wav".to_string());
+    let template = apply_stt_call_options(request, effective.timeout(), effective.headers());
+
+    let rate = decoded.sample_rate.max(1) as f32;
+    let effective = &effective;
+    let template = &template;
+    let decoded = &decoded;
+    let transcripts: Vec<ChunkTranscript> = futures::stream::iter(chunks)
+        .map(|(start_frame, end_frame)| async move {
+            let mut chunk_request = template.clone();
+            chunk_request.audio = AudioInputData::binary(decoded.to_wav(start_frame, end_frame));
+            let response =
+                retry_or_call_with_abort(effective.retry(), effective.abort_signal(), || {
+                    let req = chunk_request.clone();
+                    async move { model.transcribe(req).await }
+                })
+                .await?;
+            Ok::<_, LlmError>(ChunkTranscript {
+                start: start_frame as f32 / rate,
+                end: end_frame as f32 / rate,
+                response,
+            })
+        })
+        .buffered(options.max_concurrency.max(1))
+        .try_collect()
+        .await?;
+
+    let mut response = stitch_transcripts(transcripts);
+    response.duration = Some(decoded.duration_seconds());
+    if response.text.trim().is_empty() {
+        return Err(LlmError::NoTranscriptGenerated {
+            responses: response.response.clone().into_iter().collect(),
+        });
+    }
+
+    Ok(transcription_result_from_response(
+        model.provider_id(),
+        response,
+    ))
+}
+
+fn decode_audio(
+    audio: &[u8],
+    media_type: &str,
+    options: &LongTranscriptionOptions,
+) -> Result<DecodedAudio, LlmError> {
+    let mut parts = media_type.split(';').map(str::trim);
+    let essence = parts.next().unwrap_or_default().to_ascii_lowercase();
+    let params: HashMap<String, String> = parts
+        .filter_map(|param| param.split_once('='))
+        .map(|(key, value)| (key.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+
+    match essence.as_str() {
+        "audio/wav" | "audio/x-wav" | "audio/wave" | "audio/vnd.wave" => decode_wav(audio),
+        "audio/pcm" | "audio/l16" => {
+            let (default_rate, default_channels) = options.pcm_format.unwrap_or((0, 1));
+            let sample_rate = params
+                .get("rate")
+                .and_then(|rate| rate.parse().ok())
+                .unwrap_or(default_rate);
+            let channels = params
+                .get("channels")
+                .and_then(|channels| channels.parse().ok())
+                .unwrap_or(default_channels);
+            if sample_rate == 0 || channels == 0 {
+                return Err(LlmError::InvalidInput(format!(
+                    "raw PCM audio ({media_type}) needs a sample rate: add a `rate` parameter or set LongTranscriptionOptions::pcm_format"
+                )));
+            }
+            let big_endian = essence == "audio/l16";
+            let samples = audio
+                .chunks_exact(2)
+                .map(|pair| {
+                    let bytes = [pair[0], pair[1]];
+                    if big_endian {
+                        i16::from_be_bytes(bytes)
+                    } else {
+                        i16::from_le_bytes(bytes)
+                    }
+                })
+                .collect();
+            Ok(DecodedAudio {
+                sample_rate,
+                channels,
+                samples,
+            })
+        }
+        _ => match &options.decoder {
+            Some(decoder) => decoder.decode(audio, media_type),
+            None => Err(LlmError::UnsupportedOperation(format!(
+                "long-form transcription cannot split {media_type} audio natively; \
+                 provide an AudioDecoder via LongTranscriptionOptions::with_decoder"
+            ))),
+        },
+    }
+}
+
+/// Decode a RIFF/WAVE file (integer PCM 8/16/24/32-bit or IEEE float) into 16-bit PCM.
+pub fn decode_wav(bytes: &[u8]) -> Result<DecodedAudio, LlmError> {
+    let invalid = |reason: &str| LlmError::InvalidInput(format!("invalid WAV audio: {reason}"));
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err(invalid("missing RIFF/WAVE header"));
+    }
+
+    let mut format: Option<(u16, u16, u32, u16)> = None;
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let declared = u32::from_le_bytes([
+            bytes[offset + 4],
+            bytes[offset + 5],
+            bytes[offset + 6],
+            bytes[offset + 7],
+        ]) as usize;
+        let body_start = offset + 8;
+        // Streaming writers may leave the data size unset; clamp to what is present.
+        let body_end = body_start.saturating_add(declared).min(bytes.len());
+        let body = &bytes[body_start..body_end];
+
+        match id {
+            b"fmt " => {
+                if body.len() < 16 {
+                    return Err(invalid("truncated fmt chunk"));
+                }
+                let mut tag = u16::from_le_bytes([body[0], body[1]]);
+                let channels = u16::from_le_bytes([body[2], body[3]]);
+                let sample_rate = u32::from_le_bytes([body[4], body[5], body[6], body[7]]);
+                let bits = u16::from_le_bytes([body[14], body[15]]);
+                // WAVE_FORMAT_EXTENSIBLE stores the real format in the sub-format GUID.
+                if tag == 0xFFFE && body.len() >= 26 {
+                    tag = u16::from_le_bytes([body[24], body[25]]);
+                }
+                format = Some((tag, channels, sample_rate, bits));
+            }
+            b"data" => {
+                let (tag, channels, sample_rate, bits) =
+                    format.ok_or_else(|| invalid("data chunk before fmt chunk"))?;
+                if channels == 0 || sample_rate == 0 {
+                    return Err(invalid("zero channels or sample rate"));
+                }
+                let samples = decode_wav_samples(body, tag, bits).ok_or_else(|| {
+                    invalid(&format!("unsupported sample format {tag} with {bits} bits"))
+                })?;
+                return Ok(DecodedAudio {
+                    sample_rate,
+                    channels,
+                    samples,
+                });
+            }
+            _ => {}
+        }
+        offset = body_start.saturating_add(declared + (declared & 1));
+    }
+    Err(invalid("missing data chunk"))
+}
+
+fn decode_wav_samples(data: &[u8], tag: u16, bits: u16) -> Option<Vec<i16>> {
+    let float_to_i16 = |value: f64| (value.clamp(-1.0, 1.0) * f64::from(i16::MAX)) as i16;
+    let samples = match (tag, bits) {
+        (1, 8) => data.iter().map(|b| (i16::from(*b) - 128) << 8).collect(),
+        (1, 16) => data
+            .chunks_exact(2)
+            .map(|s| i16::from_le_bytes([s[0], s[1]]))
+            .collect(),
+        (1, 24) => data
+            .chunks_exact(3)
+            .map(|s| i16::from_le_bytes([s[1], s[2]]))
+            .collect(),
+        (1, 32) => data
+            .chunks_exact(4)
+            .map(|s| i16::from_le_bytes([s[2], s[3]]))
+            .collect(),
+        (3, 32) => data
+            .chunks_exact(4)
+            .map(|s| float_to_i16(f64::from(f32::from_le_bytes([s[0], s[1], s[2], s[3]]))))
+            .collect(),
+        (3, 64) => data
+            .chunks_exact(8)
+            .map(|s| {
+                float_to_i16(f64::from_le_bytes([
+                    s[0], s[1], s[2], s[3], s[4], s[5], s[6], s[7],
+                ]))
+            })
+            .collect(),
+        _ => return None,
+    };
+    Some(samples)
+}
+
+/// Frame ranges of the chunks to transcribe.
+fn plan_chunks(
+    audio: &DecodedAudio,
+    options: &LongTranscriptionOptions,
+) -> Result<Vec<(usize, usize)>, LlmError> {
+    let frame_bytes = usize::from(audio.channels.max(1)) * 2;
+    let mut chunk_frames = options.max_chunk_bytes.saturating_sub(WAV_HEADER_LEN) / frame_bytes;
+    if let Some(max_duration) = options.max_chunk_duration {
+        let duration_frames =
+            (max_duration.as_secs_f64() * f64::from(audio.sample_rate)).floor() as usize;
+        chunk_frames = chunk_frames.min(duration_frames);
+    }
+    if chunk_frames == 0 {
+        return Err(LlmError::InvalidParameter(
+            "long-form transcription chunk limits leave no room for audio".to_string(),
+        ));
+    }
+    let overlap_frames = ((options.overlap.as_secs_f64() * f64::from(audio.sample_rate)) as usize)
+        .min(chunk_frames / 2);
+    let step = chunk_frames - overlap_frames;
+
+    let total = audio.frames();
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    loop {
+        let end = (start + chunk_frames).min(total);
+        chunks.push((start, end));
+        if end >= total {
+            break;
+        }
+        start += step;
+    }
+    Ok(chunks)
+}
+
+/// A chunk transcript with its position in the source audio (seconds).
+#[derive(Debug, Clone)]
+struct ChunkTranscript {
+    start: f32,
+    end: f32,
+    response: SttResponse,
+}
+
+/// Merge chunk transcripts (in order) into one response with global timings.
+fn stitch_transcripts(chunks: Vec<ChunkTranscript>) -> SttResponse {
+    // Each chunk owns the time between the midpoints of its overlaps with its neighbours.
+    let bounds: Vec<(f32, f32)> = (0..chunks.len())
+        .map(|i| {
+            let lower = if i == 0 {
+                f32::NEG_INFINITY
+            } else {
+                (chunks[i].start + chunks[i - 1].end) / 2.0
+            };
+            let upper = chunks
+                .get(i + 1)
+                .map_or(f32::INFINITY, |next| (next.start + chunks[i].end) / 2.0);
+            (lower, upper)
+        })
+        .collect();
+    let owns = |i: usize, start: f32, end: f32| {
+        let mid = (start + end) / 2.0;
+        mid >= bounds[i].0 && mid < bounds[i].1
+    };
+
+    let mut text = String::new();
+    let mut words: Option<Vec<WordTimestamp>> = None;
+    let mut typed_segments: Option<Vec<SttSegment>> = None;
+    let mut segments: Option<Vec<serde_json::Value>> = None;
+    let mut language = None;
+    let mut confidences = Vec::new();
+    let mut metadata = HashMap::new();
+    let mut warnings = Vec::new();
+    let mut provider_metadata = None;
+    let mut request = None;
+    let mut response = None;
+
+    for (i, chunk) in chunks.into_iter().enumerate() {
+        let offset = chunk.start;
+        let mut chunk_response = chunk.response;
+
+        text = merge_overlapping_text(&text, &chunk_response.text);
+
+        if let Some(chunk_words) = chunk_response.words.take() {
+            words.get_or_insert_with(Vec::new).extend(
+                chunk_words
+                    .into_iter()
+                    .map(|word| WordTimestamp {
+                        start: word.start + offset,
+                        end: word.end + offset,
+                        ..word
+                    })
+                    .filter(|word| owns(i, word.start, word.end)),
+            );
+        }
+
+        if let Some(chunk_segments) = chunk_response.segments.take() {
+            typed_segments.get_or_insert_with(Vec::new).extend(
+                chunk_segments
+                    .into_iter()
+                    .map(|segment| SttSegment {
+                        start: segment.start + offset,
+                        end: segment.end + offset,
+                        ..segment
+                    })
+                    .filter(|segment| owns(i, segment.start, segment.end)),
+            );
+        }
+
+        if let Some(chunk_segments) = chunk_response
+            .metadata
+            .remove("segments")
+            .and_then(|value| match value {
+                serde_json::Value::Array(items) => Some(items),
+                _ => None,
+            })
+        {
+            let merged = segments.get_or_insert_with(Vec::new);
+            for mut segment in chunk_segments {
+                let Some(object) = segment.as_object_mut() else {
+                    continue;
+                };
+                let mut span = [None, None];
+                for (slot, keys) in span.iter_mut().zip([
+                    ["start", "startSecond", "start_second"],
+                    ["end", "endSecond", "end_second"],
+                ]) {
+                    for key in keys {
+                        if let Some(value) = object.get(key).and_then(|v| v.as_f64()) {
+                            let shifted = value as f32 + offset;
+                            object.insert(key.to_string(), serde_json::json!(shifted));
+                            *slot = Some(shifted);
+                        }
+                    }
+                }
+                if let [Some(start), Some(end)] = span
+                    && !owns(i, start, end)
+                {
+                    continue;
+                }
+                merged.push(segment);
+            }
+        }
+
+        language = language.or(chunk_response.language);
+        confidences.extend(chunk_response.confidence);
+        for (key, value) in chunk_response.metadata {
+            metadata.entry(key).or_insert(value);
+        }
+        warnings.extend(chunk_response.warnings.unwrap_or_default());
+        if let Some(chunk_metadata) = chunk_response.provider_metadata {
+            merge_provider_metadata(
+                provider_metadata.get_or_insert_with(Default::default),
+                chunk_metadata,
+            );
+        }
+        request = chunk_response.request.or(request);
+        response = chunk_response.response.or(response);
+    }
+
+    if let Some(segments) = segments {
+        metadata.insert("segments".to_string(), serde_json::Value::Array(segments));
+    }
+
+    SttResponse {
+        text,
+        language,
+        confidence: (!confidences.is_empty())
+            .then(|| confidences.iter().sum::<f32>() / confidences.len() as f32),
+        words,
+        segments: typed_segments,
+        duration: None,
+        metadata,
+        warnings: (!warnings.is_empty()).then_some(warnings),
+        provider_metadata,
+        request,
+        response,
+    }
+}
+
+/// A word (or a single CJK character) with its end byte offset and normalized form.
+struct Token {
+    end: usize,
+    norm: String,
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut push = |start: usize, end: usize| {
+        let norm: String = text[start..end]
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect();
+        if !norm.is_empty() {
+            tokens.push(Token { end, norm });
+        }
+    };
+
+    let mut word_start = None;
+    for (idx, c) in text.char_indices() {
+        if c.is_whitespace() || is_unspaced_script(c) {
+            if let Some(start) = word_start.take() {
+                push(start, idx);
+            }
+            if is_unspaced_script(c) {
+                push(idx, idx + c.len_utf8());
+            }
+        } else if word_start.is_none() {
+            word_start = Some(idx);
+        }
+    }
+    if let Some(start) = word_start {
+        push(start, text.len());
+    }
+    tokens
+}
+
+/// Append `next` to `prev`, dropping the text both chunks transcribed from their overlap.
+///
+/// The longest token run shared by the tail of `prev` and the head of `next` marks the overlap;
+/// `prev` is kept up to the end of that run and `next` continues right after it, so a word cut
+/// at either chunk edge is taken from the chunk that heard it whole.
+fn merge_overlapping_text(prev: &str, next: &str) -> String {
+    let next = next.trim();
+    if next.is_empty() {
+        return prev.to_string();
+    }
+    if prev.trim().is_empty() {
+        return next.to_string();
+    }
+
+    let prev_tokens = tokenize(prev);
+    let next_tokens = tokenize(next);
+    let prev_tail = &prev_tokens[prev_tokens.len().saturating_sub(OVERLAP_TOKEN_WINDOW)..];
+    let next_head = &next_tokens[..next_tokens.len().min(OVERLAP_TOKEN_WINDOW)];
+
+    // Longest common contiguous run (dynamic programming over the two windows).
+    let mut best: Option<(usize, usize, usize)> = None;
+    let mut lengths = vec![0usize; next_head.len() + 1];
+    for (i, prev_token) in prev_tail.iter().enumerate() {
+        for j in (0..next_head.len()).rev() {
+            lengths[j + 1] = if prev_token.norm == next_head[j].norm {
+                lengths[j] + 1
+            } else {
+                0
+            };
+            let len = lengths[j + 1];
+            if len > 0 && best.is_none_or(|(best_len, _, _)| len >= best_len) {
+                best = Some((len, i, j));
+            }
+        }
+    }
+
+    if let Some((len, prev_end, next_end)) = best {
+        let run_chars: usize = next_head[next_end + 1 - len..=next_end]
+            .iter()
+            .map(|token| token.norm.chars().count())
+            .sum();
+        if len >= 2 && run_chars >= 4 {
+            let keep = &prev[..prev_tail[prev_end].end];
+            let rest = &next[next_head[next_end].end..];
+            return format!("{keep}{rest}");
+        }
+    }
+
+    let prev = prev.trim_end();
+    let joiner = match (prev.chars().last(), next.chars().next()) {
+        (Some(a), Some(b)) if is_unspaced_script(a) || is_unspaced_script(b) => "",
+        _ => " ",
+    };
+    format!("{prev}{joiner}{next}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use siumai_core::traits::ModelMetadata;
+    use std::sync::Mutex;
+
+    fn silence(seconds: f32, sample_rate: u32) -> DecodedAudio {
+        DecodedAudio {
+            sample_rate,
+            channels: 1,
+            samples: vec![0; (seconds * sample_rate as f32) as usize],
+        }
+    }
+
+    fn word(word: &str, start: f32, end: f32) -> WordTimestamp {
+        WordTimestamp {
+            word: word.to_string(),
+            start,
+            end,
+            confidence: None,
+        }
+    }
+
+    fn stt(text: &str, words: Vec<WordTimestamp>) -> SttResponse {
+        SttResponse {
+            text: text.to_string(),
+            language: Some("en".to_string()),
+            confidence: None,
+            words: Some(words),
+            segments: None,
+            duration: None,
+            metadata: HashMap::new(),
+            warnings: None,
+            provider_metadata: None,
+            request: None,
+            response: None,
+        }
+    }
+
+    #[test]
+    fn wav_round_trips_through_chunk_encoding() {
+        let audio = DecodedAudio {
+            sample_rate: 8000,
+            channels: 2,
+            samples: vec![1, -1, 2, -2, 3, -3, 4, -4],
+        };
+        let wav = audio.to_wav(1, 3);
+        assert_eq!(wav.len(), WAV_HEADER_LEN + 8);
+
+        let decoded = decode_wav(&wav).expect("decode wav");
+        assert_eq!(decoded.sample_rate, 8000);
+        assert_eq!(decoded.channels, 2);
+        assert_eq!(decoded.samples, vec![2, -2, 3, -3]);
+        assert!(decode_wav(b"not a wav file").is_err());
+    }
+
+    #[test]
+    fn chunks_overlap_and_respect_byte_limit() {
+        let audio = silence(10.0, 1000);
+        let options = LongTranscriptionOptions::default()
+            .with_max_chunk_bytes(WAV_HEADER_LEN + 4000 * 2)
+            .with_overlap(Duration::from_secs(1));
+
+        let chunks = plan_chunks(&audio, &options).expect("plan");
+        assert_eq!(chunks, vec![(0, 4000), (3000, 7000), (6000, 10000)]);
+        assert!(
+            chunks
+                .iter()
+                .all(|(s, e)| audio.to_wav(*s, *e).len() <= options.max_chunk_bytes)
+        );
+    }
+
+    #[test]
+    fn overlap_text_is_deduplicated() {
+        assert_eq!(
+            merge_overlapping_text(
+                "We should ship the release on Fri",
+                "the release on Friday, then rest."
+            ),
+            "We should ship the release on Friday, then rest."
+        );
+        assert_eq!(
+            merge_overlapping_text("Hello there.", "General Kenobi."),
+            "Hello there. General Kenobi."
+        );
+        assert_eq!(
+            merge_overlapping_text("今天我们讨论发布计", "讨论发布计划的细节。"),
+            "今天我们讨论发布计划的细节。"
+        );
+    }
+
+    #[test]
+    fn stitching_offsets_words_and_keeps_one_copy_per_overlap() {
+        let merged = stitch_transcripts(vec![
+            ChunkTranscript {
+                start: 0.0,
+                end: 4.0,
+                response: stt(
+                    "one two three four",
+                    vec![
+                        word("one", 0.5, 1.0),
+                        word("two", 1.5, 2.0),
+                        word("three", 2.8, 3.3),
+                        word("four", 3.6, 4.0),
+                    ],
+                ),
+            },
+            ChunkTranscript {
+                start: 3.0,
+                end: 7.0,
+                response: stt(
+                    "three four five",
+                    vec![
+                        word("three", 0.0, 0.3),
+                        word("four", 0.6, 1.0),
+                        word("five", 2.0, 2.5),
+                    ],
+                ),
+            },
+        ]);
+
+        assert_eq!(merged.text, "one two three four five");
+        let words = merged.words.expect("words");
+        let summary: Vec<(&str, f32)> = words.iter().map(|w| (w.word.as_str(), w.start)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("one", 0.5),
+                ("two", 1.5),
+                ("three", 2.8),
+                ("four", 3.6),
+                ("five", 5.0)
+            ]
+        );
+        assert_eq!(merged.language.as_deref(), Some("en"));
+    }
+
+    struct ChunkEchoModel {
+        calls: Mutex<Vec<usize>>,
+    }
+
+    impl ModelMetadata for ChunkEchoModel {
+        fn provider_id(&self) -> &str {
+            "fake"
+        }
+
+        fn model_id(&self) -> &str {
+            "chunk-echo"
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl TranscriptionModel for ChunkEchoModel {
+        async fn transcribe(&self, request: SttRequest) -> Result<SttResponse, LlmError> {
+            assert_eq!(request.media_type, "audio/wav");
+            let bytes = request.audio.as_bytes().expect("binary audio");
+            let frames = decode_wav(&bytes).expect("chunk wav").frames();
+            let index = {
+                let mut calls = self.calls.lock().unwrap();
+                calls.push(frames);
+                calls.len() - 1
+            };
+            Ok(stt(
+                &format!("part {index} ends"),
+                vec![word("part", 1.0, 1.2)],
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn transcribe_long_splits_oversized_wav_and_merges_results() {
+        let audio = silence(10.0, 1000);
+        let model = ChunkEchoModel {
+            calls: Mutex::new(Vec::new()),
+        };
+        let result = transcribe_long(
+            &model,
+            SttRequest::from_audio(audio.to_wav(0, audio.frames()), "audio/wav"),
+            LongTranscriptionOptions::default()
+                .with_max_chunk_duration(Duration::from_secs(4))
+                .with_overlap(Duration::from_secs(1))
+                .with_max_concurrency(2),
+        )
+        .await
+        .expect("long transcription");
+
+        assert_eq!(*model.calls.lock().unwrap(), vec![4000, 4000, 4000]);
+        assert_eq!(result.text, "part 0 ends part 1 ends part 2 ends");
+        assert_eq!(result.duration, Some(10.0));
+        let starts: Vec<f32> = result
+            .words
+            .expect("words")
+            .iter()
+            .map(|w| w.start)
+            .collect();
+        assert_eq!(starts, vec![1.0, 4.0, 7.0]);
+    }
+
+    #[tokio::test]
+    async fn compressed_audio_without_decoder_is_rejected() {
+        let model = ChunkEchoModel {
+            calls: Mutex::new(Vec::new()),
+        };
+        let err = transcribe_long(
+            &model,
+            SttRequest::from_audio(vec![0u8; 64], "audio/mpeg"),
+            LongTranscriptionOptions::default().with_max_chunk_bytes(16),
+        )
+        .await
+        .expect_err("mp3 needs a decoder");
+        assert!(matches!(err, LlmError::UnsupportedOperation(_)));
+    }
+}
@@ -0,0 +1,430 @@
+//! SRT and WebVTT subtitle export for transcription segments.
+//!
+//! Segments are re-flowed into cues that respect a per-line character limit, a maximum number of
+//! lines per cue and a maximum cue duration. Long segments are split at word boundaries (or at
+//! character boundaries for scripts written without spaces) and their time span is shared out in
+//! proportion to the text each cue carries. Short cues are stretched to the minimum duration when
+//! the next cue leaves room.
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use super::{TranscriptionResult, TranscriptionSegment, is_unspaced_script};
+
+/// Layout constraints for [`to_srt`] and [`to_webvtt`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleOptions {
+    /// Maximum characters per subtitle line.
+    pub max_line_chars: usize,
+    /// Maximum lines per cue.
+    pub max_lines: usize,
+    /// Maximum time a cue stays on screen.
+    pub max_cue_duration: Duration,
+    /// Minimum time a cue stays on screen, when the next cue leaves room.
+    pub min_cue_duration: Duration,
+    /// Render speaker labels of diarized segments.
+    pub include_speakers: bool,
+}
+
+impl Default for SubtitleOptions {
+    fn default() -> Self {
+        Self {
+            max_line_chars: 42,
+            max_lines: 2,
+            max_cue_duration: Duration::from_secs(7),
+            min_cue_duration: Duration::from_millis(1000),
+            include_speakers: true,
+        }
+    }
+}
+
+impl SubtitleOptions {
+    /// Set the maximum characters per line.
+    pub fn with_max_line_chars(mut self, max_line_chars: usize) -> Self {
+        self.max_line_chars = max_line_chars;
+        self
+    }
+
+    /// Set the maximum lines per cue.
+    pub fn with_max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines;
+        self
+    }
+
+    /// Set the maximum cue duration.
+    pub fn with_max_cue_duration(mut self, max_cue_duration: Duration) -> Self {
+        self.max_cue_duration = max_cue_duration;
+        self
+    }
+
+    /// Set the minimum cue duration.
+    pub fn with_min_cue_duration(mut self, min_cue_duration: Duration) -> Self {
+        self.min_cue_duration = min_cue_duration;
+        self
+    }
+
+    /// Enable or disable speaker labels.
+    pub fn with_speakers(mut self, include_speakers: bool) -> Self {
+        self.include_speakers = include_speakers;
+        self
+    }
+}
+
+impl TranscriptionResult {
+    /// Render the transcript segments as SubRip (`.srt`) subtitles.
+    pub fn to_srt(&self, options: &SubtitleOptions) -> String {
+        to_srt(&self.segments, options)
+    }
+
+    /// Render the transcript segments as WebVTT (`.vtt`) subtitles.
+    pub fn to_webvtt(&self, options: &SubtitleOptions) -> String {
+        to_webvtt(&self.segments, options)
+    }
+}
+
+/// Render segments as SubRip (`.srt`) subtitles.
+///
+/// Speaker labels are written as a `Speaker: ` prefix on the first line of the cue.
+pub fn to_srt(segments: &[TranscriptionSegment], options: &SubtitleOptions) -> String {
+    let mut out = String::new();
+    for (index, cue) in build_cues(segments, options, true).iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "{}\n{} --> {}",
+            index + 1,
+            format_timestamp(cue.start, ','),
+            format_timestamp(cue.end, ',')
+        );
+        for (line_index, line) in cue.lines.iter().enumerate() {
+            match (&cue.speaker, line_index) {
+                (Some(speaker), 0) => {
+                    let _ = writeln!(out, "{speaker}: {line}");
+                }
+                _ => {
+                    let _ = writeln!(out, "{line}");
+                }
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Render segments as WebVTT (`.vtt`) subtitles.
+///
+/// Speaker labels are written as `<v Speaker>` voice spans.
+pub fn to_webvtt(segments: &[TranscriptionSegment], options: &SubtitleOptions) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in build_cues(segments, options, false) {
+        let _ = writeln!(
+            out,
+            "{} --> {}",
+            format_timestamp(cue.start, '.'),
+            format_timestamp(cue.end, '.')
+        );
+        let text = cue
+            .lines
+            .iter()
+            .map(|line| escape_vtt(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        match &cue.speaker {
+            Some(speaker) => {
+                let _ = writeln!(out, "<v {}>{text}", escape_vtt(speaker));
+            }
+            None => {
+                let _ = writeln!(out, "{text}");
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Cue {
+    start: f32,
+    end: f32,
+    speaker: Option<String>,
+    lines: Vec<String>,
+}
+
+/// A word (or a piece of an over-long word) and whether a space precedes it.
+struct Token<'a> {
+    text: &'a str,
+    spaced: bool,
+    chars: usize,
+}
+
+fn tokenize(text: &str, max_chars: usize) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    for word in text.split_whitespace() {
+        // Unspaced scripts and over-long words break at character boundaries.
+        let breakable = word.chars().any(is_unspaced_script);
+        let mut spaced = true;
+        let mut piece_start = 0;
+        let mut piece_chars = 0;
+        for (idx, _) in word.char_indices() {
+            if piece_chars > 0 && (breakable || piece_chars == max_chars) {
+                tokens.push(Token {
+                    text: &word[piece_start..idx],
+                    spaced,
+                    chars: piece_chars,
+                });
+                spaced = false;
+                piece_start = idx;
+                piece_chars = 0;
+            }
+            piece_chars += 1;
+        }
+        if piece_chars > 0 {
+            tokens.push(Token {
+                text: &word[piece_start..],
+                spaced,
+                chars: piece_chars,
+            });
+        }
+    }
+    tokens
+}
+
+/// Split segments into cues. With `prefix_speakers`, the first line of each cue leaves room for
+/// the `Speaker: ` prefix that SRT writes in front of it.
+fn build_cues(
+    segments: &[TranscriptionSegment],
+    options: &SubtitleOptions,
+    prefix_speakers: bool,
+) -> Vec<Cue> {
+    let max_line_chars = options.max_line_chars.max(1);
+    let max_lines = options.max_lines.max(1);
+    let max_cue_seconds = options.max_cue_duration.as_secs_f32();
+
+    let mut cues = Vec::new();
+    for segment in segments {
+        let speaker = segment.speaker.clone().filter(|_| options.include_speakers);
+        let prefix_chars = match &speaker {
+            Some(speaker) if prefix_speakers => speaker.chars().count() + 2,
+            _ => 0,
+        };
+        let first_line_chars = max_line_chars.saturating_sub(prefix_chars).max(1);
+        let tokens = tokenize(&segment.text, first_line_chars);
+        let total_chars: usize = tokens.iter().map(|token| token.chars).sum();
+        if total_chars == 0 {
+            continue;
+        }
+
+        // Cap the text per cue so that its share of the segment fits the maximum duration.
+        let duration = (segment.end_second - segment.start_second).max(0.0);
+        let mut cue_budget = max_line_chars * max_lines;
+        if max_cue_seconds > 0.0 && duration > max_cue_seconds {
+            let share = (total_chars as f32 * max_cue_seconds / duration).floor() as usize;
+            cue_budget = cue_budget.min(share.max(1));
+        }
+
+        let mut packed: Vec<(Vec<String>, usize)> = Vec::new();
+        let mut lines: Vec<String> = Vec::new();
+        let mut line = String::new();
+        let mut line_chars = 0;
+        let mut cue_chars = 0;
+        for token in &tokens {
+            let joiner = usize::from(token.spaced && line_chars > 0);
+            let line_limit = if lines.is_empty() {
+                first_line_chars
+            } else {
+                max_line_chars
+            };
+            if cue_chars > 0 && cue_chars + token.chars > cue_budget {
+                if !line.is_empty() {
+                    lines.push(std::mem::take(&mut line));
+                }
+                packed.push((std::mem::take(&mut lines), cue_chars));
+                line_chars = 0;
+                cue_chars = 0;
+            } else if line_chars > 0 && line_chars + joiner + token.chars > line_limit {
+                lines.push(std::mem::take(&mut line));
+                line_chars = 0;
+                if lines.len() == max_lines {
+                    packed.push((std::mem::take(&mut lines), cue_chars));
+                    cue_chars = 0;
+                }
+            }
+            if token.spaced && line_chars > 0 {
+                line.push(' ');
+                line_chars += 1;
+            }
+            line.push_str(token.text);
+            line_chars += token.chars;
+            cue_chars += token.chars;
+        }
+        if !line.is_empty() {
+            lines.push(line);
+        }
+        if !lines.is_empty() {
+            packed.push((lines, cue_chars));
+        }
+
+        let mut consumed = 0;
+        for (lines, chars) in packed {
+            let start = segment.start_second + duration * consumed as f32 / total_chars as f32;
+            consumed += chars;
+            let end = segment.start_second + duration * consumed as f32 / total_chars as f32;
+            cues.push(Cue {
+                start,
+                end,
+                speaker: speaker.clone(),
+                lines,
+            });
+        }
+    }
+
+    cues.sort_by(|a, b| a.start.total_cmp(&b.start));
+    let min_seconds = options.min_cue_duration.as_secs_f32();
+    for index in 0..cues.len() {
+        let limit = cues.get(index + 1).map_or(f32::INFINITY, |next| next.start);
+        let cue = &mut cues[index];
+        if cue.end - cue.start < min_seconds {
+            cue.end = (cue.start + min_seconds).min(limit).max(cue.end);
+        }
+    }
+    cues
+}
+
+fn format_timestamp(seconds: f32, millis_separator: char) -> String {
+    let total_millis = (f64::from(seconds.max(0.0)) * 1000.0).round() as u64;
+    let hours = total_millis / 3_600_000;
+    let minutes = total_millis / 60_000 % 60;
+    let secs = total_millis / 1000 % 60;
+    let millis = total_millis % 1000;
+    format!("{hours:02}:{minutes:02}:{secs:02}{millis_separator}{millis:03}")
+}
+
+fn escape_vtt(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(text: &str, start: f32, end: f32, speaker: Option<&str>) -> TranscriptionSegment {
+        TranscriptionSegment {
+            text: text.to_string(),
+            start_second: start,
+            end_second: end,
+            speaker: speaker.map(str::to_string),
+            avg_logprob: None,
+            no_speech_prob: None,
+        }
+    }
+
+    #[test]
+    fn srt_numbers_cues_and_prefixes_speakers() {
+        let srt = to_srt(
+            &[
+                segment("Hello there.", 0.0, 1.5, Some("A")),
+                segment("General Kenobi.", 1.5, 3.25, Some("B")),
+            ],
+            &SubtitleOptions::default(),
+        );
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:01,500\nA: Hello there.\n\n\
+             2\n00:00:01,500 --> 00:00:03,250\nB: General Kenobi.\n\n"
+        );
+    }
+
+    #[test]
+    fn srt_speaker_prefix_counts_toward_the_first_line() {
+        let options = SubtitleOptions::default()
+            .with_max_line_chars(13)
+            .with_max_lines(2);
+        let srt = to_srt(&[segment("one two three", 0.0, 2.0, Some("Ann"))], &options);
+        assert_eq!(
+            srt,
+            "1\n00:00:00,000 --> 00:00:02,000\nAnn: one two\nthree\n\n"
+        );
+
+        // WebVTT voice spans are not rendered as text, so the line keeps its full width.
+        let cues = build_cues(
+            &[segment("one two three", 0.0, 2.0, Some("Ann"))],
+            &options,
+            false,
+        );
+        assert_eq!(cues[0].lines, vec!["one two three"]);
+    }
+
+    #[test]
+    fn webvtt_uses_voice_spans_and_dot_millis() {
+        let vtt = to_webvtt(
+            &[segment("a < b", 3661.0, 3662.5, Some("Ann"))],
+            &SubtitleOptions::default(),
+        );
+        assert_eq!(
+            vtt,
+            "WEBVTT\n\n01:01:01.000 --> 01:01:02.500\n<v Ann>a &lt; b\n\n"
+        );
+    }
+
+    #[test]
+    fn long_segments_wrap_lines_and_split_cues() {
+        let options = SubtitleOptions::default()
+            .with_max_line_chars(10)
+            .with_max_lines(2);
+        let cues = build_cues(
+            &[segment("one two three four five six seven", 0.0, 6.0, None)],
+            &options,
+            false,
+        );
+
+        let lines: Vec<Vec<&str>> = cues
+            .iter()
+            .map(|cue| cue.lines.iter().map(String::as_str).collect())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![vec!["one two", "three four"], vec!["five six", "seven"]]
+        );
+        assert!(
+            cues.iter()
+                .all(|cue| cue.lines.iter().all(|line| line.chars().count() <= 10))
+        );
+        assert_eq!(cues[0].start, 0.0);
+        assert_eq!(cues[1].end, 6.0);
+        assert!((cues[0].end - cues[1].start).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn cue_duration_limits_split_slow_segments_and_stretch_short_ones() {
+        let options = SubtitleOptions::default().with_max_cue_duration(Duration::from_secs(5));
+        let cues = build_cues(
+            &[
+                segment("slow speech spread over a long pause", 0.0, 20.0, None),
+                segment("hi", 20.0, 20.2, None),
+                segment("bye", 20.4, 21.0, None),
+            ],
+            &options,
+            false,
+        );
+
+        let (slow, rest) = cues.split_at(cues.len() - 2);
+        assert!(slow.len() >= 4);
+        assert!(slow.iter().all(|cue| cue.end - cue.start <= 5.0 + 1e-3));
+        // The short cue grows toward the minimum duration but never overlaps the next one.
+        assert!((rest[0].end - 20.4).abs() < 1e-4);
+        assert!((rest[1].end - 21.4).abs() < 1e-4);
+    }
+
+    #[test]
+    fn unspaced_scripts_break_between_characters() {
+        let options = SubtitleOptions::default().with_max_line_chars(4);
+        let cues = build_cues(
+            &[segment("今天我们讨论发布", 0.0, 2.0, None)],
+            &options,
+            false,
+        );
+        assert_eq!(cues.len(), 1);
+        assert_eq!(cues[0].lines, vec!["今天我们", "讨论发布"]);
+    }
+}
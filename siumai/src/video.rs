@@ -792,6 +792,7 @@ async fn download_generated_video_url(
                 "url": url,
                 "body": body,
             })),
+            http_details: None,
         });
     }
 
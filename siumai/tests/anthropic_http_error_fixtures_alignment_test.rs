@@ -0,0 +1,52 @@
+#![cfg(feature = "anthropic")]
+
+use siumai::experimental::standards::anthropic::errors::classify_anthropic_http_error;
+use siumai::prelude::unified::{LlmError, LlmErrorExt};
+use std::path::Path;
+
+fn fixture_text(path: impl AsRef<Path>) -> String {
+    std::fs::read_to_string(path).expect("read fixture")
+}
+
+#[test]
+fn anthropic_overloaded_fixture_maps_to_overloaded() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("anthropic")
+            .join("errors")
+            .join("anthropic-error.1.json"),
+    );
+
+    let err = classify_anthropic_http_error("anthropic", 529, &body).expect("classified");
+
+    assert!(matches!(err, LlmError::Overloaded { .. }), "{err:?}");
+    assert!(err.is_retryable());
+    assert_eq!(err.status_code(), Some(529));
+}
+
+#[test]
+fn anthropic_prompt_too_long_fixture_maps_to_context_length_exceeded() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("anthropic")
+            .join("errors")
+            .join("anthropic-error.2.json"),
+    );
+
+    let err = classify_anthropic_http_error("anthropic", 400, &body).expect("classified");
+
+    match &err {
+        LlmError::ContextLengthExceeded { message, .. } => {
+            assert_eq!(
+                message,
+                "prompt is too long: 215337 tokens > 200000 maximum"
+            )
+        }
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+    assert!(!err.is_retryable());
+}
@@ -36,3 +36,53 @@ fn bedrock_error_fixture_preserves_message() {
         other => panic!("unexpected error variant: {other:?}"),
     }
 }
+
+#[test]
+fn bedrock_input_too_long_fixture_maps_to_context_length_exceeded() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("bedrock")
+            .join("errors")
+            .join("bedrock-error.2.json"),
+    );
+
+    let standard = siumai::experimental::standards::bedrock::chat::BedrockChatStandard::new();
+    let spec = standard.create_spec("bedrock");
+    let err = spec
+        .classify_http_error(400, &body, &HeaderMap::new())
+        .expect("classified");
+
+    match &err {
+        LlmError::ContextLengthExceeded { message, .. } => {
+            assert_eq!(message, "Input is too long for requested model.")
+        }
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+    assert_eq!(
+        err.http_details()
+            .and_then(|details| details.error_type.as_deref()),
+        Some("ValidationException")
+    );
+}
+
+#[test]
+fn bedrock_service_unavailable_fixture_maps_to_overloaded() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("bedrock")
+            .join("errors")
+            .join("bedrock-error.3.json"),
+    );
+
+    let standard = siumai::experimental::standards::bedrock::chat::BedrockChatStandard::new();
+    let spec = standard.create_spec("bedrock");
+    let err = spec
+        .classify_http_error(503, &body, &HeaderMap::new())
+        .expect("classified");
+
+    assert!(matches!(err, LlmError::Overloaded { .. }), "{err:?}");
+}
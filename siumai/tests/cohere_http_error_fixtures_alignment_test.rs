@@ -31,3 +31,29 @@ fn cohere_error_fixture_preserves_message() {
         other => panic!("unexpected error variant: {other:?}"),
     }
 }
+
+#[test]
+fn cohere_too_many_tokens_fixture_maps_to_context_length_exceeded() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("cohere")
+            .join("errors")
+            .join("cohere-error.2.json"),
+    );
+
+    let spec = siumai::experimental::standards::cohere::rerank::CohereRerankStandard::new()
+        .create_spec("cohere");
+    let err = spec
+        .classify_http_error(400, &body, &HeaderMap::new())
+        .expect("classified");
+
+    // The message says "cannot exceed"; it must not be mistaken for a quota error.
+    match err {
+        LlmError::ContextLengthExceeded { message, .. } => {
+            assert!(message.starts_with("too many tokens"))
+        }
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+}
@@ -288,6 +288,7 @@ fn facade_audio_and_structured_helpers_do_not_read_request_provider_options() {
     for relative_path in [
         "src/speech.rs",
         "src/transcription.rs",
+        "src/transcription/long_form.rs",
         "src/structured_output.rs",
     ] {
         let source = read_source(relative_path);
@@ -0,0 +1,399 @@
+#![cfg(all(feature = "openai", feature = "google"))]
+#![allow(deprecated)]
+
+//! Streaming/resumable file uploads and streaming downloads against local protocol stand-ins.
+//!
+//! The wiremock responders below implement the server side of Gemini's resumable upload
+//! protocol (`X-Goog-Upload-*`) and OpenAI's Uploads API, including injected failures.
+
+use futures_util::StreamExt;
+use siumai::extensions::FileManagementCapability;
+use siumai::extensions::types::{FileStreamUploadRequest, UploadSession};
+use siumai::prelude::compat::Siumai;
+use std::sync::{Arc, Mutex};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+fn payload(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+fn header<'a>(req: &'a Request, name: &str) -> Option<&'a str> {
+    req.headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+/// Gemini resumable upload session stand-in.
+#[derive(Clone, Default)]
+struct ResumableServer {
+    state: Arc<Mutex<ResumableState>>,
+}
+
+#[derive(Default)]
+struct ResumableState {
+    received: Vec<u8>,
+    finalized: bool,
+    upload_calls: usize,
+    query_calls: usize,
+    /// Query call (1-based) that fails with a retryable 503.
+    fail_query_on_call: Option<usize>,
+    /// Upload call (1-based) that only persists half its body and answers 503.
+    truncate_on_call: Option<usize>,
+    /// Upload call (1-based) that is rejected with a non-retryable 400.
+    reject_on_call: Option<usize>,
+}
+
+impl Respond for ResumableServer {
+    fn respond(&self, req: &Request) -> ResponseTemplate {
+        let mut state = self.state.lock().unwrap();
+        let command = header(req, "x-goog-upload-command").unwrap_or_default();
+        if command == "query" {
+            state.query_calls += 1;
+            if state.fail_query_on_call == Some(state.query_calls) {
+                return ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                    "error": { "code": 503, "message": "busy", "status": "UNAVAILABLE" }
+                }));
+            }
+            let status = if state.finalized { "final" } else { "active" };
+            return ResponseTemplate::new(200)
+                .insert_header("x-goog-upload-status", status)
+                .insert_header(
+                    "x-goog-upload-size-received",
+                    state.received.len().to_string().as_str(),
+                );
+        }
+
+        state.upload_calls += 1;
+        let call = state.upload_calls;
+        let offset: usize = header(req, "x-goog-upload-offset")
+            .and_then(|v| v.parse().ok())
+            .expect("upload offset");
+        assert_eq!(
+            offset,
+            state.received.len(),
+            "chunk must continue at the persisted offset"
+        );
+        if !command.contains("finalize") {
+            assert_eq!(
+                req.body.len() % (256 * 1024),
+                0,
+                "non-final chunks must be multiples of 256 KiB"
+            );
+        }
+
+        if state.reject_on_call == Some(call) {
+            return ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "error": { "code": 400, "message": "rejected", "status": "INVALID_ARGUMENT" }
+            }));
+        }
+        if state.truncate_on_call == Some(call) {
+            let half = req.body.len() / 2;
+            state.received.extend_from_slice(&req.body[..half]);
+            return ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "error": { "code": 503, "message": "interrupted", "status": "UNAVAILABLE" }
+            }));
+        }
+
+        state.received.extend_from_slice(&req.body);
+        if command.contains("finalize") {
+            state.finalized = true;
+            return ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "file": {
+                    "name": "files/streamed",
+                    "displayName": "big.bin",
+                    "mimeType": "application/octet-stream",
+                    "sizeBytes": state.received.len().to_string(),
+                    "state": "ACTIVE"
+                }
+            }));
+        }
+        ResponseTemplate::new(200).insert_header("x-goog-upload-status", "active")
+    }
+}
+
+async fn mount_gemini(server: &MockServer, session: ResumableServer) {
+    let upload_url = format!("{}/resumable/session-1", server.uri());
+    Mock::given(method("POST"))
+        .and(path("/upload/v1beta/files"))
+        .respond_with(move |req: &Request| {
+            assert_eq!(header(req, "x-goog-upload-protocol"), Some("resumable"));
+            assert_eq!(header(req, "x-goog-upload-command"), Some("start"));
+            assert_eq!(
+                header(req, "x-goog-upload-header-content-length"),
+                Some("655360")
+            );
+            ResponseTemplate::new(200).insert_header("x-goog-upload-url", upload_url.as_str())
+        })
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/resumable/session-1"))
+        .respond_with(session)
+        .mount(server)
+        .await;
+}
+
+async fn gemini_client(server: &MockServer) -> Siumai {
+    Siumai::builder()
+        .gemini()
+        .api_key("test-key")
+        .base_url(format!("{}/v1beta", server.uri()))
+        .model("gemini-2.5-flash")
+        .build()
+        .await
+        .expect("build gemini client")
+}
+
+#[tokio::test]
+async fn gemini_resumable_upload_retries_a_partially_received_chunk() {
+    let server = MockServer::start().await;
+    let session = ResumableServer::default();
+    {
+        let mut state = session.state.lock().unwrap();
+        state.truncate_on_call = Some(2);
+        state.fail_query_on_call = Some(1);
+    }
+    mount_gemini(&server, session.clone()).await;
+
+    let data = payload(640 * 1024);
+    let progress = Arc::new(Mutex::new(Vec::<u64>::new()));
+    let progress_sink = progress.clone();
+    let request = FileStreamUploadRequest::from_reader(
+        std::io::Cursor::new(data.clone()),
+        data.len() as u64,
+        "general",
+    )
+    .with_filename("big.bin")
+    .with_chunk_size(256 * 1024)
+    .with_progress(move |session: &UploadSession| {
+        progress_sink.lock().unwrap().push(session.offset)
+    });
+
+    let file = gemini_client(&server)
+        .await
+        .upload_file_stream(request)
+        .await
+        .expect("upload ok");
+
+    assert_eq!(file.id, "streamed");
+    let state = session.state.lock().unwrap();
+    assert!(state.finalized);
+    assert_eq!(state.received, data);
+    // The failed offset query is retried, then the truncated chunk's remainder is merged
+    // with the last bytes into one final send instead of an unaligned non-final chunk.
+    assert_eq!(state.query_calls, 2);
+    assert_eq!(state.upload_calls, 3);
+    assert_eq!(*progress.lock().unwrap(), vec![262_144, 393_216, 655_360]);
+}
+
+#[tokio::test]
+async fn gemini_resumable_upload_resumes_an_interrupted_session_from_a_path() {
+    let server = MockServer::start().await;
+    let session = ResumableServer::default();
+    session.state.lock().unwrap().reject_on_call = Some(2);
+    mount_gemini(&server, session.clone()).await;
+
+    let data = payload(640 * 1024);
+    let file_path =
+        std::env::temp_dir().join(format!("siumai-resumable-{}.bin", std::process::id()));
+    std::fs::write(&file_path, &data).unwrap();
+
+    let last_session = Arc::new(Mutex::new(None::<UploadSession>));
+    let sink = last_session.clone();
+    let client = gemini_client(&server).await;
+    let err = client
+        .upload_file_stream(
+            FileStreamUploadRequest::from_path(&file_path, "general")
+                .with_chunk_size(256 * 1024)
+                .with_progress(move |session: &UploadSession| {
+                    *sink.lock().unwrap() = Some(session.clone())
+                }),
+        )
+        .await
+        .expect_err("second chunk is rejected");
+    assert!(!err.to_string().is_empty());
+
+    let saved = last_session.lock().unwrap().clone().expect("saved session");
+    assert_eq!(saved.provider_id, "gemini");
+    assert_eq!(saved.offset, 262_144);
+    assert_eq!(saved.total_bytes, data.len() as u64);
+
+    let file = client
+        .upload_file_stream(
+            FileStreamUploadRequest::from_path(&file_path, "general")
+                .with_chunk_size(256 * 1024)
+                .with_resume(saved),
+        )
+        .await
+        .expect("resumed upload ok");
+    std::fs::remove_file(&file_path).ok();
+
+    assert_eq!(file.id, "streamed");
+    assert_eq!(session.state.lock().unwrap().received, data);
+}
+
+/// OpenAI Uploads API stand-in: parts are stored by id and assembled on completion.
+#[derive(Clone, Default)]
+struct UploadsServer {
+    state: Arc<Mutex<UploadsState>>,
+}
+
+#[derive(Default)]
+struct UploadsState {
+    parts: Vec<(String, Vec<u8>)>,
+    part_calls: usize,
+    assembled: Vec<u8>,
+}
+
+fn multipart_data_field(req: &Request) -> Vec<u8> {
+    let content_type = header(req, "content-type").expect("content-type");
+    let boundary = content_type
+        .split("boundary=")
+        .nth(1)
+        .expect("multipart boundary");
+    let delimiter = format!("--{boundary}");
+    let body = &req.body;
+    let start = body
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .expect("part headers")
+        + 4;
+    let end = body
+        .windows(delimiter.len() + 2)
+        .rposition(|w| w == format!("\r\n{delimiter}").as_bytes())
+        .expect("closing boundary");
+    body[start..end].to_vec()
+}
+
+impl Respond for UploadsServer {
+    fn respond(&self, req: &Request) -> ResponseTemplate {
+        let mut state = self.state.lock().unwrap();
+        state.part_calls += 1;
+        if state.part_calls == 2 {
+            return ResponseTemplate::new(500).set_body_json(serde_json::json!({
+                "error": { "message": "flaky", "type": "server_error" }
+            }));
+        }
+        let id = format!("part_{}", state.parts.len() + 1);
+        let data = multipart_data_field(req);
+        state.parts.push((id.clone(), data));
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": id, "object": "upload.part", "upload_id": "upload_abc"
+        }))
+    }
+}
+
+#[tokio::test]
+async fn openai_uploads_api_retries_parts_and_completes_in_order() {
+    let server = MockServer::start().await;
+    let uploads = UploadsServer::default();
+
+    Mock::given(method("POST"))
+        .and(path("/v1/uploads"))
+        .respond_with(|req: &Request| {
+            let body: serde_json::Value = serde_json::from_slice(&req.body).unwrap();
+            assert_eq!(body["purpose"], "batch");
+            assert_eq!(body["bytes"], 40);
+            assert_eq!(body["filename"], "data.jsonl");
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "upload_abc", "object": "upload", "status": "pending"
+            }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/uploads/upload_abc/parts"))
+        .respond_with(uploads.clone())
+        .mount(&server)
+        .await;
+    let state = uploads.state.clone();
+    Mock::given(method("POST"))
+        .and(path("/v1/uploads/upload_abc/complete"))
+        .respond_with(move |req: &Request| {
+            let body: serde_json::Value = serde_json::from_slice(&req.body).unwrap();
+            let mut state = state.lock().unwrap();
+            let mut assembled = Vec::new();
+            for id in body["part_ids"].as_array().unwrap() {
+                let (_, data) = state
+                    .parts
+                    .iter()
+                    .find(|(part_id, _)| part_id == id.as_str().unwrap())
+                    .expect("known part");
+                assembled.extend_from_slice(data);
+            }
+            state.assembled = assembled;
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "id": "upload_abc",
+                "object": "upload",
+                "status": "completed",
+                "file": {
+                    "id": "file-streamed",
+                    "object": "file",
+                    "bytes": 40,
+                    "created_at": 1710000000u64,
+                    "filename": "data.jsonl",
+                    "purpose": "batch",
+                    "status": "processed"
+                }
+            }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Siumai::builder()
+        .openai()
+        .api_key("test-api-key")
+        .base_url(format!("{}/v1", server.uri()))
+        .model("gpt-4o")
+        .build()
+        .await
+        .expect("build ok");
+
+    let data = payload(40);
+    let file = client
+        .upload_file_stream(
+            FileStreamUploadRequest::from_reader(std::io::Cursor::new(data.clone()), 40, "batch")
+                .with_filename("data.jsonl")
+                .with_chunk_size(16),
+        )
+        .await
+        .expect("upload ok");
+
+    assert_eq!(file.id, "file-streamed");
+    let state = uploads.state.lock().unwrap();
+    assert_eq!(state.parts.len(), 3);
+    assert_eq!(state.part_calls, 4);
+    assert_eq!(state.assembled, data);
+}
+
+#[tokio::test]
+async fn openai_file_content_can_be_streamed() {
+    let server = MockServer::start().await;
+    let data = payload(64 * 1024);
+    Mock::given(method("GET"))
+        .and(path("/v1/files/file_123/content"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(data.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = Siumai::builder()
+        .openai()
+        .api_key("test-api-key")
+        .base_url(format!("{}/v1", server.uri()))
+        .model("gpt-4o")
+        .build()
+        .await
+        .expect("build ok");
+
+    let mut stream = client
+        .get_file_content_stream("file_123".to_string())
+        .await
+        .expect("stream ok");
+    let mut downloaded = Vec::new();
+    while let Some(chunk) = stream.next().await {
+        downloaded.extend_from_slice(&chunk.expect("chunk ok"));
+    }
+    assert_eq!(downloaded, data);
+}
@@ -129,7 +129,7 @@ mod tests {
         assert!(auth_error.is_auth_error());
         assert!(!auth_error.is_retryable());
 
-        let rate_limit_error = LlmError::RateLimitError("Too many requests".to_string());
+        let rate_limit_error = LlmError::rate_limit_error("Too many requests");
         assert!(rate_limit_error.is_retryable());
         assert!(rate_limit_error.is_rate_limit_error());
 
@@ -289,6 +289,7 @@ mod tests {
             code: 429,
             message: "Rate limit exceeded".to_string(),
             details: None,
+            http_details: None,
         };
 
         // Test the category method that exists on LlmError
@@ -682,4 +682,192 @@ mod minimaxi_tests {
         assert!(deleted.deleted);
         assert_eq!(deleted.id, "123");
     }
+
+    /// Test MiniMaxi voice management (list -> clone -> design -> delete).
+    ///
+    /// Cloning uploads the reference audio with purpose `voice_clone` before calling
+    /// `/v1/voice_clone`; deleting without a `voice_id:voice_type` suffix resolves the type
+    /// from the voice list.
+    #[tokio::test]
+    async fn test_minimaxi_voice_management_lifecycle() {
+        use siumai::extensions::VoiceManagementCapability;
+        use siumai::extensions::types::{VoiceCloneRequest, VoiceDesignRequest};
+
+        let mock_server = MockServer::start().await;
+
+        let list_response = json!({
+            "system_voice": [
+                {
+                    "voice_id": "male-qn-qingse",
+                    "voice_name": "青涩青年音色",
+                    "description": ["Young male voice"]
+                }
+            ],
+            "voice_cloning": [
+                {
+                    "voice_id": "MyVoice001",
+                    "description": [],
+                    "created_time": "2025-06-01"
+                }
+            ],
+            "voice_generation": [],
+            "base_resp": { "status_code": 0, "status_msg": "success" }
+        });
+
+        Mock::given(method("POST"))
+            .and(path("/v1/get_voice"))
+            .and(header("authorization", "Bearer test-api-key"))
+            .and(body_json(json!({ "voice_type": "all" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(list_response))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/files/upload"))
+            .and(body_string_contains("voice_clone"))
+            .and(body_string_contains("reference.mp3"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "file": {
+                    "file_id": 456,
+                    "bytes": 3,
+                    "created_at": 1700469398,
+                    "filename": "reference.mp3",
+                    "purpose": "voice_clone"
+                },
+                "base_resp": { "status_code": 0, "status_msg": "success" }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/voice_clone"))
+            .and(body_json(json!({
+                "file_id": 456,
+                "voice_id": "MyVoice001",
+                "text": "Hello there",
+                "model": "speech-2.6-hd",
+                "need_noise_reduction": true
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "input_sensitive": false,
+                "demo_audio": "https://example.com/demo.mp3",
+                "base_resp": { "status_code": 0, "status_msg": "success" }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/voice_design"))
+            .and(body_json(json!({
+                "prompt": "A calm narrator with a low voice",
+                "preview_text": "Once upon a time"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "voice_id": "ttv-voice-2025",
+                "trial_audio": "010203",
+                "base_resp": { "status_code": 0, "status_msg": "success" }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/delete_voice"))
+            .and(body_json(json!({
+                "voice_type": "voice_cloning",
+                "voice_id": "MyVoice001"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "voice_id": "MyVoice001",
+                "base_resp": { "status_code": 0, "status_msg": "success" }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = Siumai::builder()
+            .minimaxi()
+            .api_key("test-api-key")
+            .base_url(mock_server.uri())
+            .build()
+            .await
+            .expect("Failed to build client");
+
+        let voices = client.list_voices().await.expect("List voices failed");
+        assert_eq!(voices.len(), 2);
+        assert_eq!(voices[0].id, "male-qn-qingse");
+        assert_eq!(voices[0].category.as_deref(), Some("system"));
+        assert_eq!(voices[0].description.as_deref(), Some("Young male voice"));
+        assert_eq!(voices[1].name, "MyVoice001");
+        assert_eq!(voices[1].category.as_deref(), Some("cloned"));
+
+        let cloned = client
+            .clone_voice(
+                VoiceCloneRequest::new(b"abc".to_vec())
+                    .with_media_type("audio/mpeg")
+                    .with_voice_id("MyVoice001")
+                    .with_preview_text("Hello there")
+                    .with_provider_option("minimaxi", json!({ "need_noise_reduction": true })),
+            )
+            .await
+            .expect("Clone failed");
+        assert_eq!(cloned.voice_id, "MyVoice001");
+        assert_eq!(
+            cloned.preview_url.as_deref(),
+            Some("https://example.com/demo.mp3")
+        );
+
+        let designed = client
+            .design_voice(
+                VoiceDesignRequest::new("A calm narrator with a low voice")
+                    .with_preview_text("Once upon a time"),
+            )
+            .await
+            .expect("Design failed");
+        assert_eq!(designed.voice_id, "ttv-voice-2025");
+        assert_eq!(designed.preview_audio, Some(vec![1, 2, 3]));
+
+        let deleted = client
+            .delete_voice("MyVoice001".to_string())
+            .await
+            .expect("Delete failed");
+        assert!(deleted.deleted);
+        assert_eq!(deleted.id, "MyVoice001");
+
+        // `SpeechExtras`-style voice listing on the audio capability uses the same catalog.
+        let audio_voices = siumai::extensions::AudioCapability::get_voices(&client)
+            .await
+            .expect("get_voices failed");
+        assert_eq!(audio_voices.len(), 2);
+    }
+
+    /// Test MiniMaxi voice cloning requires a caller-chosen voice id (vendor constraint).
+    #[tokio::test]
+    async fn test_minimaxi_clone_voice_requires_voice_id() {
+        use siumai::extensions::VoiceManagementCapability;
+        use siumai::extensions::types::VoiceCloneRequest;
+
+        let mock_server = MockServer::start().await;
+
+        let client = Siumai::builder()
+            .minimaxi()
+            .api_key("test-api-key")
+            .base_url(mock_server.uri())
+            .build()
+            .await
+            .expect("Failed to build client");
+
+        let err = client
+            .clone_voice(VoiceCloneRequest::new(b"abc".to_vec()))
+            .await
+            .expect_err("Expected clone_voice to fail without voice_id");
+        assert!(
+            err.to_string()
+                .contains("requires VoiceCloneRequest.voice_id"),
+            "Unexpected error: {err}"
+        );
+    }
 }
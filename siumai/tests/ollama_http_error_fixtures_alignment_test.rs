@@ -52,3 +52,25 @@ fn ollama_error_fixture_preserves_message() {
     // Smoke: build_headers should be fine without auth.
     let _headers = spec.build_headers(&ctx).expect("headers");
 }
+
+#[test]
+fn ollama_server_busy_fixture_maps_to_overloaded() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("ollama")
+            .join("errors")
+            .join("ollama-error.2.json"),
+    );
+
+    let spec = OllamaSpec::new(OllamaParams::default());
+    let err = spec
+        .classify_http_error(503, &body, &HeaderMap::new())
+        .expect("classified");
+
+    match err {
+        LlmError::Overloaded { message, .. } => assert!(message.starts_with("server busy")),
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+}
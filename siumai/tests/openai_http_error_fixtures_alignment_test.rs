@@ -30,7 +30,75 @@ fn openai_error_fixture_maps_message_losslessly() {
     let err = classify_openai_compatible_http_error("openai", 429, &body).expect("classified");
 
     match err {
-        LlmError::QuotaExceededError(msg) => assert_eq!(msg, expected_message),
+        LlmError::QuotaExceededError { message: msg, .. } => assert_eq!(msg, expected_message),
         other => panic!("unexpected error variant: {other:?}"),
     }
 }
+
+#[test]
+fn openai_context_length_fixture_maps_to_context_length_exceeded() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("openai")
+            .join("errors")
+            .join("openai-error.2.json"),
+    );
+
+    let err = classify_openai_compatible_http_error("openai", 400, &body).expect("classified");
+
+    match &err {
+        LlmError::ContextLengthExceeded { message, .. } => {
+            assert!(message.starts_with("This model's maximum context length is 128000 tokens"))
+        }
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+    assert_eq!(
+        err.http_details()
+            .and_then(|details| details.provider_code.as_deref()),
+        Some("context_length_exceeded")
+    );
+}
+
+#[test]
+fn openai_content_policy_fixture_maps_to_content_filtered() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("openai")
+            .join("errors")
+            .join("openai-error.3.json"),
+    );
+
+    let err = classify_openai_compatible_http_error("openai", 400, &body).expect("classified");
+
+    assert!(matches!(err, LlmError::ContentFiltered { .. }), "{err:?}");
+}
+
+#[test]
+fn azure_content_filter_fixture_maps_to_content_filtered() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("azure")
+            .join("errors")
+            .join("azure-error.1.json"),
+    );
+
+    let err = classify_openai_compatible_http_error("azure", 400, &body).expect("classified");
+
+    match &err {
+        LlmError::ContentFiltered { message, .. } => {
+            assert!(message.contains("content management policy"))
+        }
+        other => panic!("unexpected error variant: {other:?}"),
+    }
+    assert_eq!(
+        err.http_details()
+            .and_then(|details| details.provider_code.as_deref()),
+        Some("content_filter")
+    );
+}
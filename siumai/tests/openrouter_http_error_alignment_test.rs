@@ -23,7 +23,9 @@ fn openrouter_resource_exhausted_error_is_classified_as_rate_limit() {
         .expect("classified as openai-compatible");
 
     match err {
-        LlmError::RateLimitError(msg) => assert!(msg.contains("RESOURCE_EXHAUSTED")),
+        LlmError::RateLimitError { message: msg, .. } => {
+            assert!(msg.contains("RESOURCE_EXHAUSTED"))
+        }
         other => panic!("unexpected error variant: {other:?}"),
     }
 }
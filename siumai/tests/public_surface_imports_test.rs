@@ -72,6 +72,8 @@ fn public_surface_unified_imports_compile() {
     let _ = size_of::<PartialJsonParseState>();
     let _ = size_of::<PartialJsonValueStream>();
     let _ = size_of::<PartialJsonValueStreamEvent>();
+    let _ = size_of::<JsonArrayElementStream>();
+    let _ = size_of::<JsonArrayElementStreamEvent>();
     let _ = size_of::<RepairTextContext>();
     let _ = size_of::<RepairTextFunction>();
     let _ = size_of::<RepairTextFuture>();
@@ -585,6 +587,7 @@ fn public_surface_unified_imports_compile() {
         PartialJsonParseState::RepairedParse
     );
     let _ = partial_json_value_stream as fn(ChatStream) -> PartialJsonValueStream;
+    let _ = json_array_element_stream as fn(ChatStream) -> JsonArrayElementStream;
     let normalized = normalize_headers([("X-Test", "1")]);
     assert_eq!(normalized.get("x-test").map(String::as_str), Some("1"));
     let optional = normalize_optional_headers([("X-Keep", Some("yes")), ("X-Drop", None)]);
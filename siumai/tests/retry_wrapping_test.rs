@@ -34,6 +34,7 @@ impl ChatCapability for TestProvider {
                 code: 500,
                 message: format!("forced failure attempt {n}"),
                 details: None,
+                http_details: None,
             })
         } else {
             Ok(ChatResponse {
@@ -480,7 +480,9 @@ async fn factory_http_429_classified_as_rate_limit() {
     .await;
 
     match res {
-        Err(LlmError::RateLimitError(msg)) => assert!(msg.contains("provider=factory-test")),
+        Err(LlmError::RateLimitError { message: msg, .. }) => {
+            assert!(msg.contains("provider=factory-test"))
+        }
         Ok(_) => panic!("expected RateLimitError, got Ok"),
         Err(e) => panic!("expected RateLimitError, got: {e:?}"),
     }
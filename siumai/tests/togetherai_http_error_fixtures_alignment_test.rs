@@ -31,3 +31,26 @@ fn togetherai_error_fixture_preserves_message() {
         other => panic!("unexpected error variant: {other:?}"),
     }
 }
+
+#[test]
+fn togetherai_token_budget_fixture_maps_to_context_length_exceeded() {
+    let body = fixture_text(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("togetherai")
+            .join("errors")
+            .join("togetherai-error.2.json"),
+    );
+
+    let spec = siumai::experimental::standards::togetherai::rerank::TogetherAiRerankStandard::new()
+        .create_spec("togetherai");
+    let err = spec
+        .classify_http_error(400, &body, &HeaderMap::new())
+        .expect("classified");
+
+    assert!(
+        matches!(err, LlmError::ContextLengthExceeded { .. }),
+        "{err:?}"
+    );
+}